@@ -1,4 +1,5 @@
 use super::contract::{AiModel, AiProvider};
+use super::providers::{is_openai_compatible, PROVIDER_CUSTOM, PROVIDER_OLLAMA};
 use serde::Deserialize;
 use std::sync::LazyLock;
 
@@ -50,7 +51,7 @@ fn parse_catalog(json: &str) -> Catalog {
     };
 
     catalog.providers.push(CatalogProvider {
-        id: "custom".to_string(),
+        id: PROVIDER_CUSTOM.to_string(),
         label: "Custom (OpenAI-compatible)".to_string(),
         status: "production".to_string(),
         adapter: None,
@@ -60,6 +61,18 @@ fn parse_catalog(json: &str) -> Catalog {
         supports_reasoning: false,
         models: Vec::new(),
     });
+    // Local models the user has pulled; the model name is typed in, not listed.
+    catalog.providers.push(CatalogProvider {
+        id: PROVIDER_OLLAMA.to_string(),
+        label: "Ollama (local)".to_string(),
+        status: "production".to_string(),
+        adapter: None,
+        namespace: None,
+        requires_api_key: false,
+        supports_base_url: true,
+        supports_reasoning: false,
+        models: Vec::new(),
+    });
     catalog
 }
 
@@ -148,14 +161,14 @@ mod tests {
         let generated_provider_count = catalog
             .providers
             .iter()
-            .filter(|provider| provider.id != "custom")
+            .filter(|provider| !is_openai_compatible(&provider.id))
             .count();
         assert!(generated_provider_count >= 3);
 
         let mut provider_ids = HashSet::new();
         for provider in &catalog.providers {
             assert!(provider_ids.insert(provider.id.as_str()));
-            if provider.id != "custom"
+            if !is_openai_compatible(&provider.id)
                 && matches!(provider.status.as_str(), "production" | "experimental")
             {
                 assert!(provider
@@ -191,7 +204,7 @@ mod tests {
     #[test]
     fn production_and_experimental_providers_have_adapters() {
         for provider in &catalog().providers {
-            if provider.id != "custom"
+            if !is_openai_compatible(&provider.id)
                 && matches!(provider.status.as_str(), "production" | "experimental")
             {
                 assert!(
@@ -206,7 +219,7 @@ mod tests {
     #[test]
     fn adapter_to_provider_mapping_round_trips() {
         for provider in &catalog().providers {
-            if provider.id == "custom" {
+            if is_openai_compatible(&provider.id) {
                 continue;
             }
 
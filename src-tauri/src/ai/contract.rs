@@ -25,6 +25,9 @@ pub struct AiPolishRequest {
     pub input_text: String,
     pub prompt: String,
     pub timeout_ms: u64,
+    /// Sampling temperature; dropped for models that reject it.
+    pub temperature: Option<f64>,
+    pub max_output_tokens: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -2,11 +2,15 @@ use super::contract::{AiPolishRequest, AiPolishResult};
 use super::error::{AiProviderError, MappedAiProviderError};
 use super::genai_runtime::{AiKeyResolver, GenaiRuntime};
 use super::openai_compatible::OpenAiCompatibleRuntime;
-use super::providers::PROVIDER_CUSTOM;
+use super::providers::is_openai_compatible;
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 use tokio_util::sync::CancellationToken;
 
+/// Wait before the second and later retries of a network or rate-limit
+/// failure when the provider sends no Retry-After; doubles each time.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+
 #[derive(Clone)]
 pub struct AiExecutor {
     genai_runtime: GenaiRuntime,
@@ -59,7 +63,7 @@ impl AiExecutor {
         let start = Instant::now();
         let budget = Duration::from_millis(request.timeout_ms);
         let deadline = start + budget;
-        let mut attempt = 0_u8;
+        let mut retries = 0_u32;
 
         loop {
             let remaining = deadline
@@ -82,18 +86,25 @@ impl AiExecutor {
                         duration_ms: start.elapsed().as_millis().min(u128::from(u64::MAX)) as u64,
                     });
                 }
-                Err(mapped) if attempt == 0 && should_retry(&mapped.error) => {
-                    attempt += 1;
-                    if let Some(retry_after) = mapped.retry_after {
+                Err(mapped) if retries < max_retries(&mapped.error) => {
+                    retries += 1;
+                    let delay = mapped.retry_after.unwrap_or_else(|| retry_backoff(retries));
+                    if !delay.is_zero() {
                         let remaining_after_sleep = deadline
                             .checked_duration_since(Instant::now())
                             .ok_or(AiProviderError::Timeout)?;
-                        if retry_after >= remaining_after_sleep {
+                        if delay >= remaining_after_sleep {
                             return Err(mapped.error);
                         }
+                        log::info!(
+                            "AI request failed ({}); retry {} in {}ms",
+                            mapped.error,
+                            retries,
+                            delay.as_millis()
+                        );
                         tokio::select! {
                             _ = cancellation_token.cancelled() => return Err(AiProviderError::Canceled),
-                            _ = tokio::time::sleep(retry_after) => {}
+                            _ = tokio::time::sleep(delay) => {}
                         }
                     }
                 }
@@ -122,7 +133,7 @@ impl AiExecutor {
     ) -> Result<String, MappedAiProviderError> {
         if crate::ai::catalog::is_native_provider(&request.provider_id) {
             self.genai_runtime.polish(request).await
-        } else if request.provider_id == PROVIDER_CUSTOM {
+        } else if is_openai_compatible(&request.provider_id) {
             self.custom_runtime.polish(request).await
         } else {
             Err(MappedAiProviderError::new(
@@ -132,13 +143,22 @@ impl AiExecutor {
     }
 }
 
-fn should_retry(error: &AiProviderError) -> bool {
-    matches!(
-        error,
-        AiProviderError::RateLimited
-            | AiProviderError::ServiceUnavailable
-            | AiProviderError::Network
-    )
+/// Retries allowed after `error`: network blips and rate limits get two,
+/// a failing service one, anything else none.
+fn max_retries(error: &AiProviderError) -> u32 {
+    match error {
+        AiProviderError::RateLimited | AiProviderError::Network => 2,
+        AiProviderError::ServiceUnavailable => 1,
+        _ => 0,
+    }
+}
+
+/// The first retry goes out at once; later ones back off from 250 ms.
+fn retry_backoff(retry: u32) -> Duration {
+    match retry {
+        0 | 1 => Duration::ZERO,
+        n => RETRY_BASE_DELAY * 2_u32.pow(n - 2),
+    }
 }
 
 /// Sanitize a model's cleanup response before it is returned for auto-typing.
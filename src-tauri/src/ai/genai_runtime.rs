@@ -8,6 +8,11 @@ use genai::{Client, ClientBuilder, ModelIden, ServiceTarget};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Extra output tokens granted to reasoning models on top of the requested
+/// limit, matching the minimal thinking budget (Gemini LOW = 1000, Anthropic
+/// 1024) so thinking cannot starve the cleaned text.
+const REASONING_TOKEN_HEADROOM: u32 = 1024;
+
 pub type AiKeyResolver = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
 
 #[derive(Clone)]
@@ -60,15 +65,29 @@ impl GenaiRuntime {
         //   - Anthropic (4.x): adaptive thinking, effort "low" / 1024 budget tokens
         // Non-reasoning models are left with `None` so we never attach a reasoning
         // parameter a provider would reject (e.g. gpt-4o, gemini-2.0-flash).
-        let chat_options = if model_supports_reasoning(&request.provider_id, &request.model_id) {
-            Some(ChatOptions::default().with_reasoning_effort(ReasoningEffort::Minimal))
-        } else {
-            None
-        };
+        let reasoning = model_supports_reasoning(&request.provider_id, &request.model_id);
+        let mut chat_options = ChatOptions::default();
+        if reasoning {
+            chat_options = chat_options.with_reasoning_effort(ReasoningEffort::Minimal);
+        }
+        if let Some(temperature) = request
+            .temperature
+            .filter(|_| accepts_temperature(adapter_kind, reasoning))
+        {
+            chat_options = chat_options.with_temperature(temperature);
+        }
+        if let Some(max_tokens) = request.max_output_tokens {
+            let max_tokens = if reasoning {
+                max_tokens.saturating_add(REASONING_TOKEN_HEADROOM)
+            } else {
+                max_tokens
+            };
+            chat_options = chat_options.with_max_tokens(max_tokens);
+        }
 
         let response = self
             .client
-            .exec_chat(model, chat_request, chat_options.as_ref())
+            .exec_chat(model, chat_request, Some(&chat_options))
             .await
             .map_err(|error| map_genai_error(&error))?;
 
@@ -113,6 +132,13 @@ fn model_supports_reasoning(provider_id: &str, model_id: &str) -> bool {
         .any(|model| model.model_id == model_id && model.reasoning)
 }
 
+/// OpenAI reasoning models only accept the default temperature and Anthropic
+/// extended thinking requires it to stay at 1, so a custom temperature is only
+/// sent to non-reasoning models and to Gemini, which accepts it either way.
+fn accepts_temperature(adapter_kind: AdapterKind, reasoning: bool) -> bool {
+    !reasoning || matches!(adapter_kind, AdapterKind::Gemini)
+}
+
 fn ensure_trailing_slash(base_url: &str) -> String {
     if base_url.ends_with('/') {
         base_url.to_string()
@@ -176,4 +202,12 @@ mod tests {
         assert!(!model_supports_reasoning("openai", "does-not-exist"));
         assert!(!model_supports_reasoning("custom", "anything"));
     }
+
+    #[test]
+    fn temperature_is_dropped_only_for_reasoning_models_that_reject_it() {
+        assert!(accepts_temperature(AdapterKind::OpenAI, false));
+        assert!(!accepts_temperature(AdapterKind::OpenAI, true));
+        assert!(!accepts_temperature(AdapterKind::Anthropic, true));
+        assert!(accepts_temperature(AdapterKind::Gemini, true));
+    }
 }
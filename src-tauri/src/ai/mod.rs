@@ -3,9 +3,11 @@ pub mod contract;
 pub mod error;
 pub mod executor;
 pub mod genai_runtime;
+pub mod model_list;
 pub mod openai_compatible;
 pub mod prompts;
 pub mod providers;
+pub mod response_cleanup;
 
 pub use prompts::EnhancementOptions;
 
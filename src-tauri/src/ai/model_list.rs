@@ -0,0 +1,329 @@
+//! Live model lists from the providers' `/models` endpoints, so the model
+//! picker shows new releases without an app update. Callers fall back to the
+//! embedded catalog when no key is set or the request fails.
+
+use super::catalog;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// How long a fetched model list is reused before asking the provider again.
+pub const MODEL_LIST_CACHE_TTL_SECS: u64 = 60 * 60;
+
+const MODEL_LIST_TIMEOUT: Duration = Duration::from_secs(10);
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// A chat-capable model as reported by the provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListedModel {
+    pub id: String,
+    pub name: String,
+}
+
+/// API base the models endpoint hangs off, for providers that have one.
+pub fn default_models_base_url(provider_id: &str) -> Option<&'static str> {
+    match catalog::adapter_name(provider_id)? {
+        "OpenAI" => Some("https://api.openai.com/v1"),
+        "Anthropic" => Some("https://api.anthropic.com/v1"),
+        "Gemini" => Some("https://generativelanguage.googleapis.com/v1beta"),
+        _ => None,
+    }
+}
+
+#[derive(Deserialize)]
+struct OpenAiModels {
+    data: Vec<OpenAiModel>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiModel {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct AnthropicModels {
+    data: Vec<AnthropicModel>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicModel {
+    id: String,
+    #[serde(default)]
+    display_name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GeminiModels {
+    #[serde(default)]
+    models: Vec<GeminiModel>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiModel {
+    name: String,
+    #[serde(default)]
+    display_name: Option<String>,
+    #[serde(default)]
+    supported_generation_methods: Vec<String>,
+}
+
+/// OpenAI lists every model family under one endpoint; keep the chat ones.
+fn is_openai_chat_model(id: &str) -> bool {
+    const CHAT_PREFIXES: &[&str] = &["gpt-", "chatgpt-", "o1", "o3", "o4"];
+    const NON_CHAT_MARKERS: &[&str] = &[
+        "audio",
+        "realtime",
+        "transcribe",
+        "tts",
+        "image",
+        "search",
+        "embedding",
+        "instruct",
+    ];
+    CHAT_PREFIXES.iter().any(|prefix| id.starts_with(prefix))
+        && !NON_CHAT_MARKERS.iter().any(|marker| id.contains(marker))
+}
+
+/// Gemini reports what each model supports; text-only chat needs
+/// `generateContent` and excludes the image, speech and embedding variants.
+fn is_gemini_chat_model(model: &GeminiModel) -> bool {
+    const NON_CHAT_MARKERS: &[&str] = &["embedding", "image", "tts", "aqa", "imagen"];
+    model
+        .supported_generation_methods
+        .iter()
+        .any(|method| method == "generateContent")
+        && !NON_CHAT_MARKERS
+            .iter()
+            .any(|marker| model.name.contains(marker))
+}
+
+async fn get_json<T: for<'de> Deserialize<'de>>(
+    request: reqwest::RequestBuilder,
+) -> Result<T, String> {
+    let response = request
+        .timeout(MODEL_LIST_TIMEOUT)
+        .send()
+        .await
+        .map_err(|e| format!("Model list request failed: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("Model list request returned {}", status));
+    }
+    response
+        .json::<T>()
+        .await
+        .map_err(|e| format!("Failed to parse model list: {}", e))
+}
+
+/// Fetch the chat-capable models `provider_id` offers, in the provider's order.
+pub async fn fetch_chat_models(
+    client: &reqwest::Client,
+    provider_id: &str,
+    base_url: &str,
+    api_key: &str,
+) -> Result<Vec<ListedModel>, String> {
+    let base_url = base_url.trim_end_matches('/');
+    let models: Vec<ListedModel> = match catalog::adapter_name(provider_id) {
+        Some("OpenAI") => {
+            let listed: OpenAiModels = get_json(
+                client
+                    .get(format!("{}/models", base_url))
+                    .bearer_auth(api_key),
+            )
+            .await?;
+            listed
+                .data
+                .into_iter()
+                .filter(|model| is_openai_chat_model(&model.id))
+                .map(|model| ListedModel {
+                    name: model.id.clone(),
+                    id: model.id,
+                })
+                .collect()
+        }
+        Some("Anthropic") => {
+            let listed: AnthropicModels = get_json(
+                client
+                    .get(format!("{}/models?limit=1000", base_url))
+                    .header("x-api-key", api_key)
+                    .header("anthropic-version", ANTHROPIC_VERSION),
+            )
+            .await?;
+            listed
+                .data
+                .into_iter()
+                .map(|model| ListedModel {
+                    name: model.display_name.unwrap_or_else(|| model.id.clone()),
+                    id: model.id,
+                })
+                .collect()
+        }
+        Some("Gemini") => {
+            let listed: GeminiModels = get_json(
+                client
+                    .get(format!("{}/models?pageSize=1000", base_url))
+                    .header("x-goog-api-key", api_key),
+            )
+            .await?;
+            listed
+                .models
+                .into_iter()
+                .filter(is_gemini_chat_model)
+                .map(|model| {
+                    let id = model
+                        .name
+                        .strip_prefix("models/")
+                        .unwrap_or(&model.name)
+                        .to_string();
+                    ListedModel {
+                        name: model.display_name.unwrap_or_else(|| id.clone()),
+                        id,
+                    }
+                })
+                .collect()
+        }
+        _ => {
+            return Err(format!(
+                "Model listing is not supported for provider: {}",
+                provider_id
+            ))
+        }
+    };
+
+    if models.is_empty() {
+        return Err(format!("{} returned no chat models", provider_id));
+    }
+    Ok(models)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn ids(models: &[ListedModel]) -> Vec<&str> {
+        models.iter().map(|model| model.id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn openai_listing_keeps_chat_models_only() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .and(header("authorization", "Bearer sk-test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [
+                    { "id": "gpt-5-mini" },
+                    { "id": "text-embedding-3-small" },
+                    { "id": "gpt-4o-transcribe" },
+                    { "id": "o4-mini" },
+                    { "id": "dall-e-3" },
+                    { "id": "gpt-4o-realtime-preview" },
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let models = fetch_chat_models(&reqwest::Client::new(), "openai", &server.uri(), "sk-test")
+            .await
+            .unwrap();
+        assert_eq!(ids(&models), vec!["gpt-5-mini", "o4-mini"]);
+    }
+
+    #[tokio::test]
+    async fn gemini_listing_requires_generate_content_and_strips_prefix() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .and(query_param("pageSize", "1000"))
+            .and(header("x-goog-api-key", "g-test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "models": [
+                    {
+                        "name": "models/gemini-2.5-flash",
+                        "displayName": "Gemini 2.5 Flash",
+                        "supportedGenerationMethods": ["generateContent", "countTokens"]
+                    },
+                    {
+                        "name": "models/gemini-embedding-001",
+                        "supportedGenerationMethods": ["embedContent"]
+                    },
+                    {
+                        "name": "models/gemini-2.5-flash-preview-tts",
+                        "supportedGenerationMethods": ["generateContent"]
+                    }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let models = fetch_chat_models(&reqwest::Client::new(), "gemini", &server.uri(), "g-test")
+            .await
+            .unwrap();
+        assert_eq!(
+            models,
+            vec![ListedModel {
+                id: "gemini-2.5-flash".to_string(),
+                name: "Gemini 2.5 Flash".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn anthropic_listing_uses_display_names() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .and(header("x-api-key", "a-test"))
+            .and(header("anthropic-version", ANTHROPIC_VERSION))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{ "id": "claude-haiku-4-5", "display_name": "Claude Haiku 4.5" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let models = fetch_chat_models(
+            &reqwest::Client::new(),
+            "anthropic",
+            &server.uri(),
+            "a-test",
+        )
+        .await
+        .unwrap();
+        assert_eq!(models[0].name, "Claude Haiku 4.5");
+    }
+
+    #[tokio::test]
+    async fn failed_or_empty_listing_is_an_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+        assert!(
+            fetch_chat_models(&reqwest::Client::new(), "openai", &server.uri(), "bad")
+                .await
+                .is_err()
+        );
+
+        let empty = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": [] })))
+            .mount(&empty)
+            .await;
+        assert!(
+            fetch_chat_models(&reqwest::Client::new(), "openai", &empty.uri(), "sk")
+                .await
+                .is_err()
+        );
+        assert!(
+            fetch_chat_models(&reqwest::Client::new(), "custom", &empty.uri(), "sk")
+                .await
+                .is_err()
+        );
+    }
+}
@@ -30,7 +30,7 @@ impl OpenAiCompatibleRuntime {
 
     pub async fn polish(&self, request: &AiPolishRequest) -> Result<String, MappedAiProviderError> {
         let url = format!("{}/chat/completions", self.base_url.trim_end_matches('/'));
-        let payload = json!({
+        let mut payload = json!({
             "model": request.model_id,
             "messages": [
                 { "role": "system", "content": request.prompt },
@@ -38,6 +38,12 @@ impl OpenAiCompatibleRuntime {
             ],
             "stream": false
         });
+        if let Some(temperature) = request.temperature {
+            payload["temperature"] = json!(temperature);
+        }
+        if let Some(max_tokens) = request.max_output_tokens {
+            payload["max_tokens"] = json!(max_tokens);
+        }
         let mut builder = self.client.post(url).json(&payload);
         if !self.no_auth {
             let key = (self.key_resolver)(PROVIDER_CUSTOM)
@@ -1,6 +1,13 @@
 use super::contract::{AiModel, AiProvider};
 
 pub const PROVIDER_CUSTOM: &str = "custom";
+pub const PROVIDER_OLLAMA: &str = "ollama";
+
+/// Providers served by the OpenAI-compatible runtime at a user-configured base URL
+/// instead of a native genai adapter.
+pub fn is_openai_compatible(provider_id: &str) -> bool {
+    provider_id == PROVIDER_CUSTOM || provider_id == PROVIDER_OLLAMA
+}
 
 pub fn launch_providers() -> Vec<AiProvider> {
     crate::ai::catalog::launch_providers()
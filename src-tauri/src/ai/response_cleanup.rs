@@ -0,0 +1,145 @@
+//! Deterministic cleanup of AI formatting responses.
+//!
+//! Prompts ask providers to return only the rewritten text, but some models
+//! still wrap it ("Here is the corrected text:", code fences, quotes, a
+//! closing "Let me know if..."). This strips those wrappers before insertion.
+//! Anything the user actually dictated is left alone: a wrapper is only
+//! removed when the original input did not contain it.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+static RE_PREAMBLE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^(?:(?:sure|okay|ok|certainly|of course|absolutely)[,!.]?\s+)?(?:here(?:'s|’s| is| are)\b[^\n:]{0,80}|(?:the\s+|your\s+)?(?:corrected|cleaned(?:[- ]up)?|enhanced|formatted|polished|improved|edited|revised|rewritten|fixed)\s+(?:text|version|transcription|transcript|message)\b[^\n:]{0,40}|output|result)\s*:[ \t]*\n?",
+    )
+    .expect("valid preamble regex")
+});
+static RE_POSTAMBLE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\n\s*(?:let me know\b|i hope this helps\b|hope this helps\b|feel free to\b|is there anything else\b)[^\n]*\s*$",
+    )
+    .expect("valid postamble regex")
+});
+static RE_FENCE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)^```[\w+#.-]*[ \t]*\n(.*?)\n?```$").expect("valid fence regex")
+});
+
+const QUOTE_PAIRS: &[(char, char)] = &[('"', '"'), ('“', '”'), ('\'', '\''), ('«', '»')];
+
+/// Remove provider preamble, closing chatter, a whole-response code fence, and
+/// surrounding quotes. Returns the trimmed `output` unchanged if cleanup would
+/// leave nothing.
+pub fn strip_response_wrappers(output: &str, input: &str) -> String {
+    let input = input.trim();
+    let input_lower = input.to_lowercase();
+    let mut text = output.trim();
+
+    if let Some(found) = RE_PREAMBLE.find(text) {
+        let phrase = found.as_str().trim().trim_end_matches(':').to_lowercase();
+        if !input_lower.contains(&phrase) {
+            text = text[found.end()..].trim_start();
+        }
+    }
+
+    if let Some(found) = RE_POSTAMBLE.find(text) {
+        let phrase = found.as_str().trim().to_lowercase();
+        if !input_lower.contains(&phrase) {
+            text = text[..found.start()].trim_end();
+        }
+    }
+
+    if !input.starts_with("```") {
+        if let Some(inner) = RE_FENCE.captures(text).and_then(|caps| caps.get(1)) {
+            text = inner.as_str().trim();
+        }
+    }
+
+    for &(open, close) in QUOTE_PAIRS {
+        if input.starts_with(open) {
+            continue;
+        }
+        if let Some(inner) = text
+            .strip_prefix(open)
+            .and_then(|rest| rest.strip_suffix(close))
+        {
+            if !inner.contains(open) && !inner.contains(close) {
+                text = inner.trim();
+                break;
+            }
+        }
+    }
+
+    if text.is_empty() {
+        output.trim().to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_response_wrappers;
+
+    #[test]
+    fn strips_common_preambles() {
+        let input = "um so the meeting is moved to friday";
+        for output in [
+            "Here is the corrected text:\nThe meeting is moved to Friday.",
+            "Here's the cleaned-up version: The meeting is moved to Friday.",
+            "Sure! Here is your text:\n\nThe meeting is moved to Friday.",
+            "Corrected text: The meeting is moved to Friday.",
+            "Output:\nThe meeting is moved to Friday.",
+        ] {
+            assert_eq!(
+                strip_response_wrappers(output, input),
+                "The meeting is moved to Friday.",
+                "{output}"
+            );
+        }
+    }
+
+    #[test]
+    fn strips_postamble_fences_and_quotes() {
+        let input = "ship it";
+        assert_eq!(
+            strip_response_wrappers("Ship it.\n\nLet me know if you need anything else!", input),
+            "Ship it."
+        );
+        assert_eq!(
+            strip_response_wrappers("```text\nShip it.\n```", input),
+            "Ship it."
+        );
+        assert_eq!(
+            strip_response_wrappers("Here is the text:\n```\nShip it.\n```", input),
+            "Ship it."
+        );
+        assert_eq!(strip_response_wrappers("\"Ship it.\"", input), "Ship it.");
+        assert_eq!(strip_response_wrappers("“Ship it.”", input), "Ship it.");
+    }
+
+    #[test]
+    fn keeps_wrappers_the_user_dictated() {
+        assert_eq!(
+            strip_response_wrappers(
+                "Here is the plan: we ship Friday.",
+                "here is the plan we ship friday"
+            ),
+            "Here is the plan: we ship Friday."
+        );
+        assert_eq!(
+            strip_response_wrappers("\"Quote me.\"", "\"quote me\""),
+            "\"Quote me.\""
+        );
+        assert_eq!(
+            strip_response_wrappers("\"Yes,\" she said, \"now.\"", "yes she said now"),
+            "\"Yes,\" she said, \"now.\""
+        );
+    }
+
+    #[test]
+    fn never_returns_empty_text() {
+        assert_eq!(strip_response_wrappers("Output:", "hello"), "Output:");
+        assert_eq!(strip_response_wrappers("\"\"", "nothing"), "\"\"");
+    }
+}
@@ -4,7 +4,7 @@ mod tests {
     use super::super::error::AiProviderError;
     use super::super::executor::AiExecutor;
     use super::super::genai_runtime::AiKeyResolver;
-    use super::super::providers::PROVIDER_CUSTOM;
+    use super::super::providers::{is_openai_compatible, PROVIDER_CUSTOM, PROVIDER_OLLAMA};
     use reqwest::header::AUTHORIZATION;
     use serde_json::json;
     use std::collections::HashMap;
@@ -340,6 +340,56 @@ mod tests {
         assert!(started.elapsed() < Duration::from_millis(300));
     }
 
+    #[tokio::test]
+    async fn ai_runtime_backs_off_before_second_rate_limit_retry() {
+        let case = ProviderCase {
+            id: PROVIDER_CUSTOM,
+            model: "custom-model",
+        };
+        let server = MockServer::start().await;
+        mount_sequence(
+            &server,
+            case.id,
+            vec![
+                error_response(429, "rate limited"),
+                error_response(429, "rate limited"),
+                ok_response(case.id, "polished"),
+            ],
+        )
+        .await;
+        let executor = executor_for(case, &server, true, false);
+        let started = Instant::now();
+
+        let result = executor
+            .polish(request(case, 2_000), CancellationToken::new())
+            .await
+            .unwrap();
+
+        let elapsed = started.elapsed();
+        assert_eq!(result.output_text, "polished");
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+        assert!(elapsed >= Duration::from_millis(250), "elapsed={elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn ai_runtime_gives_up_after_three_rate_limited_attempts() {
+        let case = ProviderCase {
+            id: PROVIDER_CUSTOM,
+            model: "custom-model",
+        };
+        let server = MockServer::start().await;
+        mount_sequence(&server, case.id, vec![error_response(429, "rate limited")]).await;
+        let executor = executor_for(case, &server, true, false);
+
+        let error = executor
+            .polish(request(case, 2_000), CancellationToken::new())
+            .await
+            .unwrap_err();
+
+        assert_eq!(error, AiProviderError::RateLimited);
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
     #[tokio::test]
     async fn ai_runtime_retries_after_http_date_retry_after_within_budget() {
         let case = ProviderCase {
@@ -465,6 +515,109 @@ mod tests {
         assert_eq!(server.received_requests().await.unwrap().len(), 1);
     }
 
+    #[tokio::test]
+    async fn ai_runtime_ollama_uses_openai_compatible_endpoint_without_auth() {
+        let case = ProviderCase {
+            id: PROVIDER_OLLAMA,
+            model: "llama3.1",
+        };
+        let server = MockServer::start().await;
+        mount_sequence(&server, case.id, vec![ok_response(case.id, "polished")]).await;
+        let executor = executor_for(case, &server, false, true);
+
+        let result = executor
+            .polish(request(case, 1_000), CancellationToken::new())
+            .await
+            .unwrap();
+
+        assert_eq!(result.output_text, "polished");
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].headers.get(AUTHORIZATION).is_none());
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["model"], "llama3.1");
+    }
+
+    #[tokio::test]
+    async fn ai_runtime_ollama_not_running_is_a_network_error() {
+        let case = ProviderCase {
+            id: PROVIDER_OLLAMA,
+            model: "llama3.1",
+        };
+        let server = MockServer::start().await;
+        let base_url = server.uri();
+        drop(server);
+        let executor = AiExecutor::new(reqwest::Client::new(), Arc::new(|_| None), base_url, true);
+
+        let error = executor
+            .polish(request(case, 1_000), CancellationToken::new())
+            .await
+            .unwrap_err();
+
+        assert_eq!(error, AiProviderError::Network);
+    }
+
+    #[tokio::test]
+    async fn ai_runtime_sends_sampling_values_to_gemini_and_openai_compatible() {
+        let cases = [
+            ProviderCase {
+                id: PROVIDER_GEMINI,
+                model: "gemini-2.0-flash",
+            },
+            ProviderCase {
+                id: PROVIDER_CUSTOM,
+                model: "custom-model",
+            },
+        ];
+        for case in cases {
+            let server = MockServer::start().await;
+            mount_sequence(&server, case.id, vec![ok_response(case.id, "polished")]).await;
+            let executor = executor_for(case, &server, true, false);
+            let request = AiPolishRequest {
+                temperature: Some(0.2),
+                max_output_tokens: Some(512),
+                ..request(case, 1_000)
+            };
+
+            executor
+                .polish(request, CancellationToken::new())
+                .await
+                .unwrap();
+
+            let requests = server.received_requests().await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+            if case.id == PROVIDER_GEMINI {
+                assert_eq!(body["generationConfig"]["temperature"], json!(0.2));
+                assert_eq!(body["generationConfig"]["maxOutputTokens"], json!(512));
+            } else {
+                assert_eq!(body["temperature"], json!(0.2));
+                assert_eq!(body["max_tokens"], json!(512));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn ai_runtime_reasoning_openai_model_ignores_temperature() {
+        let case = PROVIDERS[0];
+        let server = MockServer::start().await;
+        mount_sequence(&server, case.id, vec![ok_response(case.id, "polished")]).await;
+        let executor = executor_for(case, &server, true, false);
+        let request = AiPolishRequest {
+            temperature: Some(0.2),
+            ..request(case, 1_000)
+        };
+
+        let result = executor
+            .polish(request, CancellationToken::new())
+            .await
+            .unwrap();
+
+        assert_eq!(result.output_text, "polished");
+        let requests = server.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert!(body.get("temperature").is_none());
+    }
+
     async fn mount_sequence(
         server: &MockServer,
         provider_id: &str,
@@ -499,7 +652,7 @@ mod tests {
             }
         });
         let mut overrides = HashMap::new();
-        if case.id != PROVIDER_CUSTOM {
+        if !is_openai_compatible(case.id) {
             overrides.insert(case.id.to_string(), server.uri());
         }
         AiExecutor::with_native_endpoint_overrides(
@@ -518,6 +671,8 @@ mod tests {
             input_text: "raw transcript".to_string(),
             prompt: "polish the transcript".to_string(),
             timeout_ms,
+            temperature: None,
+            max_output_tokens: None,
         }
     }
 
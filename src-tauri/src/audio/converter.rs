@@ -8,10 +8,116 @@ use symphonia::core::io::MediaSourceStream;
 use symphonia::core::meta::MetadataOptions;
 use symphonia::core::probe::Hint;
 
+/// Value of the `channel_select` setting when unset.
+pub const DEFAULT_CHANNEL_SELECT: &str = "mix";
+
+/// Which input channel becomes the mono signal when downmixing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChannelSelect {
+    /// Average every channel.
+    #[default]
+    Mix,
+    /// Keep only the first channel.
+    Left,
+    /// Keep only the second channel.
+    Right,
+}
+
+impl ChannelSelect {
+    /// Parse the `channel_select` setting; unknown values mean `Mix`.
+    pub fn parse(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "left" => Self::Left,
+            "right" => Self::Right,
+            _ => Self::Mix,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Mix => "mix",
+            Self::Left => "left",
+            Self::Right => "right",
+        }
+    }
+}
+
+/// Downmix interleaved `samples` to mono. `Left`/`Right` copy that channel
+/// straight through; `Right` on a mono source keeps the only channel.
+pub fn downmix_to_mono(samples: &[i16], channels: usize, select: ChannelSelect) -> Vec<i16> {
+    if channels <= 1 {
+        return samples.to_vec();
+    }
+    let frames = samples.chunks_exact(channels);
+    match select {
+        ChannelSelect::Mix => frames
+            .map(|frame| {
+                let sum: i32 = frame.iter().map(|&s| s as i32).sum();
+                (sum / channels as i32) as i16
+            })
+            .collect(),
+        ChannelSelect::Left => frames.map(|frame| frame[0]).collect(),
+        ChannelSelect::Right => frames.map(|frame| frame[1]).collect(),
+    }
+}
+
+/// Rewrite a multi-channel 16-bit PCM WAV in place as mono using `select`.
+/// Mono files are left untouched.
+pub fn downmix_wav(path: &Path, select: ChannelSelect) -> Result<(), String> {
+    let mut reader =
+        hound::WavReader::open(path).map_err(|e| format!("Failed to open WAV: {}", e))?;
+    let spec = reader.spec();
+    if spec.channels <= 1 {
+        return Ok(());
+    }
+    if spec.bits_per_sample != 16 || spec.sample_format != hound::SampleFormat::Int {
+        return Err("Downmixing expects a 16-bit PCM WAV".to_string());
+    }
+    let samples = reader
+        .samples::<i16>()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read samples: {}", e))?;
+    drop(reader);
+
+    let mono = downmix_to_mono(&samples, spec.channels as usize, select);
+    let mut writer = hound::WavWriter::create(
+        path,
+        hound::WavSpec {
+            channels: 1,
+            ..spec
+        },
+    )
+    .map_err(|e| format!("Failed to write WAV: {}", e))?;
+    for sample in mono {
+        writer
+            .write_sample(sample)
+            .map_err(|e| format!("Failed to write WAV: {}", e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to write WAV: {}", e))
+}
+
+/// Interleave a decoded buffer's channel planes into frames.
+fn interleave<T: Copy>(planes: &[&[T]], to_i16: impl Fn(T) -> i16) -> Vec<i16> {
+    let frames = planes.iter().map(|plane| plane.len()).min().unwrap_or(0);
+    let mut samples = Vec::with_capacity(frames * planes.len());
+    for frame in 0..frames {
+        for plane in planes {
+            samples.push(to_i16(plane[frame]));
+        }
+    }
+    samples
+}
+
 /// Convert any audio file to WAV format (16kHz, mono, 16-bit PCM)
 /// Returns the path to the converted WAV file
 #[allow(dead_code)]
-pub fn convert_to_wav(input_path: &Path, output_dir: &Path) -> Result<PathBuf, String> {
+pub fn convert_to_wav(
+    input_path: &Path,
+    output_dir: &Path,
+    channel_select: ChannelSelect,
+) -> Result<PathBuf, String> {
     // Check if input is already a WAV file
     if input_path
         .extension()
@@ -93,42 +199,18 @@ pub fn convert_to_wav(input_path: &Path, output_dir: &Path) -> Result<PathBuf, S
 
         match decoder.decode(&packet) {
             Ok(decoded) => {
-                // Convert to i16 samples
+                // Convert to interleaved i16 samples
                 let samples_i16 = match decoded {
                     AudioBufferRef::S32(buf) => {
-                        let mut samples = Vec::new();
-                        for plane in buf.planes().planes() {
-                            for &sample in plane.iter() {
-                                samples.push((sample >> 16) as i16);
-                            }
-                        }
-                        samples
-                    }
-                    AudioBufferRef::S16(buf) => {
-                        let mut samples = Vec::new();
-                        for plane in buf.planes().planes() {
-                            samples.extend_from_slice(plane);
-                        }
-                        samples
-                    }
-                    AudioBufferRef::F32(buf) => {
-                        let mut samples = Vec::new();
-                        for plane in buf.planes().planes() {
-                            for &sample in plane.iter() {
-                                samples.push((sample.clamp(-1.0, 1.0) * 32767.0) as i16);
-                            }
-                        }
-                        samples
-                    }
-                    AudioBufferRef::F64(buf) => {
-                        let mut samples = Vec::new();
-                        for plane in buf.planes().planes() {
-                            for &sample in plane.iter() {
-                                samples.push((sample.clamp(-1.0, 1.0) * 32767.0) as i16);
-                            }
-                        }
-                        samples
+                        interleave(buf.planes().planes(), |sample| (sample >> 16) as i16)
                     }
+                    AudioBufferRef::S16(buf) => interleave(buf.planes().planes(), |sample| sample),
+                    AudioBufferRef::F32(buf) => interleave(buf.planes().planes(), |sample| {
+                        (sample.clamp(-1.0, 1.0) * 32767.0) as i16
+                    }),
+                    AudioBufferRef::F64(buf) => interleave(buf.planes().planes(), |sample| {
+                        (sample.clamp(-1.0, 1.0) * 32767.0) as i16
+                    }),
                     _ => continue,
                 };
                 all_samples.extend(samples_i16);
@@ -139,18 +221,7 @@ pub fn convert_to_wav(input_path: &Path, output_dir: &Path) -> Result<PathBuf, S
     }
 
     // Convert to mono if needed
-    let mono_samples = if channels > 1 {
-        // Average channels to create mono
-        all_samples
-            .chunks(channels)
-            .map(|chunk| {
-                let sum: i32 = chunk.iter().map(|&s| s as i32).sum();
-                (sum / channels as i32) as i16
-            })
-            .collect()
-    } else {
-        all_samples
-    };
+    let mono_samples = downmix_to_mono(&all_samples, channels, channel_select);
 
     // Resample to 16kHz if needed
     let final_samples = if sample_rate != 16000 {
@@ -21,7 +21,7 @@ mod tests {
         writer.finalize().unwrap();
 
         // Should return the same path without conversion
-        let result = convert_to_wav(&wav_path, temp_dir.path()).unwrap();
+        let result = convert_to_wav(&wav_path, temp_dir.path(), ChannelSelect::Mix).unwrap();
         assert_eq!(result, wav_path);
     }
 
@@ -31,7 +31,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let fake_path = temp_dir.path().join("this/does/not/exist.mp3");
 
-        let result = convert_to_wav(&fake_path, temp_dir.path());
+        let result = convert_to_wav(&fake_path, temp_dir.path(), ChannelSelect::Mix);
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Failed to open audio file"));
     }
@@ -43,9 +43,72 @@ mod tests {
         let text_file = temp_dir.path().join("not_audio.txt");
         fs::write(&text_file, b"This is text, not audio").unwrap();
 
-        let result = convert_to_wav(&text_file, temp_dir.path());
+        let result = convert_to_wav(&text_file, temp_dir.path(), ChannelSelect::Mix);
         assert!(result.is_err());
         // Should fail at format probing, not file opening
         assert!(!result.unwrap_err().contains("Failed to open audio file"));
     }
+
+    /// Interleaved stereo with a distinct signal on each side: voice-like
+    /// ramp on the left, constant noise floor on the right.
+    fn distinct_stereo(frames: usize) -> (Vec<i16>, Vec<i16>, Vec<i16>) {
+        let left: Vec<i16> = (0..frames).map(|i| (i as i16 + 1) * 1000).collect();
+        let right = vec![-200i16; frames];
+        let interleaved = left
+            .iter()
+            .zip(&right)
+            .flat_map(|(&l, &r)| [l, r])
+            .collect();
+        (interleaved, left, right)
+    }
+
+    #[test]
+    fn test_downmix_keeps_selected_channel() {
+        let (stereo, left, right) = distinct_stereo(8);
+
+        assert_eq!(downmix_to_mono(&stereo, 2, ChannelSelect::Left), left);
+        assert_eq!(downmix_to_mono(&stereo, 2, ChannelSelect::Right), right);
+        let mixed: Vec<i16> = left
+            .iter()
+            .zip(&right)
+            .map(|(&l, &r)| ((l as i32 + r as i32) / 2) as i16)
+            .collect();
+        assert_eq!(downmix_to_mono(&stereo, 2, ChannelSelect::Mix), mixed);
+        // Mono input passes through whatever the selection.
+        assert_eq!(downmix_to_mono(&left, 1, ChannelSelect::Right), left);
+    }
+
+    #[test]
+    fn test_downmix_wav_rewrites_stereo_file_as_selected_mono_channel() {
+        let temp_dir = TempDir::new().unwrap();
+        let wav_path = temp_dir.path().join("stereo.wav");
+        let (stereo, left, _) = distinct_stereo(16);
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&wav_path, spec).unwrap();
+        for sample in &stereo {
+            writer.write_sample(*sample).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        downmix_wav(&wav_path, ChannelSelect::Left).unwrap();
+
+        let mut reader = hound::WavReader::open(&wav_path).unwrap();
+        assert_eq!(reader.spec().channels, 1);
+        assert_eq!(reader.spec().sample_rate, 48000);
+        let samples: Vec<i16> = reader.samples::<i16>().map(Result::unwrap).collect();
+        assert_eq!(samples, left);
+    }
+
+    #[test]
+    fn test_channel_select_parses_setting_values() {
+        assert_eq!(ChannelSelect::parse("left"), ChannelSelect::Left);
+        assert_eq!(ChannelSelect::parse(" RIGHT "), ChannelSelect::Right);
+        assert_eq!(ChannelSelect::parse("mix"), ChannelSelect::Mix);
+        assert_eq!(ChannelSelect::parse("both"), ChannelSelect::Mix);
+    }
 }
@@ -0,0 +1,207 @@
+//! Spectral-subtraction noise suppression for steady background noise (fans,
+//! hum, keyboard hiss). The noise spectrum is estimated from the opening
+//! [`NOISE_ESTIMATE_MS`] of the clip, which is usually the pause before the
+//! first word, and subtracted from every frame.
+
+use std::path::Path;
+
+use realfft::RealFftPlanner;
+
+/// Opening stretch of audio assumed to hold only background noise.
+pub const NOISE_ESTIMATE_MS: u32 = 300;
+
+/// FFT frame length and hop (50% overlap).
+const FRAME_LEN: usize = 512;
+const HOP_LEN: usize = FRAME_LEN / 2;
+/// How many times the noise power is subtracted; above 1 to leave less
+/// residual "musical" noise behind.
+const OVERSUBTRACTION: f32 = 2.0;
+/// Smallest gain a bin can get, so speech buried in noise is dimmed rather
+/// than cut out.
+const SPECTRAL_FLOOR: f32 = 0.05;
+
+/// Suppress stationary noise in mono `samples`. Returns the input unchanged
+/// when it is too short to estimate the noise from.
+pub fn denoise(samples: &[f32], sample_rate: u32) -> Vec<f32> {
+    let noise_len = (sample_rate as usize * NOISE_ESTIMATE_MS as usize) / 1000;
+    if noise_len < FRAME_LEN || samples.len() < noise_len {
+        return samples.to_vec();
+    }
+
+    let noise_rms =
+        (samples[..noise_len].iter().map(|s| s * s).sum::<f32>() / noise_len as f32).sqrt();
+    log::info!(
+        "[DENOISE] Estimated noise floor: {:.1} dBFS",
+        20.0 * noise_rms.max(1e-9).log10()
+    );
+
+    // sqrt-Hann on analysis and synthesis sums to one at 50% overlap.
+    let window: Vec<f32> = (0..FRAME_LEN)
+        .map(|n| {
+            let hann = 0.5 - 0.5 * (2.0 * std::f32::consts::PI * n as f32 / FRAME_LEN as f32).cos();
+            hann.sqrt()
+        })
+        .collect();
+
+    let mut planner = RealFftPlanner::<f32>::new();
+    let forward = planner.plan_fft_forward(FRAME_LEN);
+    let inverse = planner.plan_fft_inverse(FRAME_LEN);
+    let mut frame = forward.make_input_vec();
+    let mut spectrum = forward.make_output_vec();
+
+    // Pad by one hop on each side so every sample is covered by two frames.
+    let mut padded = vec![0.0; HOP_LEN];
+    padded.extend_from_slice(samples);
+    padded.resize(padded.len() + FRAME_LEN, 0.0);
+    let frame_starts = (0..=padded.len() - FRAME_LEN).step_by(HOP_LEN);
+
+    let mut noise_power = vec![0.0f32; spectrum.len()];
+    let mut noise_frames = 0usize;
+    // Skip the first frame, which is half padding.
+    for start in frame_starts.clone().skip(1) {
+        if start + FRAME_LEN > HOP_LEN + noise_len {
+            break;
+        }
+        for (slot, (sample, w)) in frame
+            .iter_mut()
+            .zip(padded[start..start + FRAME_LEN].iter().zip(&window))
+        {
+            *slot = sample * w;
+        }
+        if forward.process(&mut frame, &mut spectrum).is_err() {
+            return samples.to_vec();
+        }
+        for (power, bin) in noise_power.iter_mut().zip(&spectrum) {
+            *power += bin.norm_sqr();
+        }
+        noise_frames += 1;
+    }
+    if noise_frames == 0 {
+        return samples.to_vec();
+    }
+    for power in &mut noise_power {
+        *power /= noise_frames as f32;
+    }
+
+    let mut output = vec![0.0f32; padded.len()];
+    for start in frame_starts {
+        for (slot, (sample, w)) in frame
+            .iter_mut()
+            .zip(padded[start..start + FRAME_LEN].iter().zip(&window))
+        {
+            *slot = sample * w;
+        }
+        if forward.process(&mut frame, &mut spectrum).is_err() {
+            return samples.to_vec();
+        }
+        for (bin, noise) in spectrum.iter_mut().zip(&noise_power) {
+            let power = bin.norm_sqr();
+            let gain = if power > 0.0 {
+                (1.0 - OVERSUBTRACTION * noise / power)
+                    .max(SPECTRAL_FLOOR * SPECTRAL_FLOOR)
+                    .sqrt()
+            } else {
+                SPECTRAL_FLOOR
+            };
+            *bin *= gain;
+        }
+        if inverse.process(&mut spectrum, &mut frame).is_err() {
+            return samples.to_vec();
+        }
+        for (out, (sample, w)) in output[start..start + FRAME_LEN]
+            .iter_mut()
+            .zip(frame.iter().zip(&window))
+        {
+            *out += sample * w / FRAME_LEN as f32;
+        }
+    }
+
+    output[HOP_LEN..HOP_LEN + samples.len()].to_vec()
+}
+
+/// Denoise a 16-bit mono WAV in place.
+pub fn denoise_wav(path: &Path) -> Result<(), String> {
+    let mut reader =
+        hound::WavReader::open(path).map_err(|e| format!("Failed to open audio: {}", e))?;
+    let spec = reader.spec();
+    if spec.channels != 1
+        || spec.bits_per_sample != 16
+        || spec.sample_format != hound::SampleFormat::Int
+    {
+        return Err("Noise suppression expects 16-bit mono WAV".to_string());
+    }
+    let samples = reader
+        .samples::<i16>()
+        .map(|s| s.map(|s| s as f32 / i16::MAX as f32))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read audio: {}", e))?;
+    drop(reader);
+
+    let cleaned = denoise(&samples, spec.sample_rate);
+
+    let mut writer = hound::WavWriter::create(path, spec)
+        .map_err(|e| format!("Failed to write audio: {}", e))?;
+    for sample in cleaned {
+        writer
+            .write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+            .map_err(|e| format!("Failed to write audio: {}", e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to write audio: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16_000;
+
+    /// Deterministic white noise in [-amplitude, amplitude].
+    fn white_noise(len: usize, amplitude: f32) -> Vec<f32> {
+        let mut state: u32 = 0x1234_5678;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                ((state >> 8) as f32 / (1u32 << 24) as f32 * 2.0 - 1.0) * amplitude
+            })
+            .collect()
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn attenuates_white_noise_on_silent_clip() {
+        let noisy = white_noise(RATE as usize * 2, 0.05);
+        let cleaned = denoise(&noisy, RATE);
+
+        assert_eq!(cleaned.len(), noisy.len());
+        assert!(
+            rms(&cleaned) < rms(&noisy) * 0.5,
+            "noise rms {} -> {}",
+            rms(&noisy),
+            rms(&cleaned)
+        );
+    }
+
+    #[test]
+    fn keeps_a_tone_above_the_noise() {
+        let mut signal = white_noise(RATE as usize * 2, 0.02);
+        let tone_start = RATE as usize / 2;
+        for (i, sample) in signal[tone_start..].iter_mut().enumerate() {
+            *sample += 0.3 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / RATE as f32).sin();
+        }
+        let cleaned = denoise(&signal, RATE);
+
+        let tone_rms = rms(&cleaned[tone_start + 1_000..]);
+        assert!(tone_rms > 0.3 / 2f32.sqrt() * 0.8, "tone rms {tone_rms}");
+    }
+
+    #[test]
+    fn leaves_clips_shorter_than_the_noise_window_alone() {
+        let short = white_noise(1_000, 0.1);
+        assert_eq!(denoise(&short, RATE), short);
+    }
+}
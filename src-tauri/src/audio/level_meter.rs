@@ -1,4 +1,120 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+
+/// Points in the pill's scrolling waveform.
+pub const WAVEFORM_POINTS: usize = 50;
+/// RMS windows per second of audio, so the waveform spans about 1.7s.
+const WAVEFORM_WINDOWS_PER_SECOND: usize = 30;
+
+/// Ring buffer of the most recent RMS windows, filled from the capture
+/// callback and read by the level thread for the `audio-waveform` event.
+#[derive(Debug)]
+pub struct WaveformBuffer {
+    points: VecDeque<f32>,
+    capacity: usize,
+    window_samples: usize,
+    sum_squares: f32,
+    count: usize,
+}
+
+pub type SharedWaveform = Arc<Mutex<WaveformBuffer>>;
+
+impl WaveformBuffer {
+    pub fn new(capacity: usize, sample_rate: u32) -> Self {
+        Self {
+            points: VecDeque::with_capacity(capacity),
+            capacity,
+            window_samples: (sample_rate as usize / WAVEFORM_WINDOWS_PER_SECOND).max(1),
+            sum_squares: 0.0,
+            count: 0,
+        }
+    }
+
+    /// Window length follows the capture rate, which is only known once the
+    /// device is open.
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.window_samples = (sample_rate as usize / WAVEFORM_WINDOWS_PER_SECOND).max(1);
+        self.sum_squares = 0.0;
+        self.count = 0;
+    }
+
+    /// Fold samples into the current window, closing it into a point whenever
+    /// it fills. Never allocates once the ring is full.
+    pub fn push_samples(&mut self, samples: &[f32]) {
+        for &sample in samples {
+            self.sum_squares += sample * sample;
+            self.count += 1;
+            if self.count >= self.window_samples {
+                let rms = (self.sum_squares / self.count as f32).sqrt();
+                if self.points.len() == self.capacity {
+                    self.points.pop_front();
+                }
+                self.points.push_back(rms);
+                self.sum_squares = 0.0;
+                self.count = 0;
+            }
+        }
+    }
+
+    /// Display levels (0-0.95, as for `audio-level`), oldest first, always
+    /// `capacity` long with silence before the first window.
+    pub fn snapshot(&self) -> Vec<f32> {
+        let mut out = vec![0.0; self.capacity - self.points.len()];
+        out.extend(self.points.iter().map(|&rms| map_voice_level(rms) as f32));
+        out
+    }
+}
+
+/// Magnitude counted as full scale. Gain is soft-clipped, so boosted input
+/// saturates just under 1.0 rather than on it.
+const CLIP_LEVEL: f32 = 0.98;
+/// Share of a window's samples at full scale that counts as clipping.
+const CLIP_RATIO_THRESHOLD: f32 = 0.01;
+/// Clip detection windows per second of audio.
+const CLIP_WINDOWS_PER_SECOND: usize = 2;
+
+/// Counts samples at or near full scale over half-second windows and flags
+/// a window where more than 1% of them clipped.
+#[derive(Debug)]
+pub struct ClipDetector {
+    window_samples: usize,
+    clipped: usize,
+    count: usize,
+}
+
+/// Set from the capture callback when a window clips; the level thread
+/// clears it when it reports the warning.
+pub type ClipFlag = Arc<AtomicBool>;
+
+impl ClipDetector {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            window_samples: (sample_rate as usize / CLIP_WINDOWS_PER_SECOND).max(1),
+            clipped: 0,
+            count: 0,
+        }
+    }
+
+    /// Fold samples into the current window. True when a window closed with
+    /// too many clipped samples.
+    pub fn push_samples(&mut self, samples: &[f32]) -> bool {
+        let mut clipping = false;
+        for &sample in samples {
+            if sample.abs() >= CLIP_LEVEL {
+                self.clipped += 1;
+            }
+            self.count += 1;
+            if self.count >= self.window_samples {
+                clipping |= self.clipped as f32 > self.count as f32 * CLIP_RATIO_THRESHOLD;
+                self.clipped = 0;
+                self.count = 0;
+            }
+        }
+        clipping
+    }
+}
 
 /// Simple voice-optimized audio level meter
 /// Maps normal speaking voice to 0.5-0.8 range for better UX
@@ -7,6 +123,8 @@ pub struct AudioLevelMeter {
     smoothed_level: f32,
     sample_count: usize,
     update_interval: usize,
+    waveform: Option<SharedWaveform>,
+    clipping: Option<(ClipDetector, ClipFlag)>,
 }
 
 impl AudioLevelMeter {
@@ -20,9 +138,26 @@ impl AudioLevelMeter {
             smoothed_level: 0.0,
             sample_count: 0,
             update_interval: (sample_rate as usize) / 10, // Update 10 times per second
+            waveform: None,
+            clipping: None,
         })
     }
 
+    /// Also feed `waveform` with this meter's samples.
+    pub fn with_waveform(mut self, waveform: SharedWaveform, sample_rate: u32) -> Self {
+        if let Ok(mut buffer) = waveform.lock() {
+            buffer.set_sample_rate(sample_rate);
+        }
+        self.waveform = Some(waveform);
+        self
+    }
+
+    /// Also raise `flag` whenever the input clips.
+    pub fn with_clip_flag(mut self, flag: ClipFlag, sample_rate: u32) -> Self {
+        self.clipping = Some((ClipDetector::new(sample_rate), flag));
+        self
+    }
+
     /// Process audio samples and send level updates
     pub fn process_samples(&mut self, samples: &[f32]) -> Result<(), String> {
         // Calculate RMS (Root Mean Square) - simple and effective for voice
@@ -35,6 +170,18 @@ impl AudioLevelMeter {
 
         self.sample_count += samples.len();
 
+        if let Some(waveform) = &self.waveform {
+            if let Ok(mut buffer) = waveform.try_lock() {
+                buffer.push_samples(samples);
+            }
+        }
+
+        if let Some((detector, flag)) = &mut self.clipping {
+            if detector.push_samples(samples) {
+                flag.store(true, Ordering::Relaxed);
+            }
+        }
+
         // Send level update at intervals
         if self.sample_count >= self.update_interval {
             self.sample_count = 0;
@@ -53,6 +200,64 @@ impl AudioLevelMeter {
     }
 }
 
+/// Peak and RMS accumulated over a mic calibration sample, linear full scale.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LevelStats {
+    peak: f32,
+    sum_squares: f64,
+    count: u64,
+}
+
+impl LevelStats {
+    pub fn add(&mut self, samples: &[f32]) {
+        for &sample in samples {
+            self.peak = self.peak.max(sample.abs());
+            self.sum_squares += (sample as f64) * (sample as f64);
+        }
+        self.count += samples.len() as u64;
+    }
+
+    pub fn peak(&self) -> f32 {
+        self.peak
+    }
+
+    pub fn rms(&self) -> f32 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        (self.sum_squares / self.count as f64).sqrt() as f32
+    }
+}
+
+/// Linear level to dBFS, floored at -120 so silence stays finite.
+pub fn to_dbfs(level: f32) -> f32 {
+    (20.0 * level.max(1e-6).log10()).max(-120.0)
+}
+
+// Calibration targets: average level of a few seconds of reading aloud, and
+// how close peaks may get to full scale.
+const TARGET_RMS_DBFS: f32 = -26.0;
+const PEAK_CEILING_DBFS: f32 = -3.0;
+// Below this nothing was said, so there is nothing to calibrate against.
+const CALIBRATION_SILENCE_DBFS: f32 = -60.0;
+
+/// `mic_gain_db` that brings a sample captured at `current_gain_db` to the
+/// target level without pushing peaks past the ceiling. Rounded to whole dB
+/// and clamped to the setting's range; a silent sample keeps the current gain.
+pub fn suggest_mic_gain_db(stats: &LevelStats, current_gain_db: f32) -> f32 {
+    use crate::commands::settings::{MAX_MIC_GAIN_DB, MIN_MIC_GAIN_DB};
+
+    let rms_dbfs = to_dbfs(stats.rms());
+    if rms_dbfs < CALIBRATION_SILENCE_DBFS {
+        return current_gain_db;
+    }
+    let headroom = PEAK_CEILING_DBFS - to_dbfs(stats.peak());
+    let adjustment = (TARGET_RMS_DBFS - rms_dbfs).min(headroom);
+    (current_gain_db + adjustment)
+        .round()
+        .clamp(MIN_MIC_GAIN_DB, MAX_MIC_GAIN_DB)
+}
+
 /// Map RMS level to display level optimized for voice
 fn map_voice_level(rms: f32) -> f64 {
     // These thresholds are tuned for typical speaking voice
@@ -104,4 +309,92 @@ mod tests {
         // Very loud
         assert_eq!(map_voice_level(0.2), 0.95);
     }
+
+    #[test]
+    fn waveform_keeps_the_latest_rms_windows_oldest_first() {
+        // 300 Hz capture → 10-sample windows.
+        let mut waveform = WaveformBuffer::new(4, 300);
+        assert_eq!(waveform.snapshot(), vec![0.0; 4]);
+
+        waveform.push_samples(&[0.05; 10]);
+        waveform.push_samples(&[0.01; 5]);
+        let snapshot = waveform.snapshot();
+        assert_eq!(snapshot.len(), 4);
+        assert_eq!(&snapshot[..3], &[0.0; 3]);
+        assert!((snapshot[3] - map_voice_level(0.05) as f32).abs() < 1e-6);
+
+        // Finish the partial window, then overflow the ring.
+        waveform.push_samples(&[0.01; 5]);
+        waveform.push_samples(&[0.2; 30]);
+        let snapshot = waveform.snapshot();
+        assert!((snapshot[0] - map_voice_level(0.01) as f32).abs() < 1e-6);
+        assert_eq!(&snapshot[1..], &[0.95; 3]);
+    }
+
+    #[test]
+    fn clip_detector_fires_on_clipped_input_only() {
+        // 200 Hz capture → 100-sample windows.
+        let mut clean = ClipDetector::new(200);
+        let speech: Vec<f32> = (0..100).map(|i| 0.3 * (i as f32 * 0.2).sin()).collect();
+        assert!(!clean.push_samples(&speech));
+
+        // A single stray peak stays under the 1% threshold.
+        let mut one_peak = speech.clone();
+        one_peak[10] = 1.0;
+        assert!(!clean.push_samples(&one_peak));
+
+        let mut clipped = ClipDetector::new(200);
+        let flattened: Vec<f32> = speech.iter().map(|&s| (s * 5.0).clamp(-1.0, 1.0)).collect();
+        assert!(clipped.push_samples(&flattened));
+    }
+
+    #[test]
+    fn meter_raises_the_clip_flag() {
+        let (tx, _rx) = std::sync::mpsc::channel();
+        let flag = ClipFlag::default();
+        let mut meter = AudioLevelMeter::new(200, 1, tx)
+            .unwrap()
+            .with_clip_flag(flag.clone(), 200);
+
+        meter.process_samples(&[0.2; 100]).unwrap();
+        assert!(!flag.load(Ordering::Relaxed));
+        meter.process_samples(&[0.99; 100]).unwrap();
+        assert!(flag.load(Ordering::Relaxed));
+    }
+
+    fn stats_for(samples: &[f32]) -> LevelStats {
+        let mut stats = LevelStats::default();
+        stats.add(samples);
+        stats
+    }
+
+    #[test]
+    fn level_stats_track_peak_and_rms() {
+        let stats = stats_for(&[0.5, -0.5, 0.5, -0.8]);
+        assert_eq!(stats.peak(), 0.8);
+        assert!((stats.rms() - 0.5895).abs() < 1e-3);
+        assert_eq!(LevelStats::default().rms(), 0.0);
+    }
+
+    #[test]
+    fn quiet_mic_gets_boosted() {
+        // About -40 dBFS RMS with peaks near -34 dBFS, so headroom is no limit.
+        let stats = stats_for(&[0.002, -0.002, 0.02, -0.002]);
+        let rms_dbfs = to_dbfs(stats.rms());
+        let expected = (TARGET_RMS_DBFS - rms_dbfs).round();
+        assert_eq!(suggest_mic_gain_db(&stats, 0.0), expected);
+        assert!(expected > 10.0);
+    }
+
+    #[test]
+    fn clipping_mic_gets_turned_down() {
+        let stats = stats_for(&[1.0, -1.0, 0.9, -0.95]);
+        assert_eq!(suggest_mic_gain_db(&stats, 6.0), 3.0);
+    }
+
+    #[test]
+    fn silent_sample_keeps_current_gain() {
+        let stats = stats_for(&[0.0001; 64]);
+        assert_eq!(suggest_mic_gain_db(&stats, 4.0), 4.0);
+    }
 }
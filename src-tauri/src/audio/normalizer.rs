@@ -15,8 +15,10 @@ const SPEECH_MAX_GAIN: f32 = 32.0;
 const SPEECH_RMS_FLOOR: f32 = crate::audio::silence_detector::VOICE_RMS_THRESHOLD;
 const SILENCE_RMS_THRESHOLD: f32 = 1e-4; // ~ -80 dBFS
 
-/// Normalize any WAV (our recorder output) to the local engine contract:
-/// WAV PCM S16LE, mono, 16 kHz, peak-normalized with speech-gated quiet-clip gain and light dither.
+/// Normalize any WAV (our recorder output, or 8/24/32-bit integer and float
+/// files) to the local engine contract: WAV PCM S16LE, mono, 16 kHz,
+/// peak-normalized with speech-gated quiet-clip gain and light dither. Needs no
+/// ffmpeg, so recording keeps working when the sidecar is missing.
 pub fn normalize_to_whisper_wav(input_wav: &Path, out_dir: &Path) -> Result<PathBuf, String> {
     if !input_wav.exists() {
         return Err(format!("Input WAV does not exist: {:?}", input_wav));
@@ -24,38 +26,19 @@ pub fn normalize_to_whisper_wav(input_wav: &Path, out_dir: &Path) -> Result<Path
 
     fs::create_dir_all(out_dir).map_err(|e| format!("Failed to create out_dir: {}", e))?;
 
-    // Open source wav (expect our recorder: PCM 16-bit interleaved)
+    // Open source wav (usually our recorder: PCM 16-bit interleaved)
     let mut reader =
         WavReader::open(input_wav).map_err(|e| format!("Failed to open WAV: {}", e))?;
     let spec = reader.spec();
 
-    if spec.sample_format != SampleFormat::Int || spec.bits_per_sample != 16 {
-        // For now we handle our own 16-bit files; other formats can be extended later.
-        // Avoid surprising runtime errors by surfacing a clear message.
-        log::warn!(
-            "Normalizer expected 16-bit PCM. Got {:?} {}-bit; proceeding best-effort.",
-            spec.sample_format,
-            spec.bits_per_sample
-        );
-    }
-
     let channels = spec.channels.max(1);
     let sample_rate = spec.sample_rate.max(1);
 
-    // Read samples as i16 → f32 [-1,1]
-    let samples_i16: Vec<i16> = reader
-        .samples::<i16>()
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| format!("Failed to read samples: {}", e))?;
-    if samples_i16.is_empty() {
+    let samples_f32 = read_samples_f32(&mut reader)?;
+    if samples_f32.is_empty() {
         return Err("WAV contains no samples".to_string());
     }
 
-    let samples_f32: Vec<f32> = samples_i16
-        .iter()
-        .map(|&s| s as f32 / i16::MAX as f32)
-        .collect();
-
     // If multi-channel, compute per-channel RMS and ignore near-silent channels.
     let mono: Vec<f32> = if channels == 1 {
         samples_f32
@@ -118,6 +101,50 @@ pub fn normalize_to_whisper_wav(input_wav: &Path, out_dir: &Path) -> Result<Path
     Ok(out_path)
 }
 
+/// Read interleaved samples as f32 in [-1, 1]. Integer PCM is scaled by its
+/// bit depth's maximum (`i16::MAX` for the recorder's 16-bit files).
+fn read_samples_f32<R: std::io::Read>(reader: &mut WavReader<R>) -> Result<Vec<f32>, String> {
+    let spec = reader.spec();
+    match spec.sample_format {
+        SampleFormat::Float => reader.samples::<f32>().collect::<Result<Vec<_>, _>>(),
+        SampleFormat::Int => {
+            let bits = spec.bits_per_sample.clamp(2, 32) as u32;
+            let scale = ((1i64 << (bits - 1)) - 1) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|s| s as f32 / scale))
+                .collect::<Result<Vec<_>, _>>()
+        }
+    }
+    .map_err(|e| format!("Failed to read samples: {}", e))
+}
+
+/// Where soft clipping starts; samples below this level pass through unchanged.
+const SOFT_CLIP_KNEE: f32 = 0.8;
+
+/// Apply `db` of gain to f32 samples in place. Anything pushed above
+/// `SOFT_CLIP_KNEE` is bent smoothly toward full scale instead of being
+/// hard-clipped, so a hot boost saturates rather than crackles. 0 dB is a no-op.
+pub fn apply_gain(samples: &mut [f32], db: f32) {
+    if db == 0.0 || !db.is_finite() {
+        return;
+    }
+    let linear = 10f32.powf(db / 20.0);
+    for sample in samples.iter_mut() {
+        *sample = soft_clip(*sample * linear);
+    }
+}
+
+fn soft_clip(sample: f32) -> f32 {
+    let magnitude = sample.abs();
+    if magnitude <= SOFT_CLIP_KNEE {
+        return sample;
+    }
+    let headroom = 1.0 - SOFT_CLIP_KNEE;
+    let bent = SOFT_CLIP_KNEE + headroom * ((magnitude - SOFT_CLIP_KNEE) / headroom).tanh();
+    bent.copysign(sample)
+}
+
 pub(crate) fn peak_normalization_gain(peak: f32, speech_like: bool) -> f32 {
     if peak <= 0.0 {
         return 1.0;
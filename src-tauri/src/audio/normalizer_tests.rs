@@ -1,4 +1,4 @@
-use super::normalizer::{normalize_to_whisper_wav, peak_normalization_gain};
+use super::normalizer::{apply_gain, normalize_to_whisper_wav, peak_normalization_gain};
 use hound::{SampleFormat, WavSpec, WavWriter};
 use std::f32::consts::PI;
 use std::fs;
@@ -285,6 +285,52 @@ fn normalize_resamples_48k_to_16k() {
     let _ = fs::remove_dir_all(&out_dir);
 }
 
+#[test]
+fn normalize_accepts_float_and_24_bit_wavs() {
+    for (name, bits, format) in [
+        ("float32", 32, SampleFormat::Float),
+        ("int24", 24, SampleFormat::Int),
+    ] {
+        let input = temp_file(&format!("{}_in.wav", name));
+        let out_dir = temp_file(&format!("{}_out_dir", name));
+        let _ = fs::create_dir_all(&out_dir);
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 48_000,
+            bits_per_sample: bits,
+            sample_format: format,
+        };
+        let mut writer = WavWriter::create(&input, spec).expect("create wav");
+        for n in 0..(48_000 * 3 / 10) {
+            let sample = 0.5 * (2.0 * PI * 440.0 * n as f32 / 48_000.0).sin();
+            for _ in 0..2 {
+                match format {
+                    SampleFormat::Float => writer.write_sample(sample),
+                    SampleFormat::Int => writer.write_sample((sample * 8_388_607.0) as i32),
+                }
+                .expect("write sample");
+            }
+        }
+        writer.finalize().expect("finalize wav");
+
+        let out_path = normalize_to_whisper_wav(&input, &out_dir).expect("normalize");
+
+        let reader = hound::WavReader::open(&out_path).expect("open normalized");
+        let out_spec = reader.spec();
+        assert_eq!(out_spec.sample_rate, 16_000, "{}", name);
+        assert_eq!(out_spec.channels, 1, "{}", name);
+        assert_eq!(out_spec.bits_per_sample, 16, "{}", name);
+        let duration = reader.duration() as f32 / 16_000.0;
+        assert!((duration - 0.3).abs() < 0.05, "{}: {}s", name, duration);
+        let peak = read_peak(&out_path);
+        assert!((peak - 0.8).abs() < 0.05, "{}: peak {}", name, peak);
+
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&out_path);
+        let _ = fs::remove_dir_all(&out_dir);
+    }
+}
+
 #[test]
 fn normalize_downmix_ignores_silent_channel() {
     let input = temp_file("downmix_stereo_in.wav");
@@ -377,3 +423,24 @@ fn normalize_very_short_non_empty_wav_outputs_valid_16k_mono_s16() {
     let _ = fs::remove_file(&out_path);
     let _ = fs::remove_dir_all(&out_dir);
 }
+
+#[test]
+fn apply_gain_doubles_at_six_db_and_saturates_when_hot() {
+    let mut samples = vec![0.1f32, -0.2, 0.0];
+    apply_gain(&mut samples, 6.0);
+    assert!((samples[0] - 0.2).abs() < 0.005, "got {}", samples[0]);
+    assert!((samples[1] + 0.4).abs() < 0.01, "got {}", samples[1]);
+    assert_eq!(samples[2], 0.0);
+
+    // +24 dB pushes these far past full scale: they must stay in range and
+    // keep their sign instead of wrapping around.
+    let mut hot = vec![0.5f32, -0.5, 0.9];
+    apply_gain(&mut hot, 24.0);
+    assert!(hot[0] > 0.95 && hot[0] <= 1.0, "got {}", hot[0]);
+    assert!(hot[1] < -0.95 && hot[1] >= -1.0, "got {}", hot[1]);
+    assert!(hot[2] >= hot[0] && hot[2] <= 1.0);
+
+    let mut unchanged = vec![0.95f32];
+    apply_gain(&mut unchanged, 0.0);
+    assert_eq!(unchanged[0], 0.95);
+}
@@ -1,13 +1,17 @@
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::sync::mpsc::{self, RecvTimeoutError, SyncSender, TryRecvError, TrySendError};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
-use super::level_meter::AudioLevelMeter;
-use super::silence_detector::{SilenceDetector, SilenceDetectorEvent};
+use super::converter::ChannelSelect;
+use super::level_meter::{
+    AudioLevelMeter, ClipFlag, LevelStats, SharedWaveform, WaveformBuffer, WAVEFORM_POINTS,
+};
+use super::normalizer::apply_gain;
+use super::silence_detector::{SilenceAutoStop, SilenceDetector, SilenceDetectorEvent};
 
 // Type-safe recording size limits
 pub struct RecordingSize;
@@ -70,6 +74,13 @@ const WRITER_JOIN_TIMEOUT: Duration = Duration::from_secs(3);
 /// an unfinalized WAV that the command layer then deleted.
 const STOP_JOIN_TIMEOUT: Duration = Duration::from_secs(8);
 
+/// How long the recording thread keeps trying to reopen the default input
+/// device after the active one disappears (unplugged headset, Bluetooth
+/// drop). Past this window the recording stops and keeps what was captured.
+const DEVICE_RECONNECT_WINDOW: Duration = Duration::from_secs(2);
+// Pause between reopen attempts inside the reconnect window.
+const DEVICE_RECONNECT_RETRY: Duration = Duration::from_millis(250);
+
 enum WriterMsg {
     Chunk(Vec<i16>),
     Finalize,
@@ -178,10 +189,586 @@ fn chunk_capacity_for(max_frames: usize, channels: usize) -> usize {
     max_frames.saturating_mul(channels).max(CHUNK_CAPACITY_MIN)
 }
 
+/// Sample rate every engine consumes; other rates are resampled after capture.
+pub const TARGET_SAMPLE_RATE: u32 = 16_000;
+
+/// Standard rates reported for a device when its config ranges include them.
+const COMMON_SAMPLE_RATES: [u32; 9] = [
+    8_000, 16_000, 22_050, 24_000, 32_000, 44_100, 48_000, 88_200, 96_000,
+];
+
+/// An input device together with the capture formats it advertises.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputDeviceInfo {
+    pub name: String,
+    pub is_default: bool,
+    pub sample_rates: Vec<u32>,
+    pub channel_counts: Vec<u16>,
+    /// False when capture at 16 kHz is impossible and audio must be resampled.
+    pub supports_target_rate: bool,
+}
+
+/// Collapse `(min, max)` sample-rate ranges into the common rates they cover,
+/// plus each range's own endpoints, sorted and de-duplicated.
+fn sample_rates_in_ranges(ranges: &[(u32, u32)]) -> Vec<u32> {
+    let mut rates: Vec<u32> = COMMON_SAMPLE_RATES
+        .iter()
+        .copied()
+        .filter(|rate| ranges.iter().any(|(min, max)| min <= rate && rate <= max))
+        .chain(ranges.iter().flat_map(|(min, max)| [*min, *max]))
+        .collect();
+    rates.sort_unstable();
+    rates.dedup();
+    rates
+}
+
+/// The rate to open capture at: `preferred` when a range covers it, otherwise
+/// the closest rate any range supports (the higher one on a tie, since
+/// downsampling loses nothing the target rate could carry). `None` when the
+/// device reported no ranges.
+fn choose_capture_rate(preferred: u32, ranges: &[(u32, u32)]) -> Option<u32> {
+    ranges
+        .iter()
+        .map(|(min, max)| preferred.clamp(*min, *max))
+        .min_by_key(|rate| (rate.abs_diff(preferred), std::cmp::Reverse(*rate)))
+}
+
+/// Sample rates chosen for a recording, reported once capture starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureConfig {
+    /// The device's default (native) rate.
+    pub device_sample_rate: u32,
+    /// The rate the stream was opened at.
+    pub capture_sample_rate: u32,
+    /// The rate of the finished WAV; differs from the capture rate when the
+    /// recording is resampled after capture.
+    pub output_sample_rate: u32,
+    pub channels: u16,
+}
+
+fn describe_input_device(
+    device: &cpal::Device,
+    default_name: Option<&str>,
+) -> Option<InputDeviceInfo> {
+    let name = device.name().ok()?;
+    let mut ranges = Vec::new();
+    let mut channel_counts = Vec::new();
+    match device.supported_input_configs() {
+        Ok(configs) => {
+            for config in configs {
+                ranges.push((config.min_sample_rate().0, config.max_sample_rate().0));
+                channel_counts.push(config.channels());
+            }
+        }
+        Err(e) => log::debug!("Failed to query configs for input device '{}': {}", name, e),
+    }
+    channel_counts.sort_unstable();
+    channel_counts.dedup();
+
+    Some(InputDeviceInfo {
+        is_default: default_name == Some(name.as_str()),
+        supports_target_rate: ranges
+            .iter()
+            .any(|(min, max)| *min <= TARGET_SAMPLE_RATE && TARGET_SAMPLE_RATE <= *max),
+        sample_rates: sample_rates_in_ranges(&ranges),
+        channel_counts,
+        name,
+    })
+}
+
+/// A copy of captured audio forwarded to a live consumer such as realtime
+/// STT. Sent from the WAV writer worker, never from the real-time callback.
+pub struct CapturedChunk {
+    pub samples: Vec<i16>,
+    pub sample_rate: u32,
+    pub channels: u16,
+}
+
+/// Input device changes during a recording, reported so the pill can tell
+/// the user their microphone went away and whether capture resumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceEvent {
+    /// The capture stream failed; reopening the default device.
+    Lost,
+    /// Capture continues on the default device, in the same file.
+    Recovered,
+    /// No device came back within [`DEVICE_RECONNECT_WINDOW`]; the
+    /// recording stops with the audio captured so far.
+    RecoveryFailed,
+}
+
+/// Retry schedule while the input device is gone: an attempt every
+/// [`DEVICE_RECONNECT_RETRY`] until `window` has passed since the loss.
+struct DeviceReconnect {
+    lost_at: Instant,
+    window: Duration,
+}
+
+impl DeviceReconnect {
+    fn new(lost_at: Instant, window: Duration) -> Self {
+        Self { lost_at, window }
+    }
+
+    /// How long to wait before the next attempt, or `None` once the window
+    /// has closed.
+    fn next_delay(&self, now: Instant) -> Option<Duration> {
+        let remaining = self
+            .window
+            .checked_sub(now.saturating_duration_since(self.lost_at))?;
+        (!remaining.is_zero()).then_some(remaining.min(DEVICE_RECONNECT_RETRY))
+    }
+}
+
+/// How [`run_capture`] ended.
+struct CaptureEnd<S> {
+    /// The stream still capturing, to drain and release. `None` when the
+    /// device was lost and capture never resumed.
+    stream: Option<S>,
+    reason: Option<RecorderCommand>,
+    /// The device error, when no replacement device opened in time.
+    device_error: Option<String>,
+}
+
+/// Block the recording thread until capture should end. A `DeviceLost` from
+/// the stream's error callback releases the dead stream and retries `reopen`
+/// for up to `window`; the WAV writer is untouched throughout, so a
+/// replacement stream keeps appending to the same file.
+fn run_capture<S>(
+    stream: S,
+    stop_rx: &mpsc::Receiver<RecorderCommand>,
+    window: Duration,
+    mut reopen: impl FnMut() -> Result<S, String>,
+    mut release: impl FnMut(S),
+    mut on_event: impl FnMut(DeviceEvent),
+) -> CaptureEnd<S> {
+    let mut stream = Some(stream);
+    loop {
+        let error = match stop_rx.recv() {
+            Ok(RecorderCommand::DeviceLost(error)) => error,
+            reason => {
+                return CaptureEnd {
+                    stream,
+                    reason: reason.ok(),
+                    device_error: None,
+                }
+            }
+        };
+
+        log::warn!("{}; reopening the default input device", error);
+        on_event(DeviceEvent::Lost);
+        if let Some(dead) = stream.take() {
+            release(dead);
+        }
+
+        let reconnect = DeviceReconnect::new(Instant::now(), window);
+        while stream.is_none() {
+            match reopen() {
+                Ok(replacement) => stream = Some(replacement),
+                Err(e) => log::warn!("Input device not available yet: {}", e),
+            }
+            if stream.is_some() {
+                break;
+            }
+            let Some(delay) = reconnect.next_delay(Instant::now()) else {
+                log::error!(
+                    "No input device within {}ms; stopping with the audio captured so far",
+                    window.as_millis()
+                );
+                on_event(DeviceEvent::RecoveryFailed);
+                return CaptureEnd {
+                    stream: None,
+                    reason: None,
+                    device_error: Some(error),
+                };
+            };
+            match stop_rx.recv_timeout(delay) {
+                Ok(RecorderCommand::DeviceLost(_)) | Err(RecvTimeoutError::Timeout) => {}
+                Ok(reason @ RecorderCommand::Stop) => {
+                    return CaptureEnd {
+                        stream: None,
+                        reason: Some(reason),
+                        device_error: None,
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    return CaptureEnd {
+                        stream: None,
+                        reason: None,
+                        device_error: None,
+                    }
+                }
+            }
+        }
+
+        // Errors queued before the dead stream was released belong to it,
+        // not to the replacement.
+        loop {
+            match stop_rx.try_recv() {
+                Ok(RecorderCommand::DeviceLost(_)) => {}
+                Ok(reason @ RecorderCommand::Stop) => {
+                    return CaptureEnd {
+                        stream,
+                        reason: Some(reason),
+                        device_error: None,
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    return CaptureEnd {
+                        stream,
+                        reason: None,
+                        device_error: None,
+                    }
+                }
+            }
+        }
+
+        log::info!("Input device recovered; recording continues");
+        on_event(DeviceEvent::Recovered);
+    }
+}
+
+/// Audio processing shared by every stream of one recording, so a stream
+/// reopened after a device loss feeds the same writer, meter and detector.
+type SharedProcessor = Arc<Mutex<dyn Fn(&[f32], &[i16]) + Send>>;
+
+/// Build (without starting) an input stream on `device` that converts each
+/// callback to both f32 and i16 and hands them to `process_audio`. A non-zero
+/// `gain_db` is applied before that split, so the level meter, silence
+/// detector and written WAV all see the boosted signal.
+fn build_capture_stream(
+    device: &cpal::Device,
+    config: &cpal::SupportedStreamConfig,
+    chunk_capacity: usize,
+    gain_db: f32,
+    process_audio: SharedProcessor,
+    err_fn: impl FnMut(cpal::StreamError) + Send + 'static,
+) -> Result<cpal::Stream, String> {
+    // Only one stream is alive at a time (a lost stream is released before
+    // its replacement is built), so `try_lock` is uncontended here and, like
+    // the rest of the callback path, never blocks or allocates.
+    let stream = match config.sample_format() {
+        cpal::SampleFormat::F32 => {
+            let mut f32_scratch: Vec<f32> = Vec::with_capacity(chunk_capacity);
+            let mut i16_scratch: Vec<i16> = Vec::with_capacity(chunk_capacity);
+            device.build_input_stream(
+                &config.config(),
+                move |data: &[f32], _: &_| {
+                    let data = if gain_db != 0.0 {
+                        f32_scratch.clear();
+                        f32_scratch.extend_from_slice(data);
+                        apply_gain(&mut f32_scratch, gain_db);
+                        &f32_scratch[..]
+                    } else {
+                        data
+                    };
+
+                    // Convert F32 to I16 with proper clamping to avoid distortion
+                    i16_scratch.clear();
+                    i16_scratch.extend(data.iter().map(|&sample| f32_to_i16(sample)));
+
+                    // Process audio
+                    if let Ok(process) = process_audio.try_lock() {
+                        (*process)(data, &i16_scratch);
+                    }
+                },
+                err_fn,
+                None,
+            )
+        }
+        cpal::SampleFormat::I16 => {
+            let mut f32_scratch: Vec<f32> = Vec::with_capacity(chunk_capacity);
+            let mut i16_scratch: Vec<i16> = Vec::with_capacity(chunk_capacity);
+            device.build_input_stream(
+                &config.config(),
+                move |data: &[i16], _: &_| {
+                    // Convert I16 to F32 for processing
+                    f32_scratch.clear();
+                    f32_scratch.extend(data.iter().map(|&sample| i16_to_f32(sample)));
+
+                    let data = if gain_db != 0.0 {
+                        apply_gain(&mut f32_scratch, gain_db);
+                        i16_scratch.clear();
+                        i16_scratch.extend(f32_scratch.iter().map(|&sample| f32_to_i16(sample)));
+                        &i16_scratch[..]
+                    } else {
+                        data
+                    };
+
+                    // Process audio
+                    if let Ok(process) = process_audio.try_lock() {
+                        (*process)(&f32_scratch, data);
+                    }
+                },
+                err_fn,
+                None,
+            )
+        }
+        cpal::SampleFormat::U16 => {
+            let mut f32_scratch: Vec<f32> = Vec::with_capacity(chunk_capacity);
+            let mut i16_scratch: Vec<i16> = Vec::with_capacity(chunk_capacity);
+            device.build_input_stream(
+                &config.config(),
+                move |data: &[u16], _: &_| {
+                    // Convert U16 to F32 for processing
+                    f32_scratch.clear();
+                    f32_scratch.extend(data.iter().map(|&sample| u16_to_f32(sample)));
+
+                    // Convert U16 to I16 for writing
+                    i16_scratch.clear();
+                    if gain_db != 0.0 {
+                        apply_gain(&mut f32_scratch, gain_db);
+                        i16_scratch.extend(f32_scratch.iter().map(|&sample| f32_to_i16(sample)));
+                    } else {
+                        i16_scratch.extend(data.iter().map(|&sample| u16_to_i16(sample)));
+                    }
+
+                    // Process audio
+                    if let Ok(process) = process_audio.try_lock() {
+                        (*process)(&f32_scratch, &i16_scratch);
+                    }
+                },
+                err_fn,
+                None,
+            )
+        }
+        format => return Err(format!("Unsupported sample format: {:?}", format)),
+    };
+    stream.map_err(|e| e.to_string())
+}
+
+/// The config to reopen capture with on `device` so its audio still fits the
+/// WAV already being written: the default config when it matches, otherwise
+/// any supported format with the same channel count covering `sample_rate`.
+fn matching_input_config(
+    device: &cpal::Device,
+    sample_rate: u32,
+    channels: u16,
+) -> Result<cpal::SupportedStreamConfig, String> {
+    if let Ok(config) = device.default_input_config() {
+        if config.sample_rate().0 == sample_rate && config.channels() == channels {
+            return Ok(config);
+        }
+    }
+    device
+        .supported_input_configs()
+        .map_err(|e| e.to_string())?
+        .find(|range| {
+            range.channels() == channels
+                && range.min_sample_rate().0 <= sample_rate
+                && sample_rate <= range.max_sample_rate().0
+                && matches!(
+                    range.sample_format(),
+                    cpal::SampleFormat::F32 | cpal::SampleFormat::I16 | cpal::SampleFormat::U16
+                )
+        })
+        .map(|range| range.with_sample_rate(cpal::SampleRate(sample_rate)))
+        .ok_or_else(|| {
+            format!(
+                "Input device does not support {} Hz with {} channel(s)",
+                sample_rate, channels
+            )
+        })
+}
+
+/// The input device named `device_name`, or the default input device when it
+/// is `None` or no longer connected.
+fn select_input_device(device_name: Option<String>) -> Result<cpal::Device, String> {
+    let host = cpal::default_host();
+    if let Some(device_name) = device_name {
+        // Try to find the specified device
+        host.input_devices()
+            .map_err(|e| format!("Failed to enumerate input devices: {}", e))?
+            .find(|d| d.name().map(|n| n == device_name).unwrap_or(false))
+            .ok_or_else(|| {
+                log::warn!(
+                    "Specified device '{}' not found, falling back to default",
+                    device_name
+                );
+                format!("Device '{}' not found", device_name)
+            })
+            .or_else(|_| {
+                // Fallback to default device if specified device not found
+                host.default_input_device()
+                    .ok_or("No input device available".to_string())
+            })
+    } else {
+        // Use default device
+        host.default_input_device()
+            .ok_or_else(|| "No input device available".to_string())
+    }
+}
+
+/// Whether the input `device_name` (the default input when `None`) is muted at
+/// the OS level. `None` when the platform doesn't expose it, the query fails,
+/// or the device isn't the system default (the only one the probes cover), so
+/// callers must treat `None` as "go ahead". Blocking; spawns a short-lived process.
+pub fn device_is_muted(device_name: Option<&str>) -> Option<bool> {
+    if let Some(name) = device_name {
+        let default_name = cpal::default_host().default_input_device()?.name().ok()?;
+        if default_name != name {
+            return None;
+        }
+    }
+    system_input_muted()
+}
+
+#[cfg(target_os = "macos")]
+fn system_input_muted() -> Option<bool> {
+    let output = std::process::Command::new("/usr/bin/osascript")
+        .args(["-e", "input volume of (get volume settings)"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_macos_input_volume(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(target_os = "linux")]
+fn system_input_muted() -> Option<bool> {
+    let output = std::process::Command::new("pactl")
+        .args(["get-source-mute", "@DEFAULT_SOURCE@"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_pactl_source_mute(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn system_input_muted() -> Option<bool> {
+    None
+}
+
+/// macOS reports a muted input as volume 0; devices without a volume
+/// control report `missing value`.
+#[cfg_attr(not(any(target_os = "macos", test)), allow(dead_code))]
+fn parse_macos_input_volume(output: &str) -> Option<bool> {
+    output.trim().parse::<u32>().ok().map(|volume| volume == 0)
+}
+
+/// `pactl get-source-mute` prints `Mute: yes` or `Mute: no`.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_pactl_source_mute(output: &str) -> Option<bool> {
+    match output.trim().strip_prefix("Mute:")?.trim() {
+        "yes" => Some(true),
+        "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// Capture `duration` from `device_name` (the default input when `None`) with
+/// `gain_db` applied, feeding a level meter that reports on `audio_level_tx`,
+/// and return the sample's peak and RMS. Nothing is written to disk and no
+/// `AudioRecorder` is involved, so recording state is untouched. Blocking.
+pub fn sample_input_level(
+    device_name: Option<String>,
+    duration: Duration,
+    gain_db: f32,
+    audio_level_tx: mpsc::Sender<f64>,
+) -> Result<LevelStats, String> {
+    let device = select_input_device(device_name)?;
+    let config = device.default_input_config().map_err(|e| e.to_string())?;
+    log::info!(
+        "Sampling input level on '{}' for {}ms",
+        device.name().unwrap_or_else(|_| "Unknown".to_string()),
+        duration.as_millis()
+    );
+
+    let level_meter = Arc::new(Mutex::new(
+        AudioLevelMeter::new(
+            config.sample_rate().0,
+            config.channels() as u32,
+            audio_level_tx,
+        )
+        .map_err(|e| format!("Failed to create level meter: {}", e))?,
+    ));
+    let stats = Arc::new(Mutex::new(LevelStats::default()));
+    let process_audio: SharedProcessor = Arc::new(Mutex::new({
+        let level_meter = level_meter.clone();
+        let stats = stats.clone();
+        move |f32_samples: &[f32], _: &[i16]| {
+            if f32_samples.is_empty() {
+                return;
+            }
+            if let Ok(mut meter) = level_meter.try_lock() {
+                let _ = meter.process_samples(f32_samples);
+            }
+            if let Ok(mut stats) = stats.try_lock() {
+                stats.add(f32_samples);
+            }
+        }
+    }));
+
+    let stream = build_capture_stream(
+        &device,
+        &config,
+        max_callback_samples(&device, &config),
+        gain_db,
+        process_audio,
+        |err| log::warn!("Input level sample stream error: {}", err),
+    )?;
+    stream
+        .play()
+        .map_err(|e| format!("Failed to start audio stream: {}", e))?;
+    thread::sleep(duration);
+    release_stream(stream);
+
+    let stats = *stats
+        .lock()
+        .map_err(|e| format!("Failed to read input levels: {}", e))?;
+    Ok(stats)
+}
+
+/// Drop a capture stream, guarding against the platforms where that can hang.
+fn release_stream(stream: cpal::Stream) {
+    // On Windows, some USB/wireless WASAPI devices can hang during
+    // Stream::drop(). We attempt a clean drop with a timeout guard;
+    // if it hangs beyond 3 seconds, we fall back to mem::forget.
+    #[cfg(target_os = "windows")]
+    {
+        let stream_drop_result = std::sync::mpsc::channel::<()>();
+        let (drop_tx, drop_rx) = stream_drop_result;
+        // Move stream into a thread so drop doesn't block the recording thread
+        std::thread::spawn(move || {
+            drop(stream);
+            let _ = drop_tx.send(());
+        });
+        match drop_rx.recv_timeout(Duration::from_secs(3)) {
+            Ok(()) => log::info!("Audio stream stopped and cleaned up"),
+            Err(_) => log::warn!(
+                "Audio stream drop timed out (3s). Stream resources will be reclaimed on process exit."
+            ),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        drop(stream);
+        log::info!("Audio stream stopped and cleaned up");
+    }
+}
+
 pub struct AudioRecorder {
     recording_handle: Arc<Mutex<Option<RecordingHandle>>>,
     audio_level_receiver: Arc<Mutex<Option<mpsc::Receiver<f64>>>>,
+    waveform: Option<SharedWaveform>,
+    clip_flag: Option<ClipFlag>,
     silence_event_receiver: Arc<Mutex<Option<mpsc::Receiver<SilenceDetectorEvent>>>>,
+    device_event_receiver: Arc<Mutex<Option<mpsc::Receiver<DeviceEvent>>>>,
+    capture_config_receiver: Arc<Mutex<Option<mpsc::Receiver<CaptureConfig>>>>,
+    sample_tap: Option<mpsc::Sender<CapturedChunk>>,
+    silence_auto_stop: Option<SilenceAutoStop>,
+    mic_gain_db: f32,
+    preferred_sample_rate: u32,
+    channel_select: ChannelSelect,
+    /// While set, captured audio is discarded instead of written and the
+    /// silence detector's clocks are held.
+    paused: Arc<AtomicBool>,
 }
 
 impl Drop for AudioRecorder {
@@ -207,6 +794,16 @@ impl Drop for AudioRecorder {
         } else {
             log::error!("Failed to acquire silence event receiver lock during drop");
         }
+        if let Ok(mut receiver_guard) = self.device_event_receiver.lock() {
+            receiver_guard.take();
+        } else {
+            log::error!("Failed to acquire device event receiver lock during drop");
+        }
+        if let Ok(mut receiver_guard) = self.capture_config_receiver.lock() {
+            receiver_guard.take();
+        } else {
+            log::error!("Failed to acquire capture config receiver lock during drop");
+        }
         if let Ok(mut receiver_guard) = self.audio_level_receiver.lock() {
             receiver_guard.take();
         } else {
@@ -223,6 +820,8 @@ struct RecordingHandle {
 #[derive(Debug)]
 enum RecorderCommand {
     Stop,
+    /// Sent by the stream error callback when the input device fails.
+    DeviceLost(String),
 }
 
 impl AudioRecorder {
@@ -230,10 +829,52 @@ impl AudioRecorder {
         Self {
             recording_handle: Arc::new(Mutex::new(None)),
             audio_level_receiver: Arc::new(Mutex::new(None)),
+            waveform: None,
+            clip_flag: None,
             silence_event_receiver: Arc::new(Mutex::new(None)),
+            device_event_receiver: Arc::new(Mutex::new(None)),
+            capture_config_receiver: Arc::new(Mutex::new(None)),
+            sample_tap: None,
+            silence_auto_stop: None,
+            mic_gain_db: 0.0,
+            preferred_sample_rate: TARGET_SAMPLE_RATE,
+            channel_select: ChannelSelect::Mix,
+            paused: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    /// Forward a copy of the next recording's audio to `tap`. Applies to the
+    /// next `start_recording` only; the tap closes when that recording stops.
+    pub fn set_sample_tap(&mut self, tap: Option<mpsc::Sender<CapturedChunk>>) {
+        self.sample_tap = tap;
+    }
+
+    /// Silence auto-stop settings for the next `start_recording` only; `None`
+    /// uses the detector's built-in defaults.
+    pub fn set_silence_auto_stop(&mut self, config: Option<SilenceAutoStop>) {
+        self.silence_auto_stop = config;
+    }
+
+    /// Gain in dB applied to captured samples from the next `start_recording`
+    /// on; 0 records the device level untouched.
+    pub fn set_mic_gain_db(&mut self, gain_db: f32) {
+        self.mic_gain_db = gain_db;
+    }
+
+    /// Sample rate of the WAV written from the next `start_recording` on.
+    /// Capture opens at this rate when the device supports it, otherwise at
+    /// the closest supported rate, and the file is resampled after capture.
+    pub fn set_preferred_sample_rate(&mut self, sample_rate: u32) {
+        self.preferred_sample_rate = sample_rate;
+    }
+
+    /// Channel kept when a multi-channel device is recorded, from the next
+    /// `start_recording` on. `Mix` keeps every channel in the WAV and leaves
+    /// averaging to the transcriber; `Left`/`Right` write a mono file.
+    pub fn set_channel_select(&mut self, channel_select: ChannelSelect) {
+        self.channel_select = channel_select;
+    }
+
     pub fn start_recording(
         &mut self,
         output_path: &str,
@@ -262,46 +903,101 @@ impl AudioRecorder {
         if let Ok(mut guard) = self.silence_event_receiver.lock() {
             guard.take();
         }
+        if let Ok(mut guard) = self.device_event_receiver.lock() {
+            guard.take();
+        }
+        if let Ok(mut guard) = self.capture_config_receiver.lock() {
+            guard.take();
+        }
 
         let output_path = PathBuf::from(output_path);
+        let sample_tap = self.sample_tap.take();
+        let silence_auto_stop = self.silence_auto_stop.take();
+        let mic_gain_db = self.mic_gain_db;
+        let preferred_sample_rate = self.preferred_sample_rate;
+        let channel_select = self.channel_select;
+        self.paused.store(false, Ordering::SeqCst);
+        let paused = self.paused.clone();
         let (stop_tx, stop_rx) = mpsc::channel();
         let stop_tx_clone = stop_tx.clone();
 
         // Create audio level channel (f64 for EBU R128 loudness values)
         let (audio_level_tx, audio_level_rx) = mpsc::channel::<f64>();
+        let waveform: SharedWaveform = Arc::new(Mutex::new(WaveformBuffer::new(
+            WAVEFORM_POINTS,
+            TARGET_SAMPLE_RATE,
+        )));
+        self.waveform = Some(waveform.clone());
+        let clip_flag = ClipFlag::default();
+        self.clip_flag = Some(clip_flag.clone());
         let (silence_event_tx, silence_event_rx) = mpsc::sync_channel::<SilenceDetectorEvent>(8);
+        let (device_event_tx, device_event_rx) = mpsc::sync_channel::<DeviceEvent>(8);
+        let (capture_config_tx, capture_config_rx) = mpsc::sync_channel::<CaptureConfig>(1);
         // Spawn recording thread
         let thread_handle = thread::spawn(move || -> Result<String, String> {
-            let host = cpal::default_host();
-            let device = if let Some(device_name) = device_name {
-                // Try to find the specified device
-                host.input_devices()
-                    .map_err(|e| format!("Failed to enumerate input devices: {}", e))?
-                    .find(|d| d.name().map(|n| n == device_name).unwrap_or(false))
-                    .ok_or_else(|| {
-                        log::warn!(
-                            "Specified device '{}' not found, falling back to default",
-                            device_name
-                        );
-                        format!("Device '{}' not found", device_name)
-                    })
-                    .or_else(|_| {
-                        // Fallback to default device if specified device not found
-                        host.default_input_device()
-                            .ok_or("No input device available".to_string())
-                    })?
-            } else {
-                // Use default device
-                host.default_input_device()
-                    .ok_or("No input device available")?
-            };
+            let device = select_input_device(device_name)?;
 
             let device_name = device.name().unwrap_or_else(|_| "Unknown".to_string());
             log::info!("======================================");
             log::info!("🎤 AUDIO DEVICE SELECTED: {}", device_name);
             log::info!("======================================");
 
-            let config = device.default_input_config().map_err(|e| e.to_string())?;
+            let default_config = device.default_input_config().map_err(|e| e.to_string())?;
+            let native_rate = default_config.sample_rate().0;
+            log::info!(
+                "Device native sample rate: {} Hz (preferred {} Hz)",
+                native_rate,
+                preferred_sample_rate
+            );
+
+            // Open at the preferred rate when the device offers it, otherwise
+            // at the closest rate it does; the WAV is resampled after capture.
+            let ranges: Vec<(u32, u32)> = device
+                .supported_input_configs()
+                .map(|configs| {
+                    configs
+                        .filter(|range| {
+                            range.channels() == default_config.channels()
+                                && matches!(
+                                    range.sample_format(),
+                                    cpal::SampleFormat::F32
+                                        | cpal::SampleFormat::I16
+                                        | cpal::SampleFormat::U16
+                                )
+                        })
+                        .map(|range| (range.min_sample_rate().0, range.max_sample_rate().0))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let capture_rate =
+                choose_capture_rate(preferred_sample_rate, &ranges).unwrap_or(native_rate);
+            let config = if capture_rate == native_rate {
+                default_config
+            } else {
+                matching_input_config(&device, capture_rate, default_config.channels())
+                    .unwrap_or_else(|e| {
+                        log::warn!(
+                            "Cannot open capture at {} Hz ({}), using native {} Hz",
+                            capture_rate,
+                            e,
+                            native_rate
+                        );
+                        default_config
+                    })
+            };
+            if config.sample_rate().0 != preferred_sample_rate {
+                log::warn!(
+                    "Device cannot capture at {} Hz; recording at {} Hz and resampling",
+                    preferred_sample_rate,
+                    config.sample_rate().0
+                );
+            }
+            let _ = capture_config_tx.try_send(CaptureConfig {
+                device_sample_rate: native_rate,
+                capture_sample_rate: config.sample_rate().0,
+                output_sample_rate: preferred_sample_rate,
+                channels: config.channels(),
+            });
 
             log::info!(
                 "Audio config: sample_rate={} Hz, channels={}, format={:?}",
@@ -316,17 +1012,23 @@ impl AudioRecorder {
             let chunk_capacity = max_callback_samples(&device, &config);
 
             // Initialize silence detector and level meter
-            let silence_detector = Arc::new(Mutex::new(SilenceDetector::new()));
+            let silence_detector = Arc::new(Mutex::new(match silence_auto_stop {
+                Some(config) => SilenceDetector::with_auto_stop(config),
+                None => SilenceDetector::new(),
+            }));
             let level_meter = Arc::new(Mutex::new(
                 AudioLevelMeter::new(
                     config.sample_rate().0,
                     config.channels() as u32,
                     audio_level_tx.clone(),
                 )
-                .map_err(|e| format!("Failed to create level meter: {}", e))?,
+                .map_err(|e| format!("Failed to create level meter: {}", e))?
+                .with_waveform(waveform, config.sample_rate().0)
+                .with_clip_flag(clip_flag, config.sample_rate().0),
             ));
 
-            // Record with native settings, Whisper will handle resampling
+            // Record at the capture rate; resampled to the preferred rate
+            // once the WAV is finalized.
             let spec = hound::WavSpec {
                 channels: config.channels(),
                 sample_rate: config.sample_rate().0,
@@ -363,6 +1065,7 @@ impl AudioRecorder {
             let writer_handle = thread::spawn(move || -> Result<(), String> {
                 let mut writer = writer;
                 let mut write_error = None::<String>;
+                let mut sample_tap = sample_tap;
 
                 loop {
                     let mut samples = match next_writer_action(
@@ -406,6 +1109,18 @@ impl AudioRecorder {
                         let _ = stop_tx_for_size.send(RecorderCommand::Stop);
                     }
 
+                    if let Some(tap) = &sample_tap {
+                        let chunk = CapturedChunk {
+                            samples: samples.clone(),
+                            sample_rate: spec.sample_rate,
+                            channels: spec.channels,
+                        };
+                        if tap.send(chunk).is_err() {
+                            // Consumer went away; keep recording without it.
+                            sample_tap = None;
+                        }
+                    }
+
                     samples.clear();
                     let _ = recycle_tx.send(samples);
                 }
@@ -432,27 +1147,27 @@ impl AudioRecorder {
                 Ok(())
             });
 
-            // Error callback that triggers stop on device errors (e.g., disconnection)
+            // Error callback that reports device errors (e.g., disconnection) to
+            // the recording thread, which then tries to reopen capture. Every
+            // stream of this recording, including reopened ones, gets its own.
             let stop_tx_for_error = stop_tx_clone.clone();
-            let error_occurred = Arc::new(Mutex::new(None::<String>));
-            let error_occurred_for_callback = error_occurred.clone();
-
-            let err_fn = move |err: cpal::StreamError| {
-                // Detailed logging for audio device errors
-                log::error!("═══════════════════════════════════════════════════════");
-                log::error!("🔴 AUDIO DEVICE ERROR DETECTED");
-                log::error!("═══════════════════════════════════════════════════════");
-                log::error!("Error type: {:?}", err);
-                log::error!("Error message: {}", err);
-                log::error!("Action: Triggering graceful recording stop");
-                log::error!("═══════════════════════════════════════════════════════");
-
-                // Store the error
-                if let Ok(mut guard) = error_occurred_for_callback.lock() {
-                    *guard = Some(format!("Audio device error: {}", err));
+            let make_err_fn = move || {
+                let stop_tx_for_error = stop_tx_for_error.clone();
+                move |err: cpal::StreamError| {
+                    // Detailed logging for audio device errors
+                    log::error!("═══════════════════════════════════════════════════════");
+                    log::error!("🔴 AUDIO DEVICE ERROR DETECTED");
+                    log::error!("═══════════════════════════════════════════════════════");
+                    log::error!("Error type: {:?}", err);
+                    log::error!("Error message: {}", err);
+                    log::error!("Action: Reopening the default input device");
+                    log::error!("═══════════════════════════════════════════════════════");
+
+                    let _ = stop_tx_for_error.send(RecorderCommand::DeviceLost(format!(
+                        "Audio device error: {}",
+                        err
+                    )));
                 }
-                // Signal the recording thread to stop
-                let _ = stop_tx_for_error.send(RecorderCommand::Stop);
             };
 
             // Drain barrier flags shared between callback and stop path
@@ -460,7 +1175,7 @@ impl AudioRecorder {
             let callback_drained = Arc::new(AtomicBool::new(false));
 
             // Common audio processing closure
-            let process_audio = {
+            let process_audio: SharedProcessor = Arc::new(Mutex::new({
                 let writer_tx_clone: SyncSender<WriterMsg> = writer_tx.clone();
                 let dropped_chunks_clone = dropped_chunks.clone();
                 let recycle_tx_for_drop = recycle_tx_for_drop.clone();
@@ -469,6 +1184,7 @@ impl AudioRecorder {
                 let level_meter_clone = level_meter.clone();
                 let stop_requested_clone = stop_requested.clone();
                 let callback_drained_clone = callback_drained.clone();
+                let paused_clone = paused.clone();
 
                 move |f32_samples: &[f32], i16_samples: &[i16]| {
                     // A panic in this real-time path would unwind into CPAL's
@@ -504,6 +1220,15 @@ impl AudioRecorder {
                             }
                             return;
                         }
+                        // Paused: keep the stream alive but write nothing, and
+                        // hold the silence clocks so auto-stop cannot fire.
+                        let is_paused = paused_clone.load(Ordering::SeqCst);
+                        if let Ok(mut detector) = silence_detector_clone.try_lock() {
+                            detector.set_paused(is_paused);
+                        }
+                        if is_paused {
+                            return;
+                        }
                         // Calculate RMS for both level meter and silence detection
                         let sum: f32 = f32_samples.iter().map(|x| x * x).sum();
                         let rms = (sum / f32_samples.len() as f32).sqrt();
@@ -546,78 +1271,16 @@ impl AudioRecorder {
                         }
                     }));
                 }
-            };
+            }));
 
-            let stream = match config.sample_format() {
-                cpal::SampleFormat::F32 => {
-                    let process_audio = process_audio;
-                    let mut i16_scratch: Vec<i16> = Vec::with_capacity(chunk_capacity);
-                    device
-                        .build_input_stream(
-                            &config.config(),
-                            move |data: &[f32], _: &_| {
-                                // Convert F32 to I16 with proper clamping to avoid distortion
-                                i16_scratch.clear();
-                                i16_scratch.extend(data.iter().map(|&sample| f32_to_i16(sample)));
-
-                                // Process audio
-                                process_audio(data, &i16_scratch);
-                            },
-                            err_fn,
-                            None,
-                        )
-                        .map_err(|e| e.to_string())?
-                }
-                cpal::SampleFormat::I16 => {
-                    let process_audio = process_audio;
-                    let mut f32_scratch: Vec<f32> = Vec::with_capacity(chunk_capacity);
-                    device
-                        .build_input_stream(
-                            &config.config(),
-                            move |data: &[i16], _: &_| {
-                                // Convert I16 to F32 for processing
-                                f32_scratch.clear();
-                                f32_scratch.extend(data.iter().map(|&sample| i16_to_f32(sample)));
-
-                                // Process audio
-                                process_audio(&f32_scratch, data);
-                            },
-                            err_fn,
-                            None,
-                        )
-                        .map_err(|e| e.to_string())?
-                }
-                cpal::SampleFormat::U16 => {
-                    let process_audio = process_audio;
-                    let mut f32_scratch: Vec<f32> = Vec::with_capacity(chunk_capacity);
-                    let mut i16_scratch: Vec<i16> = Vec::with_capacity(chunk_capacity);
-                    device
-                        .build_input_stream(
-                            &config.config(),
-                            move |data: &[u16], _: &_| {
-                                // Convert U16 to F32 for processing
-                                f32_scratch.clear();
-                                f32_scratch.extend(data.iter().map(|&sample| u16_to_f32(sample)));
-
-                                // Convert U16 to I16 for writing
-                                i16_scratch.clear();
-                                i16_scratch.extend(data.iter().map(|&sample| u16_to_i16(sample)));
-
-                                // Process audio
-                                process_audio(&f32_scratch, &i16_scratch);
-                            },
-                            err_fn,
-                            None,
-                        )
-                        .map_err(|e| e.to_string())?
-                }
-                _ => {
-                    return Err(format!(
-                        "Unsupported sample format: {:?}",
-                        config.sample_format()
-                    ))
-                }
-            };
+            let stream = build_capture_stream(
+                &device,
+                &config,
+                chunk_capacity,
+                mic_gain_db,
+                process_audio.clone(),
+                make_err_fn(),
+            )?;
 
             stream.play().map_err(|e| {
                 log::error!("Failed to start audio stream: {}", e);
@@ -626,57 +1289,69 @@ impl AudioRecorder {
 
             log::info!("Audio stream started successfully");
 
-            // Wait for stop signal
-            let stop_reason = stop_rx.recv().ok();
+            // Capture until stopped. If the device disappears, the default
+            // device is reopened with the WAV's format and capture continues
+            // into the same file.
+            let reopen = || -> Result<cpal::Stream, String> {
+                let device = cpal::default_host()
+                    .default_input_device()
+                    .ok_or("No input device available")?;
+                let config = matching_input_config(&device, spec.sample_rate, spec.channels)?;
+                let stream = build_capture_stream(
+                    &device,
+                    &config,
+                    chunk_capacity,
+                    mic_gain_db,
+                    process_audio.clone(),
+                    make_err_fn(),
+                )?;
+                stream.play().map_err(|e| e.to_string())?;
+                log::info!(
+                    "🎤 Recording continues on {}",
+                    device.name().unwrap_or_else(|_| "Unknown".to_string())
+                );
+                Ok(stream)
+            };
+            let CaptureEnd {
+                stream,
+                reason: stop_reason,
+                device_error,
+            } = run_capture(
+                stream,
+                &stop_rx,
+                DEVICE_RECONNECT_WINDOW,
+                reopen,
+                release_stream,
+                |event| {
+                    let _ = device_event_tx.try_send(event);
+                },
+            );
 
-            // Drain barrier: signal callback to drain and wait for acknowledgment
-            stop_requested.store(true, Ordering::SeqCst);
-            let drain_start = Instant::now();
-            while !callback_drained.load(Ordering::SeqCst) {
-                if drain_start.elapsed() > Duration::from_millis(200) {
-                    log::warn!(
-                        "Drain timeout: proceeding with finalization after {}ms",
-                        drain_start.elapsed().as_millis()
-                    );
-                    callback_drained.store(true, Ordering::SeqCst);
-                    break;
+            if let Some(stream) = stream {
+                // Drain barrier: signal callback to drain and wait for acknowledgment
+                stop_requested.store(true, Ordering::SeqCst);
+                let drain_start = Instant::now();
+                while !callback_drained.load(Ordering::SeqCst) {
+                    if drain_start.elapsed() > Duration::from_millis(200) {
+                        log::warn!(
+                            "Drain timeout: proceeding with finalization after {}ms",
+                            drain_start.elapsed().as_millis()
+                        );
+                        callback_drained.store(true, Ordering::SeqCst);
+                        break;
+                    }
+                    std::thread::sleep(Duration::from_millis(5));
+                }
+                if callback_drained.load(Ordering::SeqCst) {
+                    log::info!("Drain complete: callback acknowledged stop");
                 }
-                std::thread::sleep(Duration::from_millis(5));
-            }
-            if callback_drained.load(Ordering::SeqCst) {
-                log::info!("Drain complete: callback acknowledged stop");
-            }
-
-            // Pause the stream to stop audio capture
-            if let Err(e) = stream.pause() {
-                log::warn!("Failed to pause audio stream: {}", e);
-            }
 
-            // Platform-specific stream cleanup.
-            // On Windows, some USB/wireless WASAPI devices can hang during
-            // Stream::drop(). We attempt a clean drop with a timeout guard;
-            // if it hangs beyond 3 seconds, we fall back to mem::forget.
-            #[cfg(target_os = "windows")]
-            {
-                let stream_drop_result = std::sync::mpsc::channel::<()>();
-                let (drop_tx, drop_rx) = stream_drop_result;
-                // Move stream into a thread so drop doesn't block the recording thread
-                std::thread::spawn(move || {
-                    drop(stream);
-                    let _ = drop_tx.send(());
-                });
-                match drop_rx.recv_timeout(Duration::from_secs(3)) {
-                    Ok(()) => log::info!("Audio stream stopped and cleaned up"),
-                    Err(_) => log::warn!(
-                        "Audio stream drop timed out (3s). Stream resources will be reclaimed on process exit."
-                    ),
+                // Pause the stream to stop audio capture
+                if let Err(e) = stream.pause() {
+                    log::warn!("Failed to pause audio stream: {}", e);
                 }
-            }
 
-            #[cfg(not(target_os = "windows"))]
-            {
-                drop(stream);
-                log::info!("Audio stream stopped and cleaned up");
+                release_stream(stream);
             }
 
             // Signal the writer to finalize, NON-blockingly. A blocking
@@ -704,18 +1379,38 @@ impl AudioRecorder {
 
             writer_result?;
 
-            // Check if any errors occurred during recording after preserving writer integrity
-            // failures as the primary stop error.
-            if let Ok(guard) = error_occurred.lock() {
-                if let Some(error) = &*guard {
-                    return Err(error.clone());
+            if spec.channels > 1 && channel_select != ChannelSelect::Mix {
+                if let Err(e) = super::converter::downmix_wav(&output_path, channel_select) {
+                    log::warn!(
+                        "Failed to keep only the {} channel, keeping all channels: {}",
+                        channel_select.as_str(),
+                        e
+                    );
+                }
+            }
+
+            if spec.sample_rate != preferred_sample_rate {
+                if let Err(e) = super::resampler::resample_wav(&output_path, preferred_sample_rate)
+                {
+                    log::warn!(
+                        "Failed to resample recording to {} Hz, keeping {} Hz: {}",
+                        preferred_sample_rate,
+                        spec.sample_rate,
+                        e
+                    );
                 }
             }
 
+            // Report a device that never came back after preserving writer
+            // integrity failures as the primary stop error.
+            if let Some(error) = device_error {
+                return Err(error);
+            }
+
             // Return appropriate message based on stop reason
             match stop_reason {
                 Some(RecorderCommand::Stop) => Ok("Recording stopped by user".to_string()),
-                None => Ok("Recording stopped".to_string()),
+                _ => Ok("Recording stopped".to_string()),
             }
         });
 
@@ -732,6 +1427,14 @@ impl AudioRecorder {
             .silence_event_receiver
             .lock()
             .map_err(|e| format!("Failed to acquire lock: {}", e))? = Some(silence_event_rx);
+        *self
+            .device_event_receiver
+            .lock()
+            .map_err(|e| format!("Failed to acquire lock: {}", e))? = Some(device_event_rx);
+        *self
+            .capture_config_receiver
+            .lock()
+            .map_err(|e| format!("Failed to acquire lock: {}", e))? = Some(capture_config_rx);
         *self
             .audio_level_receiver
             .lock()
@@ -740,12 +1443,37 @@ impl AudioRecorder {
         Ok(())
     }
 
+    /// Stop writing captured audio to the WAV without ending the recording.
+    /// The stream stays open, so [`Self::resume`] picks up instantly and the
+    /// paused stretch is simply absent from the file. Returns whether the
+    /// recording was running (false if it was already paused).
+    pub fn pause(&self) -> Result<bool, String> {
+        if !self.is_recording() {
+            return Err("Not recording".to_string());
+        }
+        Ok(!self.paused.swap(true, Ordering::SeqCst))
+    }
+
+    /// Resume writing after [`Self::pause`]. Returns whether the recording was
+    /// paused.
+    pub fn resume(&self) -> Result<bool, String> {
+        if !self.is_recording() {
+            return Err("Not recording".to_string());
+        }
+        Ok(self.paused.swap(false, Ordering::SeqCst))
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst) && self.is_recording()
+    }
+
     pub fn stop_recording(&mut self) -> Result<String, String> {
         let handle = self
             .recording_handle
             .lock()
             .map_err(|e| format!("Failed to acquire lock: {}", e))?
             .take();
+        self.paused.store(false, Ordering::SeqCst);
 
         // Also clear the audio level receiver
 
@@ -753,6 +1481,9 @@ impl AudioRecorder {
         if let Ok(mut guard) = self.silence_event_receiver.lock() {
             guard.take();
         }
+        if let Ok(mut guard) = self.device_event_receiver.lock() {
+            guard.take();
+        }
         if let Ok(mut guard) = self.audio_level_receiver.lock() {
             guard.take();
         }
@@ -814,6 +1545,20 @@ impl AudioRecorder {
             .and_then(|mut guard| guard.take())
     }
 
+    pub fn take_device_event_receiver(&mut self) -> Option<mpsc::Receiver<DeviceEvent>> {
+        self.device_event_receiver
+            .lock()
+            .ok()
+            .and_then(|mut guard| guard.take())
+    }
+
+    pub fn take_capture_config_receiver(&mut self) -> Option<mpsc::Receiver<CaptureConfig>> {
+        self.capture_config_receiver
+            .lock()
+            .ok()
+            .and_then(|mut guard| guard.take())
+    }
+
     pub fn is_recording(&self) -> bool {
         self.recording_handle
             .lock()
@@ -843,12 +1588,37 @@ impl AudioRecorder {
             .and_then(|mut guard| guard.take())
     }
 
+    /// Recent RMS windows of the current recording, for the pill waveform.
+    pub fn take_waveform(&mut self) -> Option<SharedWaveform> {
+        self.waveform.take()
+    }
+
+    /// Raised while the current recording clips, for the pill warning.
+    pub fn take_clip_flag(&mut self) -> Option<ClipFlag> {
+        self.clip_flag.take()
+    }
+
     pub fn get_devices() -> Vec<String> {
         let host = cpal::default_host();
         host.input_devices()
             .map(|devices| devices.filter_map(|device| device.name().ok()).collect())
             .unwrap_or_else(|_| Vec::new())
     }
+
+    /// Input devices with their advertised sample rates and channel counts.
+    pub fn get_devices_detailed() -> Vec<InputDeviceInfo> {
+        let host = cpal::default_host();
+        let default_name = host
+            .default_input_device()
+            .and_then(|device| device.name().ok());
+        host.input_devices()
+            .map(|devices| {
+                devices
+                    .filter_map(|device| describe_input_device(&device, default_name.as_deref()))
+                    .collect()
+            })
+            .unwrap_or_else(|_| Vec::new())
+    }
 }
 
 /// Joins the WAV writer worker with a hard deadline.
@@ -909,6 +1679,52 @@ pub(crate) fn stop_error_is_integrity_failure(error: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn mute_probe_output_parses_or_stays_unknown() {
+        assert_eq!(parse_macos_input_volume("0\n"), Some(true));
+        assert_eq!(parse_macos_input_volume("75\n"), Some(false));
+        assert_eq!(parse_macos_input_volume("missing value\n"), None);
+
+        assert_eq!(parse_pactl_source_mute("Mute: yes\n"), Some(true));
+        assert_eq!(parse_pactl_source_mute("Mute: no\n"), Some(false));
+        assert_eq!(parse_pactl_source_mute("Connection failure"), None);
+    }
+
+    #[test]
+    fn sample_rates_in_ranges_lists_covered_common_rates_and_endpoints() {
+        assert_eq!(
+            sample_rates_in_ranges(&[(44_100, 48_000), (11_025, 11_025)]),
+            vec![11_025, 44_100, 48_000]
+        );
+        assert_eq!(
+            sample_rates_in_ranges(&[(8_000, 24_000)]),
+            vec![8_000, 16_000, 22_050, 24_000]
+        );
+        assert!(sample_rates_in_ranges(&[]).is_empty());
+    }
+
+    #[test]
+    fn choose_capture_rate_prefers_supported_rate_else_closest() {
+        assert_eq!(
+            choose_capture_rate(16_000, &[(8_000, 48_000)]),
+            Some(16_000)
+        );
+        assert_eq!(
+            choose_capture_rate(16_000, &[(44_100, 44_100), (48_000, 96_000)]),
+            Some(44_100)
+        );
+        assert_eq!(
+            choose_capture_rate(16_000, &[(8_000, 8_000), (32_000, 32_000)]),
+            Some(8_000)
+        );
+        // Equally close: take the higher rate and downsample.
+        assert_eq!(
+            choose_capture_rate(16_000, &[(12_000, 12_000), (20_000, 20_000)]),
+            Some(20_000)
+        );
+        assert_eq!(choose_capture_rate(16_000, &[]), None);
+    }
+
     #[test]
     fn next_writer_action_writes_chunk() {
         assert!(matches!(
@@ -1219,6 +2035,111 @@ mod tests {
         assert!(recorder.take_silence_event_receiver().is_some());
         assert!(recorder.take_silence_event_receiver().is_none());
     }
+    #[test]
+    fn run_capture_reopens_device_after_mid_capture_stream_error() {
+        let (stop_tx, stop_rx) = mpsc::channel::<RecorderCommand>();
+        // The error callback fires mid-capture; the user stops later.
+        stop_tx
+            .send(RecorderCommand::DeviceLost(
+                "Audio device error: unplugged".to_string(),
+            ))
+            .unwrap();
+        stop_tx.send(RecorderCommand::Stop).unwrap();
+
+        let mut attempts = 0;
+        let mut released = Vec::new();
+        let mut events = Vec::new();
+        let end = run_capture(
+            1u32,
+            &stop_rx,
+            Duration::from_secs(2),
+            || {
+                attempts += 1;
+                if attempts < 2 {
+                    Err("No input device available".to_string())
+                } else {
+                    Ok(2u32)
+                }
+            },
+            |stream| released.push(stream),
+            |event| events.push(event),
+        );
+
+        assert_eq!(end.stream, Some(2));
+        assert!(matches!(end.reason, Some(RecorderCommand::Stop)));
+        assert_eq!(end.device_error, None);
+        assert_eq!(released, vec![1]);
+        assert_eq!(events, vec![DeviceEvent::Lost, DeviceEvent::Recovered]);
+    }
+
+    #[test]
+    fn run_capture_gives_up_when_no_device_returns_in_window() {
+        let (stop_tx, stop_rx) = mpsc::channel::<RecorderCommand>();
+        stop_tx
+            .send(RecorderCommand::DeviceLost(
+                "Audio device error: unplugged".to_string(),
+            ))
+            .unwrap();
+
+        let mut events = Vec::new();
+        let end = run_capture(
+            1u32,
+            &stop_rx,
+            Duration::from_millis(30),
+            || Err::<u32, _>("No input device available".to_string()),
+            |_| {},
+            |event| events.push(event),
+        );
+
+        assert_eq!(end.stream, None);
+        assert!(end.reason.is_none());
+        assert_eq!(
+            end.device_error.as_deref(),
+            Some("Audio device error: unplugged")
+        );
+        // The WAV is finalized, so the stop path keeps the captured audio.
+        assert!(!stop_error_is_unfinalized(
+            end.device_error.as_deref().unwrap()
+        ));
+        assert_eq!(events, vec![DeviceEvent::Lost, DeviceEvent::RecoveryFailed]);
+    }
+
+    #[test]
+    fn pause_and_resume_require_an_active_recording() {
+        let recorder = AudioRecorder::new();
+        assert!(recorder.pause().is_err());
+        assert!(recorder.resume().is_err());
+        assert!(!recorder.is_paused());
+    }
+
+    #[test]
+    fn pause_and_resume_toggle_until_stopped() {
+        let (stop_tx, stop_rx) = mpsc::channel::<RecorderCommand>();
+        let thread_handle = thread::spawn(move || {
+            let _ = stop_rx.recv();
+            Ok::<String, String>("stopped".to_string())
+        });
+        let mut recorder = AudioRecorder::new();
+        *recorder.recording_handle.lock().unwrap() = Some(RecordingHandle {
+            stop_tx,
+            thread_handle,
+        });
+
+        assert_eq!(recorder.pause(), Ok(true));
+        assert!(recorder.is_paused());
+        // A second pause is a no-op.
+        assert_eq!(recorder.pause(), Ok(false));
+        assert_eq!(recorder.resume(), Ok(true));
+        assert!(!recorder.is_paused());
+        assert_eq!(recorder.resume(), Ok(false));
+
+        // Stopping while paused clears the flag for the next recording.
+        assert_eq!(recorder.pause(), Ok(true));
+        recorder.stop_recording().unwrap();
+        assert!(!recorder.is_paused());
+        assert!(!recorder.paused.load(Ordering::SeqCst));
+    }
+
     #[test]
     fn recording_thread_finished_is_false_when_idle() {
         let recorder = AudioRecorder::new();
@@ -1,25 +1,42 @@
 use audioadapter_buffers::direct::SequentialSlice;
 use rubato::{Fft, FixedSync, Resampler};
+use std::path::Path;
 
 /// Resample audio from any sample rate to 16kHz for Whisper.
+pub fn resample_to_16khz(input: &[f32], input_sample_rate: u32) -> Result<Vec<f32>, String> {
+    resample(input, input_sample_rate, 16_000)
+}
+
+/// Resample mono audio between two sample rates.
 ///
 /// Uses rubato's FFT-based synchronous resampler with `process_all_into_buffer`,
 /// which handles arbitrary-length clips in a single call — including chunking,
 /// partial tails, and internal delay buffer flushing. This eliminates tail-sample
 /// loss that occurred with manual single-shot `process_into_buffer` calls.
-pub fn resample_to_16khz(input: &[f32], input_sample_rate: u32) -> Result<Vec<f32>, String> {
-    if input_sample_rate == 16_000 {
-        log::debug!("Audio already at 16kHz, no resampling needed");
+pub fn resample(
+    input: &[f32],
+    input_sample_rate: u32,
+    output_sample_rate: u32,
+) -> Result<Vec<f32>, String> {
+    if input_sample_rate == output_sample_rate {
+        log::debug!(
+            "Audio already at {} Hz, no resampling needed",
+            output_sample_rate
+        );
         return Ok(input.to_vec());
     }
 
-    log::info!("Resampling audio from {} Hz to 16000 Hz", input_sample_rate);
+    log::info!(
+        "Resampling audio from {} Hz to {} Hz",
+        input_sample_rate,
+        output_sample_rate
+    );
 
     // Fft resampler: fast, always best quality for fixed-ratio offline resampling.
     // FixedSync::Both lets rubato pick optimal chunk sizes for the ratio.
     let mut resampler = Fft::<f32>::new(
         input_sample_rate as usize,
-        16_000,
+        output_sample_rate as usize,
         1024, // Internal processing block size; process_all_into_buffer handles any input length
         1,    // sub_chunks
         1,    // mono
@@ -48,12 +65,58 @@ pub fn resample_to_16khz(input: &[f32], input_sample_rate: u32) -> Result<Vec<f3
         "Resampled {} samples to {} samples (ratio: {:.4})",
         input.len(),
         output.len(),
-        16_000_f64 / input_sample_rate as f64
+        output_sample_rate as f64 / input_sample_rate as f64
     );
 
     Ok(output)
 }
 
+/// Rewrite a 16-bit WAV at `output_sample_rate`, resampling each channel.
+pub fn resample_wav(path: &Path, output_sample_rate: u32) -> Result<(), String> {
+    let mut reader =
+        hound::WavReader::open(path).map_err(|e| format!("Failed to open WAV: {}", e))?;
+    let spec = reader.spec();
+    if spec.sample_rate == output_sample_rate {
+        return Ok(());
+    }
+    if spec.bits_per_sample != 16 || spec.sample_format != hound::SampleFormat::Int {
+        return Err("Resampling expects a 16-bit PCM WAV".to_string());
+    }
+    let channels = spec.channels.max(1) as usize;
+    let mut planar: Vec<Vec<f32>> = vec![Vec::new(); channels];
+    for (i, sample) in reader.samples::<i16>().enumerate() {
+        let sample = sample.map_err(|e| format!("Failed to read samples: {}", e))?;
+        planar[i % channels].push(sample as f32 / i16::MAX as f32);
+    }
+    drop(reader);
+
+    let resampled = planar
+        .iter()
+        .map(|channel| resample(channel, spec.sample_rate, output_sample_rate))
+        .collect::<Result<Vec<_>, _>>()?;
+    let frames = resampled.iter().map(Vec::len).min().unwrap_or(0);
+
+    let mut writer = hound::WavWriter::create(
+        path,
+        hound::WavSpec {
+            sample_rate: output_sample_rate,
+            ..spec
+        },
+    )
+    .map_err(|e| format!("Failed to write WAV: {}", e))?;
+    for frame in 0..frames {
+        for channel in &resampled {
+            let sample = (channel[frame].clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+            writer
+                .write_sample(sample)
+                .map_err(|e| format!("Failed to write WAV: {}", e))?;
+        }
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to write WAV: {}", e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,4 +243,81 @@ mod tests {
             head_rms
         );
     }
+
+    /// Amplitude of the `freq` component of `samples` (Goertzel).
+    fn tone_amplitude(samples: &[f32], sample_rate: u32, freq: f32) -> f32 {
+        let omega = 2.0 * std::f32::consts::PI * freq / sample_rate as f32;
+        let coeff = 2.0 * omega.cos();
+        let (mut s1, mut s2) = (0.0f32, 0.0f32);
+        for &x in samples {
+            let s0 = x + coeff * s1 - s2;
+            s2 = s1;
+            s1 = s0;
+        }
+        let power = s1 * s1 + s2 * s2 - coeff * s1 * s2;
+        2.0 * power.max(0.0).sqrt() / samples.len() as f32
+    }
+
+    #[test]
+    fn test_resample_48khz_keeps_speech_band_and_filters_above_nyquist() {
+        // 1 kHz tone (kept) plus a 12 kHz tone above the 8 kHz output Nyquist,
+        // which would alias to 4 kHz without proper low-pass filtering.
+        let input: Vec<f32> = (0..48_000)
+            .map(|i| {
+                let t = i as f32 / 48_000.0;
+                0.5 * (2.0 * std::f32::consts::PI * 1_000.0 * t).sin()
+                    + 0.3 * (2.0 * std::f32::consts::PI * 12_000.0 * t).sin()
+            })
+            .collect();
+
+        let result = resample(&input, 48_000, 16_000).unwrap();
+        assert!(
+            (result.len() as i64 - 16_000).abs() <= 16,
+            "expected ~16000 samples, got {}",
+            result.len()
+        );
+
+        // Skip the filter's start-up transient.
+        let body = &result[1_000..15_000];
+        let kept = tone_amplitude(body, 16_000, 1_000.0);
+        let aliased = tone_amplitude(body, 16_000, 4_000.0);
+        assert!((kept - 0.5).abs() < 0.05, "1 kHz amplitude {kept}");
+        assert!(aliased < 0.01, "4 kHz alias amplitude {aliased}");
+    }
+
+    #[test]
+    fn test_resample_wav_rewrites_each_channel_at_target_rate() {
+        let file = tempfile::Builder::new().suffix(".wav").tempfile().unwrap();
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 48_000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(file.path(), spec).unwrap();
+        for i in 0..48_000 {
+            let t = i as f32 / 48_000.0;
+            let left = 0.5 * (2.0 * std::f32::consts::PI * 440.0 * t).sin();
+            writer
+                .write_sample((left * i16::MAX as f32) as i16)
+                .unwrap();
+            writer.write_sample(0i16).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        resample_wav(file.path(), 16_000).unwrap();
+
+        let mut reader = hound::WavReader::open(file.path()).unwrap();
+        assert_eq!(reader.spec().sample_rate, 16_000);
+        assert_eq!(reader.spec().channels, 2);
+        let samples: Vec<f32> = reader
+            .samples::<i16>()
+            .map(|s| s.unwrap() as f32 / i16::MAX as f32)
+            .collect();
+        let left: Vec<f32> = samples.iter().step_by(2).copied().collect();
+        let right: Vec<f32> = samples.iter().skip(1).step_by(2).copied().collect();
+        assert!((left.len() as i64 - 16_000).abs() <= 16);
+        assert!((tone_amplitude(&left[1_000..15_000], 16_000, 440.0) - 0.5).abs() < 0.05);
+        assert!(right.iter().all(|s| s.abs() < 0.01));
+    }
 }
@@ -1,3 +1,5 @@
+use std::ops::Range;
+use std::path::Path;
 use std::time::{Duration, Instant};
 
 pub const VOICE_RMS_THRESHOLD: f32 = 0.005;
@@ -7,6 +9,21 @@ pub const SILENCE_TIMEOUT_AFTER: Duration = Duration::from_secs(300);
 /// Minimum continuous above-threshold duration that counts as real voice.
 /// Brief ambient blips must not flip a silent recording into the speech path.
 pub const MIN_VOICE_DURATION: Duration = Duration::from_millis(300);
+/// Peak amplitude (normalized) below which a capture counts as digital silence.
+/// Far below any real microphone noise floor, so quiet speech is never dropped.
+pub const EMPTY_CAPTURE_PEAK_THRESHOLD: f32 = 0.001;
+
+/// User-configured silence auto-stop, already validated by the settings layer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SilenceAutoStop {
+    /// When false, silence never ends a recording; the warnings still show.
+    pub enabled: bool,
+    /// Level (dBFS) at or below which audio counts as silence.
+    pub threshold_db: f32,
+    /// Silence after speech that ends the recording. `None` keeps the
+    /// `SILENCE_TIMEOUT_AFTER` safety stop.
+    pub after_speech: Option<Duration>,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SilenceDetectorEvent {
@@ -33,8 +50,11 @@ pub struct SilenceDetector {
     no_speech_warning_after: Duration,
     long_silence_warning_after: Duration,
     silence_timeout_after: Duration,
+    after_speech_timeout: Duration,
+    auto_stop: bool,
     voice_run_start: Option<Instant>,
     min_voice_duration: Duration,
+    paused_at: Option<Instant>,
 }
 
 impl SilenceDetector {
@@ -42,10 +62,21 @@ impl SilenceDetector {
         Self::new_at(Instant::now())
     }
 
+    pub fn with_auto_stop(config: SilenceAutoStop) -> Self {
+        Self::with_auto_stop_at(config, Instant::now())
+    }
+
     pub fn update(&mut self, rms: f32) -> Option<SilenceDetectorEvent> {
         self.update_at(rms, Instant::now())
     }
 
+    /// Hold the silence clocks while the recording is paused, so paused time
+    /// never counts toward a warning or auto-stop. Repeated calls with the
+    /// same value are no-ops.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.set_paused_at(paused, Instant::now())
+    }
+
     fn new_at(now: Instant) -> Self {
         Self {
             started_at: now,
@@ -56,13 +87,38 @@ impl SilenceDetector {
             no_speech_warning_after: NO_SPEECH_WARNING_AFTER,
             long_silence_warning_after: LONG_SILENCE_WARNING_AFTER,
             silence_timeout_after: SILENCE_TIMEOUT_AFTER,
+            after_speech_timeout: SILENCE_TIMEOUT_AFTER,
+            auto_stop: true,
             voice_run_start: None,
             min_voice_duration: MIN_VOICE_DURATION,
+            paused_at: None,
+        }
+    }
+
+    fn with_auto_stop_at(config: SilenceAutoStop, now: Instant) -> Self {
+        let mut detector = Self::new_at(now);
+        detector.voice_threshold = 10f32.powf(config.threshold_db / 20.0);
+        detector.after_speech_timeout = config.after_speech.unwrap_or(SILENCE_TIMEOUT_AFTER);
+        detector.auto_stop = config.enabled;
+        detector
+    }
+
+    fn set_paused_at(&mut self, paused: bool, now: Instant) {
+        match (paused, self.paused_at) {
+            (true, None) => self.paused_at = Some(now),
+            (false, Some(since)) => {
+                let held = now.saturating_duration_since(since);
+                self.started_at += held;
+                self.last_voice_time += held;
+                self.voice_run_start = None;
+                self.paused_at = None;
+            }
+            _ => {}
         }
     }
 
     fn update_at(&mut self, rms: f32, now: Instant) -> Option<SilenceDetectorEvent> {
-        if self.last_event.is_terminal() {
+        if self.last_event.is_terminal() || self.paused_at.is_some() {
             return None;
         }
 
@@ -82,7 +138,7 @@ impl SilenceDetector {
 
         if !self.speech_detected {
             let elapsed = now.saturating_duration_since(self.started_at);
-            let tier = if elapsed >= self.silence_timeout_after {
+            let tier = if self.auto_stop && elapsed >= self.silence_timeout_after {
                 SilenceDetectorEvent::TimeoutNoSpeech
             } else if elapsed >= self.no_speech_warning_after {
                 SilenceDetectorEvent::DeadMicWarn
@@ -93,7 +149,7 @@ impl SilenceDetector {
         }
 
         let elapsed = now.saturating_duration_since(self.last_voice_time);
-        let tier = if elapsed >= self.silence_timeout_after {
+        let tier = if self.auto_stop && elapsed >= self.after_speech_timeout {
             SilenceDetectorEvent::TimeoutWithSpeech
         } else if elapsed >= self.long_silence_warning_after {
             SilenceDetectorEvent::LongSilenceWarn
@@ -112,6 +168,204 @@ impl SilenceDetector {
     }
 }
 
+/// What a finished capture actually contains, independent of header size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaptureProbe {
+    pub frames: u64,
+    pub duration_ms: u64,
+    pub digitally_silent: bool,
+}
+
+impl CaptureProbe {
+    /// No samples at all, or samples that never rise above digital silence.
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0 || self.digitally_silent
+    }
+}
+
+/// Read a WAV's sample count and scan for any non-silent sample.
+///
+/// The scan stops at the first sample above `EMPTY_CAPTURE_PEAK_THRESHOLD`, so
+/// real recordings are only read until their first audible sample.
+pub fn probe_capture(path: &Path) -> Result<CaptureProbe, String> {
+    let mut reader =
+        hound::WavReader::open(path).map_err(|e| format!("Failed to open capture: {}", e))?;
+    let spec = reader.spec();
+    if spec.channels == 0 || spec.sample_rate == 0 {
+        return Err("Capture has an invalid WAV header".to_string());
+    }
+    let frames = (reader.duration() / spec.channels as u32) as u64;
+    let duration_ms = frames
+        .saturating_mul(1000)
+        .saturating_add(spec.sample_rate as u64 - 1)
+        / spec.sample_rate as u64;
+
+    let loud = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .filter_map(Result::ok)
+            .any(|s| s.abs() > EMPTY_CAPTURE_PEAK_THRESHOLD),
+        hound::SampleFormat::Int => {
+            let full_scale = (1i64 << (spec.bits_per_sample.clamp(1, 32) - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .filter_map(Result::ok)
+                .any(|s| (s as f32 / full_scale).abs() > EMPTY_CAPTURE_PEAK_THRESHOLD)
+        }
+    };
+
+    Ok(CaptureProbe {
+        frames,
+        duration_ms,
+        digitally_silent: !loud,
+    })
+}
+
+/// Speech kept on both sides of every detected voiced stretch, so trimming
+/// never clips word onsets or drops a short "yes" or "no".
+pub const TRIM_SPEECH_GUARD_MS: u64 = 200;
+/// Analysis window used to classify audio as speech or silence.
+const TRIM_FRAME_MS: u64 = 10;
+
+/// Result of `trim_silence`: the kept audio and the sample ranges (of the
+/// input) that were removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SilenceTrim {
+    pub samples: Vec<i16>,
+    pub removed: Vec<Range<usize>>,
+}
+
+impl SilenceTrim {
+    pub fn removed_ms(&self, sample_rate: u32) -> u64 {
+        let removed: usize = self.removed.iter().map(|range| range.len()).sum();
+        removed as u64 * 1000 / sample_rate.max(1) as u64
+    }
+}
+
+fn ms_to_samples(ms: u64, sample_rate: u32) -> usize {
+    (ms * sample_rate as u64 / 1000) as usize
+}
+
+/// Remove leading and trailing silence from mono PCM and, when `max_gap_ms`
+/// is set, shorten internal pauses between speech to about that long.
+///
+/// Audio counts as speech when a 10 ms window's RMS exceeds `threshold_db`
+/// (dBFS). Every speech stretch keeps `TRIM_SPEECH_GUARD_MS` of padding, and
+/// audio with no detected speech is returned untouched.
+pub fn trim_silence(
+    samples: &[i16],
+    sample_rate: u32,
+    threshold_db: f32,
+    max_gap_ms: Option<u64>,
+) -> SilenceTrim {
+    let untouched = || SilenceTrim {
+        samples: samples.to_vec(),
+        removed: Vec::new(),
+    };
+    let frame_len = ms_to_samples(TRIM_FRAME_MS, sample_rate).max(1);
+    let threshold = 10f32.powf(threshold_db / 20.0);
+    let guard = ms_to_samples(TRIM_SPEECH_GUARD_MS, sample_rate);
+
+    // Voiced stretches, padded by the guard and merged where they touch.
+    let mut kept: Vec<Range<usize>> = Vec::new();
+    for (index, frame) in samples.chunks(frame_len).enumerate() {
+        let energy: f32 = frame
+            .iter()
+            .map(|&s| {
+                let s = s as f32 / i16::MAX as f32;
+                s * s
+            })
+            .sum();
+        if (energy / frame.len() as f32).sqrt() <= threshold {
+            continue;
+        }
+        let start = (index * frame_len).saturating_sub(guard);
+        let end = (index * frame_len + frame.len() + guard).min(samples.len());
+        match kept.last_mut() {
+            Some(last) if start <= last.end => last.end = end,
+            _ => kept.push(start..end),
+        }
+    }
+    let (Some(first), Some(last)) = (kept.first(), kept.last()) else {
+        return untouched();
+    };
+
+    let mut removed = Vec::new();
+    if first.start > 0 {
+        removed.push(0..first.start);
+    }
+    if let Some(max_gap_ms) = max_gap_ms {
+        // The guards on either side already count toward the kept pause.
+        let max_gap = ms_to_samples(max_gap_ms, sample_rate).saturating_sub(2 * guard);
+        for pair in kept.windows(2) {
+            let gap = pair[0].end..pair[1].start;
+            if gap.len() > max_gap {
+                let keep_before = max_gap / 2;
+                removed.push(gap.start + keep_before..gap.end - (max_gap - keep_before));
+            }
+        }
+    }
+    if last.end < samples.len() {
+        removed.push(last.end..samples.len());
+    }
+    if removed.is_empty() {
+        return untouched();
+    }
+
+    let mut trimmed = Vec::with_capacity(samples.len());
+    let mut cursor = 0;
+    for range in &removed {
+        trimmed.extend_from_slice(&samples[cursor..range.start]);
+        cursor = range.end;
+    }
+    trimmed.extend_from_slice(&samples[cursor..]);
+
+    SilenceTrim {
+        samples: trimmed,
+        removed,
+    }
+}
+
+/// Apply `trim_silence` to a 16-bit mono WAV in place. Returns the number of
+/// milliseconds removed; the file is only rewritten when something was trimmed.
+pub fn trim_silence_in_wav(
+    path: &Path,
+    threshold_db: f32,
+    max_gap_ms: Option<u64>,
+) -> Result<u64, String> {
+    let mut reader =
+        hound::WavReader::open(path).map_err(|e| format!("Failed to open audio: {}", e))?;
+    let spec = reader.spec();
+    if spec.channels != 1
+        || spec.bits_per_sample != 16
+        || spec.sample_format != hound::SampleFormat::Int
+    {
+        return Err("Silence trimming expects 16-bit mono WAV".to_string());
+    }
+    let samples = reader
+        .samples::<i16>()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read audio: {}", e))?;
+    drop(reader);
+
+    let trim = trim_silence(&samples, spec.sample_rate, threshold_db, max_gap_ms);
+    if trim.removed.is_empty() {
+        return Ok(0);
+    }
+
+    let mut writer = hound::WavWriter::create(path, spec)
+        .map_err(|e| format!("Failed to write audio: {}", e))?;
+    for sample in &trim.samples {
+        writer
+            .write_sample(*sample)
+            .map_err(|e| format!("Failed to write audio: {}", e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to write audio: {}", e))?;
+    Ok(trim.removed_ms(spec.sample_rate))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -348,4 +602,190 @@ mod tests {
             None
         );
     }
+
+    fn auto_stop(enabled: bool, after_speech_ms: u64) -> SilenceAutoStop {
+        SilenceAutoStop {
+            enabled,
+            threshold_db: -40.0,
+            after_speech: Some(Duration::from_millis(after_speech_ms)),
+        }
+    }
+
+    #[test]
+    fn brief_dip_below_threshold_does_not_trigger_auto_stop() {
+        let start = t0();
+        let mut detector = SilenceDetector::with_auto_stop_at(auto_stop(true, 1_500), start);
+        // -40 dBFS is an RMS of 0.01; 0.02 is speech, 0.005 is silence.
+        let speech = 0.02;
+        let quiet = 0.005;
+
+        let spoke = start + MIN_VOICE_DURATION;
+        detector.update_at(speech, start);
+        detector.update_at(speech, spoke);
+        assert!(detector.speech_detected);
+
+        // A one-second thinking pause stays under the 1.5s auto-stop.
+        assert_eq!(
+            detector.update_at(quiet, spoke + Duration::from_millis(1_000)),
+            None
+        );
+        let resumed = spoke + Duration::from_millis(1_100);
+        detector.update_at(speech, resumed);
+        assert_eq!(
+            detector.update_at(speech, resumed + MIN_VOICE_DURATION),
+            None
+        );
+
+        // Only a pause longer than the configured duration ends it.
+        let stopped_at = resumed + MIN_VOICE_DURATION + Duration::from_millis(1_500);
+        assert_eq!(
+            detector.update_at(quiet, stopped_at),
+            Some(SilenceDetectorEvent::TimeoutWithSpeech)
+        );
+    }
+
+    #[test]
+    fn disabled_auto_stop_never_times_out() {
+        let start = t0();
+        let mut detector = SilenceDetector::with_auto_stop_at(auto_stop(false, 500), start);
+        assert_eq!(
+            detector.update_at(SILENT, start + SILENCE_TIMEOUT_AFTER * 2),
+            Some(SilenceDetectorEvent::DeadMicWarn)
+        );
+
+        let spoke = confirm_speech(&mut detector, start + SILENCE_TIMEOUT_AFTER * 2);
+        assert_eq!(
+            detector.update_at(SILENT, spoke + SILENCE_TIMEOUT_AFTER * 2),
+            Some(SilenceDetectorEvent::LongSilenceWarn)
+        );
+    }
+
+    #[test]
+    fn paused_time_never_counts_toward_auto_stop() {
+        let start = t0();
+        let mut detector = SilenceDetector::with_auto_stop_at(auto_stop(true, 1_500), start);
+        let spoke = start + MIN_VOICE_DURATION;
+        detector.update_at(0.02, start);
+        detector.update_at(0.02, spoke);
+
+        // A long pause right after speaking emits nothing while paused.
+        let paused = spoke + Duration::from_millis(1_000);
+        detector.set_paused_at(true, paused);
+        assert_eq!(
+            detector.update_at(SILENT, paused + SILENCE_TIMEOUT_AFTER),
+            None
+        );
+
+        // After resuming, only the silence outside the pause counts: 1s before
+        // plus 0.4s after stays under the 1.5s auto-stop.
+        let resumed = paused + SILENCE_TIMEOUT_AFTER;
+        detector.set_paused_at(false, resumed);
+        assert_eq!(
+            detector.update_at(SILENT, resumed + Duration::from_millis(400)),
+            None
+        );
+        assert_eq!(
+            detector.update_at(SILENT, resumed + Duration::from_millis(500)),
+            Some(SilenceDetectorEvent::TimeoutWithSpeech)
+        );
+    }
+
+    #[test]
+    fn repeated_pause_and_resume_calls_are_idempotent() {
+        let start = t0();
+        let mut detector = SilenceDetector::new_at(start);
+
+        detector.set_paused_at(true, start + Duration::from_secs(1));
+        detector.set_paused_at(true, start + Duration::from_secs(5));
+        detector.set_paused_at(false, start + Duration::from_secs(9));
+        detector.set_paused_at(false, start + Duration::from_secs(20));
+
+        // Paused from 1s to 9s, so the no-speech clock reads 2s at 10s.
+        assert_eq!(detector.started_at, start + Duration::from_secs(8));
+        assert_eq!(
+            detector.update_at(SILENT, start + Duration::from_secs(17)),
+            None
+        );
+        assert_eq!(
+            detector.update_at(SILENT, start + Duration::from_secs(18)),
+            Some(SilenceDetectorEvent::DeadMicWarn)
+        );
+    }
+
+    fn write_capture(samples: &[i16]) -> tempfile::NamedTempFile {
+        let file = tempfile::Builder::new().suffix(".wav").tempfile().unwrap();
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16_000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(file.path(), spec).unwrap();
+        for sample in samples {
+            writer.write_sample(*sample).unwrap();
+        }
+        writer.finalize().unwrap();
+        file
+    }
+
+    #[test]
+    fn probe_flags_header_only_and_silent_captures_as_empty() {
+        let header_only = probe_capture(write_capture(&[]).path()).unwrap();
+        assert_eq!(header_only.frames, 0);
+        assert!(header_only.is_empty());
+
+        let silent = probe_capture(write_capture(&[0; 16_000]).path()).unwrap();
+        assert_eq!(silent.duration_ms, 1000);
+        assert!(silent.is_empty());
+
+        let mut voiced = vec![0i16; 16_000];
+        voiced[8_000] = 4_000;
+        let voiced = probe_capture(write_capture(&voiced).path()).unwrap();
+        assert!(!voiced.is_empty());
+    }
+
+    fn tone(ms: usize) -> Vec<i16> {
+        (0..ms * 16)
+            .map(|i| if i % 2 == 0 { 8_000 } else { -8_000 })
+            .collect()
+    }
+
+    fn silence(ms: usize) -> Vec<i16> {
+        vec![0; ms * 16]
+    }
+
+    #[test]
+    fn trim_silence_removes_edges_but_keeps_speech_guard() {
+        let samples = [silence(1_000), tone(100), silence(1_000)].concat();
+        let trim = trim_silence(&samples, 16_000, -40.0, None);
+
+        let guard = TRIM_SPEECH_GUARD_MS as usize * 16;
+        assert_eq!(trim.samples.len(), 100 * 16 + 2 * guard);
+        assert_eq!(
+            trim.removed,
+            vec![0..16_000 - guard, 17_600 + guard..samples.len()]
+        );
+        assert_eq!(trim.removed_ms(16_000), 1_600);
+    }
+
+    #[test]
+    fn trim_silence_collapses_long_internal_gaps_only_when_asked() {
+        let samples = [tone(200), silence(3_000), tone(200)].concat();
+
+        let kept = trim_silence(&samples, 16_000, -40.0, None);
+        assert!(kept.removed.is_empty());
+        assert_eq!(kept.samples, samples);
+
+        let collapsed = trim_silence(&samples, 16_000, -40.0, Some(1_000));
+        assert_eq!(collapsed.samples.len(), (200 + 1_000 + 200) * 16);
+        assert_eq!(collapsed.removed_ms(16_000), 2_000);
+    }
+
+    #[test]
+    fn trim_silence_leaves_audio_without_speech_untouched() {
+        let samples = silence(500);
+        let trim = trim_silence(&samples, 16_000, -40.0, Some(1_000));
+        assert!(trim.removed.is_empty());
+        assert_eq!(trim.samples, samples);
+    }
 }
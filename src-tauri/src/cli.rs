@@ -285,6 +285,7 @@ async fn build_cli_app(
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_shell::init())
         .build(context)?;
+    crate::secure_store::load_rotated_encryption_key(app.handle())?;
 
     let models_dir = app.path().app_data_dir()?.join("models");
     std::fs::create_dir_all(&models_dir)?;
@@ -421,7 +422,7 @@ async fn run_record(app: &tauri::AppHandle, args: RecordArgs) -> Result<(), Box<
 
     check_license_status(app.clone()).await?;
     let settings = get_settings(app.clone()).await?;
-    let recordings_dir = app.path().app_data_dir()?.join("recordings");
+    let recordings_dir = crate::commands::audio::resolve_recordings_dir(app)?;
     std::fs::create_dir_all(&recordings_dir)?;
     let timestamp = chrono::Utc::now().format("%Y%m%d%H%M%S%3f");
     let uuid_part = uuid::Uuid::new_v4().to_string()[..8].to_string();
@@ -536,7 +537,7 @@ async fn normalize_audio_for_remote(
     app: &tauri::AppHandle,
     file: &Path,
 ) -> Result<RemoteNormalizedAudio, Box<dyn Error>> {
-    let recordings_dir = app.path().app_data_dir()?.join("recordings");
+    let recordings_dir = crate::commands::audio::resolve_recordings_dir(app)?;
     std::fs::create_dir_all(&recordings_dir)?;
 
     // Millisecond timestamps alone collide for simultaneous CLI invocations;
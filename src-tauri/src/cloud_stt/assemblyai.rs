@@ -0,0 +1,439 @@
+//! AssemblyAI cloud STT: upload + transcript + poll flow.
+//!
+//! AssemblyAI sends the key as a bare `Authorization` header, uploads the raw
+//! audio body, and returns the finished transcript from the poll endpoint
+//! itself. With `speaker_labels` enabled it also returns utterances, which are
+//! rendered as "Speaker A: ..." lines when the user turns the setting on.
+
+use super::common::{self, AuthScheme};
+use std::path::Path;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+pub(super) const MODEL: &str = "universal";
+
+const POLL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(180);
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1000);
+
+pub(super) async fn validate_key(base_url: &str, key: &str) -> Result<(), String> {
+    common::get_validate(
+        &format!("{}/transcript?limit=1", base_url),
+        AuthScheme::Raw,
+        key,
+        "AssemblyAI",
+    )
+    .await
+    .map_err(|e| e.message("AssemblyAI"))
+}
+
+/// Whether dictation text should carry "Speaker A:" prefixes.
+fn speaker_labels_enabled(app: &AppHandle) -> bool {
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get("assemblyai_speaker_labels"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+fn build_create_payload(
+    audio_url: &str,
+    language: Option<&str>,
+    speaker_labels: bool,
+) -> serde_json::Value {
+    let mut payload = serde_json::json!({
+        "audio_url": audio_url,
+        "speech_model": MODEL,
+    });
+
+    match language.map(str::trim).filter(|lang| !lang.is_empty()) {
+        Some(lang) => payload["language_code"] = serde_json::json!(lang),
+        None => payload["language_detection"] = serde_json::json!(true),
+    }
+
+    if speaker_labels {
+        payload["speaker_labels"] = serde_json::json!(true);
+    }
+
+    payload
+}
+
+/// Layer user extra params over the create payload. `audio_url` and
+/// `speech_model` are never overridden, even if a stale stored map still
+/// carries them.
+fn merge_extra_params(payload: &mut serde_json::Value, extra: &common::ExtraParams) {
+    let Some(object) = payload.as_object_mut() else {
+        return;
+    };
+    for (key, value) in extra {
+        if super::CloudProvider::Assemblyai
+            .reserved_params()
+            .contains(&key.as_str())
+        {
+            continue;
+        }
+        object.insert(key.clone(), value.clone());
+    }
+}
+
+/// One "Speaker X: text" line per utterance, or `None` when the response has
+/// no utterances (speaker labels off, or silence).
+fn format_speaker_text(json: &serde_json::Value) -> Option<String> {
+    let lines: Vec<String> = json
+        .get("utterances")
+        .and_then(|v| v.as_array())?
+        .iter()
+        .filter_map(|utterance| {
+            let speaker = utterance.get("speaker").and_then(|v| v.as_str())?;
+            let text = utterance.get("text").and_then(|v| v.as_str())?.trim();
+            (!text.is_empty()).then(|| format!("Speaker {}: {}", speaker, text))
+        })
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+fn parse_assemblyai_word(w: &serde_json::Value) -> Option<crate::transcription::TranscriptionWord> {
+    let text = w.get("text").and_then(|v| v.as_str())?.to_string();
+    let start_ms = w.get("start").and_then(|v| v.as_u64());
+    let end_ms = w.get("end").and_then(|v| v.as_u64());
+    let speaker_id = w
+        .get("speaker")
+        .and_then(|v| v.as_str())
+        .map(|s| format!("Speaker {s}"));
+    let confidence = w
+        .get("confidence")
+        .and_then(|v| v.as_f64())
+        .map(|c| c as f32);
+    Some(crate::transcription::TranscriptionWord {
+        text,
+        start_ms,
+        end_ms,
+        speaker_id,
+        confidence,
+    })
+}
+
+pub(super) async fn transcribe_typed(
+    app: &AppHandle,
+    base_url: &str,
+    key: &str,
+    wav_path: &Path,
+    language: Option<&str>,
+    extra: &common::ExtraParams,
+) -> Result<String, common::SttError> {
+    let speaker_labels = speaker_labels_enabled(app);
+    let json = transcribe_at(base_url, key, wav_path, language, speaker_labels, extra).await?;
+    if speaker_labels {
+        if let Some(text) = format_speaker_text(&json) {
+            return Ok(text);
+        }
+    }
+    json.get("text")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .ok_or(common::SttError::BadResponse)
+}
+
+pub(super) async fn transcribe_typed_diarized(
+    base_url: &str,
+    key: &str,
+    wav_path: &Path,
+    language: Option<&str>,
+    extra: &common::ExtraParams,
+) -> Result<super::CloudTranscript, common::SttError> {
+    let json = transcribe_at(base_url, key, wav_path, language, true, extra).await?;
+    let text = json
+        .get("text")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .ok_or(common::SttError::BadResponse)?;
+    let words = json
+        .get("words")
+        .and_then(|v| v.as_array())
+        .map(|words| words.iter().filter_map(parse_assemblyai_word).collect())
+        .unwrap_or_default();
+    Ok(super::CloudTranscript { text, words })
+}
+
+/// Upload `wav_path`, start a transcript and poll it to completion, returning
+/// the completed transcript JSON.
+async fn transcribe_at(
+    base_url: &str,
+    key: &str,
+    wav_path: &Path,
+    language: Option<&str>,
+    speaker_labels: bool,
+    extra: &common::ExtraParams,
+) -> Result<serde_json::Value, common::SttError> {
+    use tokio::fs;
+
+    let wav_bytes = fs::read(wav_path)
+        .await
+        .map_err(|_| common::SttError::BadResponse)?;
+
+    let client = common::http_client();
+
+    // 1) Upload raw audio -> upload_url
+    let upload_endpoint = format!("{}/upload", base_url);
+    let upload_resp = common::with_retry(|| {
+        let client = client.clone();
+        let upload_endpoint = upload_endpoint.clone();
+        let wav_bytes = wav_bytes.clone();
+        async move {
+            let resp = client
+                .post(&upload_endpoint)
+                .header("Authorization", key)
+                .header("Content-Type", "application/octet-stream")
+                .body(wav_bytes)
+                .send()
+                .await
+                .map_err(|e| common::classify_reqwest_err(&e))?;
+            if resp.status().is_success() {
+                Ok(resp)
+            } else {
+                Err(common::log_http_body(resp, "AssemblyAI upload").await)
+            }
+        }
+    })
+    .await?;
+    let upload_json: serde_json::Value = upload_resp
+        .json()
+        .await
+        .map_err(|_| common::SttError::BadResponse)?;
+    let audio_url = upload_json
+        .get("upload_url")
+        .and_then(|v| v.as_str())
+        .ok_or(common::SttError::BadResponse)?
+        .to_string();
+
+    // 2) Create transcript -> transcript id
+    let mut payload = build_create_payload(&audio_url, language, speaker_labels);
+    merge_extra_params(&mut payload, extra);
+
+    let create_url = format!("{}/transcript", base_url);
+    let create_resp = common::with_retry(|| {
+        let client = client.clone();
+        let create_url = create_url.clone();
+        let payload = payload.clone();
+        async move {
+            let resp = client
+                .post(&create_url)
+                .header("Authorization", key)
+                .json(&payload)
+                .send()
+                .await
+                .map_err(|e| common::classify_reqwest_err(&e))?;
+            if resp.status().is_success() {
+                Ok(resp)
+            } else {
+                Err(common::log_http_body(resp, "AssemblyAI create transcript").await)
+            }
+        }
+    })
+    .await?;
+    let create_json: serde_json::Value = create_resp
+        .json()
+        .await
+        .map_err(|_| common::SttError::BadResponse)?;
+    let transcript_id = create_json
+        .get("id")
+        .and_then(|v| v.as_str())
+        .ok_or(common::SttError::BadResponse)?
+        .to_string();
+
+    // 3) Poll until the transcript completes; the final poll carries the result
+    let status_url = format!("{}/transcript/{}", base_url, transcript_id);
+    let started = std::time::Instant::now();
+    loop {
+        let resp = common::with_retry(|| {
+            let client = client.clone();
+            let status_url = status_url.clone();
+            async move {
+                let resp = client
+                    .get(&status_url)
+                    .header("Authorization", key)
+                    .send()
+                    .await
+                    .map_err(|e| common::classify_reqwest_err(&e))?;
+                if resp.status().is_success() {
+                    Ok(resp)
+                } else {
+                    Err(common::log_http_body(resp, "AssemblyAI status").await)
+                }
+            }
+        })
+        .await?;
+        let json: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|_| common::SttError::BadResponse)?;
+        match json.get("status").and_then(|v| v.as_str()).unwrap_or("") {
+            "completed" => return Ok(json),
+            "error" => {
+                let message = json
+                    .get("error")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default();
+                let snippet: String = message.chars().take(500).collect();
+                log::warn!("AssemblyAI transcript failed: {snippet}");
+                return Err(common::SttError::Server);
+            }
+            _ => {
+                if started.elapsed() > POLL_TIMEOUT {
+                    return Err(common::SttError::Timeout);
+                }
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn audio_file() -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"wav").unwrap();
+        file
+    }
+
+    #[test]
+    fn create_payload_sets_language_or_detection_and_speaker_labels() {
+        let payload = build_create_payload("https://cdn/u1", Some(" en "), true);
+        assert_eq!(payload["audio_url"], "https://cdn/u1");
+        assert_eq!(payload["speech_model"], MODEL);
+        assert_eq!(payload["language_code"], "en");
+        assert_eq!(payload["speaker_labels"].as_bool(), Some(true));
+        assert!(payload.get("language_detection").is_none());
+
+        let payload = build_create_payload("https://cdn/u1", None, false);
+        assert_eq!(payload["language_detection"].as_bool(), Some(true));
+        assert!(payload.get("language_code").is_none());
+        assert!(payload.get("speaker_labels").is_none());
+    }
+
+    #[test]
+    fn format_speaker_text_prefixes_each_utterance() {
+        let json = serde_json::json!({
+            "text": "Hi there. Hello.",
+            "utterances": [
+                { "speaker": "A", "text": "Hi there." },
+                { "speaker": "B", "text": " Hello. " }
+            ]
+        });
+        assert_eq!(
+            format_speaker_text(&json).as_deref(),
+            Some("Speaker A: Hi there.\nSpeaker B: Hello.")
+        );
+        assert_eq!(
+            format_speaker_text(&serde_json::json!({ "text": "x" })),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn transcribe_at_uploads_creates_and_polls_with_raw_auth() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/upload"))
+            .and(header("authorization", "k"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "upload_url": "https://cdn/u1" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/transcript"))
+            .and(header("authorization", "k"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "id": "t1", "status": "queued" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/transcript/t1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "t1",
+                "status": "completed",
+                "text": "Hi there.",
+                "words": [
+                    { "text": "Hi", "start": 0, "end": 240, "confidence": 0.98, "speaker": "A" }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let audio = audio_file();
+
+        let json = transcribe_at(
+            &server.uri(),
+            "k",
+            audio.path(),
+            Some("en"),
+            true,
+            &common::ExtraParams::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(json["text"], "Hi there.");
+        let word = parse_assemblyai_word(&json["words"][0]).unwrap();
+        assert_eq!(word.speaker_id.as_deref(), Some("Speaker A"));
+        assert_eq!(word.end_ms, Some(240));
+
+        let requests = server.received_requests().await.unwrap();
+        let create: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(create["audio_url"], "https://cdn/u1");
+        assert_eq!(create["speaker_labels"].as_bool(), Some(true));
+    }
+
+    #[tokio::test]
+    async fn transcribe_at_maps_failed_job_to_server_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/upload"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "upload_url": "https://cdn/u1" })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/transcript"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": "t1" })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/transcript/t1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "error",
+                "error": "Audio file is empty"
+            })))
+            .mount(&server)
+            .await;
+        let audio = audio_file();
+
+        let error = transcribe_at(
+            &server.uri(),
+            "k",
+            audio.path(),
+            None,
+            false,
+            &common::ExtraParams::new(),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error, common::SttError::Server));
+    }
+}
@@ -9,9 +9,9 @@ use tauri::AppHandle;
 
 pub(super) const MODEL: &str = "cohere-transcribe-03-2026";
 
-pub(super) async fn validate_key(key: &str) -> Result<(), String> {
+pub(super) async fn validate_key(base_url: &str, key: &str) -> Result<(), String> {
     common::get_validate(
-        "https://api.cohere.com/v1/models",
+        &format!("{}/v1/models", base_url),
         AuthScheme::Bearer,
         key,
         "Cohere",
@@ -22,11 +22,13 @@ pub(super) async fn validate_key(key: &str) -> Result<(), String> {
 
 pub(super) async fn transcribe_typed(
     _app: &AppHandle,
+    base_url: &str,
     key: &str,
     audio_path: &Path,
     language: Option<&str>,
+    extra: &common::ExtraParams,
 ) -> Result<String, common::SttError> {
-    transcribe_at("https://api.cohere.com", key, audio_path, language).await
+    transcribe_at(base_url, key, audio_path, language, extra).await
 }
 
 pub(super) async fn transcribe_at(
@@ -34,6 +36,7 @@ pub(super) async fn transcribe_at(
     key: &str,
     audio_path: &Path,
     language: Option<&str>,
+    extra: &common::ExtraParams,
 ) -> Result<String, common::SttError> {
     use reqwest::multipart::{Form, Part};
     use tokio::fs;
@@ -53,6 +56,7 @@ pub(super) async fn transcribe_at(
         .filter(|lang| !lang.is_empty())
         .unwrap_or("en")
         .to_string();
+    let extra_pairs = common::extra_param_pairs(extra);
     let url = format!("{}/v2/audio/transcriptions", base_url);
 
     let client = common::http_client();
@@ -61,16 +65,20 @@ pub(super) async fn transcribe_at(
         let client = client.clone();
         let filename = filename.clone();
         let lang = lang.clone();
+        let extra_pairs = extra_pairs.clone();
         let url = url.clone();
         async move {
             let file_part = Part::bytes(bytes)
                 .file_name(filename)
                 .mime_str("audio/wav")
                 .map_err(|_| common::SttError::BadResponse)?;
-            let form = Form::new()
+            let mut form = Form::new()
                 .part("file", file_part)
                 .text("model", MODEL)
                 .text("language", lang);
+            for (key, value) in extra_pairs {
+                form = form.text(key, value);
+            }
 
             let resp = client
                 .post(&url)
@@ -114,9 +122,15 @@ mod tests {
             .await;
         let audio = audio_file();
 
-        let text = transcribe_at(&server.uri(), "k", audio.path(), None)
-            .await
-            .unwrap();
+        let text = transcribe_at(
+            &server.uri(),
+            "k",
+            audio.path(),
+            None,
+            &common::ExtraParams::new(),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(text, "ok");
         let requests = server.received_requests().await.unwrap();
@@ -143,9 +157,15 @@ mod tests {
             .await;
         let audio = audio_file();
 
-        let error = transcribe_at(&server.uri(), "k", audio.path(), None)
-            .await
-            .unwrap_err();
+        let error = transcribe_at(
+            &server.uri(),
+            "k",
+            audio.path(),
+            None,
+            &common::ExtraParams::new(),
+        )
+        .await
+        .unwrap_err();
 
         assert!(matches!(error, common::SttError::Auth));
         assert_eq!(server.received_requests().await.unwrap().len(), 1);
@@ -9,6 +9,8 @@ pub(super) enum AuthScheme {
     Bearer,
     /// `Authorization: Token <key>` (Deepgram)
     Token,
+    /// `Authorization: <key>` (AssemblyAI)
+    Raw,
 }
 
 #[derive(Debug)]
@@ -20,6 +22,8 @@ pub(crate) enum SttError {
     Network,
     Server,
     BadResponse,
+    /// The user cancelled the recording while the request was in flight.
+    Cancelled,
 }
 
 impl SttError {
@@ -37,6 +41,7 @@ impl SttError {
             Self::Network => format!("Network error reaching {}", provider_name),
             Self::Server => format!("{} service error. Try again shortly.", provider_name),
             Self::BadResponse => format!("{}: unexpected response", provider_name),
+            Self::Cancelled => format!("{} transcription cancelled", provider_name),
         }
     }
 }
@@ -215,6 +220,7 @@ pub(super) async fn get_validate(
                 AuthScheme::Token => client
                     .get(url)
                     .header("Authorization", format!("Token {}", key)),
+                AuthScheme::Raw => client.get(url).header("Authorization", key),
             };
             let resp = req.send().await.map_err(|e| classify_reqwest_err(&e))?;
             if resp.status().is_success() {
@@ -243,6 +249,43 @@ fn cap_transcription_prompt(prompt: &str) -> String {
     prompt[..cut].trim_end_matches([',', ' ']).to_string()
 }
 
+/// User-supplied request parameters merged into a provider request.
+pub type ExtraParams = serde_json::Map<String, serde_json::Value>;
+
+/// Flatten scalar extra params into form/query pairs. Strings are sent as-is;
+/// numbers and booleans use their JSON spelling. Non-scalars are rejected at
+/// validation time for providers that use this.
+pub(super) fn extra_param_pairs(extra: &ExtraParams) -> Vec<(String, String)> {
+    extra
+        .iter()
+        .filter_map(|(key, value)| match value {
+            serde_json::Value::String(s) => Some((key.clone(), s.clone())),
+            serde_json::Value::Number(_) | serde_json::Value::Bool(_) => {
+                Some((key.clone(), value.to_string()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// `response_format` requested from an OpenAI-compatible audio endpoint. Both
+/// return the transcript in `text`; `VerboseJson` also carries timed segments
+/// but is only served by `whisper-1`-class models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ResponseFormat {
+    Json,
+    VerboseJson,
+}
+
+impl ResponseFormat {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::VerboseJson => "verbose_json",
+        }
+    }
+}
+
 /// Transcribe via an OpenAI-compatible multipart `/audio/transcriptions`
 /// endpoint (OpenAI, Groq). `base_url` excludes the trailing path.
 pub(super) async fn openai_compatible_transcribe(
@@ -253,6 +296,35 @@ pub(super) async fn openai_compatible_transcribe(
     language: Option<&str>,
     prompt: Option<&str>,
     label: &str,
+    extra: &ExtraParams,
+) -> Result<String, SttError> {
+    openai_compatible_audio_request(
+        &format!("{}/audio/transcriptions", base_url),
+        key,
+        model,
+        ResponseFormat::Json,
+        audio_path,
+        language,
+        prompt,
+        label,
+        extra,
+    )
+    .await
+}
+
+/// Post `audio_path` as multipart to an OpenAI-compatible audio `url`
+/// (`/audio/transcriptions` or `/audio/translations`) and return its `text`.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn openai_compatible_audio_request(
+    url: &str,
+    key: &str,
+    model: &str,
+    response_format: ResponseFormat,
+    audio_path: &Path,
+    language: Option<&str>,
+    prompt: Option<&str>,
+    label: &str,
+    extra: &ExtraParams,
 ) -> Result<String, SttError> {
     use futures_util::stream;
     use reqwest::{
@@ -279,14 +351,16 @@ pub(super) async fn openai_compatible_transcribe(
         .map(str::trim)
         .filter(|prompt| !prompt.is_empty())
         .map(cap_transcription_prompt);
+    let extra_pairs = extra_param_pairs(extra);
     let client = http_client();
-    let url = format!("{}/audio/transcriptions", base_url);
+    let url = url.to_string();
 
     with_retry(|| {
         let client = client.clone();
         let filename = filename.clone();
         let language = language.clone();
         let prompt = prompt.clone();
+        let extra_pairs = extra_pairs.clone();
         let url = url.clone();
         async move {
             let file = File::open(audio_path)
@@ -309,13 +383,16 @@ pub(super) async fn openai_compatible_transcribe(
             let mut form = Form::new()
                 .part("file", file_part)
                 .text("model", model.to_string())
-                .text("response_format", "json".to_string());
+                .text("response_format", response_format.as_str().to_string());
             if let Some(lang) = language {
                 form = form.text("language", lang);
             }
             if let Some(prompt) = prompt {
                 form = form.text("prompt", prompt);
             }
+            for (key, value) in extra_pairs {
+                form = form.text(key, value);
+            }
 
             let resp = client
                 .post(&url)
@@ -333,7 +410,10 @@ pub(super) async fn openai_compatible_transcribe(
 
 #[cfg(test)]
 mod tests {
-    use super::{get_validate, openai_compatible_transcribe, warm_origin, AuthScheme, SttError};
+    use super::{
+        get_validate, openai_compatible_audio_request, openai_compatible_transcribe, warm_origin,
+        AuthScheme, ExtraParams, ResponseFormat, SttError,
+    };
     use std::io::Write;
     use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::Arc;
@@ -382,6 +462,26 @@ mod tests {
             .await;
     }
 
+    #[test]
+    fn extra_param_pairs_spells_scalars_and_skips_structures() {
+        let extra = serde_json::json!({
+            "temperature": 0.2,
+            "punctuate": true,
+            "tag": "team-a",
+            "nested": { "x": 1 }
+        });
+        let mut pairs = super::extra_param_pairs(extra.as_object().unwrap());
+        pairs.sort();
+        assert_eq!(
+            pairs,
+            vec![
+                ("punctuate".to_string(), "true".to_string()),
+                ("tag".to_string(), "team-a".to_string()),
+                ("temperature".to_string(), "0.2".to_string()),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn openai_compatible_transcribe_posts_multipart_and_parses_text() {
         let server = MockServer::start().await;
@@ -404,6 +504,7 @@ mod tests {
             None,
             None,
             "OpenAI transcription",
+            &ExtraParams::new(),
         )
         .await
         .unwrap();
@@ -451,6 +552,7 @@ mod tests {
             None,
             Some(prompt),
             "OpenAI transcription",
+            &ExtraParams::new(),
         )
         .await
         .unwrap();
@@ -484,6 +586,7 @@ mod tests {
             None,
             Some("   "),
             "OpenAI transcription",
+            &ExtraParams::new(),
         )
         .await
         .unwrap();
@@ -496,6 +599,7 @@ mod tests {
             None,
             None,
             "OpenAI transcription",
+            &ExtraParams::new(),
         )
         .await
         .unwrap();
@@ -506,6 +610,44 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn openai_compatible_audio_request_sends_verbose_json_to_the_given_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/audio/translations"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "task": "translate",
+                "language": "english",
+                "text": "hello",
+                "segments": [{ "id": 0, "start": 0.0, "end": 1.0, "text": "hello" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let audio = audio_file();
+
+        let text = openai_compatible_audio_request(
+            &format!("{}/audio/translations", server.uri()),
+            "k",
+            "whisper-1",
+            ResponseFormat::VerboseJson,
+            audio.path(),
+            None,
+            None,
+            "OpenAI translation",
+            &ExtraParams::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(text, "hello");
+        let request = &server.received_requests().await.unwrap()[0];
+        let body = String::from_utf8_lossy(&request.body);
+        assert!(body.contains("verbose_json"));
+        assert!(body.contains("whisper-1"));
+        assert!(!body.contains("name=\"language\""));
+    }
+
     #[tokio::test]
     async fn openai_compatible_transcribe_retries_500_once_then_succeeds() {
         let server = MockServer::start().await;
@@ -529,6 +671,7 @@ mod tests {
             None,
             None,
             "OpenAI transcription",
+            &ExtraParams::new(),
         )
         .await
         .unwrap();
@@ -571,6 +714,7 @@ mod tests {
             None,
             None,
             "OpenAI transcription",
+            &ExtraParams::new(),
         )
         .await
         .unwrap();
@@ -600,6 +744,7 @@ mod tests {
             None,
             None,
             "OpenAI transcription",
+            &ExtraParams::new(),
         )
         .await
         .unwrap_err();
@@ -629,6 +774,7 @@ mod tests {
             None,
             None,
             "OpenAI transcription",
+            &ExtraParams::new(),
         )
         .await
         .unwrap_err();
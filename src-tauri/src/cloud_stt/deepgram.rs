@@ -11,9 +11,9 @@ use tauri::AppHandle;
 pub(super) const MODEL: &str = "nova-3";
 
 // /v1/auth/token validates any key; /v1/projects needs `project:read`, absent on default tokens.
-pub(super) async fn validate_key(key: &str) -> Result<(), String> {
+pub(super) async fn validate_key(base_url: &str, key: &str) -> Result<(), String> {
     common::get_validate(
-        "https://api.deepgram.com/v1/auth/token",
+        &format!("{}/v1/auth/token", base_url),
         AuthScheme::Token,
         key,
         "Deepgram",
@@ -73,20 +73,14 @@ fn compile_keyterms(app: &AppHandle, language: Option<&str>) -> Vec<String> {
 
 pub(super) async fn transcribe_typed(
     app: &AppHandle,
+    base_url: &str,
     key: &str,
     audio_path: &Path,
     language: Option<&str>,
+    extra: &common::ExtraParams,
 ) -> Result<String, common::SttError> {
     let keyterms = compile_keyterms(app, language);
-    transcribe_at(
-        "https://api.deepgram.com",
-        key,
-        audio_path,
-        language,
-        MODEL,
-        &keyterms,
-    )
-    .await
+    transcribe_at(base_url, key, audio_path, language, MODEL, &keyterms, extra).await
 }
 
 pub(super) async fn transcribe_at(
@@ -96,6 +90,7 @@ pub(super) async fn transcribe_at(
     language: Option<&str>,
     model: &str,
     keyterms: &[String],
+    extra: &common::ExtraParams,
 ) -> Result<String, common::SttError> {
     use tokio::fs;
 
@@ -104,6 +99,7 @@ pub(super) async fn transcribe_at(
         .map_err(|_| common::SttError::BadResponse)?;
 
     let params = build_listen_params(model, language, keyterms, false);
+    let extra_params = common::extra_param_pairs(extra);
     let endpoint = format!("{}/v1/listen", base_url);
 
     let client = common::http_client();
@@ -111,11 +107,13 @@ pub(super) async fn transcribe_at(
         let body = bytes.clone();
         let client = client.clone();
         let params = params.clone();
+        let extra_params = extra_params.clone();
         let endpoint = endpoint.clone();
         async move {
             let resp = client
                 .post(&endpoint)
                 .query(&params)
+                .query(&extra_params)
                 .header("Authorization", format!("Token {}", key))
                 .header("Content-Type", "audio/wav")
                 .body(body)
@@ -142,20 +140,14 @@ pub(super) async fn transcribe_at(
 
 pub(super) async fn transcribe_typed_diarized(
     app: &AppHandle,
+    base_url: &str,
     key: &str,
     audio_path: &Path,
     language: Option<&str>,
+    extra: &common::ExtraParams,
 ) -> Result<super::CloudTranscript, common::SttError> {
     let keyterms = compile_keyterms(app, language);
-    transcribe_at_diarized(
-        "https://api.deepgram.com",
-        key,
-        audio_path,
-        language,
-        MODEL,
-        &keyterms,
-    )
-    .await
+    transcribe_at_diarized(base_url, key, audio_path, language, MODEL, &keyterms, extra).await
 }
 
 pub(super) async fn transcribe_at_diarized(
@@ -165,6 +157,7 @@ pub(super) async fn transcribe_at_diarized(
     language: Option<&str>,
     model: &str,
     keyterms: &[String],
+    extra: &common::ExtraParams,
 ) -> Result<super::CloudTranscript, common::SttError> {
     use tokio::fs;
 
@@ -173,6 +166,7 @@ pub(super) async fn transcribe_at_diarized(
         .map_err(|_| common::SttError::BadResponse)?;
 
     let params = build_listen_params(model, language, keyterms, true);
+    let extra_params = common::extra_param_pairs(extra);
     let endpoint = format!("{}/v1/listen", base_url);
 
     let client = common::http_client();
@@ -180,11 +174,13 @@ pub(super) async fn transcribe_at_diarized(
         let body = bytes.clone();
         let client = client.clone();
         let params = params.clone();
+        let extra_params = extra_params.clone();
         let endpoint = endpoint.clone();
         async move {
             let resp = client
                 .post(&endpoint)
                 .query(&params)
+                .query(&extra_params)
                 .header("Authorization", format!("Token {}", key))
                 .header("Content-Type", "audio/wav")
                 .body(body)
@@ -292,6 +288,7 @@ mod tests {
             Some("en"),
             super::MODEL,
             &[],
+            &common::ExtraParams::new(),
         )
         .await
         .unwrap();
@@ -317,9 +314,17 @@ mod tests {
             .await;
         let audio = audio_file();
 
-        let error = transcribe_at(&server.uri(), "k", audio.path(), None, super::MODEL, &[])
-            .await
-            .unwrap_err();
+        let error = transcribe_at(
+            &server.uri(),
+            "k",
+            audio.path(),
+            None,
+            super::MODEL,
+            &[],
+            &common::ExtraParams::new(),
+        )
+        .await
+        .unwrap_err();
 
         assert!(matches!(error, common::SttError::Auth));
         assert_eq!(server.received_requests().await.unwrap().len(), 1);
@@ -347,6 +352,7 @@ mod tests {
             Some("en"),
             super::MODEL,
             &keyterms,
+            &common::ExtraParams::new(),
         )
         .await
         .unwrap();
@@ -393,6 +399,7 @@ mod tests {
             Some("en"),
             "nova-2",
             &keyterms,
+            &common::ExtraParams::new(),
         )
         .await
         .unwrap();
@@ -436,6 +443,7 @@ mod tests {
             Some("en"),
             super::MODEL,
             &keyterms,
+            &common::ExtraParams::new(),
         )
         .await
         .unwrap();
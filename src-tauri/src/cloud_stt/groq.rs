@@ -6,11 +6,9 @@ use tauri::AppHandle;
 
 pub(super) const MODEL: &str = "whisper-large-v3-turbo";
 
-const BASE: &str = "https://api.groq.com/openai/v1";
-
-pub(super) async fn validate_key(key: &str) -> Result<(), String> {
+pub(super) async fn validate_key(base_url: &str, key: &str) -> Result<(), String> {
     common::get_validate(
-        "https://api.groq.com/openai/v1/models",
+        &format!("{}/models", base_url),
         AuthScheme::Bearer,
         key,
         "Groq",
@@ -21,21 +19,24 @@ pub(super) async fn validate_key(key: &str) -> Result<(), String> {
 
 pub(super) async fn transcribe_typed(
     app: &AppHandle,
+    base_url: &str,
     key: &str,
     audio_path: &Path,
     language: Option<&str>,
+    extra: &common::ExtraParams,
 ) -> Result<String, common::SttError> {
     // The personal dictionary is reused as the recognizer's initial prompt so
     // jargon/brand names are reconciled against the audio at recognition time.
     let prompt = crate::commands::audio::compile_remote_request_context(app, language);
     common::openai_compatible_transcribe(
-        BASE,
+        base_url,
         key,
         MODEL,
         audio_path,
         language,
         prompt.as_deref(),
         "Groq transcription",
+        extra,
     )
     .await
 }
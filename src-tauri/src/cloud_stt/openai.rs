@@ -1,16 +1,49 @@
-//! OpenAI cloud STT via the OpenAI-compatible `/v1/audio/transcriptions`.
+//! OpenAI cloud STT via the OpenAI-compatible `/v1/audio/transcriptions`, and
+//! `/v1/audio/translations` for the translate-to-English task.
 
-use super::common::{self, AuthScheme};
+use super::common::{self, AuthScheme, ResponseFormat};
 use std::path::Path;
 use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
 
 pub(super) const MODEL: &str = "gpt-4o-transcribe";
+/// The only OpenAI model served by `/audio/translations`, and the only one
+/// that returns `verbose_json` segments.
+pub(super) const WHISPER_MODEL: &str = "whisper-1";
+/// Transcription models the user can pick between (settings `openai_stt_model`).
+pub(super) const MODELS: &[&str] = &[MODEL, WHISPER_MODEL];
 
-const BASE: &str = "https://api.openai.com/v1";
+/// Map a stored model id onto a supported one, falling back to [`MODEL`].
+pub(crate) fn normalize_model(value: &str) -> &'static str {
+    let value = value.trim();
+    MODELS
+        .iter()
+        .copied()
+        .find(|model| model.eq_ignore_ascii_case(value))
+        .unwrap_or(MODEL)
+}
+
+fn selected_model(app: &AppHandle) -> &'static str {
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get("openai_stt_model"))
+        .and_then(|value| value.as_str().map(normalize_model))
+        .unwrap_or(MODEL)
+}
+
+/// `gpt-4o-*` models only answer with plain `json`; `whisper-1` gets
+/// `verbose_json` so its segments are available.
+fn response_format(model: &str) -> ResponseFormat {
+    if model == WHISPER_MODEL {
+        ResponseFormat::VerboseJson
+    } else {
+        ResponseFormat::Json
+    }
+}
 
-pub(super) async fn validate_key(key: &str) -> Result<(), String> {
+pub(super) async fn validate_key(base_url: &str, key: &str) -> Result<(), String> {
     common::get_validate(
-        "https://api.openai.com/v1/models",
+        &format!("{}/models", base_url),
         AuthScheme::Bearer,
         key,
         "OpenAI",
@@ -21,21 +54,69 @@ pub(super) async fn validate_key(key: &str) -> Result<(), String> {
 
 pub(super) async fn transcribe_typed(
     app: &AppHandle,
+    base_url: &str,
     key: &str,
     audio_path: &Path,
     language: Option<&str>,
+    extra: &common::ExtraParams,
 ) -> Result<String, common::SttError> {
     // The personal dictionary is reused as the recognizer's initial prompt so
     // jargon/brand names are reconciled against the audio at recognition time.
     let prompt = crate::commands::audio::compile_remote_request_context(app, language);
-    common::openai_compatible_transcribe(
-        BASE,
+    let model = selected_model(app);
+    common::openai_compatible_audio_request(
+        &format!("{}/audio/transcriptions", base_url),
         key,
-        MODEL,
+        model,
+        response_format(model),
         audio_path,
         language,
         prompt.as_deref(),
         "OpenAI transcription",
+        extra,
+    )
+    .await
+}
+
+/// Transcribe `audio_path` straight into English text. The endpoint detects
+/// the spoken language itself and takes no `language` field.
+pub(super) async fn translate_typed(
+    app: &AppHandle,
+    base_url: &str,
+    key: &str,
+    audio_path: &Path,
+    extra: &common::ExtraParams,
+) -> Result<String, common::SttError> {
+    let prompt = crate::commands::audio::compile_remote_request_context(app, Some("en"));
+    common::openai_compatible_audio_request(
+        &format!("{}/audio/translations", base_url),
+        key,
+        WHISPER_MODEL,
+        ResponseFormat::VerboseJson,
+        audio_path,
+        None,
+        prompt.as_deref(),
+        "OpenAI translation",
+        extra,
     )
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_model_accepts_known_models_and_falls_back() {
+        assert_eq!(normalize_model("whisper-1"), WHISPER_MODEL);
+        assert_eq!(normalize_model(" GPT-4o-Transcribe "), MODEL);
+        assert_eq!(normalize_model("whisper-large"), MODEL);
+        assert_eq!(normalize_model(""), MODEL);
+    }
+
+    #[test]
+    fn only_whisper_1_requests_verbose_json() {
+        assert_eq!(response_format(WHISPER_MODEL), ResponseFormat::VerboseJson);
+        assert_eq!(response_format(MODEL), ResponseFormat::Json);
+    }
+}
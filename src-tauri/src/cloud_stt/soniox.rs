@@ -2,21 +2,210 @@
 
 use super::common::{self, AuthScheme};
 use std::path::Path;
-use tauri::AppHandle;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
 
 pub(super) const MODEL: &str = "stt-async-v5";
 
-const BASE: &str = "https://api.soniox.com/v1";
+/// Hard ceiling for creating and polling a job, rate-limit waits included.
+const JOB_TIMEOUT: Duration = Duration::from_secs(180);
+/// Most retries of a create or poll call answered with HTTP 429.
+const RATE_LIMIT_MAX_RETRIES: u32 = 3;
+/// Longest `Retry-After` honored; larger values are capped.
+const RATE_LIMIT_MAX_WAIT: Duration = Duration::from_secs(30);
+/// Wait used when a 429 has no usable `Retry-After`.
+const RATE_LIMIT_DEFAULT_WAIT: Duration = Duration::from_secs(1);
+/// Pause between job status polls.
+const POLL_INTERVAL: Duration = Duration::from_millis(1000);
+/// How often an in-flight request or poll wait checks for cancellation.
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Wait for a 429 from its `Retry-After` header (delay-seconds form).
+fn retry_after_wait(header: Option<&str>) -> Duration {
+    header
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(RATE_LIMIT_DEFAULT_WAIT)
+        .min(RATE_LIMIT_MAX_WAIT)
+}
+
+/// Send a create/poll request, waiting out HTTP 429 per `Retry-After` up to
+/// [`RATE_LIMIT_MAX_RETRIES`] times as long as the wait ends before
+/// `deadline`. Other failures keep the single transient retry of
+/// [`common::with_retry`]. `on_rate_limited` gets each wait before it starts.
+async fn send_rate_limited<F>(
+    label: &str,
+    deadline: Instant,
+    mut request: F,
+    on_rate_limited: impl Fn(Duration),
+) -> Result<reqwest::Response, common::SttError>
+where
+    F: FnMut() -> reqwest::RequestBuilder,
+{
+    let mut rate_limit_retries = 0;
+    let mut retried_server_error = false;
+    loop {
+        let resp = common::with_retry(|| {
+            let send = request().send();
+            async move { send.await.map_err(|e| common::classify_reqwest_err(&e)) }
+        })
+        .await?;
+        let status = resp.status();
+        if status.is_success() {
+            return Ok(resp);
+        }
+
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            && rate_limit_retries < RATE_LIMIT_MAX_RETRIES
+        {
+            let wait = retry_after_wait(
+                resp.headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+            );
+            if Instant::now() + wait < deadline {
+                rate_limit_retries += 1;
+                log::warn!(
+                    "{label}: rate limited, retrying in {}ms ({}/{})",
+                    wait.as_millis(),
+                    rate_limit_retries,
+                    RATE_LIMIT_MAX_RETRIES
+                );
+                on_rate_limited(wait);
+                tokio::time::sleep(wait).await;
+                continue;
+            }
+        }
+
+        let err = common::log_http_body(resp, label).await;
+        if matches!(err, common::SttError::Server) && !retried_server_error {
+            retried_server_error = true;
+            tokio::time::sleep(Duration::from_millis(400)).await;
+            continue;
+        }
+        return Err(err);
+    }
+}
+
+/// Tell the pill that Soniox is throttling rather than failing.
+fn notify_rate_limited(app: &AppHandle, wait: Duration) {
+    let _ = crate::emit_to_window(
+        app,
+        "pill",
+        "transcription-rate-limited",
+        serde_json::json!({ "provider": "soniox", "retry_in_ms": wait.as_millis() as u64 }),
+    );
+    crate::commands::audio::pill_toast(
+        app,
+        &format!("Soniox is busy, retrying in {}s", wait.as_secs().max(1)),
+        wait.as_millis().clamp(1500, 5000) as u64,
+    );
+}
+
+/// Whether the user cancelled the recording this job belongs to.
+fn cancellation_requested(app: &AppHandle) -> bool {
+    app.try_state::<crate::AppState>()
+        .is_some_and(|state| state.is_cancellation_requested())
+}
+
+/// Drive `fut` to completion unless `is_cancelled` turns true first, checked
+/// every [`CANCEL_CHECK_INTERVAL`]. On cancellation the future is dropped,
+/// aborting whatever request it has in flight.
+async fn abortable<T>(
+    fut: impl std::future::Future<Output = Result<T, common::SttError>>,
+    is_cancelled: &impl Fn() -> bool,
+) -> Result<T, common::SttError> {
+    if is_cancelled() {
+        return Err(common::SttError::Cancelled);
+    }
+    tokio::pin!(fut);
+    loop {
+        tokio::select! {
+            result = &mut fut => return result,
+            _ = tokio::time::sleep(CANCEL_CHECK_INTERVAL) => {
+                if is_cancelled() {
+                    return Err(common::SttError::Cancelled);
+                }
+            }
+        }
+    }
+}
+
+/// Poll a transcription job until it completes, fails, passes `deadline` or
+/// is cancelled.
+async fn poll_until_complete(
+    client: &reqwest::Client,
+    status_url: &str,
+    key: &str,
+    deadline: Instant,
+    label: &str,
+    is_cancelled: &impl Fn() -> bool,
+    on_rate_limited: impl Fn(Duration),
+) -> Result<(), common::SttError> {
+    loop {
+        let status_request = send_rate_limited(
+            label,
+            deadline,
+            || client.get(status_url).bearer_auth(key),
+            &on_rate_limited,
+        );
+        let resp = abortable(status_request, is_cancelled).await?;
+        let json: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|_| common::SttError::BadResponse)?;
+        let status = json.get("status").and_then(|v| v.as_str()).unwrap_or("");
+        match status {
+            "completed" => return Ok(()),
+            "error" => {
+                log::warn!("{label}: transcription job failed");
+                return Err(common::SttError::Server);
+            }
+            _ => {
+                if Instant::now() > deadline {
+                    return Err(common::SttError::Timeout);
+                }
+                let wait = async {
+                    tokio::time::sleep(POLL_INTERVAL).await;
+                    Ok(())
+                };
+                abortable(wait, is_cancelled).await?;
+            }
+        }
+    }
+}
 
-pub(super) async fn validate_key(key: &str) -> Result<(), String> {
+/// Best-effort delete of a cancelled job so Soniox stops working on it.
+fn discard_transcription(
+    client: &reqwest::Client,
+    base_url: &str,
+    key: &str,
+    transcription_id: &str,
+) {
+    let request = client
+        .delete(format!("{}/transcriptions/{}", base_url, transcription_id))
+        .bearer_auth(key);
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = request.send().await {
+            log::debug!("Soniox: failed to delete cancelled transcription: {e}");
+        }
+    });
+}
+
+pub(super) async fn validate_key(base_url: &str, key: &str) -> Result<(), String> {
+    validate_key_typed(base_url, key)
+        .await
+        .map_err(|e| e.message("Soniox"))
+}
+
+pub(super) async fn validate_key_typed(base_url: &str, key: &str) -> Result<(), common::SttError> {
     common::get_validate(
-        "https://api.soniox.com/v1/models",
+        &format!("{}/models", base_url),
         AuthScheme::Bearer,
         key,
         "Soniox",
     )
     .await
-    .map_err(|e| e.message("Soniox"))
 }
 
 fn build_create_payload(
@@ -52,11 +241,30 @@ fn build_create_payload(
     payload
 }
 
+/// Layer user extra params over the create payload. `model` and `file_id`
+/// are never overridden, even if a stale stored map still carries them.
+fn merge_extra_params(payload: &mut serde_json::Value, extra: &common::ExtraParams) {
+    let Some(object) = payload.as_object_mut() else {
+        return;
+    };
+    for (key, value) in extra {
+        if super::CloudProvider::Soniox
+            .reserved_params()
+            .contains(&key.as_str())
+        {
+            continue;
+        }
+        object.insert(key.clone(), value.clone());
+    }
+}
+
 pub(super) async fn transcribe_typed(
     app: &AppHandle,
+    base_url: &str,
     key: &str,
     wav_path: &Path,
     language: Option<&str>,
+    extra: &common::ExtraParams,
 ) -> Result<String, common::SttError> {
     use reqwest::multipart::{Form, Part};
     use tokio::fs;
@@ -66,6 +274,7 @@ pub(super) async fn transcribe_typed(
         .map_err(|_| common::SttError::BadResponse)?;
 
     let client = common::http_client();
+    let is_cancelled = || cancellation_requested(app);
 
     // 1) Upload file -> file_id
     let filename = wav_path
@@ -73,8 +282,8 @@ pub(super) async fn transcribe_typed(
         .and_then(|s| s.to_str())
         .unwrap_or("audio.wav")
         .to_string();
-    let upload_url = format!("{}/files", BASE);
-    let upload_resp = common::with_retry(|| {
+    let upload_url = format!("{}/files", base_url);
+    let upload = common::with_retry(|| {
         let client = client.clone();
         let filename = filename.clone();
         let upload_url = upload_url.clone();
@@ -99,8 +308,8 @@ pub(super) async fn transcribe_typed(
                 Err(common::log_http_body(resp, "Soniox upload").await)
             }
         }
-    })
-    .await?;
+    });
+    let upload_resp = abortable(upload, &is_cancelled).await?;
     let upload_json: serde_json::Value = upload_resp
         .json()
         .await
@@ -121,30 +330,24 @@ pub(super) async fn transcribe_typed(
             None
         }
     };
-    let payload = build_create_payload(&file_id, language, soniox_context, false);
-
-    let create_url = format!("{}/transcriptions", BASE);
-    let create_resp = common::with_retry(|| {
-        let client = client.clone();
-        let create_url = create_url.clone();
-        let payload = payload.clone();
-        async move {
-            let resp = client
+    let mut payload = build_create_payload(&file_id, language, soniox_context, false);
+    merge_extra_params(&mut payload, extra);
+
+    let create_url = format!("{}/transcriptions", base_url);
+    let deadline = Instant::now() + JOB_TIMEOUT;
+    let create = send_rate_limited(
+        "Soniox create transcription",
+        deadline,
+        || {
+            client
                 .post(&create_url)
                 .bearer_auth(key)
                 .header("Content-Type", "application/json")
                 .json(&payload)
-                .send()
-                .await
-                .map_err(|e| common::classify_reqwest_err(&e))?;
-            if resp.status().is_success() {
-                Ok(resp)
-            } else {
-                Err(common::log_http_body(resp, "Soniox create transcription").await)
-            }
-        }
-    })
-    .await?;
+        },
+        |wait| notify_rate_limited(app, wait),
+    );
+    let create_resp = abortable(create, &is_cancelled).await?;
     let create_json: serde_json::Value = create_resp
         .json()
         .await
@@ -156,51 +359,30 @@ pub(super) async fn transcribe_typed(
         .to_string();
 
     // 3) Poll status
-    let status_url = format!("{}/transcriptions/{}", BASE, transcription_id);
-    let started = std::time::Instant::now();
-    let timeout = std::time::Duration::from_secs(180);
-    loop {
-        let resp = common::with_retry(|| {
-            let client = client.clone();
-            let status_url = status_url.clone();
-            async move {
-                let resp = client
-                    .get(&status_url)
-                    .bearer_auth(key)
-                    .send()
-                    .await
-                    .map_err(|e| common::classify_reqwest_err(&e))?;
-                if resp.status().is_success() {
-                    Ok(resp)
-                } else {
-                    Err(common::log_http_body(resp, "Soniox status").await)
-                }
-            }
-        })
-        .await?;
-        let json: serde_json::Value = resp
-            .json()
-            .await
-            .map_err(|_| common::SttError::BadResponse)?;
-        let status = json.get("status").and_then(|v| v.as_str()).unwrap_or("");
-        match status {
-            "completed" => break,
-            "error" => {
-                log::warn!("Soniox transcription job failed");
-                return Err(common::SttError::Server);
-            }
-            _ => {
-                if started.elapsed() > timeout {
-                    return Err(common::SttError::Timeout);
-                }
-                tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
-            }
+    let status_url = format!("{}/transcriptions/{}", base_url, transcription_id);
+    if let Err(err) = poll_until_complete(
+        &client,
+        &status_url,
+        key,
+        deadline,
+        "Soniox status",
+        &is_cancelled,
+        |wait| notify_rate_limited(app, wait),
+    )
+    .await
+    {
+        if matches!(err, common::SttError::Cancelled) {
+            discard_transcription(&client, base_url, key, &transcription_id);
         }
+        return Err(err);
     }
 
     // 4) Fetch transcript
-    let transcript_url = format!("{}/transcriptions/{}/transcript", BASE, transcription_id);
-    let resp = common::with_retry(|| {
+    let transcript_url = format!(
+        "{}/transcriptions/{}/transcript",
+        base_url, transcription_id
+    );
+    let transcript = common::with_retry(|| {
         let client = client.clone();
         let transcript_url = transcript_url.clone();
         async move {
@@ -216,8 +398,8 @@ pub(super) async fn transcribe_typed(
                 Err(common::log_http_body(resp, "Soniox transcript").await)
             }
         }
-    })
-    .await?;
+    });
+    let resp = abortable(transcript, &is_cancelled).await?;
     let json: serde_json::Value = resp
         .json()
         .await
@@ -249,9 +431,11 @@ pub(super) async fn transcribe_typed(
 
 pub(super) async fn transcribe_typed_diarized(
     app: &AppHandle,
+    base_url: &str,
     key: &str,
     wav_path: &Path,
     language: Option<&str>,
+    extra: &common::ExtraParams,
 ) -> Result<super::CloudTranscript, common::SttError> {
     use reqwest::multipart::{Form, Part};
     use tokio::fs;
@@ -261,6 +445,7 @@ pub(super) async fn transcribe_typed_diarized(
         .map_err(|_| common::SttError::BadResponse)?;
 
     let client = common::http_client();
+    let is_cancelled = || cancellation_requested(app);
 
     // 1) Upload file -> file_id
     let filename = wav_path
@@ -268,8 +453,8 @@ pub(super) async fn transcribe_typed_diarized(
         .and_then(|s| s.to_str())
         .unwrap_or("audio.wav")
         .to_string();
-    let upload_url = format!("{}/files", BASE);
-    let upload_resp = common::with_retry(|| {
+    let upload_url = format!("{}/files", base_url);
+    let upload = common::with_retry(|| {
         let client = client.clone();
         let filename = filename.clone();
         let upload_url = upload_url.clone();
@@ -293,8 +478,8 @@ pub(super) async fn transcribe_typed_diarized(
                 Err(common::log_http_body(resp, "Soniox upload (diarized)").await)
             }
         }
-    })
-    .await?;
+    });
+    let upload_resp = abortable(upload, &is_cancelled).await?;
     let upload_json: serde_json::Value = upload_resp
         .json()
         .await
@@ -315,30 +500,24 @@ pub(super) async fn transcribe_typed_diarized(
             None
         }
     };
-    let payload = build_create_payload(&file_id, language, soniox_context, true);
-
-    let create_url = format!("{}/transcriptions", BASE);
-    let create_resp = common::with_retry(|| {
-        let client = client.clone();
-        let create_url = create_url.clone();
-        let payload = payload.clone();
-        async move {
-            let resp = client
+    let mut payload = build_create_payload(&file_id, language, soniox_context, true);
+    merge_extra_params(&mut payload, extra);
+
+    let create_url = format!("{}/transcriptions", base_url);
+    let deadline = Instant::now() + JOB_TIMEOUT;
+    let create = send_rate_limited(
+        "Soniox create transcription (diarized)",
+        deadline,
+        || {
+            client
                 .post(&create_url)
                 .bearer_auth(key)
                 .header("Content-Type", "application/json")
                 .json(&payload)
-                .send()
-                .await
-                .map_err(|e| common::classify_reqwest_err(&e))?;
-            if resp.status().is_success() {
-                Ok(resp)
-            } else {
-                Err(common::log_http_body(resp, "Soniox create transcription (diarized)").await)
-            }
-        }
-    })
-    .await?;
+        },
+        |wait| notify_rate_limited(app, wait),
+    );
+    let create_resp = abortable(create, &is_cancelled).await?;
     let create_json: serde_json::Value = create_resp
         .json()
         .await
@@ -350,51 +529,30 @@ pub(super) async fn transcribe_typed_diarized(
         .to_string();
 
     // 3) Poll status
-    let status_url = format!("{}/transcriptions/{}", BASE, transcription_id);
-    let started = std::time::Instant::now();
-    let timeout = std::time::Duration::from_secs(180);
-    loop {
-        let resp = common::with_retry(|| {
-            let client = client.clone();
-            let status_url = status_url.clone();
-            async move {
-                let resp = client
-                    .get(&status_url)
-                    .bearer_auth(key)
-                    .send()
-                    .await
-                    .map_err(|e| common::classify_reqwest_err(&e))?;
-                if resp.status().is_success() {
-                    Ok(resp)
-                } else {
-                    Err(common::log_http_body(resp, "Soniox status (diarized)").await)
-                }
-            }
-        })
-        .await?;
-        let json: serde_json::Value = resp
-            .json()
-            .await
-            .map_err(|_| common::SttError::BadResponse)?;
-        let status = json.get("status").and_then(|v| v.as_str()).unwrap_or("");
-        match status {
-            "completed" => break,
-            "error" => {
-                log::warn!("Soniox diarized transcription job failed");
-                return Err(common::SttError::Server);
-            }
-            _ => {
-                if started.elapsed() > timeout {
-                    return Err(common::SttError::Timeout);
-                }
-                tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
-            }
+    let status_url = format!("{}/transcriptions/{}", base_url, transcription_id);
+    if let Err(err) = poll_until_complete(
+        &client,
+        &status_url,
+        key,
+        deadline,
+        "Soniox status (diarized)",
+        &is_cancelled,
+        |wait| notify_rate_limited(app, wait),
+    )
+    .await
+    {
+        if matches!(err, common::SttError::Cancelled) {
+            discard_transcription(&client, base_url, key, &transcription_id);
         }
+        return Err(err);
     }
 
     // 4) Fetch transcript
-    let transcript_url = format!("{}/transcriptions/{}/transcript", BASE, transcription_id);
-    let resp = common::with_retry(|| {
+    let transcript_url = format!(
+        "{}/transcriptions/{}/transcript",
+        base_url, transcription_id
+    );
+    let transcript = common::with_retry(|| {
         let client = client.clone();
         let transcript_url = transcript_url.clone();
         async move {
@@ -410,8 +568,8 @@ pub(super) async fn transcribe_typed_diarized(
                 Err(common::log_http_body(resp, "Soniox transcript (diarized)").await)
             }
         }
-    })
-    .await?;
+    });
+    let resp = abortable(transcript, &is_cancelled).await?;
     let json: serde_json::Value = resp
         .json()
         .await
@@ -533,6 +691,23 @@ mod tests {
             .is_none());
     }
 
+    #[test]
+    fn merge_extra_params_adds_fields_but_keeps_file_id_and_model() {
+        let mut payload = build_create_payload("fid", Some("en"), None, false);
+        let extra = serde_json::json!({
+            "enable_speaker_diarization": true,
+            "language_hints": ["en", "de"],
+            "file_id": "other",
+            "model": "other-model"
+        });
+        merge_extra_params(&mut payload, extra.as_object().unwrap());
+
+        assert_eq!(payload["enable_speaker_diarization"].as_bool(), Some(true));
+        assert_eq!(payload["language_hints"], serde_json::json!(["en", "de"]));
+        assert_eq!(payload["file_id"], "fid");
+        assert_eq!(payload["model"], MODEL);
+    }
+
     #[test]
     fn parse_soniox_token_with_speaker_produces_speaker_id() {
         let t = serde_json::json!({
@@ -578,4 +753,133 @@ mod tests {
         let t = serde_json::json!({ "start_ms": 0, "end_ms": 100 });
         assert!(parse_soniox_token(&t).is_none());
     }
+
+    #[test]
+    fn retry_after_wait_reads_seconds_and_caps_at_thirty() {
+        assert_eq!(retry_after_wait(Some("2")), Duration::from_secs(2));
+        assert_eq!(retry_after_wait(Some(" 120 ")), RATE_LIMIT_MAX_WAIT);
+        assert_eq!(
+            retry_after_wait(Some("Wed, 21 Oct 2015 07:28:00 GMT")),
+            RATE_LIMIT_DEFAULT_WAIT
+        );
+        assert_eq!(retry_after_wait(None), RATE_LIMIT_DEFAULT_WAIT);
+    }
+
+    #[tokio::test]
+    async fn send_rate_limited_waits_out_a_429_then_succeeds() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/transcriptions"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/transcriptions"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": "t1" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = reqwest::Client::new();
+        let url = format!("{}/transcriptions", server.uri());
+        let notified = AtomicU32::new(0);
+        let started = Instant::now();
+        let resp = send_rate_limited(
+            "Soniox create transcription",
+            Instant::now() + JOB_TIMEOUT,
+            || client.post(&url),
+            |wait| {
+                assert_eq!(wait, Duration::from_secs(1));
+                notified.fetch_add(1, Ordering::SeqCst);
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(resp.status(), reqwest::StatusCode::OK);
+        assert_eq!(notified.load(Ordering::SeqCst), 1);
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn send_rate_limited_gives_up_when_the_wait_passes_the_deadline() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "5"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = reqwest::Client::new();
+        let error = send_rate_limited(
+            "Soniox status",
+            Instant::now() + Duration::from_secs(2),
+            || client.get(server.uri()),
+            |_| panic!("must not wait past the deadline"),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error, common::SttError::RateLimited), "{error:?}");
+    }
+
+    #[tokio::test]
+    async fn poll_returns_promptly_when_cancelled_mid_poll() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "status": "processing" })),
+            )
+            .mount(&server)
+            .await;
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let flag = cancelled.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            flag.store(true, Ordering::SeqCst);
+        });
+
+        let client = reqwest::Client::new();
+        let started = Instant::now();
+        let error = poll_until_complete(
+            &client,
+            &server.uri(),
+            "key",
+            Instant::now() + JOB_TIMEOUT,
+            "Soniox status",
+            &|| cancelled.load(Ordering::SeqCst),
+            |_| {},
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error, common::SttError::Cancelled), "{error:?}");
+        assert!(started.elapsed() < POLL_INTERVAL, "{:?}", started.elapsed());
+    }
+
+    #[tokio::test]
+    async fn abortable_short_circuits_when_already_cancelled() {
+        let never = std::future::pending::<Result<(), common::SttError>>();
+        let error = abortable(never, &|| true).await.unwrap_err();
+
+        assert!(matches!(error, common::SttError::Cancelled), "{error:?}");
+    }
 }
@@ -0,0 +1,427 @@
+//! Soniox realtime STT over WebSocket.
+//!
+//! Instead of uploading the finished recording and polling, captured audio is
+//! streamed to Soniox while the user is still speaking, so the transcript is
+//! ready almost as soon as recording stops. Interim text is emitted to the pill
+//! as `transcription-partial`, and typed at the cursor when `insert_streaming`
+//! is on. Any failure here is reported to the caller,
+//! which falls back to the file-based Soniox flow with the saved recording.
+
+use crate::audio::recorder::CapturedChunk;
+use futures_util::{SinkExt, StreamExt};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::async_runtime::JoinHandle;
+use tauri::AppHandle;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+
+const DEFAULT_REALTIME_URL: &str = "wss://stt-rt.soniox.com/transcribe-websocket";
+const REALTIME_MODEL: &str = "stt-rt-preview";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long `finish` waits for Soniox to finalize the audio already sent.
+const FINISH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Realtime endpoint for a Soniox REST base URL, so a regional or self-hosted
+/// override set for file transcription also applies here. The `api` host label
+/// becomes `stt-rt` (`https://api.soniox.com/v1` maps to the default
+/// endpoint); hosts without one are used as they are.
+fn realtime_url(base_url: &str) -> String {
+    let Ok(url) = reqwest::Url::parse(base_url) else {
+        return DEFAULT_REALTIME_URL.to_string();
+    };
+    let Some(host) = url.host_str() else {
+        return DEFAULT_REALTIME_URL.to_string();
+    };
+    let host = host
+        .split('.')
+        .map(|label| if label == "api" { "stt-rt" } else { label })
+        .collect::<Vec<_>>()
+        .join(".");
+    match url.port() {
+        Some(port) => format!("wss://{}:{}/transcribe-websocket", host, port),
+        None => format!("wss://{}/transcribe-websocket", host),
+    }
+}
+
+/// What a finished realtime session produced.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RealtimeOutput {
+    pub text: String,
+    /// Language Soniox identified for most of the final tokens.
+    pub language: Option<String>,
+}
+
+/// Final and interim text assembled from Soniox token responses.
+#[derive(Debug, Default)]
+struct RealtimeTranscript {
+    final_text: String,
+    // Final token count per identified language
+    languages: HashMap<String, usize>,
+}
+
+impl RealtimeTranscript {
+    /// Fold one response into the transcript and return the text to show now:
+    /// every finalized token plus the current interim tail.
+    fn apply(&mut self, response: &serde_json::Value) -> String {
+        let mut interim = String::new();
+        let tokens = response.get("tokens").and_then(|v| v.as_array());
+        for token in tokens.into_iter().flatten() {
+            let Some(text) = token.get("text").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            // Control markers such as `<end>` and `<fin>` are not speech.
+            if text.starts_with('<') && text.ends_with('>') {
+                continue;
+            }
+            if token.get("is_final").and_then(|v| v.as_bool()) == Some(true) {
+                self.final_text.push_str(text);
+                if let Some(language) = token.get("language").and_then(|v| v.as_str()) {
+                    *self.languages.entry(language.to_string()).or_default() += 1;
+                }
+            } else {
+                interim.push_str(text);
+            }
+        }
+        format!("{}{}", self.final_text, interim)
+    }
+
+    fn final_text(&self) -> String {
+        self.final_text.trim().to_string()
+    }
+
+    fn output(&self) -> RealtimeOutput {
+        let language = self
+            .languages
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(language, _)| language.clone());
+        RealtimeOutput {
+            text: self.final_text(),
+            language,
+        }
+    }
+}
+
+/// An open realtime transcription session.
+pub struct SonioxStream {
+    audio_tx: mpsc::UnboundedSender<Message>,
+    reader: JoinHandle<Result<RealtimeOutput, String>>,
+    api_key: String,
+    language: Option<String>,
+    configured: bool,
+}
+
+impl SonioxStream {
+    /// Connect to the realtime endpoint at `url`. The session config is sent
+    /// with the first audio, once the recorder's native format is known. With
+    /// `typing_generation`, interim text is also typed into the focused app
+    /// for as long as that session is current.
+    pub async fn start(
+        app: &AppHandle,
+        url: &str,
+        api_key: &str,
+        language: Option<&str>,
+        typing_generation: Option<u64>,
+    ) -> Result<Self, String> {
+        let (socket, _) =
+            tokio::time::timeout(CONNECT_TIMEOUT, tokio_tungstenite::connect_async(url))
+                .await
+                .map_err(|_| "Soniox realtime connection timed out".to_string())?
+                .map_err(|e| format!("Soniox realtime connection failed: {}", e))?;
+        let (mut sink, mut stream) = socket.split();
+
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<Message>();
+        tauri::async_runtime::spawn(async move {
+            while let Some(message) = audio_rx.recv().await {
+                if let Err(e) = sink.send(message).await {
+                    log::warn!("Soniox realtime send failed: {}", e);
+                    break;
+                }
+            }
+        });
+
+        let app = app.clone();
+        let reader = tauri::async_runtime::spawn(async move {
+            let mut transcript = RealtimeTranscript::default();
+            let mut shown = String::new();
+            while let Some(frame) = stream.next().await {
+                let text = match frame {
+                    Ok(Message::Text(text)) => text,
+                    Ok(Message::Close(_)) => break,
+                    Ok(_) => continue,
+                    Err(e) => return Err(format!("Soniox realtime connection lost: {}", e)),
+                };
+                let response: serde_json::Value = serde_json::from_str(&text)
+                    .map_err(|e| format!("Soniox realtime: unexpected response: {}", e))?;
+                if let Some(code) = response.get("error_code") {
+                    let message = response
+                        .get("error_message")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default();
+                    return Err(format!("Soniox realtime error {}: {}", code, message));
+                }
+
+                let partial = transcript.apply(&response);
+                if partial != shown {
+                    let _ = crate::emit_to_window(
+                        &app,
+                        "pill",
+                        "transcription-partial",
+                        serde_json::json!({ "text": partial }),
+                    );
+                    if typing_generation
+                        .is_some_and(|g| g == SESSION_GENERATION.load(Ordering::SeqCst))
+                    {
+                        // A skipped update is caught up by the next one
+                        if let Err(e) =
+                            crate::commands::text::insert_streaming_text(partial.clone(), false)
+                                .await
+                        {
+                            log::debug!("Skipped interim text update: {}", e);
+                        }
+                    }
+                    shown = partial;
+                }
+                if response.get("finished").and_then(|v| v.as_bool()) == Some(true) {
+                    break;
+                }
+            }
+            Ok(transcript.output())
+        });
+
+        Ok(Self {
+            audio_tx,
+            reader,
+            api_key: api_key.to_string(),
+            language: language.map(str::to_string),
+            configured: false,
+        })
+    }
+
+    /// Send captured PCM. The first call also sends the session config for
+    /// this sample rate and channel count.
+    pub fn push_samples(
+        &mut self,
+        samples: &[i16],
+        sample_rate: u32,
+        channels: u16,
+    ) -> Result<(), String> {
+        if !self.configured {
+            let mut config = serde_json::json!({
+                "api_key": self.api_key,
+                "model": REALTIME_MODEL,
+                "audio_format": "pcm_s16le",
+                "sample_rate": sample_rate,
+                "num_channels": channels,
+                "enable_language_identification": true,
+            });
+            if let Some(language) = self.language.as_deref() {
+                config["language_hints"] = serde_json::json!([language]);
+            }
+            self.send(Message::text(config.to_string()))?;
+            self.configured = true;
+        }
+
+        let bytes: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+        self.send(Message::binary(bytes))
+    }
+
+    /// Signal end of audio and wait for the final transcript.
+    pub async fn finish(self) -> Result<RealtimeOutput, String> {
+        if !self.configured {
+            return Err("Soniox realtime received no audio".to_string());
+        }
+        // An empty frame tells Soniox the audio is complete.
+        self.send(Message::binary(Vec::<u8>::new()))?;
+        drop(self.audio_tx);
+
+        match tokio::time::timeout(FINISH_TIMEOUT, self.reader).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => Err(format!("Soniox realtime reader failed: {}", e)),
+            Err(_) => Err("Soniox realtime did not finish in time".to_string()),
+        }
+    }
+
+    fn send(&self, message: Message) -> Result<(), String> {
+        self.audio_tx
+            .send(message)
+            .map_err(|_| "Soniox realtime connection closed".to_string())
+    }
+}
+
+// Session for the recording in progress; resolves to the stream once the
+// recorder closes its audio tap.
+static ACTIVE_SESSION: Mutex<Option<JoinHandle<Option<SonioxStream>>>> = Mutex::new(None);
+
+// Bumped by every begin and discard, so a superseded session's reader stops
+// typing interim text into the focused app.
+static SESSION_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+fn replace_session(session: Option<JoinHandle<Option<SonioxStream>>>) {
+    let previous = std::mem::replace(
+        &mut *ACTIVE_SESSION
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner()),
+        session,
+    );
+    if let Some(previous) = previous {
+        previous.abort();
+    }
+}
+
+/// Open a realtime session for a recording about to start and return the tap
+/// the recorder should forward captured audio into. Returns `None` when no
+/// Soniox key is stored. Connection happens in the background; audio captured
+/// meanwhile is buffered in the tap.
+pub fn begin_session(
+    app: &AppHandle,
+    language: Option<String>,
+    type_partials: bool,
+) -> Option<std::sync::mpsc::Sender<CapturedChunk>> {
+    let generation = SESSION_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let typing_generation = type_partials.then_some(generation);
+    let api_key = crate::secure_store::secure_get(app, super::CloudProvider::Soniox.key_name())
+        .ok()
+        .flatten()?;
+    let url = realtime_url(&super::CloudProvider::Soniox.base_url(app));
+    let (tap_tx, tap_rx) = std::sync::mpsc::channel::<CapturedChunk>();
+
+    let app = app.clone();
+    let session = tauri::async_runtime::spawn(async move {
+        let mut stream =
+            match SonioxStream::start(&app, &url, &api_key, language.as_deref(), typing_generation)
+                .await
+            {
+                Ok(stream) => stream,
+                Err(e) => {
+                    log::warn!("{}; falling back to file transcription", e);
+                    return None;
+                }
+            };
+        log::info!("Soniox realtime session connected");
+
+        tokio::task::spawn_blocking(move || {
+            while let Ok(chunk) = tap_rx.recv() {
+                if let Err(e) =
+                    stream.push_samples(&chunk.samples, chunk.sample_rate, chunk.channels)
+                {
+                    log::warn!("{}; falling back to file transcription", e);
+                    return None;
+                }
+            }
+            Some(stream)
+        })
+        .await
+        .ok()
+        .flatten()
+    });
+
+    replace_session(Some(session));
+    Some(tap_tx)
+}
+
+/// Finish the session for the recording that just stopped. `None` means
+/// there was no usable session and the caller should transcribe the file.
+pub async fn finish_session() -> Option<RealtimeOutput> {
+    let session = ACTIVE_SESSION
+        .lock()
+        .unwrap_or_else(|poison| poison.into_inner())
+        .take()?;
+    let stream = match tokio::time::timeout(FINISH_TIMEOUT, session).await {
+        Ok(Ok(Some(stream))) => stream,
+        Ok(_) => return None,
+        Err(_) => {
+            log::warn!("Soniox realtime audio tap did not close in time");
+            return None;
+        }
+    };
+
+    match stream.finish().await {
+        Ok(output) if !output.text.is_empty() => Some(output),
+        Ok(_) => {
+            log::info!("Soniox realtime returned no text; falling back to file transcription");
+            None
+        }
+        Err(e) => {
+            log::warn!("{}; falling back to file transcription", e);
+            None
+        }
+    }
+}
+
+/// Drop any session left over from a cancelled or non-Soniox recording, so it
+/// can never be mistaken for the next recording's transcript.
+pub fn discard_session() {
+    SESSION_GENERATION.fetch_add(1, Ordering::SeqCst);
+    replace_session(None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{realtime_url, RealtimeTranscript, DEFAULT_REALTIME_URL};
+    use serde_json::json;
+
+    #[test]
+    fn realtime_url_follows_the_rest_base_url() {
+        assert_eq!(
+            realtime_url("https://api.soniox.com/v1"),
+            DEFAULT_REALTIME_URL
+        );
+        assert_eq!(
+            realtime_url("https://api.eu.soniox.com/v1"),
+            "wss://stt-rt.eu.soniox.com/transcribe-websocket"
+        );
+        assert_eq!(
+            realtime_url("https://stt.example.com:8443/soniox/v1"),
+            "wss://stt.example.com:8443/transcribe-websocket"
+        );
+    }
+
+    #[test]
+    fn realtime_transcript_reports_the_majority_language() {
+        let mut transcript = RealtimeTranscript::default();
+        transcript.apply(&json!({
+            "tokens": [
+                { "text": "Hola", "is_final": true, "language": "es" },
+                { "text": " amigo", "is_final": true, "language": "es" },
+                { "text": " okay", "is_final": true, "language": "en" },
+                { "text": " más", "is_final": false, "language": "es" }
+            ]
+        }));
+        let output = transcript.output();
+        assert_eq!(output.text, "Hola amigo okay");
+        assert_eq!(output.language.as_deref(), Some("es"));
+    }
+
+    #[test]
+    fn realtime_transcript_keeps_finals_and_shows_interim_tail() {
+        let mut transcript = RealtimeTranscript::default();
+
+        let shown = transcript.apply(&json!({
+            "tokens": [
+                { "text": "Hello", "is_final": true },
+                { "text": " wor", "is_final": false }
+            ]
+        }));
+        assert_eq!(shown, "Hello wor");
+
+        let shown = transcript.apply(&json!({
+            "tokens": [
+                { "text": " world", "is_final": true },
+                { "text": "<end>", "is_final": true }
+            ]
+        }));
+        assert_eq!(shown, "Hello world");
+        assert_eq!(transcript.final_text(), "Hello world");
+    }
+
+    #[test]
+    fn realtime_transcript_ignores_responses_without_tokens() {
+        let mut transcript = RealtimeTranscript::default();
+        assert_eq!(transcript.apply(&json!({ "finished": true })), "");
+        assert_eq!(transcript.final_text(), "");
+    }
+}
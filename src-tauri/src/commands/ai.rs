@@ -3,7 +3,10 @@ use crate::ai::contract::AiPolishRequest;
 use crate::ai::error::{user_facing_message, AiProviderError};
 use crate::ai::executor::AiExecutor;
 use crate::ai::genai_runtime::AiKeyResolver;
-use crate::ai::providers::{launch_providers, PROVIDER_CUSTOM};
+use crate::ai::model_list::{self, ListedModel};
+use crate::ai::providers::{
+    is_openai_compatible, launch_providers, PROVIDER_CUSTOM, PROVIDER_OLLAMA,
+};
 use crate::ai::EnhancementOptions;
 use crate::commands::audio::pill_toast;
 use crate::commands::settings::{
@@ -12,6 +15,7 @@ use crate::commands::settings::{
     FINAL_TEXT_LANGUAGE_SAME_AS_TRANSCRIPT, TRANSCRIPTION_TASK_TRANSCRIBE,
 };
 use crate::secure_store;
+use crate::simple_cache::{self, SetItemOptions};
 use crate::writing::{load_writing_settings, save_writing_settings, WritingSettings};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
@@ -30,6 +34,9 @@ const CUSTOM_BASE_URL_KEY: &str = "ai_custom_base_url";
 const CUSTOM_NO_AUTH_KEY: &str = "ai_custom_no_auth";
 const LEGACY_OPENAI_BASE_URL_KEY: &str = "ai_openai_base_url";
 const LEGACY_OPENAI_NO_AUTH_KEY: &str = "ai_openai_no_auth";
+// Ollama serves the OpenAI chat schema under /v1 and needs no API key
+const DEFAULT_OLLAMA_BASE_URL: &str = "http://localhost:11434/v1";
+const OLLAMA_BASE_URL_KEY: &str = "ai_ollama_base_url";
 
 // One pooled reqwest::Client shared across the LLM enhancement path so the connection pool stays hot across calls.
 static SHARED_AI_CLIENT: std::sync::LazyLock<reqwest::Client> = std::sync::LazyLock::new(|| {
@@ -121,6 +128,8 @@ fn check_has_api_key<R: tauri::Runtime>(
         let configured_base = store.get(CUSTOM_BASE_URL_KEY).is_some()
             || store.get(LEGACY_OPENAI_BASE_URL_KEY).is_some();
         configured_base || cache.contains_key(&format!("ai_api_key_{}", provider))
+    } else if provider == PROVIDER_OLLAMA {
+        store.get(OLLAMA_BASE_URL_KEY).is_some()
     } else {
         cache.contains_key(&format!("ai_api_key_{}", provider))
     }
@@ -427,21 +436,17 @@ pub struct ValidateAiApiKeyArgs {
     #[serde(alias = "noAuth", alias = "no_auth")]
     pub no_auth: Option<bool>,
 }
-/// Read the user's previously-configured custom model from settings.
+/// Read the user's previously-configured model for an OpenAI-compatible provider.
 ///
-/// The custom provider has no catalog models; its model is whatever the user
+/// Custom and Ollama have no catalog models; the model is whatever the user
 /// picked. It is persisted in the per-provider map (`ai_models_by_provider`),
 /// or — for the currently-active provider — in the single `ai_model` value.
-fn configured_custom_model(app: &tauri::AppHandle) -> Option<String> {
+fn configured_custom_model(app: &tauri::AppHandle, provider: &str) -> Option<String> {
     app.store("settings").ok().and_then(|store| {
         let from_map = store
             .get("ai_models_by_provider")
             .and_then(|v| serde_json::from_value::<HashMap<String, String>>(v.clone()).ok())
-            .and_then(|map| {
-                map.get(PROVIDER_CUSTOM)
-                    .cloned()
-                    .filter(|m| !m.trim().is_empty())
-            });
+            .and_then(|map| map.get(provider).cloned().filter(|m| !m.trim().is_empty()));
         if from_map.is_some() {
             return from_map;
         }
@@ -449,7 +454,7 @@ fn configured_custom_model(app: &tauri::AppHandle) -> Option<String> {
             .get("ai_provider")
             .and_then(|v| v.as_str().map(|s| s.to_string()))
             .unwrap_or_default();
-        if active_provider == PROVIDER_CUSTOM {
+        if active_provider == provider {
             store
                 .get("ai_model")
                 .and_then(|v| v.as_str().map(|s| s.to_string()))
@@ -500,17 +505,18 @@ pub async fn validate_ai_api_key(
     }
 
     let provided_key = api_key.unwrap_or_default();
-    let no_auth =
-        provider == PROVIDER_CUSTOM && (no_auth.unwrap_or(false) || provided_key.trim().is_empty());
+    let no_auth = provider == PROVIDER_OLLAMA
+        || (provider == PROVIDER_CUSTOM
+            && (no_auth.unwrap_or(false) || provided_key.trim().is_empty()));
     if !no_auth && provided_key.trim().is_empty() {
         return Err(user_facing_message(&AiProviderError::MissingApiKey).to_string());
     }
 
-    let validation_model = if provider == PROVIDER_CUSTOM {
-        // The custom provider has no catalog models, so a model must be
+    let validation_model = if is_openai_compatible(&provider) {
+        // Custom and Ollama have no catalog models, so a model must be
         // supplied explicitly or already configured in settings. Never fall
         // back to gpt-5-nano — that would 404 against a local endpoint.
-        let configured = configured_custom_model(&app);
+        let configured = configured_custom_model(&app, &provider);
         resolve_custom_validation_model(model.as_deref(), configured.as_deref())?
     } else {
         model
@@ -528,10 +534,14 @@ pub async fn validate_ai_api_key(
             .filter(|candidate| !candidate.trim().is_empty())
             .or_else(|| custom_base_url_from_settings(&app))
             .unwrap_or_else(|| DEFAULT_OPENAI_BASE_URL.to_string())
+    } else if provider == PROVIDER_OLLAMA {
+        base_url
+            .filter(|candidate| !candidate.trim().is_empty())
+            .unwrap_or_else(|| ollama_base_url_from_settings(&app))
     } else {
         DEFAULT_OPENAI_BASE_URL.to_string()
     };
-    if provider == PROVIDER_CUSTOM {
+    if is_openai_compatible(&provider) {
         validate_custom_base_url(&custom_base_url)?;
     }
 
@@ -552,6 +562,8 @@ pub async fn validate_ai_api_key(
         input_text: "ok".to_string(),
         prompt: "Reply with exactly: ok".to_string(),
         timeout_ms: 10_000,
+        temperature: None,
+        max_output_tokens: None,
     };
 
     executor
@@ -610,21 +622,28 @@ pub async fn test_openai_endpoint(
 // Frontend is responsible for removing API keys from Stronghold
 // This command clears the cache
 #[tauri::command]
-pub async fn clear_ai_api_key_cache(
-    _app: tauri::AppHandle,
-    provider: String,
-) -> Result<(), String> {
+pub async fn clear_ai_api_key_cache(app: tauri::AppHandle, provider: String) -> Result<(), String> {
     // Skip validation if provider is empty (happens when clearing selection)
     if !provider.is_empty() {
         validate_provider_name(&provider)?;
     }
 
+    // Ollama has no key; its stored endpoint is the whole configuration
+    if provider == PROVIDER_OLLAMA {
+        let store = app.store("settings").map_err(|e| e.to_string())?;
+        store.delete(OLLAMA_BASE_URL_KEY);
+        store
+            .save()
+            .map_err(|e| format!("Failed to save AI settings: {}", e))?;
+    }
+
     let mut cache = API_KEY_CACHE
         .lock()
         .map_err(|_| "Failed to access cache".to_string())?;
 
     if !provider.is_empty() {
         cache.remove(&format!("ai_api_key_{}", provider));
+        let _ = simple_cache::remove(&app, &models_cache_key(&provider));
         log::info!("API key cache cleared for provider: {}", provider);
     }
 
@@ -679,6 +698,12 @@ pub async fn update_ai_settings(
                 );
                 return Err("API key not found. Please add an API key first.".to_string());
             }
+        } else if provider == PROVIDER_OLLAMA {
+            let store = app.store("settings").map_err(|e| e.to_string())?;
+            if store.get(OLLAMA_BASE_URL_KEY).is_none() {
+                log::warn!("Attempted to enable AI enhancement before configuring Ollama");
+                return Err("Ollama is not configured. Please set it up first.".to_string());
+            }
         } else if provider == "openai" {
             let store = app.store("settings").map_err(|e| e.to_string())?;
             let cache_has_key = {
@@ -727,10 +752,8 @@ pub async fn update_ai_settings(
     if !enabled {
         store.set(
             "enhancement_options",
-            serde_json::to_value(EnhancementOptions {
-                preset: crate::ai::prompts::EnhancementPreset::PersonalDictation,
-            })
-            .map_err(|e| format!("Failed to serialize enhancement options: {}", e))?,
+            serde_json::to_value(EnhancementOptions::default_for_ai_enabled(false))
+                .map_err(|e| format!("Failed to serialize enhancement options: {}", e))?,
         );
         store.set(
             "final_text_language",
@@ -763,10 +786,8 @@ pub async fn disable_ai_enhancement(app: tauri::AppHandle) -> Result<(), String>
     store.set("ai_enabled", json!(false));
     store.set(
         "enhancement_options",
-        serde_json::to_value(EnhancementOptions {
-            preset: crate::ai::prompts::EnhancementPreset::PersonalDictation,
-        })
-        .map_err(|e| format!("Failed to serialize enhancement options: {}", e))?,
+        serde_json::to_value(EnhancementOptions::default_for_ai_enabled(false))
+            .map_err(|e| format!("Failed to serialize enhancement options: {}", e))?,
     );
     store.set(
         "final_text_language",
@@ -814,6 +835,8 @@ pub async fn update_enhancement_options(
     options: EnhancementOptions,
     app: tauri::AppHandle,
 ) -> Result<(), String> {
+    options.validate()?;
+
     let store = app.store("settings").map_err(|e| e.to_string())?;
 
     store.set(
@@ -828,7 +851,12 @@ pub async fn update_enhancement_options(
 
     crate::commands::audio::invalidate_recording_config_cache(&app).await;
 
-    log::info!("Enhancement options updated: preset={:?}", options.preset);
+    log::info!(
+        "Enhancement options updated: preset={:?}, temperature={}, max_output_tokens={:?}",
+        options.preset,
+        options.temperature,
+        options.max_output_tokens
+    );
 
     Ok(())
 }
@@ -848,6 +876,21 @@ pub async fn update_writing_settings(
     Ok(())
 }
 
+/// Whether code mode is switched on for the current session.
+#[tauri::command]
+pub async fn get_code_mode_session() -> Result<bool, String> {
+    Ok(crate::writing::code_mode_session_enabled())
+}
+
+/// Switch code mode on or off until the app restarts. Apps listed in the
+/// writing settings' code mode rules use it regardless.
+#[tauri::command]
+pub async fn set_code_mode_session(enabled: bool) -> Result<(), String> {
+    crate::writing::set_code_mode_session(enabled);
+    log::info!("Code mode session toggle set to {}", enabled);
+    Ok(())
+}
+
 fn custom_base_url_from_settings(app: &tauri::AppHandle) -> Option<String> {
     app.store("settings").ok().and_then(|store| {
         store
@@ -861,6 +904,17 @@ fn custom_base_url_from_settings(app: &tauri::AppHandle) -> Option<String> {
     })
 }
 
+fn ollama_base_url_from_settings(app: &tauri::AppHandle) -> String {
+    app.store("settings")
+        .ok()
+        .and_then(|store| {
+            store
+                .get(OLLAMA_BASE_URL_KEY)
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+        })
+        .unwrap_or_else(|| DEFAULT_OLLAMA_BASE_URL.to_string())
+}
+
 fn custom_no_auth_from_settings(app: &tauri::AppHandle, has_key: bool) -> bool {
     app.store("settings")
         .ok()
@@ -898,6 +952,9 @@ pub(crate) fn ai_provider_origin(app: &tauri::AppHandle, provider_id: &str) -> O
         let base = custom_base_url_from_settings(app).unwrap_or_else(|| DEFAULT_OPENAI_BASE_URL.to_string());
         return url_origin(&base);
     }
+    if provider_id == PROVIDER_OLLAMA {
+        return url_origin(&ollama_base_url_from_settings(app));
+    }
     native_origin(provider_id).map(str::to_string)
 }
 
@@ -991,6 +1048,13 @@ fn executor_for_provider(
             custom_no_auth_from_settings(app, has_key),
             keys,
         )
+    } else if selected_provider == PROVIDER_OLLAMA {
+        (
+            PROVIDER_OLLAMA.to_string(),
+            ollama_base_url_from_settings(app),
+            true,
+            HashMap::new(),
+        )
     } else {
         let key = selected_key.ok_or(AiProviderError::MissingApiKey)?;
         let mut keys = HashMap::new();
@@ -1002,7 +1066,7 @@ fn executor_for_provider(
             keys,
         )
     };
-    if runtime_provider == PROVIDER_CUSTOM {
+    if is_openai_compatible(&runtime_provider) {
         if let Err(reason) = validate_custom_base_url(&custom_base_url) {
             log::error!(
                 "Refusing to use disallowed custom endpoint ({}): {}",
@@ -1032,6 +1096,7 @@ async fn polish_text_with_prompt_typed(
     model: String,
     provider: String,
     prompt: String,
+    options: &EnhancementOptions,
 ) -> Result<String, AiProviderError> {
     let (executor, runtime_provider) = executor_for_provider(app, &provider)?;
     let request = AiPolishRequest {
@@ -1040,6 +1105,8 @@ async fn polish_text_with_prompt_typed(
         input_text: text.to_string(),
         prompt,
         timeout_ms: 30_000,
+        temperature: Some(options.temperature),
+        max_output_tokens: options.max_output_tokens,
     };
     let result = executor
         .polish(request, tokio_util::sync::CancellationToken::new())
@@ -1051,6 +1118,18 @@ async fn polish_text_with_prompt_typed(
         result.output_text.len(),
         result.duration_ms
     );
+    let strip_wrappers = app
+        .store("settings")
+        .ok()
+        .and_then(|store| store.get("strip_ai_preamble"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    if strip_wrappers {
+        return Ok(crate::ai::response_cleanup::strip_response_wrappers(
+            &result.output_text,
+            text,
+        ));
+    }
     Ok(result.output_text)
 }
 
@@ -1060,10 +1139,29 @@ pub async fn polish_text_typed(
     options: &crate::ai::EnhancementOptions,
     output_language: Option<&str>,
     context: Option<&str>,
+    recent_context: Option<&str>,
 ) -> Result<String, crate::ai::error::AiProviderError> {
     let (provider, model) = selected_ai_provider_and_model(app)?;
-    let prompt = crate::ai::prompts::build_enhancement_prompt(context, options, output_language);
-    polish_text_with_prompt_typed(app, text, model, provider, prompt).await
+    let mut prompt =
+        crate::ai::prompts::build_enhancement_prompt(context, options, output_language);
+    if let Some(recent) = recent_context {
+        crate::ai::prompts::append_recent_context(&mut prompt, recent);
+    }
+    polish_text_with_prompt_typed(app, text, model, provider, prompt, options).await
+}
+
+/// Translate `text` into `target_language` with the selected AI provider.
+/// Network and rate-limit failures are retried like enhancement.
+pub async fn translate_text_typed(
+    app: &tauri::AppHandle,
+    text: &str,
+    source_language: Option<&str>,
+    target_language: &str,
+) -> Result<String, AiProviderError> {
+    let (provider, model) = selected_ai_provider_and_model(app)?;
+    let prompt = crate::ai::prompts::build_translation_prompt(source_language, target_language);
+    let options = EnhancementOptions::default();
+    polish_text_with_prompt_typed(app, text, model, provider, prompt, &options).await
 }
 
 pub(crate) async fn enhance_transcription_internal(
@@ -1195,7 +1293,9 @@ pub(crate) async fn enhance_transcription_internal(
         language.as_deref(),
     );
 
-    match polish_text_with_prompt_typed(&app, &text, model, provider, prompt).await {
+    match polish_text_with_prompt_typed(&app, &text, model, provider, prompt, &enhancement_options)
+        .await
+    {
         Ok(enhanced_text) => Ok(enhanced_text),
         Err(error) => {
             log::warn!(
@@ -1247,6 +1347,8 @@ pub struct SetOpenAIConfigArgs {
     pub base_url: String,
     #[serde(alias = "noAuth", alias = "no_auth")]
     pub no_auth: Option<bool>,
+    // "ollama" stores the Ollama endpoint; anything else configures the custom provider
+    pub provider: Option<String>,
 }
 
 #[tauri::command]
@@ -1256,6 +1358,16 @@ pub async fn set_openai_config(
 ) -> Result<(), String> {
     validate_custom_base_url(&args.base_url)?;
     let store = app.store("settings").map_err(|e| e.to_string())?;
+    if args.provider.as_deref() == Some(PROVIDER_OLLAMA) {
+        store.set(
+            OLLAMA_BASE_URL_KEY,
+            serde_json::Value::String(args.base_url),
+        );
+        store
+            .save()
+            .map_err(|e| format!("Failed to save AI settings: {}", e))?;
+        return Ok(());
+    }
     store.set(
         CUSTOM_BASE_URL_KEY,
         serde_json::Value::String(args.base_url),
@@ -1271,7 +1383,16 @@ pub async fn set_openai_config(
 }
 
 #[tauri::command]
-pub async fn get_openai_config(app: tauri::AppHandle) -> Result<OpenAIConfig, String> {
+pub async fn get_openai_config(
+    app: tauri::AppHandle,
+    provider: Option<String>,
+) -> Result<OpenAIConfig, String> {
+    if provider.as_deref() == Some(PROVIDER_OLLAMA) {
+        return Ok(OpenAIConfig {
+            base_url: ollama_base_url_from_settings(&app),
+            no_auth: true,
+        });
+    }
     let store = app.store("settings").map_err(|e| e.to_string())?;
     let base_url = store
         .get(CUSTOM_BASE_URL_KEY)
@@ -1324,6 +1445,36 @@ fn provider_models(provider: &str) -> Vec<ProviderModel> {
         .collect()
 }
 
+/// Models from a live listing, with catalog metadata for the ones the catalog
+/// knows. Recommended models come first; otherwise the provider's order holds.
+fn provider_models_from_listing(provider: &str, listed: Vec<ListedModel>) -> Vec<ProviderModel> {
+    let known = provider_models(provider);
+    let mut models: Vec<ProviderModel> = listed
+        .into_iter()
+        .map(|listed| {
+            known
+                .iter()
+                .find(|model| model.id == listed.id)
+                .cloned()
+                .unwrap_or(ProviderModel {
+                    id: listed.id,
+                    name: listed.name,
+                    recommended: false,
+                    reasoning: false,
+                    context_window: None,
+                    cost_input: None,
+                    cost_output: None,
+                })
+        })
+        .collect();
+    models.sort_by_key(|model| !model.recommended);
+    models
+}
+
+fn models_cache_key(provider: &str) -> String {
+    format!("ai_models_{}", provider)
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ProviderInfo {
     pub id: String,
@@ -1355,7 +1506,7 @@ pub async fn list_provider_models(
 ) -> Result<Vec<ProviderModel>, String> {
     validate_provider_name(&provider)?;
 
-    if provider == PROVIDER_CUSTOM {
+    if is_openai_compatible(&provider) {
         return Ok(Vec::new());
     }
 
@@ -1370,6 +1521,60 @@ pub async fn list_provider_models(
     }
 }
 
+/// List the chat models a provider currently offers, fetched from its models
+/// endpoint with the cached API key and reused for an hour. Without a key, or
+/// when the request fails, the built-in catalog list is returned instead.
+#[tauri::command]
+pub async fn list_ai_models(
+    app: tauri::AppHandle,
+    provider: String,
+) -> Result<Vec<ProviderModel>, String> {
+    validate_provider_name(&provider)?;
+    if is_openai_compatible(&provider) {
+        return Ok(Vec::new());
+    }
+
+    let cache_key = models_cache_key(&provider);
+    if let Some(cached) = simple_cache::get(&app, &cache_key)
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_value::<Vec<ProviderModel>>(value).ok())
+    {
+        return Ok(cached);
+    }
+
+    let api_key = API_KEY_CACHE
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(&format!("ai_api_key_{}", provider)).cloned());
+    let (Some(api_key), Some(base_url)) = (api_key, model_list::default_models_base_url(&provider))
+    else {
+        return list_provider_models(provider, app).await;
+    };
+
+    match model_list::fetch_chat_models(&shared_ai_client(), &provider, base_url, &api_key).await {
+        Ok(listed) => {
+            let models = provider_models_from_listing(&provider, listed);
+            let options = SetItemOptions {
+                ttl: Some(model_list::MODEL_LIST_CACHE_TTL_SECS),
+                ..Default::default()
+            };
+            if let Err(e) = simple_cache::set(&app, &cache_key, json!(models), Some(options)) {
+                log::debug!("Failed to cache model list for {}: {}", provider, e);
+            }
+            Ok(models)
+        }
+        Err(e) => {
+            log::warn!(
+                "Live model list for {} unavailable, using catalog: {}",
+                provider,
+                e
+            );
+            list_provider_models(provider, app).await
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1400,6 +1605,7 @@ mod tests {
         assert_eq!(native_origin("anthropic"), Some("https://api.anthropic.com"));
         assert_eq!(native_origin("gemini"), Some("https://generativelanguage.googleapis.com"));
         assert_eq!(native_origin("custom"), None);
+        assert_eq!(native_origin("ollama"), None);
     }
 
     #[test]
@@ -1442,10 +1648,37 @@ mod tests {
         assert!(provider_models("unknown").is_empty());
     }
 
+    #[test]
+    fn listed_models_pick_up_catalog_metadata_and_recommended_go_first() {
+        let listed = vec![
+            ListedModel {
+                id: "gpt-9-preview".to_string(),
+                name: "gpt-9-preview".to_string(),
+            },
+            ListedModel {
+                id: "gpt-5-nano".to_string(),
+                name: "gpt-5-nano".to_string(),
+            },
+        ];
+        let catalog_nano = provider_models("openai")
+            .into_iter()
+            .find(|model| model.id == "gpt-5-nano")
+            .unwrap();
+
+        let models = provider_models_from_listing("openai", listed);
+
+        let ids: Vec<&str> = models.iter().map(|model| model.id.as_str()).collect();
+        assert_eq!(ids, vec!["gpt-5-nano", "gpt-9-preview"]);
+        assert_eq!(models[0].name, catalog_nano.name);
+        assert_eq!(models[0].context_window, catalog_nano.context_window);
+        assert!(!models[1].recommended);
+        assert_eq!(models[1].context_window, None);
+    }
+
     #[test]
     fn test_list_command_dto_shape_includes_catalog_providers() {
         let providers = provider_infos();
-        // 8 generated catalog providers + the synthetic custom provider.
+        // Generated catalog providers + the synthetic custom and Ollama providers.
         assert_eq!(providers.len(), launch_providers().len());
         let by_id = |id: &str| providers.iter().find(|provider| provider.id == id);
         assert_eq!(
@@ -1464,6 +1697,10 @@ mod tests {
             by_id("custom").map(|p| (p.name.as_str(), p.status.as_str())),
             Some(("Custom (OpenAI-compatible)", "production"))
         );
+        assert_eq!(
+            by_id("ollama").map(|p| (p.name.as_str(), p.status.as_str())),
+            Some(("Ollama (local)", "production"))
+        );
     }
 
     #[test]
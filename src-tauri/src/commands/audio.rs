@@ -2,15 +2,15 @@ use std::sync::atomic::{AtomicBool, AtomicU64, Ordering as AtomicOrdering};
 use tauri::{AppHandle, Emitter, Manager, State};
 
 use crate::ai::error::{user_facing_message, AiProviderError};
-use crate::audio::recorder::AudioRecorder;
-use crate::audio::silence_detector::SilenceDetectorEvent;
+use crate::audio::recorder::{AudioRecorder, CaptureConfig, DeviceEvent};
+use crate::audio::silence_detector::{SilenceAutoStop, SilenceDetectorEvent};
 use crate::commands::settings::{
     get_settings, normalize_final_text_language, normalize_speech_language_for_model,
     normalize_transcription_task, recording_retention_days_from_store, resolve_pill_indicator_mode,
-    task_uses_translate_to_english, Settings, TRANSCRIPTION_TASK_TRANSCRIBE,
+    task_uses_translate_to_english, AppProfile, Settings, TRANSCRIPTION_TASK_TRANSCRIBE,
 };
 use crate::license::LicenseState;
-use crate::media::MediaPauseController;
+use crate::media::{MediaPauseController, MediaPauseTestResult, PauseAttempt};
 use crate::parakeet::manager::ParakeetTranscriptionOptions;
 use crate::parakeet::messages::{ParakeetResponse, ParakeetSegment};
 use crate::parakeet::ParakeetManager;
@@ -20,6 +20,7 @@ use crate::remote::client::{
     TranscriptionRequest as RemoteTranscriptionRequest, TranscriptionSource as RemoteTimeoutSource,
 };
 use crate::remote::settings::RemoteSettings;
+use crate::state::recording_events::{emit_recording_event, RecordingPhase};
 use crate::transcription::error::TranscriptionErrorCode;
 use crate::transcription::executor::transcribe_with_app;
 use crate::transcription::request::{
@@ -35,7 +36,7 @@ use crate::utils::logger::*;
 use crate::utils::system_monitor;
 use crate::whisper::cache::TranscriberCache;
 use crate::whisper::manager::WhisperManager;
-use crate::whisper::transcriber::WhisperTranscriptionOutput;
+use crate::whisper::transcriber::{DecodingOptions, WhisperTranscriptionOutput};
 use crate::{
     emit_to_all, emit_to_window, update_recording_state, AppState, RecordingMode, RecordingState,
 };
@@ -53,12 +54,17 @@ use uuid::Uuid;
 pub(crate) const PTT_START_ABORTED_AFTER_RELEASE: &str =
     "PTT key released before recording could start";
 
+pub(crate) const MICROPHONE_MUTED: &str = "Microphone is muted";
+
 /// Atomic counter for toast IDs to prevent race conditions
 static TOAST_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
 
 /// Global media pause controller for pausing/resuming system media during recording
 static MEDIA_CONTROLLER: Lazy<MediaPauseController> = Lazy::new(MediaPauseController::new);
 
+/// How long `test_media_pause` keeps media paused before resuming it
+const MEDIA_PAUSE_TEST_HOLD: std::time::Duration = std::time::Duration::from_millis(1500);
+
 /// Monotonically increasing recording-generation counter. `start_recording`
 /// bumps it to open a new generation; a transcription task captures the value
 /// at spawn time and rejects its own result when the generation has advanced
@@ -87,42 +93,46 @@ pub(crate) fn recording_generation_is_stale(captured: u64) -> bool {
     captured != current_recording_generation()
 }
 
-/// Audio file owned by the currently in-flight transcription task, keyed by
-/// the recording generation that owns it. `cancel_recording` takes the current
-/// slot and deletes that file; a task's own cleanup only clears the slot when
-/// its captured generation still owns it, so a stale task can never erase a
-/// newer recording's tracker.
-static IN_FLIGHT_TRANSCRIPTION_AUDIO: Lazy<Mutex<Option<(u64, PathBuf)>>> =
-    Lazy::new(|| Mutex::new(None));
+/// Audio files owned by in-flight transcription jobs, one slot per recording
+/// generation so queued jobs keep their own. `cancel_recording` takes the
+/// slot of the job it cancels and deletes that file; a task's own cleanup only
+/// clears its own generation's slot, so a stale task can never erase a newer
+/// recording's tracker.
+static IN_FLIGHT_TRANSCRIPTION_AUDIO: Lazy<Mutex<Vec<(u64, PathBuf)>>> =
+    Lazy::new(|| Mutex::new(Vec::new()));
 
 /// Record the audio path the in-flight transcription task owns for this
 /// generation, so a `cancel_recording` that aborts that task can still delete
 /// the file.
 pub(crate) fn set_in_flight_transcription_audio(generation: u64, path: PathBuf) {
     if let Ok(mut guard) = IN_FLIGHT_TRANSCRIPTION_AUDIO.lock() {
-        *guard = Some((generation, path));
+        // Early-return paths remove their file without clearing the slot
+        guard.retain(|(tracked_generation, tracked_path)| {
+            *tracked_generation != generation && tracked_path.exists()
+        });
+        guard.push((generation, path));
     }
 }
 
-/// Remove and return the currently tracked in-flight transcription path. Used
-/// by `cancel_recording`, which always cancels the current recording attempt.
+/// Remove and return the tracked path of the current recording generation.
 pub(crate) fn take_in_flight_transcription_audio() -> Option<PathBuf> {
-    IN_FLIGHT_TRANSCRIPTION_AUDIO
-        .lock()
-        .ok()
-        .and_then(|mut guard| guard.take().map(|(_, path)| path))
+    take_in_flight_transcription_audio_for_generation(current_recording_generation())
+}
+
+/// Remove and return the path tracked for `generation`. Used by
+/// `cancel_recording` for the job it aborted.
+pub(crate) fn take_in_flight_transcription_audio_for_generation(
+    generation: u64,
+) -> Option<PathBuf> {
+    let mut guard = IN_FLIGHT_TRANSCRIPTION_AUDIO.lock().ok()?;
+    let index = guard
+        .iter()
+        .position(|(tracked_generation, _)| *tracked_generation == generation)?;
+    Some(guard.remove(index).1)
 }
 
 fn clear_in_flight_transcription_audio_for_generation(generation: u64) {
-    if let Ok(mut guard) = IN_FLIGHT_TRANSCRIPTION_AUDIO.lock() {
-        if guard
-            .as_ref()
-            .map(|(tracked_generation, _)| *tracked_generation == generation)
-            .unwrap_or(false)
-        {
-            *guard = None;
-        }
-    }
+    let _ = take_in_flight_transcription_audio_for_generation(generation);
 }
 
 /// Remove the task-owned temp recording and release the in-flight tracker slot
@@ -185,11 +195,66 @@ pub(crate) fn delete_persisted_recording(recordings_dir: &Path, filename: &str)
 /// `delete_persisted_recording` for the spawn-internal recheck sites, where
 /// only the saved filename (not the dir) is in scope.
 async fn revoke_saved_recording(app: &AppHandle, filename: &str) {
-    let Ok(dir) = app.path().app_data_dir() else {
+    let Ok(dir) = resolve_recordings_dir(app) else {
         return;
     };
-    delete_persisted_recording(&dir.join("recordings"), filename);
+    delete_persisted_recording(&dir, filename);
+}
+
+/// Subfolder created inside a user-chosen `recordings_dir`. Retention and the
+/// orphan sweep delete audio files, so they must never run on the chosen
+/// folder itself (e.g. ~/Music).
+pub(crate) const RECORDINGS_SUBFOLDER: &str = "voicetypr";
+
+/// Folder for captures and saved recordings: a `voicetypr` subfolder of the
+/// `recordings_dir` setting when it holds an absolute path, otherwise
+/// `recordings` under app data.
+pub(crate) fn resolve_recordings_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let custom = app
+        .store("settings")
+        .ok()
+        .and_then(|store| store.get("recordings_dir"))
+        .and_then(|v| {
+            v.as_str()
+                .map(crate::commands::settings::normalize_recordings_dir)
+        })
+        .filter(|dir| !dir.is_empty());
+    match custom {
+        Some(dir) => Ok(PathBuf::from(dir).join(RECORDINGS_SUBFOLDER)),
+        None => default_recordings_dir(app),
+    }
+}
+
+pub(crate) fn default_recordings_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("recordings"))
+        .map_err(|e| e.to_string())
+}
+/// Where a saved recording lives. History rows store bare filenames, so a
+/// recording saved before `recordings_dir` changed is still found in the
+/// previous default folder.
+pub(crate) fn locate_recording(current_dir: &Path, default_dir: &Path, filename: &str) -> PathBuf {
+    let current = current_dir.join(filename);
+    if current.exists() {
+        return current;
+    }
+    let previous = default_dir.join(filename);
+    if previous.exists() {
+        previous
+    } else {
+        current
+    }
 }
+
+fn resolve_recording_file(app: &AppHandle, filename: &str) -> Result<PathBuf, String> {
+    let current_dir = resolve_recordings_dir(app)?;
+    Ok(match default_recordings_dir(app) {
+        Ok(default_dir) => locate_recording(&current_dir, &default_dir, filename),
+        Err(_) => current_dir.join(filename),
+    })
+}
+
 struct StopInFlightGuard(Arc<AtomicBool>);
 
 impl StopInFlightGuard {
@@ -214,16 +279,12 @@ fn stop_should_reset_to_idle(current: RecordingState) -> bool {
         RecordingState::Stopping | RecordingState::Transcribing
     )
 }
-/// Whether a transcription task is currently running (spawned and not yet
+/// Whether a transcription task is currently running or queued (not yet
 /// finished). Used to distinguish a genuinely stuck `Stopping` state (no work
 /// will ever advance it) from a `Stopping` state that is merely waiting for a
 /// just-spawned transcription task to flip to `Transcribing`.
 fn transcription_task_in_flight(app_state: &AppState) -> bool {
-    app_state
-        .transcription_task
-        .lock()
-        .map(|guard| guard.as_ref().map(|h| !h.is_finished()).unwrap_or(false))
-        .unwrap_or(false)
+    app_state.transcription_queue.is_busy()
 }
 
 fn take_and_remove_current_recording_path(app_state: &AppState, reason: &str) {
@@ -706,17 +767,185 @@ pub(crate) fn page_history_keys(mut keys: Vec<String>, limit: usize) -> Vec<Stri
     keys
 }
 
+/// Keys to evict so history holds at most `max_entries` rows (0 = no cap).
+/// Oldest rows go first; rows for which `is_favorite` is true are never evicted,
+/// so favorites alone may still exceed the cap.
+pub(crate) fn history_keys_over_cap(
+    keys: Vec<String>,
+    max_entries: usize,
+    is_favorite: impl Fn(&str) -> bool,
+) -> Vec<String> {
+    if max_entries == 0 || keys.len() <= max_entries {
+        return Vec::new();
+    }
+    let excess = keys.len() - max_entries;
+    let newest_first = page_history_keys(keys, usize::MAX);
+    newest_first
+        .into_iter()
+        .rev()
+        .filter(|key| !is_favorite(key))
+        .take(excess)
+        .collect()
+}
+
+/// Keys of history rows saved before `cutoff`. Keys that are not RFC 3339
+/// timestamps are left alone.
+pub(crate) fn history_keys_older_than(
+    keys: Vec<String>,
+    cutoff: chrono::DateTime<chrono::Utc>,
+) -> Vec<String> {
+    keys.into_iter()
+        .filter(|key| {
+            chrono::DateTime::parse_from_rfc3339(key)
+                .map(|date| date < cutoff)
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+/// Keys removed by a scheduled cleanup: rows older than `cutoff` (if any),
+/// then the oldest non-favorites beyond `max_entries` among what remains.
+pub(crate) fn history_cleanup_keys(
+    keys: Vec<String>,
+    cutoff: Option<chrono::DateTime<chrono::Utc>>,
+    max_entries: usize,
+    is_favorite: impl Fn(&str) -> bool,
+) -> Vec<String> {
+    let mut removed = match cutoff {
+        Some(cutoff) => history_keys_older_than(keys.clone(), cutoff),
+        None => Vec::new(),
+    };
+    let remaining: Vec<String> = keys
+        .into_iter()
+        .filter(|key| !removed.contains(key))
+        .collect();
+    removed.extend(history_keys_over_cap(remaining, max_entries, is_favorite));
+    removed
+}
+
+/// A history row matching a search. `matches` holds the matched spans of the
+/// row's text as `[start, end)` UTF-16 offsets, merged and in order, so the UI
+/// can slice the JS string directly.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TranscriptionSearchHit {
+    #[serde(flatten)]
+    pub entry: serde_json::Value,
+    pub matches: Vec<(usize, usize)>,
+}
+
+/// `text` lowercased, plus for each byte of the result the byte range of the
+/// original char it came from (lowercasing can change a char's length).
+fn fold_case_with_origins(text: &str) -> (String, Vec<(usize, usize)>) {
+    let mut folded = String::with_capacity(text.len());
+    let mut origins = Vec::with_capacity(text.len());
+    for (start, ch) in text.char_indices() {
+        let end = start + ch.len_utf8();
+        for lower in ch.to_lowercase() {
+            folded.push(lower);
+            for _ in 0..lower.len_utf8() {
+                origins.push((start, end));
+            }
+        }
+    }
+    (folded, origins)
+}
+
+/// Spans of `text` matched by the search `terms`, or `None` unless every term
+/// occurs in it (case-insensitive substring match).
+pub(crate) fn history_search_matches(text: &str, terms: &[String]) -> Option<Vec<(usize, usize)>> {
+    let (folded, origins) = fold_case_with_origins(text);
+    let mut spans = Vec::new();
+    for term in terms {
+        let before = spans.len();
+        spans.extend(
+            folded
+                .match_indices(term.as_str())
+                .map(|(start, matched)| (origins[start].0, origins[start + matched.len() - 1].1)),
+        );
+        if spans.len() == before {
+            return None;
+        }
+    }
+    spans.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(spans.len());
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    let utf16_offset = |byte: usize| text[..byte].encode_utf16().count();
+    Some(
+        merged
+            .into_iter()
+            .map(|(start, end)| (utf16_offset(start), utf16_offset(end)))
+            .collect(),
+    )
+}
+
+/// Newest-first history rows whose text contains every whitespace-separated
+/// term of `query`, at most `limit` of them. A blank query matches nothing.
+pub(crate) fn search_history_entries(
+    keys: Vec<String>,
+    get: impl Fn(&str) -> Option<serde_json::Value>,
+    query: &str,
+    limit: usize,
+) -> Vec<TranscriptionSearchHit> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+    let mut hits = Vec::new();
+    for key in page_history_keys(keys, usize::MAX) {
+        if hits.len() >= limit {
+            break;
+        }
+        let Some(mut entry) = get(&key) else {
+            continue;
+        };
+        let Some(matches) = entry
+            .get("text")
+            .and_then(|v| v.as_str())
+            .and_then(|text| history_search_matches(text, &terms))
+        else {
+            continue;
+        };
+        if let Some(map) = entry.as_object_mut() {
+            map.entry("timestamp")
+                .or_insert_with(|| serde_json::Value::String(key.clone()));
+        }
+        hits.push(TranscriptionSearchHit { entry, matches });
+    }
+    hits
+}
+
+/// Text as compared by the duplicate-save guard: trimmed, lowercased and with
+/// whitespace runs collapsed to single spaces.
+fn normalize_for_duplicate_check(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Whether saving `text` would repeat the latest history row: same model,
+/// same normalized text, and saved no more than `window_secs` apart. A zero
+/// window disables the guard.
 pub(crate) fn is_duplicate_transcription(
     latest_key: &str,
     latest: &serde_json::Value,
     text: &str,
     model: &str,
     now: chrono::DateTime<chrono::Utc>,
+    window_secs: u64,
 ) -> bool {
+    if window_secs == 0 {
+        return false;
+    }
     let same_text = latest
         .get("text")
         .and_then(|x| x.as_str())
-        .map(|s| s == text)
+        .map(|s| normalize_for_duplicate_check(s) == normalize_for_duplicate_check(text))
         .unwrap_or(false);
     let same_model = latest
         .get("model")
@@ -731,7 +960,7 @@ pub(crate) fn is_duplicate_transcription(
                 .num_seconds()
                 .checked_abs()
         })
-        .map(|secs| secs <= 2)
+        .map(|secs| secs as u64 <= window_secs)
         .unwrap_or(false);
 
     same_text && same_model && within_window
@@ -903,6 +1132,7 @@ fn build_desktop_transcription_request(
     job: &TranscriptionJob,
     spoken_language: Option<String>,
     audio_path: PathBuf,
+    whisper_initial_prompt: &str,
 ) -> Result<TranscriptionRequest, TranscriptionFailure> {
     let engine = ProviderEngine::from_engine_str(active.engine_name()).ok_or_else(|| {
         TranscriptionFailure::Local(format!(
@@ -911,7 +1141,7 @@ fn build_desktop_transcription_request(
         ))
     })?;
     let initial_prompt = if matches!(active, ActiveEngineSelection::Whisper { .. }) {
-        compile_whisper_initial_prompt(app, spoken_language.as_deref())
+        compile_whisper_initial_prompt(app, spoken_language.as_deref(), whisper_initial_prompt)
     } else {
         None
     };
@@ -1087,6 +1317,13 @@ fn build_remote_transcription_result(
     result
 }
 
+/// Whether a transcript's mean token confidence is low enough to suggest
+/// re-recording. Engines without a confidence score and a zero threshold
+/// never warn.
+fn is_low_confidence(avg_confidence: Option<f32>, threshold: f32) -> bool {
+    threshold > 0.0 && avg_confidence.is_some_and(|confidence| confidence < threshold)
+}
+
 fn build_writing_history_metadata(
     transcription: &TranscriptionResult,
     writing: Option<&crate::writing::WritingResult>,
@@ -1103,6 +1340,9 @@ fn build_writing_history_metadata(
     if let Some(v) = transcription.timings.processing_duration_ms {
         map.insert("processing_duration_ms".into(), v.into());
     }
+    if let Some(v) = transcription.avg_confidence {
+        map.insert("avg_confidence".into(), v.into());
+    }
     map.insert("diarized".into(), transcription.words.is_some().into());
     if let Some(wr) = writing {
         map.insert(
@@ -1196,6 +1436,11 @@ fn is_ai_auth_error(error: &AiProviderError) -> bool {
 }
 
 fn emit_enhancing_failed(app: &AppHandle, error: &AiProviderError) {
+    emit_recording_event(
+        app,
+        RecordingPhase::EnhancingFailed,
+        Some(error.to_string()),
+    );
     if app.webview_windows().is_empty() {
         return;
     }
@@ -1229,11 +1474,13 @@ async fn save_ai_polish_fallback_history(
         app.clone(),
         writing_result.final_text.clone(),
         transcription.model.clone(),
+        Some(transcription.engine.clone()),
         None,
         Some(build_writing_history_metadata(
             transcription,
             Some(writing_result),
         )),
+        transcription.segments.clone(),
     )
     .await?;
     let _ = emit_to_window(&app, "main", "history-updated", ());
@@ -1263,6 +1510,17 @@ fn plan_desktop_writing_success(
     }
 }
 
+/// A dry run returns the raw transcript to its caller and nothing else: no
+/// writing pipeline, no delivery and no history entry.
+fn plan_dry_run_success(transcription: &TranscriptionResult) -> DesktopWritingSuccessPlan {
+    DesktopWritingSuccessPlan {
+        final_text: transcription.raw_text.clone(),
+        writing_metadata: None,
+        should_deliver: false,
+        save_history_entries: 0,
+    }
+}
+
 fn load_ai_enabled(app: &AppHandle) -> Result<bool, String> {
     let store = app.store("settings").map_err(|e| e.to_string())?;
     Ok(store
@@ -1314,8 +1572,45 @@ pub(crate) fn compile_parakeet_custom_vocabulary_for_transcription(
     crate::writing::compile_parakeet_custom_vocabulary(&settings, language)
 }
 
-fn compile_whisper_initial_prompt(app: &AppHandle, language: Option<&str>) -> Option<String> {
-    compile_remote_request_context(app, language)
+/// The global Whisper initial prompt, for flows that run without a recording profile.
+fn stored_whisper_initial_prompt(app: &AppHandle) -> String {
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get("whisper_initial_prompt"))
+        .and_then(|value| {
+            value
+                .as_str()
+                .map(crate::commands::settings::normalize_whisper_initial_prompt)
+        })
+        .unwrap_or_default()
+}
+
+/// Beam width and temperature fallback for in-process Whisper decoding.
+pub(crate) fn whisper_decoding_options(app: &AppHandle) -> DecodingOptions {
+    let Ok(store) = app.store("settings") else {
+        return DecodingOptions::default();
+    };
+    DecodingOptions {
+        beam_size: store
+            .get("whisper_beam_size")
+            .and_then(|v| v.as_u64())
+            .map(crate::commands::settings::normalize_whisper_beam_size),
+        temperature_fallback: store
+            .get("whisper_temperature_fallback")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true),
+    }
+}
+
+fn compile_whisper_initial_prompt(
+    app: &AppHandle,
+    language: Option<&str>,
+    user_prompt: &str,
+) -> Option<String> {
+    crate::writing::combine_whisper_initial_prompt(
+        user_prompt,
+        compile_remote_request_context(app, language),
+    )
 }
 
 #[cfg(target_os = "windows")]
@@ -1439,15 +1734,22 @@ where
     let audio_path = audio_path.to_path_buf();
     let language = language.map(str::to_owned);
     let initial_prompt = initial_prompt.map(str::to_owned);
+    let decoding = whisper_decoding_options(app);
     let should_cancel_for_decode = should_cancel.clone();
     let result = tokio::task::spawn_blocking(move || {
-        transcriber.transcribe_with_metadata_with_prompt(
+        let output = transcriber.transcribe_with_metadata_with_prompt(
             &audio_path,
             language.as_deref(),
             translate,
             initial_prompt.as_deref(),
+            decoding,
             should_cancel_for_decode,
-        )
+        )?;
+        // Read back on the same worker, right after this run
+        Ok::<_, String>(WhisperTranscriptionOutput {
+            avg_confidence: transcriber.last_avg_confidence(),
+            ..output
+        })
     })
     .await
     .map_err(|error| format!("Whisper transcription worker failed: {error}"))?;
@@ -1465,6 +1767,143 @@ where
     result
 }
 
+/// Whether [`transcribe_whisper_with_acceleration`] will try the Windows
+/// Vulkan sidecar first. Always false on other platforms.
+async fn whisper_prefers_gpu_sidecar(app: &AppHandle) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        let mode = transcription_acceleration_mode(app).await;
+        if mode == "cpu" {
+            return false;
+        }
+        let gpu_client = app.state::<crate::whisper::gpu_sidecar::GpuSidecarClient>();
+        mode == "gpu" || gpu_client.status().await.gpu_available != Some(false)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = app;
+        false
+    }
+}
+
+/// Auto language mode for Whisper: detect the language from the opening
+/// seconds of `audio_path` and show the choice on the pill. Returns
+/// `configured` when detection fails or is below
+/// [`DETECTION_MIN_CONFIDENCE`](crate::whisper::languages::DETECTION_MIN_CONFIDENCE).
+/// Skipped when the GPU sidecar will transcribe, since detecting in-process
+/// would load a second copy of the model on the CPU.
+async fn detect_whisper_language(
+    app: &AppHandle,
+    model_path: &Path,
+    audio_path: &Path,
+    configured: Option<String>,
+) -> Option<String> {
+    use crate::whisper::languages::{get_language_name, resolve_detected_language};
+
+    if whisper_prefers_gpu_sidecar(app).await {
+        log::info!("[LANGUAGE] Skipping detection, the GPU sidecar transcribes this recording");
+        return configured;
+    }
+
+    let transcriber = {
+        let cache_state = app.state::<AsyncMutex<TranscriberCache>>();
+        let mut cache = cache_state.lock().await;
+        match cache.get_or_create(model_path) {
+            Ok(transcriber) => transcriber,
+            Err(error) => {
+                log::warn!("[LANGUAGE] Skipping detection, model unavailable: {error}");
+                return configured;
+            }
+        }
+    };
+
+    let path = audio_path.to_path_buf();
+    let app_for_cancel = app.clone();
+    let detected = tokio::task::spawn_blocking(move || {
+        transcriber.detect_language_in_file(&path, || {
+            app_for_cancel
+                .state::<AppState>()
+                .is_cancellation_requested()
+        })
+    })
+    .await;
+    let (code, confidence) = match detected {
+        Ok(Ok(detected)) => detected,
+        Ok(Err(error)) => {
+            log::warn!("[LANGUAGE] Detection failed, using configured language: {error}");
+            return configured;
+        }
+        Err(error) => {
+            log::warn!("[LANGUAGE] Detection worker failed: {error}");
+            return configured;
+        }
+    };
+
+    let fallback = configured.as_deref().unwrap_or("en");
+    let chosen = resolve_detected_language(&code, confidence, fallback);
+    let name = get_language_name(&chosen).unwrap_or(chosen.as_str());
+    let message = if chosen == code {
+        format!("Detected {name}")
+    } else {
+        log::info!(
+            "[LANGUAGE] '{}' below confidence threshold ({:.2}), using '{}'",
+            code,
+            confidence,
+            chosen
+        );
+        format!("Using {name}")
+    };
+    pill_toast(app, &message, 1500);
+
+    Some(chosen)
+}
+
+/// Whisper with word timestamps. Always runs the in-process (CPU) transcriber,
+/// since the GPU sidecar does not report token timings.
+async fn transcribe_whisper_with_words(
+    app: &AppHandle,
+    model_path: &Path,
+    audio_path: &Path,
+    language: Option<&str>,
+    translate: bool,
+    initial_prompt: Option<&str>,
+) -> Result<WhisperTranscriptionOutput, String> {
+    let transcriber = {
+        let cache_state = app.state::<AsyncMutex<TranscriberCache>>();
+        let mut cache = cache_state.lock().await;
+        cache.get_or_create(model_path)?
+    };
+
+    let audio_path = audio_path.to_path_buf();
+    let language = language.map(str::to_owned);
+    let initial_prompt = initial_prompt.map(str::to_owned);
+    let decoding = whisper_decoding_options(app);
+    tokio::task::spawn_blocking(move || {
+        transcriber.transcribe_with_words(
+            &audio_path,
+            language.as_deref(),
+            translate,
+            initial_prompt.as_deref(),
+            decoding,
+        )
+    })
+    .await
+    .map_err(|error| format!("Whisper transcription worker failed: {error}"))?
+}
+
+fn word_timing_to_transcription_word(
+    word: crate::whisper::transcriber::WordTiming,
+) -> TranscriptionWord {
+    TranscriptionWord {
+        text: word.text,
+        start_ms: Some(word.start_ms),
+        end_ms: Some(word.end_ms),
+        speaker_id: None,
+        confidence: Some(word.confidence),
+    }
+}
+
 fn build_remote_upload_transcription_request(
     audio_path: &Path,
     audio_data: Vec<u8>,
@@ -1499,27 +1938,41 @@ mod tests {
         build_failed_transcription_row, build_remote_server_error_payload,
         build_remote_transcription_result, build_remote_upload_transcription_request,
         build_transcription_job, build_translation_failed_history_metadata,
-        build_writing_history_metadata, classify_local_failure, finalize_in_flight_audio,
-        is_ai_auth_error, is_non_speech_transcript, persist_if_current,
-        plan_desktop_writing_success, recording_license_state, remote_server_error_pill_message,
+        build_writing_history_metadata, check_max_duration, classify_local_failure,
+        delayed_pill_hide_still_wanted, delayed_pill_still_wanted, delivery_target,
+        finalize_in_flight_audio, fresh_key_check, fresh_reachability, is_ai_auth_error,
+        is_low_confidence, is_non_speech_transcript, persist_if_current,
+        plan_desktop_writing_success, plan_dry_run_success, recording_license_state,
+        remote_server_error_pill_message, run_transcription_batch, session_language_override,
         set_in_flight_transcription_audio, should_hide_pill_when_idle, should_use_active_remote,
         silence_event_runs_in_state, silence_timeout_disposition, stop_should_reset_to_idle,
         sync_retranscription_failure_metadata, take_in_flight_transcription_audio,
-        toast_clear_is_current, transcription_watchdog_budget, LocalFailureKind,
-        NormalizedTempFile, PillToastEventPayload, RecordingLicenseState, SilenceDetectorEvent,
-        SilenceTimeoutDisposition, StopInFlightGuard, TranscriptionFailure, TranscriptionStatus,
+        toast_clear_is_current, transcription_watchdog_budget, CloudKeyStatus, DeliveryTarget,
+        LocalFailureKind, MaxDurationCheck, NormalizedTempFile, PillToastEventPayload,
+        RecordingLicenseState, SilenceDetectorEvent, SilenceTimeoutDisposition, StopInFlightGuard,
+        TranscriptionFailure, TranscriptionStatus, CLOUD_REACHABILITY_TTL,
+        MAX_DURATION_PAUSED_POLL, SONIOX_KEY_CHECK_TTL,
     };
     use crate::commands::license::CachedLicense;
     use crate::license::{LicenseState, LicenseStatus};
     use crate::remote::client::{
         calculate_timeout_ms, RemoteClientError, RemoteEndpoint, TranscriptionSource,
     };
+    use crate::state::app_state::RecordingClock;
     use crate::{AppState, RecordingState};
     use reqwest::StatusCode;
     use std::fs;
     use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
     use std::sync::Arc;
 
+    #[test]
+    fn session_language_override_accepts_supported_codes_only() {
+        assert_eq!(session_language_override("de"), Some("de"));
+        assert_eq!(session_language_override(" FR "), Some("fr"));
+        assert_eq!(session_language_override("xx"), None);
+        assert_eq!(session_language_override(""), None);
+    }
+
     fn cached_license(status: LicenseState) -> CachedLicense {
         CachedLicense::new(LicenseStatus {
             status,
@@ -1527,6 +1980,7 @@ mod tests {
             license_type: None,
             license_key: None,
             expires_at: None,
+            offline_grace_days_left: None,
         })
     }
 
@@ -1834,6 +2288,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dry_run_returns_raw_text_without_delivery_or_history() {
+        let transcription = crate::transcription::TranscriptionResult::new(
+            &build_transcription_job(
+                crate::transcription::TranscriptionSource::DesktopRecording,
+                "whisper",
+                "base",
+                Some("en".to_string()),
+                false,
+            ),
+            "testing one two three",
+        );
+
+        let plan = plan_dry_run_success(&transcription);
+
+        assert_eq!(plan.final_text, "testing one two three");
+        assert!(!plan.should_deliver);
+        assert_eq!(plan.save_history_entries, 0);
+        assert!(plan.writing_metadata.is_none());
+    }
+
     #[test]
     fn ai_polish_failure_payload_keeps_enhancing_failed_compatible() {
         let payload = ai_failure_payload(&crate::ai::error::AiProviderError::RateLimited);
@@ -2226,6 +2701,7 @@ mod tests {
                 audio_duration_ms: Some(5000),
                 processing_duration_ms: Some(1200),
             },
+            avg_confidence: None,
         }
     }
 
@@ -2293,6 +2769,22 @@ mod tests {
         let obj = meta.as_object().unwrap();
         assert!(!obj.contains_key("audio_duration_ms"));
         assert!(!obj.contains_key("processing_duration_ms"));
+        assert!(!obj.contains_key("avg_confidence"));
+    }
+
+    #[test]
+    fn writing_metadata_records_avg_confidence() {
+        let tr = minimal_transcription_result().with_avg_confidence(Some(0.25));
+        let meta = build_writing_history_metadata(&tr, None);
+        assert!((meta["avg_confidence"].as_f64().unwrap() - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn low_confidence_needs_a_score_below_an_enabled_threshold() {
+        assert!(is_low_confidence(Some(0.2), 0.35));
+        assert!(!is_low_confidence(Some(0.6), 0.35));
+        assert!(!is_low_confidence(None, 0.35));
+        assert!(!is_low_confidence(Some(0.2), 0.0));
     }
 
     #[test]
@@ -2334,6 +2826,40 @@ mod tests {
         assert!(!data["writing"]["diarized"].as_bool().unwrap());
     }
 
+    #[test]
+    fn history_row_engine_prefers_explicit_then_writing_metadata() {
+        let metadata = serde_json::json!({ "engine": "parakeet" });
+        assert_eq!(
+            history_row_engine(Some("whisper"), Some(&metadata)).as_deref(),
+            Some("whisper")
+        );
+        assert_eq!(
+            history_row_engine(None, Some(&metadata)).as_deref(),
+            Some("parakeet")
+        );
+        assert_eq!(history_row_engine(Some("  "), None), None);
+        assert_eq!(history_row_engine(None, None), None);
+    }
+
+    #[test]
+    fn with_history_engine_backfills_legacy_rows() {
+        let stored = serde_json::json!({ "text": "a", "model": "large-v3", "engine": "whisper" });
+        assert_eq!(with_history_engine(stored.clone()), stored);
+
+        let from_metadata = with_history_engine(serde_json::json!({
+            "text": "b",
+            "model": "parakeet-tdt-0.6b-v3",
+            "writing": { "engine": "parakeet" }
+        }));
+        assert_eq!(from_metadata["engine"], "parakeet");
+
+        let cloud = with_history_engine(serde_json::json!({ "text": "c", "model": "soniox" }));
+        assert_eq!(cloud["engine"], "soniox");
+
+        let unknown = with_history_engine(serde_json::json!({ "text": "d", "model": "base.en" }));
+        assert!(unknown.get("engine").is_none());
+    }
+
     #[test]
     fn save_transcription_without_metadata_no_writing_key() {
         // Verify the JSON assembly logic: None → data["writing"] is absent.
@@ -2539,40 +3065,352 @@ mod tests {
         assert_eq!(commits, 1, "cancelled generation must not run commit");
     }
 
-    #[tokio::test(flavor = "current_thread")]
-    #[allow(clippy::await_holding_lock)] // process-wide test serialization lock; current-thread runtime
-    async fn stale_task_cannot_clear_newer_in_flight_tracker() {
+    #[test]
+    fn delayed_pill_only_shows_for_the_same_running_recording() {
         let _guard = POST_TRANSCRIPTION_TEST_LOCK.lock().unwrap();
-        let stale_generation = begin_recording_generation();
-        let stale_path = unique_side_effect_path("stale-audio");
-        fs::write(&stale_path, b"stale audio").unwrap();
-        set_in_flight_transcription_audio(stale_generation, stale_path.clone());
+        let generation = begin_recording_generation();
+        assert!(delayed_pill_still_wanted(
+            generation,
+            &RecordingState::Recording
+        ));
+        assert!(!delayed_pill_still_wanted(
+            generation,
+            &RecordingState::Transcribing
+        ));
 
-        let newer_generation = begin_recording_generation();
-        let newer_path = unique_side_effect_path("newer-audio");
-        fs::write(&newer_path, b"newer audio").unwrap();
-        set_in_flight_transcription_audio(newer_generation, newer_path.clone());
+        let _newer_generation = begin_recording_generation();
+        assert!(!delayed_pill_still_wanted(
+            generation,
+            &RecordingState::Recording
+        ));
+    }
 
-        let stale_path_for_task = stale_path.clone();
-        tokio::spawn(async move {
-            finalize_in_flight_audio(stale_generation, &stale_path_for_task);
-        })
-        .await
-        .unwrap();
+    #[test]
+    fn max_duration_timer_stops_only_the_recording_that_ran_too_long() {
+        let _guard = POST_TRANSCRIPTION_TEST_LOCK.lock().unwrap();
+        let generation = begin_recording_generation();
+        let limit = std::time::Duration::from_millis(50);
+        let started = std::time::Instant::now();
+        let clock = Some(RecordingClock::start_at(started));
+        let after_limit = started + std::time::Duration::from_millis(60);
 
-        assert!(
-            !stale_path.exists(),
-            "stale task still removes its own temp file"
+        assert_eq!(
+            check_max_duration(
+                generation,
+                &RecordingState::Recording,
+                clock,
+                limit,
+                after_limit
+            ),
+            MaxDurationCheck::Due
         );
-        let tracked = take_in_flight_transcription_audio();
         assert_eq!(
-            tracked.as_ref(),
-            Some(&newer_path),
-            "stale finalization must not clear the newer generation tracker"
+            check_max_duration(
+                generation,
+                &RecordingState::Recording,
+                clock,
+                limit,
+                started + std::time::Duration::from_millis(10)
+            ),
+            MaxDurationCheck::Wait(std::time::Duration::from_millis(40))
+        );
+        assert_eq!(
+            check_max_duration(
+                generation,
+                &RecordingState::Transcribing,
+                clock,
+                limit,
+                after_limit
+            ),
+            MaxDurationCheck::Over
+        );
+        assert_eq!(
+            check_max_duration(
+                generation,
+                &RecordingState::Recording,
+                None,
+                limit,
+                after_limit
+            ),
+            MaxDurationCheck::Over
+        );
+
+        let _newer_generation = begin_recording_generation();
+        assert_eq!(
+            check_max_duration(
+                generation,
+                &RecordingState::Recording,
+                clock,
+                limit,
+                after_limit
+            ),
+            MaxDurationCheck::Over
         );
-        if let Some(path) = tracked {
-            let _ = fs::remove_file(path);
-        }
+    }
+
+    #[test]
+    fn max_duration_timer_does_not_count_paused_time() {
+        let _guard = POST_TRANSCRIPTION_TEST_LOCK.lock().unwrap();
+        let generation = begin_recording_generation();
+        let limit = std::time::Duration::from_millis(50);
+        let ms = std::time::Duration::from_millis;
+        let started = std::time::Instant::now();
+        let mut clock = RecordingClock::start_at(started);
+
+        // Paused after 20 ms; the limit passes on the wall clock only
+        clock.set_paused_at(true, started + ms(20));
+        assert_eq!(
+            check_max_duration(
+                generation,
+                &RecordingState::Recording,
+                Some(clock),
+                limit,
+                started + ms(500)
+            ),
+            MaxDurationCheck::Wait(MAX_DURATION_PAUSED_POLL)
+        );
+
+        // Resumed at 520 ms: 30 ms of capture remain
+        clock.set_paused_at(false, started + ms(520));
+        assert_eq!(
+            check_max_duration(
+                generation,
+                &RecordingState::Recording,
+                Some(clock),
+                limit,
+                started + ms(520)
+            ),
+            MaxDurationCheck::Wait(ms(30))
+        );
+        assert_eq!(
+            check_max_duration(
+                generation,
+                &RecordingState::Recording,
+                Some(clock),
+                limit,
+                started + ms(550)
+            ),
+            MaxDurationCheck::Due
+        );
+    }
+
+    #[test]
+    fn clipboard_only_never_attempts_a_paste() {
+        assert_eq!(delivery_target(false, true, false), DeliveryTarget::Paste);
+        assert_eq!(
+            delivery_target(false, true, true),
+            DeliveryTarget::Clipboard
+        );
+        assert_eq!(
+            delivery_target(false, false, false),
+            DeliveryTarget::Clipboard
+        );
+        assert_eq!(
+            delivery_target(true, true, true),
+            DeliveryTarget::Scratchpad
+        );
+    }
+
+    #[test]
+    fn delayed_pill_hide_skips_once_a_new_recording_starts() {
+        let _guard = POST_TRANSCRIPTION_TEST_LOCK.lock().unwrap();
+        let generation = begin_recording_generation();
+
+        assert!(delayed_pill_hide_still_wanted(
+            generation,
+            &RecordingState::Idle
+        ));
+        assert!(!delayed_pill_hide_still_wanted(
+            generation,
+            &RecordingState::Recording
+        ));
+
+        let _newer_generation = begin_recording_generation();
+        assert!(!delayed_pill_hide_still_wanted(
+            generation,
+            &RecordingState::Idle
+        ));
+    }
+
+    #[test]
+    fn key_preflight_separates_rejected_keys_from_network_failures() {
+        use crate::cloud_stt::common::SttError;
+
+        assert_eq!(CloudKeyStatus::from_check(&Ok(())), CloudKeyStatus::Valid);
+        assert_eq!(
+            CloudKeyStatus::from_check(&Err(SttError::Auth)),
+            CloudKeyStatus::Invalid
+        );
+        assert_eq!(
+            CloudKeyStatus::from_check(&Err(SttError::Network)),
+            CloudKeyStatus::Unreachable
+        );
+        assert_eq!(
+            CloudKeyStatus::from_check(&Err(SttError::Timeout)),
+            CloudKeyStatus::Unreachable
+        );
+        // A struggling service says nothing about the key.
+        assert_eq!(
+            CloudKeyStatus::from_check(&Err(SttError::Server)),
+            CloudKeyStatus::Valid
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn batch_transcribes_in_order_and_skips_failed_files() {
+        let paths = vec![
+            "a.wav".to_string(),
+            "bad.wav".to_string(),
+            "c.wav".to_string(),
+        ];
+        let events = std::cell::RefCell::new(Vec::new());
+
+        let results = run_transcription_batch(
+            paths,
+            || false,
+            |path: String| async move {
+                if path == "bad.wav" {
+                    Err("unsupported format".to_string())
+                } else {
+                    Ok(format!("text of {}", path))
+                }
+            },
+            |index, _path, status, _error| events.borrow_mut().push((index, status.to_string())),
+        )
+        .await;
+
+        let order: Vec<&str> = results.iter().map(|entry| entry.path.as_str()).collect();
+        assert_eq!(order, vec!["a.wav", "bad.wav", "c.wav"]);
+        assert_eq!(results[0].text.as_deref(), Some("text of a.wav"));
+        assert_eq!(results[1].error.as_deref(), Some("unsupported format"));
+        assert!(results[1].text.is_none());
+        assert_eq!(results[2].text.as_deref(), Some("text of c.wav"));
+        assert_eq!(
+            events.into_inner(),
+            vec![
+                (0, "started".to_string()),
+                (0, "done".to_string()),
+                (1, "started".to_string()),
+                (1, "failed".to_string()),
+                (2, "started".to_string()),
+                (2, "done".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn batch_cancel_skips_the_files_after_the_current_one() {
+        let paths = vec![
+            "a.wav".to_string(),
+            "b.wav".to_string(),
+            "c.wav".to_string(),
+        ];
+        let cancel = AtomicBool::new(false);
+        let transcribed = std::cell::RefCell::new(Vec::new());
+
+        let results = run_transcription_batch(
+            paths,
+            || cancel.load(Ordering::SeqCst),
+            |path: String| {
+                transcribed.borrow_mut().push(path.clone());
+                // Cancel arrives while the second file is being transcribed
+                if path == "b.wav" {
+                    cancel.store(true, Ordering::SeqCst);
+                }
+                async move { Ok(path) }
+            },
+            |_, _, _, _| {},
+        )
+        .await;
+
+        assert_eq!(transcribed.into_inner(), vec!["a.wav", "b.wav"]);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[1].text.as_deref(), Some("b.wav"));
+        assert_eq!(results[2].path, "c.wav");
+        assert_eq!(results[2].error.as_deref(), Some("Cancelled"));
+    }
+
+    #[test]
+    fn key_preflight_result_is_reused_only_within_the_ttl() {
+        let checked_at = std::time::Instant::now();
+        let cached = Some((checked_at, CloudKeyStatus::Invalid));
+
+        assert_eq!(
+            fresh_key_check(cached, checked_at + std::time::Duration::from_secs(1)),
+            Some(CloudKeyStatus::Invalid)
+        );
+        assert_eq!(
+            fresh_key_check(cached, checked_at + SONIOX_KEY_CHECK_TTL),
+            None
+        );
+        assert_eq!(fresh_key_check(None, checked_at), None);
+    }
+
+    #[test]
+    fn reachability_probe_is_reused_only_for_the_same_url_within_the_ttl() {
+        let checked_at = std::time::Instant::now();
+        let cached = (checked_at, "https://api.soniox.com".to_string(), false);
+
+        assert_eq!(
+            fresh_reachability(
+                Some(&cached),
+                "https://api.soniox.com",
+                checked_at + std::time::Duration::from_secs(1)
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            fresh_reachability(
+                Some(&cached),
+                "https://api.soniox.com",
+                checked_at + CLOUD_REACHABILITY_TTL
+            ),
+            None
+        );
+        assert_eq!(
+            fresh_reachability(Some(&cached), "https://api.deepgram.com", checked_at),
+            None
+        );
+        assert_eq!(
+            fresh_reachability(None, "https://api.soniox.com", checked_at),
+            None
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    #[allow(clippy::await_holding_lock)] // process-wide test serialization lock; current-thread runtime
+    async fn stale_task_cannot_clear_newer_in_flight_tracker() {
+        let _guard = POST_TRANSCRIPTION_TEST_LOCK.lock().unwrap();
+        let stale_generation = begin_recording_generation();
+        let stale_path = unique_side_effect_path("stale-audio");
+        fs::write(&stale_path, b"stale audio").unwrap();
+        set_in_flight_transcription_audio(stale_generation, stale_path.clone());
+
+        let newer_generation = begin_recording_generation();
+        let newer_path = unique_side_effect_path("newer-audio");
+        fs::write(&newer_path, b"newer audio").unwrap();
+        set_in_flight_transcription_audio(newer_generation, newer_path.clone());
+
+        let stale_path_for_task = stale_path.clone();
+        tokio::spawn(async move {
+            finalize_in_flight_audio(stale_generation, &stale_path_for_task);
+        })
+        .await
+        .unwrap();
+
+        assert!(
+            !stale_path.exists(),
+            "stale task still removes its own temp file"
+        );
+        let tracked = take_in_flight_transcription_audio();
+        assert_eq!(
+            tracked.as_ref(),
+            Some(&newer_path),
+            "stale finalization must not clear the newer generation tracker"
+        );
+        if let Some(path) = tracked {
+            let _ = fs::remove_file(path);
+        }
     }
 
     #[tokio::test(flavor = "current_thread")]
@@ -2746,6 +3584,169 @@ fn play_recording_end_sound() {
     // No-op on other platforms
 }
 
+/// System-notification fallback for recording start/stop feedback, for
+/// full-screen apps that occlude the always-on-top pill.
+fn show_recording_indicator_notification(app: &AppHandle, body: &str) {
+    use tauri_plugin_notification::NotificationExt;
+
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title("VoiceTypr")
+        .body(body)
+        .show()
+    {
+        log::warn!("Failed to show recording indicator notification: {}", e);
+    }
+}
+
+async fn show_recording_pill(app: &AppHandle) {
+    match crate::commands::window::show_pill_widget(app.clone()).await {
+        Ok(_) => log::debug!("Pill widget shown successfully"),
+        Err(e) => {
+            log::warn!(
+                "Failed to show pill widget: {}. Recording will continue without visual feedback.",
+                e
+            );
+
+            // Emit event so frontend knows pill isn't visible
+            let _ = emit_to_window(
+                app,
+                "main",
+                "pill-widget-error",
+                "Recording indicator unavailable. Recording is still active.",
+            );
+        }
+    }
+}
+
+/// A delayed pill show only fires if the same recording is still running.
+fn delayed_pill_still_wanted(generation: u64, state: &RecordingState) -> bool {
+    !recording_generation_is_stale(generation) && *state == RecordingState::Recording
+}
+
+/// Where a finished dictation goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeliveryTarget {
+    Scratchpad,
+    Paste,
+    Clipboard,
+}
+
+/// Clipboard delivery (auto-paste off, or a profile's `clipboard_only`) copies
+/// the text without simulating a paste, so it never needs the accessibility
+/// permission.
+fn delivery_target(
+    scratchpad_mode: bool,
+    auto_paste: bool,
+    clipboard_only: bool,
+) -> DeliveryTarget {
+    if scratchpad_mode {
+        DeliveryTarget::Scratchpad
+    } else if auto_paste && !clipboard_only {
+        DeliveryTarget::Paste
+    } else {
+        DeliveryTarget::Clipboard
+    }
+}
+
+/// Whether a realtime session should type interim text at the cursor:
+/// `insert_streaming` is on and the dictation will be pasted at the cursor.
+fn type_streaming_partials(app: &AppHandle, clipboard_only: bool) -> bool {
+    let Ok(store) = app.store("settings") else {
+        return false;
+    };
+    let read_bool =
+        |key: &str, default: bool| store.get(key).and_then(|v| v.as_bool()).unwrap_or(default);
+    let sinks: Vec<String> = store
+        .get("transcription_sinks")
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+    read_bool("insert_streaming", false)
+        && delivery_target(
+            read_bool("scratchpad_mode", false),
+            read_bool("auto_paste_transcription", true),
+            clipboard_only,
+        ) == DeliveryTarget::Paste
+        && crate::text::sinks::parse_sinks(&sinks)
+            .contains(&crate::text::sinks::TranscriptionSink::Cursor)
+}
+
+/// Whether a deferred post-delivery pill hide still applies: no newer
+/// recording has started and the app is still idle.
+fn delayed_pill_hide_still_wanted(generation: u64, state: &RecordingState) -> bool {
+    !recording_generation_is_stale(generation) && *state == RecordingState::Idle
+}
+
+/// How often a paused recording's max-duration timer checks for a resume.
+const MAX_DURATION_PAUSED_POLL: std::time::Duration = std::time::Duration::from_millis(250);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MaxDurationCheck {
+    /// The recording the timer was armed for is over.
+    Over,
+    /// The recording has captured for `limit`; stop it.
+    Due,
+    /// Check again after this long.
+    Wait(std::time::Duration),
+}
+
+/// What the max-duration timer should do for the recording of `generation`.
+/// Paused time does not count toward `limit`.
+fn check_max_duration(
+    generation: u64,
+    state: &RecordingState,
+    clock: Option<crate::state::app_state::RecordingClock>,
+    limit: std::time::Duration,
+    now: Instant,
+) -> MaxDurationCheck {
+    let Some(clock) = clock.filter(|_| delayed_pill_still_wanted(generation, state)) else {
+        return MaxDurationCheck::Over;
+    };
+    let left = limit.saturating_sub(clock.elapsed_at(now));
+    if clock.is_paused() {
+        MaxDurationCheck::Wait(left.max(MAX_DURATION_PAUSED_POLL))
+    } else if left.is_zero() {
+        MaxDurationCheck::Due
+    } else {
+        MaxDurationCheck::Wait(left)
+    }
+}
+
+/// Stop the current recording once it has captured for `limit`, as if the
+/// user had pressed stop. `recording-max-duration` is emitted first so the UI
+/// can say why.
+fn spawn_max_duration_timer(app: AppHandle, generation: u64, limit: std::time::Duration) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let state = crate::get_recording_state(&app);
+            let clock = app
+                .state::<AppState>()
+                .recording_clock
+                .lock()
+                .ok()
+                .and_then(|guard| *guard);
+            match check_max_duration(generation, &state, clock, limit, Instant::now()) {
+                MaxDurationCheck::Over => return,
+                MaxDurationCheck::Due => break,
+                MaxDurationCheck::Wait(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+
+        log::info!(
+            "Recording reached the {}s maximum duration; stopping",
+            limit.as_secs()
+        );
+        let _ = emit_to_window(&app, "pill", "recording-max-duration", limit.as_secs());
+        let _ = emit_to_window(&app, "main", "recording-max-duration", limit.as_secs());
+        pill_toast(&app, "Maximum recording length reached", 1500);
+        let recorder_state = app.state::<RecorderState>();
+        if let Err(e) = stop_recording(app.clone(), recorder_state).await {
+            log::error!("Max-duration stop failed: {}", e);
+        }
+    });
+}
+
 /// Cached recording configuration to avoid repeated store access during transcription flow
 /// Cache is invalidated when settings change via update hooks
 #[derive(Clone, Debug)]
@@ -2761,6 +3762,32 @@ pub struct RecordingConfig {
     pub transcription_task: String,
     pub final_text_language: String,
     pub show_recording_status: bool,
+    pub recording_indicator: String, // "pill", "notification", "both", or "none"
+    pub pill_show_delay_ms: u64,
+    pub pill_hide_delay_ms: u64,
+    pub pill_hide_delay_error_ms: u64,
+    pub pill_hide_delay_empty_ms: u64,
+    pub max_queued_transcriptions: usize,
+    pub auto_engine_by_network: bool,
+    pub offline_fallback_model: String,
+    pub offline_fallback_engine: String,
+    pub soniox_realtime: bool,
+    pub soniox_fallback_local: bool,
+    pub silence_auto_stop: SilenceAutoStop,
+    pub mic_gain_db: f32,
+    pub auto_trim_silence: bool,
+    pub trim_silence_threshold_db: f32,
+    pub trim_max_gap_ms: u64,
+    pub clipping_warning_enabled: bool,
+    pub preferred_sample_rate: u32,
+    pub channel_select: crate::audio::converter::ChannelSelect,
+    pub max_recording_duration_s: u64,
+    pub low_confidence_threshold: f32,
+    pub whisper_initial_prompt: String,
+    pub auto_detect_language: bool,
+    pub profiles: Vec<AppProfile>,
+    // Copy instead of pasting even with auto-paste on (set by an app profile)
+    pub clipboard_only: bool,
     // Internal cache metadata
     loaded_at: Instant,
 }
@@ -2852,6 +3879,134 @@ impl RecordingConfig {
                 .get("show_recording_status")
                 .and_then(|v| v.as_bool())
                 .unwrap_or(true),
+            recording_indicator: crate::commands::settings::normalize_recording_indicator(
+                store
+                    .get("recording_indicator")
+                    .and_then(|v| v.as_str().map(|s| s.to_string()))
+                    .as_deref(),
+            ),
+            pill_show_delay_ms: store
+                .get("pill_show_delay_ms")
+                .and_then(|v| v.as_u64())
+                .map(|v| v.min(crate::commands::settings::MAX_PILL_SHOW_DELAY_MS as u64))
+                .unwrap_or(0),
+            pill_hide_delay_ms: store
+                .get("pill_hide_delay_ms")
+                .and_then(|v| v.as_u64())
+                .map(|v| v.min(crate::commands::settings::MAX_PILL_HIDE_DELAY_MS as u64))
+                .unwrap_or(0),
+            pill_hide_delay_error_ms: store
+                .get("pill_hide_delay_error_ms")
+                .and_then(|v| v.as_u64())
+                .map(|v| v.min(crate::commands::settings::MAX_PILL_HIDE_DELAY_MS as u64))
+                .unwrap_or(crate::commands::settings::DEFAULT_PILL_HIDE_DELAY_ERROR_MS as u64),
+            pill_hide_delay_empty_ms: store
+                .get("pill_hide_delay_empty_ms")
+                .and_then(|v| v.as_u64())
+                .map(|v| v.min(crate::commands::settings::MAX_PILL_HIDE_DELAY_MS as u64))
+                .unwrap_or(crate::commands::settings::DEFAULT_PILL_HIDE_DELAY_EMPTY_MS as u64),
+            max_queued_transcriptions: store
+                .get("max_queued_transcriptions")
+                .and_then(|v| v.as_u64())
+                .map(crate::commands::settings::normalize_max_queued_transcriptions)
+                .unwrap_or(crate::commands::settings::DEFAULT_MAX_QUEUED_TRANSCRIPTIONS)
+                as usize,
+            auto_engine_by_network: store
+                .get("auto_engine_by_network")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            offline_fallback_model: store
+                .get("offline_fallback_model")
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+                .unwrap_or_default(),
+            offline_fallback_engine: store
+                .get("offline_fallback_engine")
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+                .unwrap_or_default(),
+            soniox_realtime: store
+                .get("soniox_realtime")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            soniox_fallback_local: store
+                .get("soniox_fallback_local")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            silence_auto_stop: crate::commands::settings::silence_auto_stop_config(
+                store
+                    .get("silence_auto_stop_enabled")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true),
+                store
+                    .get("silence_threshold_db")
+                    .and_then(|v| v.as_f64())
+                    .map(|v| v as f32)
+                    .unwrap_or(crate::commands::settings::DEFAULT_SILENCE_THRESHOLD_DB),
+                store
+                    .get("silence_duration_ms")
+                    .and_then(|v| v.as_u64())
+                    .map(|v| v.min(u32::MAX as u64) as u32),
+            ),
+            mic_gain_db: crate::commands::settings::normalize_mic_gain_db(
+                store
+                    .get("mic_gain_db")
+                    .and_then(|v| v.as_f64())
+                    .unwrap_or(0.0),
+            ),
+            auto_trim_silence: store
+                .get("auto_trim_silence")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            trim_silence_threshold_db: store
+                .get("trim_silence_threshold_db")
+                .and_then(|v| v.as_f64())
+                .map(crate::commands::settings::normalize_trim_silence_threshold_db)
+                .unwrap_or(crate::commands::settings::DEFAULT_TRIM_SILENCE_THRESHOLD_DB),
+            trim_max_gap_ms: store
+                .get("trim_max_gap_ms")
+                .and_then(|v| v.as_u64())
+                .map(crate::commands::settings::normalize_trim_max_gap_ms)
+                .unwrap_or(crate::commands::settings::DEFAULT_TRIM_MAX_GAP_MS)
+                as u64,
+            clipping_warning_enabled: store
+                .get("clipping_warning_enabled")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true),
+            preferred_sample_rate: store
+                .get("preferred_sample_rate")
+                .and_then(|v| v.as_u64())
+                .map(crate::commands::settings::normalize_preferred_sample_rate)
+                .unwrap_or(crate::audio::recorder::TARGET_SAMPLE_RATE),
+            channel_select: store
+                .get("channel_select")
+                .and_then(|v| {
+                    v.as_str()
+                        .map(crate::audio::converter::ChannelSelect::parse)
+                })
+                .unwrap_or_default(),
+            max_recording_duration_s: store
+                .get("max_recording_duration_s")
+                .and_then(|v| v.as_u64())
+                .map(crate::commands::settings::normalize_max_recording_duration_s)
+                .unwrap_or(crate::commands::settings::DEFAULT_MAX_RECORDING_DURATION_S)
+                as u64,
+            low_confidence_threshold: store
+                .get("low_confidence_threshold")
+                .and_then(|v| v.as_f64())
+                .map(crate::commands::settings::normalize_low_confidence_threshold)
+                .unwrap_or(crate::commands::settings::DEFAULT_LOW_CONFIDENCE_THRESHOLD),
+            whisper_initial_prompt: store
+                .get("whisper_initial_prompt")
+                .and_then(|v| {
+                    v.as_str()
+                        .map(crate::commands::settings::normalize_whisper_initial_prompt)
+                })
+                .unwrap_or_default(),
+            auto_detect_language: store
+                .get("auto_detect_language")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            profiles: crate::commands::settings::app_profiles_from_store(store.get("profiles")),
+            clipboard_only: false,
             loaded_at: Instant::now(),
         };
         let mut config = config;
@@ -2867,6 +4022,37 @@ impl RecordingConfig {
     pub fn is_fresh(&self) -> bool {
         self.loaded_at.elapsed() < Self::MAX_CACHE_AGE
     }
+
+    /// Apply a per-app profile on top of the global settings.
+    pub fn with_profile(mut self, profile: &AppProfile) -> Self {
+        if !profile.model.is_empty() {
+            self.current_model = profile.model.clone();
+            if !profile.engine.is_empty() {
+                self.current_engine = profile.engine.clone();
+            }
+        }
+        if !profile.language.is_empty() {
+            self.speech_language = profile.language.clone();
+        }
+        if let Some(ai_enabled) = profile.ai_enabled {
+            self.ai_enabled = ai_enabled;
+        }
+        if let Some(clipboard_only) = profile.clipboard_only {
+            self.clipboard_only = clipboard_only;
+        }
+        if !profile.whisper_initial_prompt.is_empty() {
+            self.whisper_initial_prompt =
+                crate::commands::settings::normalize_whisper_initial_prompt(
+                    &profile.whisper_initial_prompt,
+                );
+        }
+        self.speech_language = normalize_speech_language_for_model(
+            &self.current_engine,
+            &self.current_model,
+            &self.speech_language,
+        );
+        self
+    }
 }
 
 // Implement UnwindSafe traits for panic testing compatibility
@@ -2941,10 +4127,10 @@ async fn save_recording_internal(
     }
 
     // Get recordings directory
-    let recordings_dir = match app.path().app_data_dir() {
-        Ok(dir) => dir.join("recordings"),
+    let recordings_dir = match resolve_recordings_dir(app) {
+        Ok(dir) => dir,
         Err(e) => {
-            log::error!("Failed to get app data directory: {}", e);
+            log::error!("Failed to resolve recordings directory: {}", e);
             return None;
         }
     };
@@ -2955,10 +4141,36 @@ async fn save_recording_internal(
         return None;
     }
 
-    // Generate filename: timestamp_uuid.wav
+    // Encode before entering the persistence chokepoint; the copy below stays
+    // synchronous. A failed encode keeps the recording as WAV.
+    let format = crate::ffmpeg::RecordingFormat::from_setting(
+        store
+            .get("recording_format")
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .as_deref()
+            .unwrap_or("wav"),
+    );
+    let encoded_path = audio_path.with_extension(format.as_str());
+    let (source_path, format) = if format == crate::ffmpeg::RecordingFormat::Wav {
+        (audio_path, format)
+    } else {
+        match crate::ffmpeg::encode_recording(app, audio_path, &encoded_path, format).await {
+            Ok(()) => (encoded_path.as_path(), format),
+            Err(e) => {
+                log::warn!(
+                    "Failed to encode recording as {}, saving WAV: {}",
+                    format.as_str(),
+                    e
+                );
+                (audio_path, crate::ffmpeg::RecordingFormat::Wav)
+            }
+        }
+    };
+
+    // Generate filename: timestamp_uuid.<format>
     let timestamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S");
     let uuid_part = uuid::Uuid::new_v4().to_string()[..8].to_string();
-    let filename = format!("{}_{}.wav", timestamp, uuid_part);
+    let filename = format!("{}_{}.{}", timestamp, uuid_part, format.as_str());
     let dest_path = recordings_dir.join(&filename);
 
     // Copy the file to persistent storage. The gated production path enters the
@@ -2967,11 +4179,14 @@ async fn save_recording_internal(
         Some(generation) => {
             let app_state = app.state::<AppState>();
             persist_if_current(&app_state, generation, || {
-                std::fs::copy(audio_path, &dest_path)
+                std::fs::copy(source_path, &dest_path)
             })
         }
-        None => Some(std::fs::copy(audio_path, &dest_path)),
+        None => Some(std::fs::copy(source_path, &dest_path)),
     };
+    if source_path != audio_path {
+        let _ = std::fs::remove_file(source_path);
+    }
 
     match copy_result {
         None => {
@@ -3006,10 +4221,10 @@ async fn save_recording_without_cleanup(
     audio_path: &Path,
     generation: Option<u64>,
 ) -> Option<String> {
-    let recordings_dir = match app.path().app_data_dir() {
-        Ok(dir) => dir.join("recordings"),
+    let recordings_dir = match resolve_recordings_dir(app) {
+        Ok(dir) => dir,
         Err(e) => {
-            log::error!("Failed to get app data directory: {}", e);
+            log::error!("Failed to resolve recordings directory: {}", e);
             return None;
         }
     };
@@ -3072,9 +4287,7 @@ fn cleanup_old_recordings(recordings_dir: &Path, retention_days: u32) {
 
     for entry in recordings.filter_map(|entry| entry.ok()) {
         let path = entry.path();
-        let is_wav = path.extension().map(|ext| ext == "wav").unwrap_or(false);
-
-        if !is_wav {
+        if crate::ffmpeg::RecordingFormat::from_path(&path).is_none() {
             continue;
         }
 
@@ -3101,11 +4314,7 @@ fn cleanup_old_recordings(recordings_dir: &Path, retention_days: u32) {
 /// Get the full path to the recordings directory
 #[tauri::command]
 pub async fn get_recordings_directory(app: AppHandle) -> Result<String, String> {
-    let recordings_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("recordings");
+    let recordings_dir = resolve_recordings_dir(&app)?;
 
     // Create if it doesn't exist
     std::fs::create_dir_all(&recordings_dir)
@@ -3117,11 +4326,7 @@ pub async fn get_recordings_directory(app: AppHandle) -> Result<String, String>
 /// Open the recordings directory in the system file manager
 #[tauri::command]
 pub async fn open_recordings_folder(app: AppHandle) -> Result<(), String> {
-    let recordings_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {}", e))?
-        .join("recordings");
+    let recordings_dir = resolve_recordings_dir(&app)?;
 
     // Create directory if it doesn't exist
     if !recordings_dir.exists() {
@@ -3195,42 +4400,340 @@ impl ActiveEngineSelection {
     }
 }
 
-async fn abort_due_to_missing_model(
-    app: &AppHandle,
-    audio_path: &Path,
-    log_message: &str,
-    user_message: &str,
-) -> Result<String, String> {
-    log::error!("{}", log_message);
-    update_recording_state(app, RecordingState::Error, Some(user_message.to_string()));
+/// How long to wait for a cloud provider's host before treating the network as offline.
+const CLOUD_REACHABILITY_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(1500);
 
-    if let Err(e) = std::fs::remove_file(audio_path) {
-        log::warn!("Failed to remove audio file: {}", e);
-    }
+// Whether the missing-ffmpeg warning has been shown this session
+static FFMPEG_MISSING_WARNED: AtomicBool = AtomicBool::new(false);
 
-    // Show pill toast for no models error
-    pill_toast(app, user_message, 2000);
-    // Bring the dashboard forward so the no-models error is actionable
-    // (the main window normally stays hidden in tray/pill mode).
-    let _ = crate::commands::window::focus_main_window(app.clone()).await;
+// Whether the last recording fell back to a local model because the cloud was unreachable
+static OFFLINE_FALLBACK_ACTIVE: AtomicBool = AtomicBool::new(false);
 
-    // Also emit domain event for main window
-    let _ = emit_to_window(
-        app,
-        "main",
-        "no-models-error",
-        serde_json::json!({
-            "title": "No Models Installed",
-            "message": user_message,
-            "action": "open-settings"
-        }),
-    );
+/// How long a cloud reachability probe is reused so back-to-back recordings
+/// don't each pay for a fresh connection.
+const CLOUD_REACHABILITY_TTL: std::time::Duration = std::time::Duration::from_secs(30);
 
-    if should_hide_pill(app).await {
-        if let Err(e) = crate::commands::window::hide_pill_widget(app.clone()).await {
-            log::error!("Failed to hide pill window: {}", e);
-        }
-    }
+// Last cloud reachability probe: when it ran, which URL it hit and whether it connected
+static CLOUD_REACHABILITY: Mutex<Option<(Instant, String, bool)>> = Mutex::new(None);
+
+/// The cached reachability of `url`, if it was probed recently enough at `now`.
+pub(crate) fn fresh_reachability(
+    cached: Option<&(Instant, String, bool)>,
+    url: &str,
+    now: Instant,
+) -> Option<bool> {
+    cached
+        .filter(|(checked_at, probed_url, _)| {
+            probed_url == url && now.saturating_duration_since(*checked_at) < CLOUD_REACHABILITY_TTL
+        })
+        .map(|(_, _, online)| *online)
+}
+
+/// Upper bound on the OS mute query so a slow probe cannot hold up recording.
+const MUTE_CHECK_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// How long a Soniox key pre-flight result is reused before checking again.
+const SONIOX_KEY_CHECK_TTL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Upper bound on the Soniox key pre-flight so a slow network cannot hold up recording.
+const SONIOX_KEY_CHECK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Minimum gap between clipping warnings so a loud passage doesn't stack toasts.
+const CLIP_WARNING_COOLDOWN: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Outcome of the record-time cloud key pre-flight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CloudKeyStatus {
+    Valid,
+    /// The provider rejected the key.
+    Invalid,
+    /// The provider could not be reached; the key may still be fine.
+    Unreachable,
+}
+
+impl CloudKeyStatus {
+    /// Only a rejected key or a failed connection is conclusive; a rate limit
+    /// or server error says nothing about the key, so recording goes ahead.
+    pub(crate) fn from_check(result: &Result<(), crate::cloud_stt::common::SttError>) -> Self {
+        use crate::cloud_stt::common::SttError;
+        match result {
+            Err(SttError::Auth) => Self::Invalid,
+            Err(SttError::Network | SttError::Timeout) => Self::Unreachable,
+            _ => Self::Valid,
+        }
+    }
+}
+
+// Last Soniox key pre-flight outcome and when it was taken
+static SONIOX_KEY_CHECK: Mutex<Option<(Instant, CloudKeyStatus)>> = Mutex::new(None);
+
+// Whether the current recording transcribes locally because the Soniox key was rejected
+static SONIOX_KEY_FALLBACK_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// The cached pre-flight outcome, if it is still fresh at `now`.
+pub(crate) fn fresh_key_check(
+    cached: Option<(Instant, CloudKeyStatus)>,
+    now: Instant,
+) -> Option<CloudKeyStatus> {
+    cached
+        .filter(|(checked_at, _)| now.saturating_duration_since(*checked_at) < SONIOX_KEY_CHECK_TTL)
+        .map(|(_, status)| status)
+}
+
+/// Forget the cached pre-flight so the next recording checks the key again.
+pub(crate) fn clear_soniox_key_check() {
+    if let Ok(mut cached) = SONIOX_KEY_CHECK.lock() {
+        *cached = None;
+    }
+}
+
+async fn soniox_key_status(app: &AppHandle) -> CloudKeyStatus {
+    let now = Instant::now();
+    let cached = SONIOX_KEY_CHECK.lock().ok().and_then(|cached| *cached);
+    if let Some(status) = fresh_key_check(cached, now) {
+        return status;
+    }
+
+    let result = tokio::time::timeout(
+        SONIOX_KEY_CHECK_TIMEOUT,
+        crate::cloud_stt::CloudProvider::Soniox.preflight_key(app),
+    )
+    .await
+    .unwrap_or(Err(crate::cloud_stt::common::SttError::Timeout));
+    let status = CloudKeyStatus::from_check(&result);
+    if let Ok(mut cached) = SONIOX_KEY_CHECK.lock() {
+        *cached = Some((now, status));
+    }
+    status
+}
+
+/// Check the Soniox key before recording so a rejected key surfaces now
+/// instead of as a failed upload afterwards. Emits `cloud-key-check` with a
+/// `reason` of `key_invalid` or `network_down` when the check does not pass.
+/// A rejected key records with a local model when `soniox_fallback_local` is
+/// on and one is downloaded; otherwise recording is refused.
+async fn preflight_soniox_key(app: &AppHandle) -> Result<(), String> {
+    SONIOX_KEY_FALLBACK_ACTIVE.store(false, AtomicOrdering::SeqCst);
+    let Ok(config) = get_recording_config(app).await else {
+        return Ok(());
+    };
+    if config.current_engine != crate::cloud_stt::CloudProvider::Soniox.id() {
+        return Ok(());
+    }
+
+    match soniox_key_status(app).await {
+        CloudKeyStatus::Valid => Ok(()),
+        CloudKeyStatus::Unreachable => {
+            log::warn!("Soniox unreachable during key pre-flight; recording anyway");
+            let _ = emit_to_all(
+                app,
+                "cloud-key-check",
+                serde_json::json!({
+                    "provider": "soniox",
+                    "reason": "network_down",
+                    "fallback": null,
+                }),
+            );
+            Ok(())
+        }
+        CloudKeyStatus::Invalid => {
+            let fallback = if config.soniox_fallback_local {
+                resolve_offline_fallback(app, &config).await
+            } else {
+                None
+            };
+            if let Some(fallback) = fallback {
+                log::warn!(
+                    "Soniox key rejected; recording with {} '{}' instead",
+                    fallback.engine_name(),
+                    fallback.model_name()
+                );
+                SONIOX_KEY_FALLBACK_ACTIVE.store(true, AtomicOrdering::SeqCst);
+                let _ = emit_to_all(
+                    app,
+                    "cloud-key-check",
+                    serde_json::json!({
+                        "provider": "soniox",
+                        "reason": "key_invalid",
+                        "fallback": {
+                            "engine": fallback.engine_name(),
+                            "model": fallback.model_name(),
+                        },
+                    }),
+                );
+                pill_toast(app, "Soniox key rejected. Using local model", 2500);
+                return Ok(());
+            }
+
+            log::error!("Recording blocked: Soniox key rejected");
+            let _ = crate::commands::window::focus_main_window(app.clone()).await;
+            let _ = emit_to_all(
+                app,
+                "cloud-key-check",
+                serde_json::json!({
+                    "provider": "soniox",
+                    "reason": "key_invalid",
+                    "fallback": null,
+                    "title": "Soniox Key Invalid",
+                    "message": "Soniox rejected your API key. Update it in Models before recording.",
+                    "action": "open-settings",
+                }),
+            );
+            Err("Soniox API key is invalid. Update it in Models before recording.".to_string())
+        }
+    }
+}
+
+/// Local engine used in place of a cloud provider while offline: the designated
+/// fallback model when it is installed, otherwise the best downloaded Whisper model.
+async fn resolve_offline_fallback(
+    app: &AppHandle,
+    config: &RecordingConfig,
+) -> Option<ActiveEngineSelection> {
+    let whisper_manager = app.state::<AsyncRwLock<WhisperManager>>();
+    let designated = config.offline_fallback_model.as_str();
+    if !designated.is_empty() {
+        if config.offline_fallback_engine == "parakeet" {
+            let downloaded = app
+                .state::<ParakeetManager>()
+                .list_models()
+                .into_iter()
+                .any(|model| model.name == designated && model.downloaded);
+            if downloaded {
+                return Some(ActiveEngineSelection::Parakeet {
+                    model_name: designated.to_string(),
+                });
+            }
+        } else if let Some(model_path) = whisper_manager.read().await.get_model_path(designated) {
+            return Some(ActiveEngineSelection::Whisper {
+                model_name: designated.to_string(),
+                model_path,
+            });
+        }
+        log::warn!(
+            "Offline fallback model '{}' is not installed; using best available Whisper model",
+            designated
+        );
+    }
+
+    let manager = whisper_manager.read().await;
+    let downloaded_models = manager.get_downloaded_model_names();
+    if downloaded_models.is_empty() {
+        return None;
+    }
+    let model_name =
+        select_best_fallback_model(&downloaded_models, "", &manager.get_models_by_size());
+    let model_path = manager.get_model_path(&model_name)?;
+    Some(ActiveEngineSelection::Whisper {
+        model_name,
+        model_path,
+    })
+}
+
+/// With `auto_engine_by_network` on, keep the cloud engine while its provider is
+/// reachable and swap in the local fallback while it is not. Emits
+/// `active-engine-changed` whenever the effective engine flips between the two.
+async fn select_engine_by_network(
+    app: &AppHandle,
+    config: &RecordingConfig,
+    provider: crate::cloud_stt::CloudProvider,
+    cloud_selection: ActiveEngineSelection,
+) -> ActiveEngineSelection {
+    let url = provider.base_url(app);
+    let now = Instant::now();
+    let cached = CLOUD_REACHABILITY
+        .lock()
+        .ok()
+        .and_then(|cached| fresh_reachability(cached.as_ref(), &url, now));
+    let online = match cached {
+        Some(online) => online,
+        None => {
+            let online = crate::utils::network_diagnostics::url_is_reachable(
+                &url,
+                CLOUD_REACHABILITY_TIMEOUT,
+            )
+            .await;
+            if let Ok(mut cached) = CLOUD_REACHABILITY.lock() {
+                *cached = Some((Instant::now(), url, online));
+            }
+            online
+        }
+    };
+
+    let selection = if online {
+        cloud_selection
+    } else {
+        match resolve_offline_fallback(app, config).await {
+            Some(fallback) => {
+                log::info!(
+                    "{} unreachable; transcribing offline with {} '{}'",
+                    provider.display_name(),
+                    fallback.engine_name(),
+                    fallback.model_name()
+                );
+                fallback
+            }
+            None => {
+                log::warn!(
+                    "{} unreachable and no local model installed; keeping cloud engine",
+                    provider.display_name()
+                );
+                cloud_selection
+            }
+        }
+    };
+
+    let using_fallback = !matches!(selection, ActiveEngineSelection::Cloud { .. });
+    if OFFLINE_FALLBACK_ACTIVE.swap(using_fallback, AtomicOrdering::SeqCst) != using_fallback {
+        let _ = emit_to_all(
+            app,
+            "active-engine-changed",
+            serde_json::json!({
+                "engine": selection.engine_name(),
+                "model": selection.model_name(),
+                "reason": if using_fallback { "offline" } else { "online" },
+            }),
+        );
+    }
+    selection
+}
+
+async fn abort_due_to_missing_model(
+    app: &AppHandle,
+    audio_path: &Path,
+    log_message: &str,
+    user_message: &str,
+) -> Result<String, String> {
+    log::error!("{}", log_message);
+    update_recording_state(app, RecordingState::Error, Some(user_message.to_string()));
+
+    if let Err(e) = std::fs::remove_file(audio_path) {
+        log::warn!("Failed to remove audio file: {}", e);
+    }
+
+    // Show pill toast for no models error
+    pill_toast(app, user_message, 2000);
+    // Bring the dashboard forward so the no-models error is actionable
+    // (the main window normally stays hidden in tray/pill mode).
+    let _ = crate::commands::window::focus_main_window(app.clone()).await;
+
+    // Also emit domain event for main window
+    let _ = emit_to_window(
+        app,
+        "main",
+        "no-models-error",
+        serde_json::json!({
+            "title": "No Models Installed",
+            "message": user_message,
+            "action": "open-settings"
+        }),
+    );
+
+    if should_hide_pill(app).await {
+        if let Err(e) = crate::commands::window::hide_pill_widget(app.clone()).await {
+            log::error!("Failed to hide pill window: {}", e);
+        }
+    }
 
     update_recording_state(app, RecordingState::Idle, None);
 
@@ -3379,6 +4882,69 @@ pub async fn invalidate_recording_config_cache(app: &AppHandle) {
     log::debug!("Recording config cache invalidated due to settings change");
 }
 
+/// Take the overrides a shortcut staged for the next start. Every start
+/// attempt takes them, so a rejected or aborted start cannot hand them to a
+/// later recording.
+pub(crate) fn take_shortcut_profile(app: &AppHandle) -> Option<AppProfile> {
+    app.state::<AppState>()
+        .shortcut_profile
+        .lock()
+        .ok()
+        .and_then(|mut staged| staged.take())
+}
+
+/// Resolve the profile for the frontmost app when a recording starts, layer
+/// the starting shortcut's overrides on top and remember the result, so the
+/// stop path transcribes with the same overrides.
+async fn begin_session_profile(
+    app: &AppHandle,
+    config: RecordingConfig,
+    shortcut_profile: Option<AppProfile>,
+) -> RecordingConfig {
+    let profile = if config.profiles.is_empty() {
+        None
+    } else {
+        let frontmost = tokio::task::spawn_blocking(crate::utils::frontmost::detect_frontmost_app)
+            .await
+            .ok()
+            .flatten();
+        log::debug!("Frontmost app at recording start: {:?}", frontmost);
+        frontmost.and_then(|app_id| {
+            crate::commands::settings::resolve_app_profile(&config.profiles, &app_id).cloned()
+        })
+    };
+
+    let app_state = app.state::<AppState>();
+    // A shortcut's own language/model wins over the frontmost app's profile.
+    let profile = crate::commands::settings::layer_profiles(profile, shortcut_profile);
+    if let Ok(mut session_profile) = app_state.session_profile.lock() {
+        *session_profile = profile.clone();
+    }
+    match profile {
+        Some(profile) => {
+            log::info!("Using recording profile for '{}'", profile.app_match);
+            config.with_profile(&profile)
+        }
+        None => config,
+    }
+}
+
+/// Recording config for the session in progress: global settings plus the
+/// profile resolved when it started.
+async fn get_session_recording_config(app: &AppHandle) -> Result<RecordingConfig, String> {
+    let config = get_recording_config(app).await?;
+    let profile = app
+        .state::<AppState>()
+        .session_profile
+        .lock()
+        .ok()
+        .and_then(|profile| profile.clone());
+    Ok(match profile {
+        Some(profile) => config.with_profile(&profile),
+        None => config,
+    })
+}
+
 /// Helper function to get cached recording config or load from store
 pub async fn get_recording_config(app: &AppHandle) -> Result<RecordingConfig, String> {
     let app_state = app.state::<AppState>();
@@ -3472,6 +5038,58 @@ fn recording_license_state(
     }
 }
 /// Pre-recording validation using the readiness state
+/// Refuse to record from a microphone the OS reports as muted, unless the user
+/// chose to record anyway. An unknown mute state never blocks.
+async fn ensure_microphone_unmuted(app: &AppHandle) -> Result<(), String> {
+    let Ok(store) = app.store("settings") else {
+        return Ok(());
+    };
+    let allow_muted = store
+        .get("allow_recording_when_muted")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if allow_muted {
+        return Ok(());
+    }
+    let selected_microphone = store
+        .get("selected_microphone")
+        .and_then(|v| v.as_str().map(str::to_string));
+
+    let probe = tokio::task::spawn_blocking({
+        let selected_microphone = selected_microphone.clone();
+        move || crate::audio::recorder::device_is_muted(selected_microphone.as_deref())
+    });
+    let muted = match tokio::time::timeout(MUTE_CHECK_TIMEOUT, probe).await {
+        Ok(Ok(muted)) => muted,
+        _ => {
+            log::debug!("Microphone mute state unavailable; recording anyway");
+            None
+        }
+    };
+    if muted != Some(true) {
+        return Ok(());
+    }
+
+    log::warn!(
+        "Not recording: microphone '{}' is muted",
+        selected_microphone.as_deref().unwrap_or("default")
+    );
+    let _ = emit_to_window(
+        app,
+        "pill",
+        "microphone-muted",
+        serde_json::json!({ "device": selected_microphone }),
+    );
+    pill_toast_with_suggestion(
+        app,
+        MICROPHONE_MUTED,
+        "Unmute it in your system settings, or allow recording while muted",
+        3000,
+        Some(PillToastVariant::Warning),
+    );
+    Err(MICROPHONE_MUTED.to_string())
+}
+
 async fn validate_recording_requirements(app: &AppHandle) -> Result<(), String> {
     let validate_start = std::time::Instant::now();
     log::debug!("⏱️ [VALIDATE] starting recognition_availability_snapshot");
@@ -3534,15 +5152,23 @@ async fn validate_recording_requirements(app: &AppHandle) -> Result<(), String>
 
             let _ = crate::commands::window::focus_main_window(app.clone()).await;
 
-            let _ = emit_to_all(
-                app,
-                "license-required",
+            let offline_grace_ended = cache
+                .as_ref()
+                .is_some_and(|cached| cached.status.offline_grace_days_left == Some(0));
+            let payload = if offline_grace_ended {
+                serde_json::json!({
+                    "title": "License Check Required",
+                    "message": "The offline grace period has ended. Connect to the internet to revalidate your license",
+                    "action": "reconnect"
+                })
+            } else {
                 serde_json::json!({
                     "title": "License Required",
                     "message": "Your trial has expired. Please purchase a license to continue",
                     "action": "purchase"
-                }),
-            );
+                })
+            };
+            let _ = emit_to_all(app, "license-required", payload);
             return Err("License required to record".to_string());
         }
         RecordingLicenseState::Ready => {}
@@ -3563,6 +5189,9 @@ async fn validate_recording_requirements(app: &AppHandle) -> Result<(), String>
             );
         }
     }
+    drop(cache);
+
+    preflight_soniox_key(app).await?;
 
     log::debug!(
         "⏱️ [VALIDATE] validation complete (+{}ms)",
@@ -3611,6 +5240,57 @@ fn clear_active_silence_toast(app: &AppHandle, active_toast_id: &mut Option<u64>
     }
 }
 
+/// Tell the pill when the microphone drops out mid-recording and whether the
+/// recorder managed to resume on the default device. If it did not, the
+/// recorder watchdog stops the recording and keeps what was captured.
+fn spawn_device_event_listener(
+    app: AppHandle,
+    device_event_rx: std::sync::mpsc::Receiver<DeviceEvent>,
+) {
+    std::thread::spawn(move || {
+        let mut active_device_toast_id: Option<u64> = None;
+
+        while let Ok(event) = device_event_rx.recv() {
+            clear_active_silence_toast(&app, &mut active_device_toast_id);
+            match event {
+                DeviceEvent::Lost => {
+                    let _ = emit_to_window(&app, "pill", "recording-device-lost", ());
+                    active_device_toast_id = Some(pill_toast_persistent(
+                        &app,
+                        "Microphone disconnected — reconnecting…",
+                        PillToastVariant::Warning,
+                    ));
+                }
+                DeviceEvent::Recovered => {
+                    let _ = emit_to_window(&app, "pill", "recording-device-recovered", ());
+                    pill_toast_with_variant(
+                        &app,
+                        "Microphone reconnected",
+                        1500,
+                        PillToastVariant::Info,
+                    );
+                }
+                DeviceEvent::RecoveryFailed => {
+                    log::warn!("Microphone did not come back; recording will stop");
+                }
+            }
+        }
+    });
+}
+
+/// Forward the recorder's chosen sample rates to the main window as a
+/// `recording-config` event once the device is open.
+fn spawn_capture_config_listener(
+    app: AppHandle,
+    config_rx: std::sync::mpsc::Receiver<CaptureConfig>,
+) {
+    std::thread::spawn(move || {
+        if let Ok(config) = config_rx.recv_timeout(std::time::Duration::from_secs(10)) {
+            let _ = emit_to_window(&app, "main", "recording-config", config);
+        }
+    });
+}
+
 async fn stop_recording_after_long_silence(
     app: AppHandle,
     state: State<'_, RecorderState>,
@@ -3729,6 +5409,91 @@ pub(crate) fn ptt_key_released(app_state: &AppState) -> bool {
             .load(std::sync::atomic::Ordering::SeqCst)
 }
 
+/// Debounce window applied by the recording start gate, from settings.
+fn recording_start_debounce(app: &AppHandle) -> std::time::Duration {
+    let ms = app
+        .store("settings")
+        .ok()
+        .and_then(|store| store.get("recording_start_debounce_ms"))
+        .and_then(|v| v.as_u64())
+        .map(|v| v.min(crate::commands::settings::MAX_RECORDING_START_DEBOUNCE_MS as u64))
+        .unwrap_or(crate::commands::settings::DEFAULT_RECORDING_START_DEBOUNCE_MS as u64);
+    std::time::Duration::from_millis(ms)
+}
+
+/// Minimum recording length from settings for the current recording mode;
+/// shorter captures skip transcription.
+fn min_recording_duration_ms(app: &AppHandle) -> u64 {
+    let Ok(store) = app.store("settings") else {
+        return crate::commands::settings::DEFAULT_MIN_RECORDING_DURATION_MS as u64;
+    };
+    let shared_ms = store
+        .get("min_recording_duration_ms")
+        .and_then(|v| v.as_u64())
+        .map(|v| v.min(crate::commands::settings::MAX_MIN_RECORDING_DURATION_MS as u64))
+        .unwrap_or(crate::commands::settings::DEFAULT_MIN_RECORDING_DURATION_MS as u64);
+    let mode_s = |key: &str| {
+        store
+            .get(key)
+            .and_then(|v| v.as_f64())
+            .map(crate::commands::settings::normalize_min_recording_mode_s)
+    };
+    let mode = app
+        .state::<AppState>()
+        .recording_mode
+        .lock()
+        .map(|guard| *guard)
+        .unwrap_or(RecordingMode::Toggle);
+    min_recording_ms_for_mode(
+        mode,
+        mode_s("min_recording_toggle_s"),
+        mode_s("min_recording_ptt_s"),
+        shared_ms,
+    )
+}
+
+/// The per-mode minimum (seconds) when set, else the shared minimum (ms).
+pub(crate) fn min_recording_ms_for_mode(
+    mode: RecordingMode,
+    toggle_s: Option<f32>,
+    ptt_s: Option<f32>,
+    shared_ms: u64,
+) -> u64 {
+    let mode_s = match mode {
+        RecordingMode::Toggle => toggle_s,
+        RecordingMode::PushToTalk => ptt_s,
+    };
+    mode_s
+        .map(|seconds| (seconds.max(0.0) * 1000.0).round() as u64)
+        .unwrap_or(shared_ms)
+}
+
+/// Whether a capture of `duration_ms` falls under the minimum; 0 disables the
+/// gate (empty captures are still caught separately).
+pub(crate) fn recording_too_short(duration_ms: u64, min_duration_ms: u64) -> bool {
+    min_duration_ms > 0 && duration_ms < min_duration_ms
+}
+
+fn keep_raw_recordings_enabled(app: &AppHandle) -> bool {
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get("keep_raw_recordings"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+fn noise_suppression_enabled(app: &AppHandle) -> bool {
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get("noise_suppression"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+fn min_duration_seconds_label(ms: u64) -> String {
+    format!("{}", ms as f64 / 1000.0)
+}
+
 #[tauri::command]
 pub async fn start_recording(
     app: AppHandle,
@@ -3747,6 +5512,25 @@ pub async fn start_recording(
         ],
     );
 
+    let shortcut_profile = take_shortcut_profile(&app);
+
+    // Every trigger source (native hotkey, in-app fallback, tray, frontend)
+    // funnels through this gate so near-simultaneous starts cannot race past
+    // validation. The permit is held until this call returns.
+    let _start_permit = match crate::recording::start_gate::try_begin_start(
+        crate::get_recording_state(&app),
+        recording_start_debounce(&app),
+    ) {
+        Ok(permit) => permit,
+        Err(rejection) => {
+            log::debug!(
+                "start_recording: ignoring concurrent start ({:?})",
+                rejection
+            );
+            return Ok(());
+        }
+    };
+
     // If we're stuck in Error, recover to Idle before attempting a new start
     let current_state = crate::get_recording_state(&app);
     if matches!(current_state, crate::RecordingState::Error) {
@@ -3791,6 +5575,9 @@ pub async fn start_recording(
         }
     }
 
+    // Recording a muted microphone only yields silence; stop before any file exists
+    ensure_microphone_unmuted(&app).await?;
+
     // PTT guard: if recording mode is PushToTalk and the key was already released
     // while validation was running, abort now. This prevents recording from starting
     // after the user has already released the PTT key (e.g., during slow license checks).
@@ -3868,13 +5655,13 @@ pub async fn start_recording(
         }
     }
 
-    // Pause system media if enabled (default: off)
+    // Pause system media if enabled (default: on)
     let mut resume_media_on_error = false;
     if let Ok(store) = app.store("settings") {
         let pause_media = store
             .get("pause_media_during_recording")
             .and_then(|v| v.as_bool())
-            .unwrap_or(false); // Default to off
+            .unwrap_or(true); // Default to on
         if pause_media {
             log::info!("🎵 Pause media during recording is enabled");
             let paused = MEDIA_CONTROLLER.pause_if_playing();
@@ -3897,7 +5684,7 @@ pub async fn start_recording(
         recording_start.elapsed().as_millis()
     );
     let config = match get_recording_config(&app).await {
-        Ok(config) => config,
+        Ok(config) => begin_session_profile(&app, config, shortcut_profile).await,
         Err(e) => {
             log::error!("Failed to load recording config: {}", e);
             resume_media_if_needed();
@@ -3921,7 +5708,7 @@ pub async fn start_recording(
                 guard.get_active_connection().is_some()
             };
             if !remote_active && crate::secure_store::secure_has(&app, provider.key_name()).unwrap_or(false) {
-                provider.warm_up().await;
+                provider.warm_up(&app).await;
             }
         });
     }
@@ -3930,16 +5717,16 @@ pub async fn start_recording(
         let app = app.clone();
         let provider_id = config.ai_provider.clone();
         tokio::spawn(async move {
-            if provider_id == crate::ai::providers::PROVIDER_CUSTOM
+            if crate::ai::providers::is_openai_compatible(&provider_id)
                 || crate::commands::ai::ai_provider_has_key(&provider_id)
             {
                 crate::commands::ai::warm_ai_provider(app, provider_id).await;
             }
         });
     }
-    // Get app data directory for recordings
-    let recordings_dir = match app.path().app_data_dir() {
-        Ok(dir) => dir.join("recordings"),
+    // Get the recordings directory (app data unless overridden)
+    let recordings_dir = match resolve_recordings_dir(&app) {
+        Ok(dir) => dir,
         Err(e) => {
             resume_media_if_needed();
             return Err(e.to_string());
@@ -4083,8 +5870,33 @@ pub async fn start_recording(
 
         log_file_operation("RECORDING_START", audio_path_str, false, None, None);
 
-        // Start recording and get side-channel receivers
-        let (audio_level_rx, silence_event_rx) =
+        // Stream to Soniox while recording when realtime is enabled; the saved
+        // WAV is still the fallback if the stream fails.
+        crate::cloud_stt::soniox_stream::discard_session();
+        if config.soniox_realtime
+            && config.current_engine == crate::cloud_stt::CloudProvider::Soniox.id()
+            && config.transcription_task == TRANSCRIPTION_TASK_TRANSCRIBE
+            && !SONIOX_KEY_FALLBACK_ACTIVE.load(AtomicOrdering::SeqCst)
+        {
+            let language = Some(config.speech_language.clone())
+                .filter(|language| !language.is_empty() && language != "auto");
+            recorder.set_sample_tap(crate::cloud_stt::soniox_stream::begin_session(
+                &app,
+                language,
+                type_streaming_partials(&app, config.clipboard_only),
+            ));
+        }
+
+        recorder.set_silence_auto_stop(Some(config.silence_auto_stop));
+        recorder.set_mic_gain_db(config.mic_gain_db);
+        if config.mic_gain_db != 0.0 {
+            log::info!("Applying {:+.1} dB microphone gain", config.mic_gain_db);
+        }
+        recorder.set_preferred_sample_rate(config.preferred_sample_rate);
+        recorder.set_channel_select(config.channel_select);
+
+        // Start recording and get side-channel receivers
+        let (audio_level_rx, silence_event_rx) =
             match recorder.start_recording(audio_path_str, selected_microphone.clone()) {
                 Ok(_) => {
                     log::debug!(
@@ -4095,8 +5907,16 @@ pub async fn start_recording(
                     let is_recording = recorder.is_recording();
 
                     // Get receivers before potentially dropping recorder
-                    let level_rx = recorder.take_audio_level_receiver();
+                    let level_rx = recorder
+                        .take_audio_level_receiver()
+                        .map(|rx| (rx, recorder.take_waveform(), recorder.take_clip_flag()));
                     let silence_rx = recorder.take_silence_event_receiver();
+                    if let Some(device_rx) = recorder.take_device_event_receiver() {
+                        spawn_device_event_listener(app.clone(), device_rx);
+                    }
+                    if let Some(config_rx) = recorder.take_capture_config_receiver() {
+                        spawn_capture_config_listener(app.clone(), config_rx);
+                    }
 
                     if !is_recording {
                         drop(recorder); // Release the lock if we're erroring out
@@ -4293,6 +6113,18 @@ pub async fn start_recording(
 
     // Update state to recording
     update_recording_state(&app, RecordingState::Recording, None);
+    if let Ok(mut clock) = app_state.recording_clock.lock() {
+        *clock = Some(crate::state::app_state::RecordingClock::start_at(
+            Instant::now(),
+        ));
+    }
+    if config.max_recording_duration_s > 0 {
+        spawn_max_duration_timer(
+            app.clone(),
+            current_recording_generation(),
+            std::time::Duration::from_secs(config.max_recording_duration_s),
+        );
+    }
 
     // If a stop was requested while starting (toggle or PTT), honor it immediately
     // after entering Recording state. For PTT, key-up in Starting state sets this flag.
@@ -4315,51 +6147,109 @@ pub async fn start_recording(
         spawn_silence_event_listener(app.clone(), silence_event_rx);
     }
 
-    if let Some(audio_level_rx) = audio_level_rx_to_spawn {
+    if let Some((audio_level_rx, waveform, clip_flag)) = audio_level_rx_to_spawn {
         let app_for_levels = app.clone();
+        let clip_flag = clip_flag.filter(|_| config.clipping_warning_enabled);
         // Use a thread instead of tokio spawn for std::sync::mpsc
         std::thread::spawn(move || {
             let mut last_emit = std::time::Instant::now();
             let emit_interval = std::time::Duration::from_millis(100); // Throttle to 10fps
             let mut last_emitted_level = 0.0f64;
             const LEVEL_CHANGE_THRESHOLD: f64 = 0.05; // Only emit if change > 5%
+            let mut last_waveform_emit = std::time::Instant::now();
+            let waveform_interval = std::time::Duration::from_millis(66); // Throttle to ~15fps
+            let mut last_clip_warning: Option<std::time::Instant> = None;
+
+            loop {
+                match audio_level_rx.recv_timeout(waveform_interval) {
+                    Ok(level) => {
+                        // Check both time throttling and significant change
+                        let level_changed =
+                            (level - last_emitted_level).abs() > LEVEL_CHANGE_THRESHOLD;
+
+                        if last_emit.elapsed() >= emit_interval && level_changed {
+                            // Only emit to pill window - main window doesn't need audio levels
+                            let _ = emit_to_window(&app_for_levels, "pill", "audio-level", level);
+                            last_emit = std::time::Instant::now();
+                            last_emitted_level = level;
+                        }
+                    }
+                    Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
+                    Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break,
+                }
 
-            while let Ok(level) = audio_level_rx.recv() {
-                // Check both time throttling and significant change
-                let level_changed = (level - last_emitted_level).abs() > LEVEL_CHANGE_THRESHOLD;
+                let clipped = clip_flag
+                    .as_ref()
+                    .is_some_and(|flag| flag.swap(false, std::sync::atomic::Ordering::Relaxed));
+                if clipped
+                    && last_clip_warning.is_none_or(|at| at.elapsed() >= CLIP_WARNING_COOLDOWN)
+                {
+                    let suggestion = "Lower the mic gain or move back from the microphone";
+                    let _ = emit_to_window(
+                        &app_for_levels,
+                        "pill",
+                        "recording-clipping",
+                        serde_json::json!({ "suggestion": suggestion }),
+                    );
+                    pill_toast_with_suggestion(
+                        &app_for_levels,
+                        "Input is clipping",
+                        suggestion,
+                        2500,
+                        Some(PillToastVariant::Warning),
+                    );
+                    last_clip_warning = Some(std::time::Instant::now());
+                }
 
-                if last_emit.elapsed() >= emit_interval && level_changed {
-                    // Only emit to pill window - main window doesn't need audio levels
-                    let _ = emit_to_window(&app_for_levels, "pill", "audio-level", level);
-                    last_emit = std::time::Instant::now();
-                    last_emitted_level = level;
+                if last_waveform_emit.elapsed() < waveform_interval {
+                    continue;
                 }
+                let points = waveform
+                    .as_ref()
+                    .and_then(|waveform| waveform.lock().ok().map(|buffer| buffer.snapshot()));
+                if let Some(points) = points {
+                    let _ = emit_to_window(&app_for_levels, "pill", "audio-waveform", points);
+                }
+                last_waveform_emit = std::time::Instant::now();
             }
         });
     }
 
     // Show pill widget if enabled and mode is not "never" (graceful degradation)
-    let should_show_pill = config.show_pill_widget && config.pill_indicator_mode != "never";
+    let should_show_pill = config.show_pill_widget
+        && config.pill_indicator_mode != "never"
+        && crate::commands::settings::recording_indicator_shows_pill(&config.recording_indicator);
     log::info!(
-        "pill_visibility: start_recording show_pill_widget={} pill_indicator_mode='{}' should_show={}",
+        "pill_visibility: start_recording show_pill_widget={} pill_indicator_mode='{}' recording_indicator='{}' should_show={}",
         config.show_pill_widget,
         config.pill_indicator_mode,
+        config.recording_indicator,
         should_show_pill
     );
+    if crate::commands::settings::recording_indicator_notifies(&config.recording_indicator) {
+        show_recording_indicator_notification(&app, "Recording started");
+    }
     if should_show_pill {
-        match crate::commands::window::show_pill_widget(app.clone()).await {
-            Ok(_) => log::debug!("Pill widget shown successfully"),
-            Err(e) => {
-                log::warn!("Failed to show pill widget: {}. Recording will continue without visual feedback.", e);
-
-                // Emit event so frontend knows pill isn't visible
-                let _ = emit_to_window(
-                    &app,
-                    "main",
-                    "pill-widget-error",
-                    "Recording indicator unavailable. Recording is still active.",
-                );
-            }
+        // The grace delay only applies when the pill is hidden between recordings;
+        // level/elapsed events still flow immediately, only the window show waits.
+        if config.pill_show_delay_ms > 0 && config.pill_indicator_mode == "when_recording" {
+            let generation = current_recording_generation();
+            let delay = std::time::Duration::from_millis(config.pill_show_delay_ms);
+            let app_for_pill = app.clone();
+            tauri::async_runtime::spawn(async move {
+                tokio::time::sleep(delay).await;
+                let state = crate::get_recording_state(&app_for_pill);
+                if delayed_pill_still_wanted(generation, &state) {
+                    show_recording_pill(&app_for_pill).await;
+                } else {
+                    log::debug!(
+                        "Pill show skipped: recording ended within the {:?} grace delay",
+                        delay
+                    );
+                }
+            });
+        } else {
+            show_recording_pill(&app).await;
         }
     } else if config.pill_indicator_mode == "never" {
         log::debug!("Pill widget hidden (pill_indicator_mode=never)");
@@ -4402,6 +6292,113 @@ pub async fn start_recording(
     Ok(())
 }
 
+/// The language a one-off recording should use: `requested` when Whisper
+/// supports it, otherwise `None` so the configured language applies.
+fn session_language_override(requested: &str) -> Option<&'static str> {
+    let requested = requested.trim().to_lowercase();
+    let validated = crate::whisper::languages::validate_language(Some(&requested));
+    (validated == requested).then_some(validated)
+}
+
+/// How long a dry run's transcript stays on the pill.
+const DRY_RUN_PILL_MS: u64 = 4000;
+
+fn take_dry_run_reply(
+    app: &AppHandle,
+) -> Option<tokio::sync::oneshot::Sender<Result<String, String>>> {
+    app.state::<AppState>()
+        .dry_run_reply
+        .lock()
+        .ok()
+        .and_then(|mut slot| slot.take())
+}
+
+/// Record once and return the raw transcript without inserting it, touching
+/// the clipboard or saving history, so users can check their mic and model.
+/// The recording is stopped the usual way (shortcut, pill or `stop_recording`).
+#[tauri::command]
+pub async fn test_record_once(
+    app: AppHandle,
+    state: State<'_, RecorderState>,
+) -> Result<String, String> {
+    let (reply, result) = tokio::sync::oneshot::channel();
+    {
+        let app_state = app.state::<AppState>();
+        let mut slot = app_state
+            .dry_run_reply
+            .lock()
+            .map_err(|e| format!("Failed to acquire dry run lock: {}", e))?;
+        if slot.is_some() {
+            return Err("A test recording is already in progress".to_string());
+        }
+        *slot = Some(reply);
+    }
+
+    if let Err(e) = start_recording(app.clone(), state).await {
+        take_dry_run_reply(&app);
+        return Err(e);
+    }
+
+    result
+        .await
+        .unwrap_or_else(|_| Err("Recording ended without a transcription".to_string()))
+}
+
+/// Show a dry run's transcript in the pill for a few seconds, then go idle.
+async fn finish_dry_run(app: &AppHandle, text: &str) {
+    let message = if text.trim().is_empty() {
+        "No speech detected"
+    } else {
+        text
+    };
+    pill_toast(app, message, DRY_RUN_PILL_MS);
+    tokio::time::sleep(std::time::Duration::from_millis(DRY_RUN_PILL_MS)).await;
+    if should_hide_pill(app).await {
+        if let Err(e) = crate::commands::window::hide_pill_widget(app.clone()).await {
+            log::error!("Failed to hide pill window: {}", e);
+        }
+    }
+    update_recording_state(app, RecordingState::Idle, None);
+}
+
+/// Start a recording transcribed in `language` for this session only; the
+/// saved speech language is left untouched. An unsupported code records with
+/// the configured language instead.
+#[tauri::command]
+pub async fn start_recording_with_language(
+    app: AppHandle,
+    state: State<'_, RecorderState>,
+    language: String,
+) -> Result<(), String> {
+    let override_language = session_language_override(&language);
+    match override_language {
+        Some(code) => {
+            // Staged like a secondary shortcut's language; begin_session_profile
+            // layers it over the frontmost app's profile for this session.
+            let app_state = app.state::<AppState>();
+            if let Ok(mut staged) = app_state.shortcut_profile.lock() {
+                *staged = Some(AppProfile {
+                    app_match: "language-override".to_string(),
+                    language: code.to_string(),
+                    ..AppProfile::default()
+                });
+            }
+        }
+        None => log::warn!(
+            "Unsupported language '{}' for this recording; using the configured language",
+            language
+        ),
+    }
+
+    start_recording(app.clone(), state).await?;
+
+    if let Some(code) = override_language {
+        let name = crate::whisper::languages::get_language_name(code).unwrap_or(code);
+        pill_toast(&app, &format!("Language: {}", name), 2000);
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn stop_recording(
     app: AppHandle,
@@ -4496,6 +6493,15 @@ pub async fn stop_recording(
             if play_sound {
                 play_recording_end_sound();
             }
+            let indicator = crate::commands::settings::normalize_recording_indicator(
+                store
+                    .get("recording_indicator")
+                    .and_then(|v| v.as_str().map(|s| s.to_string()))
+                    .as_deref(),
+            );
+            if crate::commands::settings::recording_indicator_notifies(&indicator) {
+                show_recording_indicator_notification(&app, "Recording stopped");
+            }
         }
 
         // Resume system media if we paused it
@@ -4633,6 +6639,10 @@ pub async fn stop_recording(
         }
     };
     let task_generation = current_recording_generation();
+    // Set when `test_record_once` started this recording: the transcript goes
+    // back to that caller and is never saved, inserted or copied.
+    let mut dry_run_reply = take_dry_run_reply(&app);
+    let dry_run = dry_run_reply.is_some();
     // Register the file the upcoming transcription task will own as EARLY as
     // possible — the moment `stop_recording` takes ownership of the recording
     // path, before model selection / normalization. A `cancel_recording` that
@@ -4645,28 +6655,49 @@ pub async fn stop_recording(
     // cancel hits NotFound, the next registration overwrites it).
     set_in_flight_transcription_audio(task_generation, audio_path.clone());
 
-    // Fast-path: handle header-only/empty WAV files before normalization
-    if let Ok(meta) = std::fs::metadata(&audio_path) {
-        // A valid WAV header is typically 44 bytes; <= 44 implies no audio samples were written
-        if meta.len() <= 44 {
-            pill_toast_with_suggestion(
+    // Fast-path: catch empty captures before normalization. Header size varies
+    // by encoder, so inspect the samples themselves rather than the byte count.
+    let min_duration_ms = min_recording_duration_ms(&app);
+    let capture_probe = crate::audio::silence_detector::probe_capture(&audio_path);
+    let capture_empty = match &capture_probe {
+        Ok(probe) => probe.is_empty(),
+        Err(e) => {
+            log::debug!("Capture probe failed, falling back to header size: {}", e);
+            std::fs::metadata(&audio_path)
+                .map(|meta| meta.len() <= 44)
+                .unwrap_or(false)
+        }
+    };
+    if capture_empty {
+        pill_toast_with_suggestion(&app, "No audio captured", "Try recording again", 1000, None);
+        if let Err(e) = std::fs::remove_file(&audio_path) {
+            log::debug!("Failed to remove empty audio file: {}", e);
+        }
+        // Frontend will hide pill after showing feedback
+        update_recording_state(&app, RecordingState::Idle, None);
+        return Ok("".to_string());
+    }
+    if let Ok(probe) = &capture_probe {
+        if recording_too_short(probe.duration_ms, min_duration_ms) {
+            let _ = emit_to_window(
                 &app,
-                "No audio captured",
-                "Try recording again",
-                1000,
-                None,
+                "pill",
+                "recording-too-short",
+                format!(
+                    "Recording shorter than {} seconds",
+                    min_duration_seconds_label(min_duration_ms)
+                ),
             );
             if let Err(e) = std::fs::remove_file(&audio_path) {
-                log::debug!("Failed to remove empty audio file: {}", e);
+                log::debug!("Failed to remove short audio file: {}", e);
             }
-            // Frontend will hide pill after showing feedback
             update_recording_state(&app, RecordingState::Idle, None);
             return Ok("".to_string());
         }
     }
 
     // Decide engine early to optionally skip normalization for cloud providers
-    let config = get_recording_config(&app).await.map_err(|e| {
+    let config = get_session_recording_config(&app).await.map_err(|e| {
         log::error!("Failed to load recording config: {}", e);
         format!("Configuration error: {}", e)
     })?;
@@ -4786,9 +6817,28 @@ pub async fn stop_recording(
                     .await;
                 }
 
-                ActiveEngineSelection::Cloud {
+                let cloud_selection = ActiveEngineSelection::Cloud {
                     provider,
                     model_name: config.current_model.clone(),
+                };
+                let key_fallback = if provider == crate::cloud_stt::CloudProvider::Soniox
+                    && SONIOX_KEY_FALLBACK_ACTIVE.load(AtomicOrdering::SeqCst)
+                {
+                    resolve_offline_fallback(&app, &config).await
+                } else {
+                    None
+                };
+                if let Some(fallback) = key_fallback {
+                    log::info!(
+                        "Soniox key rejected; transcribing with {} '{}'",
+                        fallback.engine_name(),
+                        fallback.model_name()
+                    );
+                    fallback
+                } else if config.auto_engine_by_network {
+                    select_engine_by_network(&app, &config, provider, cloud_selection).await
+                } else {
+                    cloud_selection
                 }
             }
             _ => {
@@ -4918,6 +6968,14 @@ pub async fn stop_recording(
         _ => {
             // Normalize captured audio to Whisper contract (WAV PCM s16, mono, 16k):
             // try in-process first (off the async runtime), fall back to ffmpeg sidecar.
+            // The capture is always WAV, so a missing sidecar only loses that fallback.
+            let ffmpeg_available = crate::ffmpeg::ffmpeg_available(&app);
+            if !ffmpeg_available && !FFMPEG_MISSING_WARNED.swap(true, AtomicOrdering::Relaxed) {
+                log::warn!(
+                    "ffmpeg sidecar not found; using the built-in WAV normalizer, advanced normalization is unavailable"
+                );
+                pill_toast(&app, "Advanced audio normalization unavailable", 2500);
+            }
             let parent_dir = audio_path
                 .parent()
                 .map(|p| p.to_path_buf())
@@ -4938,6 +6996,19 @@ pub async fn stop_recording(
                             Ok(Err(e)) => e.clone(),
                             Err(e) => e.to_string(),
                         };
+                        if !ffmpeg_available {
+                            log::error!(
+                                "In-process audio normalization failed and ffmpeg is unavailable: {}",
+                                other_err
+                            );
+                            update_recording_state(
+                                &app,
+                                RecordingState::Error,
+                                Some("Audio normalization failed".to_string()),
+                            );
+                            let _ = std::fs::remove_file(&audio_path);
+                            return Err("Audio normalization failed".to_string());
+                        }
                         log::warn!(
                             "In-process audio normalization failed; falling back to ffmpeg: {:?}",
                             other_err
@@ -4961,27 +7032,24 @@ pub async fn stop_recording(
                 }
             };
 
-            // Remove raw capture after successful normalization
-            if let Err(e) = std::fs::remove_file(&audio_path) {
+            // Remove raw capture after successful normalization, or keep it as
+            // `raw_<ts>.wav` so the orphan sweep leaves it alone
+            if !dry_run && keep_raw_recordings_enabled(&app) {
+                let raw_name = audio_path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .map(|n| n.replacen("recording_", "raw_", 1))
+                    .unwrap_or_else(|| "raw_recording.wav".to_string());
+                if let Err(e) = std::fs::rename(&audio_path, parent_dir.join(raw_name)) {
+                    log::debug!("Failed to keep raw audio: {}", e);
+                }
+            } else if let Err(e) = std::fs::remove_file(&audio_path) {
                 log::debug!("Failed to remove raw audio: {}", e);
             }
 
-            // Determine min duration based on recording mode (PTT vs Toggle) once
-            let (min_duration_s_f32, min_duration_label) = {
-                let app_state = app.state::<AppState>();
-                let mode = app_state
-                    .recording_mode
-                    .lock()
-                    .ok()
-                    .map(|g| *g)
-                    .unwrap_or(RecordingMode::Toggle);
-                match mode {
-                    RecordingMode::PushToTalk => (0.5f32, "0.5".to_string()),
-                    RecordingMode::Toggle => (0.5f32, "0.5".to_string()),
-                }
-            };
+            let min_duration_label = min_duration_seconds_label(min_duration_ms);
 
-            // Duration gate (mode-specific) using normalized file
+            // Duration gate (configurable minimum) using normalized file
             let duration_gate = (|| -> Result<(bool, u64), String> {
                 let reader = hound::WavReader::open(&normalized_path)
                     .map_err(|e| format!("Failed to open normalized wav: {}", e))?;
@@ -5002,7 +7070,10 @@ pub async fn stop_recording(
                         ("duration_s", format!("{:.2}", duration).as_str()),
                     ],
                 );
-                Ok((duration < min_duration_s_f32, duration_ms))
+                Ok((
+                    recording_too_short(duration_ms, min_duration_ms),
+                    duration_ms,
+                ))
             })();
 
             if matches!(duration_gate, Ok((true, _))) {
@@ -5021,6 +7092,41 @@ pub async fn stop_recording(
                 return Ok("".to_string());
             }
 
+            // Optional noise suppression, before trimming so the silence
+            // detector sees the cleaned-up floor.
+            if noise_suppression_enabled(&app) {
+                let path = normalized_path.clone();
+                let denoised =
+                    tokio::task::spawn_blocking(move || crate::audio::denoise::denoise_wav(&path))
+                        .await
+                        .unwrap_or_else(|e| Err(e.to_string()));
+                if let Err(e) = denoised {
+                    log::warn!("Noise suppression skipped: {}", e);
+                }
+            }
+
+            // Optional VAD pass: drop leading/trailing silence and collapse long
+            // pauses so local models don't spend time on dead air.
+            if config.auto_trim_silence {
+                let path = normalized_path.clone();
+                let threshold_db = config.trim_silence_threshold_db;
+                let max_gap_ms = config.trim_max_gap_ms;
+                let trimmed = tokio::task::spawn_blocking(move || {
+                    crate::audio::silence_detector::trim_silence_in_wav(
+                        &path,
+                        threshold_db,
+                        Some(max_gap_ms),
+                    )
+                })
+                .await
+                .unwrap_or_else(|e| Err(e.to_string()));
+                match trimmed {
+                    Ok(0) => log::info!("[RECORD] Silence trim: nothing to remove"),
+                    Ok(ms) => log::info!("[RECORD] Silence trim removed {}ms of audio", ms),
+                    Err(e) => log::warn!("Silence trim skipped: {}", e),
+                }
+            }
+
             normalized_path
         }
     };
@@ -5051,6 +7157,14 @@ pub async fn stop_recording(
             &config.speech_language,
         ))
     };
+    // Auto language mode: Whisper detects the spoken language per recording,
+    // with the configured language kept as the low-confidence fallback.
+    let auto_detect_language = config.auto_detect_language
+        && matches!(engine_selection, ActiveEngineSelection::Whisper { .. })
+        && !crate::commands::settings::model_requires_english_speech(
+            engine_selection.engine_name(),
+            engine_selection.model_name(),
+        );
     let transcription_task = resolve_transcription_task_for_audio(
         &app,
         config.ai_enabled,
@@ -5069,7 +7183,11 @@ pub async fn stop_recording(
     );
     log::info!(
         "[LANGUAGE] stop_recording: language={:?}, transcription_task={}, translate={}",
-        language.as_deref(),
+        if auto_detect_language {
+            Some("auto")
+        } else {
+            language.as_deref()
+        },
         transcription_task,
         translate_to_english
     );
@@ -5090,9 +7208,11 @@ pub async fn stop_recording(
     let language_for_task = language.clone();
     let selected_model_name_for_task = selected_model_name.clone();
     let transcription_job_for_task = transcription_job.clone();
-    // Spawn and track the transcription task
+    let max_queued_transcriptions = config.max_queued_transcriptions;
+    let queued_audio_path = audio_path_clone.clone();
+    // Queue the transcription behind any still running so none is lost
     let app_for_task = app.clone();
-    let task_handle = tokio::spawn(async move {
+    let transcription = async move {
         log::debug!("Transcription task started");
 
         // Update state to transcribing
@@ -5113,6 +7233,7 @@ pub async fn stop_recording(
             // SAME cleanup the normal completion path runs below; the old
             // early-cancel branch returned here without it, orphaning the file.
             finalize_in_flight_audio(task_generation, &audio_path_clone);
+            crate::commands::text::discard_streaming_text().await;
 
             // Hide pill window since we're cancelling (only if show_pill_indicator is false)
             if should_hide_pill(&app_for_task).await {
@@ -5127,8 +7248,47 @@ pub async fn stop_recording(
             return;
         }
 
+        // A realtime Soniox session already has the transcript; anything else
+        // (or a failed session) transcribes the saved recording below.
+        let mut realtime_transcript = match &engine_selection_for_task {
+            ActiveEngineSelection::Cloud {
+                provider: crate::cloud_stt::CloudProvider::Soniox,
+                ..
+            } => crate::cloud_stt::soniox_stream::finish_session().await,
+            _ => {
+                crate::cloud_stt::soniox_stream::discard_session();
+                None
+            }
+        };
+
+        let language_for_task = match &engine_selection_for_task {
+            ActiveEngineSelection::Whisper { model_path, .. } if auto_detect_language => {
+                detect_whisper_language(
+                    &app_for_task,
+                    model_path,
+                    &audio_path_clone,
+                    language_for_task,
+                )
+                .await
+            }
+            _ => language_for_task,
+        };
+
+        // A realtime transcript is already finished, so timing it says nothing
+        // about the engine.
+        let timed_run = realtime_transcript.is_none();
+        let audio_duration_ms = wav_duration_ms(&audio_path_clone);
+        let transcription_started = std::time::Instant::now();
         let transcription_result: Result<TranscriptionResult, TranscriptionFailure> =
             match &engine_selection_for_task {
+                _ if realtime_transcript.is_some() => {
+                    let output = realtime_transcript.take().unwrap_or_default();
+                    Ok(
+                        TranscriptionResult::new(&transcription_job_for_task, output.text)
+                            .with_transcript_language(output.language)
+                            .with_audio_duration_ms(audio_duration_ms),
+                    )
+                }
                 // Local + cloud run through the shared transcription executor (plan
                 // 020 Stage 2): it owns normalization, the interactive watchdog /
                 // shared cancel flag, Whisper retry, and the cloud network timeout.
@@ -5141,6 +7301,7 @@ pub async fn stop_recording(
                         &transcription_job_for_task,
                         language_for_task.clone(),
                         audio_path_clone.clone(),
+                        &config.whisper_initial_prompt,
                     ) {
                         Ok(request) => transcribe_with_app(&app_for_task, request)
                             .await
@@ -5229,6 +7390,7 @@ pub async fn stop_recording(
                     .await
                 }
             };
+        let transcription_elapsed_ms = transcription_started.elapsed().as_millis() as u64;
 
         // Decide persistence BEFORE touching the file. PRIVACY: a cancelled
         // dictation — or one whose recording generation has gone stale (a newer
@@ -5237,8 +7399,9 @@ pub async fn stop_recording(
         // (retryable) failure. This is the PRE-copy snapshot: it reads the
         // flags before the (synchronous) copy so a cancel already in effect
         // skips the write.
-        let pre_discard =
-            app_state.is_cancellation_requested() || recording_generation_is_stale(task_generation);
+        let pre_discard = dry_run
+            || app_state.is_cancellation_requested()
+            || recording_generation_is_stale(task_generation);
         let mut recording_file =
             if should_save_recording_audio(pre_discard, transcription_result.as_ref().err()) {
                 maybe_save_recording_if_current(&app_for_task, task_generation, &audio_path_clone)
@@ -5291,6 +7454,7 @@ pub async fn stop_recording(
                     if let Some(ref saved) = recording_file {
                         revoke_saved_recording(&app_for_task, saved).await;
                     }
+                    crate::commands::text::discard_streaming_text().await;
 
                     // Hide pill window since we're discarding (only if show_pill_indicator is false)
                     if should_hide_pill(&app_for_task).await {
@@ -5309,10 +7473,35 @@ pub async fn stop_recording(
                     "Transcription successful, {} chars",
                     transcription.raw_text.len()
                 );
+                if timed_run {
+                    crate::utils::metrics::record_transcription(
+                        &app_for_task,
+                        crate::utils::metrics::TranscriptionSample::new(
+                            &transcription.engine,
+                            &transcription.model,
+                            transcription_elapsed_ms,
+                            audio_duration_ms,
+                            &transcription.raw_text,
+                        ),
+                    );
+                }
+
+                if let Some(reply) = dry_run_reply.take() {
+                    let plan = plan_dry_run_success(&transcription);
+                    debug_assert_eq!(plan.save_history_entries, 0);
+                    log::info!(
+                        "Dry run transcription finished, {} chars",
+                        plan.final_text.len()
+                    );
+                    let _ = reply.send(Ok(plan.final_text.clone()));
+                    finish_dry_run(&app_for_task, &plan.final_text).await;
+                    return;
+                }
 
                 // Check if transcription is empty or just noise
                 if is_non_speech_transcript(&transcription.raw_text) {
                     log::info!("Whisper returned empty transcription - no speech detected");
+                    crate::commands::text::discard_streaming_text().await;
 
                     // Emit graceful feedback to user via pill toast
                     pill_toast_with_suggestion(
@@ -5325,8 +7514,9 @@ pub async fn stop_recording(
 
                     // Wait for feedback to show before hiding pill
                     let app_for_hide = app_for_task.clone();
+                    let hide_delay_ms = config.pill_hide_delay_empty_ms;
                     tokio::spawn(async move {
-                        tokio::time::sleep(std::time::Duration::from_millis(2500)).await;
+                        tokio::time::sleep(std::time::Duration::from_millis(hide_delay_ms)).await;
 
                         // Hide pill window (only if show_pill_indicator is false)
                         if should_hide_pill(&app_for_hide).await {
@@ -5345,6 +7535,32 @@ pub async fn stop_recording(
                     return;
                 }
 
+                if is_low_confidence(
+                    transcription.avg_confidence,
+                    config.low_confidence_threshold,
+                ) {
+                    log::info!(
+                        "Low-confidence transcription ({:?} < {})",
+                        transcription.avg_confidence,
+                        config.low_confidence_threshold
+                    );
+                    let _ = emit_to_window(
+                        &app_for_task,
+                        "pill",
+                        "transcription-low-confidence",
+                        serde_json::json!({
+                            "confidence": transcription.avg_confidence,
+                            "threshold": config.low_confidence_threshold,
+                        }),
+                    );
+                    pill_toast_with_variant(
+                        &app_for_task,
+                        "Low-confidence transcription — consider re-recording",
+                        2500,
+                        PillToastVariant::Warning,
+                    );
+                }
+
                 let ai_enabled = config.ai_enabled;
                 let should_emit_enhancing = if ai_enabled {
                     crate::commands::ai::get_enhancement_options_for_ai_enabled(
@@ -5360,6 +7576,7 @@ pub async fn stop_recording(
 
                 if should_emit_enhancing {
                     let _ = app_for_task.emit("enhancing-started", ());
+                    emit_recording_event(&app_for_task, RecordingPhase::EnhancingStarted, None);
                 }
 
                 // Backend handles the complete flow
@@ -5370,8 +7587,12 @@ pub async fn stop_recording(
                 let ai_enabled_for_task = ai_enabled;
                 let should_emit_enhancing_for_task = should_emit_enhancing;
                 let recording_file_for_task = recording_file.clone();
+                let pill_hide_delay_ms = config.pill_hide_delay_ms;
+                let clipboard_only = config.clipboard_only;
 
-                tokio::spawn(async move {
+                // Delivery runs inside the queued job so the next queued
+                // dictation is only inserted after this one
+                async move {
                     // 1. Process the transcription and enhancement
                     let (final_text, writing_metadata, should_deliver) =
                         match crate::writing::process_transcription(
@@ -5406,6 +7627,21 @@ pub async fn stop_recording(
                                     }
                                 } else if should_emit_enhancing_for_task {
                                     let _ = app_for_process.emit("enhancing-completed", ());
+                                    emit_recording_event(
+                                        &app_for_process,
+                                        RecordingPhase::EnhancingCompleted,
+                                        None,
+                                    );
+                                }
+                                if writing_result.warnings.iter().any(|warning| {
+                                    warning.code == crate::writing::TRANSLATION_FAILED_WARNING
+                                }) {
+                                    pill_toast_with_variant(
+                                        &app_for_process,
+                                        "Translation failed",
+                                        1500,
+                                        PillToastVariant::Warning,
+                                    );
                                 }
 
                                 if writing_result.ai_applied {
@@ -5427,6 +7663,11 @@ pub async fn stop_recording(
                                 log::warn!("Translation failed after transcription; saving raw transcript to history without delivery");
                                 if should_emit_enhancing_for_task {
                                     let _ = app_for_process.emit("enhancing-failed", ());
+                                    emit_recording_event(
+                                        &app_for_process,
+                                        RecordingPhase::EnhancingFailed,
+                                        Some(format!("Translation to {} failed", target_language)),
+                                    );
                                 }
 
                                 let saved = save_transcription_with_recording_if_current(
@@ -5434,10 +7675,12 @@ pub async fn stop_recording(
                                     task_generation,
                                     transcription_for_process.raw_text.clone(),
                                     model_for_process.clone(),
+                                    Some(transcription_for_process.engine.clone()),
                                     recording_file_for_task.clone(),
                                     Some(build_translation_failed_history_metadata(
                                         &target_language,
                                     )),
+                                    transcription_for_process.segments.clone(),
                                 )
                                 .await;
 
@@ -5494,6 +7737,14 @@ pub async fn stop_recording(
                                 log::warn!("Formatting failed: Final output language requires AI enhancement or native translation");
                                 if should_emit_enhancing_for_task {
                                     let _ = app_for_process.emit("enhancing-failed", ());
+                                    emit_recording_event(
+                                        &app_for_process,
+                                        RecordingPhase::EnhancingFailed,
+                                        Some(
+                                            "Final output language requires AI enhancement"
+                                                .to_string(),
+                                        ),
+                                    );
                                 }
 
                                 pill_toast(
@@ -5508,6 +7759,11 @@ pub async fn stop_recording(
                                 log::warn!("Formatting failed: {}", e);
                                 if should_emit_enhancing_for_task {
                                     let _ = app_for_process.emit("enhancing-failed", ());
+                                    emit_recording_event(
+                                        &app_for_process,
+                                        RecordingPhase::EnhancingFailed,
+                                        Some(e.to_string()),
+                                    );
                                 }
 
                                 pill_toast(&app_for_process, "Formatting failed", 1500);
@@ -5546,8 +7802,9 @@ pub async fn stop_recording(
                         return;
                     }
 
-                    // Hide pill window first (only if show_pill_indicator is false)
-                    if should_hide_pill(&app_for_process).await {
+                    // Hide pill window first (only if show_pill_indicator is false),
+                    // unless the user keeps it up to confirm the insertion
+                    if pill_hide_delay_ms == 0 && should_hide_pill(&app_for_process).await {
                         if let Some(window_manager) = app_state.get_window_manager() {
                             if let Err(e) = window_manager.hide_pill_window().await {
                                 log::error!("Failed to hide pill window: {}", e);
@@ -5561,6 +7818,7 @@ pub async fn stop_recording(
                     tokio::time::sleep(std::time::Duration::from_millis(20)).await;
 
                     if !should_deliver {
+                        crate::commands::text::discard_streaming_text().await;
                         update_recording_state(&app_for_process, RecordingState::Idle, None);
                         return;
                     }
@@ -5568,13 +7826,31 @@ pub async fn stop_recording(
                     // Now handle text insertion or clipboard copy based on auto_paste_transcription.
                     // Missing setting keys default inside get_settings; actual settings-read failures fail closed
                     // to avoid surprising paste into the wrong app.
-                    let auto_paste = match get_settings(app_for_process.clone()).await {
-                        Ok(settings) => settings.auto_paste_transcription,
-                        Err(error) => {
-                            log::error!("Failed to read auto-paste setting: {}", error);
-                            false
-                        }
-                    };
+                    let (auto_paste, insert_streaming, scratchpad_mode, sinks) =
+                        match get_settings(app_for_process.clone()).await {
+                            Ok(settings) => (
+                                settings.auto_paste_transcription,
+                                settings.insert_streaming,
+                                settings.scratchpad_mode,
+                                crate::text::sinks::parse_sinks(&settings.transcription_sinks),
+                            ),
+                            Err(error) => {
+                                log::error!("Failed to read auto-paste setting: {}", error);
+                                (false, false, false, crate::text::sinks::parse_sinks(&[]))
+                            }
+                        };
+                    let insertion_mode = crate::commands::text::resolve_insertion_mode(
+                        insert_streaming,
+                        &transcription_for_process.engine,
+                    );
+                    if insert_streaming
+                        && insertion_mode == crate::commands::text::InsertionMode::OnComplete
+                    {
+                        log::debug!(
+                            "insert_streaming enabled but engine '{}' does not stream; inserting on completion",
+                            transcription_for_process.engine
+                        );
+                    }
                     // Recheck (Race 3) IMMEDIATELY before text insertion: a
                     // cancel arriving during the pill-hide / sleep / settings-
                     // read window above must not paste stale/cancelled text.
@@ -5586,16 +7862,35 @@ pub async fn stop_recording(
                         if let Some(ref saved) = recording_file_for_task {
                             revoke_saved_recording(&app_for_process, saved).await;
                         }
+                        crate::commands::text::discard_streaming_text().await;
                         update_recording_state(&app_for_process, RecordingState::Idle, None);
                         return;
                     }
 
-                    if auto_paste {
+                    let target = delivery_target(scratchpad_mode, auto_paste, clipboard_only);
+                    if !sinks.contains(&crate::text::sinks::TranscriptionSink::Cursor) {
+                        log::debug!("Cursor sink disabled; skipping text insertion");
+                    } else if target == DeliveryTarget::Scratchpad {
+                        // Scratchpad mode: collect the dictation instead of pasting it
+                        match crate::commands::scratchpad::append_to_scratchpad(
+                            &app_for_process,
+                            &final_text,
+                        )
+                        .await
+                        {
+                            Ok(()) => log::debug!("Transcription added to scratchpad"),
+                            Err(e) => {
+                                log::error!("Failed to add transcription to scratchpad: {}", e);
+                                pill_toast(&app_for_process, "Scratchpad failed", 1500);
+                            }
+                        }
+                    } else if target == DeliveryTarget::Paste {
                         // Auto-paste enabled: insert text at cursor
                         let insert_result = persist_if_current(&app_state, task_generation, || {
-                            crate::commands::text::insert_text(
+                            crate::commands::text::insert_text_with_mode(
                                 app_for_process.clone(),
                                 final_text.clone(),
+                                insertion_mode,
                             )
                         });
                         let Some(insert_future) = insert_result else {
@@ -5606,6 +7901,7 @@ pub async fn stop_recording(
                             if let Some(ref saved) = recording_file_for_task {
                                 revoke_saved_recording(&app_for_process, saved).await;
                             }
+                            crate::commands::text::discard_streaming_text().await;
                             update_recording_state(&app_for_process, RecordingState::Idle, None);
                             return;
                         };
@@ -5637,7 +7933,7 @@ pub async fn stop_recording(
                             }
                         }
                     } else {
-                        // Auto-paste disabled: copy to clipboard and notify
+                        // Auto-paste disabled or clipboard-only profile: copy to clipboard and notify
                         let copy_result = persist_if_current(&app_state, task_generation, || {
                             crate::commands::text::copy_text_to_clipboard(final_text.clone())
                         });
@@ -5663,6 +7959,12 @@ pub async fn stop_recording(
                             }
                         }
                     }
+                    crate::text::sinks::deliver_to_extra_sinks(
+                        &app_for_process,
+                        &sinks,
+                        &final_text,
+                    )
+                    .await;
 
                     // Recheck (Race 3) IMMEDIATELY before history save: a cancel
                     // arriving during text insertion must not persist a history
@@ -5680,45 +7982,66 @@ pub async fn stop_recording(
                         return;
                     }
 
-                    // 5. Save transcription to history (async, non-blocking)
-                    let app_for_history = app_for_process.clone();
-                    let history_text = final_text.clone();
-                    let history_model = model_for_process.clone();
-                    let recording_file_for_history = recording_file_for_task.clone();
-                    let writing_metadata_for_history = writing_metadata.clone();
-                    let generation_for_history = task_generation;
-                    tokio::spawn(async move {
-                        match save_transcription_with_recording_if_current(
-                            app_for_history.clone(),
-                            generation_for_history,
-                            history_text,
-                            history_model,
-                            recording_file_for_history,
-                            writing_metadata_for_history,
-                        )
-                        .await
-                        {
-                            Some(Ok(())) => {
-                                // Emit history-updated event to refresh UI
-                                let _ =
-                                    emit_to_window(&app_for_history, "main", "history-updated", ());
-                                log::debug!("Transcription saved to history successfully");
-                            }
-                            Some(Err(e)) => {
-                                log::error!("Failed to save transcription to history: {}", e)
-                            }
-                            None => log::info!(
-                                "Skipped spawned history save for stale/cancelled generation {}",
-                                generation_for_history
-                            ),
+                    // 5. Save transcription to history before going idle, so the
+                    // next recording cannot start until this dictation is done
+                    match save_transcription_with_recording_if_current(
+                        app_for_process.clone(),
+                        task_generation,
+                        final_text.clone(),
+                        model_for_process.clone(),
+                        Some(transcription_for_process.engine.clone()),
+                        recording_file_for_task.clone(),
+                        writing_metadata.clone(),
+                        transcription_for_process.segments.clone(),
+                    )
+                    .await
+                    {
+                        Some(Ok(())) => {
+                            // Emit history-updated event to refresh UI
+                            let _ = emit_to_window(&app_for_process, "main", "history-updated", ());
+                            log::debug!("Transcription saved to history successfully");
                         }
-                    });
+                        Some(Err(e)) => {
+                            log::error!("Failed to save transcription to history: {}", e)
+                        }
+                        None => log::info!(
+                            "Skipped history save for stale/cancelled generation {}",
+                            task_generation
+                        ),
+                    }
 
                     // 6. Transition to idle state
                     update_recording_state(&app_for_process, RecordingState::Idle, None);
-                });
+
+                    if pill_hide_delay_ms > 0 {
+                        // Only the delayed hide outlives the job
+                        tokio::spawn(async move {
+                            tokio::time::sleep(std::time::Duration::from_millis(
+                                pill_hide_delay_ms,
+                            ))
+                            .await;
+                            let state = crate::get_recording_state(&app_for_process);
+                            if delayed_pill_hide_still_wanted(task_generation, &state)
+                                && should_hide_pill(&app_for_process).await
+                            {
+                                if let Err(e) = crate::commands::window::hide_pill_widget(
+                                    app_for_process.clone(),
+                                )
+                                .await
+                                {
+                                    log::error!("Failed to hide pill window: {}", e);
+                                }
+                            }
+                        });
+                    }
+                }
+                .await;
             }
             Err(failure) => {
+                if let Some(reply) = dry_run_reply.take() {
+                    let _ = reply.send(Err(failure.message()));
+                }
+                crate::commands::text::discard_streaming_text().await;
                 match &failure {
                     TranscriptionFailure::Local(e)
                         if e.contains("cancelled") || e.contains("Cancelled") =>
@@ -5752,8 +8075,10 @@ pub async fn stop_recording(
 
                         // Hide pill after showing feedback
                         let app_for_reset = app_for_task.clone();
+                        let hide_delay_ms = config.pill_hide_delay_empty_ms;
                         tokio::spawn(async move {
-                            tokio::time::sleep(std::time::Duration::from_millis(2500)).await;
+                            tokio::time::sleep(std::time::Duration::from_millis(hide_delay_ms))
+                                .await;
 
                             // Only hide if show_pill_indicator is false
                             if should_hide_pill(&app_for_reset).await {
@@ -5821,8 +8146,10 @@ pub async fn stop_recording(
 
                         // Transition back to Idle after showing the error
                         let app_for_reset = app_for_task.clone();
+                        let hide_delay_ms = config.pill_hide_delay_error_ms;
                         tokio::spawn(async move {
-                            tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+                            tokio::time::sleep(std::time::Duration::from_millis(hide_delay_ms))
+                                .await;
                             if should_hide_pill(&app_for_reset).await {
                                 if let Err(e) =
                                     crate::commands::window::hide_pill_widget(app_for_reset.clone())
@@ -5910,8 +8237,10 @@ pub async fn stop_recording(
 
                         // Transition back to Idle after a delay so we don't get stuck.
                         let app_for_reset = app_for_task.clone();
+                        let hide_delay_ms = config.pill_hide_delay_error_ms;
                         tokio::spawn(async move {
-                            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+                            tokio::time::sleep(std::time::Duration::from_millis(hide_delay_ms))
+                                .await;
                             log::debug!(
                                 "Resetting from Error to Idle state after transcription failure"
                             );
@@ -5929,17 +8258,27 @@ pub async fn stop_recording(
                 }
             }
         }
-    });
+    };
 
-    // Track the transcription task
     let app_state = app.state::<AppState>();
-    if let Ok(mut task_guard) = app_state.transcription_task.lock() {
-        // Cancel any existing task
-        if let Some(existing_task) = task_guard.take() {
-            existing_task.abort();
-        }
-        // Store the new task handle
-        *task_guard = Some(task_handle);
+    if let Err(full) = app_state.transcription_queue.enqueue(
+        transcription,
+        task_generation,
+        max_queued_transcriptions,
+    ) {
+        log::warn!(
+            "Transcription queue full ({} queued); dropping this recording",
+            full.capacity
+        );
+        finalize_in_flight_audio(task_generation, &queued_audio_path);
+        pill_toast_with_suggestion(
+            &app,
+            "Too many recordings waiting",
+            "Wait for the current transcription to finish",
+            2500,
+            Some(PillToastVariant::Warning),
+        );
+        update_recording_state(&app, RecordingState::Idle, None);
     }
 
     // Return immediately so front-end promise resolves before timeout
@@ -5968,11 +8307,119 @@ pub async fn get_audio_devices(app: AppHandle) -> Result<Vec<String>, String> {
     Ok(AudioRecorder::get_devices())
 }
 
-/// Get the current default audio input device.
-/// Returns error if onboarding not completed (to avoid triggering permission prompt).
+/// Get available audio input devices with their supported capture formats.
+/// Returns empty list if onboarding not completed (to avoid triggering permission prompt).
 #[tauri::command]
-pub async fn get_current_audio_device(app: AppHandle) -> Result<String, String> {
-    // Check onboarding status - don't access devices until onboarding is complete
+pub async fn get_audio_devices_detailed(
+    app: AppHandle,
+) -> Result<Vec<crate::audio::recorder::InputDeviceInfo>, String> {
+    let onboarding_done = {
+        use tauri_plugin_store::StoreExt;
+        app.store("settings")
+            .ok()
+            .and_then(|store| store.get("onboarding_completed").and_then(|v| v.as_bool()))
+            .unwrap_or(false)
+    };
+
+    if !onboarding_done {
+        log::debug!("get_audio_devices_detailed: onboarding not complete, returning empty list");
+        return Ok(Vec::new());
+    }
+
+    // Config enumeration opens each device, so keep it off the async runtime
+    tokio::task::spawn_blocking(AudioRecorder::get_devices_detailed)
+        .await
+        .map_err(|e| format!("Device enumeration failed: {}", e))
+}
+
+// Bounds for a `sample_input_level` mic check (ms)
+const MIN_INPUT_LEVEL_SAMPLE_MS: u64 = 500;
+const MAX_INPUT_LEVEL_SAMPLE_MS: u64 = 10_000;
+
+/// Levels from a [`sample_input_level`] mic check. `peak` and `rms` are linear
+/// full scale (0.0-1.0), measured with the current `mic_gain_db` applied.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct InputLevelSample {
+    pub peak: f32,
+    pub rms: f32,
+    pub peak_dbfs: f32,
+    pub rms_dbfs: f32,
+    pub clipping: bool,
+    pub suggested_mic_gain_db: f32,
+}
+
+/// Record `duration_ms` (500-10000) from the selected microphone without saving
+/// it, emitting `audio-level` to the main window while it runs, and report the
+/// levels with a suggested `mic_gain_db`. Independent of the recording flow;
+/// refuses to run while a recording is in progress.
+#[tauri::command]
+pub async fn sample_input_level(
+    app: AppHandle,
+    duration_ms: u64,
+) -> Result<InputLevelSample, String> {
+    use crate::audio::level_meter::{suggest_mic_gain_db, to_dbfs};
+
+    let recording = app
+        .state::<RecorderState>()
+        .inner()
+        .0
+        .lock()
+        .map(|recorder| recorder.is_recording())
+        .unwrap_or(false);
+    if recording {
+        return Err("Stop the current recording before testing the microphone".to_string());
+    }
+
+    let settings = get_settings(app.clone()).await?;
+    let gain_db = settings.mic_gain_db;
+    let duration = std::time::Duration::from_millis(
+        duration_ms.clamp(MIN_INPUT_LEVEL_SAMPLE_MS, MAX_INPUT_LEVEL_SAMPLE_MS),
+    );
+
+    let (audio_level_tx, audio_level_rx) = std::sync::mpsc::channel::<f64>();
+    let app_for_levels = app.clone();
+    // Use a thread instead of tokio spawn for std::sync::mpsc; it ends when
+    // the sample finishes and drops the sender.
+    std::thread::spawn(move || {
+        while let Ok(level) = audio_level_rx.recv() {
+            let _ = emit_to_window(&app_for_levels, "main", "audio-level", level);
+        }
+    });
+
+    let selected_microphone = settings.selected_microphone;
+    let stats = tokio::task::spawn_blocking(move || {
+        crate::audio::recorder::sample_input_level(
+            selected_microphone,
+            duration,
+            gain_db,
+            audio_level_tx,
+        )
+    })
+    .await
+    .map_err(|e| format!("Input level sample failed: {}", e))??;
+
+    let sample = InputLevelSample {
+        peak: stats.peak(),
+        rms: stats.rms(),
+        peak_dbfs: to_dbfs(stats.peak()),
+        rms_dbfs: to_dbfs(stats.rms()),
+        clipping: stats.peak() >= 0.99,
+        suggested_mic_gain_db: suggest_mic_gain_db(&stats, gain_db),
+    };
+    log::info!(
+        "Input level sample: peak {:.1} dBFS, rms {:.1} dBFS, suggested gain {:+.0} dB",
+        sample.peak_dbfs,
+        sample.rms_dbfs,
+        sample.suggested_mic_gain_db
+    );
+    Ok(sample)
+}
+
+/// Get the current default audio input device.
+/// Returns error if onboarding not completed (to avoid triggering permission prompt).
+#[tauri::command]
+pub async fn get_current_audio_device(app: AppHandle) -> Result<String, String> {
+    // Check onboarding status - don't access devices until onboarding is complete
     // This prevents early mic permission prompts from CPAL's default_input_device() access
     let onboarding_done = {
         use tauri_plugin_store::StoreExt;
@@ -5994,23 +8441,40 @@ pub async fn get_current_audio_device(app: AppHandle) -> Result<String, String>
         .ok_or_else(|| "No default input device found".to_string())
 }
 
+/// Pause whatever is playing, hold briefly, then resume it, so users can check
+/// that their player honors system media controls before relying on
+/// `pause_media_during_recording`. Refused while a recording is in progress.
+#[tauri::command]
+pub async fn test_media_pause(app: AppHandle) -> Result<MediaPauseTestResult, String> {
+    let state = app.state::<AppState>().get_current_state();
+    if state != RecordingState::Idle {
+        return Err("Finish the current recording before testing media pause".to_string());
+    }
+
+    tokio::task::spawn_blocking(|| {
+        let outcome = MEDIA_CONTROLLER.try_pause();
+        let resumed = outcome == PauseAttempt::Paused && {
+            std::thread::sleep(MEDIA_PAUSE_TEST_HOLD);
+            MEDIA_CONTROLLER.resume_if_we_paused()
+        };
+        let result = MediaPauseTestResult::new(outcome, resumed);
+        log::info!("Media pause test: {:?} (resumed={})", outcome, resumed);
+        result
+    })
+    .await
+    .map_err(|e| format!("Media pause test failed: {}", e))
+}
+
 #[tauri::command]
 pub async fn cleanup_old_transcriptions(app: AppHandle, days: Option<u32>) -> Result<(), String> {
-    if let Some(days) = days {
+    if let Some(days) = days.filter(|days| *days > 0) {
         let store = app.store("transcriptions").map_err(|e| e.to_string())?;
 
         let cutoff_date = chrono::Utc::now() - chrono::Duration::days(days as i64);
 
-        // Get all keys
-        let keys: Vec<String> = store.keys().into_iter().map(|k| k.to_string()).collect();
-
         // Remove old entries
-        for key in keys {
-            if let Ok(date) = chrono::DateTime::parse_from_rfc3339(&key) {
-                if date < cutoff_date {
-                    store.delete(&key);
-                }
-            }
+        for key in history_keys_older_than(store.keys(), cutoff_date) {
+            store.delete(&key);
         }
 
         store.save().map_err(|e| e.to_string())?;
@@ -6019,6 +8483,147 @@ pub async fn cleanup_old_transcriptions(app: AppHandle, days: Option<u32>) -> Re
     Ok(())
 }
 
+/// How often the background history cleanup runs after the startup pass.
+const HISTORY_CLEANUP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+
+/// Apply `transcription_cleanup_days` (None or 0 = keep forever) and the
+/// `max_history_entries` cap, emitting `history-updated` when rows were
+/// removed. Returns the number of removed rows.
+fn run_history_cleanup(app: &AppHandle) -> Result<usize, String> {
+    let settings = app.store("settings").map_err(|e| e.to_string())?;
+    let retention_days = settings
+        .get("transcription_cleanup_days")
+        .and_then(|v| v.as_u64())
+        .filter(|days| *days > 0);
+    let max_entries = max_history_entries_setting(app);
+    if retention_days.is_none() && max_entries == 0 {
+        return Ok(0);
+    }
+
+    let store = app.store("transcriptions").map_err(|e| e.to_string())?;
+    let cutoff =
+        retention_days.map(|days| chrono::Utc::now() - chrono::Duration::days(days as i64));
+    remove_history_rows(app, &store, cutoff, max_entries)
+}
+
+fn max_history_entries_setting(app: &AppHandle) -> usize {
+    app.store("settings")
+        .ok()
+        .and_then(|settings| settings.get("max_history_entries"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as usize
+}
+
+/// Delete the rows `history_cleanup_keys` selects, then save and emit
+/// `history-updated` if anything was removed. Shared by the scheduled cleanup
+/// and the cap applied after each save.
+fn remove_history_rows(
+    app: &AppHandle,
+    store: &tauri_plugin_store::Store<tauri::Wry>,
+    cutoff: Option<chrono::DateTime<chrono::Utc>>,
+    max_entries: usize,
+) -> Result<usize, String> {
+    let removed = history_cleanup_keys(store.keys(), cutoff, max_entries, |key| {
+        store
+            .get(key)
+            .and_then(|row| row.get("favorite").and_then(|v| v.as_bool()))
+            .unwrap_or(false)
+    });
+    if removed.is_empty() {
+        return Ok(0);
+    }
+
+    for key in &removed {
+        store.delete(key);
+    }
+    store.save().map_err(|e| e.to_string())?;
+    let _ = emit_to_window(app, "main", "history-updated", ());
+    Ok(removed.len())
+}
+
+/// History cleanup on startup and once a day after. Settings are re-read on
+/// every pass, so retention changes apply without a restart.
+pub fn spawn_history_cleanup(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match run_history_cleanup(&app) {
+                Ok(0) => {}
+                Ok(removed) => log::info!("History cleanup removed {} transcription(s)", removed),
+                Err(e) => log::warn!("History cleanup failed: {}", e),
+            }
+            tokio::time::sleep(HISTORY_CLEANUP_INTERVAL).await;
+        }
+    });
+}
+
+/// Captures and normalized intermediates younger than this may still belong
+/// to a transcription in flight, so the startup sweep leaves them alone.
+pub(crate) const ORPHAN_RECORDING_MIN_AGE: std::time::Duration =
+    std::time::Duration::from_secs(24 * 60 * 60);
+
+/// Whether `file_name` is a leftover capture (`recording_*.wav`) or normalized
+/// intermediate (`normalized_*.wav`) old enough to delete. Saved recordings
+/// and kept raw captures use other names and are never matched.
+pub(crate) fn is_orphaned_recording(
+    file_name: &str,
+    modified: std::time::SystemTime,
+    now: std::time::SystemTime,
+) -> bool {
+    let temp_name = (file_name.starts_with("recording_") || file_name.starts_with("normalized_"))
+        && file_name.ends_with(".wav");
+    temp_name
+        && now
+            .duration_since(modified)
+            .is_ok_and(|age| age >= ORPHAN_RECORDING_MIN_AGE)
+}
+
+/// Delete orphaned temp recordings directly inside `dir`. Returns the number
+/// of files removed.
+fn cleanup_orphaned_recordings(dir: &Path, now: std::time::SystemTime) -> usize {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    let mut removed = 0;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let Ok(modified) = entry.metadata().and_then(|m| m.modified()) else {
+            continue;
+        };
+        if !path.is_file() || !is_orphaned_recording(name, modified, now) {
+            continue;
+        }
+        match std::fs::remove_file(&path) {
+            Ok(()) => removed += 1,
+            Err(e) => log::debug!("Failed to remove orphaned recording {:?}: {}", path, e),
+        }
+    }
+    removed
+}
+
+/// One-off startup sweep of temp captures left behind by crashes or killed
+/// transcriptions, in both the default and the configured recordings folder.
+pub fn spawn_orphaned_recording_cleanup(app: AppHandle) {
+    let mut dirs: Vec<PathBuf> = default_recordings_dir(&app).into_iter().collect();
+    if let Ok(dir) = resolve_recordings_dir(&app) {
+        if !dirs.contains(&dir) {
+            dirs.push(dir);
+        }
+    }
+    tauri::async_runtime::spawn_blocking(move || {
+        let now = std::time::SystemTime::now();
+        let removed: usize = dirs
+            .iter()
+            .map(|dir| cleanup_orphaned_recordings(dir, now))
+            .sum();
+        if removed > 0 {
+            log::info!("Removed {} orphaned recording file(s)", removed);
+        }
+    });
+}
+
 /// Save transcription to history without a recording file
 #[tauri::command]
 pub async fn save_transcription(
@@ -6026,8 +8631,41 @@ pub async fn save_transcription(
     text: String,
     model: String,
     metadata: Option<serde_json::Value>,
+    engine: Option<String>,
 ) -> Result<(), String> {
-    save_transcription_with_recording(app, text, model, None, metadata).await
+    save_transcription_with_recording(app, text, model, engine, None, metadata, None).await
+}
+
+/// Engine recorded on a history row: the explicit value, else the engine the
+/// writing metadata captured for this transcription.
+fn history_row_engine(
+    engine: Option<&str>,
+    writing_metadata: Option<&serde_json::Value>,
+) -> Option<String> {
+    engine
+        .or_else(|| writing_metadata.and_then(|m| m.get("engine")?.as_str()))
+        .map(str::trim)
+        .filter(|engine| !engine.is_empty())
+        .map(str::to_string)
+}
+
+/// Fill in `engine` for rows saved before it was stored at the top level,
+/// from their writing metadata or, for cloud rows, the provider-id model.
+fn with_history_engine(mut entry: serde_json::Value) -> serde_json::Value {
+    if entry.get("engine").and_then(|v| v.as_str()).is_some() {
+        return entry;
+    }
+    let engine = history_row_engine(None, entry.get("writing")).or_else(|| {
+        entry
+            .get("model")
+            .and_then(|v| v.as_str())
+            .and_then(crate::cloud_stt::CloudProvider::from_id)
+            .map(|provider| provider.id().to_string())
+    });
+    if let (Some(engine), Some(map)) = (engine, entry.as_object_mut()) {
+        map.insert("engine".to_string(), serde_json::Value::String(engine));
+    }
+    entry
 }
 
 /// Save transcription to history with optional recording file reference
@@ -6035,56 +8673,86 @@ pub async fn save_transcription_with_recording(
     app: AppHandle,
     text: String,
     model: String,
+    engine: Option<String>,
     recording_file: Option<String>,
     writing_metadata: Option<serde_json::Value>,
+    segments: Option<Vec<TranscriptionSegment>>,
 ) -> Result<(), String> {
     save_transcription_with_recording_internal(
         app,
         text,
         model,
+        engine,
         recording_file,
         writing_metadata,
+        segments,
         None,
     )
     .await
     .unwrap_or(Ok(()))
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn save_transcription_with_recording_if_current(
     app: AppHandle,
     generation: u64,
     text: String,
     model: String,
+    engine: Option<String>,
     recording_file: Option<String>,
     writing_metadata: Option<serde_json::Value>,
+    segments: Option<Vec<TranscriptionSegment>>,
 ) -> Option<Result<(), String>> {
     save_transcription_with_recording_internal(
         app,
         text,
         model,
+        engine,
         recording_file,
         writing_metadata,
+        segments,
         Some(generation),
     )
     .await
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn save_transcription_with_recording_internal(
     app: AppHandle,
     text: String,
     model: String,
+    engine: Option<String>,
     recording_file: Option<String>,
     writing_metadata: Option<serde_json::Value>,
+    segments: Option<Vec<TranscriptionSegment>>,
     generation: Option<u64>,
 ) -> Option<Result<(), String>> {
     // De-dup guard: skip saving if the most recent entry matches the same text & model within a short window
+    let window_secs = app
+        .store("settings")
+        .ok()
+        .and_then(|settings| settings.get("duplicate_window_seconds"))
+        .and_then(|v| v.as_u64())
+        .map(crate::commands::settings::normalize_duplicate_window_seconds)
+        .unwrap_or(crate::commands::settings::DEFAULT_DUPLICATE_WINDOW_SECONDS);
     if let Ok(store) = app.store("transcriptions") {
         let latest_key = page_history_keys(store.keys(), 1).into_iter().next();
 
         if let Some(key) = latest_key {
             if let Some(value) = store.get(&key) {
-                if is_duplicate_transcription(&key, &value, &text, &model, chrono::Utc::now()) {
-                    log::info!("Skipping duplicate transcription save (same text/model within 2s)");
+                if is_duplicate_transcription(
+                    &key,
+                    &value,
+                    &text,
+                    &model,
+                    chrono::Utc::now(),
+                    window_secs as u64,
+                ) {
+                    log::info!(
+                        "Skipping near-duplicate transcription save (same normalized text/model within {}s of {})",
+                        window_secs,
+                        key
+                    );
                     return Some(Ok(()));
                 }
             }
@@ -6104,14 +8772,40 @@ async fn save_transcription_with_recording_internal(
         "timestamp": timestamp.clone()
     });
 
+    if let Some(engine) = history_row_engine(engine.as_deref(), writing_metadata.as_ref()) {
+        transcription_data["engine"] = serde_json::json!(engine);
+    }
+
     // Add recording_file if present
     if let Some(ref file) = recording_file {
         transcription_data["recording_file"] = serde_json::json!(file);
+        if let Some(format) = crate::ffmpeg::RecordingFormat::from_path(Path::new(file)) {
+            transcription_data["recording_format"] = serde_json::json!(format.as_str());
+        }
         log::info!("Saving transcription with recording file: {}", file);
     }
     if let Some(metadata) = writing_metadata {
         transcription_data["writing"] = metadata;
     }
+    // Flat copies of the writing metadata's language/duration/confidence for exports
+    if let (Ok(serde_json::Value::Object(details)), Some(map)) = (
+        serde_json::to_value(crate::commands::utils::HistoryDetails::of_entry(
+            &transcription_data,
+        )),
+        transcription_data.as_object_mut(),
+    ) {
+        map.extend(details);
+    }
+    // Segment timings let history export to subtitle formats.
+    if let Some(segments) = segments.filter(|segments| {
+        segments
+            .iter()
+            .any(|segment| segment.start_ms.is_some() && segment.end_ms.is_some())
+    }) {
+        if let Ok(value) = serde_json::to_value(segments) {
+            transcription_data["segments"] = value;
+        }
+    }
 
     let commit_result = match generation {
         Some(generation) => {
@@ -6143,6 +8837,7 @@ async fn save_transcription_with_recording_internal(
         Some(Ok(())) => {
             // Emit the new transcription data to frontend for append-only update
             let _ = emit_to_window(&app, "main", "transcription-added", transcription_data);
+            enforce_history_cap(&app, &store);
 
             // Refresh tray menu (best-effort) so Recent Transcriptions stays updated
             if let Err(e) = crate::commands::settings::update_tray_menu(app.clone()).await {
@@ -6158,6 +8853,21 @@ async fn save_transcription_with_recording_internal(
     }
 }
 
+/// Apply the `max_history_entries` cap after a save, emitting `history-updated`
+/// when rows were evicted.
+fn enforce_history_cap(app: &AppHandle, store: &tauri_plugin_store::Store<tauri::Wry>) {
+    let max_entries = max_history_entries_setting(app);
+    match remove_history_rows(app, store, None, max_entries) {
+        Ok(0) => {}
+        Ok(evicted) => log::info!(
+            "Evicted {} oldest transcription(s) to stay within {} entries",
+            evicted,
+            max_entries
+        ),
+        Err(e) => log::warn!("Failed to save history after eviction: {}", e),
+    }
+}
+
 async fn save_failed_transcription_if_current(
     app: &AppHandle,
     generation: u64,
@@ -6220,6 +8930,7 @@ async fn save_failed_transcription_internal(
         Some(Ok(())) => {
             // Emit the new transcription data to frontend
             let _ = emit_to_window(app, "main", "transcription-added", transcription_data);
+            enforce_history_cap(app, &store);
 
             // Refresh tray menu
             if let Err(e) = crate::commands::settings::update_tray_menu(app.clone()).await {
@@ -6260,7 +8971,7 @@ pub async fn get_transcription_history(
             if reconciled != value {
                 pending_updates.push((key, reconciled.clone()));
             }
-            entries.push(reconciled);
+            entries.push(with_history_engine(reconciled));
         }
     }
 
@@ -6292,6 +9003,386 @@ pub async fn get_transcription_count(app: AppHandle) -> Result<usize, String> {
     Ok(store.keys().len())
 }
 
+/// Search history text for rows containing every term of `query`
+/// (case-insensitive), newest first. Each hit carries the row plus the matched
+/// spans for highlighting.
+#[tauri::command]
+pub async fn search_transcriptions(
+    app: AppHandle,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<TranscriptionSearchHit>, String> {
+    let store = app.store("transcriptions").map_err(|e| e.to_string())?;
+    let hits = search_history_entries(
+        store.keys(),
+        |key| store.get(key).map(with_history_engine),
+        &query,
+        limit.unwrap_or(50),
+    );
+    log::debug!("History search matched {} rows", hits.len());
+    Ok(hits)
+}
+
+/// Chunks of a long upload produced by ffmpeg's segmenter, in playback order.
+/// Every chunk but the last also carries the first `CHUNK_OVERLAP_MS` of the
+/// next one, so words on a boundary are heard whole at least once;
+/// `durations_ms` are the chunks' own lengths without that overlap. The
+/// scratch directory holding them is removed on drop.
+struct UploadSegments {
+    dir: PathBuf,
+    paths: Vec<PathBuf>,
+    durations_ms: Vec<u64>,
+}
+
+/// Audio shared between consecutive upload chunks.
+const CHUNK_OVERLAP_MS: u64 = 2_000;
+
+impl Drop for UploadSegments {
+    fn drop(&mut self) {
+        if let Err(error) = std::fs::remove_dir_all(&self.dir) {
+            if error.kind() != std::io::ErrorKind::NotFound {
+                log::warn!(
+                    "Failed to remove upload segment dir {:?}: {}",
+                    self.dir,
+                    error
+                );
+            }
+        }
+    }
+}
+
+/// Upload segment length in seconds for this engine; 0 disables splitting.
+/// An explicit `upload_segment_seconds` applies to every engine. Unset,
+/// Whisper and Parakeet split long files so progress can be reported, while
+/// cloud providers get the whole file and handle its length server-side.
+fn upload_segment_seconds(app: &AppHandle, engine_selection: &ActiveEngineSelection) -> u32 {
+    let configured = app
+        .store("settings")
+        .ok()
+        .and_then(|store| store.get("upload_segment_seconds"))
+        .and_then(|v| v.as_u64());
+    match (configured, engine_selection) {
+        (Some(seconds), _) => crate::commands::settings::normalize_upload_segment_seconds(seconds),
+        (None, ActiveEngineSelection::Whisper { .. } | ActiveEngineSelection::Parakeet { .. }) => {
+            crate::commands::settings::DEFAULT_LOCAL_UPLOAD_SEGMENT_SECONDS
+        }
+        (None, _) => 0,
+    }
+}
+
+/// Append the first `overlap_ms` of `next` to the end of `chunk`. Both are
+/// the normalized 16 kHz mono s16 WAVs the segmenter writes.
+fn append_chunk_overlap(chunk: &Path, next: &Path, overlap_ms: u64) -> Result<(), String> {
+    let mut reader = hound::WavReader::open(next)
+        .map_err(|e| format!("Failed to read segment {:?}: {}", next, e))?;
+    let spec = reader.spec();
+    let samples = (spec.sample_rate as u64 * overlap_ms / 1000) as usize * spec.channels as usize;
+    let head: Vec<i16> = reader
+        .samples::<i16>()
+        .take(samples)
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to read segment {:?}: {}", next, e))?;
+
+    let mut writer = hound::WavWriter::append(chunk)
+        .map_err(|e| format!("Failed to extend segment {:?}: {}", chunk, e))?;
+    for sample in head {
+        writer
+            .write_sample(sample)
+            .map_err(|e| format!("Failed to extend segment {:?}: {}", chunk, e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to extend segment {:?}: {}", chunk, e))
+}
+
+fn wav_duration_ms(path: &Path) -> Option<u64> {
+    let reader = hound::WavReader::open(path).ok()?;
+    let spec = reader.spec();
+    if spec.sample_rate == 0 || spec.channels == 0 {
+        return None;
+    }
+    let frames = (reader.duration() / spec.channels as u32) as u64;
+    Some(frames.saturating_mul(1000) / spec.sample_rate as u64)
+}
+
+/// Split a normalized upload into `segment_seconds` chunks. Returns `None` when
+/// splitting is disabled or the audio is short enough to send in one request.
+async fn split_upload_into_segments(
+    app: &AppHandle,
+    normalized_path: &Path,
+    recordings_dir: &Path,
+    segment_seconds: u32,
+) -> Result<Option<UploadSegments>, String> {
+    if segment_seconds == 0 {
+        return Ok(None);
+    }
+    let duration_ms = wav_duration_ms(normalized_path).unwrap_or(0);
+    if duration_ms <= segment_seconds as u64 * 1000 {
+        return Ok(None);
+    }
+
+    let dir = recordings_dir.join(format!("upload_segments_{}", Uuid::new_v4()));
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create segment directory: {}", e))?;
+    let mut segments = UploadSegments {
+        dir,
+        paths: Vec::new(),
+        durations_ms: Vec::new(),
+    };
+    crate::ffmpeg::segment(
+        app,
+        normalized_path,
+        &segments.dir.join("segment_%04d.wav"),
+        segment_seconds,
+    )
+    .await
+    .map_err(|e| format!("Audio segmentation (ffmpeg) failed: {}", e))?;
+
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(&segments.dir)
+        .map_err(|e| format!("Failed to read segment directory: {}", e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "wav"))
+        .collect();
+    paths.sort();
+    if paths.is_empty() {
+        return Err("Audio segmentation produced no segments".to_string());
+    }
+    log::info!(
+        "[UPLOAD] Split {}ms upload into {} segments of {}s",
+        duration_ms,
+        paths.len(),
+        segment_seconds
+    );
+    // Measure before adding overlap: offsets are the chunks' own lengths.
+    segments.durations_ms = paths
+        .iter()
+        .map(|path| wav_duration_ms(path).unwrap_or(0))
+        .collect();
+    for pair in paths.windows(2) {
+        append_chunk_overlap(&pair[0], &pair[1], CHUNK_OVERLAP_MS)?;
+    }
+    segments.paths = paths;
+    Ok(Some(segments))
+}
+
+/// Transcribe each chunk in order and stitch the results back together with
+/// timestamps relative to the whole file, dropping the words each chunk
+/// repeats from the overlap with the one before. Emits `transcription-progress`
+/// as chunks finish so the UI can show where a long upload is.
+async fn transcribe_upload_segments(
+    app: &AppHandle,
+    engine_selection: &ActiveEngineSelection,
+    segments: &UploadSegments,
+    language: &str,
+    translate_to_english: bool,
+    transcription_job: &TranscriptionJob,
+) -> Result<TranscriptionResult, String> {
+    let total = segments.paths.len();
+    let emit_progress = |done: usize| {
+        let _ = emit_to_all(
+            app,
+            "transcription-progress",
+            serde_json::json!({ "done_chunks": done, "total_chunks": total }),
+        );
+    };
+    emit_progress(0);
+
+    let mut offset_ms = 0u64;
+    let mut previous_text = String::new();
+    let mut results = Vec::with_capacity(total);
+    for (index, path) in segments.paths.iter().enumerate() {
+        let mut result = transcribe_normalized_upload(
+            app,
+            engine_selection,
+            path,
+            language,
+            translate_to_english,
+            transcription_job,
+        )
+        .await
+        .map_err(|e| format!("Segment {} of {} failed: {}", index + 1, total, e))?;
+        let deduped =
+            crate::transcription::strip_repeated_overlap(&previous_text, &result.raw_text)
+                .to_string();
+        if !deduped.trim().is_empty() {
+            previous_text = deduped.clone();
+        }
+        result.raw_text = deduped;
+
+        let segment_ms = segments.durations_ms.get(index).copied().unwrap_or(0);
+        if index + 1 < total {
+            crate::transcription::drop_overlap_timings(&mut result, segment_ms);
+        }
+        results.push((offset_ms, result.with_audio_duration_ms(Some(segment_ms))));
+        offset_ms = offset_ms.saturating_add(segment_ms);
+        emit_progress(index + 1);
+    }
+    crate::transcription::merge_chunk_results(results)
+        .ok_or_else(|| "Audio segmentation produced no segments".to_string())
+}
+
+/// Cloud providers transcribe in the spoken language; translation is left to
+/// the writing pipeline.
+fn cloud_upload_job(transcription_job: &TranscriptionJob) -> TranscriptionJob {
+    build_transcription_job(
+        TranscriptionSource::AudioFile,
+        transcription_job.engine.clone(),
+        transcription_job.model.clone(),
+        transcription_job.spoken_language.clone(),
+        false,
+    )
+}
+
+/// Transcribe one normalized (16 kHz mono s16) upload WAV with the resolved
+/// engine. Cloud providers return plain text here; the diarized single-request
+/// path is handled by the caller.
+async fn transcribe_normalized_upload(
+    app: &AppHandle,
+    engine_selection: &ActiveEngineSelection,
+    normalized_path: &Path,
+    language: &str,
+    translate_to_english: bool,
+    transcription_job: &TranscriptionJob,
+) -> Result<TranscriptionResult, String> {
+    match engine_selection {
+        ActiveEngineSelection::Whisper { model_path, .. } => {
+            let initial_prompt = compile_whisper_initial_prompt(
+                app,
+                Some(language),
+                &stored_whisper_initial_prompt(app),
+            );
+            let output = transcribe_whisper_with_acceleration(
+                app,
+                model_path,
+                normalized_path,
+                Some(language),
+                translate_to_english,
+                initial_prompt.as_deref(),
+                || false,
+            )
+            .await?;
+            Ok(TranscriptionResult::new(transcription_job, output.raw_text)
+                .with_transcript_language(output.transcript_language)
+                .with_segments(output.segments)
+                .with_audio_duration_ms(Some(output.audio_duration_ms))
+                .with_processing_duration_ms(Some(output.processing_duration_ms))
+                .with_avg_confidence(output.avg_confidence))
+        }
+        ActiveEngineSelection::Parakeet { model_name } => {
+            let parakeet_manager = app.state::<ParakeetManager>();
+
+            parakeet_manager
+                .load_model(app, model_name)
+                .await
+                .map_err(|e| format!("Failed to load Parakeet model: {}", e))?;
+
+            let custom_vocabulary =
+                compile_parakeet_custom_vocabulary_for_transcription(app, Some(language));
+
+            match parakeet_manager
+                .transcribe_with_custom_vocabulary(
+                    app,
+                    model_name,
+                    normalized_path.to_path_buf(),
+                    ParakeetTranscriptionOptions {
+                        language: Some(language.to_string()),
+                        translate: translate_to_english,
+                        custom_vocabulary,
+                        cancel_flag: None,
+                        emit_partials: false,
+                    },
+                )
+                .await
+            {
+                Ok(ParakeetResponse::Transcription {
+                    text,
+                    segments,
+                    language,
+                    duration,
+                }) => Ok(TranscriptionResult::new(transcription_job, text)
+                    .with_transcript_language(language)
+                    .with_segments(parakeet_segments_to_transcription_segments(segments))
+                    .with_audio_duration_ms(seconds_to_duration_ms(duration))),
+                Ok(other) => Err(format!("Unexpected Parakeet response: {:?}", other)),
+                Err(err) => Err(format!("Parakeet transcription failed: {}", err)),
+            }
+        }
+        ActiveEngineSelection::Cloud { provider, .. } => {
+            let text = provider
+                .transcribe(app, normalized_path, Some(language))
+                .await?;
+            Ok(TranscriptionResult::new(
+                &cloud_upload_job(transcription_job),
+                text,
+            ))
+        }
+        ActiveEngineSelection::Remote {
+            server_id,
+            server_name,
+            host,
+            port,
+            password,
+        } => {
+            log::info!(
+                "🌐 [Remote Upload] Starting transcription to '{}' ({}:{})",
+                server_name,
+                host,
+                port
+            );
+
+            // Read the normalized audio file
+            let audio_data = std::fs::read(normalized_path)
+                .map_err(|e| format!("Failed to read audio file: {}", e))?;
+
+            let audio_size_kb = audio_data.len() as f64 / 1024.0;
+            log::info!(
+                "🌐 [Remote Upload] Sending {:.1} KB audio to '{}'",
+                audio_size_kb,
+                server_name
+            );
+
+            // Create HTTP client connection
+            let server_conn = RemoteServerConnection::new(host.clone(), *port, password.clone());
+
+            let request_context = crate::commands::remote::resolve_remote_request_context(
+                app,
+                server_id,
+                transcription_job.spoken_language.as_deref(),
+            )
+            .await;
+
+            let (request, timeout_ms) = build_remote_upload_transcription_request(
+                normalized_path,
+                audio_data,
+                Some(transcription_job),
+                request_context,
+            );
+
+            let response = client::transcribe_audio(&server_conn, request, timeout_ms)
+                .await
+                .map_err(|e| {
+                    log::warn!(
+                        "🌐 [Remote Upload] Remote transcription FAILED to '{}': {}",
+                        server_name,
+                        e
+                    );
+                    e.to_string()
+                })?;
+
+            log::info!(
+                "🌐 [Remote Upload] Transcription COMPLETED from '{}': {} chars received",
+                server_name,
+                response.text.len()
+            );
+
+            Ok(build_remote_transcription_result(
+                transcription_job,
+                response,
+            ))
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn transcribe_audio_file(
     app: AppHandle,
@@ -6299,7 +9390,160 @@ pub async fn transcribe_audio_file(
     model_name: String,
     model_engine: Option<String>,
 ) -> Result<UploadTranscription, String> {
-    transcribe_audio_file_impl(app, file_path, model_name, model_engine, true).await
+    transcribe_audio_file_impl(
+        app,
+        file_path,
+        model_name,
+        model_engine,
+        true,
+        crate::ffmpeg::PreprocessOptions::default(),
+        false,
+    )
+    .await
+}
+
+/// Transcribe an audio file and include per-word timings (start, end,
+/// confidence) in `words`. Whisper models only.
+#[tauri::command]
+pub async fn transcribe_audio_file_detailed(
+    app: AppHandle,
+    file_path: String,
+    model_name: String,
+    model_engine: Option<String>,
+) -> Result<UploadTranscription, String> {
+    transcribe_audio_file_impl(
+        app,
+        file_path,
+        model_name,
+        model_engine,
+        true,
+        crate::ffmpeg::PreprocessOptions::default(),
+        true,
+    )
+    .await
+}
+
+/// One file's outcome in a [`transcribe_audio_files`] batch: `text` on
+/// success, `error` when the file was skipped.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BatchFileTranscription {
+    pub path: String,
+    pub text: Option<String>,
+    pub error: Option<String>,
+}
+
+// Set by cancel_batch_transcription; checked before each file of a batch
+static BATCH_CANCEL_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Run `transcribe` over `paths` in order, reporting each file through
+/// `on_progress`. A failed file is recorded and the batch moves on; once
+/// `should_cancel` returns true the remaining files are marked cancelled
+/// without being transcribed.
+pub(crate) async fn run_transcription_batch<T, Fut>(
+    paths: Vec<String>,
+    should_cancel: impl Fn() -> bool,
+    mut transcribe: T,
+    on_progress: impl Fn(usize, &str, &str, Option<&str>),
+) -> Vec<BatchFileTranscription>
+where
+    T: FnMut(String) -> Fut,
+    Fut: std::future::Future<Output = Result<String, String>>,
+{
+    let mut results = Vec::with_capacity(paths.len());
+    for (index, path) in paths.into_iter().enumerate() {
+        if should_cancel() {
+            on_progress(index, &path, "cancelled", None);
+            results.push(BatchFileTranscription {
+                path,
+                text: None,
+                error: Some("Cancelled".to_string()),
+            });
+            continue;
+        }
+
+        on_progress(index, &path, "started", None);
+        let entry = match transcribe(path.clone()).await {
+            Ok(text) => {
+                on_progress(index, &path, "done", None);
+                BatchFileTranscription {
+                    path,
+                    text: Some(text),
+                    error: None,
+                }
+            }
+            Err(error) => {
+                log::warn!("[BATCH] Skipping {}: {}", path, error);
+                on_progress(index, &path, "failed", Some(&error));
+                BatchFileTranscription {
+                    path,
+                    text: None,
+                    error: Some(error),
+                }
+            }
+        };
+        results.push(entry);
+    }
+    results
+}
+
+/// Transcribe several files one after another with the same model. A failed
+/// file is reported in its entry and the batch moves on. Emits
+/// `batch-transcription-progress` before and after each file; local models
+/// stay loaded in the transcriber cache, so they load once per batch.
+/// [`cancel_batch_transcription`] stops the batch before its next file.
+#[tauri::command]
+pub async fn transcribe_audio_files(
+    app: AppHandle,
+    file_paths: Vec<String>,
+    model_name: String,
+    model_engine: Option<String>,
+) -> Result<Vec<BatchFileTranscription>, String> {
+    let total = file_paths.len();
+    BATCH_CANCEL_REQUESTED.store(false, AtomicOrdering::SeqCst);
+
+    let results = run_transcription_batch(
+        file_paths,
+        || BATCH_CANCEL_REQUESTED.load(AtomicOrdering::SeqCst),
+        |path| {
+            let app = app.clone();
+            let model_name = model_name.clone();
+            let model_engine = model_engine.clone();
+            async move {
+                transcribe_audio_file(app, path, model_name, model_engine)
+                    .await
+                    .map(|transcription| transcription.text)
+            }
+        },
+        |index, path, status, error| {
+            let _ = emit_to_all(
+                &app,
+                "batch-transcription-progress",
+                serde_json::json!({
+                    "index": index,
+                    "total": total,
+                    "path": path,
+                    "status": status,
+                    "error": error,
+                }),
+            );
+        },
+    )
+    .await;
+
+    log::info!(
+        "[BATCH] Transcribed {} of {} file(s)",
+        results.iter().filter(|entry| entry.error.is_none()).count(),
+        total
+    );
+    Ok(results)
+}
+
+/// Stop a running [`transcribe_audio_files`] batch. The file in progress
+/// finishes; the rest are reported as cancelled.
+#[tauri::command]
+pub fn cancel_batch_transcription() {
+    log::info!("[BATCH] Cancellation requested");
+    BATCH_CANCEL_REQUESTED.store(true, AtomicOrdering::SeqCst);
 }
 
 pub async fn transcribe_audio_file_for_cli(
@@ -6308,7 +9552,64 @@ pub async fn transcribe_audio_file_for_cli(
     model_name: String,
     model_engine: Option<String>,
 ) -> Result<UploadTranscription, String> {
-    transcribe_audio_file_impl(app, file_path, model_name, model_engine, false).await
+    transcribe_audio_file_impl(
+        app,
+        file_path,
+        model_name,
+        model_engine,
+        false,
+        crate::ffmpeg::PreprocessOptions::default(),
+        false,
+    )
+    .await
+}
+
+/// Re-run a retained recording through the upload pipeline with extra audio
+/// preprocessing. The history entry at `replace_timestamp` is overwritten only
+/// when given; otherwise the result is just returned for comparison.
+#[tauri::command]
+pub async fn retranscribe_with_options(
+    app: AppHandle,
+    recording_file: String,
+    model_name: String,
+    model_engine: Option<String>,
+    options: crate::ffmpeg::PreprocessOptions,
+    replace_timestamp: Option<String>,
+) -> Result<UploadTranscription, String> {
+    validate_recording_filename(&recording_file)?;
+    let recording_path = resolve_recording_file(&app, &recording_file)?;
+    if !recording_path.exists() {
+        return Err(format!("Recording file not found: {}", recording_file));
+    }
+    log::info!(
+        "[RETRANSCRIBE] {} with model {} and options {:?}",
+        recording_file,
+        model_name,
+        options
+    );
+
+    let result = transcribe_audio_file_impl(
+        app.clone(),
+        recording_path.to_string_lossy().to_string(),
+        model_name.clone(),
+        model_engine,
+        true,
+        options,
+        false,
+    )
+    .await?;
+
+    if let Some(timestamp) = replace_timestamp {
+        update_transcription(
+            app,
+            timestamp,
+            result.text.clone(),
+            model_name,
+            Some(TranscriptionStatus::Completed),
+        )
+        .await?;
+    }
+    Ok(result)
 }
 
 async fn transcribe_audio_file_impl(
@@ -6317,6 +9618,8 @@ async fn transcribe_audio_file_impl(
     model_name: String,
     model_engine: Option<String>,
     validate_requirements: bool,
+    preprocess: crate::ffmpeg::PreprocessOptions,
+    word_timestamps: bool,
 ) -> Result<UploadTranscription, String> {
     log::info!(
         "[UPLOAD] transcribe_audio_file START | file_path={:?}, model_name={}, engine_hint={:?}",
@@ -6337,11 +9640,7 @@ async fn transcribe_audio_file_impl(
     }
 
     // Convert to WAV if needed
-    let recordings_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("recordings");
+    let recordings_dir = resolve_recordings_dir(&app)?;
 
     std::fs::create_dir_all(&recordings_dir)
         .map_err(|e| format!("Failed to create recordings directory: {}", e))?;
@@ -6357,6 +9656,11 @@ async fn transcribe_audio_file_impl(
         "[UPLOAD] Engine resolved to: {}",
         engine_selection.engine_name()
     );
+    let word_timing_model = match &engine_selection {
+        _ if !word_timestamps => None,
+        ActiveEngineSelection::Whisper { model_path, .. } => Some(model_path.clone()),
+        _ => return Err("Word timings are only available for Whisper models".to_string()),
+    };
 
     // Get language and translation settings
     let store = app.store("settings").map_err(|e| e.to_string())?;
@@ -6408,208 +9712,106 @@ async fn transcribe_audio_file_impl(
         translate_to_english,
     );
 
-    // For cloud providers, skip normalization and send original wav_path
-    let transcription_result = match engine_selection {
-        ActiveEngineSelection::Whisper { model_path, .. } => {
-            // Normalize to Whisper contract
-            log::debug!("[UPLOAD] Normalizing to Whisper WAV (16k mono s16)...");
-            let normalized_file = NormalizedTempFile::new({
-                let ts = chrono::Local::now().format("%Y%m%d_%H%M%S");
-                let out_path = recordings_dir.join(format!("normalized_{}.wav", ts));
-                crate::ffmpeg::normalize_streaming(&app, &wav_path, &out_path)
-                    .await
-                    .map_err(|e| format!("Audio normalization (ffmpeg) failed: {}", e))?;
-                out_path
-            });
-            log::info!("[UPLOAD] Normalized WAV at {:?}", normalized_file.path());
-            let initial_prompt = compile_whisper_initial_prompt(&app, Some(&language));
-            let output = transcribe_whisper_with_acceleration(
-                &app,
-                &model_path,
-                normalized_file.path(),
-                Some(&language),
-                translate_to_english,
-                initial_prompt.as_deref(),
-                || false,
-            )
-            .await?;
-            TranscriptionResult::new(&transcription_job, output.raw_text)
-                .with_transcript_language(output.transcript_language)
-                .with_segments(output.segments)
-                .with_audio_duration_ms(Some(output.audio_duration_ms))
-                .with_processing_duration_ms(Some(output.processing_duration_ms))
-        }
-        ActiveEngineSelection::Parakeet { model_name } => {
-            // Normalize to Whisper/Parakeet contract first
-            log::debug!("[UPLOAD] Normalizing to Whisper WAV (16k mono s16)...");
-            let normalized_file = NormalizedTempFile::new({
-                let ts = chrono::Local::now().format("%Y%m%d_%H%M%S");
-                let out_path = recordings_dir.join(format!("normalized_{}.wav", ts));
-                crate::ffmpeg::normalize_streaming(&app, &wav_path, &out_path)
-                    .await
-                    .map_err(|e| format!("Audio normalization (ffmpeg) failed: {}", e))?;
-                out_path
-            });
-            log::info!("[UPLOAD] Normalized WAV at {:?}", normalized_file.path());
-            let parakeet_manager = app.state::<ParakeetManager>();
-
-            parakeet_manager
-                .load_model(&app, &model_name)
-                .await
-                .map_err(|e| format!("Failed to load Parakeet model: {}", e))?;
-
-            let custom_vocabulary =
-                compile_parakeet_custom_vocabulary_for_transcription(&app, Some(&language));
-
-            match parakeet_manager
-                .transcribe_with_custom_vocabulary(
-                    &app,
-                    &model_name,
-                    normalized_file.path().to_path_buf(),
-                    ParakeetTranscriptionOptions {
-                        language: Some(language.clone()),
-                        translate: translate_to_english,
-                        custom_vocabulary,
-                        cancel_flag: None,
-                    },
-                )
-                .await
-            {
-                Ok(ParakeetResponse::Transcription {
-                    text,
-                    segments,
-                    language,
-                    duration,
-                }) => TranscriptionResult::new(&transcription_job, text)
-                    .with_transcript_language(language)
-                    .with_segments(parakeet_segments_to_transcription_segments(segments))
-                    .with_audio_duration_ms(seconds_to_duration_ms(duration)),
-                Ok(other) => {
-                    return Err(format!("Unexpected Parakeet response: {:?}", other));
-                }
-                Err(err) => {
-                    return Err(format!("Parakeet transcription failed: {}", err));
-                }
-            }
-        }
-        ActiveEngineSelection::Cloud { provider, .. } => {
-            log::debug!("[UPLOAD] Normalizing to WAV for cloud transcription...");
-            let normalized_file = NormalizedTempFile::new({
-                let ts = chrono::Local::now().format("%Y%m%d_%H%M%S");
-                let out_path = recordings_dir.join(format!("normalized_{}.wav", ts));
-                crate::ffmpeg::normalize_streaming(&app, &wav_path, &out_path)
-                    .await
-                    .map_err(|e| format!("Audio normalization (ffmpeg) failed: {}", e))?;
-                out_path
-            });
-            let cloud_transcript = provider
-                .transcribe_diarized(&app, normalized_file.path(), Some(&language))
-                .await?;
-
-            // If the provider returned speaker-attributed words, group them and
-            // return directly — no AI polish for diarized uploads.
-            if !cloud_transcript.words.is_empty() {
-                let words = cloud_transcript.words;
-                let text = group_words_into_speaker_text(&words);
-                log::info!(
-                    "[UPLOAD] Diarized cloud transcript: {} words, {} chars",
-                    words.len(),
-                    text.len()
-                );
-                let mut diarized_result =
-                    TranscriptionResult::new(&transcription_job, text.clone());
-                diarized_result.words = Some(words.clone());
-                let metadata = Some(build_writing_history_metadata(&diarized_result, None));
-                return Ok(UploadTranscription {
-                    text,
-                    words: Some(words),
-                    metadata,
-                });
-            }
-
-            let cloud_job = build_transcription_job(
-                TranscriptionSource::AudioFile,
-                transcription_job.engine.clone(),
-                transcription_job.model.clone(),
-                transcription_job.spoken_language.clone(),
-                false,
-            );
-            TranscriptionResult::new(&cloud_job, cloud_transcript.text)
-        }
-        ActiveEngineSelection::Remote {
-            server_id,
-            server_name,
-            host,
-            port,
-            password,
-            ..
-        } => {
-            // Normalize to Whisper contract (16k mono s16 WAV) for remote transcription
-            log::debug!("[UPLOAD] Normalizing to Whisper WAV for remote transcription...");
-            let normalized_file = NormalizedTempFile::new({
-                let ts = chrono::Local::now().format("%Y%m%d_%H%M%S");
-                let out_path = recordings_dir.join(format!("normalized_{}.wav", ts));
-                crate::ffmpeg::normalize_streaming(&app, &wav_path, &out_path)
-                    .await
-                    .map_err(|e| format!("Audio normalization (ffmpeg) failed: {}", e))?;
-                out_path
-            });
-            log::info!("[UPLOAD] Normalized WAV at {:?}", normalized_file.path());
-
-            log::info!(
-                "🌐 [Remote Upload] Starting transcription to '{}' ({}:{})",
-                server_name,
-                host,
-                port
-            );
-
-            // Read the normalized audio file
-            let audio_data = std::fs::read(normalized_file.path())
-                .map_err(|e| format!("Failed to read audio file: {}", e))?;
-
-            let audio_size_kb = audio_data.len() as f64 / 1024.0;
-            log::info!(
-                "🌐 [Remote Upload] Sending {:.1} KB audio to '{}'",
-                audio_size_kb,
-                server_name
-            );
-
-            // Create HTTP client connection
-            let server_conn = RemoteServerConnection::new(host.clone(), port, password.clone());
-
-            let request_context = crate::commands::remote::resolve_remote_request_context(
-                &app,
-                &server_id,
-                transcription_job.spoken_language.as_deref(),
-            )
-            .await;
+    // Every engine consumes the same 16 kHz mono s16 WAV contract
+    log::debug!("[UPLOAD] Normalizing to WAV (16k mono s16)...");
+    let normalized_file = NormalizedTempFile::new({
+        let ts = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        let out_path = recordings_dir.join(format!("normalized_{}.wav", ts));
+        crate::ffmpeg::normalize_with_options(&app, &wav_path, &out_path, &preprocess)
+            .await
+            .map_err(|e| format!("Audio normalization (ffmpeg) failed: {}", e))?;
+        out_path
+    });
+    log::info!("[UPLOAD] Normalized WAV at {:?}", normalized_file.path());
 
-            let (request, timeout_ms) = build_remote_upload_transcription_request(
-                normalized_file.path(),
-                audio_data,
-                Some(&transcription_job),
-                request_context,
-            );
+    // Word timings are relative to the whole file, so detailed runs are never split.
+    let segments = if word_timing_model.is_some() {
+        None
+    } else {
+        split_upload_into_segments(
+            &app,
+            normalized_file.path(),
+            &recordings_dir,
+            upload_segment_seconds(&app, &engine_selection),
+        )
+        .await?
+    };
 
-            let response = client::transcribe_audio(&server_conn, request, timeout_ms)
-                .await
-                .map_err(|e| {
-                    log::warn!(
-                        "🌐 [Remote Upload] Remote transcription FAILED to '{}': {}",
-                        server_name,
-                        e
-                    );
-                    e.to_string()
-                })?;
+    let transcription_result = if let Some(segments) = segments {
+        transcribe_upload_segments(
+            &app,
+            &engine_selection,
+            &segments,
+            &language,
+            translate_to_english,
+            &transcription_job,
+        )
+        .await?
+    } else if let ActiveEngineSelection::Cloud { provider, .. } = &engine_selection {
+        let cloud_transcript = provider
+            .transcribe_diarized(&app, normalized_file.path(), Some(&language))
+            .await?;
 
+        // If the provider returned speaker-attributed words, group them and
+        // return directly — no AI polish for diarized uploads.
+        if !cloud_transcript.words.is_empty() {
+            let words = cloud_transcript.words;
+            let text = group_words_into_speaker_text(&words);
             log::info!(
-                "🌐 [Remote Upload] Transcription COMPLETED from '{}': {} chars received",
-                server_name,
-                response.text.len()
+                "[UPLOAD] Diarized cloud transcript: {} words, {} chars",
+                words.len(),
+                text.len()
             );
-
-            build_remote_transcription_result(&transcription_job, response)
+            let mut diarized_result = TranscriptionResult::new(&transcription_job, text.clone());
+            diarized_result.words = Some(words.clone());
+            let metadata = Some(build_writing_history_metadata(&diarized_result, None));
+            return Ok(UploadTranscription {
+                text,
+                words: Some(words),
+                metadata,
+            });
         }
+
+        TranscriptionResult::new(&cloud_upload_job(&transcription_job), cloud_transcript.text)
+    } else if let Some(model_path) = &word_timing_model {
+        let initial_prompt = compile_whisper_initial_prompt(
+            &app,
+            Some(&language),
+            &stored_whisper_initial_prompt(&app),
+        );
+        let output = transcribe_whisper_with_words(
+            &app,
+            model_path,
+            normalized_file.path(),
+            Some(&language),
+            translate_to_english,
+            initial_prompt.as_deref(),
+        )
+        .await?;
+        let mut result = TranscriptionResult::new(&transcription_job, output.raw_text)
+            .with_transcript_language(output.transcript_language)
+            .with_segments(output.segments)
+            .with_audio_duration_ms(Some(output.audio_duration_ms))
+            .with_processing_duration_ms(Some(output.processing_duration_ms))
+            .with_avg_confidence(output.avg_confidence);
+        result.words = Some(
+            output
+                .words
+                .into_iter()
+                .map(word_timing_to_transcription_word)
+                .collect(),
+        );
+        result
+    } else {
+        transcribe_normalized_upload(
+            &app,
+            &engine_selection,
+            normalized_file.path(),
+            &language,
+            translate_to_english,
+            &transcription_job,
+        )
+        .await?
     };
 
     log::info!(
@@ -6638,7 +9840,9 @@ async fn transcribe_audio_file_impl(
     ));
     Ok(UploadTranscription {
         text: writing_result.final_text,
-        words: None,
+        words: transcription_result
+            .words
+            .filter(|_| word_timing_model.is_some()),
         metadata,
     })
 }
@@ -6692,11 +9896,7 @@ pub async fn transcribe_audio(
     validate_recording_requirements(&app).await?;
 
     // Save audio data to app data directory
-    let recordings_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("recordings");
+    let recordings_dir = resolve_recordings_dir(&app)?;
 
     // Ensure directory exists
     std::fs::create_dir_all(&recordings_dir)
@@ -6761,7 +9961,11 @@ pub async fn transcribe_audio(
 
     let transcription_result = match engine_selection {
         ActiveEngineSelection::Whisper { model_path, .. } => {
-            let initial_prompt = compile_whisper_initial_prompt(&app, Some(language.as_str()));
+            let initial_prompt = compile_whisper_initial_prompt(
+                &app,
+                Some(language.as_str()),
+                &stored_whisper_initial_prompt(&app),
+            );
             let output = transcribe_whisper_with_acceleration(
                 &app,
                 &model_path,
@@ -6777,6 +9981,7 @@ pub async fn transcribe_audio(
                 .with_segments(output.segments)
                 .with_audio_duration_ms(Some(output.audio_duration_ms))
                 .with_processing_duration_ms(Some(output.processing_duration_ms))
+                .with_avg_confidence(output.avg_confidence)
         }
         ActiveEngineSelection::Parakeet { model_name } => {
             let parakeet_manager = app.state::<ParakeetManager>();
@@ -6799,6 +10004,7 @@ pub async fn transcribe_audio(
                         translate: translate_to_english,
                         custom_vocabulary,
                         cancel_flag: None,
+                        emit_partials: false,
                     },
                 )
                 .await
@@ -6930,38 +10136,63 @@ pub async fn transcribe_audio(
     Ok(writing_result.final_text)
 }
 
+/// JoinHandle::abort preempts the task at its next await and skips the task's
+/// own remove_file cleanup, so explicitly delete the temp recording the
+/// aborted task owned. Without this the cancelled dictation's audio is left on
+/// disk. A NotFound result means the task already cleaned up.
+fn remove_cancelled_transcription_audio(tracked: Option<PathBuf>) {
+    if let Some(cancelled_audio) = tracked {
+        log::info!("Removing transcription task's temp recording after abort");
+        if let Err(e) = std::fs::remove_file(&cancelled_audio) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                log::warn!("Failed to remove cancelled transcription audio: {}", e);
+            }
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn cancel_recording(app: AppHandle) -> Result<(), String> {
     log::info!("=== CANCEL RECORDING CALLED ===");
 
-    // Request cancellation FIRST
     let app_state = app.state::<AppState>();
+
+    // While the latest dictation waits in the queue, cancel only the older
+    // transcription running ahead of it; the waiting one keeps its turn
+    let current_generation = current_recording_generation();
+    if app_state.transcription_queue.is_waiting(current_generation) {
+        if let Some(generation) = app_state.transcription_queue.cancel_current() {
+            log::info!("Aborting transcription task ahead of the queued one");
+            remove_cancelled_transcription_audio(
+                take_in_flight_transcription_audio_for_generation(generation),
+            );
+        }
+        return Ok(());
+    }
+
+    // Request cancellation FIRST
     app_state.request_cancellation();
+    // A pending test recording resolves with an error instead of waiting forever
+    take_dry_run_reply(&app);
     log::info!("Cancellation requested in app state");
 
+    // Stop typing the dictation's interim text and erase what was typed
+    crate::cloud_stt::soniox_stream::discard_session();
+    crate::commands::text::discard_streaming_text().await;
+
     // Get current state
     let current_state = app_state.get_current_state();
     log::info!("Current state when cancelling: {:?}", current_state);
 
-    // Abort any ongoing transcription task
-    if let Ok(mut task_guard) = app_state.transcription_task.lock() {
-        if let Some(task) = task_guard.take() {
-            log::info!("Aborting transcription task");
-            task.abort();
-        }
-    }
-    // JoinHandle::abort preempts the task at its next await and skips the
-    // task's own remove_file cleanup, so explicitly delete the temp recording
-    // the aborted task owned. Without this the cancelled dictation's audio is
-    // left on disk. A NotFound result means the task already cleaned up.
-    if let Some(cancelled_audio) = take_in_flight_transcription_audio() {
-        log::info!("Removing transcription task's temp recording after abort");
-        if let Err(e) = std::fs::remove_file(&cancelled_audio) {
-            if e.kind() != std::io::ErrorKind::NotFound {
-                log::warn!("Failed to remove cancelled transcription audio: {}", e);
-            }
-        }
+    // Abort the transcription being processed
+    if let Some(generation) = app_state.transcription_queue.cancel_current() {
+        log::info!("Aborting transcription task");
+        remove_cancelled_transcription_audio(take_in_flight_transcription_audio_for_generation(
+            generation,
+        ));
     }
+    // The current dictation may not have reached the queue yet
+    remove_cancelled_transcription_audio(take_in_flight_transcription_audio());
 
     #[cfg(target_os = "windows")]
     if matches!(current_state, RecordingState::Transcribing) {
@@ -7059,6 +10290,60 @@ pub async fn cancel_recording(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Pause the active recording without ending it. Capture keeps running but
+/// nothing is written, so the transcribed audio and its duration exclude the
+/// paused stretch, and silence auto-stop is held until `resume_recording`.
+#[tauri::command]
+pub async fn pause_recording(app: AppHandle) -> Result<(), String> {
+    set_recording_paused(&app, true)
+}
+
+#[tauri::command]
+pub async fn resume_recording(app: AppHandle) -> Result<(), String> {
+    set_recording_paused(&app, false)
+}
+
+fn set_recording_paused(app: &AppHandle, paused: bool) -> Result<(), String> {
+    let current_state = app.state::<AppState>().get_current_state();
+    if current_state != RecordingState::Recording {
+        return Err(format!(
+            "Cannot {} while {:?}",
+            if paused { "pause" } else { "resume" },
+            current_state
+        ));
+    }
+
+    let changed = {
+        let recorder = app
+            .state::<RecorderState>()
+            .inner()
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire recorder lock: {}", e))?;
+        if paused {
+            recorder.pause()?
+        } else {
+            recorder.resume()?
+        }
+    };
+
+    if changed {
+        if let Ok(mut clock) = app.state::<AppState>().recording_clock.lock() {
+            if let Some(clock) = clock.as_mut() {
+                clock.set_paused_at(paused, Instant::now());
+            }
+        }
+        let event = if paused {
+            "recording-paused"
+        } else {
+            "recording-resumed"
+        };
+        log::info!("{}", event);
+        let _ = emit_to_window(app, "pill", event, ());
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn delete_transcription_entry(app: AppHandle, timestamp: String) -> Result<(), String> {
     let store = app
@@ -7085,6 +10370,45 @@ pub async fn delete_transcription_entry(app: AppHandle, timestamp: String) -> Re
     Ok(())
 }
 
+/// Mark or unmark a history row as a favorite. Favorites are exempt from the
+/// `max_history_entries` cap.
+#[tauri::command]
+pub async fn set_transcription_favorite(
+    app: AppHandle,
+    timestamp: String,
+    favorite: bool,
+) -> Result<(), String> {
+    let store = app
+        .store("transcriptions")
+        .map_err(|e| format!("Failed to get transcriptions store: {}", e))?;
+
+    let mut updated = store
+        .get(&timestamp)
+        .ok_or_else(|| format!("Transcription not found: {}", timestamp))?;
+    let map = updated
+        .as_object_mut()
+        .ok_or_else(|| "Transcription entry is not an object".to_string())?;
+    if favorite {
+        map.insert("favorite".to_string(), serde_json::Value::Bool(true));
+    } else {
+        map.remove("favorite");
+    }
+    store.set(&timestamp, updated);
+
+    store
+        .save()
+        .map_err(|e| format!("Failed to save store after favorite change: {}", e))?;
+
+    let _ = emit_to_window(&app, "main", "history-updated", ());
+
+    log::info!(
+        "Set favorite={} on transcription entry: {}",
+        favorite,
+        timestamp
+    );
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn clear_all_transcriptions(app: AppHandle) -> Result<(), String> {
     log::info!("[Clear All] Clearing all transcriptions");
@@ -7175,24 +10499,14 @@ fn validate_recording_filename(filename: &str) -> Result<(), String> {
 #[tauri::command]
 pub async fn check_recording_exists(app: AppHandle, filename: String) -> Result<bool, String> {
     validate_recording_filename(&filename)?;
-    let recordings_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("recordings");
-    Ok(recordings_dir.join(&filename).exists())
+    Ok(resolve_recording_file(&app, &filename)?.exists())
 }
 
 /// Get the full path to a recording file for playback
 #[tauri::command]
 pub async fn get_recording_path(app: AppHandle, filename: String) -> Result<String, String> {
     validate_recording_filename(&filename)?;
-    let recordings_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("recordings");
-    let file_path = recordings_dir.join(&filename);
+    let file_path = resolve_recording_file(&app, &filename)?;
     if !file_path.exists() {
         return Err(format!("Recording file not found: {}", filename));
     }
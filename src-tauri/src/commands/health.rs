@@ -0,0 +1,249 @@
+use serde::Serialize;
+use tauri::async_runtime::RwLock as AsyncRwLock;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+use crate::license::{LicenseState, LicenseStatus};
+use crate::recognition::RecognitionAvailabilitySnapshot;
+
+/// Below this much free space on the app data volume, recordings and model
+/// downloads may fail.
+pub const MIN_FREE_DISK_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Result of one health check, with a short explanation for the UI.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthCheck {
+    pub ok: bool,
+    pub detail: String,
+}
+
+impl HealthCheck {
+    pub fn pass(detail: impl Into<String>) -> Self {
+        Self {
+            ok: true,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn fail(detail: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Readiness of every subsystem a dictation depends on, for the Diagnostics
+/// panel. Build it with the checks filled in, then call [`Self::summarize`].
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    pub microphone_permission: HealthCheck,
+    pub accessibility_permission: HealthCheck,
+    pub model_available: HealthCheck,
+    pub ffmpeg_sidecar: HealthCheck,
+    pub selected_model_loadable: HealthCheck,
+    pub ai_api_key: HealthCheck,
+    pub license: HealthCheck,
+    pub disk_space: HealthCheck,
+    /// Whether every check passed
+    pub healthy: bool,
+    /// Names of the failing checks, in field order
+    pub failing: Vec<&'static str>,
+}
+
+impl HealthReport {
+    fn checks(&self) -> [(&'static str, &HealthCheck); 8] {
+        [
+            ("microphone_permission", &self.microphone_permission),
+            ("accessibility_permission", &self.accessibility_permission),
+            ("model_available", &self.model_available),
+            ("ffmpeg_sidecar", &self.ffmpeg_sidecar),
+            ("selected_model_loadable", &self.selected_model_loadable),
+            ("ai_api_key", &self.ai_api_key),
+            ("license", &self.license),
+            ("disk_space", &self.disk_space),
+        ]
+    }
+
+    /// Fill in `healthy` and `failing` from the individual checks.
+    pub fn summarize(mut self) -> Self {
+        let failing: Vec<&'static str> = self
+            .checks()
+            .into_iter()
+            .filter(|(_, check)| !check.ok)
+            .map(|(name, _)| name)
+            .collect();
+        self.healthy = failing.is_empty();
+        self.failing = failing;
+        self
+    }
+}
+
+/// Run every readiness check at once: the checks `perform_startup_checks` and
+/// the permission commands do one at a time, without changing any settings.
+#[tauri::command]
+pub async fn run_health_check(app: AppHandle) -> Result<HealthReport, String> {
+    let availability = crate::recognition::recognition_availability_snapshot(&app).await;
+
+    let microphone_permission =
+        match crate::commands::permissions::check_microphone_permission().await {
+            Ok(true) => HealthCheck::pass("Microphone access granted"),
+            Ok(false) => HealthCheck::fail("Microphone access not granted"),
+            Err(e) => HealthCheck::fail(format!("Could not check microphone access: {}", e)),
+        };
+    let accessibility_permission =
+        match crate::commands::permissions::check_accessibility_permission().await {
+            Ok(true) => HealthCheck::pass("Accessibility access granted"),
+            Ok(false) => {
+                HealthCheck::fail("Accessibility access not granted; text cannot be typed")
+            }
+            Err(e) => HealthCheck::fail(format!("Could not check accessibility access: {}", e)),
+        };
+
+    let model_available = if availability.any_available() {
+        HealthCheck::pass("A speech recognition engine is ready")
+    } else {
+        HealthCheck::fail("No model downloaded and no cloud or remote engine ready")
+    };
+
+    let ffmpeg_sidecar = if crate::ffmpeg::ffmpeg_available(&app) {
+        HealthCheck::pass("ffmpeg sidecar found")
+    } else {
+        HealthCheck::fail("ffmpeg sidecar not found; the built-in WAV normalizer is used")
+    };
+
+    let selected_model_loadable = selected_model_check(&app, &availability).await;
+    let ai_api_key = ai_api_key_check(&app).await;
+
+    let license = match crate::commands::license::check_license_status_internal(&app).await {
+        Ok(status) => license_check(&status),
+        Err(e) => HealthCheck::fail(format!("Could not check license: {}", e)),
+    };
+
+    let disk_space = match app.path().app_data_dir() {
+        Ok(dir) => disk_space_check(crate::utils::diagnostics::available_disk_space(&dir)),
+        Err(e) => HealthCheck::fail(format!("Could not resolve app data directory: {}", e)),
+    };
+
+    Ok(HealthReport {
+        microphone_permission,
+        accessibility_permission,
+        model_available,
+        ffmpeg_sidecar,
+        selected_model_loadable,
+        ai_api_key,
+        license,
+        disk_space,
+        healthy: false,
+        failing: Vec::new(),
+    }
+    .summarize())
+}
+
+/// Whether the engine and model in settings can transcribe right now.
+async fn selected_model_check(
+    app: &AppHandle,
+    availability: &RecognitionAvailabilitySnapshot,
+) -> HealthCheck {
+    if availability.remote_selected {
+        return if availability.remote_available {
+            HealthCheck::pass("Remote server is online")
+        } else {
+            HealthCheck::fail("Remote server is not reachable")
+        };
+    }
+
+    let Ok(store) = app.store("settings") else {
+        return HealthCheck::fail("Settings could not be read");
+    };
+    let model = store
+        .get("current_model")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_default();
+    let engine = store
+        .get("current_model_engine")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_else(|| "whisper".to_string());
+
+    if let Some(provider) = crate::cloud_stt::CloudProvider::from_id(&engine) {
+        return if availability.cloud_ready {
+            HealthCheck::pass(format!("{} is configured", provider.display_name()))
+        } else {
+            HealthCheck::fail(format!("{} has no API key", provider.display_name()))
+        };
+    }
+    if model.is_empty() {
+        return HealthCheck::fail("No model selected");
+    }
+
+    let downloaded = if engine == "parakeet" {
+        app.try_state::<crate::parakeet::ParakeetManager>()
+            .map(|manager| {
+                manager
+                    .list_models()
+                    .iter()
+                    .any(|m| m.name == model && m.downloaded)
+            })
+            .unwrap_or(false)
+    } else {
+        match app.try_state::<AsyncRwLock<crate::whisper::manager::WhisperManager>>() {
+            Some(manager) => manager
+                .read()
+                .await
+                .get_downloaded_model_names()
+                .contains(&model),
+            None => false,
+        }
+    };
+    if downloaded {
+        HealthCheck::pass(format!("Model '{}' is downloaded", model))
+    } else {
+        HealthCheck::fail(format!("Model '{}' is not on disk; re-download it", model))
+    }
+}
+
+/// The AI key check only applies while AI enhancement is on. The key is
+/// checked for presence, not against the provider, so the check stays offline.
+async fn ai_api_key_check(app: &AppHandle) -> HealthCheck {
+    match crate::commands::ai::get_ai_settings(app.clone()).await {
+        Ok(settings) if !settings.enabled => HealthCheck::pass("AI enhancement is off"),
+        Ok(settings) if !settings.has_api_key => {
+            HealthCheck::fail(format!("No API key stored for {}", settings.provider))
+        }
+        Ok(settings) if settings.model.is_empty() => HealthCheck::fail("No AI model selected"),
+        Ok(settings) => HealthCheck::pass(format!(
+            "{} key stored, using {}",
+            settings.provider, settings.model
+        )),
+        Err(e) => HealthCheck::fail(format!("Could not read AI settings: {}", e)),
+    }
+}
+
+pub fn license_check(status: &LicenseStatus) -> HealthCheck {
+    if status.offline_grace_days_left == Some(0) {
+        return HealthCheck::fail("License could not be re-validated and offline grace has ended");
+    }
+    match status.status {
+        LicenseState::Licensed => HealthCheck::pass("Licensed"),
+        LicenseState::Trial => match status.trial_days_left {
+            Some(days) => HealthCheck::pass(format!("Trial, {} day(s) left", days)),
+            None => HealthCheck::pass("Trial"),
+        },
+        LicenseState::Expired => HealthCheck::fail("Trial or license expired"),
+        LicenseState::None => HealthCheck::fail("No license"),
+    }
+}
+
+pub fn disk_space_check(available: Result<u64, String>) -> HealthCheck {
+    match available {
+        Ok(bytes) => {
+            let gb = bytes as f64 / 1_073_741_824.0;
+            if bytes >= MIN_FREE_DISK_BYTES {
+                HealthCheck::pass(format!("{:.1} GB free", gb))
+            } else {
+                HealthCheck::fail(format!("Only {:.2} GB free; recordings may fail", gb))
+            }
+        }
+        Err(e) => HealthCheck::fail(format!("Could not check free space: {}", e)),
+    }
+}
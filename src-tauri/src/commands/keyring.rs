@@ -1,5 +1,7 @@
+use crate::remote::lifecycle::RemoteServerManager;
+use crate::remote::settings::RemoteSettings;
 use crate::secure_store;
-use tauri::AppHandle;
+use tauri::{async_runtime::Mutex as AsyncMutex, AppHandle, State};
 
 /// Validate key names to prevent edge cases and security issues
 fn validate_key(key: &str) -> Result<(), String> {
@@ -75,3 +77,50 @@ pub fn keyring_has(app: AppHandle, key: String) -> Result<bool, String> {
     // Check secure store
     secure_store::secure_has(&app, &key)
 }
+
+/// Secure-store entries that `clear_all_keys` leaves alone. The license has its
+/// own activation flow and is removed by a full app reset instead.
+const PRESERVED_KEYS: &[&str] = &["license"];
+
+/// Identifiers of every credential held in the secure store. Only presence is
+/// reported; values never leave the backend.
+#[tauri::command]
+pub fn list_stored_keys(app: AppHandle) -> Result<Vec<String>, String> {
+    secure_store::secure_list_keys(&app)
+}
+
+/// Delete every stored API key and connection password and drop the in-memory
+/// key caches. A running share is stopped, since it would otherwise keep
+/// accepting the sharing password that was just removed. Returns the
+/// identifiers that were removed.
+#[tauri::command]
+pub async fn clear_all_keys(
+    app: AppHandle,
+    server_manager: State<'_, AsyncMutex<RemoteServerManager>>,
+    remote_settings: State<'_, AsyncMutex<RemoteSettings>>,
+) -> Result<Vec<String>, String> {
+    let removed = secure_store::secure_delete_where(&app, |key| !PRESERVED_KEYS.contains(&key))?;
+    crate::commands::ai::clear_all_api_key_cache()?;
+    crate::commands::audio::clear_soniox_key_check();
+    remote_settings.lock().await.server_config.password = None;
+    log::info!("Cleared {} keys from secure store", removed.len());
+
+    let sharing = server_manager.lock().await.is_running();
+    if sharing {
+        log::info!("Stopping sharing after its password was cleared");
+        crate::commands::remote::stop_sharing(app, server_manager, remote_settings).await?;
+    }
+    Ok(removed)
+}
+
+/// Re-encrypt every stored secret under a freshly salted key with fresh
+/// nonces. The key still derives from this device, so this neither protects a
+/// compromised store nor makes data copied from another machine readable.
+/// Returns the re-encrypted identifiers.
+#[tauri::command]
+pub async fn rotate_encryption_key(app: AppHandle) -> Result<Vec<String>, String> {
+    // Key derivation and the store save block, so keep them off the async runtime
+    tokio::task::spawn_blocking(move || secure_store::rotate_encryption_key(&app))
+        .await
+        .map_err(|e| format!("Key rotation task failed: {}", e))?
+}
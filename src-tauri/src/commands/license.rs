@@ -49,11 +49,12 @@ struct CachedLicenseStatus {
 }
 
 // Constants for cache and grace periods
-const OFFLINE_GRACE_PERIOD_DAYS: i64 = 90; // 90 days offline grace for licensed users - generous for legitimate use
+const OFFLINE_GRACE_PERIOD_DAYS: i64 = 7; // 7 days offline grace for a previously-validated license, counted from the last successful validation
 const TRIAL_OFFLINE_GRACE_PERIOD_DAYS: i64 = 1; // 1 day offline grace for trial users - prevents abuse while allowing temporary outages
 const CACHE_TTL_HOURS: u64 = 8; // 8-hour cache TTL for both licensed and trial users
 const LICENSE_CACHE_KEY: &str = "license_status";
-const LAST_VALIDATION_KEY: &str = "last_license_validation";
+const LAST_VALIDATION_KEY: &str = "last_successful_validation";
+const LEGACY_LAST_VALIDATION_KEY: &str = "last_license_validation"; // Read-only fallback for timestamps stored before the rename
 const LAST_TRIAL_VALIDATION_KEY: &str = "last_trial_validation"; // Tracks when trial was last validated online
 const TRIAL_EXPIRES_KEY: &str = "trial_expires_at"; // Cache key for trial expiry date
 
@@ -80,50 +81,49 @@ fn hours_to_days(hours: i64) -> i32 {
     (hours as f64 / 24.0).ceil() as i32
 }
 
-// Check if we're within the grace period for offline access
-fn is_within_grace_period(app: &AppHandle) -> Option<i64> {
-    // Use store-backed cache helper
-    // let cache = app.cache();
-
-    if let Ok(Some(timestamp_json)) = scache::get(app, LAST_VALIDATION_KEY) {
-        if let Ok(last_validation) = serde_json::from_value::<DateTime<Utc>>(timestamp_json) {
-            let elapsed = Utc::now().signed_duration_since(last_validation);
-            let days_elapsed = elapsed.num_days();
-
-            if days_elapsed < OFFLINE_GRACE_PERIOD_DAYS {
-                return Some(OFFLINE_GRACE_PERIOD_DAYS - days_elapsed);
-            }
-        }
+// Days of offline access left when the last successful online validation was
+// at `last_validation`, rounded up; None once `grace_days` have passed
+fn offline_grace_days_left(
+    last_validation: DateTime<Utc>,
+    now: DateTime<Utc>,
+    grace_days: i64,
+) -> Option<i64> {
+    let remaining = Duration::days(grace_days) - now.signed_duration_since(last_validation);
+    if remaining <= Duration::zero() {
+        return None;
     }
-
-    None
+    Some((remaining.num_seconds() as f64 / 86_400.0).ceil() as i64)
 }
 
-// Check if grace period timestamp exists (regardless of whether it's valid)
-fn has_grace_period_timestamp(app: &AppHandle) -> bool {
-    // let cache = app.cache();
-    scache::get(app, LAST_VALIDATION_KEY)
+fn stored_timestamp(app: &AppHandle, key: &str) -> Option<DateTime<Utc>> {
+    scache::get(app, key)
         .ok()
         .flatten()
-        .is_some()
+        .and_then(|timestamp_json| serde_json::from_value(timestamp_json).ok())
 }
 
-// Check if we're within the trial grace period
-fn is_within_trial_grace_period(app: &AppHandle) -> Option<i64> {
-    // let cache = app.cache();
-
-    if let Ok(Some(timestamp_json)) = scache::get(app, LAST_TRIAL_VALIDATION_KEY) {
-        if let Ok(last_validation) = serde_json::from_value::<DateTime<Utc>>(timestamp_json) {
-            let elapsed = Utc::now().signed_duration_since(last_validation);
-            let days_elapsed = elapsed.num_days();
+// When the stored license last validated online
+fn last_successful_validation(app: &AppHandle) -> Option<DateTime<Utc>> {
+    stored_timestamp(app, LAST_VALIDATION_KEY)
+        .or_else(|| stored_timestamp(app, LEGACY_LAST_VALIDATION_KEY))
+}
 
-            if days_elapsed < TRIAL_OFFLINE_GRACE_PERIOD_DAYS {
-                return Some(TRIAL_OFFLINE_GRACE_PERIOD_DAYS - days_elapsed);
-            }
-        }
-    }
+// Check if we're within the grace period for offline access
+fn is_within_grace_period(app: &AppHandle) -> Option<i64> {
+    offline_grace_days_left(
+        last_successful_validation(app)?,
+        Utc::now(),
+        OFFLINE_GRACE_PERIOD_DAYS,
+    )
+}
 
-    None
+// Check if we're within the trial grace period
+fn is_within_trial_grace_period(app: &AppHandle) -> Option<i64> {
+    offline_grace_days_left(
+        stored_timestamp(app, LAST_TRIAL_VALIDATION_KEY)?,
+        Utc::now(),
+        TRIAL_OFFLINE_GRACE_PERIOD_DAYS,
+    )
 }
 
 // Conservative license deletion check - only delete when absolutely certain
@@ -267,6 +267,7 @@ async fn check_license_status_impl(app: AppHandle) -> Result<LicenseStatus, Stri
                         license_type: Some("pro".to_string()), // You might want to get this from the API
                         license_key: Some(license_key),
                         expires_at: None,
+                        offline_grace_days_left: None,
                     };
 
                     // Store last successful validation timestamp
@@ -338,7 +339,8 @@ async fn check_license_status_impl(app: AppHandle) -> Result<LicenseStatus, Stri
                         trial_days_left: None,
                         license_type: Some("pro".to_string()),
                         license_key: Some(license_key),
-                        expires_at: Some(format!("{} days offline remaining", days_remaining)),
+                        expires_at: None,
+                        offline_grace_days_left: Some(days_remaining as i32),
                     };
 
                     // Cache with 8-hour TTL during grace period
@@ -360,25 +362,32 @@ async fn check_license_status_impl(app: AppHandle) -> Result<LicenseStatus, Stri
                     );
 
                     return Ok(status);
+                } else if last_successful_validation(&app).is_some() {
+                    // Grace period used up: block recording until the license
+                    // validates online again. DO NOT DELETE THE LICENSE! User
+                    // paid for it and might just be offline for a long time.
+                    log::error!(
+                        "Offline grace period of {} days has expired. License requires online validation.",
+                        OFFLINE_GRACE_PERIOD_DAYS
+                    );
+                    return Ok(LicenseStatus {
+                        status: LicenseState::Expired,
+                        trial_days_left: None,
+                        license_type: Some("pro".to_string()),
+                        license_key: Some(license_key),
+                        expires_at: None,
+                        offline_grace_days_left: Some(0),
+                    });
                 } else {
-                    // Check if we have a timestamp at all before assuming grace period expired
-                    if has_grace_period_timestamp(&app) {
-                        // Timestamp exists and grace period expired
-                        log::error!("Grace period of {} days has expired. License requires online validation.",
-                                  OFFLINE_GRACE_PERIOD_DAYS);
-                        // DO NOT DELETE THE LICENSE! User paid for it and might just be offline temporarily
-                        // Clear the timestamp so next successful validation starts fresh grace period
-                        let _ = scache::remove(&app, LAST_VALIDATION_KEY);
-                        return Err("Grace period expired. Please connect to internet to revalidate your license.".to_string());
-                    } else {
-                        // No timestamp exists - this is the first offline attempt
-                        log::warn!("No previous online validation found. Initial online validation required.");
-                        // DON'T delete the license - it may still be valid
-                        return Err(
-                            "Initial online validation required. Please connect to internet."
-                                .to_string(),
-                        );
-                    }
+                    // No timestamp exists - this is the first offline attempt
+                    log::warn!(
+                        "No previous online validation found. Initial online validation required."
+                    );
+                    // DON'T delete the license - it may still be valid
+                    return Err(
+                        "Initial online validation required. Please connect to internet."
+                            .to_string(),
+                    );
                 }
             }
         }
@@ -398,6 +407,7 @@ async fn check_license_status_impl(app: AppHandle) -> Result<LicenseStatus, Stri
                     license_type: None,
                     license_key: None,
                     expires_at: None,
+                    offline_grace_days_left: None,
                 };
 
                 // Don't cache expired status - always check
@@ -414,6 +424,7 @@ async fn check_license_status_impl(app: AppHandle) -> Result<LicenseStatus, Stri
                     license_type: None,
                     license_key: None,
                     expires_at: None,
+                    offline_grace_days_left: None,
                 };
 
                 // Set last successful trial validation timestamp for grace period tracking
@@ -511,6 +522,7 @@ async fn check_license_status_impl(app: AppHandle) -> Result<LicenseStatus, Stri
                                 license_type: None,
                                 license_key: None,
                                 expires_at: None,
+                                offline_grace_days_left: None,
                             });
                         }
 
@@ -538,10 +550,8 @@ async fn check_license_status_impl(app: AppHandle) -> Result<LicenseStatus, Stri
                                 trial_days_left: Some(days_left.max(0)),
                                 license_type: None,
                                 license_key: None,
-                                expires_at: Some(format!(
-                                    "Offline grace: {} day remaining",
-                                    grace_days_remaining
-                                )),
+                                expires_at: None,
+                                offline_grace_days_left: Some(grace_days_remaining as i32),
                             };
 
                             return Ok(status);
@@ -605,6 +615,7 @@ async fn check_license_status_impl(app: AppHandle) -> Result<LicenseStatus, Stri
                 license_type: None,
                 license_key: None,
                 expires_at: None,
+                offline_grace_days_left: None,
             };
 
             // Don't cache None status - always check
@@ -669,6 +680,7 @@ pub async fn restore_license(app: AppHandle) -> Result<LicenseStatus, String> {
                     license_type: Some("pro".to_string()),
                     license_key: Some(license_key),
                     expires_at: None,
+                    offline_grace_days_left: None,
                 };
                 seed_runtime_license_cache(&app, &status).await;
                 Ok(status)
@@ -793,6 +805,7 @@ async fn activate_license_internal(
                     license_type: Some("pro".to_string()),
                     license_key: Some(license_key),
                     expires_at: None,
+                    offline_grace_days_left: None,
                 };
                 seed_runtime_license_cache(&app, &status).await;
                 Ok(status)
@@ -840,6 +853,7 @@ pub async fn deactivate_license(app: AppHandle) -> Result<(), String> {
                 }
                 // Clear validation timestamp when deactivating - this is intentional removal
                 let _ = scache::remove(&app, LAST_VALIDATION_KEY);
+                let _ = scache::remove(&app, LEGACY_LAST_VALIDATION_KEY);
 
                 // Clear our performance cache too
                 let _ = invalidate_license_cache(app.clone()).await;
@@ -947,6 +961,7 @@ pub async fn invalidate_license_cache(app: AppHandle) -> Result<(), String> {
         Err(e) => log::warn!("Failed to clear old license cache: {}", e),
     }
     let _ = scache::remove(&app, LAST_VALIDATION_KEY);
+    let _ = scache::remove(&app, LEGACY_LAST_VALIDATION_KEY);
 
     // Clear the new performance cache
     let app_state = app.state::<AppState>();
@@ -955,3 +970,43 @@ pub async fn invalidate_license_cache(app: AppHandle) -> Result<(), String> {
     log::debug!("License cache invalidated due to license state change");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offline_grace_counts_down_from_last_successful_validation() {
+        let validated = Utc::now();
+        assert_eq!(offline_grace_days_left(validated, validated, 7), Some(7));
+        assert_eq!(
+            offline_grace_days_left(validated, validated + Duration::hours(30), 7),
+            Some(6)
+        );
+        assert_eq!(
+            offline_grace_days_left(validated, validated + Duration::days(6), 7),
+            Some(1)
+        );
+        assert_eq!(
+            offline_grace_days_left(
+                validated,
+                validated + Duration::days(7) - Duration::minutes(1),
+                7
+            ),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn offline_grace_is_exhausted_after_the_period() {
+        let validated = Utc::now();
+        assert_eq!(
+            offline_grace_days_left(validated, validated + Duration::days(7), 7),
+            None
+        );
+        assert_eq!(
+            offline_grace_days_left(validated, validated + Duration::days(30), 7),
+            None
+        );
+    }
+}
@@ -2,8 +2,11 @@ use chrono::{Local, NaiveDate};
 use serde::Serialize;
 use std::fs;
 use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::OnceLock;
-use tauri::Manager;
+use std::time::Duration;
+use tauri::{Emitter, Manager};
 
 #[tauri::command]
 pub async fn clear_old_logs(app: tauri::AppHandle, days_to_keep: u32) -> Result<u32, String> {
@@ -330,3 +333,278 @@ pub async fn get_latest_log_for_bug_report(
         status_note: String::new(),
     })
 }
+
+/// Tauri command: zip logs, redacted settings, downloaded models, OS details
+/// and the last crash report into `path` for support. Returns the written
+/// path so the UI can reveal it.
+#[tauri::command]
+pub async fn export_diagnostics(app: tauri::AppHandle, path: String) -> Result<String, String> {
+    if path.trim().is_empty() {
+        return Err("No destination chosen for the diagnostics bundle".to_string());
+    }
+    let entries = crate::utils::diagnostics::collect_diagnostics_bundle(&app).await;
+    let destination = PathBuf::from(path);
+    let written = tauri::async_runtime::spawn_blocking(move || {
+        crate::utils::diagnostics::write_diagnostics_zip(&destination, &entries)
+    })
+    .await
+    .map_err(|e| format!("Diagnostics export task failed: {}", e))??;
+
+    log::info!(
+        "Exported diagnostics bundle to {:?}",
+        written.file_name().unwrap_or_default()
+    );
+    Ok(written.to_string_lossy().to_string())
+}
+
+/// Returns true for log targets that are pure noise in user-facing logs.
+/// Shared by the `tauri_plugin_log` target filter and the live log stream:
+/// - Audio crates (always): whisper_rs, cpal, rubato, hound, audio::level_meter
+/// - HTTP/transport crates below Warn: h2, hyper, hyper_util, reqwest, tower
+pub(crate) fn is_noisy_log_target(target: &str, level: log::Level) -> bool {
+    // Always drop low-level audio processing noise
+    if target.contains("whisper_rs")
+        || target.contains("audio::level_meter")
+        || target.contains("cpal")
+        || target.contains("rubato")
+        || target.contains("hound")
+    {
+        return true;
+    }
+    // Drop HTTP/transport framing noise at Info/Debug/Trace; keep Warn and Error
+    let is_http_transport = target == "h2"
+        || target.starts_with("h2::")
+        || target.starts_with("hyper")
+        || target.starts_with("hyper_util")
+        || target.starts_with("reqwest")
+        || target.starts_with("tower");
+    is_http_transport && level > log::Level::Warn
+}
+
+/// Default number of lines returned by `get_recent_logs`.
+const DEFAULT_RECENT_LOG_LINES: usize = 200;
+/// Upper bound on lines returned by `get_recent_logs`.
+const MAX_RECENT_LOG_LINES: usize = 2_000;
+/// Bytes read from the tail of the latest log when serving recent lines.
+const RECENT_LOGS_TAIL_BYTES: u64 = 524_288; // 512KB
+/// How often the live log stream checks the log file for new content.
+const LOG_STREAM_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Bumped on every subscribe/unsubscribe so only the newest stream keeps running.
+static LOG_STREAM_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// A single parsed log line, emitted as `log-line` and returned by `get_recent_logs`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogLine {
+    /// "YYYY-MM-DD HH:MM:SS" when the line carries a log header.
+    pub timestamp: Option<String>,
+    /// Upper-case level ("ERROR", "WARN", "INFO", "DEBUG", "TRACE").
+    /// None for continuation lines of multi-line messages.
+    pub level: Option<String>,
+    /// Module path that produced the line (e.g. "voicetypr_lib::commands::audio").
+    pub target: Option<String>,
+    pub message: String,
+}
+
+/// Parse a `tauri_plugin_log` line: `[2026-04-27][10:11:12][target][LEVEL] message`.
+/// Lines without a header (e.g. wrapped panic output) keep their text as the message.
+pub fn parse_log_line(line: &str) -> LogLine {
+    static LOG_LINE_RE: OnceLock<regex::Regex> = OnceLock::new();
+    let re = LOG_LINE_RE.get_or_init(|| {
+        regex::Regex::new(
+            r"^\[(\d{4}-\d{2}-\d{2})\]\[(\d{2}:\d{2}:\d{2})\]\[([^\]]*)\]\[(TRACE|DEBUG|INFO|WARN|ERROR)\] ?(.*)$",
+        )
+        .unwrap()
+    });
+
+    match re.captures(line) {
+        Some(caps) => LogLine {
+            timestamp: Some(format!("{} {}", &caps[1], &caps[2])),
+            level: Some(caps[4].to_string()),
+            target: Some(caps[3].to_string()),
+            message: caps[5].to_string(),
+        },
+        None => LogLine {
+            timestamp: None,
+            level: None,
+            target: None,
+            message: line.to_string(),
+        },
+    }
+}
+
+/// True when a parsed line comes from one of the noisy targets filtered at write time.
+fn is_noisy_log_line(line: &LogLine) -> bool {
+    let (Some(target), Some(level)) = (line.target.as_deref(), line.level.as_deref()) else {
+        return false;
+    };
+    level
+        .parse::<log::Level>()
+        .map(|level| is_noisy_log_target(target, level))
+        .unwrap_or(false)
+}
+
+/// Parse raw log text into lines, dropping blanks and noisy targets, keeping the last `limit`.
+pub fn parse_recent_log_lines(content: &str, limit: usize) -> Vec<LogLine> {
+    let lines: Vec<LogLine> = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_log_line)
+        .filter(|line| !is_noisy_log_line(line))
+        .collect();
+    let skip = lines.len().saturating_sub(limit);
+    lines.into_iter().skip(skip).collect()
+}
+
+/// Incremental reader over the newest `voicetypr-*.log` in a directory.
+/// Follows rotation (a newer file appears) and truncation (the file shrinks),
+/// and buffers partial lines until their newline has been written.
+#[derive(Debug, Default)]
+pub struct LogTail {
+    path: Option<PathBuf>,
+    offset: u64,
+    pending: String,
+}
+
+impl LogTail {
+    /// Start tailing at the current end of the newest log so only new lines are reported.
+    pub fn at_end(log_dir: &Path) -> Self {
+        let path = find_newest_log(log_dir);
+        let offset = path
+            .as_ref()
+            .and_then(|p| fs::metadata(p).ok())
+            .map(|meta| meta.len())
+            .unwrap_or(0);
+        Self {
+            path,
+            offset,
+            pending: String::new(),
+        }
+    }
+
+    /// Read any complete lines appended since the previous poll.
+    pub fn poll(&mut self, log_dir: &Path) -> std::io::Result<Vec<String>> {
+        use std::io::{Seek, SeekFrom};
+
+        let Some(newest) = find_newest_log(log_dir) else {
+            return Ok(Vec::new());
+        };
+        if self.path.as_ref() != Some(&newest) {
+            // Rotated to a new file: read it from the beginning.
+            self.path = Some(newest.clone());
+            self.offset = 0;
+            self.pending.clear();
+        }
+
+        let mut file = fs::File::open(&newest)?;
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            // Truncated in place.
+            self.offset = 0;
+            self.pending.clear();
+        }
+        if len == self.offset {
+            return Ok(Vec::new());
+        }
+
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut bytes = Vec::new();
+        file.by_ref()
+            .take(len - self.offset)
+            .read_to_end(&mut bytes)?;
+        self.offset += bytes.len() as u64;
+        self.pending.push_str(&String::from_utf8_lossy(&bytes));
+
+        let Some(last_newline) = self.pending.rfind('\n') else {
+            return Ok(Vec::new());
+        };
+        let complete: String = self.pending.drain(..=last_newline).collect();
+        Ok(complete
+            .lines()
+            .map(str::to_string)
+            .filter(|line| !line.trim().is_empty())
+            .collect())
+    }
+}
+
+/// Tauri command: returns the last `lines` entries of the latest log (default 200, max 2000).
+/// Noisy third-party targets are dropped the same way `setup_logging` filters them.
+#[tauri::command]
+pub async fn get_recent_logs(
+    app: tauri::AppHandle,
+    lines: Option<usize>,
+) -> Result<Vec<LogLine>, String> {
+    let log_dir = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| format!("Failed to get log directory: {}", e))?;
+    let limit = lines
+        .unwrap_or(DEFAULT_RECENT_LOG_LINES)
+        .clamp(1, MAX_RECENT_LOG_LINES);
+
+    let Some(log_path) = find_newest_log(&log_dir) else {
+        return Ok(Vec::new());
+    };
+
+    let (raw, _original_byte_count, _truncated) = read_log_tail(&log_path, RECENT_LOGS_TAIL_BYTES)
+        .map_err(|e| format!("Failed to read log file: {}", e))?;
+
+    Ok(parse_recent_log_lines(&raw, limit))
+}
+
+/// Tauri command: start streaming new log lines as `log-line` events.
+/// Subscribing again replaces the previous stream; `unsubscribe_logs` stops it.
+#[tauri::command]
+pub async fn subscribe_logs(app: tauri::AppHandle) -> Result<(), String> {
+    let log_dir = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| format!("Failed to get log directory: {}", e))?;
+
+    let generation = LOG_STREAM_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    tauri::async_runtime::spawn(async move {
+        let mut tail = LogTail::at_end(&log_dir);
+        let mut read_failing = false;
+        log::debug!("Log stream {} started", generation);
+
+        loop {
+            tokio::time::sleep(LOG_STREAM_POLL_INTERVAL).await;
+            if LOG_STREAM_GENERATION.load(Ordering::SeqCst) != generation {
+                break;
+            }
+
+            let lines = match tail.poll(&log_dir) {
+                Ok(lines) => {
+                    read_failing = false;
+                    lines
+                }
+                Err(e) => {
+                    // Once per failure streak, so the warning can't feed back
+                    // into the stream on every poll.
+                    if !read_failing {
+                        log::warn!("Log stream failed to read log file: {}", e);
+                        read_failing = true;
+                    }
+                    continue;
+                }
+            };
+
+            for line in lines.iter().map(|line| parse_log_line(line)) {
+                if is_noisy_log_line(&line) {
+                    continue;
+                }
+                let _ = app.emit("log-line", &line);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Tauri command: stop the live log stream started by `subscribe_logs`.
+#[tauri::command]
+pub async fn unsubscribe_logs() -> Result<(), String> {
+    LOG_STREAM_GENERATION.fetch_add(1, Ordering::SeqCst);
+    Ok(())
+}
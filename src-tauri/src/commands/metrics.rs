@@ -0,0 +1,8 @@
+use crate::utils::metrics::{self, ModelMetrics};
+use tauri::AppHandle;
+
+/// Latency summary per engine/model over the recent transcription window.
+#[tauri::command]
+pub async fn get_transcription_metrics(app: AppHandle) -> Result<Vec<ModelMetrics>, String> {
+    Ok(metrics::aggregate(&metrics::load_samples(&app)?))
+}
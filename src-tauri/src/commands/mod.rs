@@ -5,19 +5,24 @@ pub mod clipboard;
 pub mod debug;
 pub mod device;
 pub mod distribution;
+pub mod health;
 pub mod key_normalizer;
 pub mod keyring;
 pub mod license;
 pub mod logs;
+pub mod metrics;
 pub mod model;
 pub mod permissions;
 pub mod remote;
 pub mod reset;
+pub mod scratchpad;
 pub mod settings;
 pub mod shortcuts;
 pub mod stt;
 pub mod system_info;
 pub mod telemetry;
+pub mod templates;
 pub mod text;
+pub mod updates;
 pub mod utils;
 pub mod window;
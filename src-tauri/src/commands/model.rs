@@ -6,15 +6,67 @@ use crate::secure_store;
 use crate::utils::onboarding_logger;
 #[cfg(debug_assertions)]
 use crate::utils::system_monitor;
-use crate::whisper::manager::{ModelInfo, WhisperManager};
+use crate::whisper::manager::{verify_ggml_whisper_header, ModelInfo, WhisperManager};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex as StdMutex};
-#[cfg(debug_assertions)]
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tauri::async_runtime::{Mutex as AsyncMutex, RwLock};
 use tauri::{AppHandle, Emitter, Manager, State};
 
+/// Minimum spacing between download rate samples (and progress events).
+const DOWNLOAD_RATE_INTERVAL: Duration = Duration::from_millis(250);
+/// Weight of the newest sample in the moving-average rate.
+const DOWNLOAD_RATE_SMOOTHING: f64 = 0.3;
+
+/// Moving-average transfer rate for download progress events. The first
+/// sample only sets a baseline, so bytes already on disk from a resumed
+/// download never count toward the rate.
+#[derive(Debug, Default)]
+pub(crate) struct DownloadRate {
+    last: Option<(Instant, u64)>,
+    bytes_per_sec: Option<f64>,
+}
+
+impl DownloadRate {
+    /// Record `downloaded` at `now`. Returns false when the previous sample
+    /// is under [`DOWNLOAD_RATE_INTERVAL`] old and nothing was recorded.
+    pub(crate) fn update(&mut self, now: Instant, downloaded: u64) -> bool {
+        match self.last {
+            Some((at, bytes)) if downloaded >= bytes => {
+                let elapsed = now.saturating_duration_since(at);
+                if elapsed < DOWNLOAD_RATE_INTERVAL {
+                    return false;
+                }
+                let sample = (downloaded - bytes) as f64 / elapsed.as_secs_f64();
+                self.bytes_per_sec = Some(match self.bytes_per_sec {
+                    Some(rate) => rate + DOWNLOAD_RATE_SMOOTHING * (sample - rate),
+                    None => sample,
+                });
+                self.last = Some((now, downloaded));
+            }
+            // First sample, or the count went backwards (the download restarted)
+            _ => {
+                *self = Self {
+                    last: Some((now, downloaded)),
+                    bytes_per_sec: None,
+                }
+            }
+        }
+        true
+    }
+
+    pub(crate) fn bytes_per_sec(&self) -> Option<u64> {
+        self.bytes_per_sec.map(|rate| rate.round() as u64)
+    }
+
+    /// Seconds until `total` at the current rate; None until a rate is known.
+    pub(crate) fn eta_seconds(&self, downloaded: u64, total: u64) -> Option<u64> {
+        let rate = self.bytes_per_sec.filter(|rate| *rate > 0.0)?;
+        Some((total.saturating_sub(downloaded) as f64 / rate).ceil() as u64)
+    }
+}
+
 type ActiveDownloadsState<'a> = State<'a, Arc<StdMutex<HashMap<String, Arc<AtomicBool>>>>>;
 
 pub(crate) fn register_active_download(
@@ -67,6 +119,36 @@ impl ModelEngine {
     }
 }
 
+/// Headroom kept free beyond the model size so a download never fills the disk.
+const DOWNLOAD_SPACE_BUFFER_BYTES: u64 = 500 * 1024 * 1024;
+
+/// Fail fast when `available` bytes cannot hold the rest of a `size_bytes`
+/// model plus [`DOWNLOAD_SPACE_BUFFER_BYTES`], rather than dying halfway
+/// through a write. `already_downloaded` bytes of a resumable partial file are
+/// on disk already and are not counted again.
+pub(crate) fn ensure_download_space(
+    model_name: &str,
+    size_bytes: u64,
+    already_downloaded: u64,
+    available: u64,
+) -> Result<(), String> {
+    let required = size_bytes
+        .saturating_sub(already_downloaded)
+        .saturating_add(DOWNLOAD_SPACE_BUFFER_BYTES);
+    if available >= required {
+        return Ok(());
+    }
+    const GB: f64 = 1024.0 * 1024.0 * 1024.0;
+    Err(format!(
+        "Not enough disk space to download {}. Need {:.1} GB, only {:.1} GB free (required {} bytes, available {} bytes).",
+        model_name,
+        required as f64 / GB,
+        available as f64 / GB,
+        required,
+        available
+    ))
+}
+
 #[derive(Clone, Copy, Debug)]
 struct DownloadTarget {
     engine: ModelEngine,
@@ -98,6 +180,36 @@ pub async fn download_model(
             }
         };
 
+    let download_dir = match download_target.engine {
+        ModelEngine::Whisper => whisper_state.read().await.models_dir(),
+        ModelEngine::Parakeet => parakeet_manager.model_dir(&model_name),
+    };
+    // Whisper downloads resume from `<name>.bin.part`; Parakeet starts over
+    let already_downloaded = match download_target.engine {
+        ModelEngine::Whisper => {
+            let output_path = download_dir.join(format!("{}.bin", model_name));
+            std::fs::metadata(WhisperManager::partial_download_path(&output_path))
+                .map(|metadata| metadata.len().min(download_target.size_bytes))
+                .unwrap_or(0)
+        }
+        ModelEngine::Parakeet => 0,
+    };
+    match crate::utils::diagnostics::available_disk_space(&download_dir) {
+        Ok(available) => {
+            if let Err(error) = ensure_download_space(
+                &model_name,
+                download_target.size_bytes,
+                already_downloaded,
+                available,
+            ) {
+                log::warn!("{}", error);
+                clear_active_download(&active_downloads, &model_name);
+                return Err(error);
+            }
+        }
+        Err(e) => log::warn!("Could not check free disk space before download: {}", e),
+    }
+
     log::info!("Starting download for model: {}", model_name);
 
     // Monitor system resources at download start
@@ -117,13 +229,28 @@ pub async fn download_model(
 
     // Create an async-safe wrapper for progress callback
     let (progress_tx, mut progress_rx) =
-        tokio::sync::mpsc::unbounded_channel::<(u64, u64, Option<String>)>();
+        tokio::sync::mpsc::unbounded_channel::<(u64, u64, u64, Option<String>)>();
 
     // Spawn task to handle progress updates
     let progress_handle = tokio::spawn(async move {
         let mut verification_emitted = false;
+        let mut rate = DownloadRate::default();
+        let mut last_phase: Option<String> = None;
+
+        while let Some((downloaded, total, resumed_from, phase)) = progress_rx.recv().await {
+            // Parakeet phases count their own bytes; restart the rate on each
+            let phase_changed = phase != last_phase;
+            if phase_changed {
+                rate = DownloadRate::default();
+                last_phase = phase.clone();
+            }
+            let sampled = rate.update(Instant::now(), downloaded);
+            // Keep events to a few per second, but never drop a phase change
+            // or the final update
+            if !sampled && !phase_changed && downloaded < total {
+                continue;
+            }
 
-        while let Some((downloaded, total, phase)) = progress_rx.recv().await {
             let progress = (downloaded as f64 / total as f64) * 100.0;
             log::debug!(
                 "Download progress for {}: {:.1}%",
@@ -149,6 +276,9 @@ pub async fn download_model(
                     "progress": progress,
                     "requestId": request_id_for_progress.as_deref(),
                     "phase": phase.as_deref(),
+                    "resumedFrom": resumed_from,
+                    "bytesPerSec": rate.bytes_per_sec(),
+                    "etaSeconds": rate.eta_seconds(downloaded, total),
                 }),
             ) {
                 log::warn!("Failed to emit download progress: {}", e);
@@ -197,8 +327,8 @@ pub async fn download_model(
                     &output_path,
                     &models_dir,
                     Some(cancel_flag.clone()),
-                    move |downloaded, total| {
-                        let _ = progress_tx_clone.send((downloaded, total, None));
+                    move |downloaded, total, resumed_from| {
+                        let _ = progress_tx_clone.send((downloaded, total, resumed_from, None));
                     },
                 )
                 .await
@@ -210,7 +340,7 @@ pub async fn download_model(
                         &model_name,
                         Some(cancel_flag.clone()),
                         move |downloaded, total, phase| {
-                            let _ = progress_tx_clone.send((downloaded, total, phase));
+                            let _ = progress_tx_clone.send((downloaded, total, 0, phase));
                         },
                     )
                     .await
@@ -677,6 +807,63 @@ pub async fn list_downloaded_models(
     Ok(manager.list_downloaded_files())
 }
 
+/// Import a ggml Whisper model from disk (e.g. a fine-tune) so it shows up in
+/// the model picker and tray and loads like a downloaded model.
+#[tauri::command]
+pub async fn import_whisper_model(
+    app: AppHandle,
+    path: String,
+    display_name: String,
+    whisper_state: State<'_, RwLock<WhisperManager>>,
+) -> Result<UnifiedModelInfo, String> {
+    let source = std::path::PathBuf::from(&path);
+    if !source.is_file() {
+        return Err(format!("Model file not found: {}", path));
+    }
+    // Reject the wrong file before spending time copying gigabytes
+    verify_ggml_whisper_header(&source)?;
+
+    let models_dir = whisper_state.read().await.models_dir();
+    tokio::fs::create_dir_all(&models_dir)
+        .await
+        .map_err(|e| format!("Failed to create models directory: {}", e))?;
+    let staged = models_dir.join(format!(".import-{}.part", uuid::Uuid::new_v4()));
+    if let Err(e) = tokio::fs::copy(&source, &staged).await {
+        let _ = tokio::fs::remove_file(&staged).await;
+        return Err(format!("Failed to copy model file: {}", e));
+    }
+
+    let info = {
+        let mut manager = whisper_state.write().await;
+        manager.register_custom_model(&staged, &display_name)
+    };
+    let info = match info {
+        Ok(info) => info,
+        Err(e) => {
+            let _ = tokio::fs::remove_file(&staged).await;
+            return Err(e);
+        }
+    };
+
+    if let Err(e) = emit_to_all(
+        &app,
+        "model-downloaded",
+        serde_json::json!({
+            "model": info.name,
+            "engine": ModelEngine::Whisper.as_str(),
+        }),
+    ) {
+        log::warn!("Failed to emit model-downloaded event: {}", e);
+    }
+
+    // Refresh tray menu so the imported model appears in the tray immediately
+    if let Err(e) = crate::commands::settings::update_tray_menu(app.clone()).await {
+        log::warn!("Failed to update tray menu after model import: {}", e);
+    }
+
+    Ok(convert_whisper_model(info.name.clone(), info))
+}
+
 async fn identify_download_target(
     model_name: &str,
     whisper_state: &State<'_, RwLock<WhisperManager>>,
@@ -963,3 +1150,185 @@ pub async fn preload_model(
 
     Ok(())
 }
+
+/// How often the keep-warm timer re-touches the cloud engine. Kept under
+/// reqwest's 90 s idle-connection timeout so the pooled connection survives.
+const ENGINE_KEEP_WARM_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct EngineWarmupResult {
+    pub engine: String,
+    pub model: String,
+    pub kind: String,
+    pub duration_ms: u64,
+}
+
+fn selected_engine_and_model(app: &AppHandle) -> Result<(String, String), String> {
+    use tauri_plugin_store::StoreExt;
+
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    let model = store
+        .get("current_model")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_default();
+    let engine = store
+        .get("current_model_engine")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_else(|| "whisper".to_string());
+    Ok((engine, model))
+}
+
+async fn warm_cloud_engine(
+    app: &AppHandle,
+    provider: crate::cloud_stt::CloudProvider,
+) -> Result<(), String> {
+    let key = secure_store::secure_get(app, provider.key_name())?
+        .ok_or_else(|| format!("{} API key not set", provider.display_name()))?;
+    // Open the pooled transcription connection first, then confirm the key so a
+    // revoked key surfaces now rather than on the first dictation.
+    provider.warm_up(app).await;
+    provider
+        .validate_key_at(&provider.base_url(app), &key)
+        .await
+}
+
+/// Warm the selected (or given) transcription engine ahead of the first dictation.
+///
+/// Cloud engines get a hot pooled connection and a key check; local engines
+/// load their model (Whisper via [`preload_model`], Parakeet via its sidecar).
+#[tauri::command]
+pub async fn warmup_engine(
+    app: AppHandle,
+    engine: Option<String>,
+    model: Option<String>,
+) -> Result<EngineWarmupResult, String> {
+    let started = std::time::Instant::now();
+    let (selected_engine, selected_model) = selected_engine_and_model(&app)?;
+    let engine = engine
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or(selected_engine);
+    let model = model
+        .filter(|m| !m.trim().is_empty())
+        .unwrap_or(selected_model);
+
+    let kind = if let Some(provider) = crate::cloud_stt::CloudProvider::from_id(&engine) {
+        warm_cloud_engine(&app, provider).await?;
+        "cloud"
+    } else {
+        if model.is_empty() {
+            return Err("No model selected".to_string());
+        }
+        if engine == "parakeet" {
+            let parakeet_manager = app.state::<ParakeetManager>();
+            parakeet_manager
+                .preload_model(&app, &model)
+                .await
+                .map_err(|e| e.to_string())?;
+        } else {
+            let whisper_state = app.state::<RwLock<WhisperManager>>();
+            preload_model(app.clone(), model.clone(), whisper_state).await?;
+        }
+        "local"
+    };
+
+    let duration_ms = started.elapsed().as_millis() as u64;
+    log::info!(
+        "Warmed {} engine '{}' ({}) in {}ms",
+        kind,
+        engine,
+        model,
+        duration_ms
+    );
+    Ok(EngineWarmupResult {
+        engine,
+        model,
+        kind: kind.to_string(),
+        duration_ms,
+    })
+}
+
+/// Start the Parakeet sidecar and load `model_name` so the first transcription
+/// skips the cold start. Emits `parakeet-ready` once the model is loaded.
+#[tauri::command]
+pub async fn preload_parakeet_model(app: AppHandle, model_name: String) -> Result<(), String> {
+    let parakeet_manager = app.state::<ParakeetManager>();
+    parakeet_manager
+        .preload_model(&app, &model_name)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// How often the idle timer checks whether the Parakeet sidecar can be shut down.
+const PARAKEET_IDLE_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Background timer that shuts the Parakeet sidecar down once it has been idle
+/// for `parakeet_idle_timeout_minutes` (0 keeps it alive). The next
+/// transcription respawns it and reloads the model, as after a crash.
+pub fn spawn_parakeet_idle_shutdown(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        use crate::commands::settings::{
+            DEFAULT_PARAKEET_IDLE_TIMEOUT_MINUTES, MAX_PARAKEET_IDLE_TIMEOUT_MINUTES,
+        };
+        use tauri_plugin_store::StoreExt;
+
+        loop {
+            tokio::time::sleep(PARAKEET_IDLE_CHECK_INTERVAL).await;
+
+            if crate::get_recording_state(&app) != crate::RecordingState::Idle {
+                continue;
+            }
+            let minutes = app
+                .store("settings")
+                .ok()
+                .and_then(|store| store.get("parakeet_idle_timeout_minutes"))
+                .and_then(|v| v.as_u64())
+                .map(|v| v.min(MAX_PARAKEET_IDLE_TIMEOUT_MINUTES as u64))
+                .unwrap_or(DEFAULT_PARAKEET_IDLE_TIMEOUT_MINUTES as u64);
+            let parakeet_manager = app.state::<ParakeetManager>();
+            parakeet_manager
+                .shutdown_if_idle(std::time::Duration::from_secs(minutes * 60))
+                .await;
+        }
+    });
+}
+
+/// Background timer that keeps the selected cloud engine's connection warm
+/// while `keep_engine_warm` is enabled. Local models stay resident once loaded,
+/// so only cloud engines are touched. Skipped mid-recording and while a remote
+/// server handles dispatch.
+pub fn spawn_engine_keep_warm(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        use tauri_plugin_store::StoreExt;
+
+        loop {
+            tokio::time::sleep(ENGINE_KEEP_WARM_INTERVAL).await;
+
+            let enabled = app
+                .store("settings")
+                .ok()
+                .and_then(|store| store.get("keep_engine_warm"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            if !enabled || crate::get_recording_state(&app) != crate::RecordingState::Idle {
+                continue;
+            }
+            let remote_active = {
+                let remote = app.state::<AsyncMutex<crate::remote::settings::RemoteSettings>>();
+                let guard = remote.lock().await;
+                guard.get_active_connection().is_some()
+            };
+            if remote_active {
+                continue;
+            }
+            let Ok((engine, _)) = selected_engine_and_model(&app) else {
+                continue;
+            };
+            let Some(provider) = crate::cloud_stt::CloudProvider::from_id(&engine) else {
+                continue;
+            };
+            if secure_store::secure_has(&app, provider.key_name()).unwrap_or(false) {
+                provider.warm_up(&app).await;
+            }
+        }
+    });
+}
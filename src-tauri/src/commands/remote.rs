@@ -1512,17 +1512,23 @@ pub async fn transcribe_remote(
 
     // Security: constrain audio_path to the app's recordings directory so a
     // compromised renderer cannot read and exfiltrate arbitrary files
-    // (e.g. ~/.ssh/id_rsa) via the remote transcription upload.
-    let recordings_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to resolve app data directory: {}", e))?
-        .join("recordings");
+    // (e.g. ~/.ssh/id_rsa) via the remote transcription upload. A custom
+    // `recordings_dir` only ever adds its dedicated `voicetypr` subfolder,
+    // never the folder the renderer picked.
+    let mut allowed_dirs = vec![crate::commands::audio::default_recordings_dir(&app)
+        .map_err(|e| format!("Failed to resolve recordings directory: {}", e))?];
+    if let Ok(dir) = crate::commands::audio::resolve_recordings_dir(&app) {
+        if dir.ends_with(crate::commands::audio::RECORDINGS_SUBFOLDER) {
+            allowed_dirs.push(dir);
+        }
+    }
     let canonical_audio = std::fs::canonicalize(&audio_path)
         .map_err(|e| format!("Failed to resolve audio file path: {}", e))?;
-    let canonical_dir = std::fs::canonicalize(&recordings_dir)
-        .map_err(|e| format!("Failed to resolve recordings directory: {}", e))?;
-    if !canonical_audio.starts_with(&canonical_dir) {
+    let inside_recordings_dir = allowed_dirs
+        .iter()
+        .filter_map(|dir| std::fs::canonicalize(dir).ok())
+        .any(|dir| canonical_audio.starts_with(dir));
+    if !inside_recordings_dir {
         return Err(format!(
             "Audio path is outside the recordings directory: {}",
             audio_path
@@ -2115,7 +2121,14 @@ mod tests {
         );
 
         for engine in [
-            "soniox", "openai", "groq", "deepgram", "cohere", "Soniox", " soniox ",
+            "soniox",
+            "openai",
+            "groq",
+            "deepgram",
+            "cohere",
+            "assemblyai",
+            "Soniox",
+            " soniox ",
         ] {
             assert_eq!(ensure_sharing_engine_supported(engine), expected.clone());
         }
@@ -0,0 +1,123 @@
+use crate::AppState;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+/// Store holding the scratchpad contents, so they survive restarts.
+const SCRATCHPAD_STORE: &str = "scratchpad";
+const SCRATCHPAD_TEXT_KEY: &str = "text";
+
+/// Add one dictation to the end of the scratchpad, one paragraph per dictation.
+fn append_entry(existing: &str, text: &str) -> String {
+    let text = text.trim();
+    let existing = existing.trim_end();
+    if text.is_empty() {
+        return existing.to_string();
+    }
+    if existing.is_empty() {
+        return text.to_string();
+    }
+    format!("{}\n\n{}", existing, text)
+}
+
+fn read_scratchpad(app: &AppHandle) -> Result<String, String> {
+    let store = app
+        .store(SCRATCHPAD_STORE)
+        .map_err(|e| format!("Failed to access scratchpad: {}", e))?;
+    Ok(store
+        .get(SCRATCHPAD_TEXT_KEY)
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_default())
+}
+
+fn write_scratchpad(app: &AppHandle, text: &str) -> Result<(), String> {
+    let store = app
+        .store(SCRATCHPAD_STORE)
+        .map_err(|e| format!("Failed to access scratchpad: {}", e))?;
+    store.set(SCRATCHPAD_TEXT_KEY, serde_json::json!(text));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save scratchpad: {}", e))?;
+    let _ = crate::emit_to_window(
+        app,
+        "scratchpad",
+        "scratchpad-updated",
+        serde_json::json!({ "text": text }),
+    );
+    Ok(())
+}
+
+fn with_window_manager(
+    app: &AppHandle,
+    action: impl FnOnce(&crate::window_manager::WindowManager) -> Result<(), String>,
+) -> Result<(), String> {
+    let app_state = app.state::<AppState>();
+    let window_manager = app_state
+        .get_window_manager()
+        .ok_or_else(|| "Window manager not initialized".to_string())?;
+    action(&window_manager)
+}
+
+/// Route a finished dictation to the scratchpad instead of the focused app.
+/// Works without accessibility permission because nothing is pasted.
+pub async fn append_to_scratchpad(app: &AppHandle, text: &str) -> Result<(), String> {
+    let updated = append_entry(&read_scratchpad(app)?, text);
+    write_scratchpad(app, &updated)?;
+    with_window_manager(app, |window_manager| {
+        window_manager.show_scratchpad_window()
+    })
+}
+
+#[tauri::command]
+pub async fn show_scratchpad(app: AppHandle) -> Result<(), String> {
+    with_window_manager(&app, |window_manager| {
+        window_manager.show_scratchpad_window()
+    })
+}
+
+#[tauri::command]
+pub async fn hide_scratchpad(app: AppHandle) -> Result<(), String> {
+    with_window_manager(&app, |window_manager| {
+        window_manager.hide_scratchpad_window()
+    })
+}
+
+#[tauri::command]
+pub async fn get_scratchpad(app: AppHandle) -> Result<String, String> {
+    read_scratchpad(&app)
+}
+
+#[tauri::command]
+pub async fn clear_scratchpad(app: AppHandle) -> Result<(), String> {
+    write_scratchpad(&app, "")?;
+    log::info!("Scratchpad cleared");
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn copy_scratchpad(app: AppHandle) -> Result<(), String> {
+    let text = read_scratchpad(&app)?;
+    if text.is_empty() {
+        return Err("Scratchpad is empty".to_string());
+    }
+    crate::commands::text::copy_text_to_clipboard(text).await
+}
+
+#[tauri::command]
+pub async fn export_scratchpad(app: AppHandle, path: String) -> Result<(), String> {
+    crate::commands::utils::save_transcript_file(path, read_scratchpad(&app)?).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::append_entry;
+
+    #[test]
+    fn append_entry_separates_dictations_with_a_blank_line() {
+        assert_eq!(append_entry("", "  First note. "), "First note.");
+        assert_eq!(
+            append_entry("First note.\n", "Second note."),
+            "First note.\n\nSecond note."
+        );
+        assert_eq!(append_entry("First note.", "   "), "First note.");
+    }
+}
@@ -1,4 +1,5 @@
 use crate::audio::device_watcher::try_start_device_watcher_if_ready;
+use crate::audio::silence_detector::SilenceAutoStop;
 use crate::commands::key_normalizer::{
     normalize_shortcut_keys, validate_key_combination_allowing_safe_single_key,
 };
@@ -12,6 +13,7 @@ use crate::remote::settings::{ConnectionStatus, RemoteSettings};
 use crate::whisper::languages::{validate_language, SUPPORTED_LANGUAGES};
 use crate::whisper::manager::WhisperManager;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 use tauri::async_runtime::Mutex as AsyncMutex;
 
 /// Generation counter for tray menu updates to prevent race conditions.
@@ -27,18 +29,145 @@ pub const MIN_INDICATOR_OFFSET: u32 = 10;
 pub const MAX_INDICATOR_OFFSET: u32 = 50;
 pub const DEFAULT_INDICATOR_OFFSET: u32 = 10;
 
+// Number of recent transcriptions offered to AI formatting as context (1-10)
+pub const MIN_RECENT_CONTEXT_COUNT: u32 = 1;
+pub const MAX_RECENT_CONTEXT_COUNT: u32 = 10;
+pub const DEFAULT_RECENT_CONTEXT_COUNT: u32 = 3;
+
+// Window in which a second recording start from any trigger is ignored (ms)
+pub const MAX_RECORDING_START_DEBOUNCE_MS: u32 = 2000;
+pub const DEFAULT_RECORDING_START_DEBOUNCE_MS: u32 = 250;
+
+// Recordings shorter than this are discarded before transcription (ms)
+pub const MAX_MIN_RECORDING_DURATION_MS: u32 = 5000;
+pub const DEFAULT_MIN_RECORDING_DURATION_MS: u32 = 500;
+
+// Push-to-talk key must be held this long before a recording starts (ms)
+pub const MAX_PTT_MIN_HOLD_MS: u32 = 1000;
+pub const DEFAULT_PTT_MIN_HOLD_MS: u32 = 150;
+
+// Idle minutes before the Parakeet sidecar is shut down to free RAM (0 = keep it alive)
+pub const MAX_PARAKEET_IDLE_TIMEOUT_MINUTES: u32 = 24 * 60;
+pub const DEFAULT_PARAKEET_IDLE_TIMEOUT_MINUTES: u32 = 15;
+
+// Silence auto-stop: level counted as silence (dBFS) and pause after speech that ends a recording (ms)
+pub const MIN_SILENCE_THRESHOLD_DB: f32 = -60.0;
+pub const MAX_SILENCE_THRESHOLD_DB: f32 = -20.0;
+pub const DEFAULT_SILENCE_THRESHOLD_DB: f32 = -46.0;
+pub const MIN_SILENCE_DURATION_MS: u32 = 500;
+pub const MAX_SILENCE_DURATION_MS: u32 = 10_000;
+
+// Auto trim silence: level counted as silence (dBFS) and longest pause kept inside a recording (ms)
+pub const MIN_TRIM_SILENCE_THRESHOLD_DB: f32 = -60.0;
+pub const MAX_TRIM_SILENCE_THRESHOLD_DB: f32 = -20.0;
+pub const DEFAULT_TRIM_SILENCE_THRESHOLD_DB: f32 = -45.0;
+pub const MIN_TRIM_MAX_GAP_MS: u32 = 300;
+pub const MAX_TRIM_MAX_GAP_MS: u32 = 10_000;
+pub const DEFAULT_TRIM_MAX_GAP_MS: u32 = 1_500;
+
+// Gain applied to microphone samples as they are captured (dB)
+pub const MIN_MIC_GAIN_DB: f32 = -12.0;
+pub const MAX_MIC_GAIN_DB: f32 = 24.0;
+
+// Sample rate recordings are saved at; devices that can't capture it are resampled (Hz)
+pub const MIN_PREFERRED_SAMPLE_RATE: u32 = 8_000;
+pub const MAX_PREFERRED_SAMPLE_RATE: u32 = 96_000;
+
+// Whisper transcripts whose mean token confidence falls below this are flagged (0 = off)
+pub const DEFAULT_LOW_CONFIDENCE_THRESHOLD: f32 = 0.35;
+// Longest user-written Whisper initial prompt kept, leaving room for dictionary terms (bytes)
+pub const MAX_WHISPER_INITIAL_PROMPT_BYTES: usize = 500;
+// Widest Whisper beam search; each extra beam costs roughly another decoding pass
+pub const MAX_WHISPER_BEAM_SIZE: u32 = 8;
+
+// Grace period before the pill appears, so quick taps never flash it (ms)
+pub const MAX_PILL_SHOW_DELAY_MS: u32 = 2000;
+// How long the pill stays up after a dictation finishes, per outcome (ms)
+pub const MAX_PILL_HIDE_DELAY_MS: u32 = 5000;
+pub const DEFAULT_PILL_HIDE_DELAY_ERROR_MS: u32 = 2500;
+pub const DEFAULT_PILL_HIDE_DELAY_EMPTY_MS: u32 = 2500;
+
+// Finished recordings waiting to be transcribed, counting the one in progress
+pub const DEFAULT_MAX_QUEUED_TRANSCRIPTIONS: u32 = 3;
+pub const MAX_QUEUED_TRANSCRIPTIONS_LIMIT: u32 = 10;
+
+// Uploaded files longer than this are transcribed in chunks (s, 0 = never split)
+pub const MIN_UPLOAD_SEGMENT_SECONDS: u32 = 30;
+pub const MAX_UPLOAD_SEGMENT_SECONDS: u32 = 3600;
+// Chunk length for Whisper/Parakeet uploads when unset, so long files report progress
+pub const DEFAULT_LOCAL_UPLOAD_SEGMENT_SECONDS: u32 = 300;
+
+// Extra paste attempts before leaving the text on the clipboard, and the pause between them
+pub const MAX_INSERTION_RETRY_COUNT: u32 = 5;
+pub const DEFAULT_INSERTION_RETRY_COUNT: u32 = 2;
+pub const MAX_INSERTION_RETRY_DELAY_MS: u32 = 1000;
+pub const DEFAULT_INSERTION_RETRY_DELAY_MS: u32 = 150;
+
+// How final text reaches the focused app: clipboard paste, simulated typing, or paste then type
+pub const INSERTION_METHOD_PASTE: &str = "paste";
+pub const INSERTION_METHOD_TYPE: &str = "type";
+pub const INSERTION_METHOD_AUTO: &str = "auto";
+// Pause after each typed character, for apps that drop fast input (ms)
+pub const MAX_TYPING_DELAY_MS: u32 = 200;
+// Window for skipping a repeated save of the same transcription (seconds, 0 = off)
+pub const DEFAULT_DUPLICATE_WINDOW_SECONDS: u32 = 2;
+pub const MAX_DUPLICATE_WINDOW_SECONDS: u32 = 60;
+// Recordings stop on their own after this long (seconds, 0 = unlimited)
+pub const DEFAULT_MAX_RECORDING_DURATION_S: u32 = 600;
+pub const MAX_MAX_RECORDING_DURATION_S: u32 = 4 * 60 * 60;
+
+// Background update check interval (hours, 0 = disabled)
+pub const MAX_UPDATE_CHECK_INTERVAL_HOURS: u32 = 24 * 7;
+
+// Recording start/stop feedback channel, for apps that occlude the pill
+pub const RECORDING_INDICATOR_PILL: &str = "pill";
+pub const RECORDING_INDICATOR_NOTIFICATION: &str = "notification";
+pub const RECORDING_INDICATOR_BOTH: &str = "both";
+pub const RECORDING_INDICATOR_NONE: &str = "none";
+
 pub const TRANSCRIPTION_TASK_TRANSCRIBE: &str = "transcribe";
 pub const TRANSCRIPTION_TASK_TRANSLATE_TO_ENGLISH: &str = "translate_to_english";
 pub const FINAL_TEXT_LANGUAGE_SAME_AS_TRANSCRIPT: &str = "same_as_transcript";
 
+/// Overrides applied to a recording started while a matching app is frontmost.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct AppProfile {
+    // Bundle id (macOS) or executable name (Windows), matched case-insensitively
+    pub app_match: String,
+    // Empty keeps the global model
+    #[serde(default)]
+    pub model: String,
+    // Engine of `model`: "whisper", "parakeet", or a cloud provider id
+    #[serde(default)]
+    pub engine: String,
+    // Empty keeps the global speech language
+    #[serde(default)]
+    pub language: String,
+    // None keeps the global AI formatting toggle
+    #[serde(default)]
+    pub ai_enabled: Option<bool>,
+    // Some(true) copies the text without pasting; None keeps the global auto-paste toggle
+    #[serde(default)]
+    pub clipboard_only: Option<bool>,
+    // Empty keeps the global Whisper initial prompt
+    #[serde(default)]
+    pub whisper_initial_prompt: String,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct Settings {
     pub hotkey: String,
     pub current_model: String,
     pub current_model_engine: String,
     pub speech_language: String,
+    // Language to restore when leaving an English-only model (managed by save_settings)
+    #[serde(default)]
+    pub last_multilingual_language: Option<String>,
     pub transcription_task: String,
     pub final_text_language: String,
+    // Translate finished dictations into this language with the AI provider ("" = off)
+    #[serde(default)]
+    pub translate_to_language: String,
     pub theme: String,
     pub transcription_cleanup_days: Option<u32>,
     pub pill_position: Option<(f64, f64)>,
@@ -70,9 +199,192 @@ pub struct Settings {
     // Recording persistence settings
     pub save_recordings: bool,
     pub recording_retention_days: Option<u32>, // None = keep forever
+    // Container for saved recordings: "wav", "flac" (lossless) or "opus" (smallest)
+    #[serde(default = "default_recording_format")]
+    pub recording_format: String,
+    // Folder whose `voicetypr` subfolder holds captures and saved recordings,
+    // absolute path ("" = app data)
+    #[serde(default)]
+    pub recordings_dir: String,
+    // Keep the raw microphone capture next to saved recordings for debugging
+    #[serde(default)]
+    pub keep_raw_recordings: bool,
     // Transcription hardware acceleration: "auto" | "gpu" | "cpu"
     #[serde(default = "default_transcription_acceleration")]
     pub transcription_acceleration: String,
+    // Feed recent transcriptions to AI formatting as consistency context
+    #[serde(default)]
+    pub use_recent_context: bool,
+    #[serde(default = "default_recent_context_count")]
+    pub recent_context_count: u32,
+    // Ignore a new recording start this soon after the previous one (0-2000 ms)
+    #[serde(default = "default_recording_start_debounce_ms")]
+    pub recording_start_debounce_ms: u32,
+    // Discard recordings shorter than this before transcription (0-5000 ms)
+    #[serde(default = "default_min_recording_duration_ms")]
+    pub min_recording_duration_ms: u32,
+    // Per-mode minimum in seconds (0 = keep every recording); None uses min_recording_duration_ms
+    #[serde(default)]
+    pub min_recording_toggle_s: Option<f32>,
+    #[serde(default)]
+    pub min_recording_ptt_s: Option<f32>,
+    // Ignore push-to-talk taps released before this many ms (0 = start immediately)
+    #[serde(default = "default_ptt_min_hold_ms")]
+    pub ptt_min_hold_ms: u32,
+    // Periodically re-warm the selected cloud engine's connection while idle
+    #[serde(default)]
+    pub keep_engine_warm: bool,
+    // Shut the Parakeet sidecar down after this many idle minutes (0 = never)
+    #[serde(default = "default_parakeet_idle_timeout_minutes")]
+    pub parakeet_idle_timeout_minutes: u32,
+    // Remove "Here is the corrected text:" style wrappers from AI responses
+    #[serde(default = "default_strip_ai_preamble")]
+    pub strip_ai_preamble: bool,
+    // Delay showing the pill until recording has lasted this long (0-2000 ms)
+    #[serde(default)]
+    pub pill_show_delay_ms: u32,
+    // Keep the pill visible this long after text is delivered (0-5000 ms)
+    #[serde(default)]
+    pub pill_hide_delay_ms: u32,
+    // Keep the pill visible this long after a failed transcription (0-5000 ms)
+    #[serde(default = "default_pill_hide_delay_error_ms")]
+    pub pill_hide_delay_error_ms: u32,
+    // Keep the pill visible this long when no speech was captured (0-5000 ms)
+    #[serde(default = "default_pill_hide_delay_empty_ms")]
+    pub pill_hide_delay_empty_ms: u32,
+    // Transcriptions allowed to run or wait at once before new ones are rejected (1-10)
+    #[serde(default = "default_max_queued_transcriptions")]
+    pub max_queued_transcriptions: u32,
+    // Send finished dictations to the scratchpad window instead of the focused app
+    #[serde(default)]
+    pub scratchpad_mode: bool,
+    // Where finished dictations go: "cursor", "clipboard", "file:<path template>" (see text::sinks)
+    #[serde(default = "default_transcription_sinks")]
+    pub transcription_sinks: Vec<String>,
+    // Retry a failed paste this many times before falling back to the clipboard (0-5)
+    #[serde(default = "default_insertion_retry_count")]
+    pub insertion_retry_count: u32,
+    // Pause between paste retries (0-1000 ms)
+    #[serde(default = "default_insertion_retry_delay_ms")]
+    pub insertion_retry_delay_ms: u32,
+    // Insert by "paste", "type" (simulated keystrokes), or "auto" (paste, typing on failure)
+    #[serde(default = "default_insertion_method")]
+    pub insertion_method: String,
+    // Pause after each typed character when typing (0-200 ms)
+    #[serde(default)]
+    pub typing_delay_ms: u32,
+    // Keep at most this many history rows, evicting the oldest non-favorites (0 = no cap)
+    #[serde(default)]
+    pub max_history_entries: u32,
+    // Skip saving a transcription matching the previous one (ignoring case and spacing) within this many seconds (0 = off)
+    #[serde(default = "default_duplicate_window_seconds")]
+    pub duplicate_window_seconds: u32,
+    // Stream audio to Soniox over WebSocket while recording instead of uploading afterwards
+    #[serde(default)]
+    pub soniox_realtime: bool,
+    // Record with a downloaded local model when the Soniox key is rejected at record time
+    #[serde(default)]
+    pub soniox_fallback_local: bool,
+    // Accept start/stop/status commands from scripts on a local socket (named pipe on Windows)
+    #[serde(default)]
+    pub local_control_socket: bool,
+    // Prefix AssemblyAI transcripts with "Speaker A:" lines from speaker diarization
+    #[serde(default)]
+    pub assemblyai_speaker_labels: bool,
+    // Two-pass EBU R128 loudness normalization before transcription
+    #[serde(default = "default_loudness_normalize")]
+    pub loudness_normalize: bool,
+    // Trim leading/trailing silence and long pauses before local transcription
+    #[serde(default)]
+    pub auto_trim_silence: bool,
+    // Level at or below which auto trim treats audio as silence (-60 to -20 dBFS)
+    #[serde(default = "default_trim_silence_threshold_db")]
+    pub trim_silence_threshold_db: f32,
+    // Internal pauses longer than this are shortened by auto trim (300-10000 ms)
+    #[serde(default = "default_trim_max_gap_ms")]
+    pub trim_max_gap_ms: u32,
+    // Spectral noise suppression on recordings before transcription
+    #[serde(default)]
+    pub noise_suppression: bool,
+    // Detect the spoken language per recording (Whisper); speech_language is the fallback
+    #[serde(default)]
+    pub auto_detect_language: bool,
+    // End recordings on silence (off = long thinking pauses never stop a recording)
+    #[serde(default = "default_silence_auto_stop_enabled")]
+    pub silence_auto_stop_enabled: bool,
+    // Level below which audio counts as silence (-60 to -20 dBFS)
+    #[serde(default = "default_silence_threshold_db")]
+    pub silence_threshold_db: f32,
+    // Silence after speech that ends a recording (500-10000 ms, None = 5-minute safety stop)
+    #[serde(default)]
+    pub silence_duration_ms: Option<u32>,
+    // Boost or cut applied to the microphone during capture (-12 to +24 dB)
+    #[serde(default)]
+    pub mic_gain_db: f32,
+    // Warn on the pill when the input clips during a recording
+    #[serde(default = "default_clipping_warning_enabled")]
+    pub clipping_warning_enabled: bool,
+    // Record even when the OS reports the microphone as muted
+    #[serde(default)]
+    pub allow_recording_when_muted: bool,
+    // Sample rate of recorded WAVs (8000-96000 Hz); capture is resampled when the mic can't provide it
+    #[serde(default = "default_preferred_sample_rate")]
+    pub preferred_sample_rate: u32,
+    // Channel kept from a stereo mic: "mix" (average), "left", or "right"
+    #[serde(default = "default_channel_select")]
+    pub channel_select: String,
+    // Stop a recording automatically after this many seconds (0 = unlimited)
+    #[serde(default = "default_max_recording_duration_s")]
+    pub max_recording_duration_s: u32,
+    // Warn on the pill when Whisper's mean token confidence is below this (0-1, 0 = off)
+    #[serde(default = "default_low_confidence_threshold")]
+    pub low_confidence_threshold: f32,
+    // Text that primes Whisper with domain terms and preferred spelling, ahead of dictionary terms
+    #[serde(default)]
+    pub whisper_initial_prompt: String,
+    // Whisper beam width 1-8 (1 = greedy); None keeps greedy on CPU and 5 beams on GPU
+    #[serde(default)]
+    pub whisper_beam_size: Option<u32>,
+    // Retry low-confidence Whisper segments at rising temperatures
+    #[serde(default = "default_whisper_temperature_fallback")]
+    pub whisper_temperature_fallback: bool,
+    // OpenAI cloud STT model: "gpt-4o-transcribe" or "whisper-1" (verbose segments)
+    #[serde(default = "default_openai_stt_model")]
+    pub openai_stt_model: String,
+    // Final formatting pass on inserted text, after AI and replacements (see text::formatting)
+    #[serde(default)]
+    pub trim_whitespace: bool,
+    #[serde(default)]
+    pub collapse_spaces: bool,
+    #[serde(default)]
+    pub auto_capitalize_first: bool,
+    #[serde(default)]
+    pub ensure_trailing_space: bool,
+    // Check for updates from the backend every N hours and notify (0 = off)
+    #[serde(default)]
+    pub update_check_interval_hours: u32,
+    // Use a local model instead of the cloud engine while the provider is unreachable
+    #[serde(default)]
+    pub auto_engine_by_network: bool,
+    // Local model used when offline; empty picks the best downloaded Whisper model
+    #[serde(default)]
+    pub offline_fallback_model: String,
+    // Engine of offline_fallback_model: "whisper" or "parakeet"
+    #[serde(default)]
+    pub offline_fallback_engine: String,
+    // Type Soniox realtime interim results and correct them as finals arrive
+    #[serde(default)]
+    pub insert_streaming: bool,
+    // Split uploaded files into chunks of this many seconds (0 = disabled).
+    // None = 300 s for Whisper/Parakeet, a single request for cloud providers
+    #[serde(default)]
+    pub upload_segment_seconds: Option<u32>,
+    // Recording feedback: "pill", "notification", "both", or "none"
+    #[serde(default = "default_recording_indicator")]
+    pub recording_indicator: String,
+    // Per-app model/language/AI overrides, keyed by the frontmost app
+    #[serde(default)]
+    pub profiles: Vec<AppProfile>,
 }
 
 impl Default for Settings {
@@ -82,8 +394,10 @@ impl Default for Settings {
             current_model: "".to_string(), // Empty means auto-select
             current_model_engine: "whisper".to_string(),
             speech_language: "en".to_string(),
+            last_multilingual_language: None,
             transcription_task: TRANSCRIPTION_TASK_TRANSCRIBE.to_string(),
             final_text_language: FINAL_TEXT_LANGUAGE_SAME_AS_TRANSCRIPT.to_string(),
+            translate_to_language: String::new(), // Default to no translation step
             theme: "system".to_string(),
             transcription_cleanup_days: None, // None means keep forever
             pill_position: None,              // No saved position initially
@@ -100,13 +414,75 @@ impl Default for Settings {
             pill_indicator_mode: "when_recording".to_string(), // Default to showing only when recording
             pill_indicator_position: "bottom-center".to_string(), // Default to bottom center of screen
             pill_indicator_offset: DEFAULT_INDICATOR_OFFSET,
-            pause_media_during_recording: false, // Default to off; user opts in
-            auto_paste_transcription: true,      // Default to auto-pasting transcription
-            sharing_port: Some(47842),           // Default network sharing port
-            sharing_password: None,              // No password by default
-            save_recordings: false,              // Default to not saving recordings
-            recording_retention_days: Some(30),  // Default cleanup period when saving is enabled
+            pause_media_during_recording: true, // Default to on; users opt out
+            auto_paste_transcription: true,     // Default to auto-pasting transcription
+            sharing_port: Some(47842),          // Default network sharing port
+            sharing_password: None,             // No password by default
+            save_recordings: false,             // Default to not saving recordings
+            recording_retention_days: Some(30), // Default cleanup period when saving is enabled
+            recording_format: default_recording_format(),
+            recordings_dir: String::new(), // Default to the app data recordings folder
+            keep_raw_recordings: false,    // Default to deleting the capture after transcription
             transcription_acceleration: "auto".to_string(),
+            use_recent_context: false, // Default to formatting each dictation on its own
+            recent_context_count: DEFAULT_RECENT_CONTEXT_COUNT,
+            recording_start_debounce_ms: DEFAULT_RECORDING_START_DEBOUNCE_MS,
+            min_recording_duration_ms: DEFAULT_MIN_RECORDING_DURATION_MS,
+            min_recording_toggle_s: None, // Default to the shared minimum for both modes
+            min_recording_ptt_s: None,
+            ptt_min_hold_ms: DEFAULT_PTT_MIN_HOLD_MS,
+            keep_engine_warm: false, // Default to warming only when recording starts
+            parakeet_idle_timeout_minutes: DEFAULT_PARAKEET_IDLE_TIMEOUT_MINUTES,
+            strip_ai_preamble: true,
+            pill_show_delay_ms: 0, // Default to showing the pill as soon as recording starts
+            pill_hide_delay_ms: 0, // Default to hiding the pill before inserting text
+            pill_hide_delay_error_ms: DEFAULT_PILL_HIDE_DELAY_ERROR_MS,
+            pill_hide_delay_empty_ms: DEFAULT_PILL_HIDE_DELAY_EMPTY_MS,
+            max_queued_transcriptions: DEFAULT_MAX_QUEUED_TRANSCRIPTIONS,
+            scratchpad_mode: false, // Default to inserting into the focused app
+            transcription_sinks: default_transcription_sinks(),
+            insertion_retry_count: DEFAULT_INSERTION_RETRY_COUNT,
+            insertion_retry_delay_ms: DEFAULT_INSERTION_RETRY_DELAY_MS,
+            insertion_method: INSERTION_METHOD_PASTE.to_string(), // Default to paste, the fastest method
+            typing_delay_ms: 0,                                   // Default to typing at full speed
+            max_history_entries: 0,                               // Default to no count-based limit
+            duplicate_window_seconds: DEFAULT_DUPLICATE_WINDOW_SECONDS, // Default to a 2s window
+            soniox_realtime: false, // Default to file-based Soniox transcription
+            soniox_fallback_local: false, // Default to asking for a new key
+            local_control_socket: false, // Default to no scripting socket
+            assemblyai_speaker_labels: false, // Default to plain AssemblyAI transcripts
+            loudness_normalize: true, // Default to leveling quiet laptop-mic recordings
+            auto_trim_silence: false, // Default to transcribing the recording as captured
+            trim_silence_threshold_db: DEFAULT_TRIM_SILENCE_THRESHOLD_DB,
+            trim_max_gap_ms: DEFAULT_TRIM_MAX_GAP_MS,
+            noise_suppression: false, // Default to leaving background noise to the model
+            auto_detect_language: false, // Default to the configured speech language
+            silence_auto_stop_enabled: true, // Default to the built-in silence safety stops
+            silence_threshold_db: DEFAULT_SILENCE_THRESHOLD_DB,
+            silence_duration_ms: None,
+            mic_gain_db: 0.0, // Default to capturing at the OS input level
+            clipping_warning_enabled: true, // Default to warning when the input clips
+            allow_recording_when_muted: false, // Default to refusing to record silence
+            preferred_sample_rate: default_preferred_sample_rate(),
+            channel_select: default_channel_select(),
+            max_recording_duration_s: DEFAULT_MAX_RECORDING_DURATION_S, // Default to a 10 minute cap
+            low_confidence_threshold: DEFAULT_LOW_CONFIDENCE_THRESHOLD,
+            whisper_initial_prompt: String::new(), // Default to priming with dictionary terms only
+            whisper_beam_size: None,               // Default to the per-device decoding profile
+            whisper_temperature_fallback: true,
+            openai_stt_model: default_openai_stt_model(),
+            trim_whitespace: false, // Default to inserting text exactly as produced
+            collapse_spaces: false,
+            auto_capitalize_first: false,
+            ensure_trailing_space: false,
+            update_check_interval_hours: 0, // Default to frontend-driven update checks only
+            auto_engine_by_network: false,  // Default to always using the configured engine
+            offline_fallback_model: String::new(),
+            offline_fallback_engine: String::new(),
+            insert_streaming: false, // Default to inserting once transcription completes
+            upload_segment_seconds: None, // Default to the per-engine segment length
+            recording_indicator: RECORDING_INDICATOR_PILL.to_string(),
+            profiles: Vec::new(), // Default to global settings for every app
         }
     }
 }
@@ -115,6 +491,382 @@ fn default_transcription_acceleration() -> String {
     "auto".to_string()
 }
 
+fn default_recent_context_count() -> u32 {
+    DEFAULT_RECENT_CONTEXT_COUNT
+}
+
+fn default_recording_start_debounce_ms() -> u32 {
+    DEFAULT_RECORDING_START_DEBOUNCE_MS
+}
+
+fn default_min_recording_duration_ms() -> u32 {
+    DEFAULT_MIN_RECORDING_DURATION_MS
+}
+
+/// Clamp a per-mode minimum recording length to 0..=5 seconds; NaN and
+/// negative values turn the gate off.
+pub fn normalize_min_recording_mode_s(value: f64) -> f32 {
+    if !value.is_finite() {
+        return 0.0;
+    }
+    value.clamp(0.0, MAX_MIN_RECORDING_DURATION_MS as f64 / 1000.0) as f32
+}
+
+fn default_ptt_min_hold_ms() -> u32 {
+    DEFAULT_PTT_MIN_HOLD_MS
+}
+
+fn default_parakeet_idle_timeout_minutes() -> u32 {
+    DEFAULT_PARAKEET_IDLE_TIMEOUT_MINUTES
+}
+
+fn default_silence_auto_stop_enabled() -> bool {
+    true
+}
+
+fn default_clipping_warning_enabled() -> bool {
+    true
+}
+
+fn default_silence_threshold_db() -> f32 {
+    DEFAULT_SILENCE_THRESHOLD_DB
+}
+
+fn default_pill_hide_delay_error_ms() -> u32 {
+    DEFAULT_PILL_HIDE_DELAY_ERROR_MS
+}
+
+fn default_pill_hide_delay_empty_ms() -> u32 {
+    DEFAULT_PILL_HIDE_DELAY_EMPTY_MS
+}
+
+fn default_max_queued_transcriptions() -> u32 {
+    DEFAULT_MAX_QUEUED_TRANSCRIPTIONS
+}
+
+/// Clamp the transcription queue cap; at least the running job must fit.
+pub fn normalize_max_queued_transcriptions(value: u64) -> u32 {
+    value.clamp(1, MAX_QUEUED_TRANSCRIPTIONS_LIMIT as u64) as u32
+}
+
+fn default_strip_ai_preamble() -> bool {
+    true
+}
+
+fn default_trim_silence_threshold_db() -> f32 {
+    DEFAULT_TRIM_SILENCE_THRESHOLD_DB
+}
+
+fn default_trim_max_gap_ms() -> u32 {
+    DEFAULT_TRIM_MAX_GAP_MS
+}
+
+fn default_loudness_normalize() -> bool {
+    true
+}
+
+fn default_insertion_retry_count() -> u32 {
+    DEFAULT_INSERTION_RETRY_COUNT
+}
+
+fn default_insertion_retry_delay_ms() -> u32 {
+    DEFAULT_INSERTION_RETRY_DELAY_MS
+}
+
+fn default_insertion_method() -> String {
+    INSERTION_METHOD_PASTE.to_string()
+}
+
+fn default_recording_format() -> String {
+    crate::ffmpeg::RecordingFormat::Wav.as_str().to_string()
+}
+
+fn default_recording_indicator() -> String {
+    RECORDING_INDICATOR_PILL.to_string()
+}
+
+pub fn normalize_update_check_interval_hours(value: u64) -> u32 {
+    value.min(MAX_UPDATE_CHECK_INTERVAL_HOURS as u64) as u32
+}
+
+pub fn normalize_upload_segment_seconds(value: u64) -> u32 {
+    if value == 0 {
+        return 0;
+    }
+    value.clamp(
+        MIN_UPLOAD_SEGMENT_SECONDS as u64,
+        MAX_UPLOAD_SEGMENT_SECONDS as u64,
+    ) as u32
+}
+
+pub fn normalize_silence_threshold_db(value: f64) -> f32 {
+    if !value.is_finite() {
+        return DEFAULT_SILENCE_THRESHOLD_DB;
+    }
+    (value as f32).clamp(MIN_SILENCE_THRESHOLD_DB, MAX_SILENCE_THRESHOLD_DB)
+}
+
+pub fn normalize_trim_silence_threshold_db(value: f64) -> f32 {
+    if !value.is_finite() {
+        return DEFAULT_TRIM_SILENCE_THRESHOLD_DB;
+    }
+    (value as f32).clamp(MIN_TRIM_SILENCE_THRESHOLD_DB, MAX_TRIM_SILENCE_THRESHOLD_DB)
+}
+
+pub fn normalize_trim_max_gap_ms(value: u64) -> u32 {
+    value.clamp(MIN_TRIM_MAX_GAP_MS as u64, MAX_TRIM_MAX_GAP_MS as u64) as u32
+}
+
+pub fn normalize_mic_gain_db(value: f64) -> f32 {
+    if !value.is_finite() {
+        return 0.0;
+    }
+    (value as f32).clamp(MIN_MIC_GAIN_DB, MAX_MIC_GAIN_DB)
+}
+
+fn default_transcription_sinks() -> Vec<String> {
+    vec![crate::text::sinks::DEFAULT_SINK.to_string()]
+}
+
+fn default_duplicate_window_seconds() -> u32 {
+    DEFAULT_DUPLICATE_WINDOW_SECONDS
+}
+
+pub fn normalize_duplicate_window_seconds(value: u64) -> u32 {
+    value.min(MAX_DUPLICATE_WINDOW_SECONDS as u64) as u32
+}
+
+fn default_preferred_sample_rate() -> u32 {
+    crate::audio::recorder::TARGET_SAMPLE_RATE
+}
+
+pub fn normalize_preferred_sample_rate(value: u64) -> u32 {
+    value.clamp(
+        MIN_PREFERRED_SAMPLE_RATE as u64,
+        MAX_PREFERRED_SAMPLE_RATE as u64,
+    ) as u32
+}
+
+fn default_channel_select() -> String {
+    crate::audio::converter::DEFAULT_CHANNEL_SELECT.to_string()
+}
+
+pub fn normalize_channel_select(value: &str) -> String {
+    crate::audio::converter::ChannelSelect::parse(value)
+        .as_str()
+        .to_string()
+}
+
+fn default_max_recording_duration_s() -> u32 {
+    DEFAULT_MAX_RECORDING_DURATION_S
+}
+
+pub fn normalize_max_recording_duration_s(value: u64) -> u32 {
+    value.min(MAX_MAX_RECORDING_DURATION_S as u64) as u32
+}
+
+fn default_low_confidence_threshold() -> f32 {
+    DEFAULT_LOW_CONFIDENCE_THRESHOLD
+}
+
+pub fn normalize_low_confidence_threshold(value: f64) -> f32 {
+    if !value.is_finite() {
+        return DEFAULT_LOW_CONFIDENCE_THRESHOLD;
+    }
+    (value as f32).clamp(0.0, 1.0)
+}
+
+fn default_whisper_temperature_fallback() -> bool {
+    true
+}
+
+pub fn normalize_whisper_beam_size(value: u64) -> u32 {
+    value.clamp(1, MAX_WHISPER_BEAM_SIZE as u64) as u32
+}
+
+pub fn normalize_whisper_initial_prompt(value: &str) -> String {
+    let mut prompt: String = value.trim().chars().filter(|ch| *ch != '\0').collect();
+    if prompt.len() > MAX_WHISPER_INITIAL_PROMPT_BYTES {
+        let mut end = MAX_WHISPER_INITIAL_PROMPT_BYTES;
+        while !prompt.is_char_boundary(end) {
+            end -= 1;
+        }
+        prompt.truncate(end);
+        prompt.truncate(prompt.trim_end().len());
+    }
+    prompt
+}
+
+fn default_openai_stt_model() -> String {
+    crate::cloud_stt::normalize_openai_stt_model("").to_string()
+}
+
+pub fn normalize_silence_duration_ms(value: u64) -> u32 {
+    value.clamp(
+        MIN_SILENCE_DURATION_MS as u64,
+        MAX_SILENCE_DURATION_MS as u64,
+    ) as u32
+}
+
+/// Silence auto-stop settings in the form the recorder's detector consumes.
+pub(crate) fn silence_auto_stop_config(
+    enabled: bool,
+    threshold_db: f32,
+    duration_ms: Option<u32>,
+) -> SilenceAutoStop {
+    SilenceAutoStop {
+        enabled,
+        threshold_db: normalize_silence_threshold_db(threshold_db as f64),
+        after_speech: duration_ms
+            .map(|ms| Duration::from_millis(normalize_silence_duration_ms(ms as u64) as u64)),
+    }
+}
+
+pub fn normalize_insertion_method(value: Option<&str>) -> String {
+    match value {
+        Some(method @ (INSERTION_METHOD_PASTE | INSERTION_METHOD_TYPE | INSERTION_METHOD_AUTO)) => {
+            method.to_string()
+        }
+        _ => default_insertion_method(),
+    }
+}
+
+pub fn normalize_recording_indicator(value: Option<&str>) -> String {
+    match value {
+        Some(
+            indicator @ (RECORDING_INDICATOR_PILL
+            | RECORDING_INDICATOR_NOTIFICATION
+            | RECORDING_INDICATOR_BOTH
+            | RECORDING_INDICATOR_NONE),
+        ) => indicator.to_string(),
+        _ => default_recording_indicator(),
+    }
+}
+
+/// Profile whose `app_match` names the given app. Windows executable names
+/// match with or without their `.exe` suffix.
+pub fn resolve_app_profile<'a>(profiles: &'a [AppProfile], app_id: &str) -> Option<&'a AppProfile> {
+    let strip_exe = |name: &str| {
+        let name = name.trim().to_ascii_lowercase();
+        name.strip_suffix(".exe")
+            .map(str::to_string)
+            .unwrap_or(name)
+    };
+    let app_id = strip_exe(app_id);
+    if app_id.is_empty() {
+        return None;
+    }
+    profiles
+        .iter()
+        .find(|profile| strip_exe(&profile.app_match) == app_id)
+}
+
+/// Layer `top` over `base`: whatever `top` sets wins and the rest falls
+/// through to `base`. Used to put a shortcut's overrides over the app profile.
+pub fn layer_profiles(base: Option<AppProfile>, top: Option<AppProfile>) -> Option<AppProfile> {
+    let Some(top) = top else {
+        return base;
+    };
+    let Some(mut merged) = base else {
+        return Some(top);
+    };
+    if !top.model.is_empty() {
+        merged.model = top.model;
+        merged.engine = top.engine;
+    }
+    if !top.language.is_empty() {
+        merged.language = top.language;
+    }
+    if top.ai_enabled.is_some() {
+        merged.ai_enabled = top.ai_enabled;
+    }
+    if top.clipboard_only.is_some() {
+        merged.clipboard_only = top.clipboard_only;
+    }
+    if !top.whisper_initial_prompt.is_empty() {
+        merged.whisper_initial_prompt = top.whisper_initial_prompt;
+    }
+    Some(merged)
+}
+
+pub fn app_profiles_from_store(value: Option<serde_json::Value>) -> Vec<AppProfile> {
+    value
+        .and_then(|v| serde_json::from_value::<Vec<AppProfile>>(v).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|profile| !profile.app_match.trim().is_empty())
+        .collect()
+}
+
+/// Profiles whose model was added or changed relative to `stored`. Only these
+/// are checked on save, so deleting a model doesn't block unrelated settings.
+pub fn profiles_with_changed_models<'a>(
+    profiles: &'a [AppProfile],
+    stored: &[AppProfile],
+) -> Vec<&'a AppProfile> {
+    profiles
+        .iter()
+        .filter(|profile| !profile.model.is_empty())
+        .filter(|profile| {
+            !stored.iter().any(|old| {
+                old.app_match == profile.app_match
+                    && old.model == profile.model
+                    && old.engine == profile.engine
+            })
+        })
+        .collect()
+}
+
+/// Reject a profile whose local model isn't downloaded, so the mistake shows
+/// up when the profile is saved rather than when a recording is transcribed.
+/// Cloud models have nothing to download and are accepted as is.
+async fn validate_profile_models(
+    app: &AppHandle,
+    profiles: &[AppProfile],
+    stored: &[AppProfile],
+) -> Result<(), String> {
+    for profile in profiles_with_changed_models(profiles, stored) {
+        if crate::cloud_stt::CloudProvider::from_id(&profile.engine).is_some() {
+            continue;
+        }
+        let downloaded = if profile.engine == "parakeet" {
+            app.state::<ParakeetManager>()
+                .list_models()
+                .into_iter()
+                .any(|model| model.name == profile.model && model.downloaded)
+        } else {
+            app.state::<tauri::async_runtime::RwLock<WhisperManager>>()
+                .read()
+                .await
+                .get_model_path(&profile.model)
+                .is_some()
+        };
+        if !downloaded {
+            return Err(format!(
+                "The profile for '{}' uses model '{}', which is not downloaded. Download it first or pick another model.",
+                profile.app_match, profile.model
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// True when the recording indicator setting includes the on-screen pill.
+pub fn recording_indicator_shows_pill(indicator: &str) -> bool {
+    matches!(
+        indicator,
+        RECORDING_INDICATOR_PILL | RECORDING_INDICATOR_BOTH
+    )
+}
+
+/// True when the recording indicator setting includes a system notification.
+pub fn recording_indicator_notifies(indicator: &str) -> bool {
+    matches!(
+        indicator,
+        RECORDING_INDICATOR_NOTIFICATION | RECORDING_INDICATOR_BOTH
+    )
+}
+
 pub fn normalize_stored_transcription_acceleration(value: Option<&str>) -> String {
     match value {
         Some("gpu") => "gpu".to_string(),
@@ -148,6 +900,27 @@ pub fn task_uses_translate_to_english(task: &str) -> bool {
     task == TRANSCRIPTION_TASK_TRANSLATE_TO_ENGLISH
 }
 
+/// Keep a supported language code; anything else turns translation off
+/// rather than silently translating into English.
+pub fn normalize_translate_to_language(value: &str) -> String {
+    let trimmed = value.trim();
+    if crate::whisper::languages::is_language_supported(trimmed) {
+        validate_language(Some(trimmed)).to_string()
+    } else {
+        String::new()
+    }
+}
+
+/// Only absolute paths are honored; anything else falls back to app data.
+pub fn normalize_recordings_dir(value: &str) -> String {
+    let trimmed = value.trim();
+    if std::path::Path::new(trimmed).is_absolute() {
+        trimmed.to_string()
+    } else {
+        String::new()
+    }
+}
+
 pub fn normalize_final_text_language(value: Option<&str>, transcription_task: &str) -> String {
     if task_uses_translate_to_english(transcription_task) {
         "en".to_string()
@@ -279,6 +1052,29 @@ pub fn model_requires_english_speech(engine: &str, model_name: &str) -> bool {
     }
 }
 
+/// Spoken language after a model change, plus the remembered multilingual
+/// language. Moving onto an English-only model forces `"en"` and remembers the
+/// language in use before; moving back to a multilingual model restores it,
+/// unless the caller picked a language other than the forced `"en"`.
+pub fn language_for_model_switch(
+    previous_english_only: bool,
+    next_english_only: bool,
+    previous_language: &str,
+    requested_language: &str,
+    last_multilingual_language: Option<&str>,
+) -> (String, Option<String>) {
+    let remembered = last_multilingual_language.map(str::to_string);
+    match (previous_english_only, next_english_only) {
+        (false, true) => ("en".to_string(), Some(previous_language.to_string())),
+        (_, true) => ("en".to_string(), remembered),
+        (true, false) if requested_language == "en" => match remembered {
+            Some(language) => (language.clone(), Some(language)),
+            None => (requested_language.to_string(), None),
+        },
+        _ => (requested_language.to_string(), remembered),
+    }
+}
+
 pub fn normalize_speech_language_for_model(
     engine: &str,
     model_name: &str,
@@ -328,7 +1124,7 @@ pub fn normalize_speech_language_for_model(
                 "en".to_string()
             }
         }
-        "openai" | "groq" | "deepgram" => validated.to_string(),
+        "openai" | "groq" | "deepgram" | "assemblyai" => validated.to_string(),
         _ => validated.to_string(),
     }
 }
@@ -375,8 +1171,15 @@ pub async fn get_settings(app: AppHandle) -> Result<Settings, String> {
             .and_then(|v| v.as_str().map(|s| s.to_string()))
             .unwrap_or_else(|| Settings::default().current_model_engine.clone()),
         speech_language,
+        last_multilingual_language: store
+            .get("last_multilingual_language")
+            .and_then(|v| v.as_str().map(|s| s.to_string())),
         transcription_task,
         final_text_language,
+        translate_to_language: store
+            .get("translate_to_language")
+            .and_then(|v| v.as_str().map(normalize_translate_to_language))
+            .unwrap_or_else(|| Settings::default().translate_to_language),
         theme: store
             .get("theme")
             .and_then(|v| v.as_str().map(|s| s.to_string()))
@@ -470,12 +1273,296 @@ pub async fn get_settings(app: AppHandle) -> Result<Settings, String> {
             .and_then(|v| v.as_bool())
             .unwrap_or_else(|| Settings::default().save_recordings),
         recording_retention_days: recording_retention_days_from_store(&store),
+        recording_format: store
+            .get("recording_format")
+            .and_then(|v| {
+                v.as_str().map(|s| {
+                    crate::ffmpeg::RecordingFormat::from_setting(s)
+                        .as_str()
+                        .to_string()
+                })
+            })
+            .unwrap_or_else(|| Settings::default().recording_format),
+        recordings_dir: store
+            .get("recordings_dir")
+            .and_then(|v| v.as_str().map(normalize_recordings_dir))
+            .unwrap_or_else(|| Settings::default().recordings_dir),
+        keep_raw_recordings: store
+            .get("keep_raw_recordings")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().keep_raw_recordings),
         transcription_acceleration: normalize_stored_transcription_acceleration(
             store
                 .get("transcription_acceleration")
                 .and_then(|v| v.as_str().map(|s| s.to_string()))
                 .as_deref(),
         ),
+        use_recent_context: store
+            .get("use_recent_context")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().use_recent_context),
+        recent_context_count: store
+            .get("recent_context_count")
+            .and_then(|v| v.as_u64())
+            .map(|v| {
+                v.clamp(
+                    MIN_RECENT_CONTEXT_COUNT as u64,
+                    MAX_RECENT_CONTEXT_COUNT as u64,
+                ) as u32
+            })
+            .unwrap_or_else(|| Settings::default().recent_context_count),
+        recording_start_debounce_ms: store
+            .get("recording_start_debounce_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_RECORDING_START_DEBOUNCE_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().recording_start_debounce_ms),
+        min_recording_duration_ms: store
+            .get("min_recording_duration_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_MIN_RECORDING_DURATION_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().min_recording_duration_ms),
+        min_recording_toggle_s: store
+            .get("min_recording_toggle_s")
+            .and_then(|v| v.as_f64())
+            .map(normalize_min_recording_mode_s),
+        min_recording_ptt_s: store
+            .get("min_recording_ptt_s")
+            .and_then(|v| v.as_f64())
+            .map(normalize_min_recording_mode_s),
+        ptt_min_hold_ms: store
+            .get("ptt_min_hold_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_PTT_MIN_HOLD_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().ptt_min_hold_ms),
+        keep_engine_warm: store
+            .get("keep_engine_warm")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().keep_engine_warm),
+        parakeet_idle_timeout_minutes: store
+            .get("parakeet_idle_timeout_minutes")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_PARAKEET_IDLE_TIMEOUT_MINUTES as u64) as u32)
+            .unwrap_or_else(|| Settings::default().parakeet_idle_timeout_minutes),
+        strip_ai_preamble: store
+            .get("strip_ai_preamble")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().strip_ai_preamble),
+        pill_show_delay_ms: store
+            .get("pill_show_delay_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_PILL_SHOW_DELAY_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().pill_show_delay_ms),
+        pill_hide_delay_ms: store
+            .get("pill_hide_delay_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_PILL_HIDE_DELAY_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().pill_hide_delay_ms),
+        pill_hide_delay_error_ms: store
+            .get("pill_hide_delay_error_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_PILL_HIDE_DELAY_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().pill_hide_delay_error_ms),
+        pill_hide_delay_empty_ms: store
+            .get("pill_hide_delay_empty_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_PILL_HIDE_DELAY_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().pill_hide_delay_empty_ms),
+        max_queued_transcriptions: store
+            .get("max_queued_transcriptions")
+            .and_then(|v| v.as_u64())
+            .map(normalize_max_queued_transcriptions)
+            .unwrap_or_else(|| Settings::default().max_queued_transcriptions),
+        scratchpad_mode: store
+            .get("scratchpad_mode")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().scratchpad_mode),
+        transcription_sinks: store
+            .get("transcription_sinks")
+            .and_then(|v| serde_json::from_value::<Vec<String>>(v).ok())
+            .unwrap_or_else(|| Settings::default().transcription_sinks),
+        insertion_retry_count: store
+            .get("insertion_retry_count")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_INSERTION_RETRY_COUNT as u64) as u32)
+            .unwrap_or_else(|| Settings::default().insertion_retry_count),
+        insertion_retry_delay_ms: store
+            .get("insertion_retry_delay_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_INSERTION_RETRY_DELAY_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().insertion_retry_delay_ms),
+        insertion_method: normalize_insertion_method(
+            store
+                .get("insertion_method")
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+                .as_deref(),
+        ),
+        typing_delay_ms: store
+            .get("typing_delay_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_TYPING_DELAY_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().typing_delay_ms),
+        max_history_entries: store
+            .get("max_history_entries")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(u32::MAX as u64) as u32)
+            .unwrap_or_else(|| Settings::default().max_history_entries),
+        duplicate_window_seconds: store
+            .get("duplicate_window_seconds")
+            .and_then(|v| v.as_u64())
+            .map(normalize_duplicate_window_seconds)
+            .unwrap_or_else(|| Settings::default().duplicate_window_seconds),
+        soniox_realtime: store
+            .get("soniox_realtime")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().soniox_realtime),
+        soniox_fallback_local: store
+            .get("soniox_fallback_local")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().soniox_fallback_local),
+        local_control_socket: store
+            .get("local_control_socket")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().local_control_socket),
+        assemblyai_speaker_labels: store
+            .get("assemblyai_speaker_labels")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().assemblyai_speaker_labels),
+        loudness_normalize: store
+            .get("loudness_normalize")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().loudness_normalize),
+        auto_trim_silence: store
+            .get("auto_trim_silence")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().auto_trim_silence),
+        trim_silence_threshold_db: store
+            .get("trim_silence_threshold_db")
+            .and_then(|v| v.as_f64())
+            .map(normalize_trim_silence_threshold_db)
+            .unwrap_or_else(|| Settings::default().trim_silence_threshold_db),
+        trim_max_gap_ms: store
+            .get("trim_max_gap_ms")
+            .and_then(|v| v.as_u64())
+            .map(normalize_trim_max_gap_ms)
+            .unwrap_or_else(|| Settings::default().trim_max_gap_ms),
+        noise_suppression: store
+            .get("noise_suppression")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().noise_suppression),
+        auto_detect_language: store
+            .get("auto_detect_language")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().auto_detect_language),
+        silence_auto_stop_enabled: store
+            .get("silence_auto_stop_enabled")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().silence_auto_stop_enabled),
+        silence_threshold_db: store
+            .get("silence_threshold_db")
+            .and_then(|v| v.as_f64())
+            .map(normalize_silence_threshold_db)
+            .unwrap_or_else(|| Settings::default().silence_threshold_db),
+        silence_duration_ms: store
+            .get("silence_duration_ms")
+            .and_then(|v| v.as_u64())
+            .map(normalize_silence_duration_ms),
+        mic_gain_db: store
+            .get("mic_gain_db")
+            .and_then(|v| v.as_f64())
+            .map(normalize_mic_gain_db)
+            .unwrap_or_else(|| Settings::default().mic_gain_db),
+        clipping_warning_enabled: store
+            .get("clipping_warning_enabled")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().clipping_warning_enabled),
+        allow_recording_when_muted: store
+            .get("allow_recording_when_muted")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().allow_recording_when_muted),
+        preferred_sample_rate: store
+            .get("preferred_sample_rate")
+            .and_then(|v| v.as_u64())
+            .map(normalize_preferred_sample_rate)
+            .unwrap_or_else(|| Settings::default().preferred_sample_rate),
+        channel_select: store
+            .get("channel_select")
+            .and_then(|v| v.as_str().map(normalize_channel_select))
+            .unwrap_or_else(|| Settings::default().channel_select),
+        max_recording_duration_s: store
+            .get("max_recording_duration_s")
+            .and_then(|v| v.as_u64())
+            .map(normalize_max_recording_duration_s)
+            .unwrap_or_else(|| Settings::default().max_recording_duration_s),
+        low_confidence_threshold: store
+            .get("low_confidence_threshold")
+            .and_then(|v| v.as_f64())
+            .map(normalize_low_confidence_threshold)
+            .unwrap_or_else(|| Settings::default().low_confidence_threshold),
+        whisper_initial_prompt: store
+            .get("whisper_initial_prompt")
+            .and_then(|v| v.as_str().map(normalize_whisper_initial_prompt))
+            .unwrap_or_default(),
+        whisper_beam_size: store
+            .get("whisper_beam_size")
+            .and_then(|v| v.as_u64())
+            .map(normalize_whisper_beam_size),
+        whisper_temperature_fallback: store
+            .get("whisper_temperature_fallback")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().whisper_temperature_fallback),
+        openai_stt_model: store
+            .get("openai_stt_model")
+            .and_then(|v| v.as_str().map(crate::cloud_stt::normalize_openai_stt_model))
+            .map(str::to_string)
+            .unwrap_or_else(|| Settings::default().openai_stt_model),
+        trim_whitespace: store
+            .get("trim_whitespace")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().trim_whitespace),
+        collapse_spaces: store
+            .get("collapse_spaces")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().collapse_spaces),
+        auto_capitalize_first: store
+            .get("auto_capitalize_first")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().auto_capitalize_first),
+        ensure_trailing_space: store
+            .get("ensure_trailing_space")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().ensure_trailing_space),
+        update_check_interval_hours: store
+            .get("update_check_interval_hours")
+            .and_then(|v| v.as_u64())
+            .map(normalize_update_check_interval_hours)
+            .unwrap_or_else(|| Settings::default().update_check_interval_hours),
+        auto_engine_by_network: store
+            .get("auto_engine_by_network")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().auto_engine_by_network),
+        offline_fallback_model: store
+            .get("offline_fallback_model")
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_default(),
+        offline_fallback_engine: store
+            .get("offline_fallback_engine")
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_default(),
+        insert_streaming: store
+            .get("insert_streaming")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().insert_streaming),
+        upload_segment_seconds: store
+            .get("upload_segment_seconds")
+            .and_then(|v| v.as_u64())
+            .map(normalize_upload_segment_seconds),
+        recording_indicator: normalize_recording_indicator(
+            store
+                .get("recording_indicator")
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+                .as_deref(),
+        ),
+        profiles: app_profiles_from_store(store.get("profiles")),
     };
     let normalized_speech_language = normalize_speech_language_for_model(
         &settings.current_model_engine,
@@ -606,6 +1693,31 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
             ptt_hotkey: ptt_hotkey_for_validation.map(str::to_string),
         },
     )?;
+    validate_profile_models(
+        &app,
+        &settings.profiles,
+        &app_profiles_from_store(store.get("profiles")),
+    )
+    .await?;
+    let old_engine = store
+        .get("current_model_engine")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_else(|| Settings::default().current_model_engine);
+    let old_speech_language = store
+        .get("speech_language")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_else(|| Settings::default().speech_language);
+    let stored_last_multilingual_language = store
+        .get("last_multilingual_language")
+        .and_then(|v| v.as_str().map(|s| s.to_string()));
+    let (switched_speech_language, last_multilingual_language) = language_for_model_switch(
+        model_requires_english_speech(&old_engine, &old_model),
+        model_requires_english_speech(&settings.current_model_engine, &settings.current_model),
+        &old_speech_language,
+        &settings.speech_language,
+        stored_last_multilingual_language.as_deref(),
+    );
+
     store.set("hotkey", json!(settings.hotkey));
     store.set("current_model", json!(settings.current_model));
     store.set("current_model_engine", json!(settings.current_model_engine));
@@ -613,7 +1725,11 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
     let validated_speech_language = normalize_speech_language_for_model(
         &settings.current_model_engine,
         &settings.current_model,
-        &settings.speech_language,
+        &switched_speech_language,
+    );
+    store.set(
+        "last_multilingual_language",
+        json!(last_multilingual_language),
     );
     let normalized_transcription_task =
         normalize_transcription_task(Some(&settings.transcription_task), false);
@@ -624,6 +1740,12 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
     store.set("speech_language", json!(validated_speech_language));
     store.set("transcription_task", json!(normalized_transcription_task));
     store.set("final_text_language", json!(normalized_final_text_language));
+    store.set(
+        "translate_to_language",
+        json!(normalize_translate_to_language(
+            &settings.translate_to_language
+        )),
+    );
     // Keep legacy keys in sync during migration.
     store.set("language", json!(validated_speech_language));
     store.set(
@@ -690,6 +1812,242 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
         "transcription_acceleration",
         json!(&normalized_transcription_acceleration),
     );
+    store.set("use_recent_context", json!(settings.use_recent_context));
+    store.set(
+        "recent_context_count",
+        json!(settings
+            .recent_context_count
+            .clamp(MIN_RECENT_CONTEXT_COUNT, MAX_RECENT_CONTEXT_COUNT)),
+    );
+    store.set(
+        "recording_start_debounce_ms",
+        json!(settings
+            .recording_start_debounce_ms
+            .min(MAX_RECORDING_START_DEBOUNCE_MS)),
+    );
+    store.set(
+        "min_recording_duration_ms",
+        json!(settings
+            .min_recording_duration_ms
+            .min(MAX_MIN_RECORDING_DURATION_MS)),
+    );
+    store.set(
+        "min_recording_toggle_s",
+        json!(settings
+            .min_recording_toggle_s
+            .map(|s| normalize_min_recording_mode_s(s as f64))),
+    );
+    store.set(
+        "min_recording_ptt_s",
+        json!(settings
+            .min_recording_ptt_s
+            .map(|s| normalize_min_recording_mode_s(s as f64))),
+    );
+    store.set(
+        "ptt_min_hold_ms",
+        json!(settings.ptt_min_hold_ms.min(MAX_PTT_MIN_HOLD_MS)),
+    );
+    store.set("keep_engine_warm", json!(settings.keep_engine_warm));
+    store.set(
+        "parakeet_idle_timeout_minutes",
+        json!(settings
+            .parakeet_idle_timeout_minutes
+            .min(MAX_PARAKEET_IDLE_TIMEOUT_MINUTES)),
+    );
+    store.set("strip_ai_preamble", json!(settings.strip_ai_preamble));
+    store.set(
+        "pill_show_delay_ms",
+        json!(settings.pill_show_delay_ms.min(MAX_PILL_SHOW_DELAY_MS)),
+    );
+    store.set(
+        "pill_hide_delay_ms",
+        json!(settings.pill_hide_delay_ms.min(MAX_PILL_HIDE_DELAY_MS)),
+    );
+    store.set(
+        "pill_hide_delay_error_ms",
+        json!(settings
+            .pill_hide_delay_error_ms
+            .min(MAX_PILL_HIDE_DELAY_MS)),
+    );
+    store.set(
+        "pill_hide_delay_empty_ms",
+        json!(settings
+            .pill_hide_delay_empty_ms
+            .min(MAX_PILL_HIDE_DELAY_MS)),
+    );
+    store.set(
+        "max_queued_transcriptions",
+        json!(normalize_max_queued_transcriptions(
+            settings.max_queued_transcriptions as u64
+        )),
+    );
+    store.set("scratchpad_mode", json!(settings.scratchpad_mode));
+    store.set("transcription_sinks", json!(settings.transcription_sinks));
+    store.set(
+        "insertion_retry_count",
+        json!(settings
+            .insertion_retry_count
+            .min(MAX_INSERTION_RETRY_COUNT)),
+    );
+    store.set(
+        "insertion_retry_delay_ms",
+        json!(settings
+            .insertion_retry_delay_ms
+            .min(MAX_INSERTION_RETRY_DELAY_MS)),
+    );
+    store.set(
+        "insertion_method",
+        json!(normalize_insertion_method(Some(&settings.insertion_method))),
+    );
+    store.set(
+        "typing_delay_ms",
+        json!(settings.typing_delay_ms.min(MAX_TYPING_DELAY_MS)),
+    );
+    store.set("max_history_entries", json!(settings.max_history_entries));
+    store.set(
+        "duplicate_window_seconds",
+        json!(normalize_duplicate_window_seconds(
+            settings.duplicate_window_seconds as u64
+        )),
+    );
+    store.set("soniox_realtime", json!(settings.soniox_realtime));
+    store.set(
+        "soniox_fallback_local",
+        json!(settings.soniox_fallback_local),
+    );
+    store.set("local_control_socket", json!(settings.local_control_socket));
+    store.set(
+        "assemblyai_speaker_labels",
+        json!(settings.assemblyai_speaker_labels),
+    );
+    store.set("loudness_normalize", json!(settings.loudness_normalize));
+    store.set("auto_trim_silence", json!(settings.auto_trim_silence));
+    store.set(
+        "trim_silence_threshold_db",
+        json!(normalize_trim_silence_threshold_db(
+            settings.trim_silence_threshold_db as f64
+        )),
+    );
+    store.set(
+        "trim_max_gap_ms",
+        json!(normalize_trim_max_gap_ms(settings.trim_max_gap_ms as u64)),
+    );
+    store.set("noise_suppression", json!(settings.noise_suppression));
+    store.set("auto_detect_language", json!(settings.auto_detect_language));
+    store.set(
+        "silence_auto_stop_enabled",
+        json!(settings.silence_auto_stop_enabled),
+    );
+    store.set(
+        "silence_threshold_db",
+        json!(normalize_silence_threshold_db(
+            settings.silence_threshold_db as f64
+        )),
+    );
+    store.set(
+        "silence_duration_ms",
+        json!(settings
+            .silence_duration_ms
+            .map(|ms| normalize_silence_duration_ms(ms as u64))),
+    );
+    store.set(
+        "mic_gain_db",
+        json!(normalize_mic_gain_db(settings.mic_gain_db as f64)),
+    );
+    store.set(
+        "clipping_warning_enabled",
+        json!(settings.clipping_warning_enabled),
+    );
+    store.set(
+        "allow_recording_when_muted",
+        json!(settings.allow_recording_when_muted),
+    );
+    store.set(
+        "preferred_sample_rate",
+        json!(normalize_preferred_sample_rate(
+            settings.preferred_sample_rate as u64
+        )),
+    );
+    store.set(
+        "channel_select",
+        json!(normalize_channel_select(&settings.channel_select)),
+    );
+    store.set(
+        "max_recording_duration_s",
+        json!(normalize_max_recording_duration_s(
+            settings.max_recording_duration_s as u64
+        )),
+    );
+    store.set(
+        "low_confidence_threshold",
+        json!(normalize_low_confidence_threshold(
+            settings.low_confidence_threshold as f64
+        )),
+    );
+    store.set(
+        "whisper_initial_prompt",
+        json!(normalize_whisper_initial_prompt(
+            &settings.whisper_initial_prompt
+        )),
+    );
+    store.set(
+        "whisper_beam_size",
+        json!(settings
+            .whisper_beam_size
+            .map(|beams| normalize_whisper_beam_size(beams as u64))),
+    );
+    store.set(
+        "whisper_temperature_fallback",
+        json!(settings.whisper_temperature_fallback),
+    );
+    store.set(
+        "openai_stt_model",
+        json!(crate::cloud_stt::normalize_openai_stt_model(
+            &settings.openai_stt_model
+        )),
+    );
+    store.set("trim_whitespace", json!(settings.trim_whitespace));
+    store.set("collapse_spaces", json!(settings.collapse_spaces));
+    store.set(
+        "auto_capitalize_first",
+        json!(settings.auto_capitalize_first),
+    );
+    store.set(
+        "ensure_trailing_space",
+        json!(settings.ensure_trailing_space),
+    );
+    store.set(
+        "update_check_interval_hours",
+        json!(normalize_update_check_interval_hours(
+            settings.update_check_interval_hours as u64
+        )),
+    );
+    store.set(
+        "auto_engine_by_network",
+        json!(settings.auto_engine_by_network),
+    );
+    store.set(
+        "offline_fallback_model",
+        json!(settings.offline_fallback_model),
+    );
+    store.set(
+        "offline_fallback_engine",
+        json!(settings.offline_fallback_engine),
+    );
+    store.set("insert_streaming", json!(settings.insert_streaming));
+    store.set(
+        "upload_segment_seconds",
+        json!(settings
+            .upload_segment_seconds
+            .map(|seconds| normalize_upload_segment_seconds(seconds as u64))),
+    );
+    store.set(
+        "recording_indicator",
+        json!(normalize_recording_indicator(Some(
+            &settings.recording_indicator
+        ))),
+    );
+    store.set("profiles", json!(settings.profiles));
 
     // Network sharing settings
     if let Some(port) = settings.sharing_port {
@@ -707,10 +2065,19 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
 
     // Recording persistence settings
     store.set("save_recordings", json!(settings.save_recordings));
+    store.set(
+        "recordings_dir",
+        json!(normalize_recordings_dir(&settings.recordings_dir)),
+    );
+    store.set("keep_raw_recordings", json!(settings.keep_raw_recordings));
     store.set(
         "recording_retention_days",
         recording_retention_days_to_value(settings.recording_retention_days),
     );
+    store.set(
+        "recording_format",
+        json!(crate::ffmpeg::RecordingFormat::from_setting(&settings.recording_format).as_str()),
+    );
 
     // Save pill position if provided
     if let Some((x, y)) = settings.pill_position {
@@ -730,6 +2097,7 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
     }
 
     crate::trigger::engine_host::rebuild_engine_bindings(&app);
+    crate::remote::control::apply_setting(&app, settings.local_control_socket);
 
     if old_transcription_acceleration != normalized_transcription_acceleration {
         log::info!(
@@ -1173,9 +2541,6 @@ pub async fn set_model_from_tray(app: AppHandle, model_name: String) -> Result<(
         }
     }
 
-    // Get current settings
-    let mut settings = get_settings(app.clone()).await?;
-
     let engine = if let Some(p) = crate::cloud_stt::CloudProvider::from_id(&model_name) {
         p.id().to_string()
     } else {
@@ -1205,21 +2570,13 @@ pub async fn set_model_from_tray(app: AppHandle, model_name: String) -> Result<(
         }
     };
 
-    // Update the model
-    settings.current_model = model_name.clone();
-    settings.current_model_engine = engine.clone();
-    if model_requires_english_speech(&engine, &model_name) {
-        settings.speech_language = "en".to_string();
-    }
-    // Save settings (this will also preload the model)
-    save_settings(app.clone(), settings).await?;
+    // Switch model and engine together (this also preloads the model and
+    // refreshes the tray menu)
+    set_active_model(app.clone(), model_name.clone(), engine.clone()).await?;
 
     // Keep a running sharing server truthful after the selected model changes
     sync_running_sharing_server_to_model(&app, &model_name, &engine).await?;
 
-    // Update the tray menu to reflect the new selection
-    update_tray_menu(app.clone()).await?;
-
     // Emit event to update UI only after successful tray menu update
     if let Err(e) = app.emit(
         "model-changed",
@@ -1236,6 +2593,70 @@ pub async fn set_model_from_tray(app: AppHandle, model_name: String) -> Result<(
     Ok(())
 }
 
+/// Check that `name` belongs to `engine` and return the canonical engine id.
+/// Local engines need the model in their registry; a cloud engine's only
+/// model is the provider id itself.
+pub fn validate_model_engine_pairing(
+    name: &str,
+    engine: &str,
+    whisper_has: bool,
+    parakeet_has: bool,
+) -> Result<String, String> {
+    if name.trim().is_empty() {
+        return Err("Model name is required".to_string());
+    }
+    let engine = engine.trim().to_lowercase();
+    match engine.as_str() {
+        "whisper" if whisper_has => Ok(engine),
+        "whisper" => Err(format!("'{}' is not a Whisper model", name)),
+        "parakeet" if parakeet_has => Ok(engine),
+        "parakeet" => Err(format!("'{}' is not a Parakeet model", name)),
+        _ => match crate::cloud_stt::CloudProvider::from_id(&engine) {
+            Some(provider) if crate::cloud_stt::CloudProvider::from_id(name) == Some(provider) => {
+                Ok(provider.id().to_string())
+            }
+            Some(provider) => Err(format!(
+                "'{}' is not a {} model",
+                name,
+                provider.display_name()
+            )),
+            None => Err(format!("Unknown engine '{}'", engine)),
+        },
+    }
+}
+
+/// Switch the active model and engine in a single settings write, so the
+/// pair is never seen half-updated. Saving invalidates the recording config
+/// cache and emits `settings-changed`.
+#[tauri::command]
+pub async fn set_active_model(app: AppHandle, name: String, engine: String) -> Result<(), String> {
+    let name = name.trim().to_string();
+    let whisper_has = {
+        let whisper_state = app.state::<tauri::async_runtime::RwLock<WhisperManager>>();
+        let guard = whisper_state.read().await;
+        guard.get_models_status().contains_key(&name)
+    };
+    let parakeet_has = app
+        .state::<ParakeetManager>()
+        .list_models()
+        .into_iter()
+        .any(|m| m.name == name);
+    let engine = validate_model_engine_pairing(&name, &engine, whisper_has, parakeet_has)?;
+
+    let mut settings = get_settings(app.clone()).await?;
+    settings.current_model = name.clone();
+    settings.current_model_engine = engine.clone();
+    if model_requires_english_speech(&engine, &name) {
+        settings.speech_language = "en".to_string();
+    }
+    // Save settings (this will also preload the model and restore the last
+    // multilingual language when leaving an English-only model)
+    save_settings(app.clone(), settings).await?;
+
+    // Refresh even when only the engine changed
+    update_tray_menu(app.clone()).await
+}
+
 /// Increment the tray menu generation and return the new value.
 /// Used by callers who want to spawn background updates.
 pub fn next_tray_menu_generation() -> u64 {
@@ -1500,11 +2921,87 @@ pub async fn test_transcription_acceleration(
 #[cfg(test)]
 mod tests {
     use super::{
-        get_autostart_status, recording_retention_days_from_legacy_count,
-        recording_retention_days_to_value, resolve_pill_indicator_mode, set_autostart,
+        app_profiles_from_store, get_autostart_status, layer_profiles,
+        profiles_with_changed_models, recording_retention_days_from_legacy_count,
+        recording_retention_days_to_value, resolve_app_profile, resolve_pill_indicator_mode,
+        set_autostart, AppProfile,
     };
     use serde_json::json;
 
+    #[test]
+    fn app_profiles_match_bundle_ids_and_exe_names_case_insensitively() {
+        let profiles = app_profiles_from_store(Some(json!([
+            { "app_match": "com.microsoft.VSCode", "model": "base.en", "engine": "whisper" },
+            { "app_match": "OUTLOOK.EXE", "language": "de", "ai_enabled": true },
+            { "app_match": "  " }
+        ])));
+        assert_eq!(profiles.len(), 2);
+
+        let ide = resolve_app_profile(&profiles, "com.microsoft.vscode").unwrap();
+        assert_eq!(ide.model, "base.en");
+        assert_eq!(ide.ai_enabled, None);
+
+        let mail = resolve_app_profile(&profiles, "outlook").unwrap();
+        assert_eq!(mail.language, "de");
+        assert_eq!(mail.ai_enabled, Some(true));
+
+        assert!(resolve_app_profile(&profiles, "Slack.exe").is_none());
+        assert!(app_profiles_from_store(Some(json!("not a list"))).is_empty());
+    }
+
+    #[test]
+    fn only_profiles_with_new_or_changed_models_are_checked_on_save() {
+        let profile = |app_match: &str, model: &str| AppProfile {
+            app_match: app_match.to_string(),
+            model: model.to_string(),
+            engine: "whisper".to_string(),
+            ..AppProfile::default()
+        };
+        let stored = vec![profile("com.microsoft.VSCode", "base.en")];
+        let profiles = vec![
+            profile("com.microsoft.VSCode", "base.en"),
+            profile("com.apple.mail", "small"),
+            profile("Slack.exe", ""),
+        ];
+
+        let changed: Vec<&str> = profiles_with_changed_models(&profiles, &stored)
+            .into_iter()
+            .map(|profile| profile.app_match.as_str())
+            .collect();
+        assert_eq!(changed, vec!["com.apple.mail"]);
+
+        let switched = vec![profile("com.microsoft.VSCode", "large-v3")];
+        assert_eq!(profiles_with_changed_models(&switched, &stored).len(), 1);
+    }
+
+    #[test]
+    fn layered_profile_prefers_the_top_fields_that_are_set() {
+        let app = AppProfile {
+            app_match: "com.tinyspeck.slackmacgap".to_string(),
+            model: "base.en".to_string(),
+            engine: "whisper".to_string(),
+            language: "en".to_string(),
+            ai_enabled: Some(true),
+            clipboard_only: Some(true),
+        };
+        let shortcut = AppProfile {
+            app_match: "shortcut:german".to_string(),
+            language: "de".to_string(),
+            ..AppProfile::default()
+        };
+
+        let merged = layer_profiles(Some(app.clone()), Some(shortcut.clone())).unwrap();
+        assert_eq!(merged.language, "de");
+        assert_eq!(merged.model, "base.en");
+        assert_eq!(merged.engine, "whisper");
+        assert_eq!(merged.ai_enabled, Some(true));
+        assert_eq!(merged.clipboard_only, Some(true));
+
+        assert_eq!(layer_profiles(None, Some(shortcut.clone())), Some(shortcut));
+        assert_eq!(layer_profiles(Some(app.clone()), None), Some(app));
+        assert_eq!(layer_profiles(None, None), None);
+    }
+
     #[test]
     fn resolve_pill_indicator_mode_prefers_new_value() {
         let resolved = resolve_pill_indicator_mode(
@@ -11,12 +11,27 @@ use crate::commands::key_normalizer::{
     normalize_shortcut_keys, validate_key_combination,
     validate_key_combination_allowing_safe_single_key,
 };
+use crate::commands::settings::AppProfile;
 use crate::AppState;
 
 const SHORTCUT_BINDINGS_KEY: &str = "shortcut_bindings";
 
 pub const MAX_SINGLE_KEY_BINDINGS: usize = 5;
 
+/// Combos probed by `suggest_available_shortcuts`, most conventional first.
+const SUGGESTED_SHORTCUTS: &[&str] = &[
+    "CommandOrControl+Shift+Space",
+    "CommandOrControl+Alt+Space",
+    "Alt+Shift+Space",
+    "CommandOrControl+Shift+R",
+    "CommandOrControl+Alt+R",
+    "CommandOrControl+Shift+D",
+    "CommandOrControl+Alt+D",
+    "Alt+Shift+R",
+    "CommandOrControl+Shift+F9",
+    "CommandOrControl+Shift+F12",
+];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ShortcutAction {
@@ -89,6 +104,44 @@ pub struct ShortcutBinding {
     pub trigger_kind: TriggerKind,
     #[serde(default)]
     pub modifier: Option<ModifierSpec>,
+    #[serde(flatten)]
+    pub overrides: RecordingOverrides,
+}
+
+/// Language/model a recording binding starts with instead of the global
+/// settings, e.g. one shortcut for English and another for German. Empty
+/// fields keep the global setting.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordingOverrides {
+    #[serde(default)]
+    pub language: String,
+    #[serde(default)]
+    pub model: String,
+    // Engine of `model`: "whisper", "parakeet", or a cloud provider id
+    #[serde(default)]
+    pub engine: String,
+}
+
+impl RecordingOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.language.trim().is_empty() && self.model.trim().is_empty()
+    }
+
+    /// The overrides as a recording profile, or `None` when nothing is set.
+    pub fn to_profile(&self, binding_id: &str) -> Option<AppProfile> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(AppProfile {
+            app_match: format!("shortcut:{}", binding_id),
+            model: self.model.trim().to_string(),
+            engine: self.engine.trim().to_string(),
+            language: self.language.trim().to_string(),
+            ai_enabled: None,
+            clipboard_only: None,
+            whisper_initial_prompt: String::new(),
+        })
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -156,6 +209,34 @@ pub struct ExistingShortcutStrings {
     pub ptt_hotkey: Option<String>,
 }
 
+/// Result of probing a shortcut against the bindings Voicetypr already owns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ShortcutAvailability {
+    /// The shortcut after `normalize_shortcut_keys`
+    pub shortcut: String,
+    pub available: bool,
+    /// What already uses it, when unavailable
+    pub conflict: Option<String>,
+}
+
+#[tauri::command]
+pub fn check_shortcut_available(
+    app: AppHandle,
+    shortcut: String,
+) -> Result<ShortcutAvailability, String> {
+    let settings = load_shortcut_settings(&app)?;
+    shortcut_availability(&shortcut, &settings, &current_existing_shortcuts(&app))
+}
+
+#[tauri::command]
+pub fn suggest_available_shortcuts(app: AppHandle) -> Result<Vec<String>, String> {
+    let settings = load_shortcut_settings(&app)?;
+    Ok(available_suggested_shortcuts(
+        &settings,
+        &current_existing_shortcuts(&app),
+    ))
+}
+
 #[tauri::command]
 pub fn get_shortcut_settings(app: AppHandle) -> Result<ShortcutSettings, String> {
     load_shortcut_settings(&app)
@@ -205,6 +286,81 @@ pub fn normalized_custom_shortcut_conflict(
         .map(|binding| binding.id.clone())
 }
 
+/// Probe `shortcut` the way the engine would install it: normalize, validate,
+/// resolve through `parse_combo`, then compare the resolved trigger against
+/// the primary hotkey, the push-to-talk hotkey and every enabled combo
+/// binding. Nothing is stored or applied. The engine reads raw key events
+/// rather than registering with the OS, so another app's hotkey cannot be
+/// detected here.
+pub fn shortcut_availability(
+    shortcut: &str,
+    settings: &ShortcutSettings,
+    existing: &ExistingShortcutStrings,
+) -> Result<ShortcutAvailability, String> {
+    if shortcut.trim().is_empty() || shortcut.len() > 100 {
+        return Err("Invalid shortcut format".to_string());
+    }
+
+    let normalized = normalize_shortcut_keys(shortcut.trim());
+    validate_key_combination_allowing_safe_single_key(&normalized)
+        .map_err(|e| format!("Invalid key combination: {}", e))?;
+    crate::trigger::mapping::parse_combo(&normalized)
+        .map_err(|_| "Invalid shortcut format".to_string())?;
+
+    let conflict = shortcut_conflict(&normalized, settings, existing);
+    Ok(ShortcutAvailability {
+        shortcut: normalized,
+        available: conflict.is_none(),
+        conflict,
+    })
+}
+
+/// The entries of `SUGGESTED_SHORTCUTS` that nothing is bound to yet.
+pub fn available_suggested_shortcuts(
+    settings: &ShortcutSettings,
+    existing: &ExistingShortcutStrings,
+) -> Vec<String> {
+    SUGGESTED_SHORTCUTS
+        .iter()
+        .filter_map(|shortcut| shortcut_availability(shortcut, settings, existing).ok())
+        .filter(|availability| availability.available)
+        .map(|availability| availability.shortcut)
+        .collect()
+}
+
+fn shortcut_conflict(
+    normalized: &str,
+    settings: &ShortcutSettings,
+    existing: &ExistingShortcutStrings,
+) -> Option<String> {
+    let dedup_key = trigger_dedup_key(normalized);
+    let same_trigger = |other: &str| {
+        let other = normalize_shortcut_keys(other.trim());
+        !other.is_empty() && trigger_dedup_key(&other) == dedup_key
+    };
+
+    if existing.primary_hotkey.as_deref().is_some_and(same_trigger) {
+        return Some("the recording hotkey".to_string());
+    }
+    if existing.ptt_hotkey.as_deref().is_some_and(same_trigger) {
+        return Some("the push-to-talk hotkey".to_string());
+    }
+
+    settings
+        .bindings
+        .iter()
+        .filter(|binding| binding.enabled && binding.trigger_kind == TriggerKind::Combo)
+        .find(|binding| same_trigger(&binding.shortcut))
+        .map(|binding| {
+            let label = shortcut_action_definitions()
+                .into_iter()
+                .find(|definition| definition.action == binding.action)
+                .map(|definition| definition.label)
+                .unwrap_or("another shortcut");
+            format!("the \"{}\" shortcut", label)
+        })
+}
+
 pub fn action_preset(action: ShortcutAction) -> Option<EnhancementPreset> {
     match action {
         ShortcutAction::SetPersonalDictation => Some(EnhancementPreset::PersonalDictation),
@@ -244,9 +400,13 @@ pub async fn set_formatting_preset(
     preset: EnhancementPreset,
 ) -> Result<(), String> {
     let store = app.store("settings").map_err(|e| e.to_string())?;
+    let current = crate::ai::prompts::enhancement_options_for_ai_enabled(
+        store.get("enhancement_options").as_ref(),
+        true,
+    )?;
     store.set(
         "enhancement_options",
-        serde_json::to_value(EnhancementOptions { preset })
+        serde_json::to_value(EnhancementOptions { preset, ..current })
             .map_err(|e| format!("Failed to serialize enhancement options: {}", e))?,
     );
     store.save().map_err(|e| e.to_string())?;
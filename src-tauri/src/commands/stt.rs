@@ -1,19 +1,163 @@
+use crate::cloud_stt::{CloudProvider, ExtraParams, BASE_URL_OVERRIDES_KEY, EXTRA_PARAMS_KEY};
+use serde::Serialize;
 use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+/// Effective endpoint configuration for one cloud STT provider.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SttEndpoint {
+    pub provider: String,
+    pub base_url: String,
+    pub default_base_url: String,
+    pub is_custom: bool,
+}
+
+fn parse_provider(provider: &str) -> Result<CloudProvider, String> {
+    CloudProvider::from_id(provider)
+        .ok_or_else(|| format!("Unknown cloud STT provider: {}", provider))
+}
 
 #[tauri::command]
 #[allow(non_snake_case)]
 pub async fn validate_stt_key(
+    app: AppHandle,
     provider: String,
     api_key: Option<String>,
     apiKey: Option<String>,
 ) -> Result<(), String> {
     let key = apiKey.or(api_key).unwrap_or_default();
-    let p = crate::cloud_stt::CloudProvider::from_id(&provider)
-        .ok_or_else(|| format!("Unknown cloud STT provider: {}", provider))?;
-    p.validate_key(&key).await
+    let p = parse_provider(&provider)?;
+    p.validate_key_at(&p.base_url(&app), &key).await
+}
+
+/// Drop anything cached about a provider's key after it is saved or removed.
+#[tauri::command]
+pub async fn clear_stt_key_cache(_app: AppHandle, provider: String) -> Result<(), String> {
+    if parse_provider(&provider)? == CloudProvider::Soniox {
+        crate::commands::audio::clear_soniox_key_check();
+    }
+    Ok(())
 }
 
 #[tauri::command]
-pub async fn clear_stt_key_cache(_app: AppHandle, _provider: String) -> Result<(), String> {
+pub async fn get_stt_endpoint(app: AppHandle, provider: String) -> Result<SttEndpoint, String> {
+    let p = parse_provider(&provider)?;
+    let custom = p.base_url_override(&app);
+    Ok(SttEndpoint {
+        provider: p.id().to_string(),
+        is_custom: custom.is_some(),
+        base_url: custom.unwrap_or_else(|| p.default_base_url().to_string()),
+        default_base_url: p.default_base_url().to_string(),
+    })
+}
+
+/// Persist a base URL override for a cloud STT provider. A missing or blank
+/// `base_url` restores the built-in endpoint.
+#[tauri::command]
+pub async fn set_stt_endpoint(
+    app: AppHandle,
+    provider: String,
+    base_url: Option<String>,
+) -> Result<(), String> {
+    let p = parse_provider(&provider)?;
+    let normalized = match base_url.as_deref().map(str::trim) {
+        Some(raw) if !raw.is_empty() => Some(crate::cloud_stt::normalize_base_url(raw)?),
+        _ => None,
+    };
+
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    let mut overrides = store
+        .get(BASE_URL_OVERRIDES_KEY)
+        .and_then(|v| v.as_object().cloned())
+        .unwrap_or_default();
+    match normalized {
+        // Storing the default verbatim would only pin it; treat it as a reset.
+        Some(url) if url != p.default_base_url() => {
+            overrides.insert(p.id().to_string(), serde_json::Value::String(url));
+        }
+        _ => {
+            overrides.remove(p.id());
+        }
+    }
+    store.set(BASE_URL_OVERRIDES_KEY, serde_json::Value::Object(overrides));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save STT endpoint: {}", e))?;
+    if p == CloudProvider::Soniox {
+        crate::commands::audio::clear_soniox_key_check();
+    }
+    log::info!("Updated {} STT endpoint", p.display_name());
     Ok(())
 }
+
+#[tauri::command]
+pub async fn get_stt_extra_params(app: AppHandle, provider: String) -> Result<ExtraParams, String> {
+    let p = parse_provider(&provider)?;
+    Ok(p.extra_params(&app))
+}
+
+/// Persist extra request parameters merged into a cloud STT provider's
+/// transcription requests. A missing or empty object clears them.
+#[tauri::command]
+pub async fn set_stt_extra_params(
+    app: AppHandle,
+    provider: String,
+    params: Option<serde_json::Value>,
+) -> Result<(), String> {
+    let p = parse_provider(&provider)?;
+    let params = match params {
+        Some(value) => p.validate_extra_params(&value)?,
+        None => ExtraParams::new(),
+    };
+
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    let mut all = store
+        .get(EXTRA_PARAMS_KEY)
+        .and_then(|v| v.as_object().cloned())
+        .unwrap_or_default();
+    if params.is_empty() {
+        all.remove(p.id());
+    } else {
+        all.insert(p.id().to_string(), serde_json::Value::Object(params));
+    }
+    store.set(EXTRA_PARAMS_KEY, serde_json::Value::Object(all));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save STT extra parameters: {}", e))?;
+    log::info!("Updated {} STT extra parameters", p.display_name());
+    Ok(())
+}
+
+/// Check that a cloud STT endpoint is reachable and accepts the API key.
+///
+/// `base_url` defaults to the configured endpoint and `api_key` to the key in
+/// the secure store, so the UI can test either a draft or the saved setup.
+#[tauri::command]
+pub async fn test_stt_endpoint(
+    app: AppHandle,
+    provider: String,
+    base_url: Option<String>,
+    api_key: Option<String>,
+) -> Result<(), String> {
+    let p = parse_provider(&provider)?;
+    let base_url = match base_url.as_deref().map(str::trim) {
+        Some(raw) if !raw.is_empty() => crate::cloud_stt::normalize_base_url(raw)?,
+        _ => p.base_url(&app),
+    };
+    let key = match api_key.filter(|k| !k.trim().is_empty()) {
+        Some(key) => key,
+        None => crate::secure_store::secure_get(&app, p.key_name())?
+            .ok_or_else(|| format!("{} API key not set", p.display_name()))?,
+    };
+
+    p.validate_key_at(&base_url, &key).await.map_err(|error| {
+        log::error!(
+            "{} STT endpoint test failed: base_url={} error={}",
+            p.display_name(),
+            base_url,
+            error
+        );
+        error
+    })
+}
@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+/// Settings-store key holding the saved transcription templates.
+const TEMPLATES_KEY: &str = "transcription_templates";
+/// Placeholder filled by `insert_template` when a template doesn't name one.
+pub const DEFAULT_FILL_PLACEHOLDER: &str = "text";
+const MAX_TEMPLATES: usize = 100;
+const MAX_TEMPLATE_BODY_CHARS: usize = 20_000;
+
+/// A named block of structured text with `{{placeholder}}` slots.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptionTemplate {
+    pub id: String,
+    pub name: String,
+    pub body: String,
+    /// Slot that receives the latest transcription on insert.
+    #[serde(default = "default_fill_placeholder")]
+    pub fill_placeholder: String,
+}
+
+fn default_fill_placeholder() -> String {
+    DEFAULT_FILL_PLACEHOLDER.to_string()
+}
+
+fn placeholder_token(name: &str) -> String {
+    format!("{{{{{}}}}}", name)
+}
+
+fn normalize_placeholder_name(raw: Option<&str>) -> Result<String, String> {
+    let name = raw
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or(DEFAULT_FILL_PLACEHOLDER);
+    if !name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(format!(
+            "Placeholder '{}' may only contain letters, digits, '_' or '-'",
+            name
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// Names of all `{{placeholder}}` slots in a template body, in order of first use.
+pub fn template_placeholders(body: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = body;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let name = after[..end].trim();
+        if !name.is_empty() && !names.iter().any(|existing| existing == name) {
+            names.push(name.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+    names
+}
+
+/// Replace every occurrence of one placeholder, leaving the other slots intact
+/// so they can be filled by later dictation.
+pub fn render_template(body: &str, placeholder: &str, text: &str) -> String {
+    let mut rendered = String::with_capacity(body.len() + text.len());
+    let mut rest = body;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        rendered.push_str(&rest[..start]);
+        if after[..end].trim() == placeholder {
+            rendered.push_str(text);
+        } else {
+            rendered.push_str(&rest[start..start + end + 4]);
+        }
+        rest = &after[end + 2..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+fn load_templates(app: &AppHandle) -> Result<Vec<TranscriptionTemplate>, String> {
+    let store = app
+        .store("settings")
+        .map_err(|e| format!("Failed to access settings: {}", e))?;
+    Ok(store
+        .get(TEMPLATES_KEY)
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default())
+}
+
+fn save_templates(app: &AppHandle, templates: &[TranscriptionTemplate]) -> Result<(), String> {
+    let store = app
+        .store("settings")
+        .map_err(|e| format!("Failed to access settings: {}", e))?;
+    store.set(
+        TEMPLATES_KEY,
+        serde_json::to_value(templates)
+            .map_err(|e| format!("Failed to serialize templates: {}", e))?,
+    );
+    store
+        .save()
+        .map_err(|e| format!("Failed to save templates: {}", e))
+}
+
+#[tauri::command]
+pub async fn list_templates(app: AppHandle) -> Result<Vec<TranscriptionTemplate>, String> {
+    load_templates(&app)
+}
+
+#[tauri::command]
+pub async fn create_template(
+    app: AppHandle,
+    name: String,
+    body: String,
+    fill_placeholder: Option<String>,
+) -> Result<TranscriptionTemplate, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Template name cannot be empty".to_string());
+    }
+    if body.chars().count() > MAX_TEMPLATE_BODY_CHARS {
+        return Err(format!(
+            "Template body exceeds {} characters",
+            MAX_TEMPLATE_BODY_CHARS
+        ));
+    }
+    let fill_placeholder = normalize_placeholder_name(fill_placeholder.as_deref())?;
+    if !template_placeholders(&body).contains(&fill_placeholder) {
+        return Err(format!(
+            "Template body must contain the {} placeholder",
+            placeholder_token(&fill_placeholder)
+        ));
+    }
+
+    let mut templates = load_templates(&app)?;
+    if templates
+        .iter()
+        .any(|template| template.name.eq_ignore_ascii_case(&name))
+    {
+        return Err(format!("A template named '{}' already exists", name));
+    }
+    if templates.len() >= MAX_TEMPLATES {
+        return Err(format!(
+            "Cannot store more than {} templates",
+            MAX_TEMPLATES
+        ));
+    }
+
+    let template = TranscriptionTemplate {
+        id: uuid::Uuid::new_v4().to_string(),
+        name,
+        body,
+        fill_placeholder,
+    };
+    templates.push(template.clone());
+    save_templates(&app, &templates)?;
+    log::info!("Created transcription template '{}'", template.name);
+    Ok(template)
+}
+
+#[tauri::command]
+pub async fn delete_template(app: AppHandle, id: String) -> Result<(), String> {
+    let mut templates = load_templates(&app)?;
+    let before = templates.len();
+    templates.retain(|template| template.id != id);
+    if templates.len() == before {
+        return Err(format!("Template '{}' not found", id));
+    }
+    save_templates(&app, &templates)
+}
+
+/// Insert a template at the cursor with one slot filled.
+///
+/// `text` defaults to the latest completed transcription and `placeholder`
+/// defaults to the template's designated fill slot.
+#[tauri::command]
+pub async fn insert_template(
+    app: AppHandle,
+    id: String,
+    placeholder: Option<String>,
+    text: Option<String>,
+) -> Result<String, String> {
+    let template = load_templates(&app)?
+        .into_iter()
+        .find(|template| template.id == id)
+        .ok_or_else(|| format!("Template '{}' not found", id))?;
+
+    let placeholder = match placeholder {
+        Some(name) => normalize_placeholder_name(Some(&name))?,
+        None => template.fill_placeholder.clone(),
+    };
+    let text = match text {
+        Some(text) => text,
+        None => crate::commands::shortcuts::latest_copyable_transcription_text(&app)
+            .await?
+            .ok_or_else(|| "No transcription available to fill the template".to_string())?,
+    };
+
+    let rendered = render_template(&template.body, &placeholder, text.trim());
+    crate::commands::text::insert_text(app, rendered.clone()).await?;
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placeholders_are_listed_once_in_order() {
+        let body = "Steps: {{ steps }}\nExpected: {{expected}}\nAgain: {{steps}}\n{{}} {{open";
+        assert_eq!(template_placeholders(body), vec!["steps", "expected"]);
+    }
+
+    #[test]
+    fn render_fills_only_the_target_slot() {
+        let body = "Bug: {{text}}\nSeverity: {{severity}}\nSee {{ text }}";
+        assert_eq!(
+            render_template(body, "text", "crash on save"),
+            "Bug: crash on save\nSeverity: {{severity}}\nSee crash on save"
+        );
+    }
+
+    #[test]
+    fn placeholder_names_default_and_reject_punctuation() {
+        assert_eq!(normalize_placeholder_name(None).unwrap(), "text");
+        assert_eq!(normalize_placeholder_name(Some("  ")).unwrap(), "text");
+        assert_eq!(
+            normalize_placeholder_name(Some("repro_steps")).unwrap(),
+            "repro_steps"
+        );
+        assert!(normalize_placeholder_name(Some("a}}b")).is_err());
+    }
+}
@@ -6,16 +6,16 @@ use std::thread;
 use std::time::Duration;
 use tauri_plugin_store::StoreExt;
 
+use crate::text::formatting::{format_output, OutputFormatting};
+
 // rdev keyboard simulation (Linux paste only; macOS uses core-graphics, Windows uses Win32 SendInput).
 #[cfg(target_os = "linux")]
 use rdev::{simulate, EventType, Key as RdevKey, SimulateError};
 
-// Enigo is only used by the Linux paste fallback below.
+// Enigo types the text for the "type" insertion method, and is the Linux paste fallback.
 #[cfg(target_os = "linux")]
-use enigo::{
-    Direction::{Click, Press, Release},
-    Enigo, Key, Keyboard, Settings,
-};
+use enigo::Direction::{Press, Release};
+use enigo::{Direction::Click, Enigo, Key, Keyboard, Settings};
 
 // Global flag to prevent concurrent text insertions
 static IS_INSERTING: AtomicBool = AtomicBool::new(false);
@@ -110,33 +110,317 @@ pub async fn insert_text(app: tauri::AppHandle, text: String) -> Result<(), Stri
     let has_accessibility_permission = true;
 
     // Move to a blocking task since clipboard operations are synchronous
-    let keep_transcription_in_clipboard = {
+    let (keep_transcription_in_clipboard, retry, method, typing_delay, formatting) = {
         let store = app
             .store("settings")
             .map_err(|e| format!("Failed to access settings: {}", e))?;
-        store
+        let keep = store
             .get("keep_transcription_in_clipboard")
             .and_then(|v| v.as_bool())
-            .unwrap_or(false)
+            .unwrap_or(false);
+        let method = store
+            .get("insertion_method")
+            .and_then(|v| v.as_str().map(InsertionMethod::from_setting))
+            .unwrap_or(InsertionMethod::Paste);
+        let typing_delay_ms = store
+            .get("typing_delay_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(crate::commands::settings::MAX_TYPING_DELAY_MS as u64))
+            .unwrap_or(0);
+        (
+            keep,
+            PasteRetry::from_settings(&store),
+            method,
+            Duration::from_millis(typing_delay_ms),
+            OutputFormatting::from_settings(&store),
+        )
     };
 
     tokio::task::spawn_blocking(move || {
-        // Apply trailing sentence space only at the insertion boundary,
-        // so stored transcription history remains clean.
-        let insertable_text = ensure_trailing_sentence_space(&text);
-        // Always use clipboard method for reliability and to prevent duplicate insertion
-        // This function handles both copying to clipboard and pasting at cursor
+        // Apply output formatting and the trailing sentence space only at the
+        // insertion boundary, so stored transcription history remains clean.
+        let insertable_text = ensure_trailing_sentence_space(&format_output(&text, &formatting));
+        if method == InsertionMethod::Type && has_accessibility_permission {
+            match Enigo::new(&Settings::default()) {
+                Ok(mut enigo) => return type_text(&mut enigo, &insertable_text, typing_delay),
+                Err(e) => log::warn!("Keyboard simulation unavailable ({:?}), pasting instead", e),
+            }
+        }
+        // The clipboard path handles both copying to clipboard and pasting at cursor,
+        // and leaves the text on the clipboard when it cannot paste.
         insert_via_clipboard(
             insertable_text,
             has_accessibility_permission,
             Some(app),
             keep_transcription_in_clipboard,
+            retry,
+            (method == InsertionMethod::Auto).then_some(typing_delay),
         )
     })
     .await
     .map_err(|e| format!("Task failed: {}", e))?
 }
 
+/// How `insert_text` delivers final text (the `insertion_method` setting).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertionMethod {
+    /// Clipboard plus a paste keystroke. Fastest, and the default.
+    Paste,
+    /// Simulated keystrokes, for terminals and secure fields that ignore paste.
+    Type,
+    /// Paste, typing the text instead when the paste keystroke fails.
+    Auto,
+}
+
+impl InsertionMethod {
+    pub fn from_setting(value: &str) -> Self {
+        use crate::commands::settings::{INSERTION_METHOD_AUTO, INSERTION_METHOD_TYPE};
+        match value {
+            INSERTION_METHOD_TYPE => Self::Type,
+            INSERTION_METHOD_AUTO => Self::Auto,
+            _ => Self::Paste,
+        }
+    }
+}
+
+/// One piece of simulated typing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TypingStep {
+    Text(String),
+    /// A Return keypress; raw newline characters are not typed reliably.
+    Return,
+}
+
+/// Split `text` into typing steps, turning `\n`, `\r\n` and `\r` into Return
+/// presses. With `per_char`, each character is its own step so a delay can
+/// follow it.
+fn typing_steps(text: &str, per_char: bool) -> Vec<TypingStep> {
+    let mut steps = Vec::new();
+    let mut chunk = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == '\r' || ch == '\n' {
+            if ch == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            if !chunk.is_empty() {
+                steps.push(TypingStep::Text(std::mem::take(&mut chunk)));
+            }
+            steps.push(TypingStep::Return);
+            continue;
+        }
+        chunk.push(ch);
+        if per_char {
+            steps.push(TypingStep::Text(std::mem::take(&mut chunk)));
+        }
+    }
+    if !chunk.is_empty() {
+        steps.push(TypingStep::Text(chunk));
+    }
+    steps
+}
+
+/// Type `text` as keystrokes, pausing `char_delay` after each character when it
+/// is non-zero. Blocking.
+fn type_text(enigo: &mut Enigo, text: &str, char_delay: Duration) -> Result<(), String> {
+    for step in typing_steps(text, !char_delay.is_zero()) {
+        match step {
+            TypingStep::Text(chunk) => enigo
+                .text(&chunk)
+                .map_err(|e| format!("Failed to type text: {:?}", e))?,
+            TypingStep::Return => enigo
+                .key(Key::Return, Click)
+                .map_err(|e| format!("Failed to press Return: {:?}", e))?,
+        }
+        if !char_delay.is_zero() {
+            thread::sleep(char_delay);
+        }
+    }
+    log::info!("Typed text ({} chars)", text.chars().count());
+    Ok(())
+}
+
+/// How dictated text reaches the focused app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertionMode {
+    /// Paste the final text once transcription (and AI formatting) completes.
+    OnComplete,
+    /// Type interim results as they arrive and correct them in place.
+    Streaming,
+}
+
+/// Streaming insertion only applies to engines that emit interim results;
+/// everything else falls back to inserting on completion.
+pub fn resolve_insertion_mode(insert_streaming: bool, engine: &str) -> InsertionMode {
+    let engine_streams = crate::provider_capabilities::capabilities_for_engine(engine)
+        .is_some_and(|capabilities| capabilities.supports_streaming);
+    if insert_streaming && engine_streams {
+        InsertionMode::Streaming
+    } else {
+        InsertionMode::OnComplete
+    }
+}
+
+/// Keystrokes that turn the text already typed into the next result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamingEdit {
+    pub backspaces: usize,
+    pub append: String,
+}
+
+/// Keep the longest shared prefix, erase the rest of `shown`, and type the
+/// remainder of `next`. Counted in chars so multibyte text erases cleanly.
+pub fn plan_streaming_edit(shown: &str, next: &str) -> StreamingEdit {
+    let shared_bytes = shown
+        .char_indices()
+        .zip(next.chars())
+        .find(|((_, shown_char), next_char)| shown_char != next_char)
+        .map(|((index, _), _)| index)
+        .unwrap_or_else(|| shown.len().min(next.len()));
+    // `shared_bytes` is a char boundary in both strings because the prefixes match.
+    StreamingEdit {
+        backspaces: shown[shared_bytes..].chars().count(),
+        append: next[shared_bytes..].to_string(),
+    }
+}
+
+/// Text typed so far by `insert_streaming`, so each interim or final result can
+/// correct the previous one instead of appending a duplicate.
+#[derive(Debug, Default)]
+pub struct StreamingInserter {
+    shown: String,
+}
+
+impl StreamingInserter {
+    /// Replace the typed interim text with `text`. Blocking; call from a
+    /// blocking task like `insert_text` does.
+    pub fn update(&mut self, text: &str, has_accessibility_permission: bool) -> Result<(), String> {
+        if !has_accessibility_permission {
+            return Err("Streaming insertion requires accessibility permission".to_string());
+        }
+        let edit = plan_streaming_edit(&self.shown, text);
+        if edit.backspaces > 0 {
+            send_backspaces(edit.backspaces)?;
+        }
+        if !edit.append.is_empty() {
+            insert_via_clipboard(
+                edit.append,
+                has_accessibility_permission,
+                None,
+                false,
+                PasteRetry::NONE,
+                None,
+            )?;
+        }
+        self.shown = text.to_string();
+        Ok(())
+    }
+
+    /// Correct the interim text to the final result, with the same trailing
+    /// sentence space `insert_text` applies.
+    pub fn finish(
+        &mut self,
+        final_text: &str,
+        has_accessibility_permission: bool,
+    ) -> Result<(), String> {
+        let insertable_text = ensure_trailing_sentence_space(final_text);
+        self.update(&insertable_text, has_accessibility_permission)
+    }
+}
+
+// Interim text typed for the current dictation in streaming mode
+static STREAMING_INSERTER: Mutex<StreamingInserter> = Mutex::new(StreamingInserter {
+    shown: String::new(),
+});
+
+/// Type an interim (`is_final == false`) or final streaming result, correcting
+/// whatever the previous result left at the cursor. A final result ends the
+/// session so the next dictation starts from an empty slate.
+pub async fn insert_streaming_text(text: String, is_final: bool) -> Result<(), String> {
+    if IS_INSERTING.swap(true, Ordering::SeqCst) {
+        log::warn!("Text insertion already in progress, skipping streaming update");
+        return Err("Text insertion already in progress".to_string());
+    }
+    let _guard = InsertionGuard;
+
+    #[cfg(target_os = "macos")]
+    let has_accessibility_permission = {
+        use crate::commands::permissions::check_accessibility_permission;
+        check_accessibility_permission().await?
+    };
+
+    #[cfg(not(target_os = "macos"))]
+    let has_accessibility_permission = true;
+
+    tokio::task::spawn_blocking(move || {
+        let mut inserter = STREAMING_INSERTER
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner());
+        if !is_final {
+            return inserter.update(&text, has_accessibility_permission);
+        }
+        let result = inserter.finish(&text, has_accessibility_permission);
+        *inserter = StreamingInserter::default();
+        result
+    })
+    .await
+    .map_err(|e| format!("Task failed: {}", e))?
+}
+
+fn streaming_text_shown() -> bool {
+    !STREAMING_INSERTER
+        .lock()
+        .unwrap_or_else(|poison| poison.into_inner())
+        .shown
+        .is_empty()
+}
+
+/// Erase interim text typed for a dictation that will not be delivered
+/// (cancelled, discarded or failed), and end the streaming session.
+pub async fn discard_streaming_text() {
+    if !streaming_text_shown() {
+        return;
+    }
+    if let Err(e) = insert_streaming_text(String::new(), false).await {
+        log::warn!("Failed to erase interim streaming text: {}", e);
+    }
+    *STREAMING_INSERTER
+        .lock()
+        .unwrap_or_else(|poison| poison.into_inner()) = StreamingInserter::default();
+}
+
+/// Deliver a completed transcription using the resolved insertion mode. A
+/// streaming dictation that typed no interim text (realtime off or failed)
+/// is inserted normally.
+pub async fn insert_text_with_mode(
+    app: tauri::AppHandle,
+    text: String,
+    mode: InsertionMode,
+) -> Result<(), String> {
+    if mode == InsertionMode::OnComplete || !streaming_text_shown() {
+        return insert_text(app, text).await;
+    }
+    let formatting = {
+        let store = app
+            .store("settings")
+            .map_err(|e| format!("Failed to access settings: {}", e))?;
+        OutputFormatting::from_settings(&store)
+    };
+    insert_streaming_text(format_output(&text, &formatting), true).await
+}
+
+/// Insert the latest completed transcription again, using the current
+/// insertion method. Shows a pill toast when there is nothing to re-insert.
+#[tauri::command]
+pub async fn reinsert_last_transcription(app: tauri::AppHandle) -> Result<(), String> {
+    let Some(text) = crate::commands::shortcuts::latest_copyable_transcription_text(&app).await?
+    else {
+        crate::commands::audio::pill_toast(&app, "No transcription to re-insert yet", 2000);
+        return Err("No transcription to re-insert".to_string());
+    };
+    insert_text(app, text).await
+}
+
 /// Copy plain text to the system clipboard without attempting to paste
 #[tauri::command]
 pub async fn copy_text_to_clipboard(text: String) -> Result<(), String> {
@@ -175,6 +459,42 @@ enum PasteOutcome {
     NoPermission,
 }
 
+/// Extra paste attempts before falling back to leaving text on the clipboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PasteRetry {
+    retries: u32,
+    delay: Duration,
+}
+
+impl PasteRetry {
+    /// Single attempt, for streaming edits that are re-sent on the next result anyway.
+    const NONE: PasteRetry = PasteRetry {
+        retries: 0,
+        delay: Duration::ZERO,
+    };
+
+    fn from_settings(store: &tauri_plugin_store::Store<tauri::Wry>) -> Self {
+        use crate::commands::settings::{
+            DEFAULT_INSERTION_RETRY_COUNT, DEFAULT_INSERTION_RETRY_DELAY_MS,
+            MAX_INSERTION_RETRY_COUNT, MAX_INSERTION_RETRY_DELAY_MS,
+        };
+        let retries = store
+            .get("insertion_retry_count")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_INSERTION_RETRY_COUNT as u64) as u32)
+            .unwrap_or(DEFAULT_INSERTION_RETRY_COUNT);
+        let delay_ms = store
+            .get("insertion_retry_delay_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_INSERTION_RETRY_DELAY_MS as u64))
+            .unwrap_or(DEFAULT_INSERTION_RETRY_DELAY_MS as u64);
+        PasteRetry {
+            retries,
+            delay: Duration::from_millis(delay_ms),
+        }
+    }
+}
+
 const CLIPBOARD_RESTORE_DELAY: Duration = Duration::from_millis(500);
 
 // Platform-specific clipboard settle delay (after set_text, before paste).
@@ -356,6 +676,8 @@ fn insert_via_clipboard(
     has_accessibility_permission: bool,
     app_handle: Option<tauri::AppHandle>,
     keep_transcription_in_clipboard: bool,
+    retry: PasteRetry,
+    type_on_paste_failure: Option<Duration>,
 ) -> Result<(), String> {
     // This function handles both copying text to clipboard AND pasting it at cursor
     // Initialize clipboard
@@ -371,63 +693,36 @@ fn insert_via_clipboard(
             return PasteOutcome::NoPermission;
         }
 
-        // Try to paste using Cmd+V (macOS) with panic protection
-        // Add delay since pill was just hidden
-
-        // First try with rdev, fallback to AppleScript if it fails
-        let rdev_result = try_paste_with_rdev();
-
-        match rdev_result {
-            Ok(_) => {
-                log::info!("Successfully pasted with rdev");
-                PasteOutcome::Pasted
-            }
+        // Focus changes and accessibility hiccups are often transient, so retry
+        // the keystroke before leaving the text on the clipboard.
+        match paste_with_retries(&mut attempt_paste, retry, &mut thread::sleep) {
+            Ok(()) => PasteOutcome::Pasted,
             Err(e) => {
-                log::warn!("rdev paste failed: {}, trying AppleScript fallback", e);
-
-                // Fallback to AppleScript
-                let paste_result =
-                    panic::catch_unwind(AssertUnwindSafe(try_paste_with_applescript));
-
-                match paste_result {
-                    Ok(Ok(_)) => {
-                        log::info!("Successfully pasted with AppleScript");
-                        PasteOutcome::Pasted
-                    }
-                    Ok(Err(e)) => {
-                        log::warn!("AppleScript paste failed: {}, text remains in clipboard", e);
-                        // Notify user through pill toast that paste failed but text is in clipboard
-                        if let Some(app) = &app_handle {
-                            crate::commands::audio::pill_toast_with_suggestion(
-                                app,
-                                "Text copied",
-                                "Grant Accessibility permission to enable auto-paste",
-                                1500,
-                                None,
-                            );
-                        }
-                        // Don't fail - text is still in clipboard for manual paste
-                        PasteOutcome::LeftInClipboard
-                    }
-                    Err(panic_err) => {
-                        log::error!(
-                            "PANIC during paste: {:?}, text remains in clipboard",
-                            panic_err
-                        );
-                        // Notify user through pill toast about the failure
-                        if let Some(app) = &app_handle {
-                            crate::commands::audio::pill_toast_with_suggestion(
-                                app,
-                                "Text copied",
-                                "Grant Accessibility permission to enable auto-paste",
-                                1500,
-                                None,
-                            );
-                        }
-                        // Don't fail - text is still in clipboard for manual paste
-                        PasteOutcome::LeftInClipboard
+                // Nothing was pasted, so typing cannot duplicate the text. Typed
+                // text counts as delivered, restoring the clipboard as a paste would.
+                if let Some(char_delay) = type_on_paste_failure {
+                    log::warn!("{}, typing the text instead", e);
+                    match Enigo::new(&Settings::default())
+                        .map_err(|e| format!("Keyboard simulation unavailable: {:?}", e))
+                        .and_then(|mut enigo| type_text(&mut enigo, &text, char_delay))
+                    {
+                        Ok(()) => return PasteOutcome::Pasted,
+                        Err(type_error) => log::warn!("Typing fallback failed: {}", type_error),
                     }
                 }
+                log::warn!("{}, text remains in clipboard", e);
+                // Notify user through pill toast that paste failed but text is in clipboard
+                if let Some(app) = &app_handle {
+                    crate::commands::audio::pill_toast_with_suggestion(
+                        app,
+                        "Text copied",
+                        "Grant Accessibility permission to enable auto-paste",
+                        1500,
+                        None,
+                    );
+                }
+                // Don't fail - text is still in clipboard for manual paste
+                PasteOutcome::LeftInClipboard
             }
         }
     };
@@ -496,6 +791,54 @@ fn insert_via_clipboard(
     }
 }
 
+/// One paste keystroke: rdev first, then the platform fallback. Panics in the
+/// fallback are caught and reported as a failed attempt.
+fn attempt_paste() -> Result<(), String> {
+    match try_paste_with_rdev() {
+        Ok(_) => {
+            log::info!("Successfully pasted with rdev");
+            Ok(())
+        }
+        Err(e) => {
+            log::warn!("rdev paste failed: {}, trying AppleScript fallback", e);
+            match panic::catch_unwind(AssertUnwindSafe(try_paste_with_applescript)) {
+                Ok(Ok(_)) => {
+                    log::info!("Successfully pasted with AppleScript");
+                    Ok(())
+                }
+                Ok(Err(e)) => Err(format!("AppleScript paste failed: {}", e)),
+                Err(panic_err) => Err(format!("PANIC during paste: {:?}", panic_err)),
+            }
+        }
+    }
+}
+
+/// Run `attempt` once, then up to `retry.retries` more times with `retry.delay`
+/// between tries. Returns the last error once every try has failed.
+fn paste_with_retries(
+    attempt: &mut dyn FnMut() -> Result<(), String>,
+    retry: PasteRetry,
+    sleep: &mut dyn FnMut(Duration),
+) -> Result<(), String> {
+    let mut tries = 0;
+    loop {
+        match attempt() {
+            Ok(()) => return Ok(()),
+            Err(e) if tries >= retry.retries => return Err(e),
+            Err(e) => {
+                tries += 1;
+                log::warn!(
+                    "Paste attempt failed: {}; retrying ({}/{})",
+                    e,
+                    tries,
+                    retry.retries
+                );
+                sleep(retry.delay);
+            }
+        }
+    }
+}
+
 fn try_paste_with_applescript() -> Result<(), String> {
     // Use AppleScript on macOS
     #[cfg(target_os = "macos")]
@@ -749,6 +1092,83 @@ fn paste_windows() -> Result<(), String> {
     ))
 }
 
+#[cfg(target_os = "macos")]
+fn send_backspaces(count: usize) -> Result<(), String> {
+    use core_graphics::event::{CGEvent, CGEventTapLocation};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    // Virtual keycode for Delete (kVK_Delete).
+    const VK_DELETE: u16 = 51;
+
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .map_err(|_| "Failed to create CGEventSource".to_string())?;
+    for _ in 0..count {
+        for key_down in [true, false] {
+            CGEvent::new_keyboard_event(source.clone(), VK_DELETE, key_down)
+                .map_err(|_| "Failed to create Delete key event".to_string())?
+                .post(CGEventTapLocation::HID);
+        }
+        thread::sleep(Duration::from_millis(2));
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn send_backspaces(count: usize) -> Result<(), String> {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYBD_EVENT_FLAGS, KEYEVENTF_KEYUP,
+        VK_BACK,
+    };
+
+    let inputs: Vec<INPUT> = (0..count)
+        .flat_map(|_| [false, true])
+        .map(|up| INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: VK_BACK,
+                    wScan: 0,
+                    dwFlags: if up {
+                        KEYEVENTF_KEYUP
+                    } else {
+                        KEYBD_EVENT_FLAGS(0)
+                    },
+                    time: 0,
+                    dwExtraInfo: 0,
+                },
+            },
+        })
+        .collect();
+    let cb = std::mem::size_of::<INPUT>() as i32;
+
+    // SAFETY: `inputs` is a valid, correctly-sized slice of INPUT for the call.
+    let sent = unsafe { SendInput(&inputs, cb) } as usize;
+    if sent == inputs.len() {
+        Ok(())
+    } else {
+        Err(format!(
+            "SendInput injected {}/{} Backspace events",
+            sent,
+            inputs.len()
+        ))
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn send_backspaces(count: usize) -> Result<(), String> {
+    for _ in 0..count {
+        send_key_event(&EventType::KeyPress(RdevKey::Backspace))
+            .and_then(|_| send_key_event(&EventType::KeyRelease(RdevKey::Backspace)))
+            .map_err(|e| format!("Failed to send Backspace: {:?}", e))?;
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn send_backspaces(_count: usize) -> Result<(), String> {
+    Err("Unsupported platform for streaming insertion".to_string())
+}
+
 #[cfg(target_os = "linux")]
 fn paste_linux() -> Result<(), SimulateError> {
     log::debug!("Starting Linux paste simulation with rdev");
@@ -1038,12 +1458,123 @@ mod clipboard_insertion {
             DeferredRestore::Restore("ORIGINAL".to_string())
         );
     }
+
+    // --- paste_with_retries tests ---
+
+    #[test]
+    fn paste_retries_until_an_attempt_succeeds() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let sleeps = Rc::new(RefCell::new(Vec::new()));
+        let mut attempts = 0;
+        let mut attempt = || {
+            attempts += 1;
+            if attempts < 2 {
+                Err("focus changed".to_string())
+            } else {
+                Ok(())
+            }
+        };
+        let mut sleep = sleep_recorder(events, sleeps.clone());
+        let retry = PasteRetry {
+            retries: 2,
+            delay: Duration::from_millis(150),
+        };
+
+        assert_eq!(paste_with_retries(&mut attempt, retry, &mut sleep), Ok(()));
+        assert_eq!(attempts, 2);
+        assert_eq!(*sleeps.borrow(), vec![Duration::from_millis(150)]);
+    }
+
+    #[test]
+    fn paste_gives_up_after_retries_are_exhausted() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let sleeps = Rc::new(RefCell::new(Vec::new()));
+        let mut attempts = 0;
+        let mut attempt = || {
+            attempts += 1;
+            Err(format!("attempt {attempts} failed"))
+        };
+        let mut sleep = sleep_recorder(events, sleeps.clone());
+        let retry = PasteRetry {
+            retries: 2,
+            delay: Duration::from_millis(100),
+        };
+
+        assert_eq!(
+            paste_with_retries(&mut attempt, retry, &mut sleep),
+            Err("attempt 3 failed".to_string())
+        );
+        assert_eq!(attempts, 3);
+        assert_eq!(sleeps.borrow().len(), 2);
+    }
+
+    #[test]
+    fn paste_without_retries_tries_once() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let sleeps = Rc::new(RefCell::new(Vec::new()));
+        let mut attempts = 0;
+        let mut attempt = || {
+            attempts += 1;
+            Err("failed".to_string())
+        };
+        let mut sleep = sleep_recorder(events, sleeps.clone());
+
+        assert!(paste_with_retries(&mut attempt, PasteRetry::NONE, &mut sleep).is_err());
+        assert_eq!(attempts, 1);
+        assert!(sleeps.borrow().is_empty());
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn insertion_method_parses_setting_values() {
+        assert_eq!(InsertionMethod::from_setting("type"), InsertionMethod::Type);
+        assert_eq!(InsertionMethod::from_setting("auto"), InsertionMethod::Auto);
+        assert_eq!(
+            InsertionMethod::from_setting("paste"),
+            InsertionMethod::Paste
+        );
+        assert_eq!(
+            InsertionMethod::from_setting("bogus"),
+            InsertionMethod::Paste
+        );
+    }
+
+    #[test]
+    fn typing_steps_turn_newlines_into_return_presses() {
+        assert_eq!(
+            typing_steps("Hi ünïcode 👋\nsecond\r\nthird\r", false),
+            vec![
+                TypingStep::Text("Hi ünïcode 👋".to_string()),
+                TypingStep::Return,
+                TypingStep::Text("second".to_string()),
+                TypingStep::Return,
+                TypingStep::Text("third".to_string()),
+                TypingStep::Return,
+            ]
+        );
+        assert_eq!(
+            typing_steps("\n\n", false),
+            vec![TypingStep::Return, TypingStep::Return]
+        );
+    }
+
+    #[test]
+    fn slow_typing_steps_one_character_at_a_time() {
+        assert_eq!(
+            typing_steps("é👋\nx", true),
+            vec![
+                TypingStep::Text("é".to_string()),
+                TypingStep::Text("👋".to_string()),
+                TypingStep::Return,
+                TypingStep::Text("x".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn sentence_end_gets_trailing_space() {
         assert_eq!(
@@ -1198,3 +1729,68 @@ mod tests {
         );
     }
 }
+
+#[cfg(test)]
+mod streaming_insertion {
+    use super::*;
+
+    #[test]
+    fn extends_interim_text_without_erasing() {
+        assert_eq!(
+            plan_streaming_edit("hello", "hello world"),
+            StreamingEdit {
+                backspaces: 0,
+                append: " world".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn corrects_only_the_diverging_tail() {
+        assert_eq!(
+            plan_streaming_edit("I scream for", "Ice cream for"),
+            StreamingEdit {
+                backspaces: 11,
+                append: "ce cream for".to_string(),
+            }
+        );
+        assert_eq!(
+            plan_streaming_edit("naïve caf", "naïve café."),
+            StreamingEdit {
+                backspaces: 0,
+                append: "é.".to_string(),
+            }
+        );
+        assert_eq!(
+            plan_streaming_edit("café au", "café"),
+            StreamingEdit {
+                backspaces: 3,
+                append: String::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn non_streaming_engines_insert_on_complete() {
+        assert_eq!(
+            resolve_insertion_mode(true, "whisper"),
+            InsertionMode::OnComplete
+        );
+        assert_eq!(
+            resolve_insertion_mode(true, "unknown"),
+            InsertionMode::OnComplete
+        );
+        assert_eq!(
+            resolve_insertion_mode(false, "soniox"),
+            InsertionMode::OnComplete
+        );
+    }
+
+    #[test]
+    fn soniox_streams_when_enabled() {
+        assert_eq!(
+            resolve_insertion_mode(true, "soniox"),
+            InsertionMode::Streaming
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tauri_plugin_updater::UpdaterExt;
+
+/// How often the background task re-reads the interval while checks are disabled.
+const DISABLED_POLL_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// Let startup settle before the first background check.
+const INITIAL_CHECK_DELAY: Duration = Duration::from_secs(60);
+
+// Version already announced by a background notification, so it is shown once
+static LAST_NOTIFIED_VERSION: Mutex<Option<String>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCheckResult {
+    pub available: bool,
+    pub current_version: String,
+    pub version: Option<String>,
+    pub notes: Option<String>,
+}
+
+async fn check_updater(app: &AppHandle) -> Result<UpdateCheckResult, String> {
+    let current_version = app.package_info().version.to_string();
+    if crate::commands::distribution::is_store_install() {
+        return Ok(UpdateCheckResult {
+            available: false,
+            current_version,
+            version: None,
+            notes: None,
+        });
+    }
+
+    let update = app
+        .updater()
+        .map_err(|e| format!("Updater unavailable: {}", e))?
+        .check()
+        .await
+        .map_err(|e| format!("Update check failed: {}", e))?;
+
+    Ok(match update {
+        Some(update) => UpdateCheckResult {
+            available: true,
+            current_version,
+            version: Some(update.version),
+            notes: update.body,
+        },
+        None => UpdateCheckResult {
+            available: false,
+            current_version,
+            version: None,
+            notes: None,
+        },
+    })
+}
+
+/// Check the update endpoint from the backend, so the result does not depend on
+/// the main webview being loaded. Installing is still left to the frontend.
+#[tauri::command]
+pub async fn check_for_updates(app: AppHandle) -> Result<UpdateCheckResult, String> {
+    let result = check_updater(&app).await?;
+    log::info!(
+        "Update check: current={}, available={:?}",
+        result.current_version,
+        result.version
+    );
+    Ok(result)
+}
+
+fn notify_update_available(app: &AppHandle, result: &UpdateCheckResult) {
+    use tauri_plugin_notification::NotificationExt;
+
+    let Some(version) = result.version.as_deref() else {
+        return;
+    };
+    {
+        let mut last = LAST_NOTIFIED_VERSION
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner());
+        if last.as_deref() == Some(version) {
+            return;
+        }
+        *last = Some(version.to_string());
+    }
+
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title("VoiceTypr Update Available")
+        .body(format!("Version {} is ready to install.", version))
+        .show()
+    {
+        log::warn!("Failed to show update notification: {}", e);
+    }
+}
+
+/// Tray "Check for Updates": hand off to the frontend when the main window is
+/// loaded (it owns the install flow), otherwise check here and notify.
+pub fn handle_tray_check_updates(app: &AppHandle) {
+    use tauri::{Emitter, Manager};
+
+    if app.get_webview_window("main").is_some() {
+        let _ = app.emit("tray-check-updates", ());
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match check_updater(&app).await {
+            Ok(result) if result.available => notify_update_available(&app, &result),
+            Ok(_) => log::info!("Tray update check: already up to date"),
+            Err(e) => log::warn!("Tray update check failed: {}", e),
+        }
+    });
+}
+
+fn update_check_interval_hours(app: &AppHandle) -> u32 {
+    crate::commands::settings::normalize_update_check_interval_hours(
+        app.store("settings")
+            .ok()
+            .and_then(|store| store.get("update_check_interval_hours"))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0),
+    )
+}
+
+/// Background update checks driven by `update_check_interval_hours` (0 = off).
+/// The interval is re-read after every sleep, so settings changes apply without
+/// a restart.
+pub fn spawn_periodic_update_checks(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(INITIAL_CHECK_DELAY).await;
+        loop {
+            let hours = update_check_interval_hours(&app);
+            if hours == 0 {
+                tokio::time::sleep(DISABLED_POLL_INTERVAL).await;
+                continue;
+            }
+
+            match check_updater(&app).await {
+                Ok(result) if result.available => notify_update_available(&app, &result),
+                Ok(_) => log::debug!("Periodic update check: up to date"),
+                Err(e) => log::warn!("Periodic update check failed: {}", e),
+            }
+            tokio::time::sleep(Duration::from_secs(hours as u64 * 60 * 60)).await;
+        }
+    });
+}
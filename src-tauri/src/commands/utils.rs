@@ -1,11 +1,363 @@
+use serde::{Deserialize, Serialize};
 use tauri::AppHandle;
 use tauri_plugin_store::StoreExt;
 
+/// File formats `export_transcriptions` can write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Txt,
+    Json,
+    Srt,
+    Vtt,
+    Csv,
+}
+
+impl ExportFormat {
+    fn parse(value: Option<&str>) -> Result<Self, String> {
+        match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+            None | Some("") | Some("json") => Ok(Self::Json),
+            Some("txt") => Ok(Self::Txt),
+            Some("srt") => Ok(Self::Srt),
+            Some("vtt") => Ok(Self::Vtt),
+            Some("csv") => Ok(Self::Csv),
+            Some(other) => Err(format!("Unsupported export format: {}", other)),
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Txt => "txt",
+            Self::Json => "json",
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// One caption cue, in milliseconds from the start of the export timeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cue {
+    start_ms: u64,
+    end_ms: u64,
+    text: String,
+}
+
+// Rough speaking rate used to size the cue of an entry without timings.
+const FALLBACK_MS_PER_WORD: u64 = 400;
+const MIN_CUE_MS: u64 = 1000;
+
+fn entry_text(entry: &serde_json::Value) -> &str {
+    entry
+        .get("text")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .trim()
+}
+
+fn is_failed_entry(entry: &serde_json::Value) -> bool {
+    entry.get("status").and_then(|v| v.as_str()) == Some("failed")
+}
+
+/// Cues for one history entry, relative to its own start. Entries whose
+/// segments still spell out the saved text get one cue per segment; anything
+/// else (older entries, or text changed by AI formatting, replacements or an
+/// edit) gets a single cue spanning the whole text.
+fn entry_cues(entry: &serde_json::Value) -> Vec<Cue> {
+    let mut cues = Vec::new();
+    let segments = entry.get("segments").and_then(|v| v.as_array());
+    for segment in segments.into_iter().flatten() {
+        let text = segment
+            .get("text")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .trim();
+        if text.is_empty() {
+            continue;
+        }
+        let previous_end = cues.last().map(|cue: &Cue| cue.end_ms).unwrap_or(0);
+        let start_ms = segment
+            .get("start_ms")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(previous_end);
+        let end_ms = segment
+            .get("end_ms")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(start_ms)
+            .max(start_ms + 1);
+        cues.push(Cue {
+            start_ms,
+            end_ms,
+            text: text.to_string(),
+        });
+    }
+    let text = entry_text(entry);
+    let segment_text = cues
+        .iter()
+        .flat_map(|cue| cue.text.split_whitespace())
+        .collect::<Vec<_>>();
+    if !cues.is_empty() && segment_text == text.split_whitespace().collect::<Vec<_>>() {
+        return cues;
+    }
+
+    let duration_ms = entry
+        .get("writing")
+        .and_then(|writing| writing.get("audio_duration_ms"))
+        .and_then(|v| v.as_u64())
+        .or_else(|| cues.last().map(|cue| cue.end_ms))
+        .unwrap_or_else(|| text.split_whitespace().count() as u64 * FALLBACK_MS_PER_WORD)
+        .max(MIN_CUE_MS);
+    vec![Cue {
+        start_ms: 0,
+        end_ms: duration_ms,
+        text: text.to_string(),
+    }]
+}
+
+/// Lay entries (oldest first) back to back on one timeline so their cues never overlap.
+fn timeline_cues(entries: &[serde_json::Value]) -> Vec<Cue> {
+    let mut cues = Vec::new();
+    let mut offset_ms = 0;
+    for entry in entries.iter().rev() {
+        let entry_cues = entry_cues(entry);
+        let entry_end = entry_cues.iter().map(|cue| cue.end_ms).max().unwrap_or(0);
+        cues.extend(entry_cues.into_iter().map(|cue| Cue {
+            start_ms: offset_ms + cue.start_ms,
+            end_ms: offset_ms + cue.end_ms,
+            text: cue.text,
+        }));
+        offset_ms += entry_end;
+    }
+    cues
+}
+
+fn format_cue_time(ms: u64, fraction_separator: char) -> String {
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        ms / 3_600_000,
+        (ms / 60_000) % 60,
+        (ms / 1000) % 60,
+        fraction_separator,
+        ms % 1000
+    )
+}
+
+fn render_srt(cues: &[Cue]) -> String {
+    cues.iter()
+        .enumerate()
+        .map(|(index, cue)| {
+            format!(
+                "{}\n{} --> {}\n{}\n\n",
+                index + 1,
+                format_cue_time(cue.start_ms, ','),
+                format_cue_time(cue.end_ms, ','),
+                cue.text
+            )
+        })
+        .collect()
+}
+
+fn render_vtt(cues: &[Cue]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in cues {
+        out.push_str(&format!(
+            "{} --> {}\n{}\n\n",
+            format_cue_time(cue.start_ms, '.'),
+            format_cue_time(cue.end_ms, '.'),
+            cue.text
+        ));
+    }
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn render_csv(entries: &[serde_json::Value]) -> String {
+    let mut out = String::from("timestamp,model,engine,text\n");
+    for entry in entries {
+        let field = |key: &str| entry.get(key).and_then(|v| v.as_str()).unwrap_or_default();
+        out.push_str(&format!(
+            "{},{},{},{}\n",
+            csv_field(field("timestamp")),
+            csv_field(field("model")),
+            csv_field(field("engine")),
+            csv_field(entry_text(entry))
+        ));
+    }
+    out
+}
+
+fn render_txt(entries: &[serde_json::Value]) -> String {
+    entries
+        .iter()
+        .map(|entry| {
+            let timestamp = entry
+                .get("timestamp")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            format!("[{}]\n{}\n\n", timestamp, entry_text(entry))
+        })
+        .collect()
+}
+
+/// Analysis-friendly details of a history row. Rows saved before these were
+/// stored at the top level fall back to their writing metadata.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct HistoryDetails {
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+    #[serde(default)]
+    pub avg_confidence: Option<f32>,
+    #[serde(default)]
+    pub char_count: usize,
+}
+
+impl HistoryDetails {
+    pub(crate) fn of_entry(entry: &serde_json::Value) -> Self {
+        let writing = entry.get("writing");
+        let lookup = |key: &str, fallback: &str| {
+            entry
+                .get(key)
+                .filter(|v| !v.is_null())
+                .or_else(|| writing.and_then(|w| w.get(fallback)))
+                .filter(|v| !v.is_null())
+        };
+        Self {
+            language: lookup("language", "transcript_language")
+                .or_else(|| writing.and_then(|w| w.get("spoken_language")))
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            duration_ms: lookup("duration_ms", "audio_duration_ms").and_then(|v| v.as_u64()),
+            avg_confidence: lookup("avg_confidence", "avg_confidence")
+                .and_then(|v| v.as_f64())
+                .map(|v| v as f32),
+            char_count: entry_text(entry).chars().count(),
+        }
+    }
+}
+
+/// One row of the JSON export. Fields missing from older history rows default
+/// to `null`; everything else stored on the row is kept under its own key.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct ExportedTranscription {
+    #[serde(default)]
+    timestamp: Option<String>,
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    model: Option<String>,
+    #[serde(default)]
+    engine: Option<String>,
+    #[serde(flatten)]
+    details: HistoryDetails,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ExportedTranscription {
+    fn from_entry(entry: &serde_json::Value) -> Self {
+        let field = |key: &str| entry.get(key).and_then(|v| v.as_str()).map(str::to_string);
+        let mut extra = entry.as_object().cloned().unwrap_or_default();
+        for key in [
+            "timestamp",
+            "text",
+            "model",
+            "engine",
+            "language",
+            "duration_ms",
+            "avg_confidence",
+            "char_count",
+        ] {
+            extra.remove(key);
+        }
+        Self {
+            timestamp: field("timestamp"),
+            text: entry_text(entry).to_string(),
+            model: field("model"),
+            engine: field("engine").or_else(|| {
+                entry
+                    .get("writing")
+                    .and_then(|w| w.get("engine"))
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+            }),
+            details: HistoryDetails::of_entry(entry),
+            extra,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TranscriptionExport {
+    app: String,
+    #[serde(default)]
+    app_version: Option<String>,
+    export_date: String,
+    total_transcriptions: usize,
+    transcriptions: Vec<ExportedTranscription>,
+}
+
+fn render_json(entries: &[serde_json::Value]) -> Result<String, String> {
+    let export_data = TranscriptionExport {
+        app: "Voicetypr".to_string(),
+        app_version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        export_date: chrono::Utc::now().to_rfc3339(),
+        total_transcriptions: entries.len(),
+        transcriptions: entries
+            .iter()
+            .map(ExportedTranscription::from_entry)
+            .collect(),
+    };
+    serde_json::to_string_pretty(&export_data)
+        .map_err(|e| format!("Failed to serialize data: {}", e))
+}
+
+/// Entries with a transcript to export; failed rows only hold a placeholder message.
+fn exportable_transcripts(entries: &[serde_json::Value]) -> Result<Vec<serde_json::Value>, String> {
+    let transcripts: Vec<serde_json::Value> = entries
+        .iter()
+        .filter(|entry| !is_failed_entry(entry) && !entry_text(entry).is_empty())
+        .cloned()
+        .collect();
+    if transcripts.is_empty() {
+        return Err("No transcriptions to export".to_string());
+    }
+    Ok(transcripts)
+}
+
+/// Render history entries (newest first) in the requested format.
+fn render_export(format: ExportFormat, entries: &[serde_json::Value]) -> Result<String, String> {
+    match format {
+        ExportFormat::Json => render_json(entries),
+        ExportFormat::Txt => exportable_transcripts(entries).map(|t| render_txt(&t)),
+        ExportFormat::Csv => exportable_transcripts(entries).map(|t| render_csv(&t)),
+        ExportFormat::Srt => {
+            exportable_transcripts(entries).map(|t| render_srt(&timeline_cues(&t)))
+        }
+        ExportFormat::Vtt => {
+            exportable_transcripts(entries).map(|t| render_vtt(&timeline_cues(&t)))
+        }
+    }
+}
+
 #[tauri::command]
-pub async fn export_transcriptions(app: AppHandle) -> Result<String, String> {
+pub async fn export_transcriptions(
+    app: AppHandle,
+    format: Option<String>,
+) -> Result<String, String> {
     use std::fs;
 
-    log::info!("Exporting transcriptions to JSON");
+    let format = ExportFormat::parse(format.as_deref())?;
+    log::info!("Exporting transcriptions as {}", format.extension());
 
     // Get transcription history from the store
     let store = app.store("transcriptions").map_err(|e| e.to_string())?;
@@ -28,13 +380,7 @@ pub async fn export_transcriptions(app: AppHandle) -> Result<String, String> {
         return Err("No transcriptions to export".to_string());
     }
 
-    // Create export data structure
-    let export_data = serde_json::json!({
-        "app": "Voicetypr",
-        "exportDate": chrono::Utc::now().to_rfc3339(),
-        "totalTranscriptions": history.len(),
-        "transcriptions": history
-    });
+    let contents = render_export(format, &history)?;
 
     // Get the Downloads folder path
     let download_dir = if cfg!(target_os = "macos") {
@@ -50,17 +396,14 @@ pub async fn export_transcriptions(app: AppHandle) -> Result<String, String> {
 
     // Create filename with current date
     let filename = format!(
-        "voicetypr-transcriptions-{}.json",
-        chrono::Local::now().format("%Y-%m-%d")
+        "voicetypr-transcriptions-{}.{}",
+        chrono::Local::now().format("%Y-%m-%d"),
+        format.extension()
     );
 
     let file_path = download_path.join(&filename);
 
-    // Write to file with pretty formatting
-    let json_string = serde_json::to_string_pretty(&export_data)
-        .map_err(|e| format!("Failed to serialize data: {}", e))?;
-
-    fs::write(&file_path, json_string).map_err(|e| format!("Failed to write file: {}", e))?;
+    fs::write(&file_path, contents).map_err(|e| format!("Failed to write file: {}", e))?;
 
     log::info!(
         "Exported {} transcriptions to {:?}",
@@ -68,7 +411,7 @@ pub async fn export_transcriptions(app: AppHandle) -> Result<String, String> {
         file_path
     );
 
-    // Return the full path as string
+    // Return the full path so the frontend can reveal it
     Ok(file_path.to_string_lossy().to_string())
 }
 
@@ -110,4 +453,152 @@ mod tests {
 
         std::fs::remove_file(&path).ok();
     }
+
+    #[test]
+    fn json_export_round_trips_with_metadata_and_legacy_defaults() {
+        let entries = vec![
+            serde_json::json!({
+                "text": "Ship it",
+                "model": "base.en",
+                "engine": "whisper",
+                "timestamp": "2026-02-01T00:00:00Z",
+                "language": "en",
+                "duration_ms": 1800,
+                "avg_confidence": 0.5,
+                "char_count": 7,
+                "recording_file": "recording_1.wav"
+            }),
+            // Saved before the flat fields existed
+            serde_json::json!({
+                "text": "Hola",
+                "model": "soniox",
+                "timestamp": "2026-01-01T00:00:00Z",
+                "writing": {
+                    "engine": "soniox",
+                    "audio_duration_ms": 900,
+                    "transcript_language": "es"
+                }
+            }),
+            serde_json::json!({ "text": "old" }),
+        ];
+
+        let json = render_export(ExportFormat::Json, &entries).unwrap();
+        let parsed: TranscriptionExport = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            parsed.app_version.as_deref(),
+            Some(env!("CARGO_PKG_VERSION"))
+        );
+        assert_eq!(parsed.total_transcriptions, 3);
+
+        let current = &parsed.transcriptions[0];
+        assert_eq!(current.engine.as_deref(), Some("whisper"));
+        assert_eq!(
+            current.details,
+            HistoryDetails {
+                language: Some("en".to_string()),
+                duration_ms: Some(1800),
+                avg_confidence: Some(0.5),
+                char_count: 7,
+            }
+        );
+        assert_eq!(current.extra["recording_file"], "recording_1.wav");
+
+        let legacy = &parsed.transcriptions[1];
+        assert_eq!(legacy.engine.as_deref(), Some("soniox"));
+        assert_eq!(legacy.details.language.as_deref(), Some("es"));
+        assert_eq!(legacy.details.duration_ms, Some(900));
+        assert_eq!(legacy.details.avg_confidence, None);
+
+        let bare = &parsed.transcriptions[2];
+        assert_eq!(bare.timestamp, None);
+        assert_eq!(bare.details.char_count, 3);
+
+        // Re-serializing the parsed export yields the same document
+        let reparsed: TranscriptionExport =
+            serde_json::from_str(&serde_json::to_string(&parsed).unwrap()).unwrap();
+        assert_eq!(reparsed, parsed);
+    }
+
+    #[test]
+    fn export_format_defaults_to_json_and_rejects_unknown() {
+        assert_eq!(ExportFormat::parse(None), Ok(ExportFormat::Json));
+        assert_eq!(ExportFormat::parse(Some("SRT")), Ok(ExportFormat::Srt));
+        assert!(ExportFormat::parse(Some("docx")).is_err());
+    }
+
+    #[test]
+    fn srt_uses_segment_timings_and_lays_entries_end_to_end() {
+        // Newest first, as stored history is sorted
+        let entries = vec![
+            serde_json::json!({ "text": "Second take.", "writing": { "audio_duration_ms": 1500 } }),
+            serde_json::json!({
+                "text": "Hello there. General Kenobi.",
+                "segments": [
+                    { "text": " Hello there.", "start_ms": 0, "end_ms": 1200 },
+                    { "text": " General Kenobi.", "start_ms": 1200, "end_ms": 2500 }
+                ]
+            }),
+        ];
+
+        let srt = render_export(ExportFormat::Srt, &entries).unwrap();
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:01,200\nHello there.\n\n\
+             2\n00:00:01,200 --> 00:00:02,500\nGeneral Kenobi.\n\n\
+             3\n00:00:02,500 --> 00:00:04,000\nSecond take.\n\n"
+        );
+
+        let vtt = render_export(ExportFormat::Vtt, &entries).unwrap();
+        assert!(vtt.starts_with("WEBVTT\n\n00:00:00.000 --> 00:00:01.200\n"));
+    }
+
+    #[test]
+    fn entry_without_timings_becomes_one_cue_sized_by_word_count() {
+        let cues = entry_cues(&serde_json::json!({ "text": "one two three four five" }));
+        assert_eq!(
+            cues,
+            vec![Cue {
+                start_ms: 0,
+                end_ms: 2000,
+                text: "one two three four five".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn entry_with_segments_that_no_longer_match_its_text_becomes_one_cue() {
+        // AI formatting rewrote the text after the segments were captured
+        let cues = entry_cues(&serde_json::json!({
+            "text": "Hello there, General Kenobi!",
+            "segments": [
+                { "text": " hello there", "start_ms": 0, "end_ms": 1200 },
+                { "text": " general kenobi", "start_ms": 1200, "end_ms": 2500 }
+            ]
+        }));
+        assert_eq!(
+            cues,
+            vec![Cue {
+                start_ms: 0,
+                end_ms: 2500,
+                text: "Hello there, General Kenobi!".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn csv_quotes_fields_and_skips_failed_rows() {
+        let entries = vec![
+            serde_json::json!({ "text": "Transcription failed", "status": "failed" }),
+            serde_json::json!({
+                "text": "Say \"hi\", then leave",
+                "timestamp": "2026-01-01T00:00:00Z",
+                "model": "base.en",
+                "engine": "whisper"
+            }),
+        ];
+        assert_eq!(
+            render_export(ExportFormat::Csv, &entries).unwrap(),
+            "timestamp,model,engine,text\n2026-01-01T00:00:00Z,base.en,whisper,\"Say \"\"hi\"\", then leave\"\n"
+        );
+    }
 }
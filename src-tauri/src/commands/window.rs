@@ -144,8 +144,19 @@ pub async fn close_pill_widget(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
-// Note: update_pill_position has been removed since pill position is now fixed at center-bottom
-// This was a design decision made during security review to simplify the codebase
+/// Forget where the user dragged the pill and return it to the configured
+/// preset position. Recovery for a pill left somewhere inconvenient.
+#[tauri::command]
+pub async fn reset_pill_position(app: AppHandle) -> Result<(), String> {
+    let app_state = app.state::<AppState>();
+    let window_manager = app_state
+        .get_window_manager()
+        .ok_or("Window manager not initialized")?;
+
+    window_manager.reset_pill_position()?;
+    log::info!("Pill position reset to preset");
+    Ok(())
+}
 
 /// Hide the toast feedback window (called by frontend after message duration as backup)
 /// Backend also auto-hides via show_toast_feedback, but frontend can call this as safety net
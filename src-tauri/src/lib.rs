@@ -30,6 +30,7 @@ mod simple_cache;
 mod state;
 mod state_machine;
 mod telemetry;
+mod text;
 pub mod transcription;
 mod trigger;
 mod utils;
@@ -224,8 +225,9 @@ use commands::telemetry::{get_telemetry_status, report_frontend_error, set_telem
 use commands::{
     ai::{
         cache_ai_api_key, clear_ai_api_key_cache, disable_ai_enhancement, enhance_transcription,
-        get_ai_settings, get_ai_settings_for_provider, get_enhancement_options, get_openai_config,
-        get_writing_settings, list_ai_providers, list_provider_models, set_openai_config,
+        get_ai_settings, get_ai_settings_for_provider, get_code_mode_session,
+        get_enhancement_options, get_openai_config, get_writing_settings, list_ai_models,
+        list_ai_providers, list_provider_models, set_code_mode_session, set_openai_config,
         test_openai_endpoint, update_ai_settings, update_enhancement_options,
         update_writing_settings, validate_ai_api_key,
     },
@@ -235,13 +237,21 @@ use commands::{
     debug::{debug_transcription_flow, test_transcription_event},
     device::get_device_id,
     distribution::get_distribution_info,
-    keyring::{keyring_delete, keyring_get, keyring_has, keyring_set},
+    health::run_health_check,
+    keyring::{
+        clear_all_keys, keyring_delete, keyring_get, keyring_has, keyring_set, list_stored_keys,
+        rotate_encryption_key,
+    },
     license::*,
-    logs::{clear_old_logs, get_latest_log_for_bug_report, get_log_directory, open_logs_folder},
+    logs::{
+        clear_old_logs, export_diagnostics, get_latest_log_for_bug_report, get_log_directory,
+        get_recent_logs, open_logs_folder, subscribe_logs, unsubscribe_logs,
+    },
+    metrics::get_transcription_metrics,
     model::{
         cancel_download, delete_model, download_model, download_parakeet_vocabulary_model,
-        get_model_status, get_parakeet_vocabulary_status, list_downloaded_models, preload_model,
-        verify_model,
+        get_model_status, get_parakeet_vocabulary_status, import_whisper_model,
+        list_downloaded_models, preload_model, preload_parakeet_model, verify_model, warmup_engine,
     },
     permissions::{
         check_accessibility_permission, check_microphone_permission, open_accessibility_settings,
@@ -259,11 +269,23 @@ use commands::{
         update_remote_transcription_control,
     },
     reset::reset_app_data,
+    scratchpad::{
+        clear_scratchpad, copy_scratchpad, export_scratchpad, get_scratchpad, hide_scratchpad,
+        show_scratchpad,
+    },
     settings::*,
-    shortcuts::{get_shortcut_settings, list_shortcut_actions, update_shortcut_settings},
-    stt::{clear_stt_key_cache, validate_stt_key},
+    shortcuts::{
+        check_shortcut_available, get_shortcut_settings, list_shortcut_actions,
+        suggest_available_shortcuts, update_shortcut_settings,
+    },
+    stt::{
+        clear_stt_key_cache, get_stt_endpoint, get_stt_extra_params, set_stt_endpoint,
+        set_stt_extra_params, test_stt_endpoint, validate_stt_key,
+    },
     system_info::get_system_specs,
+    templates::{create_template, delete_template, insert_template, list_templates},
     text::*,
+    updates::check_for_updates,
     utils::{export_transcriptions, save_transcript_file},
     window::*,
 };
@@ -282,28 +304,9 @@ pub use state::{
 };
 
 /// Shared log filter predicate applied to every tauri_plugin_log target.
-/// Drops noisy third-party logs:
-/// - Audio crates (always): whisper_rs, cpal, rubato, hound, audio::level_meter
-/// - HTTP/transport crates below Warn: h2, hyper, hyper_util, reqwest, tower
+/// Drops noisy third-party logs; see `commands::logs::is_noisy_log_target`.
 fn log_filter_predicate(metadata: &log::Metadata) -> bool {
-    let target = metadata.target();
-    // Always drop low-level audio processing noise
-    if target.contains("whisper_rs")
-        || target.contains("audio::level_meter")
-        || target.contains("cpal")
-        || target.contains("rubato")
-        || target.contains("hound")
-    {
-        return false;
-    }
-    // Drop HTTP/transport framing noise at Info/Debug/Trace; keep Warn and Error
-    let is_http_transport = target == "h2"
-        || target.starts_with("h2::")
-        || target.starts_with("hyper")
-        || target.starts_with("hyper_util")
-        || target.starts_with("reqwest")
-        || target.starts_with("tower");
-    !(is_http_transport && metadata.level() > log::Level::Warn)
+    !commands::logs::is_noisy_log_target(metadata.target(), metadata.level())
 }
 
 // Setup logging with daily rotation
@@ -426,7 +429,7 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                     // Cmd+Q / app-menu Quit: hide to tray instead of terminating.
                     hide_main_window(app);
                 } else if id == HELP_CHECK_UPDATES_ID {
-                    let _ = app.emit("tray-check-updates", ());
+                    crate::commands::updates::handle_tray_check_updates(app);
                 } else if id == HELP_REPORT_ISSUE_ID {
                     let _ = app.opener().open_url(
                         "https://github.com/moinulmoin/voicetypr/issues",
@@ -457,6 +460,12 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             // Much faster and uses OS-native secure storage
             log::info!("🔐 Using OS-native keyring for secure API key storage");
 
+            // Switch to the rotated secure-store key before anything reads a secret.
+            // On failure secrets stay unreadable (but intact) for this session
+            if let Err(e) = secure_store::load_rotated_encryption_key(app.handle()) {
+                log::error!("Failed to load rotated encryption key: {}", e);
+            }
+
             // Set up panic handler to catch crashes
             log_start("PANIC_HANDLER_SETUP");
             log_with_context(log::Level::Debug, "Setting up panic handler", &[
@@ -492,8 +501,7 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                 eprintln!("Application panic at {}: {}", location, message);
 
                 // Try to save panic info to a crash file for debugging
-                if let Ok(home_dir) = std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE")) {
-                    let crash_file = std::path::Path::new(&home_dir).join(".voicetypr_crash.log");
+                if let Some(crash_file) = utils::diagnostics::crash_log_path() {
                     let _ = std::fs::write(&crash_file, format!(
                         "Panic at {}: {}\nFull info: {:?}\nTime: {:?}",
                         location, message, panic_info, chrono::Local::now()
@@ -622,6 +630,13 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             app.manage(AsyncMutex::new(remote_settings));
             log::info!("🌐 Remote transcription state initialized ({} saved connections)", connection_count);
 
+            // Optional keep-warm timer for the selected cloud engine (reads the
+            // remote state above, so it must start after it is managed).
+            commands::model::spawn_engine_keep_warm(app.handle().clone());
+
+            // Free the Parakeet sidecar's memory after the configured idle time
+            commands::model::spawn_parakeet_idle_shutdown(app.handle().clone());
+
             // Auto-start network sharing if it was enabled before app closed
             // BUT only if no remote server is active (can't share and use remote at same time)
             if sharing_was_enabled && active_id.is_none() {
@@ -750,6 +765,15 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                 perform_startup_checks(app_handle).await;
             });
 
+            // Backend-driven update checks, independent of the webview
+            crate::commands::updates::spawn_periodic_update_checks(app.handle().clone());
+
+            // Transcript history retention and size cap, on startup and daily
+            crate::commands::audio::spawn_history_cleanup(app.handle().clone());
+
+            // Temp captures left behind by crashes or killed transcriptions
+            crate::commands::audio::spawn_orphaned_recording_cleanup(app.handle().clone());
+
             // Show pill on startup if pill_indicator_mode is "always"
             let app_handle_for_pill = app.handle().clone();
             tauri::async_runtime::spawn(async move {
@@ -839,6 +863,11 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             display_watcher.start();
             app.manage(display_watcher);
 
+            // Create theme watcher so the pill follows dark/light changes
+            let theme_watcher = utils::theme_watcher::ThemeWatcher::new(app.app_handle().clone());
+            theme_watcher.start();
+            app.manage(theme_watcher);
+
             // Create tray icon
             use tauri::tray::{TrayIconBuilder, TrayIconEvent};
 
@@ -869,7 +898,7 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                     } else if event_id == "quit" {
                         app.exit(0);
                     } else if event_id == "check_updates" {
-                        let _ = app.emit("tray-check-updates", ());
+                        crate::commands::updates::handle_tray_check_updates(app);
                     } else if event_id.starts_with("model_") {
                         // Handle model selection
                         let model_name = match event_id.strip_prefix("model_") {
@@ -1108,6 +1137,15 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                 });
             }
 
+            // Scripting socket, opt-in via the `local_control_socket` setting
+            let local_control_socket = app
+                .store("settings")
+                .ok()
+                .and_then(|store| store.get("local_control_socket"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            crate::remote::control::apply_setting(app.app_handle(), local_control_socket);
+
             // Preload current model if set (graceful degradation)
             // Use Tauri's async runtime which is available after setup
             if let Ok(store) = app.store("settings") {
@@ -1334,22 +1372,34 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
         })
         .invoke_handler(tauri::generate_handler![
             start_recording,
+            start_recording_with_language,
             stop_recording,
+            test_record_once,
             cancel_recording,
+            pause_recording,
+            resume_recording,
             get_current_recording_state,
             debug_transcription_flow,
             test_transcription_event,
             save_transcription,
             get_audio_devices,
+            get_audio_devices_detailed,
+            sample_input_level,
             get_current_audio_device,
+            test_media_pause,
             download_model,
             get_model_status,
             get_parakeet_vocabulary_status,
             download_parakeet_vocabulary_model,
             preload_model,
+            preload_parakeet_model,
+            warmup_engine,
             verify_model,
             transcribe_audio,
             transcribe_audio_file,
+            transcribe_audio_file_detailed,
+            transcribe_audio_files,
+            cancel_batch_transcription,
             diarize_audio_file,
             get_settings,
             save_settings,
@@ -1361,12 +1411,28 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             get_shortcut_settings,
             update_shortcut_settings,
             list_shortcut_actions,
+            check_shortcut_available,
+            suggest_available_shortcuts,
             get_supported_languages,
             set_model_from_tray,
+            set_active_model,
             update_tray_menu,
             insert_text,
+            reinsert_last_transcription,
+            list_templates,
+            create_template,
+            delete_template,
+            insert_template,
+            check_for_updates,
+            show_scratchpad,
+            hide_scratchpad,
+            get_scratchpad,
+            clear_scratchpad,
+            copy_scratchpad,
+            export_scratchpad,
             delete_model,
             list_downloaded_models,
+            import_whisper_model,
             cancel_download,
             cleanup_old_transcriptions,
             get_recordings_directory,
@@ -1374,11 +1440,14 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             check_recording_exists,
             get_recording_path,
             save_retranscription,
+            retranscribe_with_options,
             update_transcription,
             show_in_folder,
             get_transcription_history,
             get_transcription_count,
+            search_transcriptions,
             delete_transcription_entry,
+            set_transcription_favorite,
             clear_all_transcriptions,
             export_transcriptions,
             save_transcript_file,
@@ -1386,6 +1455,7 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             hide_pill_widget,
             close_pill_widget,
             recreate_pill_widget,
+            reset_pill_position,
             hide_toast_window,
             focus_main_window,
             check_accessibility_permission,
@@ -1419,29 +1489,46 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             get_enhancement_options,
             update_enhancement_options,
             get_writing_settings,
+            get_code_mode_session,
+            set_code_mode_session,
             update_writing_settings,
             list_ai_providers,
             list_provider_models,
+            list_ai_models,
             keyring_set,
             keyring_get,
             keyring_delete,
             keyring_has,
+            list_stored_keys,
+            clear_all_keys,
+            rotate_encryption_key,
             validate_stt_key,
             clear_stt_key_cache,
+            get_stt_endpoint,
+            set_stt_endpoint,
+            test_stt_endpoint,
+            get_stt_extra_params,
+            set_stt_extra_params,
             get_latest_log_for_bug_report,
+            export_diagnostics,
             get_log_directory,
             open_logs_folder,
+            get_recent_logs,
+            subscribe_logs,
+            unsubscribe_logs,
             get_autostart_status,
             set_autostart,
             get_device_id,
             get_distribution_info,
             get_system_specs,
+            run_health_check,
             // CLI launcher (voicetypr on PATH)
             install_cli_tool,
             uninstall_cli_tool,
             cli_tool_status,
             // Telemetry (opt-in error reporting) consent
             get_telemetry_status,
+            get_transcription_metrics,
             set_telemetry_consent,
             report_frontend_error,
             // Remote transcription commands
@@ -1486,6 +1573,14 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
             }
+            // Remember where the user drags the pill so it reappears there.
+            if let tauri::WindowEvent::Moved(position) = event {
+                if window.label() == "pill" {
+                    if let Some(window_manager) = window.app_handle().state::<AppState>().get_window_manager() {
+                        window_manager.remember_pill_position(*position);
+                    }
+                }
+            }
             // Refresh the tray icon when the OS theme flips (Windows taskbar).
             #[cfg(target_os = "windows")]
             if let tauri::WindowEvent::ThemeChanged(_) = event {
@@ -1615,7 +1710,7 @@ fn migrate_ai_settings_values(values: &mut serde_json::Map<String, serde_json::V
 }
 
 fn ai_model_is_valid_for_provider(provider: &str, model: &str) -> bool {
-    if provider == "custom" {
+    if crate::ai::providers::is_openai_compatible(provider) {
         return !model.trim().is_empty();
     }
     crate::ai::providers::recommended_models(provider)
@@ -1885,7 +1980,7 @@ async fn perform_startup_checks(app: tauri::AppHandle) {
 
     if let Some(model_name) = autoload_parakeet_model {
         if let Some(parakeet_manager) = app.try_state::<parakeet::ParakeetManager>() {
-            match parakeet_manager.load_model(&app, &model_name).await {
+            match parakeet_manager.preload_model(&app, &model_name).await {
                 Ok(_) => {
                     log::info!("✅ Parakeet model '{}' autoloaded from cache", model_name);
                 }
@@ -7,6 +7,10 @@ pub struct LicenseStatus {
     pub license_type: Option<String>,
     pub license_key: Option<String>,
     pub expires_at: Option<String>,
+    /// Days of offline use left while the license can't be re-validated;
+    /// `Some(0)` once the grace period is used up and recording is blocked.
+    #[serde(default)]
+    pub offline_grace_days_left: Option<i32>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -128,10 +128,71 @@ fn now_playing_snapshot_via_osascript() -> Option<NowPlayingSnapshot> {
     Some(NowPlayingSnapshot { is_playing })
 }
 
+/// What a pause request found, so callers can tell "nothing to pause" apart
+/// from a player that ignored the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseAttempt {
+    Paused,
+    NothingPlaying,
+    /// Media looked active but no session accepted the pause, or the playback
+    /// state could not be read (apps without system transport controls).
+    Failed,
+    Unsupported,
+}
+
+/// Outcome of `test_media_pause`: pause, hold briefly, then resume.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaPauseTestResult {
+    pub outcome: PauseAttempt,
+    pub resumed: bool,
+    pub message: String,
+}
+
+impl MediaPauseTestResult {
+    pub fn new(outcome: PauseAttempt, resumed: bool) -> Self {
+        let message = match (outcome, resumed) {
+            (PauseAttempt::Paused, true) => "Media paused and resumed successfully",
+            (PauseAttempt::Paused, false) => {
+                "Media paused but could not be resumed. Resume it manually."
+            }
+            (PauseAttempt::NothingPlaying, _) => {
+                "No media is playing. Start playback in a media app and test again."
+            }
+            (PauseAttempt::Failed, _) => {
+                "Media could not be paused. Some apps don't expose system media controls."
+            }
+            (PauseAttempt::Unsupported, _) => "Pausing media is not supported on this platform",
+        };
+        Self {
+            outcome,
+            resumed,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// What to report when no session was paused: nothing to do when no
+/// session exists or none was playing, a failure when one looked active.
+#[cfg(any(target_os = "windows", test))]
+fn unpaused_outcome(
+    session_count: usize,
+    had_playing_candidates: bool,
+    had_timeline_candidates: bool,
+) -> PauseAttempt {
+    if session_count == 0 || (!had_playing_candidates && !had_timeline_candidates) {
+        PauseAttempt::NothingPlaying
+    } else {
+        PauseAttempt::Failed
+    }
+}
+
 /// Controller for pausing/resuming system media during voice recording.
 pub struct MediaPauseController {
-    /// Tracks if we paused the media (so we know whether to resume)
-    was_playing_before_recording: AtomicBool,
+    /// Set only when a pause request actually succeeded, so resume never
+    /// starts media we didn't stop.
+    was_paused: AtomicBool,
 
     /// On Windows, track which media session we paused so we only resume the same session.
     #[cfg(target_os = "windows")]
@@ -147,7 +208,7 @@ impl Default for MediaPauseController {
 impl MediaPauseController {
     pub fn new() -> Self {
         Self {
-            was_playing_before_recording: AtomicBool::new(false),
+            was_paused: AtomicBool::new(false),
             #[cfg(target_os = "windows")]
             paused_session_source_app_user_model_id: Mutex::new(None),
         }
@@ -156,6 +217,11 @@ impl MediaPauseController {
     /// Pause media if currently playing. Call when recording starts.
     /// Returns true if media was paused.
     pub fn pause_if_playing(&self) -> bool {
+        self.try_pause() == PauseAttempt::Paused
+    }
+
+    /// Pause media if currently playing, reporting why nothing was paused.
+    pub fn try_pause(&self) -> PauseAttempt {
         #[cfg(target_os = "macos")]
         {
             self.pause_if_playing_macos()
@@ -169,17 +235,14 @@ impl MediaPauseController {
         #[cfg(not(any(target_os = "macos", target_os = "windows")))]
         {
             log::debug!("Media pause not supported on this platform");
-            false
+            PauseAttempt::Unsupported
         }
     }
 
     /// Resume media if we paused it. Call when recording stops.
     /// Returns true if media was resumed.
     pub fn resume_if_we_paused(&self) -> bool {
-        if self
-            .was_playing_before_recording
-            .swap(false, Ordering::SeqCst)
-        {
+        if self.was_paused.swap(false, Ordering::SeqCst) {
             #[cfg(target_os = "macos")]
             {
                 self.resume_macos()
@@ -202,8 +265,7 @@ impl MediaPauseController {
     /// Reset state without resuming (e.g., if app is closing)
     #[allow(dead_code)]
     pub fn reset(&self) {
-        self.was_playing_before_recording
-            .store(false, Ordering::SeqCst);
+        self.was_paused.store(false, Ordering::SeqCst);
 
         #[cfg(target_os = "windows")]
         {
@@ -215,32 +277,29 @@ impl MediaPauseController {
 // ============================================
 #[cfg(target_os = "macos")]
 impl MediaPauseController {
-    fn pause_if_playing_macos(&self) -> bool {
-        let snapshot = now_playing_snapshot_via_osascript();
-        let is_playing = snapshot
-            .as_ref()
-            .and_then(|s| s.is_playing)
-            .unwrap_or(false);
+    fn pause_if_playing_macos(&self) -> PauseAttempt {
+        let Some(snapshot) = now_playing_snapshot_via_osascript() else {
+            log::debug!("Now-playing state unavailable, nothing to pause");
+            self.was_paused.store(false, Ordering::SeqCst);
+            return PauseAttempt::Failed;
+        };
 
-        if !is_playing {
+        if !snapshot.is_playing.unwrap_or(false) {
             log::debug!("No media playing, nothing to pause");
-            self.was_playing_before_recording
-                .store(false, Ordering::SeqCst);
-            return false;
+            self.was_paused.store(false, Ordering::SeqCst);
+            return PauseAttempt::NothingPlaying;
         }
 
         log::info!("🎵 Media is playing, pausing for recording...");
 
         if toggle_media_playback_via_osascript() {
             log::info!("✅ Media paused successfully");
-            self.was_playing_before_recording
-                .store(true, Ordering::SeqCst);
-            true
+            self.was_paused.store(true, Ordering::SeqCst);
+            PauseAttempt::Paused
         } else {
             log::warn!("⚠️ Failed to pause media");
-            self.was_playing_before_recording
-                .store(false, Ordering::SeqCst);
-            false
+            self.was_paused.store(false, Ordering::SeqCst);
+            PauseAttempt::Failed
         }
     }
 
@@ -316,8 +375,7 @@ impl MediaPauseController {
             Ok(op) => match op.join() {
                 Ok(true) => {
                     log::info!("Media paused successfully via GSMTC");
-                    self.was_playing_before_recording
-                        .store(true, Ordering::SeqCst);
+                    self.was_paused.store(true, Ordering::SeqCst);
                     *self.paused_session_source_app_user_model_id.lock() = Some(source_app_id);
                     true
                 }
@@ -337,7 +395,7 @@ impl MediaPauseController {
         }
     }
 
-    fn pause_if_playing_windows(&self) -> bool {
+    fn pause_if_playing_windows(&self) -> PauseAttempt {
         use std::{thread, time::Duration};
         use windows::Media::Control::{
             GlobalSystemMediaTransportControlsSession,
@@ -345,18 +403,15 @@ impl MediaPauseController {
             GlobalSystemMediaTransportControlsSessionPlaybackStatus,
         };
 
-        // Get the session manager (blocking wait with .join())
-        let manager = match GlobalSystemMediaTransportControlsSessionManager::RequestAsync() {
-            Ok(op) => match op.join() {
-                Ok(mgr) => mgr,
-                Err(e) => {
-                    log::warn!("Failed to get GSMTC session manager: {:?}", e);
-                    return false;
-                }
-            },
+        // Get the session manager (blocking wait with .join()). Without one
+        // there is no session to pause; recording goes ahead untouched.
+        let manager = match GlobalSystemMediaTransportControlsSessionManager::RequestAsync()
+            .and_then(|op| op.join())
+        {
+            Ok(mgr) => mgr,
             Err(e) => {
-                log::warn!("Failed to request GSMTC session manager: {:?}", e);
-                return false;
+                log::info!("No GSMTC session manager ({:?}); nothing to pause", e);
+                return unpaused_outcome(0, false, false);
             }
         };
 
@@ -417,6 +472,11 @@ impl MediaPauseController {
             }
         }
 
+        if all.is_empty() {
+            log::info!("No media sessions; nothing to pause");
+            return unpaused_outcome(0, false, false);
+        }
+
         let mut attempted: Vec<usize> = Vec::new();
         let mut had_reported_candidates = false;
 
@@ -443,7 +503,7 @@ impl MediaPauseController {
             }
             attempted.push(idx);
             if self.try_pause_session(session) {
-                return true;
+                return PauseAttempt::Paused;
             }
         }
 
@@ -497,20 +557,20 @@ impl MediaPauseController {
         for idx in timeline_candidates {
             let session = &all[idx].1;
             if self.try_pause_session(session) {
-                return true;
+                return PauseAttempt::Paused;
             }
         }
 
-        self.was_playing_before_recording
-            .store(false, Ordering::SeqCst);
+        self.was_paused.store(false, Ordering::SeqCst);
         *self.paused_session_source_app_user_model_id.lock() = None;
 
-        if !had_reported_candidates && !had_timeline_candidates {
+        let outcome = unpaused_outcome(all.len(), had_reported_candidates, had_timeline_candidates);
+        if outcome == PauseAttempt::NothingPlaying {
             log::info!("No playing media session found");
         } else {
             log::info!("No media session could be paused");
         }
-        false
+        outcome
     }
 
     fn resume_windows(&self) -> bool {
@@ -630,17 +690,13 @@ mod tests {
     #[test]
     fn test_controller_creation() {
         let controller = MediaPauseController::new();
-        assert!(!controller
-            .was_playing_before_recording
-            .load(Ordering::SeqCst));
+        assert!(!controller.was_paused.load(Ordering::SeqCst));
     }
 
     #[test]
     fn test_default_impl() {
         let controller = MediaPauseController::default();
-        assert!(!controller
-            .was_playing_before_recording
-            .load(Ordering::SeqCst));
+        assert!(!controller.was_paused.load(Ordering::SeqCst));
     }
 
     #[test]
@@ -654,30 +710,66 @@ mod tests {
     fn test_resume_clears_was_playing_flag() {
         let controller = MediaPauseController::new();
         // Manually set the flag to true
-        controller
-            .was_playing_before_recording
-            .store(true, Ordering::SeqCst);
+        controller.was_paused.store(true, Ordering::SeqCst);
 
         // Resume should clear the flag (swap returns old value)
         // Note: actual resume behavior depends on platform APIs
         let _ = controller.resume_if_we_paused();
 
         // Flag should be cleared after resume attempt
-        assert!(!controller
-            .was_playing_before_recording
-            .load(Ordering::SeqCst));
+        assert!(!controller.was_paused.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_no_media_session_means_nothing_to_pause() {
+        assert_eq!(
+            unpaused_outcome(0, false, false),
+            PauseAttempt::NothingPlaying
+        );
+        assert_eq!(
+            unpaused_outcome(2, false, false),
+            PauseAttempt::NothingPlaying
+        );
+        assert_eq!(unpaused_outcome(2, true, false), PauseAttempt::Failed);
+        assert_eq!(unpaused_outcome(1, false, true), PauseAttempt::Failed);
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn test_windows_pause_without_session_leaves_nothing_to_resume() {
+        let controller = MediaPauseController::new();
+        // On a machine with no playing session the attempt reports why and
+        // never errors; resume must then be a no-op.
+        if controller.try_pause() != PauseAttempt::Paused {
+            assert!(!controller.was_paused.load(Ordering::SeqCst));
+            assert!(!controller.resume_if_we_paused());
+        } else {
+            controller.resume_if_we_paused();
+        }
+    }
+
+    #[test]
+    fn test_media_pause_result_messages() {
+        let ok = MediaPauseTestResult::new(PauseAttempt::Paused, true);
+        assert_eq!(ok.message, "Media paused and resumed successfully");
+
+        let idle = MediaPauseTestResult::new(PauseAttempt::NothingPlaying, false);
+        assert!(idle.message.starts_with("No media is playing"));
+
+        let failed = MediaPauseTestResult::new(PauseAttempt::Failed, false);
+        assert!(failed.message.contains("system media controls"));
+
+        let json = serde_json::to_value(&idle).unwrap();
+        assert_eq!(json["outcome"], "nothing_playing");
+        assert_eq!(json["resumed"], false);
     }
 
     #[test]
     fn test_reset() {
         let controller = MediaPauseController::new();
-        controller
-            .was_playing_before_recording
-            .store(true, Ordering::SeqCst);
+        controller.was_paused.store(true, Ordering::SeqCst);
         controller.reset();
-        assert!(!controller
-            .was_playing_before_recording
-            .load(Ordering::SeqCst));
+        assert!(!controller.was_paused.load(Ordering::SeqCst));
     }
 
     #[test]
@@ -686,9 +778,7 @@ mod tests {
         controller.reset();
         controller.reset();
         controller.reset();
-        assert!(!controller
-            .was_playing_before_recording
-            .load(Ordering::SeqCst));
+        assert!(!controller.was_paused.load(Ordering::SeqCst));
     }
 
     #[test]
@@ -703,9 +793,8 @@ mod tests {
         for i in 0..10 {
             let c = Arc::clone(&controller);
             handles.push(thread::spawn(move || {
-                c.was_playing_before_recording
-                    .store(i % 2 == 0, Ordering::SeqCst);
-                c.was_playing_before_recording.load(Ordering::SeqCst)
+                c.was_paused.store(i % 2 == 0, Ordering::SeqCst);
+                c.was_paused.load(Ordering::SeqCst)
             }));
         }
 
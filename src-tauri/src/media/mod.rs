@@ -6,4 +6,4 @@
 
 mod controller;
 
-pub use controller::MediaPauseController;
+pub use controller::{MediaPauseController, MediaPauseTestResult, PauseAttempt};
@@ -1,8 +1,9 @@
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use log::{trace, warn};
+use log::{debug, trace, warn};
 use reqwest::Client;
 use serde::Serialize;
 use tauri::{AppHandle, Emitter};
@@ -10,7 +11,7 @@ use tauri::{AppHandle, Emitter};
 use super::error::ParakeetError;
 use super::messages::{ParakeetCommand, ParakeetResponse, ParakeetVocabularyTerm};
 use super::models::{get_available_models, ParakeetModelDefinition, AVAILABLE_MODELS};
-use super::sidecar::ParakeetClient;
+use super::sidecar::{ParakeetClient, ParakeetUpdate};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ParakeetModelStatus {
@@ -32,6 +33,8 @@ pub struct ParakeetTranscriptionOptions {
     pub translate: bool,
     pub custom_vocabulary: Vec<ParakeetVocabularyTerm>,
     pub cancel_flag: Option<Arc<AtomicBool>>,
+    /// Forward the sidecar's partial text to the pill as `transcription-partial`
+    pub emit_partials: bool,
 }
 
 impl ParakeetTranscriptionOptions {
@@ -45,6 +48,7 @@ impl ParakeetTranscriptionOptions {
             translate,
             custom_vocabulary: Vec::new(),
             cancel_flag,
+            emit_partials: false,
         }
     }
 }
@@ -60,9 +64,24 @@ pub struct ParakeetManager {
     root_dir: PathBuf,
     #[allow(dead_code)]
     http: Client,
+    // When the sidecar last handled a command; None while it is not running
+    last_used: Mutex<Option<Instant>>,
 }
 
 const PARAKEET_UNAVAILABLE_EVENT: &str = "parakeet-unavailable";
+const TRANSCRIPTION_PARTIAL_EVENT: &str = "transcription-partial";
+const PARAKEET_READY_EVENT: &str = "parakeet-ready";
+
+/// Whether a sidecar last used at `last_used` has idled past `timeout`.
+/// A zero timeout keeps the sidecar alive indefinitely.
+fn idle_expired(last_used: Option<Instant>, now: Instant, timeout: Duration) -> bool {
+    match last_used {
+        Some(last_used) if !timeout.is_zero() => {
+            now.saturating_duration_since(last_used) >= timeout
+        }
+        _ => false,
+    }
+}
 
 fn fluid_audio_model_dir(home: &Path, definition: &ParakeetModelDefinition) -> PathBuf {
     home.join("Library/Application Support/FluidAudio/Models")
@@ -82,6 +101,7 @@ impl ParakeetManager {
             client: ParakeetClient::new("parakeet-sidecar"),
             root_dir,
             http: Client::new(),
+            last_used: Mutex::new(None),
         }
     }
 
@@ -195,17 +215,15 @@ impl ParakeetManager {
         let estimated_size = definition.estimated_size;
         let mut last_downloaded = 0;
         match self
-            .send_command_with_progress_and_cancel(
-                app,
-                &command,
-                cancel_flag.clone(),
-                |progress, phase| {
-                    let progress = progress.clamp(0.0, 1.0) as f64;
-                    let downloaded = (estimated_size as f64 * progress).round() as u64;
-                    last_downloaded = downloaded;
-                    progress_callback(downloaded, estimated_size, phase.map(str::to_string));
-                },
-            )
+            .send_command_with_progress_and_cancel(app, &command, cancel_flag.clone(), |update| {
+                let ParakeetUpdate::Progress { progress, phase } = update else {
+                    return;
+                };
+                let progress = progress.clamp(0.0, 1.0) as f64;
+                let downloaded = (estimated_size as f64 * progress).round() as u64;
+                last_downloaded = downloaded;
+                progress_callback(downloaded, estimated_size, phase.map(str::to_string));
+            })
             .await
         {
             Ok(ParakeetResponse::Status {
@@ -291,6 +309,26 @@ impl ParakeetManager {
         self.load_model_with_cancel(app, model_name, None).await
     }
 
+    /// Spawn the sidecar and load `model_name` ahead of the first transcription,
+    /// then emit `parakeet-ready` with the model name.
+    pub async fn preload_model(
+        &self,
+        app: &AppHandle,
+        model_name: &str,
+    ) -> Result<(), ParakeetError> {
+        let started = Instant::now();
+        self.load_model(app, model_name).await?;
+        log::info!(
+            "Parakeet model '{}' warm in {}ms",
+            model_name,
+            started.elapsed().as_millis()
+        );
+        if let Err(err) = app.emit(PARAKEET_READY_EVENT, model_name.to_string()) {
+            warn!("Failed to emit Parakeet ready event: {err:?}");
+        }
+        Ok(())
+    }
+
     pub async fn load_model_with_cancel(
         &self,
         app: &AppHandle,
@@ -319,7 +357,7 @@ impl ParakeetManager {
         };
 
         match self
-            .send_command_with_progress_and_cancel(app, &command, cancel_flag, |_, _| {})
+            .send_command_with_progress_and_cancel(app, &command, cancel_flag, |_| {})
             .await?
         {
             ParakeetResponse::Ok { .. } => Ok(()),
@@ -427,8 +465,15 @@ impl ParakeetManager {
                 .then_some(options.custom_vocabulary),
         };
 
-        self.send_command_with_progress_and_cancel(app, &command, options.cancel_flag, |_, _| {})
-            .await
+        let emit_partials = options.emit_partials;
+        self.send_command_with_progress_and_cancel(app, &command, options.cancel_flag, |update| {
+            if let ParakeetUpdate::Partial { text } = update {
+                if emit_partials {
+                    Self::emit_partial(app, text);
+                }
+            }
+        })
+        .await
     }
 
     pub async fn diarize(
@@ -456,9 +501,32 @@ impl ParakeetManager {
         }
     }
 
-    #[allow(dead_code)]
     pub async fn shutdown(&self) {
         self.client.shutdown().await;
+        if let Ok(mut last_used) = self.last_used.lock() {
+            *last_used = None;
+        }
+    }
+
+    /// Shut the sidecar down to free its memory once it has been idle for
+    /// `timeout`. The next command respawns it and reloads the model.
+    pub async fn shutdown_if_idle(&self, timeout: Duration) -> bool {
+        let last_used = self.last_used.lock().ok().and_then(|last_used| *last_used);
+        if !idle_expired(last_used, Instant::now(), timeout) {
+            return false;
+        }
+        log::info!(
+            "Parakeet sidecar idle for over {}s; shutting it down",
+            timeout.as_secs()
+        );
+        self.shutdown().await;
+        true
+    }
+
+    fn touch(&self) {
+        if let Ok(mut last_used) = self.last_used.lock() {
+            *last_used = Some(Instant::now());
+        }
     }
 
     fn friendly_spawn_message(details: &str) -> String {
@@ -468,6 +536,17 @@ impl ParakeetManager {
         )
     }
 
+    fn emit_partial(app: &AppHandle, text: &str) {
+        if let Err(err) = crate::state::app_state::emit_to_window(
+            app,
+            "pill",
+            TRANSCRIPTION_PARTIAL_EVENT,
+            serde_json::json!({ "text": text }),
+        ) {
+            debug!("Failed to emit Parakeet partial transcription: {err}");
+        }
+    }
+
     fn emit_unavailable(app: &AppHandle, message: &str) {
         if let Err(err) = app.emit(PARAKEET_UNAVAILABLE_EVENT, message.to_string()) {
             warn!("Failed to emit Parakeet unavailable event: {err:?}");
@@ -479,7 +558,10 @@ impl ParakeetManager {
         app: &AppHandle,
         command: &ParakeetCommand,
     ) -> Result<ParakeetResponse, ParakeetError> {
-        match self.client.send(app, command).await {
+        self.touch();
+        let response = self.client.send(app, command).await;
+        self.touch();
+        match response {
             Ok(response) => Ok(response),
             Err(ParakeetError::SpawnError(details)) => {
                 let message = Self::friendly_spawn_message(&details);
@@ -498,13 +580,15 @@ impl ParakeetManager {
         progress_callback: F,
     ) -> Result<ParakeetResponse, ParakeetError>
     where
-        F: FnMut(f32, Option<&str>),
+        F: FnMut(ParakeetUpdate<'_>),
     {
-        match self
+        self.touch();
+        let response = self
             .client
             .send_with_progress_and_cancel(app, command, cancel_flag, progress_callback)
-            .await
-        {
+            .await;
+        self.touch();
+        match response {
             Ok(response) => Ok(response),
             Err(ParakeetError::SpawnError(details)) => {
                 let message = Self::friendly_spawn_message(&details);
@@ -518,9 +602,10 @@ impl ParakeetManager {
 
 #[cfg(test)]
 mod tests {
-    use super::{fluid_audio_model_dir, model_files_complete};
+    use super::{fluid_audio_model_dir, idle_expired, model_files_complete};
     use crate::parakeet::models::AVAILABLE_MODELS;
     use std::fs;
+    use std::time::{Duration, Instant};
     use tempfile::TempDir;
 
     #[test]
@@ -565,4 +650,16 @@ mod tests {
 
         assert!(model_files_complete(&model_dir, definition));
     }
+
+    #[test]
+    fn idle_expired_only_after_timeout_with_a_running_sidecar() {
+        let last_used = Instant::now();
+        let now = last_used + Duration::from_secs(16 * 60);
+        let timeout = Duration::from_secs(15 * 60);
+
+        assert!(idle_expired(Some(last_used), now, timeout));
+        assert!(!idle_expired(Some(now), now, timeout));
+        assert!(!idle_expired(None, now, timeout));
+        assert!(!idle_expired(Some(last_used), now, Duration::ZERO));
+    }
 }
@@ -187,6 +187,10 @@ pub enum ParakeetResponse {
         #[serde(default)]
         phase: Option<String>,
     },
+    /// Interim text for a longer clip, sent before the final `Transcription`.
+    /// Sidecars that do not stream simply never send it.
+    #[serde(rename_all = "camelCase")]
+    Partial { text: String },
     #[serde(rename_all = "camelCase")]
     Diarization {
         #[serde(default)]
@@ -345,6 +349,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn partial_response_decodes_text() {
+        let response: ParakeetResponse = serde_json::from_value(serde_json::json!({
+            "type": "partial",
+            "text": "so far the first"
+        }))
+        .unwrap();
+
+        match response {
+            ParakeetResponse::Partial { text } => assert_eq!(text, "so far the first"),
+            other => panic!("unexpected response: {:?}", other),
+        }
+    }
+
     #[test]
     fn status_response_defaults_custom_vocabulary_flags() {
         let response: ParakeetResponse = serde_json::from_value(serde_json::json!({
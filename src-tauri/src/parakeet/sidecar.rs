@@ -14,6 +14,18 @@ use tauri_plugin_shell::{
 };
 use tokio::sync::RwLockWriteGuard;
 
+/// Interim message the sidecar streams while a command is still running.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParakeetUpdate<'a> {
+    Progress {
+        progress: f32,
+        phase: Option<&'a str>,
+    },
+    Partial {
+        text: &'a str,
+    },
+}
+
 fn extract_json_payload(raw: &str) -> Option<&str> {
     let start = raw.find('{')?;
     let end = raw.rfind('}')?;
@@ -143,7 +155,7 @@ impl ParakeetSidecar {
         &mut self,
         command: &ParakeetCommand,
     ) -> Result<ParakeetResponse, ParakeetError> {
-        self.request_with_progress_and_cancel(command, None::<&mut fn(f32, Option<&str>)>, None)
+        self.request_with_progress_and_cancel(command, None::<&mut fn(ParakeetUpdate<'_>)>, None)
             .await
     }
 
@@ -154,7 +166,7 @@ impl ParakeetSidecar {
         cancel_flag: Option<Arc<AtomicBool>>,
     ) -> Result<ParakeetResponse, ParakeetError>
     where
-        F: FnMut(f32, Option<&str>),
+        F: FnMut(ParakeetUpdate<'_>),
     {
         let mut payload = serde_json::to_string(command)?;
         payload.push('\n');
@@ -226,7 +238,15 @@ impl ParakeetSidecar {
                 }
                 Ok(ParakeetResponse::Progress { progress, phase }) => {
                     if let Some(callback) = progress_callback.as_deref_mut() {
-                        callback(progress, phase.as_deref());
+                        callback(ParakeetUpdate::Progress {
+                            progress,
+                            phase: phase.as_deref(),
+                        });
+                    }
+                }
+                Ok(ParakeetResponse::Partial { text }) => {
+                    if let Some(callback) = progress_callback.as_deref_mut() {
+                        callback(ParakeetUpdate::Partial { text: &text });
                     }
                 }
                 Ok(response) => return Ok(response),
@@ -345,7 +365,7 @@ impl ParakeetClient {
         progress_callback: F,
     ) -> Result<ParakeetResponse, ParakeetError>
     where
-        F: FnMut(f32, Option<&str>),
+        F: FnMut(ParakeetUpdate<'_>),
     {
         // The progress callback is shared across the (at most two) sequential
         // attempts. Wrap it so each attempt future can borrow it mutably without
@@ -13,6 +13,7 @@ pub enum ProviderEngine {
     Groq,
     Deepgram,
     Cohere,
+    Assemblyai,
     Remote,
 }
 
@@ -23,6 +24,8 @@ pub struct ProviderCapabilities {
     pub supports_structured_terms: bool,
     pub supports_vocabulary_terms: bool,
     pub supports_translate_task: bool,
+    /// Emits interim results while audio is still arriving (Soniox realtime).
+    pub supports_streaming: bool,
 }
 
 impl ProviderEngine {
@@ -35,6 +38,7 @@ impl ProviderEngine {
             "groq" => Some(Self::Groq),
             "deepgram" => Some(Self::Deepgram),
             "cohere" => Some(Self::Cohere),
+            "assemblyai" => Some(Self::Assemblyai),
             "remote" => Some(Self::Remote),
             _ => None,
         }
@@ -49,6 +53,7 @@ impl ProviderEngine {
             Self::Groq => "groq",
             Self::Deepgram => "deepgram",
             Self::Cohere => "cohere",
+            Self::Assemblyai => "assemblyai",
             Self::Remote => "remote",
         }
     }
@@ -61,6 +66,7 @@ impl ProviderEngine {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: false,
                 supports_translate_task: true,
+                supports_streaming: false,
             },
             Self::Parakeet => ProviderCapabilities {
                 shareable_remote: true,
@@ -68,6 +74,7 @@ impl ProviderEngine {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: true,
                 supports_translate_task: false,
+                supports_streaming: false,
             },
             Self::Soniox => ProviderCapabilities {
                 shareable_remote: false,
@@ -75,13 +82,15 @@ impl ProviderEngine {
                 supports_structured_terms: true,
                 supports_vocabulary_terms: false,
                 supports_translate_task: false,
+                supports_streaming: true,
             },
             Self::Openai => ProviderCapabilities {
                 shareable_remote: false,
                 supports_initial_prompt: true,
                 supports_structured_terms: false,
                 supports_vocabulary_terms: false,
-                supports_translate_task: false,
+                supports_translate_task: true,
+                supports_streaming: false,
             },
             Self::Groq => ProviderCapabilities {
                 shareable_remote: false,
@@ -89,6 +98,7 @@ impl ProviderEngine {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: false,
                 supports_translate_task: false,
+                supports_streaming: false,
             },
             Self::Deepgram => ProviderCapabilities {
                 shareable_remote: false,
@@ -96,6 +106,7 @@ impl ProviderEngine {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: true,
                 supports_translate_task: false,
+                supports_streaming: false,
             },
             Self::Cohere => ProviderCapabilities {
                 shareable_remote: false,
@@ -103,6 +114,15 @@ impl ProviderEngine {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: false,
                 supports_translate_task: false,
+                supports_streaming: false,
+            },
+            Self::Assemblyai => ProviderCapabilities {
+                shareable_remote: false,
+                supports_initial_prompt: false,
+                supports_structured_terms: false,
+                supports_vocabulary_terms: false,
+                supports_translate_task: false,
+                supports_streaming: false,
             },
             Self::Remote => ProviderCapabilities {
                 shareable_remote: false,
@@ -110,6 +130,7 @@ impl ProviderEngine {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: false,
                 supports_translate_task: true,
+                supports_streaming: false,
             },
         }
     }
@@ -133,6 +154,7 @@ mod tests {
             ProviderEngine::Groq,
             ProviderEngine::Deepgram,
             ProviderEngine::Cohere,
+            ProviderEngine::Assemblyai,
             ProviderEngine::Remote,
         ];
 
@@ -172,6 +194,7 @@ mod tests {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: false,
                 supports_translate_task: true,
+                supports_streaming: false,
             }
         );
         assert_eq!(
@@ -182,6 +205,7 @@ mod tests {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: true,
                 supports_translate_task: false,
+                supports_streaming: false,
             }
         );
         assert_eq!(
@@ -192,6 +216,7 @@ mod tests {
                 supports_structured_terms: true,
                 supports_vocabulary_terms: false,
                 supports_translate_task: false,
+                supports_streaming: true,
             }
         );
         assert_eq!(
@@ -201,7 +226,8 @@ mod tests {
                 supports_initial_prompt: true,
                 supports_structured_terms: false,
                 supports_vocabulary_terms: false,
-                supports_translate_task: false,
+                supports_translate_task: true,
+                supports_streaming: false,
             }
         );
         assert_eq!(
@@ -212,6 +238,7 @@ mod tests {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: false,
                 supports_translate_task: false,
+                supports_streaming: false,
             }
         );
         assert_eq!(
@@ -222,6 +249,7 @@ mod tests {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: true,
                 supports_translate_task: false,
+                supports_streaming: false,
             }
         );
         assert_eq!(
@@ -232,6 +260,18 @@ mod tests {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: false,
                 supports_translate_task: false,
+                supports_streaming: false,
+            }
+        );
+        assert_eq!(
+            ProviderEngine::Assemblyai.capabilities(),
+            ProviderCapabilities {
+                shareable_remote: false,
+                supports_initial_prompt: false,
+                supports_structured_terms: false,
+                supports_vocabulary_terms: false,
+                supports_translate_task: false,
+                supports_streaming: false,
             }
         );
         assert_eq!(
@@ -242,6 +282,7 @@ mod tests {
                 supports_structured_terms: false,
                 supports_vocabulary_terms: false,
                 supports_translate_task: true,
+                supports_streaming: false,
             }
         );
     }
@@ -256,6 +297,7 @@ mod tests {
             ProviderEngine::Groq,
             ProviderEngine::Deepgram,
             ProviderEngine::Cohere,
+            ProviderEngine::Assemblyai,
             ProviderEngine::Remote,
         ];
 
@@ -307,7 +349,11 @@ mod tests {
             .collect();
         assert_eq!(
             translate_task_engines,
-            vec![ProviderEngine::Whisper, ProviderEngine::Remote]
+            vec![
+                ProviderEngine::Whisper,
+                ProviderEngine::Openai,
+                ProviderEngine::Remote
+            ]
         );
     }
 }
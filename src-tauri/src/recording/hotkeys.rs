@@ -1,14 +1,19 @@
 use crate::commands::audio::{
-    start_recording, stop_recording, RecorderState, PTT_START_ABORTED_AFTER_RELEASE,
+    start_recording, stop_recording, take_shortcut_profile, RecorderState,
+    PTT_START_ABORTED_AFTER_RELEASE,
 };
 use crate::commands::shortcuts::{
     self, hold_shortcut_transition, pressed_shortcut_should_run, CustomHoldTransition,
     ShortcutAction, ShortcutTrigger,
 };
+use crate::recording::start_gate;
 use crate::{get_recording_state, update_recording_state, AppState, RecordingState};
 use keytrigger::KeyPhase;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use tauri::Manager;
+use tauri_plugin_store::StoreExt;
 
 /// Handle toggle mode recording (click to start/stop)
 fn handle_toggle_mode(
@@ -56,11 +61,12 @@ fn handle_toggle_mode(
 
     if should_throttle {
         crate::commands::audio::pill_toast(app, "Hold on...", 1000);
+        take_shortcut_profile(app);
         return;
     }
 
     match current_state {
-        RecordingState::Idle | RecordingState::Error => {
+        state if start_gate::state_allows_start(state) => {
             log::info!("Toggle: Starting recording via hotkey");
             let app_handle = app.clone();
             tauri::async_runtime::spawn(async move {
@@ -137,13 +143,42 @@ fn handle_ptt_mode(
 ) {
     match event_state {
         KeyPhase::Pressed => {
+            // Key repeat sends more presses while the key is held; only the
+            // first one arms the start, so repeats cannot push it back
+            if app_state.ptt_key_held.swap(true, Ordering::SeqCst) {
+                log::debug!("PTT: Ignoring repeated key press");
+                return;
+            }
             log::info!("PTT: Key pressed");
-            app_state.ptt_key_held.store(true, Ordering::Relaxed);
 
-            if matches!(current_state, RecordingState::Idle | RecordingState::Error) {
-                log::info!("PTT: Starting recording");
+            if start_gate::state_allows_start(current_state) {
+                let pressed_at = Instant::now();
+                if let Ok(mut last_press) = app_state.ptt_pressed_at.lock() {
+                    *last_press = Some(pressed_at);
+                }
+
+                let min_hold = ptt_min_hold(app);
                 let app_handle = app.clone();
                 tauri::async_runtime::spawn(async move {
+                    if !min_hold.is_zero() {
+                        tokio::time::sleep(min_hold).await;
+                        let app_state = app_handle.state::<AppState>();
+                        if !ptt_press_still_held(
+                            &app_state.ptt_key_held,
+                            &app_state.ptt_pressed_at,
+                            pressed_at,
+                        ) {
+                            log::debug!("PTT: Key released before minimum hold; ignoring tap");
+                            take_shortcut_profile(&app_handle);
+                            return;
+                        }
+                        if !start_gate::state_allows_start(get_recording_state(&app_handle)) {
+                            take_shortcut_profile(&app_handle);
+                            return;
+                        }
+                    }
+
+                    log::info!("PTT: Starting recording");
                     let recorder_state = app_handle.state::<RecorderState>();
                     match start_recording(app_handle.clone(), recorder_state).await {
                         Ok(_) => log::info!("PTT: Recording started successfully"),
@@ -201,6 +236,33 @@ fn handle_ptt_mode(
     }
 }
 
+/// Minimum push-to-talk hold before a recording starts, so accidental taps
+/// never reach the pill or the too-short check.
+fn ptt_min_hold(app: &tauri::AppHandle) -> Duration {
+    let ms = app
+        .store("settings")
+        .ok()
+        .and_then(|store| store.get("ptt_min_hold_ms"))
+        .and_then(|v| v.as_u64())
+        .map(|v| v.min(crate::commands::settings::MAX_PTT_MIN_HOLD_MS as u64))
+        .unwrap_or(crate::commands::settings::DEFAULT_PTT_MIN_HOLD_MS as u64);
+    Duration::from_millis(ms)
+}
+
+/// True while the key is still down from the press made at `pressed_at`; a
+/// release, or a newer press, means this press should not start a recording.
+fn ptt_press_still_held(
+    ptt_key_held: &AtomicBool,
+    ptt_pressed_at: &Mutex<Option<Instant>>,
+    pressed_at: Instant,
+) -> bool {
+    ptt_key_held.load(Ordering::SeqCst)
+        && ptt_pressed_at
+            .lock()
+            .map(|last_press| *last_press == Some(pressed_at))
+            .unwrap_or(false)
+}
+
 fn should_dispatch_custom_pressed_binding(
     active_bindings: &mut std::collections::HashSet<String>,
     binding_id: &str,
@@ -288,20 +350,10 @@ pub(crate) fn dispatch_action(
             if event_state == KeyPhase::Pressed {
                 let app_handle = app.clone();
                 tauri::async_runtime::spawn(async move {
-                    match shortcuts::latest_copyable_transcription_text(&app_handle).await {
-                        Ok(Some(text)) => {
-                            if let Err(error) =
-                                crate::commands::text::insert_text(app_handle.clone(), text).await
-                            {
-                                log::error!("Shortcut paste_last_transcription failed: {}", error);
-                            }
-                        }
-                        Ok(None) => log::debug!(
-                            "Shortcut paste_last_transcription found no copyable history item"
-                        ),
-                        Err(error) => {
-                            log::error!("Shortcut paste_last_transcription failed: {}", error)
-                        }
+                    if let Err(error) =
+                        crate::commands::text::reinsert_last_transcription(app_handle).await
+                    {
+                        log::warn!("Shortcut paste_last_transcription failed: {}", error);
                     }
                 });
             }
@@ -361,14 +413,34 @@ pub(crate) fn dispatch_action(
 
 #[cfg(test)]
 mod tests {
-    use super::{claim_toggle_press, should_dispatch_custom_pressed_binding};
+    use super::{claim_toggle_press, ptt_press_still_held, should_dispatch_custom_pressed_binding};
     use crate::commands::shortcuts::ShortcutAction;
     use keytrigger::KeyPhase;
     use std::{
         collections::HashSet,
         sync::atomic::{AtomicBool, Ordering},
+        sync::Mutex,
+        time::{Duration, Instant},
     };
 
+    #[test]
+    fn ptt_press_counts_only_while_the_same_press_is_held() {
+        let held = AtomicBool::new(true);
+        let first_press = Instant::now();
+        let last_press = Mutex::new(Some(first_press));
+
+        assert!(ptt_press_still_held(&held, &last_press, first_press));
+
+        held.store(false, Ordering::SeqCst);
+        assert!(!ptt_press_still_held(&held, &last_press, first_press));
+
+        // Released and pressed again within the hold window: the first
+        // press's timer must not start a recording for the second.
+        held.store(true, Ordering::SeqCst);
+        *last_press.lock().unwrap() = Some(first_press + Duration::from_millis(80));
+        assert!(!ptt_press_still_held(&held, &last_press, first_press));
+    }
+
     #[test]
     fn claim_toggle_press_blocks_repeats_until_release() {
         let held = AtomicBool::new(false);
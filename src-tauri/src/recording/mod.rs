@@ -1,2 +1,3 @@
 pub mod escape_handler;
 pub(crate) mod hotkeys;
+pub(crate) mod start_gate;
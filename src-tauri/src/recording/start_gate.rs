@@ -0,0 +1,141 @@
+//! Serialized gate that every recording start passes through.
+//!
+//! Hotkeys (native and in-app fallback), the tray, and the frontend all end up
+//! in `start_recording`, which awaits validation before publishing `Starting`.
+//! Two triggers landing within that window could both pass the state check and
+//! start twice. The gate closes that race: at most one start is in flight, a
+//! start is refused while the recorder is Starting/Recording, and starts that
+//! follow an admitted one within the configured debounce window are ignored.
+
+use crate::RecordingState;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Set while an admitted start is running; cleared when its permit drops.
+static START_IN_FLIGHT: AtomicBool = AtomicBool::new(false);
+
+/// When the most recent start was admitted, for debouncing.
+static LAST_ADMITTED_START: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Why a start request was not admitted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum StartRejection {
+    /// Another trigger's start is still running.
+    InFlight,
+    /// The recorder is already Starting/Recording (or busy transcribing).
+    Busy(RecordingState),
+    /// A start was admitted less than the debounce window ago.
+    Debounced,
+}
+
+/// Held for the duration of an admitted start; releases the gate on drop.
+#[must_use = "the gate reopens as soon as the permit is dropped"]
+pub(crate) struct StartPermit(());
+
+impl Drop for StartPermit {
+    fn drop(&mut self) {
+        START_IN_FLIGHT.store(false, Ordering::SeqCst);
+    }
+}
+
+/// True when a new recording may start from `state`. Shared by the hotkey
+/// handlers' fast path and the gate itself.
+pub(crate) fn state_allows_start(state: RecordingState) -> bool {
+    matches!(state, RecordingState::Idle | RecordingState::Error)
+}
+
+/// Try to admit a recording start from any trigger source.
+pub(crate) fn try_begin_start(
+    state: RecordingState,
+    debounce: Duration,
+) -> Result<StartPermit, StartRejection> {
+    try_begin_start_at(state, debounce, Instant::now())
+}
+
+fn try_begin_start_at(
+    state: RecordingState,
+    debounce: Duration,
+    now: Instant,
+) -> Result<StartPermit, StartRejection> {
+    if START_IN_FLIGHT
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+        .is_err()
+    {
+        return Err(StartRejection::InFlight);
+    }
+    // From here on the permit owns the flag, so every early return reopens it.
+    let permit = StartPermit(());
+
+    if !state_allows_start(state) {
+        return Err(StartRejection::Busy(state));
+    }
+
+    let mut last = match LAST_ADMITTED_START.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    if let Some(previous) = *last {
+        if now.saturating_duration_since(previous) < debounce {
+            return Err(StartRejection::Debounced);
+        }
+    }
+    *last = Some(now);
+    Ok(permit)
+}
+
+#[cfg(test)]
+pub(crate) fn reset_for_test() {
+    START_IN_FLIGHT.store(false, Ordering::SeqCst);
+    if let Ok(mut last) = LAST_ADMITTED_START.lock() {
+        *last = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The gate is process-global; run its scenarios in one test so parallel
+    // test threads never observe each other's permits.
+    #[test]
+    fn gate_serializes_state_guards_and_debounces_starts() {
+        reset_for_test();
+        let debounce = Duration::from_millis(250);
+        let t0 = Instant::now();
+
+        let permit = try_begin_start_at(RecordingState::Idle, debounce, t0).unwrap();
+        assert_eq!(
+            try_begin_start_at(RecordingState::Idle, Duration::ZERO, t0).err(),
+            Some(StartRejection::InFlight)
+        );
+        drop(permit);
+
+        let later = t0 + Duration::from_secs(1);
+        assert_eq!(
+            try_begin_start_at(RecordingState::Recording, debounce, later).err(),
+            Some(StartRejection::Busy(RecordingState::Recording))
+        );
+        assert_eq!(
+            try_begin_start_at(RecordingState::Starting, debounce, later).err(),
+            Some(StartRejection::Busy(RecordingState::Starting))
+        );
+
+        // A rejected attempt must not leave the gate closed.
+        assert_eq!(
+            try_begin_start_at(
+                RecordingState::Idle,
+                debounce,
+                t0 + Duration::from_millis(100)
+            )
+            .err(),
+            Some(StartRejection::Debounced)
+        );
+        let permit = try_begin_start_at(RecordingState::Error, debounce, later).unwrap();
+        drop(permit);
+
+        // Zero debounce only relies on the in-flight and state guards.
+        assert!(try_begin_start_at(RecordingState::Idle, Duration::ZERO, later).is_ok());
+        reset_for_test();
+    }
+}
@@ -0,0 +1,516 @@
+//! Local control socket for scripting.
+//!
+//! When the `local_control_socket` setting is on, a Unix socket (a named pipe
+//! on Windows) accepts line-delimited JSON commands: `{"cmd":"start"}`,
+//! `{"cmd":"stop"}` and `{"cmd":"status"}`. Each line gets a one-line JSON
+//! reply with the recording state. Start and stop run the same commands as the
+//! hotkeys, so the start gate and recording state machine arbitrate between
+//! scripted and hotkey-driven recording.
+
+use crate::commands::audio::{start_recording, stop_recording, RecorderState};
+use crate::recording::start_gate;
+use crate::RecordingState;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Manager};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio_util::sync::CancellationToken;
+
+/// Longest command line accepted; anything longer closes the connection.
+const MAX_COMMAND_LINE_BYTES: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(tag = "cmd", rename_all = "lowercase")]
+pub enum ControlCommand {
+    Start,
+    Stop,
+    Status,
+}
+
+/// Reply to every command line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlResponse {
+    pub ok: bool,
+    /// Recording state after the command ran (`idle`, `recording`, ...).
+    pub state: String,
+    /// The socket path (or pipe name) this server listens on.
+    pub socket: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// What the socket drives. The app implements it over the `AppHandle`;
+/// tests use a fake.
+pub trait ControlContext: Send + Sync + 'static {
+    fn recording_state(&self) -> RecordingState;
+    fn start(&self) -> impl Future<Output = Result<(), String>> + Send;
+    fn stop(&self) -> impl Future<Output = Result<(), String>> + Send;
+}
+
+/// Socket path on Unix, pipe name on Windows. The socket lives in a per-user
+/// directory (the runtime dir, else the cache dir), never a shared `/tmp`.
+pub fn default_socket_path() -> Option<String> {
+    #[cfg(windows)]
+    {
+        Some(r"\\.\pipe\voicetypr-control".to_string())
+    }
+    #[cfg(not(windows))]
+    {
+        dirs::runtime_dir().or_else(dirs::cache_dir).map(|dir| {
+            dir.join("voicetypr-control.sock")
+                .to_string_lossy()
+                .into_owned()
+        })
+    }
+}
+
+/// Run one command against `ctx`.
+pub async fn handle_command<C: ControlContext>(
+    ctx: &C,
+    command: ControlCommand,
+    socket: &str,
+) -> ControlResponse {
+    let result = match command {
+        ControlCommand::Status => Ok(()),
+        ControlCommand::Start => {
+            let state = ctx.recording_state();
+            if start_gate::state_allows_start(state) {
+                ctx.start().await
+            } else {
+                Err(format!("Cannot start while {}", state.as_str()))
+            }
+        }
+        ControlCommand::Stop => match ctx.recording_state() {
+            RecordingState::Recording => ctx.stop().await,
+            state => Err(format!("Cannot stop while {}", state.as_str())),
+        },
+    };
+    ControlResponse {
+        ok: result.is_ok(),
+        state: ctx.recording_state().as_str().to_string(),
+        socket: socket.to_string(),
+        error: result.err(),
+    }
+}
+
+/// Parse and run one command line.
+pub async fn handle_line<C: ControlContext>(ctx: &C, line: &str, socket: &str) -> ControlResponse {
+    match serde_json::from_str::<ControlCommand>(line) {
+        Ok(command) => handle_command(ctx, command, socket).await,
+        Err(_) => ControlResponse {
+            ok: false,
+            state: ctx.recording_state().as_str().to_string(),
+            socket: socket.to_string(),
+            error: Some("Expected {\"cmd\":\"start\"|\"stop\"|\"status\"}".to_string()),
+        },
+    }
+}
+
+/// Answer command lines on one connection until the client hangs up.
+async fn serve_connection<C, S>(ctx: Arc<C>, stream: S, socket: String) -> std::io::Result<()>
+where
+    C: ControlContext,
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+    loop {
+        line.clear();
+        let read = (&mut reader)
+            .take(MAX_COMMAND_LINE_BYTES as u64)
+            .read_line(&mut line)
+            .await?;
+        if read == 0 {
+            return Ok(());
+        }
+        if !line.ends_with('\n') && read == MAX_COMMAND_LINE_BYTES {
+            return Ok(());
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = handle_line(ctx.as_ref(), line.trim(), &socket).await;
+        let mut reply = serde_json::to_string(&response).map_err(std::io::Error::other)?;
+        reply.push('\n');
+        writer.write_all(reply.as_bytes()).await?;
+        writer.flush().await?;
+    }
+}
+
+/// Serve one connection until the client hangs up or the server shuts down.
+fn spawn_connection<C, S>(ctx: &Arc<C>, stream: S, socket: &str, cancel: &CancellationToken)
+where
+    C: ControlContext,
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let ctx = ctx.clone();
+    let socket = socket.to_string();
+    let cancel = cancel.clone();
+    tokio::spawn(async move {
+        tokio::select! {
+            _ = cancel.cancelled() => {}
+            served = serve_connection(ctx, stream, socket) => {
+                if let Err(e) = served {
+                    log::debug!("Control socket connection closed: {}", e);
+                }
+            }
+        }
+    });
+}
+
+/// Remove a socket file left behind by a server that is gone. A path that is
+/// not a socket, or that still accepts connections, is left alone.
+#[cfg(unix)]
+async fn remove_stale_socket(socket: &str) -> std::io::Result<()> {
+    use std::io::{Error, ErrorKind};
+    use std::os::unix::fs::FileTypeExt;
+
+    let metadata = match std::fs::symlink_metadata(socket) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    if !metadata.file_type().is_socket() {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} exists and is not a socket", socket),
+        ));
+    }
+    if tokio::net::UnixStream::connect(socket).await.is_ok() {
+        return Err(Error::new(
+            ErrorKind::AddrInUse,
+            format!("Another server is listening on {}", socket),
+        ));
+    }
+    std::fs::remove_file(socket)
+}
+
+/// Listen on `socket` until `cancel` fires. The socket file is owner-only and
+/// removed on shutdown unless another server has replaced it; a stale file
+/// from a previous run is replaced, a live one is not.
+#[cfg(unix)]
+pub async fn serve<C: ControlContext>(
+    ctx: Arc<C>,
+    socket: String,
+    cancel: CancellationToken,
+) -> std::io::Result<()> {
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    if let Some(dir) = std::path::Path::new(&socket).parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    remove_stale_socket(&socket).await?;
+    let listener = tokio::net::UnixListener::bind(&socket)?;
+    std::fs::set_permissions(&socket, std::fs::Permissions::from_mode(0o600))?;
+    let bound = std::fs::metadata(&socket).map(|m| (m.dev(), m.ino()))?;
+    let result = loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break Ok(()),
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => spawn_connection(&ctx, stream, &socket, &cancel),
+                Err(e) => break Err(e),
+            },
+        }
+    };
+    if std::fs::metadata(&socket).is_ok_and(|m| (m.dev(), m.ino()) == bound) {
+        let _ = std::fs::remove_file(&socket);
+    }
+    result
+}
+
+/// Tries, 100 ms apart, to create the pipe while a previous server closes.
+#[cfg(windows)]
+const FIRST_INSTANCE_ATTEMPTS: u32 = 20;
+
+/// Create the pipe's first instance. `first_pipe_instance` refuses while any
+/// instance exists, which is also the case while a previous server's
+/// connections are closing, so retry briefly before giving up.
+#[cfg(windows)]
+async fn create_first_instance(
+    socket: &str,
+) -> std::io::Result<tokio::net::windows::named_pipe::NamedPipeServer> {
+    use tokio::net::windows::named_pipe::ServerOptions;
+
+    let mut attempt = 1;
+    loop {
+        match ServerOptions::new()
+            .first_pipe_instance(true)
+            .reject_remote_clients(true)
+            .create(socket)
+        {
+            Err(e)
+                if e.kind() == std::io::ErrorKind::PermissionDenied
+                    && attempt < FIRST_INSTANCE_ATTEMPTS =>
+            {
+                attempt += 1;
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Listen on the named pipe `socket` until `cancel` fires.
+#[cfg(windows)]
+pub async fn serve<C: ControlContext>(
+    ctx: Arc<C>,
+    socket: String,
+    cancel: CancellationToken,
+) -> std::io::Result<()> {
+    use tokio::net::windows::named_pipe::ServerOptions;
+
+    let mut server = create_first_instance(&socket).await?;
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => return Ok(()),
+            connected = server.connect() => connected?,
+        }
+        // Open the next instance before handing this one off so a client
+        // connecting in between still finds the pipe.
+        let next = ServerOptions::new()
+            .reject_remote_clients(true)
+            .create(&socket)?;
+        spawn_connection(&ctx, std::mem::replace(&mut server, next), &socket, &cancel);
+    }
+}
+
+struct AppControl {
+    app: AppHandle,
+}
+
+impl ControlContext for AppControl {
+    fn recording_state(&self) -> RecordingState {
+        crate::get_recording_state(&self.app)
+    }
+
+    async fn start(&self) -> Result<(), String> {
+        start_recording(self.app.clone(), self.app.state::<RecorderState>()).await
+    }
+
+    async fn stop(&self) -> Result<(), String> {
+        stop_recording(self.app.clone(), self.app.state::<RecorderState>())
+            .await
+            .map(|_| ())
+    }
+}
+
+/// The server task. It stays in [`CONTROL_SERVER`] after being cancelled
+/// until it exits, so a server started meanwhile can wait for it.
+struct ControlServer {
+    id: u64,
+    cancel: CancellationToken,
+    task: tauri::async_runtime::JoinHandle<()>,
+}
+
+static CONTROL_SERVER: Mutex<Option<ControlServer>> = Mutex::new(None);
+static NEXT_SERVER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Start or stop the control socket to match the `local_control_socket`
+/// setting. Safe to call repeatedly.
+pub fn apply_setting(app: &AppHandle, enabled: bool) {
+    let Ok(mut running) = CONTROL_SERVER.lock() else {
+        log::error!("Control socket state lock poisoned");
+        return;
+    };
+    if !enabled {
+        if let Some(server) = running.as_ref().filter(|s| !s.cancel.is_cancelled()) {
+            server.cancel.cancel();
+            log::info!("Local control socket stopped");
+        }
+        return;
+    }
+    let previous = match running.take() {
+        Some(server) if !server.cancel.is_cancelled() => {
+            *running = Some(server);
+            return;
+        }
+        stopping => stopping.map(|server| server.task),
+    };
+    let Some(socket) = default_socket_path() else {
+        log::error!("Local control socket disabled: no per-user directory for the socket");
+        return;
+    };
+
+    let id = NEXT_SERVER_ID.fetch_add(1, Ordering::SeqCst);
+    let cancel = CancellationToken::new();
+    let ctx = Arc::new(AppControl { app: app.clone() });
+    let server_cancel = cancel.clone();
+    let task = tauri::async_runtime::spawn(async move {
+        // The previous server removes its socket on exit; bind only after that
+        if let Some(previous) = previous {
+            let _ = previous.await;
+        }
+        if !server_cancel.is_cancelled() {
+            log::info!("Local control socket listening on {}", socket);
+            if let Err(e) = serve(ctx, socket.clone(), server_cancel).await {
+                log::error!("Local control socket on {} failed: {}", socket, e);
+            }
+        }
+        // Free the slot so the setting can start a new server after a failure
+        if let Ok(mut running) = CONTROL_SERVER.lock() {
+            if running.as_ref().is_some_and(|server| server.id == id) {
+                *running = None;
+            }
+        }
+    });
+    *running = Some(ControlServer { id, cancel, task });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mirrors the recorder's transitions without touching audio devices.
+    struct FakeControl {
+        state: Mutex<RecordingState>,
+    }
+
+    impl ControlContext for FakeControl {
+        fn recording_state(&self) -> RecordingState {
+            *self.state.lock().unwrap()
+        }
+
+        async fn start(&self) -> Result<(), String> {
+            *self.state.lock().unwrap() = RecordingState::Recording;
+            Ok(())
+        }
+
+        async fn stop(&self) -> Result<(), String> {
+            *self.state.lock().unwrap() = RecordingState::Idle;
+            Ok(())
+        }
+    }
+
+    fn fake(state: RecordingState) -> FakeControl {
+        FakeControl {
+            state: Mutex::new(state),
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_commands_the_state_machine_would_refuse() {
+        let ctx = fake(RecordingState::Transcribing);
+
+        let start = handle_line(&ctx, r#"{"cmd":"start"}"#, "sock").await;
+        assert!(!start.ok);
+        assert_eq!(start.state, "transcribing");
+
+        let stop = handle_line(&ctx, r#"{"cmd":"stop"}"#, "sock").await;
+        assert_eq!(
+            stop.error.as_deref(),
+            Some("Cannot stop while transcribing")
+        );
+
+        let unknown = handle_line(&ctx, r#"{"cmd":"reboot"}"#, "sock").await;
+        assert!(!unknown.ok);
+        assert!(unknown.error.is_some());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn drives_start_and_stop_over_the_socket() {
+        use tokio::net::UnixStream;
+
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir
+            .path()
+            .join("control.sock")
+            .to_string_lossy()
+            .into_owned();
+        let cancel = CancellationToken::new();
+        let server = tokio::spawn(serve(
+            Arc::new(fake(RecordingState::Idle)),
+            socket.clone(),
+            cancel.clone(),
+        ));
+
+        let mut stream = None;
+        for _ in 0..50 {
+            if let Ok(connected) = UnixStream::connect(&socket).await {
+                stream = Some(connected);
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let (reader, mut writer) = stream.expect("control socket should accept").into_split();
+        let mut lines = BufReader::new(reader).lines();
+        let mut send = async |cmd: &str| {
+            let line = format!("{{\"cmd\":\"{cmd}\"}}\n");
+            writer.write_all(line.as_bytes()).await.unwrap();
+            let reply = lines.next_line().await.unwrap().unwrap();
+            serde_json::from_str::<ControlResponse>(&reply).unwrap()
+        };
+
+        let status = send("status").await;
+        assert!(status.ok);
+        assert_eq!(status.state, "idle");
+        assert_eq!(status.socket, socket);
+
+        let started = send("start").await;
+        assert!(started.ok);
+        assert_eq!(started.state, "recording");
+
+        let again = send("start").await;
+        assert!(!again.ok);
+        assert_eq!(again.state, "recording");
+
+        let stopped = send("stop").await;
+        assert!(stopped.ok);
+        assert_eq!(stopped.state, "idle");
+
+        cancel.cancel();
+        server.await.unwrap().unwrap();
+        assert!(!std::path::Path::new(&socket).exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn leaves_a_live_socket_to_its_server() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir
+            .path()
+            .join("control.sock")
+            .to_string_lossy()
+            .into_owned();
+        let cancel = CancellationToken::new();
+        let server = tokio::spawn(serve(
+            Arc::new(fake(RecordingState::Idle)),
+            socket.clone(),
+            cancel.clone(),
+        ));
+        for _ in 0..50 {
+            if tokio::net::UnixStream::connect(&socket).await.is_ok() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        let second = serve(
+            Arc::new(fake(RecordingState::Idle)),
+            socket.clone(),
+            CancellationToken::new(),
+        )
+        .await;
+        assert_eq!(second.unwrap_err().kind(), std::io::ErrorKind::AddrInUse);
+        assert!(tokio::net::UnixStream::connect(&socket).await.is_ok());
+
+        cancel.cancel();
+        server.await.unwrap().unwrap();
+
+        // A socket file whose server is gone is replaced
+        drop(std::os::unix::net::UnixListener::bind(&socket).unwrap());
+        let cancel = CancellationToken::new();
+        let server = tokio::spawn(serve(
+            Arc::new(fake(RecordingState::Idle)),
+            socket.clone(),
+            cancel.clone(),
+        ));
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(tokio::net::UnixStream::connect(&socket).await.is_ok());
+        cancel.cancel();
+        server.await.unwrap().unwrap();
+    }
+}
@@ -1,14 +1,16 @@
 //! LAN discovery for remote transcription servers.
 //!
-//! This is deliberately small and dependency-free: clients broadcast a UDP
-//! discovery request on the local network, and running Voicetypr servers reply
-//! with the HTTP host/port clients should add manually or automatically.
+//! Two mechanisms run side by side. Clients broadcast a UDP discovery request
+//! on the local network and running Voicetypr servers reply with the HTTP
+//! host/port. Servers also advertise `_voicetypr._tcp` over mDNS/Bonjour,
+//! which reaches clients on networks that drop broadcast traffic.
 
 use std::collections::HashMap;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
 use serde::{Deserialize, Serialize};
 use tokio::net::UdpSocket;
 use tokio::sync::oneshot;
@@ -16,6 +18,7 @@ use tokio::sync::oneshot;
 pub const DISCOVERY_PORT: u16 = 47_842;
 const DISCOVERY_PROTOCOL: &str = "voicetypr.remote-discovery.v1";
 const MAX_PACKET_BYTES: usize = 2048;
+const MDNS_SERVICE_TYPE: &str = "_voicetypr._tcp.local.";
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 struct DiscoveryRequest {
@@ -46,10 +49,31 @@ pub struct DiscoveryResponderConfig {
 pub struct DiscoveryResponderHandle {
     shutdown_tx: Option<oneshot::Sender<()>>,
     task_handle: Option<tokio::task::JoinHandle<()>>,
+    mdns: Option<MdnsAdvertisement>,
+}
+
+/// A registered mDNS service; withdrawn (with a goodbye packet) on stop.
+struct MdnsAdvertisement {
+    daemon: ServiceDaemon,
+    fullname: String,
+}
+
+impl MdnsAdvertisement {
+    fn withdraw(self) {
+        if let Err(error) = self.daemon.unregister(&self.fullname) {
+            log::debug!("[Remote Discovery] mDNS unregister failed: {}", error);
+        }
+        if let Err(error) = self.daemon.shutdown() {
+            log::debug!("[Remote Discovery] mDNS shutdown failed: {}", error);
+        }
+    }
 }
 
 impl DiscoveryResponderHandle {
     pub async fn stop(mut self) {
+        if let Some(mdns) = self.mdns.take() {
+            mdns.withdraw();
+        }
         if let Some(tx) = self.shutdown_tx.take() {
             let _ = tx.send(());
         }
@@ -68,6 +92,9 @@ impl DiscoveryResponderHandle {
 
 impl Drop for DiscoveryResponderHandle {
     fn drop(&mut self) {
+        if let Some(mdns) = self.mdns.take() {
+            mdns.withdraw();
+        }
         if let Some(tx) = self.shutdown_tx.take() {
             let _ = tx.send(());
         }
@@ -87,6 +114,14 @@ pub async fn start_discovery_responder(
         .set_broadcast(true)
         .map_err(|error| format!("Failed to enable discovery broadcast: {}", error))?;
 
+    let mdns = match advertise_mdns(&config) {
+        Ok(mdns) => Some(mdns),
+        Err(error) => {
+            log::warn!("[Remote Discovery] mDNS advertisement disabled: {}", error);
+            None
+        }
+    };
+
     let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
     let task_handle = tokio::spawn(async move {
         let mut buffer = [0u8; MAX_PACKET_BYTES];
@@ -137,12 +172,168 @@ pub async fn start_discovery_responder(
     Ok(DiscoveryResponderHandle {
         shutdown_tx: Some(shutdown_tx),
         task_handle: Some(task_handle),
+        mdns,
+    })
+}
+
+/// TXT records advertised with the mDNS service. The model is a snapshot from
+/// server start; the UDP reply carries the live one.
+fn mdns_txt_records(server: &DiscoveredRemoteServer) -> Vec<(&'static str, String)> {
+    let auth = if server.auth_required { "1" } else { "0" };
+    vec![
+        ("name", server.name.clone()),
+        ("port", server.port.to_string()),
+        ("model", server.model.clone()),
+        ("auth", auth.to_string()),
+        ("machine_id", server.machine_id.clone()),
+    ]
+}
+
+/// Rebuild a server from a resolved mDNS service's TXT records. `port` is the
+/// SRV port, used when the TXT record is missing or malformed.
+fn server_from_mdns_txt(
+    txt: impl Fn(&str) -> Option<String>,
+    host: String,
+    port: u16,
+) -> Option<DiscoveredRemoteServer> {
+    let machine_id = txt("machine_id").filter(|id| !id.is_empty())?;
+    Some(DiscoveredRemoteServer {
+        name: txt("name").unwrap_or_else(|| host.clone()),
+        port: txt("port")
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(port),
+        model: txt("model").unwrap_or_default(),
+        auth_required: txt("auth").as_deref() == Some("1"),
+        machine_id,
+        host,
     })
 }
 
+fn advertise_mdns(config: &DiscoveryResponderConfig) -> Result<MdnsAdvertisement, String> {
+    let daemon = ServiceDaemon::new().map_err(|error| error.to_string())?;
+    // Instance and host names must be unique on the network; the server name
+    // is only a display label and goes in the TXT record.
+    let instance: String = config.machine_id.chars().take(16).collect();
+    let instance = format!("voicetypr-{}", instance);
+    let txt = mdns_txt_records(&DiscoveredRemoteServer {
+        name: config.server_name.clone(),
+        host: String::new(),
+        port: config.port,
+        model: (config.model_name)(),
+        auth_required: config.auth_required,
+        machine_id: config.machine_id.clone(),
+    });
+    let txt: Vec<(&str, &str)> = txt.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    let service = ServiceInfo::new(
+        MDNS_SERVICE_TYPE,
+        &instance,
+        &format!("{}.local.", instance),
+        "",
+        config.port,
+        &txt[..],
+    )
+    .map_err(|error| error.to_string())?
+    .enable_addr_auto();
+    let fullname = service.get_fullname().to_string();
+
+    if let Err(error) = daemon.register(service) {
+        let _ = daemon.shutdown();
+        return Err(error.to_string());
+    }
+    log::info!("[Remote Discovery] advertising {} over mDNS", fullname);
+    Ok(MdnsAdvertisement { daemon, fullname })
+}
+
+/// Browse both discovery mechanisms for `timeout` and return each server once.
+/// A server seen over UDP wins over its mDNS record, since the UDP reply
+/// reports the model currently loaded.
 pub async fn discover_remote_servers(
     local_machine_id: Option<&str>,
     timeout: Duration,
+) -> Result<Vec<DiscoveredRemoteServer>, String> {
+    let (broadcast, mdns) = tokio::join!(
+        discover_via_broadcast(local_machine_id, timeout),
+        discover_via_mdns(local_machine_id, timeout)
+    );
+    let mdns = mdns.unwrap_or_else(|error| {
+        log::debug!("[Remote Discovery] mDNS browse unavailable: {}", error);
+        Vec::new()
+    });
+    let broadcast = match broadcast {
+        Ok(servers) => servers,
+        Err(error) if !mdns.is_empty() => {
+            log::debug!("[Remote Discovery] broadcast discovery failed: {}", error);
+            Vec::new()
+        }
+        Err(error) => return Err(error),
+    };
+
+    let mut discovered: HashMap<(String, u16), DiscoveredRemoteServer> = HashMap::new();
+    for server in mdns.into_iter().chain(broadcast) {
+        discovered.insert((server.machine_id.clone(), server.port), server);
+    }
+
+    let mut servers: Vec<_> = discovered.into_values().collect();
+    servers.sort_by(|a, b| a.name.cmp(&b.name).then(a.host.cmp(&b.host)));
+    Ok(servers)
+}
+
+async fn discover_via_mdns(
+    local_machine_id: Option<&str>,
+    timeout: Duration,
+) -> Result<Vec<DiscoveredRemoteServer>, String> {
+    let daemon = ServiceDaemon::new().map_err(|error| error.to_string())?;
+    let receiver = match daemon.browse(MDNS_SERVICE_TYPE) {
+        Ok(receiver) => receiver,
+        Err(error) => {
+            let _ = daemon.shutdown();
+            return Err(error.to_string());
+        }
+    };
+
+    let local_machine_id = local_machine_id.map(str::to_string);
+    let deadline = Instant::now() + timeout;
+    let browse = tokio::task::spawn_blocking(move || {
+        let mut discovered = Vec::new();
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            let Ok(event) = receiver.recv_timeout(remaining) else {
+                break;
+            };
+            let ServiceEvent::ServiceResolved(info) = event else {
+                continue;
+            };
+            let addresses = info.get_addresses();
+            let Some(address) = addresses
+                .iter()
+                .find(|address| address.is_ipv4())
+                .or_else(|| addresses.iter().next())
+            else {
+                continue;
+            };
+            let Some(server) = server_from_mdns_txt(
+                |key| info.get_property_val_str(key).map(str::to_string),
+                address.to_string(),
+                info.get_port(),
+            ) else {
+                continue;
+            };
+            if local_machine_id.as_deref() == Some(server.machine_id.as_str()) {
+                continue;
+            }
+            discovered.push(server);
+        }
+        discovered
+    })
+    .await;
+
+    let _ = daemon.stop_browse(MDNS_SERVICE_TYPE);
+    let _ = daemon.shutdown();
+    browse.map_err(|error| format!("mDNS browse task failed: {}", error))
+}
+
+async fn discover_via_broadcast(
+    local_machine_id: Option<&str>,
+    timeout: Duration,
 ) -> Result<Vec<DiscoveredRemoteServer>, String> {
     let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
         .await
@@ -188,9 +379,7 @@ pub async fn discover_remote_servers(
         discovered.insert((server.machine_id.clone(), server.port), server);
     }
 
-    let mut servers: Vec<_> = discovered.into_values().collect();
-    servers.sort_by(|a, b| a.name.cmp(&b.name).then(a.host.cmp(&b.host)));
-    Ok(servers)
+    Ok(discovered.into_values().collect())
 }
 
 #[cfg(test)]
@@ -214,4 +403,31 @@ mod tests {
         let decoded: DiscoveredRemoteServer = serde_json::from_str(&encoded).unwrap();
         assert_eq!(decoded, response);
     }
+
+    #[test]
+    fn mdns_txt_records_round_trip() {
+        let server = DiscoveredRemoteServer {
+            name: "Studio Mac".to_string(),
+            host: "192.168.1.20".to_string(),
+            port: 47842,
+            model: "Parakeet V3".to_string(),
+            auth_required: true,
+            machine_id: "machine-a".to_string(),
+        };
+        let txt: HashMap<_, _> = mdns_txt_records(&server).into_iter().collect();
+        assert!(!txt.contains_key("password"));
+
+        let decoded =
+            server_from_mdns_txt(|key| txt.get(key).cloned(), server.host.clone(), 1).unwrap();
+        assert_eq!(decoded, server);
+    }
+
+    #[test]
+    fn mdns_service_without_machine_id_is_ignored() {
+        let txt: HashMap<&str, String> = HashMap::from([("name", "Other".to_string())]);
+        assert_eq!(
+            server_from_mdns_txt(|key| txt.get(key).cloned(), "10.0.0.2".to_string(), 80),
+            None
+        );
+    }
 }
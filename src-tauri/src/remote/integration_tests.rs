@@ -19,7 +19,7 @@
 //! ```
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use crate::remote::http::{create_routes, ClientActivityMap, ServerContext};
     use crate::remote::transcription::{RealTranscriptionContext, TranscriptionServerConfig};
     use futures_util::future::join_all;
@@ -93,7 +93,7 @@ mod tests {
     /// Ensure the tiny.en model is available, downloading if necessary
     /// Returns the path to the model file
     /// Note: This uses blocking I/O and should be called via spawn_blocking in async contexts
-    fn ensure_tiny_model_available_sync() -> Result<PathBuf, String> {
+    pub(crate) fn ensure_tiny_model_available_sync() -> Result<PathBuf, String> {
         let model_dir = get_model_dir();
         let model_path = model_dir.join(TINY_MODEL_FILENAME);
 
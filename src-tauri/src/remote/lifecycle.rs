@@ -40,7 +40,7 @@ pub struct ServerHandle {
     pub port: u16,
     /// Results of binding attempts (for UI display)
     pub binding_results: Vec<BindingResult>,
-    /// UDP discovery responder advertised while the server is running.
+    /// UDP and mDNS discovery advertised while the server is running.
     discovery_handle: Option<DiscoveryResponderHandle>,
 }
 
@@ -4,6 +4,7 @@
 //! between Voicetypr instances over the network.
 
 pub mod client;
+pub mod control;
 pub mod discovery;
 pub mod http;
 pub mod lifecycle;
@@ -13,7 +14,7 @@ pub mod settings;
 pub mod transcription;
 
 #[cfg(test)]
-mod integration_tests;
+pub(crate) mod integration_tests;
 
 #[cfg(test)]
 mod concurrent_tests;
@@ -388,6 +388,12 @@ impl RealTranscriptionContext {
 
         log::info!("Whisper model loaded, starting transcription...");
 
+        let decoding = self
+            .app_handle
+            .as_ref()
+            .map(crate::commands::audio::whisper_decoding_options)
+            .unwrap_or_default();
+
         // Perform transcription (this can take a while)
         transcriber
             .transcribe_with_metadata_with_prompt(
@@ -395,6 +401,7 @@ impl RealTranscriptionContext {
                 spoken_language,
                 translate_to_english,
                 context,
+                decoding,
                 || false,
             )
             .map_err(|e| format!("Whisper transcription failed: {}", e))
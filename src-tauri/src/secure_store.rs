@@ -8,15 +8,49 @@ use once_cell::sync::OnceCell;
 use pbkdf2::pbkdf2_hmac;
 use rand::Rng;
 use sha2::Sha256;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard, RwLock};
 use tauri::{AppHandle, Runtime};
 use tauri_plugin_store::StoreExt;
 
-// Encryption key storage - OnceCell ensures thread-safe single initialization
-static ENCRYPTION_KEY: OnceCell<[u8; 32]> = OnceCell::new();
+// Encryption key storage - replaced only by `rotate_encryption_key`
+static ENCRYPTION_KEY: RwLock<Option<[u8; 32]>> = RwLock::new(None);
 
-/// Initialize the encryption key using the device hash with PBKDF2
+// Set when a key salt is stored but the rotated key can't be derived from it.
+// Every value is then encrypted under a key we don't have, so reads must fail
+// rather than treat the entries as corrupted and delete them
+static ROTATED_KEY_UNUSABLE: AtomicBool = AtomicBool::new(false);
+
+// Serializes every secure-store access, so a rotation never interleaves with
+// another read or write of secure.dat
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+// Device hash the key is derived from, validated once
+static DEVICE_HASH: OnceCell<String> = OnceCell::new();
+
+/// Secure-store entry holding the random salt of the current key, base64.
+/// Absent until the first rotation; never an encrypted value. It sits next to
+/// the ciphertexts, so the key stays as secret as the device hash, no more:
+/// rotation re-encrypts, it does not add a secret.
+pub const KEY_SALT_ENTRY: &str = "__encryption_key_salt";
+
+/// Initialize the encryption key using the device hash with PBKDF2. Later
+/// calls keep the current (possibly rotated) key.
 pub fn initialize_encryption_key() -> Result<(), String> {
-    ENCRYPTION_KEY
+    let mut current = ENCRYPTION_KEY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if current.is_some() {
+        return Ok(());
+    }
+    let key = derive_key(device_hash()?, &[])?;
+    log::info!("Initialized encryption with PBKDF2-derived device-specific key");
+    *current = Some(key);
+    Ok(())
+}
+
+fn device_hash() -> Result<&'static str, String> {
+    DEVICE_HASH
         .get_or_try_init(|| {
             // Get the same device hash used for API authentication
             let device_hash = device::get_device_hash()?;
@@ -35,24 +69,176 @@ pub fn initialize_encryption_key() -> Result<(), String> {
                 return Err("Device hash contains invalid characters".to_string());
             }
 
-            // Use PBKDF2 to derive a proper encryption key from the device hash
-            let mut key = [0u8; 32];
+            Ok(device_hash)
+        })
+        .map(String::as_str)
+}
 
-            // Salt: app-specific constant + version for future migration support
-            let salt = b"voicetypr-secure-store-v1";
+/// Derive a key from the device hash. `extra_salt` is empty for the original
+/// key and random bytes for every rotated one.
+fn derive_key(device_hash: &str, extra_salt: &[u8]) -> Result<[u8; 32], String> {
+    // Use PBKDF2 to derive a proper encryption key from the device hash
+    let mut key = [0u8; 32];
 
-            // 100,000 iterations for good security/performance balance
-            pbkdf2_hmac::<Sha256>(device_hash.as_bytes(), salt, 100_000, &mut key);
+    // Salt: app-specific constant + version for future migration support
+    let mut salt = b"voicetypr-secure-store-v1".to_vec();
+    salt.extend_from_slice(extra_salt);
 
-            // Verify key was properly generated (not all zeros)
-            if key.iter().all(|&b| b == 0) {
-                return Err("Failed to generate encryption key".to_string());
+    // 100,000 iterations for good security/performance balance
+    pbkdf2_hmac::<Sha256>(device_hash.as_bytes(), &salt, 100_000, &mut key);
+
+    // Verify key was properly generated (not all zeros)
+    if key.iter().all(|&b| b == 0) {
+        return Err("Failed to generate encryption key".to_string());
+    }
+    Ok(key)
+}
+
+/// Switch to the rotated key recorded in the secure store, if any. Call once
+/// the store plugin is available, before any secret is read.
+pub fn load_rotated_encryption_key<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let _guard = lock_store();
+    let Ok(store) = app.store("secure.dat") else {
+        return Ok(());
+    };
+    let Some(salt) = store
+        .get(KEY_SALT_ENTRY)
+        .and_then(|v| v.as_str().map(str::to_string))
+    else {
+        return Ok(());
+    };
+    let key = match rotated_key(&salt) {
+        Ok(key) => key,
+        Err(e) => {
+            ROTATED_KEY_UNUSABLE.store(true, Ordering::SeqCst);
+            return Err(e);
+        }
+    };
+    *ENCRYPTION_KEY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(key);
+    ROTATED_KEY_UNUSABLE.store(false, Ordering::SeqCst);
+    log::info!("Loaded rotated secure store key");
+    Ok(())
+}
+
+/// The key a rotation left behind, from its stored base64 salt.
+fn rotated_key(salt: &str) -> Result<[u8; 32], String> {
+    let salt = general_purpose::STANDARD
+        .decode(salt)
+        .map_err(|_| "Stored encryption key salt is not valid base64".to_string())?;
+    derive_key(device_hash()?, &salt)
+}
+
+fn lock_store() -> MutexGuard<'static, ()> {
+    STORE_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn current_key() -> Result<[u8; 32], String> {
+    if ROTATED_KEY_UNUSABLE.load(Ordering::SeqCst) {
+        return Err(
+            "Secure store key could not be loaded; stored values are unreadable".to_string(),
+        );
+    }
+    ENCRYPTION_KEY
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .as_ref()
+        .copied()
+        .ok_or_else(|| "Encryption key not initialized".to_string())
+}
+
+/// Decrypt every value with `old_key` and encrypt it again with `new_key`.
+/// Fails without partial output when any value cannot be decrypted; the error
+/// names keys only, never values.
+fn reencrypt_entries(
+    entries: &[(String, String)],
+    old_key: &[u8; 32],
+    new_key: &[u8; 32],
+) -> Result<Vec<(String, String)>, String> {
+    let mut unreadable = Vec::new();
+    let mut rotated = Vec::with_capacity(entries.len());
+    for (name, encrypted) in entries {
+        match decrypt_with_key(old_key, encrypted) {
+            Ok(plaintext) => rotated.push((name.clone(), encrypt_with_key(new_key, &plaintext)?)),
+            Err(_) => unreadable.push(name.as_str()),
+        }
+    }
+    if !unreadable.is_empty() {
+        return Err(format!(
+            "Cannot rotate: stored values for {} could not be decrypted",
+            unreadable.join(", ")
+        ));
+    }
+    Ok(rotated)
+}
+
+/// Re-encrypt every stored secret (cloud STT, AI provider and license keys)
+/// under a freshly salted key with fresh nonces. This is re-encryption only:
+/// the new key is derived from the device hash and a salt kept in secure.dat,
+/// so anyone holding the file on this device can still derive it, and a store
+/// copied from another machine fails to decrypt here before and after. All
+/// values are written in one store save; when any step fails the store and the
+/// in-memory key are left as they were. Returns the names of the rotated entries.
+pub fn rotate_encryption_key<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<String>, String> {
+    // No other secure-store access can read an entry under one key and
+    // decrypt it with the other until the new key is in place
+    let _guard = lock_store();
+    let old_key = current_key()?;
+
+    let store = app
+        .store("secure.dat")
+        .map_err(|e| format!("Failed to access store: {}", e))?;
+    let mut entries: Vec<(String, String)> = store
+        .entries()
+        .into_iter()
+        .filter(|(name, _)| name != KEY_SALT_ENTRY)
+        .filter_map(|(name, value)| value.as_str().map(|v| (name, v.to_string())))
+        .collect();
+    entries.sort();
+
+    let mut salt = [0u8; 16];
+    rand::thread_rng().fill(&mut salt);
+    let new_key = derive_key(device_hash()?, &salt)?;
+    let rotated = reencrypt_entries(&entries, &old_key, &new_key)?;
+
+    let previous_salt = store.get(KEY_SALT_ENTRY);
+    for (name, encrypted) in &rotated {
+        store.set(name.as_str(), encrypted.as_str());
+    }
+    store.set(KEY_SALT_ENTRY, general_purpose::STANDARD.encode(salt));
+    if let Err(e) = store.save() {
+        // Put the old ciphertexts and salt back so the old key still reads them
+        for (name, encrypted) in &entries {
+            store.set(name.as_str(), encrypted.as_str());
+        }
+        match previous_salt {
+            Some(salt) => store.set(KEY_SALT_ENTRY, salt),
+            None => {
+                store.delete(KEY_SALT_ENTRY);
             }
+        }
+        if let Err(restore_err) = store.save() {
+            log::error!(
+                "Failed to restore secure store after aborted key rotation: {}",
+                restore_err
+            );
+        }
+        return Err(format!("Failed to save rotated secure store: {}", e));
+    }
 
-            log::info!("Initialized encryption with PBKDF2-derived device-specific key");
-            Ok(key)
-        })
-        .map(|_| ())
+    *ENCRYPTION_KEY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(new_key);
+    let names: Vec<String> = rotated.into_iter().map(|(name, _)| name).collect();
+    log::info!(
+        "Rotated secure store key; re-encrypted {} value(s): {}",
+        names.len(),
+        names.join(", ")
+    );
+    Ok(names)
 }
 
 /// Check if migration from keychain is needed (for future use)
@@ -72,10 +258,10 @@ pub fn check_migration_needed<R: Runtime>(app: &AppHandle<R>) -> bool {
 
 /// Encrypt a string value
 fn encrypt_value(value: &str) -> Result<String, String> {
-    let key = ENCRYPTION_KEY
-        .get()
-        .ok_or("Encryption key not initialized")?;
+    encrypt_with_key(&current_key()?, value)
+}
 
+fn encrypt_with_key(key: &[u8; 32], value: &str) -> Result<String, String> {
     let cipher = Aes256Gcm::new_from_slice(key).map_err(|_| "Failed to create cipher")?;
 
     // Generate random nonce
@@ -98,10 +284,27 @@ fn encrypt_value(value: &str) -> Result<String, String> {
 
 /// Decrypt a string value
 fn decrypt_value(encrypted: &str) -> Result<String, String> {
-    let key = ENCRYPTION_KEY
-        .get()
-        .ok_or("Encryption key not initialized")?;
+    decrypt_with_key(&current_key()?, encrypted)
+}
+
+/// A stored ciphertext read with a usable key.
+enum StoredRead {
+    Value(String),
+    /// The key is fine but this value doesn't decrypt under it.
+    Corrupted(String),
+}
 
+/// Decrypt `encrypted` with `key`. A missing key is an error, never
+/// corruption, so callers don't delete values they merely can't read yet.
+fn read_stored(key: Result<[u8; 32], String>, encrypted: &str) -> Result<StoredRead, String> {
+    let key = key?;
+    Ok(match decrypt_with_key(&key, encrypted) {
+        Ok(value) => StoredRead::Value(value),
+        Err(e) => StoredRead::Corrupted(e),
+    })
+}
+
+fn decrypt_with_key(key: &[u8; 32], encrypted: &str) -> Result<String, String> {
     // Base64 decode
     let combined = general_purpose::STANDARD
         .decode(encrypted)
@@ -127,6 +330,7 @@ fn decrypt_value(encrypted: &str) -> Result<String, String> {
 
 /// Set an encrypted value in the store
 pub fn secure_set<R: Runtime>(app: &AppHandle<R>, key: &str, value: &str) -> Result<(), String> {
+    let _guard = lock_store();
     let encrypted = encrypt_value(value)?;
 
     let store = app
@@ -143,6 +347,7 @@ pub fn secure_set<R: Runtime>(app: &AppHandle<R>, key: &str, value: &str) -> Res
 
 /// Get and decrypt a value from the store with corruption recovery
 pub fn secure_get<R: Runtime>(app: &AppHandle<R>, key: &str) -> Result<Option<String>, String> {
+    let _guard = lock_store();
     // Try to access the store with recovery on failure
     let store = match app.store("secure.dat") {
         Ok(store) => store,
@@ -157,9 +362,9 @@ pub fn secure_get<R: Runtime>(app: &AppHandle<R>, key: &str) -> Result<Option<St
         Some(value) => {
             if let Some(encrypted) = value.as_str() {
                 // Try to decrypt, but handle corruption gracefully
-                match decrypt_value(encrypted) {
-                    Ok(decrypted) => Ok(Some(decrypted)),
-                    Err(e) => {
+                match read_stored(current_key(), encrypted)? {
+                    StoredRead::Value(decrypted) => Ok(Some(decrypted)),
+                    StoredRead::Corrupted(e) => {
                         log::error!(
                             "Decryption failed for key '{}': {}. Data may be corrupted.",
                             key,
@@ -196,6 +401,7 @@ pub fn secure_get<R: Runtime>(app: &AppHandle<R>, key: &str) -> Result<Option<St
 
 /// Delete a value from the secure store
 pub fn secure_delete<R: Runtime>(app: &AppHandle<R>, key: &str) -> Result<(), String> {
+    let _guard = lock_store();
     let store = app
         .store("secure.dat")
         .map_err(|e| format!("Failed to access store: {}", e))?;
@@ -210,6 +416,7 @@ pub fn secure_delete<R: Runtime>(app: &AppHandle<R>, key: &str) -> Result<(), St
 
 /// Check if a key exists in the secure store
 pub fn secure_has<R: Runtime>(app: &AppHandle<R>, key: &str) -> Result<bool, String> {
+    let _guard = lock_store();
     let store = match app.store("secure.dat") {
         Ok(store) => store,
         Err(_) => {
@@ -223,7 +430,7 @@ pub fn secure_has<R: Runtime>(app: &AppHandle<R>, key: &str) -> Result<bool, Str
         Some(value) => {
             if let Some(encrypted) = value.as_str() {
                 // Only return true if we can successfully decrypt it
-                decrypt_value(encrypted).is_ok()
+                matches!(read_stored(current_key(), encrypted)?, StoredRead::Value(_))
             } else {
                 false
             }
@@ -232,6 +439,60 @@ pub fn secure_has<R: Runtime>(app: &AppHandle<R>, key: &str) -> Result<bool, Str
     })
 }
 
+/// List the keys whose values are present and decryptable. Values are never returned.
+pub fn secure_list_keys<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<String>, String> {
+    let _guard = lock_store();
+    let store = match app.store("secure.dat") {
+        Ok(store) => store,
+        Err(_) => return Ok(Vec::new()),
+    };
+    let encryption_key = current_key()?;
+
+    let mut keys: Vec<String> = store
+        .keys()
+        .into_iter()
+        .filter(|key| {
+            store
+                .get(key)
+                .and_then(|value| {
+                    value
+                        .as_str()
+                        .map(|encrypted| decrypt_with_key(&encryption_key, encrypted).is_ok())
+                })
+                .unwrap_or(false)
+        })
+        .collect();
+    keys.sort();
+    Ok(keys)
+}
+
+/// Delete every key for which `remove` returns true, saving once. Returns the removed keys.
+pub fn secure_delete_where<R: Runtime>(
+    app: &AppHandle<R>,
+    remove: impl Fn(&str) -> bool,
+) -> Result<Vec<String>, String> {
+    let _guard = lock_store();
+    let store = app
+        .store("secure.dat")
+        .map_err(|e| format!("Failed to access store: {}", e))?;
+
+    // The key salt stays: remaining values are still encrypted under it
+    let mut removed: Vec<String> = store
+        .keys()
+        .into_iter()
+        .filter(|key| key != KEY_SALT_ENTRY && remove(key))
+        .collect();
+    removed.sort();
+    for key in &removed {
+        store.delete(key);
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to save store: {}", e))?;
+
+    Ok(removed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,6 +525,68 @@ mod tests {
         assert_eq!(decrypt_value(&encrypted2).unwrap(), original);
     }
 
+    #[test]
+    fn test_value_round_trips_through_rotation() {
+        initialize_encryption_key().unwrap();
+        let old_key = current_key().unwrap();
+        let new_key = derive_key(device_hash().unwrap(), b"rotation-test-salt").unwrap();
+        assert_ne!(old_key, new_key);
+
+        let entries = vec![(
+            "soniox".to_string(),
+            encrypt_with_key(&old_key, "my-secret-api-key").unwrap(),
+        )];
+        let rotated = reencrypt_entries(&entries, &old_key, &new_key).unwrap();
+
+        assert_eq!(rotated[0].0, "soniox");
+        assert_eq!(
+            decrypt_with_key(&new_key, &rotated[0].1).unwrap(),
+            "my-secret-api-key"
+        );
+        assert!(decrypt_with_key(&old_key, &rotated[0].1).is_err());
+    }
+
+    #[test]
+    fn test_rotation_aborts_on_undecryptable_value() {
+        initialize_encryption_key().unwrap();
+        let old_key = current_key().unwrap();
+        let new_key = derive_key(device_hash().unwrap(), b"rotation-test-salt").unwrap();
+
+        let entries = vec![
+            (
+                "ai_api_key_openai".to_string(),
+                encrypt_with_key(&old_key, "sk-test").unwrap(),
+            ),
+            ("ai_api_key_groq".to_string(), "dGVzdA==".to_string()),
+        ];
+        let error = reencrypt_entries(&entries, &old_key, &new_key).unwrap_err();
+
+        assert!(error.contains("ai_api_key_groq"));
+        assert!(!error.contains("sk-test"));
+    }
+
+    #[test]
+    fn test_unloadable_key_salt_leaves_stored_values_alone() {
+        initialize_encryption_key().unwrap();
+        let key = current_key().unwrap();
+        let encrypted = encrypt_with_key(&key, "sk-test").unwrap();
+
+        let load = rotated_key("not-valid-base64!");
+        assert!(load.is_err());
+        // Without the key a read fails instead of reporting corruption, so
+        // secure_get returns the error and keeps the entry
+        assert!(read_stored(load, &encrypted).is_err());
+
+        assert!(matches!(
+            read_stored(Ok(key), &encrypted).unwrap(),
+            StoredRead::Value(value) if value == "sk-test"
+        ));
+        assert!(matches!(
+            read_stored(Ok(key), "dGVzdA==").unwrap(),
+            StoredRead::Corrupted(_)
+        ));
+    }
+
     #[test]
     fn test_decrypt_with_corrupted_data() {
         initialize_encryption_key().unwrap();
@@ -2,10 +2,11 @@ use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use tauri::{Emitter, Manager};
 
+use crate::state::transcription_queue::TranscriptionQueue;
 use crate::state::unified_state::UnifiedRecordingState;
 use crate::window_manager::WindowManager;
 
@@ -21,6 +22,20 @@ pub enum RecordingState {
     Error,
 }
 
+impl RecordingState {
+    /// Lowercase name used in `recording-state-changed` payloads.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RecordingState::Idle => "idle",
+            RecordingState::Starting => "starting",
+            RecordingState::Recording => "recording",
+            RecordingState::Stopping => "stopping",
+            RecordingState::Transcribing => "transcribing",
+            RecordingState::Error => "error",
+        }
+    }
+}
+
 /// Recording mode enum to distinguish between toggle and push-to-talk
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RecordingMode {
@@ -28,6 +43,44 @@ pub enum RecordingMode {
     PushToTalk,
 }
 
+/// How long the current recording has been capturing, not counting pauses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordingClock {
+    started_at: Instant,
+    paused_at: Option<Instant>,
+}
+
+impl RecordingClock {
+    pub fn start_at(now: Instant) -> Self {
+        Self {
+            started_at: now,
+            paused_at: None,
+        }
+    }
+
+    /// Stop or restart the clock. Repeated calls with the same value are no-ops.
+    pub fn set_paused_at(&mut self, paused: bool, now: Instant) {
+        match (paused, self.paused_at) {
+            (true, None) => self.paused_at = Some(now),
+            (false, Some(since)) => {
+                self.started_at += now.saturating_duration_since(since);
+                self.paused_at = None;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    pub fn elapsed_at(&self, now: Instant) -> Duration {
+        self.paused_at
+            .unwrap_or(now)
+            .saturating_duration_since(self.started_at)
+    }
+}
+
 /// Queued event for the pill window
 #[derive(Debug, Clone)]
 pub struct QueuedPillEvent {
@@ -39,9 +92,12 @@ pub struct QueuedPillEvent {
 pub struct AppState {
     pub recording_state: UnifiedRecordingState,
     pub current_recording_path: Arc<Mutex<Option<PathBuf>>>,
-    pub transcription_task: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    pub transcription_queue: TranscriptionQueue,
     pub recording_mode: Arc<Mutex<RecordingMode>>,
     pub ptt_key_held: Arc<AtomicBool>,
+    pub ptt_pressed_at: Arc<Mutex<Option<Instant>>>,
+    // Capture time of the current recording, started when it entered Recording
+    pub recording_clock: Arc<Mutex<Option<RecordingClock>>>,
     pub toggle_key_held: Arc<AtomicBool>,
     pub active_custom_hold_bindings: Arc<Mutex<HashSet<String>>>,
     pub active_custom_pressed_bindings: Arc<Mutex<HashSet<String>>>,
@@ -58,6 +114,11 @@ pub struct AppState {
     pub license_cache: Arc<tokio::sync::RwLock<Option<crate::commands::license::CachedLicense>>>,
     pub pill_event_queue: Arc<Mutex<Vec<QueuedPillEvent>>>,
     pub last_toggle_press: Arc<Mutex<Option<Instant>>>,
+    pub session_profile: Arc<Mutex<Option<crate::commands::settings::AppProfile>>>,
+    // Overrides staged by the shortcut that starts the next recording
+    pub shortcut_profile: Arc<Mutex<Option<crate::commands::settings::AppProfile>>>,
+    // Reply channel of a pending `test_record_once`; makes the next stop a dry run
+    pub dry_run_reply: Arc<Mutex<Option<tokio::sync::oneshot::Sender<Result<String, String>>>>>,
 }
 
 impl Default for AppState {
@@ -71,9 +132,11 @@ impl AppState {
         Self {
             recording_state: UnifiedRecordingState::new(),
             current_recording_path: Arc::new(Mutex::new(None)),
-            transcription_task: Arc::new(Mutex::new(None)),
+            transcription_queue: TranscriptionQueue::new(),
             recording_mode: Arc::new(Mutex::new(RecordingMode::Toggle)),
             ptt_key_held: Arc::new(AtomicBool::new(false)),
+            ptt_pressed_at: Arc::new(Mutex::new(None)),
+            recording_clock: Arc::new(Mutex::new(None)),
             toggle_key_held: Arc::new(AtomicBool::new(false)),
             active_custom_hold_bindings: Arc::new(Mutex::new(HashSet::new())),
             active_custom_pressed_bindings: Arc::new(Mutex::new(HashSet::new())),
@@ -89,6 +152,9 @@ impl AppState {
             license_cache: Arc::new(tokio::sync::RwLock::new(None)),
             pill_event_queue: Arc::new(Mutex::new(Vec::new())),
             last_toggle_press: Arc::new(Mutex::new(None)),
+            session_profile: Arc::new(Mutex::new(None)),
+            shortcut_profile: Arc::new(Mutex::new(None)),
+            dry_run_reply: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -237,18 +303,12 @@ pub fn update_recording_state(
         };
 
     let payload = serde_json::json!({
-        "state": match final_state {
-            RecordingState::Idle => "idle",
-            RecordingState::Starting => "starting",
-            RecordingState::Recording => "recording",
-            RecordingState::Stopping => "stopping",
-            RecordingState::Transcribing => "transcribing",
-            RecordingState::Error => "error",
-        },
+        "state": final_state.as_str(),
         "error": error
     });
 
     let _ = app.emit("recording-state-changed", payload);
+    crate::state::recording_events::emit_recording_event(app, final_state.into(), error);
 }
 
 /// Helper function to get current recording state
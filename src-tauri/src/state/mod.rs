@@ -1,4 +1,6 @@
 pub mod app_state;
+pub mod recording_events;
+pub mod transcription_queue;
 pub mod unified_state;
 
 pub use app_state::{
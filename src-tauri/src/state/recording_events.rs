@@ -0,0 +1,96 @@
+//! Versioned `recording-event` stream.
+//!
+//! One event per lifecycle transition, with the same payload shape every
+//! time, so integrators don't have to combine `recording-state-changed`,
+//! `transcription-started`, `enhancing-*` and friends. Those legacy events
+//! are still emitted alongside it.
+
+use serde::Serialize;
+use tauri::Emitter;
+use tauri_plugin_store::StoreExt;
+
+use crate::state::RecordingState;
+
+pub const RECORDING_EVENT: &str = "recording-event";
+
+/// Payload schema version; bump it when a field changes meaning or goes away.
+pub const RECORDING_EVENT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingPhase {
+    Idle,
+    Starting,
+    Recording,
+    Stopping,
+    Transcribing,
+    Error,
+    EnhancingStarted,
+    EnhancingCompleted,
+    EnhancingFailed,
+}
+
+impl From<RecordingState> for RecordingPhase {
+    fn from(state: RecordingState) -> Self {
+        match state {
+            RecordingState::Idle => Self::Idle,
+            RecordingState::Starting => Self::Starting,
+            RecordingState::Recording => Self::Recording,
+            RecordingState::Stopping => Self::Stopping,
+            RecordingState::Transcribing => Self::Transcribing,
+            RecordingState::Error => Self::Error,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RecordingEvent {
+    pub version: u32,
+    pub phase: RecordingPhase,
+    /// Selected model, `None` when no model is selected
+    pub model: Option<String>,
+    /// Selected engine (`whisper`, `parakeet` or a cloud provider id)
+    pub engine: Option<String>,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// Error message or other context for the phase
+    pub detail: Option<String>,
+}
+
+impl RecordingEvent {
+    pub fn new(
+        phase: RecordingPhase,
+        model: Option<String>,
+        engine: Option<String>,
+        detail: Option<String>,
+    ) -> Self {
+        Self {
+            version: RECORDING_EVENT_VERSION,
+            phase,
+            model,
+            engine,
+            timestamp: chrono::Utc::now().timestamp_millis().max(0) as u64,
+            detail,
+        }
+    }
+}
+
+/// Emit a `recording-event` for `phase`, tagged with the selected model and engine.
+pub fn emit_recording_event(app: &tauri::AppHandle, phase: RecordingPhase, detail: Option<String>) {
+    let (model, engine) = match app.store("settings") {
+        Ok(store) => {
+            let read = |key: &str| {
+                store
+                    .get(key)
+                    .and_then(|v| v.as_str().map(str::to_string))
+                    .filter(|value| !value.is_empty())
+            };
+            (read("current_model"), read("current_model_engine"))
+        }
+        Err(_) => (None, None),
+    };
+    let event = RecordingEvent::new(phase, model, engine, detail);
+    if let Err(e) = app.emit(RECORDING_EVENT, &event) {
+        log::debug!("Failed to emit {}: {}", RECORDING_EVENT, e);
+    }
+}
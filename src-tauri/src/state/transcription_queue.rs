@@ -0,0 +1,161 @@
+//! FIFO queue of transcription jobs.
+//!
+//! `stop_recording` used to abort whatever transcription task was still
+//! running when it spawned the next one, so a dictation still transcribing
+//! when the next one stopped was lost. Jobs now run one at a time, in the
+//! order they were stopped, on a worker task. A job covers delivery too
+//! (enhancement, insertion, history), so results are inserted in order; only
+//! the job currently running can be cancelled.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tokio::task::AbortHandle;
+
+type TranscriptionJob = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// A queued job and the recording generation it transcribes.
+type QueuedJob = (u64, TranscriptionJob);
+
+/// The running job's generation and the handle that aborts it.
+type RunningJob = Option<(u64, AbortHandle)>;
+
+/// Returned by [`TranscriptionQueue::enqueue`] when the queue is at capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TranscriptionQueueFull {
+    pub capacity: usize,
+}
+
+#[derive(Default)]
+pub struct TranscriptionQueue {
+    sender: Mutex<Option<mpsc::UnboundedSender<QueuedJob>>>,
+    // Running plus waiting jobs
+    queued: Arc<AtomicUsize>,
+    // Generations of jobs not yet picked up by the worker
+    waiting: Arc<Mutex<Vec<u64>>>,
+    current: Arc<Mutex<RunningJob>>,
+}
+
+impl TranscriptionQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `job`, which transcribes recording `generation`, behind any
+    /// running or pending transcription. `max_queued` counts the running job,
+    /// so `1` means "only when idle".
+    pub fn enqueue<F>(
+        &self,
+        job: F,
+        generation: u64,
+        max_queued: usize,
+    ) -> Result<(), TranscriptionQueueFull>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.queued
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |queued| {
+                (queued < max_queued).then_some(queued + 1)
+            })
+            .map_err(|_| TranscriptionQueueFull {
+                capacity: max_queued,
+            })?;
+
+        let mut sender = self
+            .sender
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.waiting
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(generation);
+        let mut job: QueuedJob = (generation, Box::pin(job));
+        if let Some(tx) = sender.as_ref() {
+            match tx.send(job) {
+                Ok(()) => return Ok(()),
+                // The worker's runtime is gone; start a new one below
+                Err(mpsc::error::SendError(returned)) => job = returned,
+            }
+        }
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(run_worker(
+            rx,
+            self.queued.clone(),
+            self.waiting.clone(),
+            self.current.clone(),
+        ));
+        let _ = tx.send(job);
+        *sender = Some(tx);
+        Ok(())
+    }
+
+    /// Whether a transcription is running or waiting to run.
+    pub fn is_busy(&self) -> bool {
+        self.queued() > 0
+    }
+
+    /// Number of transcriptions running or waiting to run.
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+
+    /// Whether the job for `generation` is queued behind another one.
+    pub fn is_waiting(&self, generation: u64) -> bool {
+        self.waiting
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .contains(&generation)
+    }
+
+    /// Abort the running transcription; the worker then moves on to the next
+    /// queued one. Returns the aborted job's generation, or `None` when
+    /// nothing was running.
+    pub fn cancel_current(&self) -> Option<u64> {
+        let current = self
+            .current
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        current.map(|(generation, handle)| {
+            handle.abort();
+            generation
+        })
+    }
+}
+
+async fn run_worker(
+    mut rx: mpsc::UnboundedReceiver<QueuedJob>,
+    queued: Arc<AtomicUsize>,
+    waiting: Arc<Mutex<Vec<u64>>>,
+    current: Arc<Mutex<RunningJob>>,
+) {
+    while let Some((generation, job)) = rx.recv().await {
+        {
+            let mut waiting = waiting
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            if let Some(index) = waiting.iter().position(|g| *g == generation) {
+                waiting.remove(index);
+            }
+        }
+        // Each job runs as its own task so it can be aborted without
+        // taking the worker down with it
+        let handle = tokio::spawn(job);
+        *current
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) =
+            Some((generation, handle.abort_handle()));
+        if let Err(e) = handle.await {
+            if e.is_panic() {
+                log::error!("Transcription task panicked: {}", e);
+            }
+        }
+        current
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        queued.fetch_sub(1, Ordering::SeqCst);
+    }
+}
@@ -54,10 +54,7 @@ mod tests {
             assert!(path.is_none());
         }
 
-        {
-            let task = app_state.transcription_task.lock().unwrap();
-            assert!(task.is_none());
-        }
+        assert!(!app_state.transcription_queue.is_busy());
     }
 
     #[test]
@@ -210,73 +207,95 @@ mod tests {
         }
     }
 
+    /// Poll until the queue drains, failing the test instead of hanging.
+    async fn wait_until_idle(app_state: &AppState) {
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while app_state.transcription_queue.is_busy() {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("transcription queue did not drain");
+    }
+
     #[tokio::test]
-    async fn test_transcription_task_management() {
+    async fn test_transcription_queue_completes_jobs_in_order() {
         let app_state = AppState::new();
-
-        // Create a dummy task
-        let task = tokio::spawn(async {
-            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-        });
-
-        // Store the task
-        {
-            let mut task_guard = app_state.transcription_task.lock().unwrap();
-            *task_guard = Some(task);
-        }
-
-        // Verify task is stored
-        {
-            let task_guard = app_state.transcription_task.lock().unwrap();
-            assert!(task_guard.is_some());
+        let completed = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        // The first job is slower, so a concurrent runner would finish it last
+        for (id, delay_ms) in [(1, 50), (2, 0)] {
+            let completed = completed.clone();
+            app_state
+                .transcription_queue
+                .enqueue(
+                    async move {
+                        tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+                        completed.lock().unwrap().push(id);
+                    },
+                    id,
+                    3,
+                )
+                .unwrap();
         }
+        assert_eq!(app_state.transcription_queue.queued(), 2);
 
-        // Take and await the task
-        let task = {
-            let mut task_guard = app_state.transcription_task.lock().unwrap();
-            task_guard.take()
-        };
-
-        if let Some(task) = task {
-            // Task should complete successfully
-            assert!(task.await.is_ok());
-        }
+        wait_until_idle(&app_state).await;
+        assert_eq!(*completed.lock().unwrap(), vec![1, 2]);
+    }
 
-        // Verify task is now None
-        {
-            let task_guard = app_state.transcription_task.lock().unwrap();
-            assert!(task_guard.is_none());
-        }
+    #[tokio::test]
+    async fn test_transcription_queue_rejects_beyond_cap() {
+        let app_state = AppState::new();
+        let queue = &app_state.transcription_queue;
+        let long_job = || tokio::time::sleep(std::time::Duration::from_secs(10));
+
+        assert!(queue.enqueue(long_job(), 1, 2).is_ok());
+        assert!(queue.enqueue(long_job(), 2, 2).is_ok());
+        let full = queue.enqueue(long_job(), 3, 2).unwrap_err();
+        assert_eq!(full.capacity, 2);
+        assert_eq!(queue.queued(), 2);
     }
 
     #[tokio::test]
     async fn test_task_cancellation() {
         let app_state = AppState::new();
+        let queue = &app_state.transcription_queue;
+        let finished = Arc::new(std::sync::atomic::AtomicBool::new(false));
 
-        // Create a long-running task
-        let task = tokio::spawn(async {
-            tokio::time::sleep(std::time::Duration::from_secs(10)).await;
-        });
-
-        // Store the task
-        {
-            let mut task_guard = app_state.transcription_task.lock().unwrap();
-            *task_guard = Some(task);
-        }
-
-        // Cancel the task
-        {
-            let mut task_guard = app_state.transcription_task.lock().unwrap();
-            if let Some(task) = task_guard.take() {
-                task.abort();
+        // A long-running job, then one that should still run after the cancel
+        queue
+            .enqueue(tokio::time::sleep(std::time::Duration::from_secs(10)), 1, 3)
+            .unwrap();
+        let finished_for_job = finished.clone();
+        queue
+            .enqueue(
+                async move {
+                    finished_for_job.store(true, std::sync::atomic::Ordering::SeqCst);
+                },
+                2,
+                3,
+            )
+            .unwrap();
+        assert!(queue.is_waiting(2));
+
+        // Wait for the worker to pick up the first job before cancelling it
+        let cancelled = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                if let Some(generation) = queue.cancel_current() {
+                    break generation;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
             }
-        }
-
-        // Verify task is cancelled and removed
-        {
-            let task_guard = app_state.transcription_task.lock().unwrap();
-            assert!(task_guard.is_none());
-        }
+        })
+        .await
+        .expect("first job never started");
+        assert_eq!(cancelled, 1, "only the running job is cancelled");
+
+        wait_until_idle(&app_state).await;
+        assert!(finished.load(std::sync::atomic::Ordering::SeqCst));
+        assert!(!queue.is_waiting(2));
+        assert_eq!(queue.cancel_current(), None);
     }
 
     // --- PTT race condition tests ---
@@ -534,7 +534,8 @@ mod audio_buffer_tests {
 mod recording_persist_and_cancel_tests {
     use crate::commands::audio::{
         begin_recording_generation, finalize_in_flight_audio, set_in_flight_transcription_audio,
-        should_save_recording_audio, take_in_flight_transcription_audio, TranscriptionFailure,
+        should_save_recording_audio, take_in_flight_transcription_audio,
+        take_in_flight_transcription_audio_for_generation, TranscriptionFailure,
     };
     use crate::remote::client::{RemoteClientError, RemoteEndpoint};
     use std::path::PathBuf;
@@ -665,6 +666,36 @@ mod recording_persist_and_cancel_tests {
         assert!(!path.exists());
         assert!(take_in_flight_transcription_audio().is_none());
     }
+
+    #[test]
+    fn cancelling_running_job_keeps_queued_job_audio_tracked() {
+        // A dictation queued behind the running one owns its own slot, so
+        // cancelling the running job must not delete the queued job's audio.
+        let _guard = IN_FLIGHT_TEST_LOCK.lock().unwrap();
+
+        let running_path = unique_temp_wav("running");
+        std::fs::write(&running_path, b"audio").unwrap();
+        let running_generation = begin_recording_generation();
+        set_in_flight_transcription_audio(running_generation, running_path.clone());
+
+        let queued_path = unique_temp_wav("queued");
+        std::fs::write(&queued_path, b"audio").unwrap();
+        let queued_generation = begin_recording_generation();
+        set_in_flight_transcription_audio(queued_generation, queued_path.clone());
+
+        assert_eq!(
+            take_in_flight_transcription_audio_for_generation(running_generation),
+            Some(running_path.clone())
+        );
+        assert_eq!(
+            take_in_flight_transcription_audio(),
+            Some(queued_path.clone()),
+            "the queued job's tracker must survive the running job's cancel"
+        );
+
+        let _ = std::fs::remove_file(running_path);
+        let _ = std::fs::remove_file(queued_path);
+    }
     // ---- regression: pre-spawn cancel window + early-cancel cleanup ----
 
     /// Race 1 (CRITICAL): if the user cancels while `stop_recording` is in the
@@ -770,3 +801,123 @@ mod recording_late_cancel_revoke_tests {
         );
     }
 }
+
+// ============================================================================
+// Orphaned recording cleanup
+// ============================================================================
+
+mod orphaned_recording_cleanup_tests {
+    use crate::commands::audio::{
+        is_orphaned_recording, locate_recording, ORPHAN_RECORDING_MIN_AGE,
+    };
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn recordings_saved_before_a_folder_change_are_still_found() {
+        let current = tempfile::tempdir().unwrap();
+        let default = tempfile::tempdir().unwrap();
+        std::fs::write(default.path().join("old.wav"), b"RIFF").unwrap();
+        std::fs::write(current.path().join("new.wav"), b"RIFF").unwrap();
+
+        assert_eq!(
+            locate_recording(current.path(), default.path(), "old.wav"),
+            default.path().join("old.wav")
+        );
+        assert_eq!(
+            locate_recording(current.path(), default.path(), "new.wav"),
+            current.path().join("new.wav")
+        );
+        assert_eq!(
+            locate_recording(current.path(), default.path(), "missing.wav"),
+            current.path().join("missing.wav")
+        );
+    }
+
+    #[test]
+    fn only_day_old_temp_captures_are_orphaned() {
+        let now = SystemTime::now();
+        let old = now - ORPHAN_RECORDING_MIN_AGE;
+        let fresh = now - (ORPHAN_RECORDING_MIN_AGE - Duration::from_secs(60));
+
+        assert!(is_orphaned_recording(
+            "recording_20260101_120000.wav",
+            old,
+            now
+        ));
+        assert!(is_orphaned_recording(
+            "normalized_20260101_120000.wav",
+            old,
+            now
+        ));
+        assert!(!is_orphaned_recording(
+            "recording_20260101_120000.wav",
+            fresh,
+            now
+        ));
+        // A clock that went backwards never makes a file look old
+        assert!(!is_orphaned_recording(
+            "recording_20260101_120000.wav",
+            now + Duration::from_secs(60),
+            now
+        ));
+    }
+
+    #[test]
+    fn saved_and_kept_recordings_are_never_orphaned() {
+        let now = SystemTime::now();
+        let old = now - ORPHAN_RECORDING_MIN_AGE * 30;
+
+        assert!(!is_orphaned_recording(
+            "2026-01-01_00-00-00_deadbeef.wav",
+            old,
+            now
+        ));
+        assert!(!is_orphaned_recording("raw_20260101_120000.wav", old, now));
+        assert!(!is_orphaned_recording(
+            "recording_20260101_120000.flac",
+            old,
+            now
+        ));
+    }
+}
+
+// ============================================================================
+// Per-mode minimum recording length
+// ============================================================================
+
+mod min_recording_length_tests {
+    use crate::commands::audio::{min_recording_ms_for_mode, recording_too_short};
+    use crate::RecordingMode;
+
+    #[test]
+    fn toggle_clip_transcribes_once_the_toggle_minimum_is_lowered() {
+        let clip_ms = 1500;
+
+        let strict = min_recording_ms_for_mode(RecordingMode::Toggle, Some(3.0), None, 500);
+        assert!(recording_too_short(clip_ms, strict));
+
+        let lowered = min_recording_ms_for_mode(RecordingMode::Toggle, Some(1.0), None, 500);
+        assert_eq!(lowered, 1000);
+        assert!(!recording_too_short(clip_ms, lowered));
+    }
+
+    #[test]
+    fn each_mode_uses_its_own_minimum_or_the_shared_one() {
+        assert_eq!(
+            min_recording_ms_for_mode(RecordingMode::PushToTalk, Some(3.0), Some(0.25), 500),
+            250
+        );
+        assert_eq!(
+            min_recording_ms_for_mode(RecordingMode::PushToTalk, Some(3.0), None, 500),
+            500
+        );
+    }
+
+    #[test]
+    fn zero_minimum_disables_the_gate() {
+        let min_ms = min_recording_ms_for_mode(RecordingMode::Toggle, Some(0.0), None, 500);
+        assert_eq!(min_ms, 0);
+        assert!(!recording_too_short(1, min_ms));
+        assert!(recording_too_short(499, 500));
+    }
+}
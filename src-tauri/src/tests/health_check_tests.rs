@@ -0,0 +1,88 @@
+use crate::commands::health::{
+    disk_space_check, license_check, HealthCheck, HealthReport, MIN_FREE_DISK_BYTES,
+};
+use crate::license::{LicenseState, LicenseStatus};
+
+fn report(ffmpeg_sidecar: HealthCheck, license: HealthCheck) -> HealthReport {
+    HealthReport {
+        microphone_permission: HealthCheck::pass("Microphone access granted"),
+        accessibility_permission: HealthCheck::pass("Accessibility access granted"),
+        model_available: HealthCheck::pass("A speech recognition engine is ready"),
+        ffmpeg_sidecar,
+        selected_model_loadable: HealthCheck::pass("Model 'base.en' is downloaded"),
+        ai_api_key: HealthCheck::pass("AI enhancement is off"),
+        license,
+        disk_space: HealthCheck::pass("20.0 GB free"),
+        healthy: false,
+        failing: Vec::new(),
+    }
+    .summarize()
+}
+
+fn license_status(status: LicenseState, offline_grace_days_left: Option<i32>) -> LicenseStatus {
+    LicenseStatus {
+        status,
+        trial_days_left: Some(3),
+        license_type: None,
+        license_key: None,
+        expires_at: None,
+        offline_grace_days_left,
+    }
+}
+
+#[test]
+fn all_passing_checks_make_a_healthy_report() {
+    let report = report(HealthCheck::pass("ffmpeg"), HealthCheck::pass("Licensed"));
+    assert!(report.healthy);
+    assert!(report.failing.is_empty());
+}
+
+#[test]
+fn failing_checks_are_listed_in_field_order() {
+    let report = report(
+        HealthCheck::fail("no ffmpeg"),
+        HealthCheck::fail("No license"),
+    );
+    assert!(!report.healthy);
+    assert_eq!(report.failing, vec!["ffmpeg_sidecar", "license"]);
+}
+
+#[test]
+fn report_serializes_every_check_with_ok_and_detail() {
+    let json = serde_json::to_value(report(
+        HealthCheck::fail("no ffmpeg"),
+        HealthCheck::pass("Licensed"),
+    ))
+    .unwrap();
+    for name in [
+        "microphone_permission",
+        "accessibility_permission",
+        "model_available",
+        "ffmpeg_sidecar",
+        "selected_model_loadable",
+        "ai_api_key",
+        "license",
+        "disk_space",
+    ] {
+        assert!(json[name]["ok"].is_boolean(), "{} has no ok flag", name);
+        assert!(json[name]["detail"].is_string(), "{} has no detail", name);
+    }
+    assert_eq!(json["healthy"], false);
+    assert_eq!(json["failing"], serde_json::json!(["ffmpeg_sidecar"]));
+}
+
+#[test]
+fn license_check_fails_when_expired_or_out_of_grace() {
+    assert!(license_check(&license_status(LicenseState::Licensed, None)).ok);
+    assert!(license_check(&license_status(LicenseState::Trial, None)).ok);
+    assert!(!license_check(&license_status(LicenseState::Expired, None)).ok);
+    assert!(!license_check(&license_status(LicenseState::None, None)).ok);
+    assert!(!license_check(&license_status(LicenseState::Licensed, Some(0))).ok);
+}
+
+#[test]
+fn disk_space_check_uses_the_minimum_free_space() {
+    assert!(disk_space_check(Ok(MIN_FREE_DISK_BYTES)).ok);
+    assert!(!disk_space_check(Ok(MIN_FREE_DISK_BYTES - 1)).ok);
+    assert!(!disk_space_check(Err("no volume".to_string())).ok);
+}
@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
     use crate::commands::logs::{
-        find_newest_log, read_log_tail, redact_log_content, LatestLogAttachment,
+        find_newest_log, is_noisy_log_target, parse_log_line, parse_recent_log_lines,
+        read_log_tail, redact_log_content, LatestLogAttachment, LogTail,
     };
     use std::io::Write;
     use tempfile::TempDir;
@@ -312,4 +313,152 @@ mod tests {
         assert!(json.contains("No log file found."));
         assert!(json.contains("\"truncated\":false"));
     }
+
+    // ── live log stream ────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_log_line_extracts_header_fields() {
+        let line =
+            parse_log_line("[2026-04-27][10:11:12][voicetypr_lib::commands::audio][WARN] Mic busy");
+        assert_eq!(line.timestamp.as_deref(), Some("2026-04-27 10:11:12"));
+        assert_eq!(line.level.as_deref(), Some("WARN"));
+        assert_eq!(
+            line.target.as_deref(),
+            Some("voicetypr_lib::commands::audio")
+        );
+        assert_eq!(line.message, "Mic busy");
+    }
+
+    #[test]
+    fn test_parse_log_line_keeps_continuation_lines() {
+        let line = parse_log_line("   at src/lib.rs:42");
+        assert!(line.level.is_none());
+        assert!(line.target.is_none());
+        assert_eq!(line.message, "   at src/lib.rs:42");
+    }
+
+    #[test]
+    fn test_noisy_targets_match_setup_logging_filter() {
+        assert!(is_noisy_log_target(
+            "whisper_rs::whisper_ctx",
+            log::Level::Error
+        ));
+        assert!(is_noisy_log_target(
+            "cpal::host::coreaudio",
+            log::Level::Info
+        ));
+        assert!(is_noisy_log_target("hyper_util::client", log::Level::Debug));
+        assert!(!is_noisy_log_target("reqwest::connect", log::Level::Warn));
+        assert!(!is_noisy_log_target(
+            "voicetypr_lib::commands::audio",
+            log::Level::Debug
+        ));
+    }
+
+    #[test]
+    fn test_parse_recent_log_lines_filters_noise_and_limits() {
+        let content = "\
+[2026-04-27][10:00:00][voicetypr_lib][INFO] one
+[2026-04-27][10:00:01][cpal::host][INFO] device spam
+[2026-04-27][10:00:02][voicetypr_lib][INFO] two
+
+[2026-04-27][10:00:03][voicetypr_lib][ERROR] three
+";
+        let lines = parse_recent_log_lines(content, 2);
+        let messages: Vec<&str> = lines.iter().map(|l| l.message.as_str()).collect();
+        assert_eq!(messages, vec!["two", "three"]);
+    }
+
+    #[test]
+    fn test_log_tail_reports_only_new_complete_lines() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("voicetypr-2026-04-27.log");
+        std::fs::write(&path, "existing line\n").unwrap();
+
+        let mut tail = LogTail::at_end(dir.path());
+        assert!(tail.poll(dir.path()).unwrap().is_empty());
+
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        write!(file, "first\nsecond without newline").unwrap();
+        file.flush().unwrap();
+        assert_eq!(tail.poll(dir.path()).unwrap(), vec!["first".to_string()]);
+
+        writeln!(file, " finished").unwrap();
+        file.flush().unwrap();
+        assert_eq!(
+            tail.poll(dir.path()).unwrap(),
+            vec!["second without newline finished".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_log_tail_restarts_after_truncation() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("voicetypr-2026-04-27.log");
+        std::fs::write(&path, "a fairly long existing line\n").unwrap();
+
+        let mut tail = LogTail::at_end(dir.path());
+        std::fs::write(&path, "fresh\n").unwrap();
+        assert_eq!(tail.poll(dir.path()).unwrap(), vec!["fresh".to_string()]);
+    }
+
+    // ── diagnostics bundle ─────────────────────────────────────────────
+
+    #[test]
+    fn test_redact_settings_strips_keyring_names_and_credentials() {
+        use crate::utils::diagnostics::{redact_settings, REDACTED};
+
+        let mut settings = serde_json::json!({
+            "speech_language": "en",
+            "stt_api_key_soniox": "sk-live-123",
+            "sharing_password": "hunter2",
+            "cached_ai_api_key_openai_hint": "sk-abc",
+            "profiles": [{ "app_match": "Slack", "custom_key": "abc" }],
+            "unset_password": null,
+        });
+        let keyring = vec!["ai_api_key_openai".to_string(), "custom_key".to_string()];
+        redact_settings(&mut settings, &keyring);
+
+        assert_eq!(settings["speech_language"], "en");
+        assert_eq!(settings["stt_api_key_soniox"], REDACTED);
+        assert_eq!(settings["sharing_password"], REDACTED);
+        assert_eq!(settings["cached_ai_api_key_openai_hint"], REDACTED);
+        assert_eq!(settings["profiles"][0]["app_match"], "Slack");
+        assert_eq!(settings["profiles"][0]["custom_key"], REDACTED);
+        assert!(settings["unset_password"].is_null());
+    }
+
+    #[test]
+    fn test_write_diagnostics_zip_adds_extension_and_entries() {
+        use crate::utils::diagnostics::{write_diagnostics_zip, BundleEntry};
+        use std::io::Read;
+
+        let dir = TempDir::new().unwrap();
+        let entries = vec![
+            BundleEntry {
+                name: "system.json".to_string(),
+                contents: b"{}".to_vec(),
+            },
+            BundleEntry {
+                name: "logs/voicetypr-2026-04-27.log".to_string(),
+                contents: b"hello".to_vec(),
+            },
+        ];
+
+        let written = write_diagnostics_zip(&dir.path().join("bundle"), &entries).unwrap();
+        assert_eq!(written, dir.path().join("bundle.zip"));
+
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(&written).unwrap()).unwrap();
+        assert_eq!(archive.len(), 2);
+        let mut log = String::new();
+        archive
+            .by_name("logs/voicetypr-2026-04-27.log")
+            .unwrap()
+            .read_to_string(&mut log)
+            .unwrap();
+        assert_eq!(log, "hello");
+    }
 }
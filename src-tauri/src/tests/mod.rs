@@ -64,6 +64,9 @@ mod tray_menu_tests;
 #[cfg(test)]
 mod shortcut_bindings;
 
+#[cfg(test)]
+mod health_check_tests;
+
 #[cfg(test)]
 mod integration_tests {
     use crate::whisper::manager::{ModelSize, WhisperManager};
@@ -1,15 +1,100 @@
 #[cfg(test)]
 mod tests {
-    use crate::commands::model::{clear_active_download, register_active_download};
-    use crate::whisper::manager::{ModelInfo, ModelSize, WhisperManager};
+    use crate::commands::model::{
+        clear_active_download, ensure_download_space, register_active_download, DownloadRate,
+    };
+    use crate::utils::diagnostics::available_space_for_path;
+    use crate::whisper::manager::{
+        verify_ggml_whisper_header, ModelInfo, ModelSize, WhisperManager,
+    };
     use sha2::{Digest, Sha256};
     use std::collections::HashMap;
     use std::sync::atomic::{AtomicBool, Ordering};
     use std::sync::{Arc, Mutex};
     use tempfile::TempDir;
-    use wiremock::matchers::{method, path};
+    use wiremock::matchers::{header, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    #[test]
+    fn test_download_space_requires_model_size_plus_buffer() {
+        const MB: u64 = 1024 * 1024;
+        assert!(ensure_download_space("base", 100 * MB, 0, 600 * MB).is_ok());
+
+        let error = ensure_download_space("large-v3", 3 * 1024 * MB, 0, 1024 * MB).unwrap_err();
+        assert!(error.contains("Need 3.5 GB, only 1.0 GB free"), "{error}");
+        assert!(error.contains(&format!("available {} bytes", 1024 * MB)));
+    }
+
+    #[test]
+    fn test_download_space_skips_bytes_already_in_the_partial_file() {
+        const MB: u64 = 1024 * 1024;
+        // 2.5 GB of a 3 GB model is on disk: only 0.5 GB + buffer is needed
+        assert!(ensure_download_space("large-v3", 3 * 1024 * MB, 2560 * MB, 1024 * MB).is_ok());
+        assert!(ensure_download_space("large-v3", 3 * 1024 * MB, 0, 1024 * MB).is_err());
+    }
+
+    #[test]
+    fn test_download_rate_is_smoothed_and_throttled() {
+        const MB: u64 = 1024 * 1024;
+        let start = std::time::Instant::now();
+        let at = |ms: u64| start + std::time::Duration::from_millis(ms);
+        let mut rate = DownloadRate::default();
+
+        assert!(rate.update(at(0), 0));
+        assert_eq!(rate.bytes_per_sec(), None);
+        // Too soon after the last sample: nothing recorded
+        assert!(!rate.update(at(100), MB));
+        assert!(rate.update(at(1_000), 10 * MB));
+        assert_eq!(rate.bytes_per_sec(), Some(10 * MB));
+        assert_eq!(rate.eta_seconds(10 * MB, 50 * MB), Some(4));
+
+        // A slower second sample pulls the average down, not all the way
+        assert!(rate.update(at(2_000), 15 * MB));
+        let bytes_per_sec = rate.bytes_per_sec().unwrap();
+        assert!(bytes_per_sec > 5 * MB && bytes_per_sec < 10 * MB);
+        assert_eq!(rate.eta_seconds(50 * MB, 50 * MB), Some(0));
+    }
+
+    #[test]
+    fn test_download_rate_ignores_bytes_resumed_from_disk() {
+        const MB: u64 = 1024 * 1024;
+        let start = std::time::Instant::now();
+        let at = |ms: u64| start + std::time::Duration::from_millis(ms);
+        let mut rate = DownloadRate::default();
+
+        // Resumed at 900 MB: the first update is only a baseline
+        assert!(rate.update(at(50), 900 * MB));
+        assert_eq!(rate.bytes_per_sec(), None);
+        assert_eq!(rate.eta_seconds(900 * MB, 1_000 * MB), None);
+        assert!(rate.update(at(1_050), 902 * MB));
+        assert_eq!(rate.bytes_per_sec(), Some(2 * MB));
+        assert_eq!(rate.eta_seconds(902 * MB, 1_000 * MB), Some(49));
+
+        // Server ignored the range request and the count restarted
+        assert!(rate.update(at(1_500), MB));
+        assert_eq!(rate.bytes_per_sec(), None);
+    }
+
+    #[test]
+    fn test_available_space_uses_most_specific_mount() {
+        let mounts = [
+            (std::path::Path::new("/"), 10),
+            (std::path::Path::new("/Volumes/External"), 20),
+        ];
+        assert_eq!(
+            available_space_for_path(mounts, std::path::Path::new("/Volumes/External/models")),
+            Some(20)
+        );
+        assert_eq!(
+            available_space_for_path(mounts, std::path::Path::new("/Users/me/models")),
+            Some(10)
+        );
+        assert_eq!(
+            available_space_for_path(mounts[1..].iter().copied(), std::path::Path::new("/tmp")),
+            None
+        );
+    }
+
     #[test]
     fn test_model_size_validation() {
         // Test minimum size validation (10MB)
@@ -461,7 +546,7 @@ mod tests {
             recommended: false,
         };
 
-        WhisperManager::download_model_file(&model, &output_path, &models_dir, None, |_, _| {})
+        WhisperManager::download_model_file(&model, &output_path, &models_dir, None, |_, _, _| {})
             .await
             .unwrap();
 
@@ -492,12 +577,259 @@ mod tests {
             recommended: false,
         };
 
-        let result =
-            WhisperManager::download_model_file(&model, &output_path, &models_dir, None, |_, _| {})
-                .await;
+        let result = WhisperManager::download_model_file(
+            &model,
+            &output_path,
+            &models_dir,
+            None,
+            |_, _, _| {},
+        )
+        .await;
 
         let error = result.unwrap_err();
         assert!(error.contains("Checksum verification failed"));
         assert!(!output_path.exists());
     }
+
+    fn resumable_test_model(server_uri: &str, body: &[u8]) -> ModelInfo {
+        ModelInfo {
+            name: "base.en".to_string(),
+            display_name: "Base (English)".to_string(),
+            size: body.len() as u64,
+            url: format!("{}/ggml-base.en.bin", server_uri),
+            sha256: format!("{:x}", Sha256::digest(body)),
+            downloaded: false,
+            speed_score: 8,
+            accuracy_score: 5,
+            recommended: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_model_file_resumes_partial_download_with_range() {
+        let temp_dir = TempDir::new().unwrap();
+        let models_dir = temp_dir.path().join("models");
+        std::fs::create_dir_all(&models_dir).unwrap();
+        let output_path = models_dir.join("base.en.bin");
+        let partial_path = models_dir.join("base.en.bin.part");
+
+        let body: Vec<u8> = (0..ModelSize::new(10 * 1024 * 1024).unwrap().as_bytes())
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let split = 4 * 1024 * 1024;
+        std::fs::write(&partial_path, &body[..split]).unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ggml-base.en.bin"))
+            .and(header("range", format!("bytes={}-", split).as_str()))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header(
+                        "content-range",
+                        format!("bytes {}-{}/{}", split, body.len() - 1, body.len()).as_str(),
+                    )
+                    .set_body_bytes(body[split..].to_vec()),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let model = resumable_test_model(&server.uri(), &body);
+        let resumed = Arc::new(Mutex::new(Vec::new()));
+        let resumed_for_callback = resumed.clone();
+        WhisperManager::download_model_file(
+            &model,
+            &output_path,
+            &models_dir,
+            None,
+            move |_, _, resumed_from| resumed_for_callback.lock().unwrap().push(resumed_from),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(std::fs::read(&output_path).unwrap(), body);
+        assert!(!partial_path.exists());
+        let resumed = resumed.lock().unwrap();
+        assert!(!resumed.is_empty());
+        assert!(resumed.iter().all(|&from| from == split as u64));
+    }
+
+    #[tokio::test]
+    async fn test_download_model_file_restarts_when_server_ignores_range() {
+        let temp_dir = TempDir::new().unwrap();
+        let models_dir = temp_dir.path().join("models");
+        std::fs::create_dir_all(&models_dir).unwrap();
+        let output_path = models_dir.join("base.en.bin");
+        let partial_path = models_dir.join("base.en.bin.part");
+        std::fs::write(&partial_path, vec![9u8; 1024]).unwrap();
+
+        let body = vec![7u8; ModelSize::new(10 * 1024 * 1024).unwrap().as_bytes() as usize];
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ggml-base.en.bin"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+            .mount(&server)
+            .await;
+
+        let model = resumable_test_model(&server.uri(), &body);
+        WhisperManager::download_model_file(&model, &output_path, &models_dir, None, |_, _, _| {})
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read(&output_path).unwrap(), body);
+        assert!(!partial_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_model_file_keeps_partial_file_on_cancel() {
+        let temp_dir = TempDir::new().unwrap();
+        let models_dir = temp_dir.path().join("models");
+        std::fs::create_dir_all(&models_dir).unwrap();
+        let output_path = models_dir.join("base.en.bin");
+        let partial_path = models_dir.join("base.en.bin.part");
+
+        let body = vec![3u8; ModelSize::new(10 * 1024 * 1024).unwrap().as_bytes() as usize];
+        std::fs::write(&partial_path, &body[..1024]).unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ggml-base.en.bin"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header(
+                        "content-range",
+                        format!("bytes 1024-{}/{}", body.len() - 1, body.len()).as_str(),
+                    )
+                    .set_body_bytes(body[1024..].to_vec()),
+            )
+            .mount(&server)
+            .await;
+
+        let model = resumable_test_model(&server.uri(), &body);
+        let cancel_flag = Arc::new(AtomicBool::new(true));
+        let error = WhisperManager::download_model_file(
+            &model,
+            &output_path,
+            &models_dir,
+            Some(cancel_flag),
+            |_, _, _| {},
+        )
+        .await
+        .unwrap_err();
+
+        assert!(error.contains("cancelled"));
+        assert!(!output_path.exists());
+        assert!(partial_path.exists());
+    }
+
+    #[test]
+    fn test_delete_model_file_removes_partial_download() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = WhisperManager::new_for_test(temp_dir.path().to_path_buf());
+        let partial_path = temp_dir.path().join("base.en.bin.part");
+        std::fs::write(&partial_path, b"partial").unwrap();
+
+        manager.delete_model_file("base.en").unwrap();
+        assert!(!partial_path.exists());
+    }
+
+    /// A ggml header with base.en's hyperparameters, padded past the minimum model size.
+    fn write_ggml_model(path: &std::path::Path) {
+        let mut header = Vec::new();
+        for value in [
+            0x6767_6d6c_u32,
+            51864,
+            1500,
+            512,
+            8,
+            6,
+            448,
+            512,
+            8,
+            6,
+            80,
+            1,
+        ] {
+            header.extend_from_slice(&value.to_le_bytes());
+        }
+        std::fs::write(path, &header).unwrap();
+        let file = std::fs::OpenOptions::new().write(true).open(path).unwrap();
+        file.set_len(11 * 1024 * 1024).unwrap();
+    }
+
+    #[test]
+    fn test_verify_ggml_header_rejects_non_whisper_files() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let text = temp_dir.path().join("notes.bin");
+        std::fs::write(
+            &text,
+            "definitely not a model, just some text padding it out",
+        )
+        .unwrap();
+        let error = verify_ggml_whisper_header(&text).unwrap_err();
+        assert!(error.contains("Not a Whisper ggml model"), "{error}");
+        assert!(error.contains("magic"), "{error}");
+
+        let short = temp_dir.path().join("short.bin");
+        std::fs::write(&short, b"lmgg").unwrap();
+        assert!(verify_ggml_whisper_header(&short)
+            .unwrap_err()
+            .contains("too short"));
+
+        // Right magic, but a vocabulary no Whisper model has
+        let mut bogus = 0x6767_6d6c_u32.to_le_bytes().to_vec();
+        bogus.extend_from_slice(&[0u8; 44]);
+        let other = temp_dir.path().join("other-ggml.bin");
+        std::fs::write(&other, bogus).unwrap();
+        assert!(verify_ggml_whisper_header(&other)
+            .unwrap_err()
+            .contains("unexpected header"));
+
+        let model = temp_dir.path().join("model.bin");
+        write_ggml_model(&model);
+        assert!(verify_ggml_whisper_header(&model).is_ok());
+    }
+
+    #[test]
+    fn test_register_custom_model_handles_name_collisions_and_persists() {
+        let temp_dir = TempDir::new().unwrap();
+        let models_dir = temp_dir.path().join("models");
+        let mut manager = WhisperManager::new(models_dir.clone());
+
+        let first_source = temp_dir.path().join("first.bin");
+        write_ggml_model(&first_source);
+        let first = manager
+            .register_custom_model(&first_source, "My Fine-Tune")
+            .unwrap();
+        assert_eq!(first.name, "custom-my-fine-tune");
+        assert!(first.downloaded);
+        assert!(models_dir.join("custom-my-fine-tune.bin").exists());
+
+        let second_source = temp_dir.path().join("second.bin");
+        write_ggml_model(&second_source);
+        let second = manager
+            .register_custom_model(&second_source, "My Fine-Tune")
+            .unwrap();
+        assert_eq!(second.name, "custom-my-fine-tune-2");
+        assert_eq!(second.display_name, "My Fine-Tune (2)");
+
+        // Imported models survive a restart and resolve to a loadable path
+        let reloaded = WhisperManager::new(models_dir.clone());
+        assert_eq!(
+            reloaded.get_model_path("custom-my-fine-tune-2"),
+            Some(models_dir.join("custom-my-fine-tune-2.bin"))
+        );
+        assert!(reloaded.get_model_info("custom-my-fine-tune").is_err());
+
+        manager.delete_model_file("custom-my-fine-tune").unwrap();
+        assert!(!manager
+            .get_models_status()
+            .contains_key("custom-my-fine-tune"));
+        let reloaded = WhisperManager::new(models_dir);
+        assert!(!reloaded
+            .get_models_status()
+            .contains_key("custom-my-fine-tune"));
+    }
 }
@@ -355,3 +355,40 @@ async fn delivery_recheck_aborts_stale_result_before_insert_and_history() {
         "a stale prior-generation result must NOT be saved to history"
     );
 }
+
+#[test]
+fn recording_event_payload_has_the_versioned_schema() {
+    use crate::state::recording_events::{RecordingEvent, RecordingPhase, RECORDING_EVENT_VERSION};
+
+    let event = RecordingEvent::new(
+        RecordingPhase::EnhancingFailed,
+        Some("base.en".to_string()),
+        Some("whisper".to_string()),
+        Some("Invalid API key".to_string()),
+    );
+    let json = serde_json::to_value(&event).unwrap();
+    let object = json.as_object().unwrap();
+
+    let mut keys: Vec<&str> = object.keys().map(String::as_str).collect();
+    keys.sort_unstable();
+    assert_eq!(
+        keys,
+        ["detail", "engine", "model", "phase", "timestamp", "version"]
+    );
+    assert_eq!(json["version"], RECORDING_EVENT_VERSION);
+    assert_eq!(json["phase"], "enhancing_failed");
+    assert_eq!(json["model"], "base.en");
+    assert_eq!(json["engine"], "whisper");
+    assert!(json["timestamp"].as_u64().unwrap() > 0);
+    assert_eq!(json["detail"], "Invalid API key");
+}
+
+#[test]
+fn recording_event_phases_match_recording_state_names() {
+    use crate::state::recording_events::RecordingPhase;
+
+    for state in all_recording_states() {
+        let phase = serde_json::to_value(RecordingPhase::from(state)).unwrap();
+        assert_eq!(phase, state.as_str());
+    }
+}
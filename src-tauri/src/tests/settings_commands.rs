@@ -1,13 +1,30 @@
 #[cfg(test)]
 mod tests {
     use crate::commands::settings::{
-        get_supported_languages, normalize_final_text_language,
-        normalize_speech_language_for_model, normalize_stored_transcription_acceleration,
-        task_uses_translate_to_english, Settings, FINAL_TEXT_LANGUAGE_SAME_AS_TRANSCRIPT,
+        get_supported_languages, language_for_model_switch, normalize_channel_select,
+        normalize_duplicate_window_seconds, normalize_final_text_language,
+        normalize_insertion_method, normalize_low_confidence_threshold,
+        normalize_max_queued_transcriptions, normalize_max_recording_duration_s,
+        normalize_mic_gain_db, normalize_min_recording_mode_s, normalize_preferred_sample_rate,
+        normalize_recording_indicator, normalize_recordings_dir, normalize_silence_duration_ms,
+        normalize_silence_threshold_db, normalize_speech_language_for_model,
+        normalize_stored_transcription_acceleration, normalize_translate_to_language,
+        normalize_trim_max_gap_ms, normalize_trim_silence_threshold_db,
+        normalize_upload_segment_seconds, normalize_whisper_beam_size,
+        normalize_whisper_initial_prompt, recording_indicator_notifies,
+        recording_indicator_shows_pill, task_uses_translate_to_english,
+        validate_model_engine_pairing, Settings, FINAL_TEXT_LANGUAGE_SAME_AS_TRANSCRIPT,
         TRANSCRIPTION_TASK_TRANSCRIBE, TRANSCRIPTION_TASK_TRANSLATE_TO_ENGLISH,
     };
     use serde_json::json;
 
+    /// Settings read back from a store saved before `key` existed.
+    fn default_when_missing(key: &str) -> Settings {
+        let mut v = serde_json::to_value(Settings::default()).unwrap();
+        v.as_object_mut().unwrap().remove(key);
+        serde_json::from_value(v).unwrap()
+    }
+
     #[test]
     fn test_settings_default() {
         let settings = Settings::default();
@@ -62,6 +79,7 @@ mod tests {
             save_recordings: true,
             recording_retention_days: Some(7),
             transcription_acceleration: "auto".to_string(),
+            ..Settings::default()
         };
 
         // Test serialization
@@ -144,6 +162,7 @@ mod tests {
             save_recordings: true,
             recording_retention_days: None,
             transcription_acceleration: "auto".to_string(),
+            ..Settings::default()
         };
 
         let cloned = settings.clone();
@@ -356,6 +375,282 @@ mod tests {
         assert_eq!(deserialized.pill_indicator_mode, "always");
     }
 
+    // ==================== Recording Indicator Tests ====================
+
+    #[test]
+    fn test_recording_indicator_normalization() {
+        assert_eq!(Settings::default().recording_indicator, "pill");
+        assert_eq!(normalize_recording_indicator(Some("both")), "both");
+        assert_eq!(normalize_recording_indicator(Some("flash")), "pill");
+        assert_eq!(normalize_recording_indicator(None), "pill");
+
+        assert!(recording_indicator_shows_pill("both"));
+        assert!(!recording_indicator_shows_pill("notification"));
+        assert!(recording_indicator_notifies("notification"));
+        assert!(!recording_indicator_notifies("none"));
+    }
+
+    #[test]
+    fn test_insertion_method_normalization() {
+        let defaults = Settings::default();
+        assert_eq!(defaults.insertion_method, "paste");
+        assert_eq!(defaults.typing_delay_ms, 0);
+        assert_eq!(normalize_insertion_method(Some("type")), "type");
+        assert_eq!(normalize_insertion_method(Some("auto")), "auto");
+        assert_eq!(normalize_insertion_method(Some("dictate")), "paste");
+        assert_eq!(normalize_insertion_method(None), "paste");
+    }
+
+    #[test]
+    fn test_upload_segment_seconds_normalization() {
+        assert_eq!(Settings::default().upload_segment_seconds, None);
+        assert_eq!(normalize_upload_segment_seconds(0), 0);
+        assert_eq!(normalize_upload_segment_seconds(5), 30);
+        assert_eq!(normalize_upload_segment_seconds(600), 600);
+        assert_eq!(normalize_upload_segment_seconds(100_000), 3600);
+    }
+
+    #[test]
+    fn test_silence_auto_stop_values_are_clamped() {
+        let defaults = Settings::default();
+        assert!(defaults.silence_auto_stop_enabled);
+        assert_eq!(defaults.silence_duration_ms, None);
+
+        assert_eq!(normalize_silence_threshold_db(-80.0), -60.0);
+        assert_eq!(normalize_silence_threshold_db(-35.0), -35.0);
+        assert_eq!(normalize_silence_threshold_db(0.0), -20.0);
+        assert_eq!(normalize_silence_threshold_db(f64::NAN), -46.0);
+        assert_eq!(normalize_silence_duration_ms(100), 500);
+        assert_eq!(normalize_silence_duration_ms(2_000), 2_000);
+        assert_eq!(normalize_silence_duration_ms(60_000), 10_000);
+    }
+
+    #[test]
+    fn test_trim_silence_values_are_clamped() {
+        let defaults = Settings::default();
+        assert_eq!(defaults.trim_silence_threshold_db, -45.0);
+        assert_eq!(defaults.trim_max_gap_ms, 1_500);
+
+        assert_eq!(normalize_trim_silence_threshold_db(-80.0), -60.0);
+        assert_eq!(normalize_trim_silence_threshold_db(-35.0), -35.0);
+        assert_eq!(normalize_trim_silence_threshold_db(0.0), -20.0);
+        assert_eq!(normalize_trim_silence_threshold_db(f64::NAN), -45.0);
+        assert_eq!(normalize_trim_max_gap_ms(0), 300);
+        assert_eq!(normalize_trim_max_gap_ms(2_000), 2_000);
+        assert_eq!(normalize_trim_max_gap_ms(60_000), 10_000);
+    }
+
+    #[test]
+    fn test_mic_gain_is_clamped() {
+        assert_eq!(Settings::default().mic_gain_db, 0.0);
+        assert_eq!(normalize_mic_gain_db(-30.0), -12.0);
+        assert_eq!(normalize_mic_gain_db(6.0), 6.0);
+        assert_eq!(normalize_mic_gain_db(40.0), 24.0);
+        assert_eq!(normalize_mic_gain_db(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_active_model_pairing_for_local_engines() {
+        assert_eq!(
+            validate_model_engine_pairing("base.en", "whisper", true, false),
+            Ok("whisper".to_string())
+        );
+        assert_eq!(
+            validate_model_engine_pairing("parakeet-tdt-0.6b-v3", "Parakeet", false, true),
+            Ok("parakeet".to_string())
+        );
+        // A Parakeet model must not be paired with the Whisper engine, or vice versa.
+        assert!(
+            validate_model_engine_pairing("parakeet-tdt-0.6b-v3", "whisper", false, true).is_err()
+        );
+        assert!(validate_model_engine_pairing("base.en", "parakeet", true, false).is_err());
+        assert!(validate_model_engine_pairing("", "whisper", true, false).is_err());
+    }
+
+    #[test]
+    fn test_active_model_pairing_for_cloud_engines() {
+        for provider in crate::cloud_stt::CloudProvider::ALL {
+            assert_eq!(
+                validate_model_engine_pairing(provider.id(), provider.id(), false, false),
+                Ok(provider.id().to_string())
+            );
+            assert!(validate_model_engine_pairing("base.en", provider.id(), true, false).is_err());
+        }
+        assert!(validate_model_engine_pairing("soniox", "deepgram", false, false).is_err());
+        assert_eq!(
+            validate_model_engine_pairing("soniox", "sonic", false, false),
+            Err("Unknown engine 'sonic'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_transcription_sinks_default_to_cursor_only() {
+        assert_eq!(Settings::default().transcription_sinks, vec!["cursor"]);
+        assert_eq!(
+            default_when_missing("transcription_sinks").transcription_sinks,
+            vec!["cursor"]
+        );
+    }
+
+    #[test]
+    fn test_preferred_sample_rate_defaults_to_16khz_and_is_clamped() {
+        assert_eq!(Settings::default().preferred_sample_rate, 16_000);
+        assert_eq!(
+            default_when_missing("preferred_sample_rate").preferred_sample_rate,
+            16_000
+        );
+        assert_eq!(normalize_preferred_sample_rate(4_000), 8_000);
+        assert_eq!(normalize_preferred_sample_rate(48_000), 48_000);
+        assert_eq!(normalize_preferred_sample_rate(192_000), 96_000);
+    }
+
+    #[test]
+    fn test_channel_select_defaults_to_mix() {
+        assert_eq!(default_when_missing("channel_select").channel_select, "mix");
+        assert_eq!(normalize_channel_select("Left"), "left");
+        assert_eq!(normalize_channel_select("right"), "right");
+        assert_eq!(normalize_channel_select("surround"), "mix");
+    }
+
+    #[test]
+    fn test_duplicate_window_defaults_to_two_seconds_and_is_capped() {
+        assert_eq!(
+            default_when_missing("duplicate_window_seconds").duplicate_window_seconds,
+            2
+        );
+        assert_eq!(normalize_duplicate_window_seconds(0), 0);
+        assert_eq!(normalize_duplicate_window_seconds(10), 10);
+        assert_eq!(normalize_duplicate_window_seconds(3600), 60);
+    }
+
+    #[test]
+    fn test_max_recording_duration_defaults_to_ten_minutes() {
+        assert_eq!(
+            default_when_missing("max_recording_duration_s").max_recording_duration_s,
+            600
+        );
+        assert_eq!(normalize_max_recording_duration_s(0), 0);
+        assert_eq!(normalize_max_recording_duration_s(1_000_000), 14_400);
+    }
+
+    #[test]
+    fn test_pill_hide_delays_default_per_outcome() {
+        let mut v = serde_json::to_value(Settings::default()).unwrap();
+        let object = v.as_object_mut().unwrap();
+        for key in [
+            "pill_hide_delay_ms",
+            "pill_hide_delay_error_ms",
+            "pill_hide_delay_empty_ms",
+        ] {
+            object.remove(key);
+        }
+        let parsed: Settings = serde_json::from_value(v).unwrap();
+        assert_eq!(parsed.pill_hide_delay_ms, 0);
+        assert_eq!(parsed.pill_hide_delay_error_ms, 2500);
+        assert_eq!(parsed.pill_hide_delay_empty_ms, 2500);
+    }
+
+    #[test]
+    fn test_translate_to_language_defaults_off_and_drops_unknown_codes() {
+        assert_eq!(Settings::default().translate_to_language, "");
+        assert_eq!(normalize_translate_to_language(" fr "), "fr");
+        assert_eq!(normalize_translate_to_language("xx"), "");
+        assert_eq!(normalize_translate_to_language(""), "");
+    }
+
+    #[test]
+    fn test_recordings_dir_keeps_only_absolute_paths() {
+        let defaults = Settings::default();
+        assert_eq!(defaults.recordings_dir, "");
+        assert!(!defaults.keep_raw_recordings);
+        assert_eq!(normalize_recordings_dir("relative/dir"), "");
+        assert_eq!(normalize_recordings_dir("  "), "");
+        let absolute = std::env::temp_dir().join("voicetypr-recordings");
+        let absolute = absolute.to_str().unwrap();
+        assert_eq!(
+            normalize_recordings_dir(&format!(" {} ", absolute)),
+            absolute
+        );
+    }
+
+    #[test]
+    fn test_whisper_beam_size_is_clamped_and_fallback_defaults_on() {
+        let defaults = Settings::default();
+        assert_eq!(defaults.whisper_beam_size, None);
+        assert!(defaults.whisper_temperature_fallback);
+        assert_eq!(normalize_whisper_beam_size(0), 1);
+        assert_eq!(normalize_whisper_beam_size(4), 4);
+        assert_eq!(normalize_whisper_beam_size(20), 8);
+    }
+
+    #[test]
+    fn test_min_recording_mode_seconds_are_non_negative_and_capped() {
+        let defaults = Settings::default();
+        assert_eq!(defaults.min_recording_toggle_s, None);
+        assert_eq!(defaults.min_recording_ptt_s, None);
+        assert_eq!(normalize_min_recording_mode_s(-1.0), 0.0);
+        assert_eq!(normalize_min_recording_mode_s(f64::NAN), 0.0);
+        assert_eq!(normalize_min_recording_mode_s(1.5), 1.5);
+        assert_eq!(normalize_min_recording_mode_s(60.0), 5.0);
+    }
+
+    #[test]
+    fn test_max_queued_transcriptions_is_clamped() {
+        assert_eq!(Settings::default().max_queued_transcriptions, 3);
+        assert_eq!(normalize_max_queued_transcriptions(0), 1);
+        assert_eq!(normalize_max_queued_transcriptions(4), 4);
+        assert_eq!(normalize_max_queued_transcriptions(500), 10);
+    }
+
+    #[test]
+    fn test_whisper_initial_prompt_is_trimmed_and_bounded() {
+        assert_eq!(Settings::default().whisper_initial_prompt, "");
+        assert_eq!(
+            normalize_whisper_initial_prompt("  Kubernetes\0 notes  "),
+            "Kubernetes notes"
+        );
+        let long = "é".repeat(400);
+        let bounded = normalize_whisper_initial_prompt(&long);
+        assert!(bounded.len() <= 500);
+        assert!(bounded.chars().all(|ch| ch == 'é'));
+    }
+
+    #[test]
+    fn test_low_confidence_threshold_is_clamped() {
+        assert_eq!(Settings::default().low_confidence_threshold, 0.35);
+        assert_eq!(normalize_low_confidence_threshold(-0.5), 0.0);
+        assert_eq!(normalize_low_confidence_threshold(0.5), 0.5);
+        assert_eq!(normalize_low_confidence_threshold(3.0), 1.0);
+        assert_eq!(normalize_low_confidence_threshold(f64::NAN), 0.35);
+    }
+
+    #[test]
+    fn test_openai_stt_model_defaults_to_gpt_4o_transcribe() {
+        assert_eq!(Settings::default().openai_stt_model, "gpt-4o-transcribe");
+        assert_eq!(
+            default_when_missing("openai_stt_model").openai_stt_model,
+            "gpt-4o-transcribe"
+        );
+    }
+
+    #[test]
+    fn test_recording_format_defaults_to_wav() {
+        assert_eq!(Settings::default().recording_format, "wav");
+        assert_eq!(
+            default_when_missing("recording_format").recording_format,
+            "wav"
+        );
+    }
+
+    #[test]
+    fn test_output_formatting_defaults_off() {
+        let settings = Settings::default();
+        assert!(!settings.trim_whitespace);
+        assert!(!settings.collapse_spaces);
+        assert!(!settings.auto_capitalize_first);
+        assert!(!settings.ensure_trailing_space);
+    }
+
     // ==================== Pill Indicator Position Tests ====================
 
     #[test]
@@ -413,10 +708,11 @@ mod tests {
     }
 
     #[test]
-    fn test_pause_media_during_recording_default_off() {
-        // PORT-S4: pause-media-during-recording defaults OFF; users opt in.
+    fn test_pause_media_during_recording_default_on() {
+        // Pause-media-during-recording defaults ON; users who want their music
+        // to keep playing opt out.
         let settings = Settings::default();
-        assert!(!settings.pause_media_during_recording);
+        assert!(settings.pause_media_during_recording);
     }
 
     #[test]
@@ -685,6 +981,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_clipping_warning_defaults_on() {
+        assert!(Settings::default().clipping_warning_enabled);
+        assert!(default_when_missing("clipping_warning_enabled").clipping_warning_enabled);
+    }
+
     // ==================== Translate Settings Tests ====================
 
     #[test]
@@ -794,7 +1096,14 @@ mod tests {
     #[test]
     fn test_model_engine_valid_values() {
         let valid_engines = vec![
-            "whisper", "parakeet", "soniox", "openai", "groq", "deepgram", "cohere",
+            "whisper",
+            "parakeet",
+            "soniox",
+            "openai",
+            "groq",
+            "deepgram",
+            "cohere",
+            "assemblyai",
         ];
 
         for engine in valid_engines {
@@ -840,6 +1149,7 @@ mod tests {
             save_recordings: true,
             recording_retention_days: None,
             transcription_acceleration: "gpu".to_string(),
+            ..Settings::default()
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -1031,4 +1341,38 @@ mod tests {
         let restored: Settings = serde_json::from_str(&json).unwrap();
         assert_eq!(restored.selected_microphone, Some(long_string));
     }
+
+    #[test]
+    fn test_english_only_model_switch_forces_and_restores_language() {
+        // Multilingual -> .en: forced to English, Spanish remembered.
+        assert_eq!(
+            language_for_model_switch(false, true, "es", "es", None),
+            ("en".to_string(), Some("es".to_string()))
+        );
+        // Staying on English-only models keeps the memory.
+        assert_eq!(
+            language_for_model_switch(true, true, "en", "en", Some("es")),
+            ("en".to_string(), Some("es".to_string()))
+        );
+        // .en -> multilingual: Spanish comes back.
+        assert_eq!(
+            language_for_model_switch(true, false, "en", "en", Some("es")),
+            ("es".to_string(), Some("es".to_string()))
+        );
+        // An explicit pick made while switching back wins over the memory.
+        assert_eq!(
+            language_for_model_switch(true, false, "en", "fr", Some("es")),
+            ("fr".to_string(), Some("es".to_string()))
+        );
+        // Nothing remembered: keep what was requested.
+        assert_eq!(
+            language_for_model_switch(true, false, "en", "en", None),
+            ("en".to_string(), None)
+        );
+        // Multilingual -> multilingual changes nothing.
+        assert_eq!(
+            language_for_model_switch(false, false, "de", "de", Some("es")),
+            ("de".to_string(), Some("es".to_string()))
+        );
+    }
 }
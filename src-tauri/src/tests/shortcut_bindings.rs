@@ -1,9 +1,10 @@
 use crate::ai::prompts::EnhancementPreset;
 use crate::commands::key_normalizer::is_typing_safe_single_key;
 use crate::commands::shortcuts::{
-    action_preset, hold_shortcut_transition, is_single_key_shortcut, next_ai_enabled,
-    normalized_custom_shortcut_conflict, pressed_shortcut_should_run, validate_shortcut_settings,
-    CustomHoldTransition, ExistingShortcutStrings, ModifierKind, ModifierSpec, ShortcutAction,
+    action_preset, available_suggested_shortcuts, hold_shortcut_transition, is_single_key_shortcut,
+    next_ai_enabled, normalized_custom_shortcut_conflict, pressed_shortcut_should_run,
+    shortcut_availability, validate_shortcut_settings, CustomHoldTransition,
+    ExistingShortcutStrings, ModifierKind, ModifierSpec, RecordingOverrides, ShortcutAction,
     ShortcutBinding, ShortcutSettings, ShortcutTrigger, SideKind, TriggerKind,
     MAX_SINGLE_KEY_BINDINGS,
 };
@@ -20,6 +21,7 @@ fn binding(action: ShortcutAction, shortcut: &str) -> ShortcutBinding {
         allow_risky_combo: false,
         trigger_kind: crate::commands::shortcuts::TriggerKind::Combo,
         modifier: None,
+        overrides: RecordingOverrides::default(),
     }
 }
 
@@ -520,6 +522,7 @@ fn modifier_hold_right_option_validates_with_empty_primary() {
             modifier: ModifierKind::Alt,
             side: SideKind::Right,
         }),
+        overrides: RecordingOverrides::default(),
     };
     let settings = ShortcutSettings {
         bindings: vec![binding],
@@ -554,6 +557,7 @@ fn engine_kind_binding_not_treated_as_combo_against_empty_primary() {
             modifier: ModifierKind::Meta,
             side: SideKind::Right,
         }),
+        overrides: RecordingOverrides::default(),
     };
     let existing = ExistingShortcutStrings {
         primary_hotkey: Some(String::new()),
@@ -567,3 +571,72 @@ fn engine_kind_binding_not_treated_as_combo_against_empty_primary() {
     )
     .is_ok());
 }
+
+#[test]
+fn shortcut_availability_reports_conflicts_on_the_resolved_trigger() {
+    let settings = ShortcutSettings {
+        bindings: vec![binding(
+            ShortcutAction::PasteLastTranscription,
+            "CommandOrControl+Alt+V",
+        )],
+    };
+    let existing = ExistingShortcutStrings {
+        primary_hotkey: Some("CommandOrControl+Shift+Space".to_string()),
+        ptt_hotkey: None,
+    };
+
+    let primary = shortcut_availability("cmd+shift+space", &settings, &existing).unwrap();
+    assert_eq!(primary.shortcut, "CommandOrControl+Shift+Space");
+    assert!(!primary.available);
+    assert_eq!(primary.conflict.as_deref(), Some("the recording hotkey"));
+
+    let custom = shortcut_availability("ctrl+alt+v", &settings, &existing).unwrap();
+    assert!(!custom.available);
+    assert!(custom.conflict.unwrap().contains("shortcut"));
+
+    let free = shortcut_availability("CommandOrControl+Alt+K", &settings, &existing).unwrap();
+    assert!(free.available);
+    assert_eq!(free.conflict, None);
+
+    assert!(shortcut_availability("Escape", &settings, &existing).is_err());
+    assert!(shortcut_availability("a", &settings, &existing).is_err());
+    assert!(shortcut_availability("", &settings, &existing).is_err());
+}
+
+#[test]
+fn suggested_shortcuts_skip_bound_combos() {
+    let mut disabled = binding(
+        ShortcutAction::PasteLastTranscription,
+        "CommandOrControl+Shift+R",
+    );
+    disabled.enabled = false;
+    let settings = ShortcutSettings {
+        bindings: vec![
+            binding(
+                ShortcutAction::PasteLastTranscription,
+                "CommandOrControl+Alt+Space",
+            ),
+            disabled,
+        ],
+    };
+    let existing = ExistingShortcutStrings {
+        primary_hotkey: Some("CommandOrControl+Shift+Space".to_string()),
+        ptt_hotkey: Some("Alt+Shift+Space".to_string()),
+    };
+
+    let suggestions = available_suggested_shortcuts(&settings, &existing);
+    assert!(!suggestions.is_empty());
+    for taken in [
+        "CommandOrControl+Shift+Space",
+        "CommandOrControl+Alt+Space",
+        "Alt+Shift+Space",
+    ] {
+        assert!(
+            !suggestions.iter().any(|s| s == taken),
+            "{} suggested",
+            taken
+        );
+    }
+    // Disabled bindings do not hold on to their combo
+    assert!(suggestions.iter().any(|s| s == "CommandOrControl+Shift+R"));
+}
@@ -1,6 +1,7 @@
 use crate::commands::audio::{
+    history_cleanup_keys, history_keys_older_than, history_keys_over_cap, history_search_matches,
     is_duplicate_transcription, page_history_keys, reconcile_transcription_history_entry,
-    TranscriptionStatus,
+    search_history_entries, TranscriptionStatus,
 };
 use serde_json::json;
 
@@ -151,6 +152,82 @@ fn page_returns_newest_first_limited() {
     );
 }
 
+#[test]
+fn history_cap_evicts_oldest_non_favorites() {
+    let keys = vec![
+        "2026-04-03T00:00:00Z".to_string(),
+        "2026-04-01T00:00:00Z".to_string(),
+        "2026-04-04T00:00:00Z".to_string(),
+        "2026-04-02T00:00:00Z".to_string(),
+    ];
+    let favorite = "2026-04-01T00:00:00Z";
+
+    assert_eq!(
+        history_keys_over_cap(keys.clone(), 2, |key| key == favorite),
+        vec![
+            "2026-04-02T00:00:00Z".to_string(),
+            "2026-04-03T00:00:00Z".to_string()
+        ]
+    );
+    assert!(history_keys_over_cap(keys.clone(), 0, |_| false).is_empty());
+    assert!(history_keys_over_cap(keys.clone(), 4, |_| false).is_empty());
+    // Favorites are exempt even when they alone exceed the cap.
+    assert!(history_keys_over_cap(keys, 1, |_| true).is_empty());
+}
+
+#[test]
+fn retention_removes_rows_older_than_cutoff() {
+    let keys = vec![
+        "2026-04-03T00:00:00Z".to_string(),
+        "2026-04-01T00:00:00Z".to_string(),
+        "not-a-timestamp".to_string(),
+        "2026-04-02T12:00:00+02:00".to_string(),
+    ];
+    let cutoff = chrono::DateTime::parse_from_rfc3339("2026-04-02T12:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+
+    assert_eq!(
+        history_keys_older_than(keys, cutoff),
+        vec![
+            "2026-04-01T00:00:00Z".to_string(),
+            "2026-04-02T12:00:00+02:00".to_string()
+        ]
+    );
+}
+
+#[test]
+fn scheduled_cleanup_applies_retention_then_cap() {
+    let keys = vec![
+        "2026-04-01T00:00:00Z".to_string(),
+        "2026-04-02T00:00:00Z".to_string(),
+        "2026-04-03T00:00:00Z".to_string(),
+        "2026-04-04T00:00:00Z".to_string(),
+        "2026-04-05T00:00:00Z".to_string(),
+    ];
+    let cutoff = chrono::DateTime::parse_from_rfc3339("2026-04-02T00:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+
+    // Age removes 04-01; the cap of 2 then evicts the oldest two of the rest.
+    let mut removed = history_cleanup_keys(keys.clone(), Some(cutoff), 2, |_| false);
+    removed.sort();
+    assert_eq!(
+        removed,
+        vec![
+            "2026-04-01T00:00:00Z".to_string(),
+            "2026-04-02T00:00:00Z".to_string(),
+            "2026-04-03T00:00:00Z".to_string()
+        ]
+    );
+    // Count-only: no cutoff, cap of 4 trims just the oldest row.
+    assert_eq!(
+        history_cleanup_keys(keys.clone(), None, 4, |_| false),
+        vec!["2026-04-01T00:00:00Z".to_string()]
+    );
+    assert!(history_cleanup_keys(keys, None, 0, |_| false).is_empty());
+}
+
 #[test]
 fn page_orders_mixed_rfc3339_offsets_by_timestamp() {
     let keys = vec![
@@ -242,13 +319,157 @@ fn duplicate_save_guard_uses_latest_entry() {
         &latest,
         "same text",
         "base",
-        now
+        now,
+        2
+    ));
+    assert!(!is_duplicate_transcription(
+        latest_key,
+        &latest,
+        "same text",
+        "base",
+        now + chrono::Duration::seconds(3),
+        2
+    ));
+    assert!(is_duplicate_transcription(
+        latest_key,
+        &latest,
+        "same text",
+        "base",
+        now + chrono::Duration::seconds(3),
+        5
     ));
     assert!(!is_duplicate_transcription(
         latest_key,
         &latest,
         "same text",
         "base",
-        now + chrono::Duration::seconds(3)
+        now,
+        0
     ));
 }
+
+#[test]
+fn duplicate_save_guard_ignores_case_and_whitespace_only() {
+    let latest_key = "2026-06-10T12:00:00Z";
+    let latest = json!({
+        "text": "Send the report  by Friday.",
+        "model": "base",
+        "timestamp": latest_key,
+    });
+    let now = chrono::DateTime::parse_from_rfc3339(latest_key)
+        .unwrap()
+        .with_timezone(&chrono::Utc)
+        + chrono::Duration::seconds(1);
+    let is_duplicate =
+        |text: &str| is_duplicate_transcription(latest_key, &latest, text, "base", now, 2);
+
+    assert!(is_duplicate(" send the report by friday.\n"));
+    assert!(is_duplicate("Send\tthe   report by Friday."));
+    assert!(is_duplicate("SEND THE REPORT BY FRIDAY."));
+    assert!(!is_duplicate("Send the report by Monday."));
+    assert!(!is_duplicate("Send the report by Friday!"));
+    assert!(!is_duplicate_transcription(
+        latest_key,
+        &latest,
+        "send the report by friday.",
+        "small",
+        now,
+        2
+    ));
+}
+
+fn search_rows() -> Vec<(String, serde_json::Value)> {
+    [
+        (
+            "2026-06-10T09:00:00Z",
+            "Call Alice about the budget",
+            "base",
+        ),
+        (
+            "2026-06-10T10:00:00Z",
+            "Budget review moved to Friday",
+            "small",
+        ),
+        ("2026-06-10T11:00:00Z", "Lunch with Bob", "base"),
+        (
+            "2026-06-10T12:00:00Z",
+            "alice sent the BUDGET draft",
+            "large-v3",
+        ),
+    ]
+    .into_iter()
+    .map(|(key, text, model)| {
+        (
+            key.to_string(),
+            json!({ "text": text, "model": model, "timestamp": key }),
+        )
+    })
+    .collect()
+}
+
+fn search(query: &str, limit: usize) -> Vec<(String, Vec<(usize, usize)>)> {
+    let rows = search_rows();
+    let keys = rows.iter().map(|(key, _)| key.clone()).collect();
+    let get = |key: &str| {
+        rows.iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.clone())
+    };
+    search_history_entries(keys, get, query, limit)
+        .into_iter()
+        .map(|hit| {
+            (
+                hit.entry["timestamp"].as_str().unwrap().to_string(),
+                hit.matches,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn history_search_requires_every_term_and_returns_newest_first() {
+    assert_eq!(
+        search("budget ALICE", 50),
+        vec![
+            ("2026-06-10T12:00:00Z".to_string(), vec![(0, 5), (15, 21)]),
+            ("2026-06-10T09:00:00Z".to_string(), vec![(5, 10), (21, 27)]),
+        ]
+    );
+    assert_eq!(search("budget", 50).len(), 3);
+    assert_eq!(search("budget", 1)[0].0, "2026-06-10T12:00:00Z");
+    assert!(search("alice lunch", 50).is_empty());
+    assert!(search("   ", 50).is_empty());
+}
+
+#[test]
+fn history_search_hit_keeps_row_fields() {
+    let rows = search_rows();
+    let keys = rows.iter().map(|(key, _)| key.clone()).collect();
+    let hits = search_history_entries(
+        keys,
+        |key| rows.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone()),
+        "lunch",
+        50,
+    );
+    let hit = serde_json::to_value(&hits[0]).unwrap();
+    assert_eq!(hit["text"], "Lunch with Bob");
+    assert_eq!(hit["model"], "base");
+    assert_eq!(hit["timestamp"], "2026-06-10T11:00:00Z");
+    assert_eq!(hit["matches"], json!([[0, 5]]));
+}
+
+#[test]
+fn history_search_matches_merge_overlaps_and_use_utf16_offsets() {
+    let terms =
+        |query: &str| -> Vec<String> { query.split_whitespace().map(str::to_lowercase).collect() };
+    assert_eq!(
+        history_search_matches("banana", &terms("ana nan")),
+        Some(vec![(1, 5)])
+    );
+    // "é" is one UTF-16 unit, the emoji two.
+    assert_eq!(
+        history_search_matches("Café 🎉 CAFÉ", &terms("café")),
+        Some(vec![(0, 4), (8, 12)])
+    );
+    assert_eq!(history_search_matches("Café", &terms("cafe")), None);
+}
@@ -0,0 +1,181 @@
+//! Final formatting pass on the text handed to the focused app, after AI
+//! enhancement and custom replacements. Every step is opt-in, since target
+//! apps disagree on what they want (a chat box wants a trailing space, a
+//! terminal prompt does not).
+
+/// Which output transformations to apply; all off by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutputFormatting {
+    /// Strip leading and trailing whitespace.
+    pub trim_whitespace: bool,
+    /// Collapse runs of spaces and tabs into one space. Line breaks are kept.
+    pub collapse_spaces: bool,
+    /// Upper-case the first letter of the text.
+    pub auto_capitalize_first: bool,
+    /// End the text with exactly one space so the next dictation doesn't run
+    /// into it. Text ending in a line break is left alone.
+    pub ensure_trailing_space: bool,
+}
+
+impl OutputFormatting {
+    pub fn from_settings(store: &tauri_plugin_store::Store<tauri::Wry>) -> Self {
+        let flag = |key: &str| store.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+        Self {
+            trim_whitespace: flag("trim_whitespace"),
+            collapse_spaces: flag("collapse_spaces"),
+            auto_capitalize_first: flag("auto_capitalize_first"),
+            ensure_trailing_space: flag("ensure_trailing_space"),
+        }
+    }
+}
+
+/// Apply `opts` in a fixed order: trim and collapse first, then capitalize,
+/// then the trailing space, so a trailing space is never trimmed back off.
+/// Running it twice gives the same result as running it once.
+pub fn format_output(text: &str, opts: &OutputFormatting) -> String {
+    let mut output = if opts.trim_whitespace {
+        text.trim().to_string()
+    } else {
+        text.to_string()
+    };
+    if opts.collapse_spaces {
+        output = collapse_spaces(&output);
+    }
+    if opts.auto_capitalize_first {
+        output = capitalize_first(&output);
+    }
+    if opts.ensure_trailing_space && !output.is_empty() && !output.ends_with(['\n', '\r']) {
+        output.truncate(output.trim_end_matches([' ', '\t']).len());
+        output.push(' ');
+    }
+    output
+}
+
+fn collapse_spaces(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut in_run = false;
+    for ch in text.chars() {
+        if ch == ' ' || ch == '\t' {
+            if !in_run {
+                output.push(' ');
+            }
+            in_run = true;
+        } else {
+            output.push(ch);
+            in_run = false;
+        }
+    }
+    output
+}
+
+/// Upper-case the first letter, skipping leading whitespace and opening
+/// punctuation such as quotes. Text starting with a digit, or whose first
+/// word looks like a URL or email address, is left alone.
+fn capitalize_first(text: &str) -> String {
+    let Some((index, first)) = text.char_indices().find(|(_, ch)| ch.is_alphanumeric()) else {
+        return text.to_string();
+    };
+    if !first.is_lowercase() {
+        return text.to_string();
+    }
+    let first_word = text[index..].split_whitespace().next().unwrap_or_default();
+    if first_word.contains("://") || first_word.contains('@') {
+        return text.to_string();
+    }
+
+    let mut output = String::with_capacity(text.len() + 2);
+    output.push_str(&text[..index]);
+    output.extend(first.to_uppercase());
+    output.push_str(&text[index + first.len_utf8()..]);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: OutputFormatting = OutputFormatting {
+        trim_whitespace: true,
+        collapse_spaces: true,
+        auto_capitalize_first: true,
+        ensure_trailing_space: true,
+    };
+
+    #[test]
+    fn defaults_leave_text_untouched() {
+        let text = "  hello   world  ";
+        assert_eq!(format_output(text, &OutputFormatting::default()), text);
+    }
+
+    #[test]
+    fn applies_trim_and_collapse_before_capitalize_and_trailing_space() {
+        assert_eq!(format_output("  hello \t  world.  ", &ALL), "Hello world. ");
+    }
+
+    #[test]
+    fn collapse_keeps_line_breaks() {
+        let opts = OutputFormatting {
+            collapse_spaces: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            format_output("first  line\n\nsecond   line", &opts),
+            "first line\n\nsecond line"
+        );
+    }
+
+    #[test]
+    fn trailing_space_is_normalized_to_one_and_skips_line_breaks() {
+        let opts = OutputFormatting {
+            ensure_trailing_space: true,
+            ..Default::default()
+        };
+        assert_eq!(format_output("done", &opts), "done ");
+        assert_eq!(format_output("done   ", &opts), "done ");
+        assert_eq!(format_output("done\n", &opts), "done\n");
+        assert_eq!(format_output("", &opts), "");
+    }
+
+    #[test]
+    fn capitalizes_unicode_first_letters() {
+        let opts = OutputFormatting {
+            auto_capitalize_first: true,
+            ..Default::default()
+        };
+        assert_eq!(format_output("über alles", &opts), "Über alles");
+        assert_eq!(format_output("éclair", &opts), "Éclair");
+        assert_eq!(format_output("ωμέγα", &opts), "Ωμέγα");
+        assert_eq!(format_output("«ça va»", &opts), "«Ça va»");
+        // Scripts without case are unchanged.
+        assert_eq!(format_output("こんにちは", &opts), "こんにちは");
+    }
+
+    #[test]
+    fn capitalize_skips_numbers_urls_and_emails() {
+        let opts = OutputFormatting {
+            auto_capitalize_first: true,
+            ..Default::default()
+        };
+        assert_eq!(format_output("3 apples", &opts), "3 apples");
+        assert_eq!(
+            format_output("https://example.com is up", &opts),
+            "https://example.com is up"
+        );
+        assert_eq!(format_output("me@example.com", &opts), "me@example.com");
+    }
+
+    #[test]
+    fn formatting_is_idempotent() {
+        for text in [
+            "  hello \t  world.  ",
+            "über\n\n  alles",
+            "\tline\n",
+            "",
+            "   ",
+            "«ça   va»",
+        ] {
+            let once = format_output(text, &ALL);
+            assert_eq!(format_output(&once, &ALL), once, "input {text:?}");
+        }
+    }
+}
@@ -0,0 +1,5 @@
+// Deterministic text transforms shared by the writing pipeline
+pub mod formatting;
+pub mod replacements;
+pub mod sinks;
+pub mod voice_commands;
@@ -0,0 +1,287 @@
+//! Matching for find-and-replace corrections, e.g. "voice typer" →
+//! "VoiceTypr". Matching is literal (never a user-supplied regex) and runs
+//! without AI, so product names come out right for every user. The writing
+//! pipeline runs its explicit rules and custom-word spoken forms through
+//! [`apply_replacements`].
+
+use regex::{Regex, RegexBuilder};
+
+use crate::writing::TextReplacementRule;
+
+/// A find-and-replace rule as [`apply_replacements`] sees it.
+pub trait ReplacementRule {
+    /// Byte ranges of `text` this rule would replace, boundaries already
+    /// checked. See [`literal_matches`].
+    fn find(&self, text: &str) -> Vec<(usize, usize)>;
+
+    fn replacement(&self) -> &str;
+
+    /// Breaks ties between matches starting at the same offset, ahead of
+    /// match length. Higher wins.
+    fn priority(&self) -> u8 {
+        0
+    }
+}
+
+impl<R: ReplacementRule + ?Sized> ReplacementRule for &R {
+    fn find(&self, text: &str) -> Vec<(usize, usize)> {
+        (**self).find(text)
+    }
+
+    fn replacement(&self) -> &str {
+        (**self).replacement()
+    }
+
+    fn priority(&self) -> u8 {
+        (**self).priority()
+    }
+}
+
+/// Settings rules compile their pattern on every call; the writing pipeline
+/// caches compiled rules instead.
+impl ReplacementRule for TextReplacementRule {
+    fn find(&self, text: &str) -> Vec<(usize, usize)> {
+        if !self.enabled {
+            return Vec::new();
+        }
+        match replacement_regex(&self.from, self.case_sensitive, self.whole_word) {
+            Some(regex) => literal_matches(&regex, self.whole_word, text),
+            None => Vec::new(),
+        }
+    }
+
+    fn replacement(&self) -> &str {
+        &self.to
+    }
+}
+
+/// One replacement made by [`apply_replacements`]: `rule` indexes the rules
+/// passed in, `start..end` is the span in the input and
+/// `target_start..target_end` where the replacement landed in the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppliedReplacement {
+    pub rule: usize,
+    pub start: usize,
+    pub end: usize,
+    pub target_start: usize,
+    pub target_end: usize,
+}
+
+/// Output of [`apply_replacements`], in input order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replaced {
+    pub text: String,
+    pub applied: Vec<AppliedReplacement>,
+}
+
+/// Characters that continue a word for whole-word matching. Unicode-aware, so
+/// `über` inside `Überweisung` is never treated as a separate word.
+pub fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+/// Whether `text[start..end]` is not glued to a word character on either side.
+pub fn has_word_boundaries(text: &str, start: usize, end: usize) -> bool {
+    let left_ok = text[..start]
+        .chars()
+        .last()
+        .map(|ch| !is_word_char(ch))
+        .unwrap_or(true);
+    let right_ok = text[end..]
+        .chars()
+        .next()
+        .map(|ch| !is_word_char(ch))
+        .unwrap_or(true);
+    left_ok && right_ok
+}
+
+/// Compile the literal `from` of a rule. Whole-word rules get `\b` on each
+/// edge that starts or ends with a word character; an edge such as the `+` of
+/// `C++` has no regex boundary to anchor on, so callers still confirm matches
+/// with [`has_word_boundaries`]. Returns `None` for an empty pattern.
+pub fn replacement_regex(from: &str, case_sensitive: bool, whole_word: bool) -> Option<Regex> {
+    if from.is_empty() {
+        return None;
+    }
+    let mut pattern = regex::escape(from);
+    if whole_word {
+        if from.chars().next().is_some_and(is_word_char) {
+            pattern.insert_str(0, r"\b");
+        }
+        if from.chars().last().is_some_and(is_word_char) {
+            pattern.push_str(r"\b");
+        }
+    }
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!case_sensitive)
+        .build()
+        .ok()
+}
+
+/// Matches of a [`replacement_regex`] in `text`, dropping whole-word matches
+/// that touch a word character on either side.
+pub fn literal_matches(regex: &Regex, whole_word: bool, text: &str) -> Vec<(usize, usize)> {
+    regex
+        .find_iter(text)
+        .map(|mat| (mat.start(), mat.end()))
+        .filter(|&(start, end)| !whole_word || has_word_boundaries(text, start, end))
+        .collect()
+}
+
+/// Apply every rule in one pass over `text`. Where matches overlap, the one
+/// starting first wins, then the higher [`ReplacementRule::priority`], then
+/// the longer one, then the earlier rule; replaced text is never matched
+/// again, so rules cannot chain.
+pub fn apply_replacements<R: ReplacementRule>(text: &str, rules: &[R]) -> Replaced {
+    let mut matches = Vec::new();
+    for (index, rule) in rules.iter().enumerate() {
+        for (start, end) in rule.find(text) {
+            matches.push((start, end, index));
+        }
+    }
+    matches.sort_by(|left, right| {
+        left.0
+            .cmp(&right.0)
+            .then(rules[right.2].priority().cmp(&rules[left.2].priority()))
+            .then((right.1 - right.0).cmp(&(left.1 - left.0)))
+            .then(left.2.cmp(&right.2))
+    });
+
+    let mut output = String::with_capacity(text.len());
+    let mut applied = Vec::new();
+    let mut last = 0usize;
+    for (start, end, index) in matches {
+        if start < last {
+            continue;
+        }
+        output.push_str(&text[last..start]);
+        let target_start = output.len();
+        output.push_str(rules[index].replacement());
+        applied.push(AppliedReplacement {
+            rule: index,
+            start,
+            end,
+            target_start,
+            target_end: output.len(),
+        });
+        last = end;
+    }
+    output.push_str(&text[last..]);
+    Replaced {
+        text: output,
+        applied,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(from: &str, to: &str) -> TextReplacementRule {
+        TextReplacementRule {
+            from: from.to_string(),
+            to: to.to_string(),
+            language: None,
+            enabled: true,
+            case_sensitive: false,
+            whole_word: true,
+        }
+    }
+
+    fn replace(text: &str, rules: &[TextReplacementRule]) -> String {
+        apply_replacements(text, rules).text
+    }
+
+    #[test]
+    fn replaces_case_insensitively_by_default() {
+        let rules = [rule("voice typer", "VoiceTypr")];
+        assert_eq!(
+            replace("I use Voice Typer daily", &rules),
+            "I use VoiceTypr daily"
+        );
+    }
+
+    #[test]
+    fn case_sensitive_rules_only_match_exact_case() {
+        let rules = [TextReplacementRule {
+            case_sensitive: true,
+            ..rule("go", "Go")
+        }];
+        assert_eq!(replace("go and GO", &rules), "Go and GO");
+    }
+
+    #[test]
+    fn overlapping_rules_prefer_earliest_then_longest_match() {
+        let rules = [
+            rule("typer", "Typer"),
+            rule("voice typer", "VoiceTypr"),
+            rule("typer app", "TyperApp"),
+        ];
+        // "voice typer" starts first and wins; "typer app" overlaps it and is
+        // dropped rather than applied to already-replaced text.
+        assert_eq!(
+            replace("voice typer app, typer", &rules),
+            "VoiceTypr app, Typer"
+        );
+    }
+
+    #[test]
+    fn replacements_do_not_chain() {
+        let rules = [rule("a", "b"), rule("b", "c")];
+        assert_eq!(replace("a b", &rules), "b c");
+    }
+
+    #[test]
+    fn whole_word_boundaries_are_unicode_aware() {
+        let rules = [rule("über", "uber"), rule("na", "NA")];
+        assert_eq!(
+            replace("Über Überweisung naïve na", &rules),
+            "uber Überweisung naïve NA"
+        );
+        let rules = [rule("café", "Café")];
+        assert_eq!(replace("cafés, café.", &rules), "cafés, Café.");
+    }
+
+    #[test]
+    fn whole_word_applies_to_symbol_edges() {
+        let rules = [rule("c++", "C++")];
+        assert_eq!(replace("c++ c++x", &rules), "C++ c++x");
+    }
+
+    #[test]
+    fn substring_rules_match_inside_words() {
+        let rules = [TextReplacementRule {
+            whole_word: false,
+            ..rule("colour", "color")
+        }];
+        assert_eq!(replace("colourful", &rules), "colorful");
+    }
+
+    #[test]
+    fn disabled_and_empty_rules_are_ignored() {
+        let rules = [
+            TextReplacementRule {
+                enabled: false,
+                ..rule("hello", "bye")
+            },
+            rule("", "x"),
+        ];
+        assert_eq!(replace("hello", &rules), "hello");
+    }
+
+    #[test]
+    fn applied_spans_point_into_input_and_output() {
+        let replaced = apply_replacements("say vt now", &[rule("vt", "VoiceTypr")]);
+        assert_eq!(replaced.text, "say VoiceTypr now");
+        assert_eq!(
+            replaced.applied,
+            vec![AppliedReplacement {
+                rule: 0,
+                start: 4,
+                end: 6,
+                target_start: 4,
+                target_end: 13,
+            }]
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! Where a finished transcription goes. The cursor sink is the regular
+//! insertion path in `stop_recording`; the clipboard and file sinks run after
+//! it, so a dictation can be pasted and also logged to a daily notes file.
+
+use chrono::{DateTime, Local};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+/// Sink used when the setting is missing or lists nothing usable.
+pub const DEFAULT_SINK: &str = "cursor";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscriptionSink {
+    /// Insert at the cursor (or the scratchpad / clipboard fallback, as
+    /// configured by the existing insertion settings).
+    Cursor,
+    /// Leave the text on the clipboard.
+    Clipboard,
+    /// Append to a markdown file; the path is a template, see
+    /// [`expand_path_template`].
+    File(String),
+}
+
+impl TranscriptionSink {
+    /// Parse `cursor`, `clipboard`, or `file:<path template>`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if let Some(template) = value.strip_prefix("file:") {
+            let template = template.trim();
+            return (!template.is_empty()).then(|| Self::File(template.to_string()));
+        }
+        match value.to_lowercase().as_str() {
+            "cursor" => Some(Self::Cursor),
+            "clipboard" => Some(Self::Clipboard),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the `transcription_sinks` setting, dropping unknown entries and
+/// duplicates. Falls back to the cursor alone when nothing is left.
+pub fn parse_sinks(values: &[String]) -> Vec<TranscriptionSink> {
+    let mut sinks = Vec::new();
+    for value in values {
+        match TranscriptionSink::parse(value) {
+            Some(sink) if !sinks.contains(&sink) => sinks.push(sink),
+            Some(_) => {}
+            None => log::warn!("Ignoring unknown transcription sink '{}'", value),
+        }
+    }
+    if sinks.is_empty() {
+        sinks.push(TranscriptionSink::Cursor);
+    }
+    sinks
+}
+
+/// Expand date placeholders in a file sink path: `{date}` (2024-05-01),
+/// `{year}`, `{month}`, `{day}`. A leading `~/` is the home directory.
+pub fn expand_path_template(template: &str, now: DateTime<Local>) -> PathBuf {
+    let expanded = template
+        .replace("{date}", &now.format("%Y-%m-%d").to_string())
+        .replace("{year}", &now.format("%Y").to_string())
+        .replace("{month}", &now.format("%m").to_string())
+        .replace("{day}", &now.format("%d").to_string());
+    match expanded.strip_prefix("~/").zip(dirs::home_dir()) {
+        Some((rest, home)) => home.join(rest),
+        None => PathBuf::from(expanded),
+    }
+}
+
+/// Append `text` under a timestamp header, creating the file and its parent
+/// directories as needed.
+pub fn append_to_file(path: &Path, text: &str, now: DateTime<Local>) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    write!(
+        file,
+        "## {}\n\n{}\n\n",
+        now.format("%Y-%m-%d %H:%M:%S"),
+        text.trim()
+    )
+    .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Run the clipboard and file sinks for a delivered transcription. Failures
+/// are reported on the pill and never undo the cursor delivery.
+pub async fn deliver_to_extra_sinks(app: &AppHandle, sinks: &[TranscriptionSink], text: &str) {
+    let now = Local::now();
+    for sink in sinks {
+        match sink {
+            TranscriptionSink::Cursor => {}
+            TranscriptionSink::Clipboard => {
+                if let Err(e) =
+                    crate::commands::text::copy_text_to_clipboard(text.to_string()).await
+                {
+                    log::error!("Clipboard sink failed: {}", e);
+                    crate::commands::audio::pill_toast(app, "Copy failed", 1500);
+                }
+            }
+            TranscriptionSink::File(template) => {
+                let path = expand_path_template(template, now);
+                let text = text.to_string();
+                let result = tokio::task::spawn_blocking({
+                    let path = path.clone();
+                    move || append_to_file(&path, &text, now)
+                })
+                .await
+                .unwrap_or_else(|e| Err(e.to_string()));
+                match result {
+                    Ok(()) => log::debug!("Transcription appended to {}", path.display()),
+                    Err(e) => {
+                        log::error!("File sink failed: {}", e);
+                        crate::commands::audio::pill_toast(app, "Saving to file failed", 1500);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(h: u32, m: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 5, 1, h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn parses_sinks_and_falls_back_to_cursor() {
+        let sinks = parse_sinks(&[
+            "clipboard".to_string(),
+            "file: notes/{date}.md".to_string(),
+            "Clipboard".to_string(),
+            "printer".to_string(),
+            "file:".to_string(),
+        ]);
+        assert_eq!(
+            sinks,
+            vec![
+                TranscriptionSink::Clipboard,
+                TranscriptionSink::File("notes/{date}.md".to_string()),
+            ]
+        );
+        assert_eq!(parse_sinks(&[]), vec![TranscriptionSink::Cursor]);
+        assert_eq!(
+            parse_sinks(&["bogus".to_string()]),
+            vec![TranscriptionSink::Cursor]
+        );
+    }
+
+    #[test]
+    fn expands_date_placeholders() {
+        assert_eq!(
+            expand_path_template("/notes/{year}/{month}/{date}-{day}.md", at(9, 0)),
+            PathBuf::from("/notes/2024/05/2024-05-01-01.md")
+        );
+    }
+
+    #[test]
+    fn appends_entries_with_timestamp_headers_creating_parent_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = expand_path_template(
+            &format!("{}/daily/{{date}}.md", dir.path().display()),
+            at(9, 30),
+        );
+
+        append_to_file(&path, "first note\n", at(9, 30)).unwrap();
+        append_to_file(&path, "second note", at(10, 5)).unwrap();
+
+        assert_eq!(path, dir.path().join("daily/2024-05-01.md"));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "## 2024-05-01 09:30:00\n\nfirst note\n\n## 2024-05-01 10:05:00\n\nsecond note\n\n"
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! Spoken dictation commands such as "new line" or "insert comma". Each rule
+//! names an output token from a fixed table, so users can add phrases in any
+//! language without being able to inject arbitrary text.
+
+use crate::writing::VoiceCommandRule;
+
+/// What a recognized command turns into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoiceCommandOutput {
+    /// Attaches to the previous word, e.g. `,` or `.`.
+    Punctuation(&'static str),
+    /// Line breaks; spaces around them are dropped.
+    Break(&'static str),
+}
+
+/// Resolve a rule's output token (`"comma"`, `"new_line"`, ...).
+pub fn voice_command_output(token: &str) -> Option<VoiceCommandOutput> {
+    match token {
+        "comma" => Some(VoiceCommandOutput::Punctuation(",")),
+        "period" => Some(VoiceCommandOutput::Punctuation(".")),
+        "question_mark" => Some(VoiceCommandOutput::Punctuation("?")),
+        "exclamation_mark" => Some(VoiceCommandOutput::Punctuation("!")),
+        "colon" => Some(VoiceCommandOutput::Punctuation(":")),
+        "semicolon" => Some(VoiceCommandOutput::Punctuation(";")),
+        "dash" => Some(VoiceCommandOutput::Punctuation("\u{2014}")),
+        "new_line" => Some(VoiceCommandOutput::Break("\n")),
+        "paragraph" => Some(VoiceCommandOutput::Break("\n\n")),
+        _ => None,
+    }
+}
+
+/// Built-in English commands. Bare "comma" and "period" are spelled
+/// "insert comma" / "insert period" so ordinary sentences ("a period of
+/// time") are never rewritten; users can add the bare words as rules.
+pub fn default_voice_commands() -> Vec<VoiceCommandRule> {
+    [
+        ("new paragraph", "paragraph"),
+        ("new line", "new_line"),
+        ("question mark", "question_mark"),
+        ("exclamation point", "exclamation_mark"),
+        ("exclamation mark", "exclamation_mark"),
+        ("full stop", "period"),
+        ("insert comma", "comma"),
+        ("insert period", "period"),
+    ]
+    .into_iter()
+    .map(|(phrase, output)| VoiceCommandRule {
+        phrase: phrase.to_string(),
+        output: output.to_string(),
+        language: Some("en".to_string()),
+        enabled: true,
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_tokens_map_to_symbols_and_breaks() {
+        assert_eq!(
+            voice_command_output("comma"),
+            Some(VoiceCommandOutput::Punctuation(","))
+        );
+        assert_eq!(
+            voice_command_output("paragraph"),
+            Some(VoiceCommandOutput::Break("\n\n"))
+        );
+        assert_eq!(voice_command_output("Comma"), None);
+        assert_eq!(voice_command_output("arbitrary_text"), None);
+    }
+
+    #[test]
+    fn default_commands_resolve_and_are_english_scoped() {
+        let defaults = default_voice_commands();
+        assert!(defaults
+            .iter()
+            .all(|rule| voice_command_output(&rule.output).is_some()));
+        assert!(defaults
+            .iter()
+            .all(|rule| rule.enabled && rule.language.as_deref() == Some("en")));
+        assert!(defaults.iter().any(|rule| rule.phrase == "new line"));
+        assert!(defaults.iter().any(|rule| rule.phrase == "new paragraph"));
+    }
+}
@@ -106,6 +106,9 @@ pub struct TranscriptionResult {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub words: Option<Vec<TranscriptionWord>>,
     pub timings: TranscriptionTimings,
+    /// Whisper's mean token probability (0-1); `None` for engines that don't report it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avg_confidence: Option<f32>,
 }
 
 impl TranscriptionResult {
@@ -123,6 +126,7 @@ impl TranscriptionResult {
             segments: None,
             words: None,
             timings: TranscriptionTimings::default(),
+            avg_confidence: None,
         }
     }
 
@@ -147,12 +151,274 @@ impl TranscriptionResult {
         self.timings.processing_duration_ms = processing_duration_ms;
         self
     }
+
+    pub fn with_avg_confidence(mut self, avg_confidence: Option<f32>) -> Self {
+        self.avg_confidence = avg_confidence;
+        self
+    }
+}
+
+fn shift_timestamps(result: &mut TranscriptionResult, offset_ms: u64) {
+    let shift = |value: &mut Option<u64>| {
+        if let Some(ms) = value.as_mut() {
+            *ms = ms.saturating_add(offset_ms);
+        }
+    };
+    for segment in result.segments.iter_mut().flatten() {
+        shift(&mut segment.start_ms);
+        shift(&mut segment.end_ms);
+    }
+    for word in result.words.iter_mut().flatten() {
+        shift(&mut word.start_ms);
+        shift(&mut word.end_ms);
+    }
+}
+
+fn sum_optional(left: Option<u64>, right: Option<u64>) -> Option<u64> {
+    match (left, right) {
+        (Some(a), Some(b)) => Some(a.saturating_add(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn overlap_word_key(word: &str) -> String {
+    word.chars()
+        .filter(|ch| ch.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Drop the start of `next` that repeats the end of `previous`, for chunks
+/// transcribed with a little shared audio at their boundary. The repeat is the
+/// longest run of at least two words (ignoring case and punctuation) that ends
+/// `previous` and starts `next`, optionally after one leading fragment of a
+/// word the chunk boundary cut in half.
+pub fn strip_repeated_overlap<'a>(previous: &str, next: &'a str) -> &'a str {
+    const MAX_OVERLAP_WORDS: usize = 24;
+
+    let mut tail: Vec<String> = previous
+        .split_whitespace()
+        .rev()
+        .take(MAX_OVERLAP_WORDS)
+        .map(overlap_word_key)
+        .collect();
+    tail.reverse();
+    let head: Vec<(usize, String)> = next
+        .split_whitespace()
+        .take(MAX_OVERLAP_WORDS + 1)
+        .map(|word| {
+            let end = word.as_ptr() as usize - next.as_ptr() as usize + word.len();
+            (end, overlap_word_key(word))
+        })
+        .collect();
+
+    for skip in 0..=1 {
+        let available = head.len().saturating_sub(skip).min(tail.len());
+        for len in (2..=available).rev() {
+            let repeated = head[skip..skip + len]
+                .iter()
+                .map(|(_, key)| key)
+                .eq(tail[tail.len() - len..].iter());
+            if repeated {
+                let (end, _) = head[skip + len - 1];
+                return next[end..].trim_start();
+            }
+        }
+    }
+    next
+}
+
+/// Drop segments and words of a chunk that start at or after `chunk_ms`, the
+/// chunk's own length. Whatever starts there lies in the overlap appended
+/// from the next chunk, which transcribes that audio again from its start.
+/// Entries without a start time are kept.
+pub fn drop_overlap_timings(result: &mut TranscriptionResult, chunk_ms: u64) {
+    let in_chunk = |start: Option<u64>| start.is_none_or(|ms| ms < chunk_ms);
+    if let Some(segments) = result.segments.as_mut() {
+        segments.retain(|segment| in_chunk(segment.start_ms));
+    }
+    if let Some(words) = result.words.as_mut() {
+        words.retain(|word| in_chunk(word.start_ms));
+    }
+}
+
+/// Stitch the results of consecutive chunks of one long input back together.
+///
+/// Each entry carries the chunk's start offset within the original audio;
+/// segment and word timestamps are shifted by it so they stay relative to the
+/// whole file. Chunk texts are joined with a single space.
+pub fn merge_chunk_results(chunks: Vec<(u64, TranscriptionResult)>) -> Option<TranscriptionResult> {
+    let mut chunks = chunks.into_iter();
+    let (first_offset, mut merged) = chunks.next()?;
+    shift_timestamps(&mut merged, first_offset);
+    merged.raw_text = merged.raw_text.trim().to_string();
+
+    for (offset_ms, mut next) in chunks {
+        shift_timestamps(&mut next, offset_ms);
+        let text = next.raw_text.trim();
+        if !text.is_empty() {
+            if !merged.raw_text.is_empty() {
+                merged.raw_text.push(' ');
+            }
+            merged.raw_text.push_str(text);
+        }
+        merged.segments = match (merged.segments.take(), next.segments) {
+            (Some(mut all), Some(more)) => {
+                all.extend(more);
+                Some(all)
+            }
+            (all, more) => all.or(more),
+        };
+        merged.words = match (merged.words.take(), next.words) {
+            (Some(mut all), Some(more)) => {
+                all.extend(more);
+                Some(all)
+            }
+            (all, more) => all.or(more),
+        };
+        merged.timings.audio_duration_ms = sum_optional(
+            merged.timings.audio_duration_ms,
+            next.timings.audio_duration_ms,
+        );
+        merged.timings.processing_duration_ms = sum_optional(
+            merged.timings.processing_duration_ms,
+            next.timings.processing_duration_ms,
+        );
+        // The least confident chunk speaks for the whole recording.
+        merged.avg_confidence = match (merged.avg_confidence, next.avg_confidence) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        if merged.transcript_language.is_none() {
+            merged.transcript_language = next.transcript_language;
+        }
+    }
+    Some(merged)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn merge_chunk_results_joins_text_and_offsets_timestamps() {
+        let job = TranscriptionJob::from_legacy_settings(
+            TranscriptionSource::AudioFile,
+            "whisper",
+            "large-v3",
+            Some("en".to_string()),
+            false,
+        );
+        let chunk = |text: &str, start: u64, end: u64| {
+            TranscriptionResult::new(&job, text)
+                .with_segments(vec![TranscriptionSegment {
+                    text: text.trim().to_string(),
+                    start_ms: Some(start),
+                    end_ms: Some(end),
+                    speaker_id: None,
+                }])
+                .with_audio_duration_ms(Some(60_000))
+        };
+
+        let merged = merge_chunk_results(vec![
+            (0, chunk(" first part ", 0, 59_000)),
+            (60_000, chunk("   ", 0, 0)),
+            (120_000, chunk("third part", 500, 4_000)),
+        ])
+        .unwrap();
+
+        assert_eq!(merged.raw_text, "first part third part");
+        let segments = merged.segments.unwrap();
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[2].start_ms, Some(120_500));
+        assert_eq!(segments[2].end_ms, Some(124_000));
+        assert_eq!(merged.timings.audio_duration_ms, Some(180_000));
+        assert!(merge_chunk_results(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn drop_overlap_timings_keeps_only_what_starts_inside_the_chunk() {
+        let job = TranscriptionJob::from_legacy_settings(
+            TranscriptionSource::AudioFile,
+            "whisper",
+            "large-v3",
+            Some("en".to_string()),
+            false,
+        );
+        let segment = |start_ms: Option<u64>| TranscriptionSegment {
+            text: "text".to_string(),
+            start_ms,
+            end_ms: None,
+            speaker_id: None,
+        };
+        let word = |start_ms: Option<u64>| TranscriptionWord {
+            text: "word".to_string(),
+            start_ms,
+            end_ms: None,
+            speaker_id: None,
+            confidence: None,
+        };
+        let mut result = TranscriptionResult::new(&job, "text").with_segments(vec![
+            segment(Some(0)),
+            segment(Some(59_999)),
+            segment(Some(60_000)),
+            segment(None),
+        ]);
+        result.words = Some(vec![word(Some(59_000)), word(Some(61_000)), word(None)]);
+
+        drop_overlap_timings(&mut result, 60_000);
+
+        let starts: Vec<_> = result
+            .segments
+            .unwrap()
+            .iter()
+            .map(|segment| segment.start_ms)
+            .collect();
+        assert_eq!(starts, vec![Some(0), Some(59_999), None]);
+        let starts: Vec<_> = result
+            .words
+            .unwrap()
+            .iter()
+            .map(|word| word.start_ms)
+            .collect();
+        assert_eq!(starts, vec![Some(59_000), None]);
+    }
+
+    #[test]
+    fn strip_repeated_overlap_drops_words_shared_with_previous_chunk() {
+        assert_eq!(
+            strip_repeated_overlap(
+                "and then we went to the park.",
+                "went to the park. After that"
+            ),
+            "After that"
+        );
+        // A word cut in half at the chunk boundary is dropped with the repeat.
+        assert_eq!(
+            strip_repeated_overlap("we shipped the new release", "ase the new release today"),
+            "today"
+        );
+        assert_eq!(
+            strip_repeated_overlap("The Quick brown fox", "quick, brown fox jumps"),
+            "jumps"
+        );
+    }
+
+    #[test]
+    fn strip_repeated_overlap_keeps_text_without_a_real_repeat() {
+        // A single shared word is too weak a signal to drop anything.
+        assert_eq!(
+            strip_repeated_overlap("this is the", "the end of it"),
+            "the end of it"
+        );
+        assert_eq!(
+            strip_repeated_overlap("first chunk", "second chunk"),
+            "second chunk"
+        );
+        assert_eq!(strip_repeated_overlap("", "hello world"), "hello world");
+        assert_eq!(strip_repeated_overlap("hello world", ""), "");
+    }
+
     #[test]
     fn test_translate_task_falls_back_to_english_transcript_language() {
         let job = TranscriptionJob::from_legacy_settings(
@@ -28,10 +28,11 @@ mod tests {
             (ProviderEngine::Whisper, true, true, true),
             (ProviderEngine::Parakeet, false, true, true),
             (ProviderEngine::Soniox, false, true, false),
-            (ProviderEngine::Openai, false, true, false),
+            (ProviderEngine::Openai, true, true, false),
             (ProviderEngine::Groq, false, true, false),
             (ProviderEngine::Deepgram, false, true, false),
             (ProviderEngine::Cohere, false, false, false),
+            (ProviderEngine::Assemblyai, false, false, false),
             (ProviderEngine::Remote, true, false, false),
         ];
 
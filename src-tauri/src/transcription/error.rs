@@ -99,6 +99,7 @@ pub(crate) fn from_stt_error(err: &SttError, source: TranscriptionSource) -> Tra
         E::Timeout => TranscriptionErrorCode::Timeout,
         E::Server => TranscriptionErrorCode::EngineFailed,
         E::BadResponse => TranscriptionErrorCode::ResponseInvalid,
+        E::Cancelled => TranscriptionErrorCode::Cancelled,
     };
 
     let mut mapped = TranscriptionError::new(code, source, user_message_for_code(code))
@@ -285,6 +286,11 @@ mod tests {
                 TranscriptionErrorCode::ResponseInvalid,
                 false,
             ),
+            (
+                SttError::Cancelled,
+                TranscriptionErrorCode::Cancelled,
+                false,
+            ),
         ];
 
         for (err, code, retryable) in cases {
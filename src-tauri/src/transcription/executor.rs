@@ -144,13 +144,13 @@ fn deferred_executor_route_error(
 
 /// Reject a translate-to-English task a cloud engine cannot honor.
 ///
-/// Every curated cloud provider transcribes in the spoken language
+/// Most curated cloud providers only transcribe in the spoken language
 /// (`supports_translate_task == false`). Routing such a request through would
 /// return source-language text that `TranscriptionResult::new` then mislabels
 /// as English — it falls back to `"en"` for a translate task. Reject up front
 /// so the caller never receives mislabeled output. An engine that genuinely
-/// supports the task passes through unchanged (none of the cloud engines do
-/// today).
+/// supports the task passes through unchanged (OpenAI, via its translations
+/// endpoint).
 fn ensure_cloud_task_supported(
     provider: crate::cloud_stt::CloudProvider,
     translate: bool,
@@ -203,7 +203,8 @@ async fn route_once(
                 .with_transcript_language(output.transcript_language)
                 .with_segments(output.segments)
                 .with_audio_duration_ms(Some(output.audio_duration_ms))
-                .with_processing_duration_ms(Some(output.processing_duration_ms)))
+                .with_processing_duration_ms(Some(output.processing_duration_ms))
+                .with_avg_confidence(output.avg_confidence))
         }
         ActiveEngineSelection::Parakeet { model_name } => {
             let manager = app.state::<ParakeetManager>();
@@ -232,6 +233,7 @@ async fn route_once(
                 translate,
                 custom_vocabulary,
                 cancel_flag: Some(cancel),
+                emit_partials: source == TranscriptionSource::DesktopRecording,
             };
 
             match manager
@@ -283,7 +285,12 @@ async fn route_once(
                     ))
                 }
             };
-            match provider.transcribe_typed(app, &key, input_path, language).await {
+            let transcript = if translate {
+                provider.translate_typed(app, &key, input_path).await
+            } else {
+                provider.transcribe_typed(app, &key, input_path, language).await
+            };
+            match transcript {
                 Ok(text) => Ok(TranscriptionResult::new(job, text)),
                 Err(e) => Err(from_stt_error(&e, source)),
             }
@@ -966,10 +973,16 @@ mod tests {
     fn cloud_translate_to_english_rejected_for_unsupported_engines() {
         use crate::cloud_stt::CloudProvider;
 
-        // No curated cloud provider supports translation; each must reject a
-        // translate-to-English request instead of returning source-language
-        // text that TranscriptionResult::new would mislabel as English.
-        for provider in CloudProvider::ALL {
+        // Only OpenAI has a translation endpoint; every other provider must
+        // reject a translate-to-English request instead of returning
+        // source-language text that TranscriptionResult::new would mislabel
+        // as English.
+        ensure_cloud_task_supported(CloudProvider::Openai, true, TranscriptionSource::AudioFile)
+            .expect("OpenAI translates via /audio/translations");
+        for provider in CloudProvider::ALL
+            .iter()
+            .filter(|provider| **provider != CloudProvider::Openai)
+        {
             let err = ensure_cloud_task_supported(*provider, true, TranscriptionSource::AudioFile)
                 .expect_err("translate-to-English must be rejected on cloud");
             assert_eq!(err.code, TranscriptionErrorCode::EngineUnavailable);
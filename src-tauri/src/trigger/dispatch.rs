@@ -1,10 +1,32 @@
 //! Route `keytrigger::TriggerEvent`s to the shared shortcut dispatch path.
 
-use keytrigger::TriggerEvent;
+use keytrigger::{KeyPhase, TriggerEvent};
 use tauri::{AppHandle, Manager};
 
+use crate::commands::settings::AppProfile;
+use crate::commands::shortcuts::ShortcutAction;
+use crate::recording::start_gate;
 use crate::state::app_state::AppState;
 
+use super::EngineBinding;
+
+/// Overrides to stage when `binding` fires with `phase` and a recording could
+/// start: `Some(None)` for a recording binding without overrides (clearing any
+/// stale ones), `None` when the event cannot start a recording.
+fn recording_overrides_to_stage(
+    binding: &EngineBinding,
+    phase: KeyPhase,
+) -> Option<Option<AppProfile>> {
+    let starts_recording = matches!(
+        binding.action,
+        ShortcutAction::ToggleRecording | ShortcutAction::HoldToRecord
+    );
+    if phase != KeyPhase::Pressed || !starts_recording {
+        return None;
+    }
+    Some(binding.overrides.to_profile(&binding.id))
+}
+
 /// Invoked on the engine dispatcher thread for every emitted trigger event.
 pub fn on_engine_event(app: &AppHandle, ev: TriggerEvent) {
     let app_state = app.state::<AppState>();
@@ -39,6 +61,14 @@ pub fn on_engine_event(app: &AppHandle, ev: TriggerEvent) {
         return;
     }
 
+    if let Some(profile) = recording_overrides_to_stage(&binding, ev.phase) {
+        if start_gate::state_allows_start(crate::get_recording_state(app)) {
+            if let Ok(mut staged) = app_state.shortcut_profile.lock() {
+                *staged = profile;
+            }
+        }
+    }
+
     crate::recording::hotkeys::dispatch_action(
         app,
         app_state.inner(),
@@ -48,3 +78,64 @@ pub fn on_engine_event(app: &AppHandle, ev: TriggerEvent) {
         ev.phase,
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::recording_overrides_to_stage;
+    use crate::commands::shortcuts::{RecordingOverrides, ShortcutAction, ShortcutTrigger};
+    use crate::trigger::EngineBinding;
+    use keytrigger::KeyPhase;
+
+    fn binding(id: &str, action: ShortcutAction, language: &str) -> EngineBinding {
+        EngineBinding {
+            id: id.to_string(),
+            action,
+            trigger: if action == ShortcutAction::HoldToRecord {
+                ShortcutTrigger::Hold
+            } else {
+                ShortcutTrigger::Pressed
+            },
+            overrides: RecordingOverrides {
+                language: language.to_string(),
+                ..RecordingOverrides::default()
+            },
+        }
+    }
+
+    #[test]
+    fn secondary_recording_shortcut_stages_its_language() {
+        let german = binding("german", ShortcutAction::ToggleRecording, "de");
+        let profile = recording_overrides_to_stage(&german, KeyPhase::Pressed)
+            .flatten()
+            .expect("language override is staged");
+        assert_eq!(profile.language, "de");
+        assert!(profile.model.is_empty());
+
+        let german_ptt = binding("german-ptt", ShortcutAction::HoldToRecord, "de");
+        let profile = recording_overrides_to_stage(&german_ptt, KeyPhase::Pressed).flatten();
+        assert_eq!(profile.map(|p| p.language).as_deref(), Some("de"));
+    }
+
+    #[test]
+    fn primary_shortcut_clears_staged_overrides() {
+        let primary = binding("primary", ShortcutAction::ToggleRecording, "");
+        assert_eq!(
+            recording_overrides_to_stage(&primary, KeyPhase::Pressed),
+            Some(None)
+        );
+    }
+
+    #[test]
+    fn releases_and_non_recording_actions_stage_nothing() {
+        let german_ptt = binding("german-ptt", ShortcutAction::HoldToRecord, "de");
+        assert_eq!(
+            recording_overrides_to_stage(&german_ptt, KeyPhase::Released),
+            None
+        );
+        let cancel = binding("escape-cancel", ShortcutAction::CancelRecording, "de");
+        assert_eq!(
+            recording_overrides_to_stage(&cancel, KeyPhase::Pressed),
+            None
+        );
+    }
+}
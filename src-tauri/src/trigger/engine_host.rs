@@ -4,7 +4,9 @@ use keytrigger::KeyPhase;
 use tauri::{AppHandle, Manager};
 use tauri_plugin_store::StoreExt;
 
-use crate::commands::shortcuts::{ShortcutAction, ShortcutBinding, ShortcutTrigger, TriggerKind};
+use crate::commands::shortcuts::{
+    RecordingOverrides, ShortcutAction, ShortcutBinding, ShortcutTrigger, TriggerKind,
+};
 use crate::state::app_state::AppState;
 use crate::{RecordingMode, RecordingState};
 
@@ -33,6 +35,7 @@ pub fn apply_engine_bindings(app: &AppHandle, bindings: &[ShortcutBinding]) {
                     id: binding.id.clone(),
                     action: binding.action,
                     trigger: binding.trigger,
+                    overrides: binding.overrides.clone(),
                 });
                 triggers.push((binding.id.clone(), trigger));
             }
@@ -288,6 +291,7 @@ fn plan_engine_bindings(
             allow_risky_combo: false,
             trigger_kind: TriggerKind::Combo,
             modifier: None,
+            overrides: RecordingOverrides::default(),
         });
     }
 
@@ -302,6 +306,7 @@ fn plan_engine_bindings(
                 allow_risky_combo: false,
                 trigger_kind: TriggerKind::Combo,
                 modifier: None,
+                overrides: RecordingOverrides::default(),
             });
         } else {
             log::warn!(
@@ -320,6 +325,7 @@ fn plan_engine_bindings(
             allow_risky_combo: true,
             trigger_kind: TriggerKind::Combo,
             modifier: None,
+            overrides: RecordingOverrides::default(),
         });
     }
 
@@ -383,8 +389,8 @@ pub fn start_engine(app: &AppHandle) {
 mod tests {
     use super::{bindings_needing_release, plan_engine_bindings, stale_primary_candidate};
     use crate::commands::shortcuts::{
-        ModifierKind, ModifierSpec, ShortcutAction, ShortcutBinding, ShortcutTrigger, SideKind,
-        TriggerKind,
+        ModifierKind, ModifierSpec, RecordingOverrides, ShortcutAction, ShortcutBinding,
+        ShortcutTrigger, SideKind, TriggerKind,
     };
     use crate::trigger::EngineBinding;
     use crate::RecordingMode;
@@ -394,6 +400,7 @@ mod tests {
             id: id.to_string(),
             action,
             trigger,
+            overrides: RecordingOverrides::default(),
         }
     }
 
@@ -496,6 +503,7 @@ mod tests {
                 modifier: ModifierKind::Alt,
                 side: SideKind::Right,
             }),
+            overrides: RecordingOverrides::default(),
         }
     }
 
@@ -545,6 +553,7 @@ mod tests {
                 modifier: ModifierKind::Alt,
                 side: SideKind::Right,
             }),
+            overrides: RecordingOverrides::default(),
         }
     }
 
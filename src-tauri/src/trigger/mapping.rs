@@ -323,8 +323,8 @@ fn numpad_named(token: &str) -> Option<NamedKey> {
 mod tests {
     use super::{has_recording_engine_binding, is_engine_kind, parse_combo, to_trigger, validate};
     use crate::commands::shortcuts::{
-        ModifierKind, ModifierSpec, ShortcutAction, ShortcutBinding, ShortcutTrigger, SideKind,
-        TriggerKind,
+        ModifierKind, ModifierSpec, RecordingOverrides, ShortcutAction, ShortcutBinding,
+        ShortcutTrigger, SideKind, TriggerKind,
     };
     use keytrigger::{KeySpec, ModSet, Modifier, NamedKey, Side, Trigger};
 
@@ -341,6 +341,7 @@ mod tests {
                 modifier: ModifierKind::Alt,
                 side: SideKind::Right,
             }),
+            overrides: RecordingOverrides::default(),
         }
     }
 
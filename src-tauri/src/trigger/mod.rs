@@ -7,7 +7,7 @@ pub mod dispatch;
 pub mod engine_host;
 pub mod mapping;
 
-use crate::commands::shortcuts::{ShortcutAction, ShortcutTrigger};
+use crate::commands::shortcuts::{RecordingOverrides, ShortcutAction, ShortcutTrigger};
 
 /// An engine-routed binding: the engine emits `TriggerEvent { id }` and we look
 /// up the action/trigger here to run the shared dispatch path.
@@ -16,4 +16,5 @@ pub struct EngineBinding {
     pub id: String,
     pub action: ShortcutAction,
     pub trigger: ShortcutTrigger,
+    pub overrides: RecordingOverrides,
 }
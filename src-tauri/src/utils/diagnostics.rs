@@ -4,11 +4,17 @@
 /// into a cohesive interface that provides comprehensive debugging capabilities while
 /// maintaining high performance and thread safety.
 use crate::utils::logger::*;
+use crate::whisper::manager::WhisperManager;
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::time::Instant;
-use sysinfo::System;
+use sysinfo::{Disks, System};
+use tauri::async_runtime::RwLock;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
 
 /// Global system instance (thread-safe singleton)
 static SYSTEM: Lazy<Mutex<System>> = Lazy::new(|| {
@@ -113,6 +119,245 @@ pub mod system {
     }
 }
 
+/// Free bytes on the volume that holds `path`. The path need not exist yet;
+/// its nearest existing ancestor decides the volume.
+pub fn available_disk_space(path: &Path) -> Result<u64, String> {
+    let existing = path
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .ok_or_else(|| format!("No existing directory for {:?}", path))?;
+    let resolved = canonical_volume_path(existing)?;
+
+    let disks = Disks::new_with_refreshed_list();
+    available_space_for_path(
+        disks
+            .list()
+            .iter()
+            .map(|disk| (disk.mount_point(), disk.available_space())),
+        &resolved,
+    )
+    .ok_or_else(|| format!("No mounted volume found for {:?}", resolved))
+}
+
+/// Available space of the mount point that most specifically contains `path`.
+pub fn available_space_for_path<'a>(
+    mounts: impl IntoIterator<Item = (&'a Path, u64)>,
+    path: &Path,
+) -> Option<u64> {
+    mounts
+        .into_iter()
+        .filter(|(mount_point, _)| path.starts_with(mount_point))
+        .max_by_key(|(mount_point, _)| mount_point.components().count())
+        .map(|(_, available)| available)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn canonical_volume_path(path: &Path) -> Result<PathBuf, String> {
+    path.canonicalize().map_err(|e| e.to_string())
+}
+
+/// Windows canonical paths carry a `\\?\` prefix that drive mount points
+/// such as `C:\` lack, so strip it before matching.
+#[cfg(target_os = "windows")]
+fn canonical_volume_path(path: &Path) -> Result<PathBuf, String> {
+    let canonical = path.canonicalize().map_err(|e| e.to_string())?;
+    let text = canonical.to_string_lossy();
+    Ok(match text.strip_prefix(r"\\?\") {
+        Some(stripped) => PathBuf::from(stripped),
+        None => canonical,
+    })
+}
+
+/// Placeholder written over secret values in a diagnostics bundle.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Largest slice of each log file copied into a diagnostics bundle.
+const BUNDLE_MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Setting names that hold secrets without being secure-store entries,
+/// e.g. `sharing_password`.
+const SECRET_SETTING_MARKERS: [&str; 5] = ["api_key", "password", "secret", "token", "license"];
+
+/// One file inside a diagnostics bundle.
+pub struct BundleEntry {
+    pub name: String,
+    pub contents: Vec<u8>,
+}
+
+impl BundleEntry {
+    fn json(name: &str, value: &serde_json::Value) -> Self {
+        Self {
+            name: name.to_string(),
+            contents: serde_json::to_vec_pretty(value).unwrap_or_default(),
+        }
+    }
+}
+
+/// Where the panic hook writes the last crash report.
+pub fn crash_log_path() -> Option<PathBuf> {
+    std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()
+        .map(|home| Path::new(&home).join(".voicetypr_crash.log"))
+}
+
+/// Whether a setting named `name` holds a secret: it names (or embeds) a
+/// secure-store key, or looks like a credential field.
+pub fn is_secret_setting(name: &str, secure_key_names: &[String]) -> bool {
+    let lowered = name.to_lowercase();
+    secure_key_names
+        .iter()
+        .any(|key| !key.is_empty() && lowered.contains(&key.to_lowercase()))
+        || SECRET_SETTING_MARKERS
+            .iter()
+            .any(|marker| lowered.contains(marker))
+}
+
+/// Replace every secret value in `settings` with [`REDACTED`], descending into
+/// nested objects and arrays. Nulls stay null so "not set" remains visible.
+pub fn redact_settings(settings: &mut serde_json::Value, secure_key_names: &[String]) {
+    match settings {
+        serde_json::Value::Object(map) => {
+            for (name, value) in map.iter_mut() {
+                if is_secret_setting(name, secure_key_names) {
+                    if !value.is_null() {
+                        *value = serde_json::Value::String(REDACTED.to_string());
+                    }
+                } else {
+                    redact_settings(value, secure_key_names);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                redact_settings(item, secure_key_names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Gather logs, redacted settings, downloaded models, OS details and the last
+/// crash report for a support bundle. Sources that cannot be read are skipped.
+pub async fn collect_diagnostics_bundle(app: &AppHandle) -> Vec<BundleEntry> {
+    let mut entries = vec![BundleEntry::json("system.json", &system_info(app))];
+
+    let secure_key_names = crate::secure_store::secure_list_keys(app).unwrap_or_default();
+    match app.store("settings") {
+        Ok(store) => {
+            let mut settings = serde_json::Value::Object(store.entries().into_iter().collect());
+            redact_settings(&mut settings, &secure_key_names);
+            entries.push(BundleEntry::json("settings.json", &settings));
+        }
+        Err(e) => log::warn!("Diagnostics bundle skipped settings: {}", e),
+    }
+
+    entries.push(BundleEntry::json(
+        "models.json",
+        &downloaded_models(app).await,
+    ));
+
+    match app.path().app_log_dir() {
+        Ok(log_dir) => entries.extend(log_entries(&log_dir)),
+        Err(e) => log::warn!("Diagnostics bundle skipped logs: {}", e),
+    }
+
+    if let Some(crash_path) = crash_log_path() {
+        if let Ok(crash) = std::fs::read_to_string(&crash_path) {
+            entries.push(BundleEntry {
+                name: "crash.log".to_string(),
+                contents: crate::commands::logs::redact_log_content(&crash).into_bytes(),
+            });
+        }
+    }
+
+    entries
+}
+
+fn system_info(app: &AppHandle) -> serde_json::Value {
+    serde_json::json!({
+        "app_version": app.package_info().version.to_string(),
+        "platform": tauri_plugin_os::platform(),
+        "os_type": tauri_plugin_os::type_().to_string(),
+        "os_version": tauri_plugin_os::version().to_string(),
+        "family": tauri_plugin_os::family(),
+        "arch": tauri_plugin_os::arch(),
+        "locale": tauri_plugin_os::locale(),
+        "generated_at": chrono::Local::now().to_rfc3339(),
+    })
+}
+
+async fn downloaded_models(app: &AppHandle) -> serde_json::Value {
+    let whisper = match app.try_state::<RwLock<WhisperManager>>() {
+        Some(state) => state.read().await.list_downloaded_files(),
+        None => Vec::new(),
+    };
+    let parakeet: Vec<String> = match app.try_state::<crate::parakeet::ParakeetManager>() {
+        Some(manager) => manager
+            .list_models()
+            .into_iter()
+            .filter(|model| model.downloaded)
+            .map(|model| model.name)
+            .collect(),
+        None => Vec::new(),
+    };
+    serde_json::json!({ "whisper": whisper, "parakeet": parakeet })
+}
+
+/// Redacted tails of the regular files in `log_dir`, under `logs/`.
+fn log_entries(log_dir: &Path) -> Vec<BundleEntry> {
+    let Ok(dir) = std::fs::read_dir(log_dir) else {
+        return Vec::new();
+    };
+    let mut entries = Vec::new();
+    for entry in dir.flatten() {
+        // Symlinks are skipped so the bundle cannot pull files from outside app_log_dir.
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_symlink() || !file_type.is_file() {
+            continue;
+        }
+        let path = entry.path();
+        match crate::commands::logs::read_log_tail(&path, BUNDLE_MAX_LOG_BYTES) {
+            Ok((content, _, _)) => entries.push(BundleEntry {
+                name: format!("logs/{}", entry.file_name().to_string_lossy()),
+                contents: crate::commands::logs::redact_log_content(&content).into_bytes(),
+            }),
+            Err(e) => log::warn!("Diagnostics bundle skipped {:?}: {}", path, e),
+        }
+    }
+    entries.sort_by(|left, right| left.name.cmp(&right.name));
+    entries
+}
+
+/// Write `entries` into a zip at `destination`, adding a `.zip` extension
+/// when the path has none. Returns the written path.
+pub fn write_diagnostics_zip(
+    destination: &Path,
+    entries: &[BundleEntry],
+) -> Result<PathBuf, String> {
+    let path = if destination.extension().is_some() {
+        destination.to_path_buf()
+    } else {
+        destination.with_extension("zip")
+    };
+    let file = std::fs::File::create(&path)
+        .map_err(|e| format!("Failed to create diagnostics bundle: {}", e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options =
+        zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    for entry in entries {
+        zip.start_file(entry.name.as_str(), options)
+            .map_err(|e| format!("Failed to write {}: {}", entry.name, e))?;
+        zip.write_all(&entry.contents)
+            .map_err(|e| format!("Failed to write {}: {}", entry.name, e))?;
+    }
+    zip.finish()
+        .map_err(|e| format!("Failed to finish diagnostics bundle: {}", e))?;
+    Ok(path)
+}
+
 // Network diagnostics moved to network_diagnostics.rs module
 // Import from there when needed: use crate::utils::network_diagnostics::*;
 
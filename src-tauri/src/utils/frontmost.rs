@@ -0,0 +1,103 @@
+//! Identify the application in front when a recording starts, so per-app
+//! profiles can override the model and language for that dictation.
+
+/// Bundle id of the frontmost app on macOS (e.g. `com.microsoft.VSCode`) or the
+/// executable name of the foreground window's process on Windows (e.g.
+/// `Code.exe`). Blocking; returns `None` when it cannot be determined.
+pub fn detect_frontmost_app() -> Option<String> {
+    #[cfg(target_os = "macos")]
+    {
+        detect_frontmost_app_macos()
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        detect_frontmost_app_windows()
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        None
+    }
+}
+
+/// Uses `lsappinfo`, which needs no Automation permission, unlike asking
+/// System Events through osascript. `front` names the frontmost app directly,
+/// so one process answers the query.
+#[cfg(target_os = "macos")]
+fn detect_frontmost_app_macos() -> Option<String> {
+    let output = std::process::Command::new("/usr/bin/lsappinfo")
+        .args(["info", "-only", "bundleid", "front"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_lsappinfo_bundle_id(String::from_utf8_lossy(&output.stdout).trim())
+}
+
+/// Parse `"CFBundleIdentifier"="com.example.App"` from `lsappinfo info`.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_lsappinfo_bundle_id(output: &str) -> Option<String> {
+    let (_, value) = output.split_once('=')?;
+    let value = value.trim().trim_matches('"');
+    (!value.is_empty() && value != "[ NULL ]").then(|| value.to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn detect_frontmost_app_windows() -> Option<String> {
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.is_invalid() {
+            return None;
+        }
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == 0 {
+            return None;
+        }
+
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let mut buffer = [0u16; 1024];
+        let mut len = buffer.len() as u32;
+        let queried = QueryFullProcessImageNameW(
+            process,
+            PROCESS_NAME_WIN32,
+            PWSTR(buffer.as_mut_ptr()),
+            &mut len,
+        );
+        let _ = CloseHandle(process);
+        queried.ok()?;
+
+        let path = String::from_utf16_lossy(&buffer[..len as usize]);
+        std::path::Path::new(&path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_lsappinfo_bundle_id;
+
+    #[test]
+    fn parses_bundle_id_from_lsappinfo_output() {
+        assert_eq!(
+            parse_lsappinfo_bundle_id("\"CFBundleIdentifier\"=\"com.microsoft.VSCode\""),
+            Some("com.microsoft.VSCode".to_string())
+        );
+        assert_eq!(
+            parse_lsappinfo_bundle_id("\"CFBundleIdentifier\"=[ NULL ]"),
+            None
+        );
+        assert_eq!(parse_lsappinfo_bundle_id(""), None);
+    }
+}
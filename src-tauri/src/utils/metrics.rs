@@ -0,0 +1,205 @@
+//! Local transcription latency metrics. A rolling window of the most recent
+//! transcriptions is kept in its own store and summarized per model on
+//! request. Nothing here is reported anywhere.
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+const METRICS_STORE: &str = "metrics";
+const SAMPLES_KEY: &str = "transcriptions";
+
+/// Number of recent transcriptions kept; older samples are dropped first.
+pub const MAX_SAMPLES: usize = 200;
+
+/// One finished transcription.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionSample {
+    pub engine: String,
+    pub model: String,
+    /// Wall time spent transcribing, in milliseconds.
+    pub duration_ms: u64,
+    /// Length of the recording, when it could be read.
+    #[serde(default)]
+    pub audio_duration_ms: Option<u64>,
+    pub char_count: usize,
+    pub recorded_at: String,
+}
+
+impl TranscriptionSample {
+    pub fn new(
+        engine: impl Into<String>,
+        model: impl Into<String>,
+        duration_ms: u64,
+        audio_duration_ms: Option<u64>,
+        text: &str,
+    ) -> Self {
+        Self {
+            engine: engine.into(),
+            model: model.into(),
+            duration_ms,
+            audio_duration_ms,
+            char_count: text.chars().count(),
+            recorded_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Latency summary for one engine/model pair.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModelMetrics {
+    pub engine: String,
+    pub model: String,
+    pub count: usize,
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+    /// Transcription time divided by audio time over the samples with a known
+    /// audio length; below 1.0 is faster than real time.
+    pub real_time_factor: Option<f64>,
+    pub avg_char_count: f64,
+}
+
+/// Append `sample`, dropping the oldest samples beyond [`MAX_SAMPLES`].
+fn push_sample(samples: &mut Vec<TranscriptionSample>, sample: TranscriptionSample) {
+    samples.push(sample);
+    if samples.len() > MAX_SAMPLES {
+        let excess = samples.len() - MAX_SAMPLES;
+        samples.drain(..excess);
+    }
+}
+
+/// Nearest-rank percentile of an ascending, non-empty slice.
+fn percentile(sorted: &[u64], pct: f64) -> u64 {
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Summarize samples per engine/model, busiest model first.
+pub fn aggregate(samples: &[TranscriptionSample]) -> Vec<ModelMetrics> {
+    let mut groups: Vec<((&str, &str), Vec<&TranscriptionSample>)> = Vec::new();
+    for sample in samples {
+        let key = (sample.engine.as_str(), sample.model.as_str());
+        match groups.iter_mut().find(|(group_key, _)| *group_key == key) {
+            Some((_, group)) => group.push(sample),
+            None => groups.push((key, vec![sample])),
+        }
+    }
+
+    let mut metrics: Vec<ModelMetrics> = groups
+        .into_iter()
+        .map(|((engine, model), group)| {
+            let mut durations: Vec<u64> = group.iter().map(|s| s.duration_ms).collect();
+            durations.sort_unstable();
+
+            let (processing_ms, audio_ms) = group
+                .iter()
+                .filter_map(|s| {
+                    s.audio_duration_ms
+                        .filter(|ms| *ms > 0)
+                        .map(|a| (s.duration_ms, a))
+                })
+                .fold((0u64, 0u64), |(p, a), (dp, da)| (p + dp, a + da));
+
+            ModelMetrics {
+                engine: engine.to_string(),
+                model: model.to_string(),
+                count: group.len(),
+                p50_ms: percentile(&durations, 50.0),
+                p95_ms: percentile(&durations, 95.0),
+                real_time_factor: (audio_ms > 0).then(|| processing_ms as f64 / audio_ms as f64),
+                avg_char_count: group.iter().map(|s| s.char_count).sum::<usize>() as f64
+                    / group.len() as f64,
+            }
+        })
+        .collect();
+    metrics.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.model.cmp(&b.model)));
+    metrics
+}
+
+pub fn load_samples(app: &AppHandle) -> Result<Vec<TranscriptionSample>, String> {
+    let store = app
+        .store(METRICS_STORE)
+        .map_err(|e| format!("Failed to access metrics store: {}", e))?;
+    Ok(store
+        .get(SAMPLES_KEY)
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default())
+}
+
+/// Add a finished transcription to the rolling window. Failures are logged
+/// and otherwise ignored; metrics must never get in the way of a dictation.
+pub fn record_transcription(app: &AppHandle, sample: TranscriptionSample) {
+    let result = load_samples(app).and_then(|mut samples| {
+        push_sample(&mut samples, sample);
+        let store = app
+            .store(METRICS_STORE)
+            .map_err(|e| format!("Failed to access metrics store: {}", e))?;
+        store.set(
+            SAMPLES_KEY,
+            serde_json::to_value(&samples).map_err(|e| e.to_string())?,
+        );
+        store.save().map_err(|e| e.to_string())
+    });
+    if let Err(e) = result {
+        log::warn!("Failed to record transcription metrics: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(
+        model: &str,
+        duration_ms: u64,
+        audio_duration_ms: Option<u64>,
+    ) -> TranscriptionSample {
+        TranscriptionSample::new("whisper", model, duration_ms, audio_duration_ms, "hello")
+    }
+
+    #[test]
+    fn push_sample_keeps_the_most_recent_window() {
+        let mut samples = Vec::new();
+        for i in 0..(MAX_SAMPLES as u64 + 5) {
+            push_sample(&mut samples, sample("base.en", i, None));
+        }
+        assert_eq!(samples.len(), MAX_SAMPLES);
+        assert_eq!(samples[0].duration_ms, 5);
+        assert_eq!(samples.last().unwrap().duration_ms, MAX_SAMPLES as u64 + 4);
+    }
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let sorted: Vec<u64> = (1..=20).collect();
+        assert_eq!(percentile(&sorted, 50.0), 10);
+        assert_eq!(percentile(&sorted, 95.0), 19);
+        assert_eq!(percentile(&[7], 95.0), 7);
+    }
+
+    #[test]
+    fn aggregate_groups_by_model_with_latency_and_real_time_factor() {
+        let samples = vec![
+            sample("base.en", 400, Some(2_000)),
+            sample("large-v3", 3_000, Some(2_000)),
+            sample("base.en", 200, Some(2_000)),
+            sample("base.en", 600, None),
+        ];
+
+        let metrics = aggregate(&samples);
+
+        assert_eq!(metrics.len(), 2);
+        let base = &metrics[0];
+        assert_eq!((base.model.as_str(), base.count), ("base.en", 3));
+        assert_eq!((base.p50_ms, base.p95_ms), (400, 600));
+        assert_eq!(base.real_time_factor, Some(0.15));
+        assert_eq!(base.avg_char_count, 5.0);
+        let large = &metrics[1];
+        assert_eq!(large.real_time_factor, Some(1.5));
+    }
+
+    #[test]
+    fn real_time_factor_is_none_without_audio_lengths() {
+        let metrics = aggregate(&[sample("soniox", 900, None)]);
+        assert_eq!(metrics[0].real_time_factor, None);
+    }
+}
@@ -1,8 +1,11 @@
 // Utility modules
 pub mod diagnostics;
 pub mod display_watcher;
+pub mod frontmost;
 pub mod logger;
+pub mod metrics;
 pub mod monitor;
 pub mod network_diagnostics;
 pub mod onboarding_logger;
 pub mod system_monitor;
+pub mod theme_watcher;
@@ -17,6 +17,22 @@ pub enum NetworkError {
 
 // NetworkStatus conversion removed - using simple logging functions instead
 
+/// Whether a TCP connection to the URL's host opens within `timeout`. A cheap
+/// reachability probe for choosing between cloud and local engines; no TLS
+/// handshake or request is made.
+pub async fn url_is_reachable(url: &str, timeout: std::time::Duration) -> bool {
+    let Ok(parsed) = reqwest::Url::parse(url) else {
+        return false;
+    };
+    let (Some(host), Some(port)) = (parsed.host_str(), parsed.port_or_known_default()) else {
+        return false;
+    };
+    matches!(
+        tokio::time::timeout(timeout, tokio::net::TcpStream::connect((host, port))).await,
+        Ok(Ok(_))
+    )
+}
+
 /// Log AI API request with full context (stateless)
 pub fn log_api_request(provider: &str, model: &str, token_count: usize) {
     log::info!("🌐 API_REQUEST_START:");
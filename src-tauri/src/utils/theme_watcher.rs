@@ -0,0 +1,198 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tauri::AppHandle;
+
+/// Event sent to the pill window with the new theme (`"dark"` or `"light"`).
+pub const THEME_CHANGED_EVENT: &str = "theme-changed";
+
+/// The OS appearance: `"dark"` or `"light"`. Platforms without a readable
+/// setting report `"light"`.
+pub fn current_system_theme() -> &'static str {
+    if system_prefers_dark() {
+        "dark"
+    } else {
+        "light"
+    }
+}
+
+/// Script run before the pill loads so its first paint already uses `theme`.
+pub fn pill_theme_init_script(theme: &str) -> String {
+    format!("window.__SYSTEM_THEME__ = {:?};", theme)
+}
+
+// `AppleInterfaceStyle` only exists (as "Dark") while dark mode is on
+#[cfg(target_os = "macos")]
+fn system_prefers_dark() -> bool {
+    std::process::Command::new("defaults")
+        .args(["read", "-g", "AppleInterfaceStyle"])
+        .output()
+        .map(|output| {
+            output.status.success()
+                && String::from_utf8_lossy(&output.stdout)
+                    .trim()
+                    .eq_ignore_ascii_case("dark")
+        })
+        .unwrap_or(false)
+}
+
+// The app theme (AppsUseLightTheme), not the taskbar theme the tray icon follows
+#[cfg(target_os = "windows")]
+fn system_prefers_dark() -> bool {
+    use winreg::enums::HKEY_CURRENT_USER;
+    use winreg::RegKey;
+    RegKey::predef(HKEY_CURRENT_USER)
+        .open_subkey(r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize")
+        .and_then(|k| k.get_value::<u32, _>("AppsUseLightTheme"))
+        .map(|v| v == 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn system_prefers_dark() -> bool {
+    false
+}
+
+fn emit_theme(app: &AppHandle, theme: &'static str) {
+    log::info!("System theme changed to {}", theme);
+    if let Err(e) = crate::emit_to_window(app, "pill", THEME_CHANGED_EVENT, theme) {
+        // The pill may not exist yet; it picks the theme up when it is created
+        log::debug!("Failed to emit {}: {}", THEME_CHANGED_EVENT, e);
+    }
+}
+
+/// Watches for OS dark/light appearance changes and tells the pill to restyle.
+pub struct ThemeWatcher {
+    started: Arc<AtomicBool>,
+    app: AppHandle,
+}
+
+impl ThemeWatcher {
+    pub fn new(app: AppHandle) -> Self {
+        Self {
+            started: Arc::new(AtomicBool::new(false)),
+            app,
+        }
+    }
+
+    /// Start watching for appearance changes.
+    /// On macOS, observes the distributed `AppleInterfaceThemeChangedNotification`.
+    pub fn start(&self) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            log::debug!("ThemeWatcher already running, skipping start");
+            return;
+        }
+
+        log::info!("Starting ThemeWatcher for system appearance changes");
+
+        #[cfg(target_os = "macos")]
+        self.start_macos();
+
+        #[cfg(target_os = "windows")]
+        self.start_windows();
+    }
+
+    #[cfg(target_os = "macos")]
+    fn start_macos(&self) {
+        use std::ffi::{c_char, c_void};
+
+        #[link(name = "CoreFoundation", kind = "framework")]
+        extern "C" {
+            fn CFNotificationCenterGetDistributedCenter() -> *const c_void;
+            fn CFNotificationCenterAddObserver(
+                center: *const c_void,
+                observer: *const c_void,
+                callback: unsafe extern "C" fn(
+                    *const c_void,
+                    *mut c_void,
+                    *const c_void,
+                    *const c_void,
+                    *const c_void,
+                ),
+                name: *const c_void,
+                object: *const c_void,
+                suspension_behavior: isize,
+            );
+            fn CFStringCreateWithCString(
+                alloc: *const c_void,
+                c_str: *const c_char,
+                encoding: u32,
+            ) -> *const c_void;
+        }
+
+        const CF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+        // CFNotificationSuspensionBehaviorDeliverImmediately
+        const DELIVER_IMMEDIATELY: isize = 4;
+
+        // Posted after the appearance flips, so reading it back is current
+        unsafe extern "C" fn theme_callback(
+            _center: *const c_void,
+            observer: *mut c_void,
+            _name: *const c_void,
+            _object: *const c_void,
+            _user_info: *const c_void,
+        ) {
+            let app_ptr = observer as *const AppHandle;
+            if !app_ptr.is_null() {
+                let app = &*app_ptr;
+                emit_theme(app, current_system_theme());
+            }
+        }
+
+        // Leak the app handle so it lives for the lifetime of the app
+        let app_box = Box::new(self.app.clone());
+        let app_ptr = Box::into_raw(app_box) as *const c_void;
+
+        unsafe {
+            let name = CFStringCreateWithCString(
+                std::ptr::null(),
+                b"AppleInterfaceThemeChangedNotification\0".as_ptr() as *const c_char,
+                CF_STRING_ENCODING_UTF8,
+            );
+            if name.is_null() {
+                log::error!("Failed to create appearance notification name");
+                return;
+            }
+            CFNotificationCenterAddObserver(
+                CFNotificationCenterGetDistributedCenter(),
+                app_ptr,
+                theme_callback,
+                name,
+                std::ptr::null(),
+                DELIVER_IMMEDIATELY,
+            );
+        }
+        log::info!("Registered system appearance observer");
+    }
+
+    #[cfg(target_os = "windows")]
+    fn start_windows(&self) {
+        // Polled like DisplayWatcher: the UISettings ColorValuesChanged event
+        // needs a WinRT apartment, while the registry value is cheap to read
+        use std::thread;
+        use std::time::Duration;
+
+        let app = self.app.clone();
+        let started = self.started.clone();
+
+        thread::spawn(move || {
+            let mut last_theme = current_system_theme();
+
+            while started.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_secs(2));
+
+                let theme = current_system_theme();
+                if theme != last_theme {
+                    emit_theme(&app, theme);
+                    last_theme = theme;
+                }
+            }
+        });
+    }
+}
+
+impl Drop for ThemeWatcher {
+    fn drop(&mut self) {
+        self.started.store(false, Ordering::Relaxed);
+        log::debug!("ThemeWatcher stopped");
+    }
+}
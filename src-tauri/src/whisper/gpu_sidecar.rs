@@ -476,6 +476,8 @@ impl GpuSidecarClient {
                     segments: sidecar_segments_to_transcription_segments(segments),
                     audio_duration_ms,
                     processing_duration_ms,
+                    words: Vec::new(),
+                    avg_confidence: None,
                 })
             }
             Ok(SidecarResponse::Error { code, message, .. }) => {
@@ -428,7 +428,6 @@ pub fn is_language_supported(code: &str) -> bool {
 }
 
 /// Get the language name for a given code
-#[cfg(test)]
 pub fn get_language_name(code: &str) -> Option<&'static str> {
     SUPPORTED_LANGUAGES.get(code).map(|lang| lang.name)
 }
@@ -456,6 +455,19 @@ pub fn validate_language(code: Option<&str>) -> &'static str {
     }
 }
 
+/// Lowest detection probability trusted over the configured language.
+pub const DETECTION_MIN_CONFIDENCE: f32 = 0.5;
+
+/// Language to transcribe with after auto-detection: the detected code when
+/// Whisper is confident and supports it, otherwise the configured `fallback`.
+pub fn resolve_detected_language(detected: &str, confidence: f32, fallback: &str) -> String {
+    if confidence >= DETECTION_MIN_CONFIDENCE && is_language_supported(detected) {
+        detected.to_string()
+    } else {
+        validate_language(Some(fallback)).to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -483,4 +495,15 @@ mod tests {
         assert_eq!(get_language_name("zh"), Some("Chinese"));
         assert_eq!(get_language_name("invalid"), None);
     }
+
+    #[test]
+    fn test_resolve_detected_language_falls_back_below_threshold() {
+        assert_eq!(resolve_detected_language("de", 0.9, "en"), "de");
+        assert_eq!(resolve_detected_language("de", 0.3, "fr"), "fr");
+        assert_eq!(
+            resolve_detected_language("de", DETECTION_MIN_CONFIDENCE, "en"),
+            "de"
+        );
+        assert_eq!(resolve_detected_language("xyz", 0.99, "es"), "es");
+    }
 }
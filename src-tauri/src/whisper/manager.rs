@@ -3,7 +3,7 @@ use reqwest;
 use sha1::Sha1;
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::fs;
@@ -61,6 +61,67 @@ impl ModelInfo {
     pub fn validated_size(&self) -> Result<ModelSize, String> {
         ModelSize::new(self.size)
     }
+
+    /// Imported models have no download source.
+    pub fn is_custom(&self) -> bool {
+        self.name.starts_with(CUSTOM_MODEL_PREFIX)
+    }
+}
+
+/// Registry names of models imported from disk start with this, so they can
+/// never shadow a catalog model.
+pub const CUSTOM_MODEL_PREFIX: &str = "custom-";
+
+/// Sidecar file in the models directory that remembers imported models.
+const CUSTOM_MODELS_FILE: &str = "custom-models.json";
+
+/// `0x67676d6c` ("ggml") as written little-endian by whisper.cpp's converter.
+const GGML_MAGIC: u32 = 0x6767_6d6c;
+
+/// Persisted metadata for a model imported from disk.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct CustomModelEntry {
+    name: String,
+    display_name: String,
+    size: u64,
+}
+
+/// Check that `path` starts with a whisper.cpp ggml header: the magic, then
+/// vocabulary and mel-band counts that a Whisper model can actually have.
+pub fn verify_ggml_whisper_header(path: &Path) -> Result<(), String> {
+    use std::io::Read;
+
+    let mut file =
+        std::fs::File::open(path).map_err(|e| format!("Cannot open model file: {}", e))?;
+    // magic + the eleven i32 hyperparameters (n_vocab .. ftype)
+    let mut header = [0u8; 48];
+    file.read_exact(&mut header)
+        .map_err(|_| "Not a Whisper ggml model: file is too short".to_string())?;
+
+    let field = |index: usize| {
+        let start = index * 4;
+        u32::from_le_bytes([
+            header[start],
+            header[start + 1],
+            header[start + 2],
+            header[start + 3],
+        ])
+    };
+    if field(0) != GGML_MAGIC {
+        return Err(
+            "Not a Whisper ggml model: missing ggml magic bytes (GGUF and PyTorch checkpoints must be converted first)"
+                .to_string(),
+        );
+    }
+    let n_vocab = field(1);
+    let n_mels = field(10);
+    if !(50_000..=100_000).contains(&n_vocab) || !matches!(n_mels, 80 | 128) {
+        return Err(format!(
+            "Not a Whisper ggml model: unexpected header (n_vocab={}, n_mels={})",
+            n_vocab, n_mels
+        ));
+    }
+    Ok(())
 }
 
 pub struct WhisperManager {
@@ -169,6 +230,7 @@ impl WhisperManager {
         // Removed: large-v3-turbo-q8_0 to simplify model list
 
         let mut manager = Self { models_dir, models };
+        manager.load_custom_models();
         manager.check_downloaded_models();
         manager
     }
@@ -215,6 +277,142 @@ impl WhisperManager {
         self.models_dir.clone()
     }
 
+    fn custom_model_info(entry: CustomModelEntry) -> ModelInfo {
+        ModelInfo {
+            name: entry.name,
+            display_name: entry.display_name,
+            size: entry.size,
+            url: String::new(),
+            sha256: String::new(),
+            downloaded: false,
+            speed_score: 5,
+            accuracy_score: 5,
+            recommended: false,
+        }
+    }
+
+    /// Register imported models recorded in the sidecar file.
+    fn load_custom_models(&mut self) {
+        let path = self.models_dir.join(CUSTOM_MODELS_FILE);
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            return;
+        };
+        let entries: Vec<CustomModelEntry> = match serde_json::from_str(&contents) {
+            Ok(entries) => entries,
+            Err(e) => {
+                log::warn!("Ignoring unreadable {}: {}", CUSTOM_MODELS_FILE, e);
+                return;
+            }
+        };
+        for entry in entries {
+            if entry.name.starts_with(CUSTOM_MODEL_PREFIX) {
+                self.models
+                    .insert(entry.name.clone(), Self::custom_model_info(entry));
+            }
+        }
+    }
+
+    fn save_custom_models(&self) -> Result<(), String> {
+        let mut entries: Vec<CustomModelEntry> = self
+            .models
+            .values()
+            .filter(|info| info.is_custom())
+            .map(|info| CustomModelEntry {
+                name: info.name.clone(),
+                display_name: info.display_name.clone(),
+                size: info.size,
+            })
+            .collect();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        let json = serde_json::to_string_pretty(&entries).map_err(|e| e.to_string())?;
+        std::fs::write(self.models_dir.join(CUSTOM_MODELS_FILE), json)
+            .map_err(|e| format!("Failed to save imported model list: {}", e))
+    }
+
+    /// Registry name for an imported model: a slug of the display name,
+    /// suffixed with a counter when it is already taken.
+    fn unique_custom_model_name(&self, display_name: &str) -> String {
+        let mut slug = String::new();
+        for c in display_name.trim().chars() {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '_' {
+                slug.push(c.to_ascii_lowercase());
+            } else if !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+        let slug = slug.trim_matches(|c| c == '-' || c == '.');
+        let base = format!(
+            "{}{}",
+            CUSTOM_MODEL_PREFIX,
+            if slug.is_empty() { "model" } else { slug }
+        );
+
+        let taken = |name: &str| {
+            self.models.contains_key(name) || self.models_dir.join(format!("{}.bin", name)).exists()
+        };
+        if !taken(&base) {
+            return base;
+        }
+        (2..)
+            .map(|n| format!("{}-{}", base, n))
+            .find(|name| !taken(name))
+            .expect("unbounded suffix search always finds a free name")
+    }
+
+    /// Move an already-copied model file into the models directory and register
+    /// it under a collision-free name. The file must pass
+    /// [`verify_ggml_whisper_header`] and the usual model size bounds.
+    pub fn register_custom_model(
+        &mut self,
+        staged_path: &Path,
+        display_name: &str,
+    ) -> Result<ModelInfo, String> {
+        let display_name = display_name.trim();
+        if display_name.is_empty() {
+            return Err("Model name cannot be empty".to_string());
+        }
+        verify_ggml_whisper_header(staged_path)?;
+        let size = std::fs::metadata(staged_path)
+            .map_err(|e| format!("Cannot read model file metadata: {}", e))?
+            .len();
+        ModelSize::new(size)?;
+
+        let name = self.unique_custom_model_name(display_name);
+        // Two imports with the same display name stay distinguishable in the picker
+        let mut unique_display_name = display_name.to_string();
+        let mut counter = 2;
+        while self
+            .models
+            .values()
+            .any(|info| info.display_name == unique_display_name)
+        {
+            unique_display_name = format!("{} ({})", display_name, counter);
+            counter += 1;
+        }
+
+        std::fs::create_dir_all(&self.models_dir)
+            .map_err(|e| format!("Failed to create models directory: {}", e))?;
+        let destination = self.models_dir.join(format!("{}.bin", name));
+        std::fs::rename(staged_path, &destination)
+            .map_err(|e| format!("Failed to move model into place: {}", e))?;
+
+        let mut info = Self::custom_model_info(CustomModelEntry {
+            name: name.clone(),
+            display_name: unique_display_name,
+            size,
+        });
+        info.downloaded = true;
+        self.models.insert(name.clone(), info.clone());
+        if let Err(e) = self.save_custom_models() {
+            self.models.remove(&name);
+            let _ = std::fs::remove_file(&destination);
+            return Err(e);
+        }
+
+        log::info!("Imported custom Whisper model '{}' ({} bytes)", name, size);
+        Ok(info)
+    }
+
     /// Get model info needed for download (doesn't hold lock during download)
     pub fn get_model_info(&self, model_name: &str) -> Result<(ModelInfo, PathBuf), String> {
         // Use centralized validation
@@ -227,6 +425,13 @@ impl WhisperManager {
             model_name
         ))?;
 
+        if model.is_custom() {
+            return Err(format!(
+                "Model '{}' was imported from disk and cannot be downloaded",
+                model_name
+            ));
+        }
+
         // Validate model size before downloading
         let _ = model.validated_size()?;
 
@@ -235,13 +440,15 @@ impl WhisperManager {
         Ok((model.clone(), output_path))
     }
 
-    /// Download a model file (should be called without holding the manager lock)
+    /// Download a model file (should be called without holding the manager lock).
+    /// `progress_callback` receives `(downloaded, total, resumed_from)` in bytes,
+    /// where `resumed_from` is how much of a previous partial download was kept.
     pub async fn download_model_file(
         model_info: &ModelInfo,
         output_path: &PathBuf,
         models_dir: &PathBuf,
         cancel_flag: Option<Arc<AtomicBool>>,
-        progress_callback: impl Fn(u64, u64),
+        progress_callback: impl Fn(u64, u64, u64),
     ) -> Result<(), String> {
         log::info!("Downloading model {}", model_info.name);
 
@@ -292,15 +499,62 @@ impl WhisperManager {
             model_info.name
         );
 
-        // Download the model
+        // Bytes land in `<name>.bin.part` and are renamed into place only after
+        // the size and checksum check out, so an interrupted download can be
+        // resumed from where it stopped.
+        let partial_path = Self::partial_download_path(output_path);
+        let mut resume_from = match fs::metadata(&partial_path).await {
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        };
+        if resume_from > model_info.size {
+            log::warn!(
+                "Partial download for '{}' is larger than the model ({} bytes); starting over",
+                model_info.name,
+                resume_from
+            );
+            let _ = fs::remove_file(&partial_path).await;
+            resume_from = 0;
+        }
+
+        if resume_from > 0 && resume_from == model_info.size {
+            log::info!(
+                "Partial download for '{}' is already complete; verifying",
+                model_info.name
+            );
+            progress_callback(resume_from, resume_from, resume_from);
+            return Self::finish_partial_download(model_info, &partial_path, output_path).await;
+        }
+
+        // Download the model, continuing a partial file when the server
+        // honors the range request
         let client = reqwest::Client::new();
-        let response = client
-            .get(&model_info.url)
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+        let mut response = Self::request_model_bytes(&client, &model_info.url, resume_from).await?;
+        if resume_from > 0 && !Self::response_resumes_at(&response, resume_from) {
+            log::info!(
+                "Server did not honor the range request for '{}' (status {}); restarting from zero",
+                model_info.name,
+                response.status()
+            );
+            if response.status() != reqwest::StatusCode::OK {
+                response = Self::request_model_bytes(&client, &model_info.url, 0).await?;
+            }
+            resume_from = 0;
+        } else if resume_from > 0 {
+            log::info!(
+                "Resuming download for '{}' at {} bytes",
+                model_info.name,
+                resume_from
+            );
+        }
+        let response = response
+            .error_for_status()
+            .map_err(|e| format!("Model download failed: {}", e))?;
 
-        let total_size = response.content_length().unwrap_or(model_info.size);
+        let total_size = resume_from
+            + response
+                .content_length()
+                .unwrap_or(model_info.size - resume_from);
 
         // Validate reported size matches expected size (allow 10% variance for compression)
         let size_variance =
@@ -317,34 +571,49 @@ impl WhisperManager {
         // Validate the total size is within our limits
         let _ = ModelSize::new(total_size)?;
 
-        let mut file = fs::File::create(&output_path)
-            .await
-            .map_err(|e| e.to_string())?;
+        let mut file = if resume_from > 0 {
+            fs::OpenOptions::new()
+                .append(true)
+                .open(&partial_path)
+                .await
+                .map_err(|e| e.to_string())?
+        } else {
+            fs::File::create(&partial_path)
+                .await
+                .map_err(|e| e.to_string())?
+        };
 
-        let mut downloaded: u64 = 0;
+        let mut downloaded: u64 = resume_from;
         let mut stream = response.bytes_stream();
-        let mut last_progress_update = 0u64;
+        let mut last_progress_update = downloaded;
         let update_threshold = total_size / 100; // Update every 1%
+        if resume_from > 0 {
+            progress_callback(downloaded, total_size, resume_from);
+        }
 
         while let Some(chunk) = stream.next().await {
             // Check for cancellation
             if let Some(ref flag) = cancel_flag {
                 if flag.load(Ordering::Relaxed) {
-                    log::info!("Download cancelled by user for model: {}", model_info.name);
-                    // Clean up partial download
+                    // The partial file is kept so the next attempt resumes;
+                    // only deleting the model removes it.
+                    log::info!(
+                        "Download cancelled by user for model: {} ({} bytes kept for resume)",
+                        model_info.name,
+                        downloaded
+                    );
                     drop(file);
-                    let _ = fs::remove_file(&output_path).await;
                     return Err("Download cancelled by user".to_string());
                 }
             }
 
-            let chunk = chunk.map_err(|e| e.to_string())?;
+            let chunk = chunk.map_err(|e| format!("Download interrupted: {}", e))?;
 
             // Prevent downloading more than expected (with 1% tolerance)
             if downloaded + chunk.len() as u64 > (total_size as f64 * 1.01) as u64 {
                 // Clean up partial download
                 drop(file);
-                let _ = fs::remove_file(&output_path).await;
+                let _ = fs::remove_file(&partial_path).await;
 
                 return Err(format!(
                     "Download exceeded expected size: downloaded {} bytes, expected {} bytes",
@@ -359,7 +628,7 @@ impl WhisperManager {
 
             // Only update progress every 1% to avoid flooding the UI
             if downloaded - last_progress_update >= update_threshold || downloaded == total_size {
-                progress_callback(downloaded, total_size);
+                progress_callback(downloaded, total_size, resume_from);
                 last_progress_update = downloaded;
             }
         }
@@ -372,19 +641,14 @@ impl WhisperManager {
             .map_err(|e| format!("Failed to sync file to disk: {}", e))?;
         drop(file);
 
-        // Also sync the parent directory to ensure directory entry is visible
-        if let Some(parent) = output_path.parent() {
-            if let Ok(dir) = std::fs::File::open(parent) {
-                let _ = dir.sync_all();
-            }
-        }
-
-        // Ensure final 100% progress is sent
         if downloaded < total_size {
-            progress_callback(total_size, total_size);
+            return Err(format!(
+                "Download incomplete: received {} of {} bytes. Download again to resume.",
+                downloaded, total_size
+            ));
         }
 
-        Self::verify_model_checksum(model_info, output_path).await?;
+        Self::finish_partial_download(model_info, &partial_path, output_path).await?;
 
         // Log what files are in the directory after download
         log::info!("[download_model] Download complete. Listing models directory:");
@@ -399,6 +663,60 @@ impl WhisperManager {
         Ok(())
     }
 
+    /// Where an in-progress download of `output_path` is written.
+    pub(crate) fn partial_download_path(output_path: &PathBuf) -> PathBuf {
+        let mut path = output_path.clone().into_os_string();
+        path.push(".part");
+        PathBuf::from(path)
+    }
+
+    async fn request_model_bytes(
+        client: &reqwest::Client,
+        url: &str,
+        resume_from: u64,
+    ) -> Result<reqwest::Response, String> {
+        let mut request = client.get(url);
+        if resume_from > 0 {
+            request = request.header(reqwest::header::RANGE, format!("bytes={}-", resume_from));
+        }
+        request.send().await.map_err(|e| e.to_string())
+    }
+
+    /// A range response only continues the partial file when it is a 206
+    /// whose `Content-Range` starts exactly where the file ends.
+    fn response_resumes_at(response: &reqwest::Response, resume_from: u64) -> bool {
+        response.status() == reqwest::StatusCode::PARTIAL_CONTENT
+            && response
+                .headers()
+                .get(reqwest::header::CONTENT_RANGE)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_content_range_start)
+                == Some(resume_from)
+    }
+
+    /// Verify a fully downloaded partial file and move it into place. A
+    /// checksum mismatch deletes the partial file, so the next attempt starts
+    /// clean.
+    async fn finish_partial_download(
+        model_info: &ModelInfo,
+        partial_path: &PathBuf,
+        output_path: &PathBuf,
+    ) -> Result<(), String> {
+        Self::verify_model_checksum(model_info, partial_path).await?;
+
+        fs::rename(partial_path, output_path)
+            .await
+            .map_err(|e| format!("Failed to move downloaded model into place: {}", e))?;
+
+        // Also sync the parent directory to ensure directory entry is visible
+        if let Some(parent) = output_path.parent() {
+            if let Ok(dir) = std::fs::File::open(parent) {
+                let _ = dir.sync_all();
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) async fn verify_model_checksum(
         model_info: &ModelInfo,
         output_path: &PathBuf,
@@ -609,13 +927,27 @@ impl WhisperManager {
         }
 
         let path = self.models_dir.join(format!("{}.bin", model_name));
-        if !path.exists() {
+        let partial_path = Self::partial_download_path(&path);
+        if !path.exists() && !partial_path.exists() {
             return Err("Model file not found".to_string());
         }
-        std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+        if path.exists() {
+            std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+        }
+        // A download paused by cancel keeps its partial file until the model is deleted
+        if partial_path.exists() {
+            std::fs::remove_file(&partial_path).map_err(|e| e.to_string())?;
+        }
 
-        // update internal flags
-        if let Some(info) = self.models.get_mut(model_name) {
+        // update internal flags; imported models have nothing to re-download
+        if self
+            .models
+            .get(model_name)
+            .is_some_and(|info| info.is_custom())
+        {
+            self.models.remove(model_name);
+            self.save_custom_models()?;
+        } else if let Some(info) = self.models.get_mut(model_name) {
             info.downloaded = false;
         }
         // Also refresh to catch any other changes
@@ -732,3 +1064,10 @@ impl WhisperManager {
         manager
     }
 }
+
+/// Start offset of a `Content-Range: bytes <start>-<end>/<total>` header.
+pub(crate) fn parse_content_range_start(value: &str) -> Option<u64> {
+    let range = value.trim().strip_prefix("bytes ")?;
+    let (start, _) = range.split_once('-')?;
+    start.trim().parse().ok()
+}
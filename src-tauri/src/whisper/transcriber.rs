@@ -1,4 +1,5 @@
 use std::path::Path;
+use std::sync::Mutex;
 use std::time::Instant;
 use whisper_rs::{
     convert_integer_to_float_audio, convert_stereo_to_mono_audio, FullParams, SamplingStrategy,
@@ -9,9 +10,64 @@ use crate::utils::logger::*;
 #[cfg(debug_assertions)]
 use crate::utils::system_monitor;
 
+/// Seconds of audio examined by [`Transcriber::detect_language`].
+const LANGUAGE_DETECTION_SECONDS: usize = 10;
+
+/// Beam width used on the GPU profile when no override is set.
+const DEFAULT_GPU_BEAM_SIZE: u32 = 5;
+
+/// User overrides for Whisper's decoder, from the `whisper_beam_size` and
+/// `whisper_temperature_fallback` settings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecodingOptions {
+    /// `None` keeps the profile default: greedy on CPU, 5 beams on GPU.
+    pub beam_size: Option<u32>,
+    pub temperature_fallback: bool,
+}
+
+impl Default for DecodingOptions {
+    fn default() -> Self {
+        Self {
+            beam_size: None,
+            temperature_fallback: true,
+        }
+    }
+}
+
+/// Decoder parameters resolved from the device profile and user overrides.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DecodingPlan {
+    /// 1 = greedy decoding
+    pub beam_size: u32,
+    pub temperature: f32,
+    /// 0 disables the temperature fallback
+    pub temperature_inc: f32,
+}
+
+pub(crate) fn decoding_plan(cpu_profile: bool, options: &DecodingOptions) -> DecodingPlan {
+    let profile_beam_size = if cpu_profile {
+        1
+    } else {
+        DEFAULT_GPU_BEAM_SIZE
+    };
+    DecodingPlan {
+        beam_size: options
+            .beam_size
+            .unwrap_or(profile_beam_size)
+            .clamp(1, crate::commands::settings::MAX_WHISPER_BEAM_SIZE),
+        temperature: if cpu_profile { 0.0 } else { 0.2 },
+        temperature_inc: if options.temperature_fallback {
+            0.2
+        } else {
+            0.0
+        },
+    }
+}
+
 pub struct Transcriber {
     context: WhisperContext,
     cpu_profile: bool,
+    last_avg_confidence: Mutex<Option<f32>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -21,6 +77,247 @@ pub struct WhisperTranscriptionOutput {
     pub segments: Vec<crate::transcription::TranscriptionSegment>,
     pub audio_duration_ms: u64,
     pub processing_duration_ms: u64,
+    /// Only filled by [`Transcriber::transcribe_with_words`].
+    pub words: Vec<WordTiming>,
+    /// Mean token probability (0-1); `None` when no speech tokens were decoded.
+    pub avg_confidence: Option<f32>,
+}
+
+/// One word with its timing in the audio and Whisper's confidence in it
+/// (token probabilities averaged across the word).
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct WordTiming {
+    pub text: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub confidence: f32,
+}
+
+/// A decoded token with Whisper's timestamps (centiseconds) and probability.
+#[derive(Debug, Clone)]
+struct TokenTiming {
+    text: String,
+    t0: i64,
+    t1: i64,
+    probability: f32,
+}
+
+fn centiseconds_to_ms(value: i64) -> u64 {
+    value.max(0) as u64 * 10
+}
+
+/// Special tokens such as `[_BEG_]` or `<|en|>` carry no spoken text.
+fn is_special_token(text: &str) -> bool {
+    (text.starts_with("[_") && text.ends_with(']'))
+        || (text.starts_with("<|") && text.ends_with("|>"))
+}
+
+/// Mean probability Whisper gave the spoken tokens, or `None` when there were
+/// none. Noisy audio decoded into plausible-looking text scores low here.
+fn average_token_confidence(tokens: &[TokenTiming]) -> Option<f32> {
+    let probabilities: Vec<f32> = tokens
+        .iter()
+        .filter(|token| !is_special_token(&token.text) && !token.text.trim().is_empty())
+        .map(|token| token.probability)
+        .collect();
+    if probabilities.is_empty() {
+        return None;
+    }
+    Some(probabilities.iter().sum::<f32>() / probabilities.len() as f32)
+}
+
+/// Join sub-word tokens into words. A token starting with whitespace opens a
+/// new word; special tokens such as `[_BEG_]` or `<|en|>` are skipped.
+fn group_tokens_into_words(tokens: &[TokenTiming]) -> Vec<WordTiming> {
+    let mut words: Vec<WordTiming> = Vec::new();
+    let mut probabilities: Vec<f32> = Vec::new();
+
+    let finish_word = |words: &mut Vec<WordTiming>, probabilities: &mut Vec<f32>| {
+        if let Some(word) = words.last_mut() {
+            if !probabilities.is_empty() {
+                word.confidence = probabilities.iter().sum::<f32>() / probabilities.len() as f32;
+            }
+        }
+        probabilities.clear();
+    };
+
+    for token in tokens {
+        if is_special_token(&token.text) || token.text.trim().is_empty() {
+            continue;
+        }
+
+        if token.text.starts_with(char::is_whitespace) || words.is_empty() {
+            finish_word(&mut words, &mut probabilities);
+            words.push(WordTiming {
+                text: token.text.trim_start().to_string(),
+                start_ms: centiseconds_to_ms(token.t0),
+                end_ms: centiseconds_to_ms(token.t1),
+                confidence: 0.0,
+            });
+        } else if let Some(word) = words.last_mut() {
+            word.text.push_str(&token.text);
+            word.end_ms = centiseconds_to_ms(token.t1).max(word.start_ms);
+        }
+        probabilities.push(token.probability);
+    }
+    finish_word(&mut words, &mut probabilities);
+
+    words
+}
+
+/// Decode a WAV file into the 16 kHz mono f32 samples Whisper expects. With
+/// `max_seconds`, only that much of the start of the file is read.
+fn decode_to_16k_mono<F>(
+    audio_path: &Path,
+    max_seconds: Option<usize>,
+    should_cancel: &F,
+) -> Result<Vec<f32>, String>
+where
+    F: Fn() -> bool,
+{
+    // Read WAV file
+    let audio_read_start = Instant::now();
+    let mut reader = hound::WavReader::open(audio_path).map_err(|e| {
+        let error = format!("Failed to open WAV file: {}", e);
+        log::error!("[TRANSCRIPTION_DEBUG] {}", error);
+
+        error
+    })?;
+
+    let spec = reader.spec();
+    log::info!(
+        "[TRANSCRIPTION_DEBUG] WAV spec: channels={}, sample_rate={}, bits={}",
+        spec.channels,
+        spec.sample_rate,
+        spec.bits_per_sample
+    );
+
+    /* ----------------------------------------------
+    1) read raw i16 pcm
+    ---------------------------------------------- */
+    let sample_limit = max_seconds
+        .map(|seconds| seconds * spec.sample_rate as usize * spec.channels as usize)
+        .unwrap_or(usize::MAX);
+    let samples_i16: Vec<i16> = reader
+        .samples::<i16>()
+        .take(sample_limit)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read audio samples: {}", e))?;
+
+    // Check cancellation after reading samples
+    if should_cancel() {
+        log::info!("[TRANSCRIPTION_DEBUG] Transcription cancelled after reading samples");
+        return Err("Transcription cancelled".to_string());
+    }
+
+    /* ----------------------------------------------
+    2) i16 → f32  (range -1.0 … 1.0)
+    ---------------------------------------------- */
+    let mut audio: Vec<f32> = vec![0.0; samples_i16.len()];
+    convert_integer_to_float_audio(&samples_i16, &mut audio).map_err(|e| e.to_string())?;
+
+    // Check cancellation after conversion
+    if should_cancel() {
+        log::info!("[TRANSCRIPTION_DEBUG] Transcription cancelled after audio conversion");
+        return Err("Transcription cancelled".to_string());
+    }
+
+    /* ----------------------------------------------
+    3) multi-channel → mono  (Whisper needs mono)
+    ---------------------------------------------- */
+    if spec.channels == 2 {
+        let mut mono_audio = vec![0.0; audio.len() / 2];
+        convert_stereo_to_mono_audio(&audio, &mut mono_audio).map_err(|e| e.to_string())?;
+        audio = mono_audio;
+    } else if spec.channels > 2 {
+        // Handle multi-channel audio (3, 4, 5.1, 7.1, etc.)
+        log::info!(
+            "[TRANSCRIPTION_DEBUG] Converting {}-channel audio to mono",
+            spec.channels
+        );
+        audio = convert_multichannel_to_mono(&audio, spec.channels as usize)?;
+    } else if spec.channels != 1 {
+        return Err(format!("Invalid channel count: {}", spec.channels));
+    }
+
+    // Store original audio length before the move
+    let _original_audio_length = audio.len();
+
+    /* ----------------------------------------------
+    4) Resample to 16kHz using high-quality resampler
+    ---------------------------------------------- */
+    // Use rubato for high-quality resampling to 16kHz
+    let resampled_audio = if spec.sample_rate != 16_000 {
+        use crate::audio::resampler::resample_to_16khz;
+
+        log::info!(
+            "[TRANSCRIPTION_DEBUG] Resampling audio from {} Hz to 16000 Hz",
+            spec.sample_rate
+        );
+
+        resample_to_16khz(&audio, spec.sample_rate)?
+    } else {
+        log::info!("[TRANSCRIPTION_DEBUG] Audio already at 16kHz, no resampling needed");
+        audio
+    };
+
+    // Log audio preprocessing performance
+    let preprocessing_time = audio_read_start.elapsed().as_millis() as u64;
+    log_performance(
+        "AUDIO_PREPROCESSING",
+        preprocessing_time,
+        Some(&format!("samples={}", resampled_audio.len())),
+    );
+    log_with_context(
+        log::Level::Debug,
+        "Audio preprocessing complete",
+        &[
+            (
+                "preprocessing_time_ms",
+                preprocessing_time.to_string().as_str(),
+            ),
+            ("sample_rate", "16000"),
+            ("channels", "1"),
+            ("samples", resampled_audio.len().to_string().as_str()),
+        ],
+    );
+
+    // Check cancellation after resampling
+    if should_cancel() {
+        log::info!("[TRANSCRIPTION_DEBUG] Transcription cancelled after resampling");
+        return Err("Transcription cancelled".to_string());
+    }
+
+    Ok(resampled_audio)
+}
+
+/// Worker threads for Whisper inference.
+fn transcription_threads() -> i32 {
+    // Use most cores but leave one free to keep UI responsive
+    let hw = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4);
+
+    // ARM big.LITTLE optimization: On ARM64 Windows (Qualcomm Snapdragon),
+    // using all cores is slower because efficiency cores drag down performance.
+    // Limit to ~4 threads (performance cores only) for better speed.
+    #[cfg(all(target_os = "windows", target_arch = "aarch64"))]
+    {
+        let t = std::cmp::min(4, std::cmp::max(1, hw.saturating_sub(1))) as i32;
+        log::info!(
+            "[PERFORMANCE] ARM64: Using {} threads (limiting to perf cores, {} total available)",
+            t,
+            hw
+        );
+        t
+    }
+
+    #[cfg(not(all(target_os = "windows", target_arch = "aarch64")))]
+    {
+        let t = std::cmp::max(1, hw.saturating_sub(1)) as i32;
+        log::info!("[PERFORMANCE] Using {} threads for transcription", t);
+        t
+    }
 }
 
 impl Transcriber {
@@ -137,6 +434,7 @@ impl Transcriber {
                     return Ok(Self {
                         context: ctx,
                         cpu_profile,
+                        last_avg_confidence: Mutex::new(None),
                     });
                 }
                 Err(gpu_err) => {
@@ -231,9 +529,19 @@ impl Transcriber {
         Ok(Self {
             context: ctx,
             cpu_profile: true,
+            last_avg_confidence: Mutex::new(None),
         })
     }
 
+    /// Average token confidence of the most recent transcription, see
+    /// [`WhisperTranscriptionOutput::avg_confidence`].
+    pub fn last_avg_confidence(&self) -> Option<f32> {
+        self.last_avg_confidence
+            .lock()
+            .ok()
+            .and_then(|confidence| *confidence)
+    }
+
     #[allow(dead_code)]
     pub fn transcribe_with_translation(
         &self,
@@ -275,6 +583,7 @@ impl Transcriber {
             language,
             translate,
             None,
+            DecodingOptions::default(),
             should_cancel,
         )
     }
@@ -285,6 +594,104 @@ impl Transcriber {
         language: Option<&str>,
         translate: bool,
         initial_prompt: Option<&str>,
+        decoding: DecodingOptions,
+        should_cancel: F,
+    ) -> Result<WhisperTranscriptionOutput, String>
+    where
+        F: Fn() -> bool + Clone + 'static,
+    {
+        self.transcribe_full(
+            audio_path,
+            language,
+            translate,
+            initial_prompt,
+            decoding,
+            false,
+            should_cancel,
+        )
+    }
+
+    /// Word-level timings for `audio_path`, from Whisper's token timestamps,
+    /// returned in `words` alongside the usual transcript metadata.
+    pub fn transcribe_with_words(
+        &self,
+        audio_path: &Path,
+        language: Option<&str>,
+        translate: bool,
+        initial_prompt: Option<&str>,
+        decoding: DecodingOptions,
+    ) -> Result<WhisperTranscriptionOutput, String> {
+        self.transcribe_full(
+            audio_path,
+            language,
+            translate,
+            initial_prompt,
+            decoding,
+            true,
+            || false,
+        )
+    }
+
+    /// Spoken language of `samples` (16 kHz mono) and Whisper's probability
+    /// for it. Only the first [`LANGUAGE_DETECTION_SECONDS`] are examined.
+    pub fn detect_language(&self, samples: &[f32]) -> Result<(String, f32), String> {
+        let window = samples.len().min(LANGUAGE_DETECTION_SECONDS * 16_000);
+        if window < 8_000 {
+            return Err("Recording too short for language detection".to_string());
+        }
+
+        let threads = transcription_threads() as usize;
+        let mut state = self
+            .context
+            .create_state()
+            .map_err(|e| format!("Failed to create Whisper state: {}", e))?;
+        state
+            .pcm_to_mel(&samples[..window], threads)
+            .map_err(|e| format!("Failed to compute mel spectrogram: {}", e))?;
+        let (lang_id, probabilities) = state
+            .lang_detect(0, threads)
+            .map_err(|e| format!("Language detection failed: {}", e))?;
+
+        let code = whisper_rs::get_lang_str(lang_id)
+            .ok_or_else(|| format!("Unknown language id {}", lang_id))?;
+        let confidence = usize::try_from(lang_id)
+            .ok()
+            .and_then(|index| probabilities.get(index).copied())
+            .unwrap_or(0.0);
+        log::info!(
+            "[LANGUAGE] Detected '{}' with confidence {:.2}",
+            code,
+            confidence
+        );
+        Ok((code.to_string(), confidence))
+    }
+
+    /// [`Self::detect_language`] on the opening seconds of a WAV file; the
+    /// rest of the file is never read.
+    pub fn detect_language_in_file<F>(
+        &self,
+        audio_path: &Path,
+        should_cancel: F,
+    ) -> Result<(String, f32), String>
+    where
+        F: Fn() -> bool,
+    {
+        let samples =
+            decode_to_16k_mono(audio_path, Some(LANGUAGE_DETECTION_SECONDS), &should_cancel)?;
+        if should_cancel() {
+            return Err("Transcription cancelled".to_string());
+        }
+        self.detect_language(&samples)
+    }
+
+    fn transcribe_full<F>(
+        &self,
+        audio_path: &Path,
+        language: Option<&str>,
+        translate: bool,
+        initial_prompt: Option<&str>,
+        decoding: DecodingOptions,
+        word_timestamps: bool,
         should_cancel: F,
     ) -> Result<WhisperTranscriptionOutput, String>
     where
@@ -362,114 +769,7 @@ impl Transcriber {
             return Err(error.to_string());
         }
 
-        // Read WAV file
-        let audio_read_start = Instant::now();
-        let mut reader = hound::WavReader::open(audio_path).map_err(|e| {
-            let error = format!("Failed to open WAV file: {}", e);
-            log::error!("[TRANSCRIPTION_DEBUG] {}", error);
-
-            error
-        })?;
-
-        let spec = reader.spec();
-        log::info!(
-            "[TRANSCRIPTION_DEBUG] WAV spec: channels={}, sample_rate={}, bits={}",
-            spec.channels,
-            spec.sample_rate,
-            spec.bits_per_sample
-        );
-
-        /* ----------------------------------------------
-        1) read raw i16 pcm
-        ---------------------------------------------- */
-        let samples_i16: Vec<i16> = reader
-            .samples::<i16>()
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| format!("Failed to read audio samples: {}", e))?;
-
-        // Check cancellation after reading samples
-        if should_cancel() {
-            log::info!("[TRANSCRIPTION_DEBUG] Transcription cancelled after reading samples");
-            return Err("Transcription cancelled".to_string());
-        }
-
-        /* ----------------------------------------------
-        2) i16 → f32  (range -1.0 … 1.0)
-        ---------------------------------------------- */
-        let mut audio: Vec<f32> = vec![0.0; samples_i16.len()];
-        convert_integer_to_float_audio(&samples_i16, &mut audio).map_err(|e| e.to_string())?;
-
-        // Check cancellation after conversion
-        if should_cancel() {
-            log::info!("[TRANSCRIPTION_DEBUG] Transcription cancelled after audio conversion");
-            return Err("Transcription cancelled".to_string());
-        }
-
-        /* ----------------------------------------------
-        3) multi-channel → mono  (Whisper needs mono)
-        ---------------------------------------------- */
-        if spec.channels == 2 {
-            let mut mono_audio = vec![0.0; audio.len() / 2];
-            convert_stereo_to_mono_audio(&audio, &mut mono_audio).map_err(|e| e.to_string())?;
-            audio = mono_audio;
-        } else if spec.channels > 2 {
-            // Handle multi-channel audio (3, 4, 5.1, 7.1, etc.)
-            log::info!(
-                "[TRANSCRIPTION_DEBUG] Converting {}-channel audio to mono",
-                spec.channels
-            );
-            audio = convert_multichannel_to_mono(&audio, spec.channels as usize)?;
-        } else if spec.channels != 1 {
-            return Err(format!("Invalid channel count: {}", spec.channels));
-        }
-
-        // Store original audio length before the move
-        let _original_audio_length = audio.len();
-
-        /* ----------------------------------------------
-        4) Resample to 16kHz using high-quality resampler
-        ---------------------------------------------- */
-        // Use rubato for high-quality resampling to 16kHz
-        let resampled_audio = if spec.sample_rate != 16_000 {
-            use crate::audio::resampler::resample_to_16khz;
-
-            log::info!(
-                "[TRANSCRIPTION_DEBUG] Resampling audio from {} Hz to 16000 Hz",
-                spec.sample_rate
-            );
-
-            resample_to_16khz(&audio, spec.sample_rate)?
-        } else {
-            log::info!("[TRANSCRIPTION_DEBUG] Audio already at 16kHz, no resampling needed");
-            audio
-        };
-
-        // Log audio preprocessing performance
-        let preprocessing_time = audio_read_start.elapsed().as_millis() as u64;
-        log_performance(
-            "AUDIO_PREPROCESSING",
-            preprocessing_time,
-            Some(&format!("samples={}", resampled_audio.len())),
-        );
-        log_with_context(
-            log::Level::Debug,
-            "Audio preprocessing complete",
-            &[
-                (
-                    "preprocessing_time_ms",
-                    preprocessing_time.to_string().as_str(),
-                ),
-                ("sample_rate", "16000"),
-                ("channels", "1"),
-                ("samples", resampled_audio.len().to_string().as_str()),
-            ],
-        );
-
-        // Check cancellation after resampling
-        if should_cancel() {
-            log::info!("[TRANSCRIPTION_DEBUG] Transcription cancelled after resampling");
-            return Err("Transcription cancelled".to_string());
-        }
+        let resampled_audio = decode_to_16k_mono(audio_path, None, &should_cancel)?;
 
         log::debug!(
             "Audio ready for Whisper: {} samples at 16kHz ({:.2}s)",
@@ -477,12 +777,22 @@ impl Transcriber {
             resampled_audio.len() as f32 / 16_000_f32
         );
 
-        let mut params = if self.cpu_profile {
+        let plan = decoding_plan(self.cpu_profile, &decoding);
+        if self.cpu_profile {
             log::info!("[PERFORMANCE] Using CPU fast transcription profile");
+        }
+        if decoding.beam_size.is_some_and(|beams| beams > 1) {
+            log::info!(
+                "[PERFORMANCE] Beam search with {} beams: decoding takes roughly {}x as long as greedy",
+                plan.beam_size,
+                plan.beam_size
+            );
+        }
+        let mut params = if plan.beam_size <= 1 {
             FullParams::new(SamplingStrategy::Greedy { best_of: 1 })
         } else {
             FullParams::new(SamplingStrategy::BeamSearch {
-                beam_size: 5,
+                beam_size: plan.beam_size as i32,
                 patience: -1.0,
             })
         };
@@ -519,36 +829,12 @@ impl Transcriber {
             params.set_translate(false);
         }
 
-        // Use most cores but leave one free to keep UI responsive
-        let hw = std::thread::available_parallelism()
-            .map(|n| n.get())
-            .unwrap_or(4);
-
-        // ARM big.LITTLE optimization: On ARM64 Windows (Qualcomm Snapdragon),
-        // using all cores is slower because efficiency cores drag down performance.
-        // Limit to ~4 threads (performance cores only) for better speed.
-        #[cfg(all(target_os = "windows", target_arch = "aarch64"))]
-        let threads = {
-            let t = std::cmp::min(4, std::cmp::max(1, hw.saturating_sub(1))) as i32;
-            log::info!(
-                "[PERFORMANCE] ARM64: Using {} threads (limiting to perf cores, {} total available)",
-                t,
-                hw
-            );
-            t
-        };
-
-        #[cfg(not(all(target_os = "windows", target_arch = "aarch64")))]
-        let threads = {
-            let t = std::cmp::max(1, hw.saturating_sub(1)) as i32;
-            log::info!("[PERFORMANCE] Using {} threads for transcription", t);
-            t
-        };
-
+        let threads = transcription_threads();
         params.set_n_threads(threads);
 
         params.set_no_context(self.cpu_profile);
-        params.set_no_timestamps(self.cpu_profile);
+        params.set_no_timestamps(self.cpu_profile && !word_timestamps);
+        params.set_token_timestamps(word_timestamps);
         params.set_print_special(false);
         params.set_print_progress(false);
         params.set_print_realtime(false);
@@ -572,8 +858,8 @@ impl Transcriber {
 
         params.set_initial_prompt(initial_prompt.unwrap_or(""));
 
-        params.set_temperature(if self.cpu_profile { 0.0 } else { 0.2 });
-        params.set_temperature_inc(0.2); // Increase by 0.2 on fallback (default)
+        params.set_temperature(plan.temperature);
+        params.set_temperature_inc(plan.temperature_inc); // 0.2 per fallback step (default), 0 = off
         params.set_max_initial_ts(1.0); // Limit initial timestamp search
 
         // Limit segment length to prevent runaway hallucinations
@@ -678,6 +964,7 @@ impl Transcriber {
 
         let mut text = String::new();
         let mut segments = Vec::with_capacity(num_segments as usize);
+        let mut tokens = Vec::new();
         for (i, segment) in state.as_iter().enumerate() {
             let segment_text = segment.to_string();
             log::debug!(
@@ -687,15 +974,51 @@ impl Transcriber {
             );
             text.push_str(&segment_text);
             text.push(' ');
+
+            // Token probabilities are always read for the confidence score;
+            // their timestamps are only meaningful with word timestamps on.
+            for index in 0..segment.n_tokens() {
+                let Some(token) = segment.get_token(index) else {
+                    continue;
+                };
+                let Ok(token_text) = token.to_str_lossy() else {
+                    continue;
+                };
+                let data = token.token_data();
+                tokens.push(TokenTiming {
+                    text: token_text.into_owned(),
+                    t0: data.t0,
+                    t1: data.t1,
+                    probability: data.p,
+                });
+            }
+            let (start_ms, end_ms) = if word_timestamps {
+                (
+                    Some(centiseconds_to_ms(segment.start_timestamp())),
+                    Some(centiseconds_to_ms(segment.end_timestamp())),
+                )
+            } else {
+                (None, None)
+            };
             segments.push(crate::transcription::TranscriptionSegment {
                 text: segment_text,
-                start_ms: None,
-                end_ms: None,
+                start_ms,
+                end_ms,
                 speaker_id: None,
             });
         }
 
         let result = text.trim().to_string();
+        let avg_confidence = average_token_confidence(&tokens);
+        if let Ok(mut last) = self.last_avg_confidence.lock() {
+            *last = avg_confidence;
+        }
+        if let Some(confidence) = avg_confidence {
+            log::debug!(
+                "[TRANSCRIPTION_DEBUG] Average token confidence: {:.2}",
+                confidence
+            );
+        }
 
         // Log text extraction performance
         let extraction_time = text_extraction_start.elapsed().as_millis() as u64;
@@ -773,6 +1096,12 @@ impl Transcriber {
             segments,
             audio_duration_ms: (duration_seconds * 1000.0) as u64,
             processing_duration_ms: total_time.as_millis() as u64,
+            words: if word_timestamps {
+                group_tokens_into_words(&tokens)
+            } else {
+                Vec::new()
+            },
+            avg_confidence,
         })
     }
 }
@@ -828,6 +1157,135 @@ fn convert_multichannel_to_mono(audio: &[f32], channels: usize) -> Result<Vec<f3
 mod tests {
     use super::*;
 
+    fn token(text: &str, t0: i64, t1: i64, probability: f32) -> TokenTiming {
+        TokenTiming {
+            text: text.to_string(),
+            t0,
+            t1,
+            probability,
+        }
+    }
+
+    #[test]
+    fn decoding_plan_keeps_profile_defaults_without_overrides() {
+        let defaults = DecodingOptions::default();
+        assert_eq!(
+            decoding_plan(true, &defaults),
+            DecodingPlan {
+                beam_size: 1,
+                temperature: 0.0,
+                temperature_inc: 0.2,
+            }
+        );
+        assert_eq!(
+            decoding_plan(false, &defaults),
+            DecodingPlan {
+                beam_size: 5,
+                temperature: 0.2,
+                temperature_inc: 0.2,
+            }
+        );
+    }
+
+    #[test]
+    fn decoding_plan_applies_beam_size_and_fallback_overrides() {
+        let options = DecodingOptions {
+            beam_size: Some(3),
+            temperature_fallback: false,
+        };
+        let plan = decoding_plan(true, &options);
+        assert_eq!(plan.beam_size, 3);
+        assert_eq!(plan.temperature_inc, 0.0);
+
+        let greedy = DecodingOptions {
+            beam_size: Some(1),
+            ..DecodingOptions::default()
+        };
+        assert_eq!(decoding_plan(false, &greedy).beam_size, 1);
+
+        let too_wide = DecodingOptions {
+            beam_size: Some(64),
+            ..DecodingOptions::default()
+        };
+        assert_eq!(decoding_plan(false, &too_wide).beam_size, 8);
+    }
+
+    #[test]
+    fn group_tokens_joins_sub_words_and_averages_confidence() {
+        let words = group_tokens_into_words(&[
+            token("[_BEG_]", 0, 0, 1.0),
+            token(" Hello", 0, 40, 0.9),
+            token(" wor", 40, 60, 0.8),
+            token("ld", 60, 85, 0.6),
+            token(".", 85, 90, 1.0),
+            token("<|endoftext|>", 90, 90, 1.0),
+        ]);
+
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].text, "Hello");
+        assert_eq!((words[0].start_ms, words[0].end_ms), (0, 400));
+        assert!((words[0].confidence - 0.9).abs() < 1e-6);
+        assert_eq!(words[1].text, "world.");
+        assert_eq!((words[1].start_ms, words[1].end_ms), (400, 900));
+        assert!((words[1].confidence - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn average_confidence_skips_special_tokens() {
+        let confidence = average_token_confidence(&[
+            token("[_BEG_]", 0, 0, 0.1),
+            token(" Hello", 0, 40, 0.9),
+            token(" world", 40, 80, 0.7),
+            token("<|endoftext|>", 80, 80, 0.1),
+        ])
+        .unwrap();
+        assert!((confidence - 0.8).abs() < 1e-6);
+        assert_eq!(
+            average_token_confidence(&[token("[_BEG_]", 0, 0, 1.0)]),
+            None
+        );
+    }
+
+    #[test]
+    #[ignore = "downloads the tiny.en Whisper model; run with --ignored"]
+    fn noisy_clip_scores_below_default_threshold() {
+        use rand::Rng;
+
+        let model_path =
+            crate::remote::integration_tests::tests::ensure_tiny_model_available_sync().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let clip = dir.path().join("noise.wav");
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16_000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&clip, spec).unwrap();
+        let mut rng = rand::thread_rng();
+        for _ in 0..16_000 * 5 {
+            writer
+                .write_sample(rng.gen_range(-8_000i16..8_000))
+                .unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let transcriber = Transcriber::new(&model_path).unwrap();
+        let output = transcriber
+            .transcribe_with_metadata(&clip, Some("en"), false, || false)
+            .unwrap();
+
+        assert_eq!(transcriber.last_avg_confidence(), output.avg_confidence);
+        // Whisper may decode nothing from noise; whatever it does decode must score low
+        if let Some(confidence) = output.avg_confidence {
+            assert!(
+                confidence < crate::commands::settings::DEFAULT_LOW_CONFIDENCE_THRESHOLD,
+                "noise clip confidence {confidence} for {:?}",
+                output.raw_text
+            );
+        }
+    }
+
     #[test]
     fn test_convert_multichannel_to_mono() {
         // Test 4-channel audio downmixing
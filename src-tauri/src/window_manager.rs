@@ -2,14 +2,93 @@ use crate::commands::settings::{
     DEFAULT_INDICATOR_OFFSET, MAX_INDICATOR_OFFSET, MIN_INDICATOR_OFFSET,
 };
 use crate::utils::logger::*;
+use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
-use tauri::{AppHandle, Emitter, Manager, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
+use std::time::{Duration, Instant};
+use tauri::{
+    AppHandle, Emitter, Manager, PhysicalPosition, WebviewUrl, WebviewWindow, WebviewWindowBuilder,
+};
+
+/// Store key for the pill position the user dragged it to.
+const PILL_POSITION_KEY: &str = "pill_custom_position";
+
+/// Moves reported this soon after we placed the pill ourselves are not drags.
+const PROGRAMMATIC_MOVE_GRACE: Duration = Duration::from_millis(500);
+
+const PILL_WIDTH: f64 = 80.0;
+const PILL_HEIGHT: f64 = 40.0;
 
 #[derive(Debug, Clone)]
 pub struct WindowManager {
     app_handle: AppHandle,
     main_window: Arc<Mutex<Option<WebviewWindow>>>,
     pill_window: Arc<Mutex<Option<WebviewWindow>>>,
+    scratchpad_window: Arc<Mutex<Option<WebviewWindow>>>,
+    pill_placed_at: Arc<Mutex<Option<Instant>>>,
+}
+
+/// Where the user dragged the pill: the display's name and the pill's
+/// top-left corner in logical pixels from that display's top-left corner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedPillPosition {
+    pub display: String,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A monitor's bounds in physical pixels.
+#[derive(Debug, Clone, PartialEq)]
+struct DisplayArea {
+    name: String,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    scale: f64,
+}
+
+impl DisplayArea {
+    fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x
+            && y >= self.y
+            && (x as i64) < self.x as i64 + self.width as i64
+            && (y as i64) < self.y as i64 + self.height as i64
+    }
+}
+
+/// Record a pill at physical `(x, y)` relative to the display holding its centre.
+fn saved_position_for(x: i32, y: i32, displays: &[DisplayArea]) -> Option<SavedPillPosition> {
+    let display = displays.iter().find(|display| {
+        let centre_x = x + (PILL_WIDTH / 2.0 * display.scale) as i32;
+        let centre_y = y + (PILL_HEIGHT / 2.0 * display.scale) as i32;
+        display.contains(centre_x, centre_y)
+    })?;
+    Some(SavedPillPosition {
+        display: display.name.clone(),
+        x: (x - display.x) as f64 / display.scale,
+        y: (y - display.y) as f64 / display.scale,
+    })
+}
+
+/// Physical position for a saved pill. Falls back to the first display (the
+/// primary) when the saved one is gone, and always clamps the pill fully
+/// inside the chosen display so it can never be restored off-screen.
+fn restore_saved_position(
+    saved: &SavedPillPosition,
+    displays: &[DisplayArea],
+) -> Option<(i32, i32)> {
+    let display = displays
+        .iter()
+        .find(|display| display.name == saved.display)
+        .or_else(|| displays.first())?;
+    let max_x = (display.width as f64 / display.scale - PILL_WIDTH).max(0.0);
+    let max_y = (display.height as f64 / display.scale - PILL_HEIGHT).max(0.0);
+    let x = saved.x.clamp(0.0, max_x);
+    let y = saved.y.clamp(0.0, max_y);
+    Some((
+        display.x + (x * display.scale).round() as i32,
+        display.y + (y * display.scale).round() as i32,
+    ))
 }
 
 fn calculate_pill_position(
@@ -67,6 +146,8 @@ impl WindowManager {
             app_handle,
             main_window: Arc::new(Mutex::new(main_window)),
             pill_window: Arc::new(Mutex::new(None)),
+            scratchpad_window: Arc::new(Mutex::new(None)),
+            pill_placed_at: Arc::new(Mutex::new(None)),
         };
 
         log_with_context(
@@ -138,6 +219,8 @@ impl WindowManager {
             }
         };
         *pill_guard = Some(window);
+        // Startup placement may still report a move; it is not a user drag.
+        self.mark_pill_placed();
         log::debug!("Stored pill window reference");
     }
 
@@ -202,10 +285,8 @@ impl WindowManager {
                     // Window exists and is valid - just show it and reposition
                     existing_window.show().map_err(|e| e.to_string())?;
 
-                    // Always position at center-bottom
-                    use tauri::LogicalPosition;
-                    let (x, y) = self.calculate_center_position();
-                    let _ = existing_window.set_position(LogicalPosition::new(x, y));
+                    // Restore where the user left it, else the configured preset
+                    self.place_pill(existing_window);
 
                     log::debug!("Showing existing pill window");
                     return Ok(());
@@ -228,6 +309,7 @@ impl WindowManager {
 
         // Create new window
         log::info!("Creating new pill window (lazy-loaded on recording start)");
+        self.mark_pill_placed();
 
         // Always use fixed center-bottom position
         let (position_x, position_y) = self.calculate_center_position();
@@ -267,6 +349,13 @@ impl WindowManager {
         #[cfg(debug_assertions)]
         let pill_builder = pill_builder;
 
+        // Start in the current system theme; `theme-changed` keeps it in sync
+        let pill_builder = pill_builder.initialization_script(
+            &crate::utils::theme_watcher::pill_theme_init_script(
+                crate::utils::theme_watcher::current_system_theme(),
+            ),
+        );
+
         let pill_window = pill_builder.build().map_err(|e| e.to_string())?;
 
         // Convert to NSPanel on macOS
@@ -325,6 +414,11 @@ impl WindowManager {
             }
         }
 
+        // The builder used the preset position; move to the dragged spot if any
+        if self.saved_pill_target().is_some() {
+            self.place_pill(&pill_window);
+        }
+
         // Show the window after NSPanel conversion
         pill_window.show().map_err(|e| e.to_string())?;
 
@@ -472,6 +566,82 @@ impl WindowManager {
         Ok(())
     }
 
+    /// Get the scratchpad window reference (validates window is still alive)
+    pub fn get_scratchpad_window(&self) -> Option<WebviewWindow> {
+        let mut guard = match self.scratchpad_window.lock() {
+            Ok(guard) => guard,
+            Err(e) => {
+                log::error!("Scratchpad window mutex is poisoned: {}", e);
+                return None;
+            }
+        };
+
+        if let Some(ref window) = *guard {
+            if window.is_closable().is_ok() {
+                return Some(window.clone());
+            }
+            log::debug!("Scratchpad window reference is stale, clearing");
+            *guard = None;
+        }
+
+        let window = self
+            .app_handle
+            .get_webview_window("scratchpad")
+            .filter(|window| window.is_closable().is_ok())?;
+        *guard = Some(window.clone());
+        Some(window)
+    }
+
+    /// Show the scratchpad window, creating it on first use. Unlike the pill it
+    /// is a normal, movable window; it is shown without taking focus so the
+    /// dictation that opened it doesn't pull the user out of their current app.
+    pub fn show_scratchpad_window(&self) -> Result<(), String> {
+        if let Some(window) = self.get_scratchpad_window() {
+            window.show().map_err(|e| e.to_string())?;
+            return Ok(());
+        }
+
+        log::info!("Creating scratchpad window");
+        let scratchpad_builder = WebviewWindowBuilder::new(
+            &self.app_handle,
+            "scratchpad",
+            WebviewUrl::App("scratchpad.html".into()),
+        )
+        .title("VoiceTypr Scratchpad")
+        .resizable(true)
+        .minimizable(true)
+        .maximizable(false)
+        .always_on_top(true)
+        .inner_size(420.0, 320.0)
+        .min_inner_size(280.0, 200.0)
+        .visible(true)
+        .focused(false);
+
+        #[cfg(not(debug_assertions))]
+        let scratchpad_builder = scratchpad_builder.initialization_script(
+            "document.addEventListener('contextmenu', e => e.preventDefault());",
+        );
+
+        let window = scratchpad_builder.build().map_err(|e| e.to_string())?;
+        match self.scratchpad_window.lock() {
+            Ok(mut guard) => *guard = Some(window),
+            Err(e) => log::error!(
+                "Scratchpad window mutex poisoned while storing window: {}",
+                e
+            ),
+        }
+        Ok(())
+    }
+
+    /// Hide the scratchpad window (don't close it, so its contents stay loaded)
+    pub fn hide_scratchpad_window(&self) -> Result<(), String> {
+        if let Some(window) = self.get_scratchpad_window() {
+            window.hide().map_err(|e| e.to_string())?;
+            log::info!("Scratchpad window hidden");
+        }
+        Ok(())
+    }
+
     /// Emit event to specific window
     pub fn emit_to_window(
         &self,
@@ -487,6 +657,7 @@ impl WindowManager {
         let window = match window_id {
             "main" => self.get_main_window(),
             "pill" => self.get_pill_window(),
+            "scratchpad" => self.get_scratchpad_window(),
             _ => None,
         };
 
@@ -669,21 +840,134 @@ impl WindowManager {
         self.calculate_position_for(&position)
     }
 
-    /// Reposition pill and toast windows to current monitor center-bottom.
-    /// Called when monitor configuration changes (display connect/disconnect, resolution change).
-    pub fn reposition_floating_windows(&self) {
+    /// Current monitors in physical pixels, primary first.
+    fn display_areas(&self) -> Vec<DisplayArea> {
+        crate::utils::monitor::catch_monitor_panic(|| {
+            let primary_name = self
+                .app_handle
+                .primary_monitor()
+                .ok()
+                .flatten()
+                .and_then(|monitor| monitor.name().cloned());
+            let mut displays: Vec<DisplayArea> = self
+                .app_handle
+                .available_monitors()
+                .unwrap_or_default()
+                .iter()
+                .enumerate()
+                .map(|(index, monitor)| DisplayArea {
+                    name: monitor
+                        .name()
+                        .cloned()
+                        .unwrap_or_else(|| format!("display-{}", index)),
+                    x: monitor.position().x,
+                    y: monitor.position().y,
+                    width: monitor.size().width,
+                    height: monitor.size().height,
+                    scale: monitor.scale_factor(),
+                })
+                .collect();
+            if let Some(primary) = primary_name {
+                displays.sort_by_key(|display| display.name != primary);
+            }
+            displays
+        })
+        .unwrap_or_default()
+    }
+
+    fn saved_pill_position(&self) -> Option<SavedPillPosition> {
+        use tauri_plugin_store::StoreExt;
+        self.app_handle
+            .store("settings")
+            .ok()
+            .and_then(|store| store.get(PILL_POSITION_KEY))
+            .and_then(|value| serde_json::from_value(value).ok())
+    }
+
+    /// Physical position of the dragged pill on the current displays, if any.
+    fn saved_pill_target(&self) -> Option<(i32, i32)> {
+        let saved = self.saved_pill_position()?;
+        restore_saved_position(&saved, &self.display_areas())
+    }
+
+    fn mark_pill_placed(&self) {
+        if let Ok(mut placed_at) = self.pill_placed_at.lock() {
+            *placed_at = Some(Instant::now());
+        }
+    }
+
+    /// Move the pill to its saved spot, or the configured preset when the user
+    /// never dragged it. Returns the logical position used for the toast.
+    fn place_pill(&self, pill: &WebviewWindow) -> (f64, f64) {
         use tauri::LogicalPosition;
 
-        let (pill_x, pill_y) = self.calculate_center_position();
+        self.mark_pill_placed();
+        if let Some((x, y)) = self.saved_pill_target() {
+            if let Err(e) = pill.set_position(PhysicalPosition::new(x, y)) {
+                log::warn!("Failed to restore pill position: {}", e);
+            }
+            let scale = pill.scale_factor().unwrap_or(1.0);
+            return (x as f64 / scale, y as f64 / scale);
+        }
 
-        // Reposition pill window
-        if let Some(pill) = self.get_pill_window() {
-            if let Err(e) = pill.set_position(LogicalPosition::new(pill_x, pill_y)) {
-                log::warn!("Failed to reposition pill window: {}", e);
-            } else {
-                log::info!("Repositioned pill window to ({}, {})", pill_x, pill_y);
+        let (x, y) = self.calculate_center_position();
+        if let Err(e) = pill.set_position(LogicalPosition::new(x, y)) {
+            log::warn!("Failed to reposition pill window: {}", e);
+        }
+        (x, y)
+    }
+
+    /// Persist the pill's position after the user drags it. Called for every
+    /// pill `Moved` event; moves caused by our own placement are ignored.
+    pub fn remember_pill_position(&self, position: PhysicalPosition<i32>) {
+        use tauri_plugin_store::StoreExt;
+
+        let placed_recently = self
+            .pill_placed_at
+            .lock()
+            .ok()
+            .and_then(|placed_at| *placed_at)
+            .is_some_and(|at| at.elapsed() < PROGRAMMATIC_MOVE_GRACE);
+        if placed_recently {
+            return;
+        }
+
+        let Some(saved) = saved_position_for(position.x, position.y, &self.display_areas()) else {
+            return;
+        };
+        // Set only: a drag fires many moves and the store's autosave debounces writes.
+        if let Ok(store) = self.app_handle.store("settings") {
+            match serde_json::to_value(&saved) {
+                Ok(value) => store.set(PILL_POSITION_KEY, value),
+                Err(e) => log::warn!("Failed to serialize pill position: {}", e),
             }
         }
+    }
+
+    /// Forget the dragged position and move the pill back to its preset.
+    pub fn reset_pill_position(&self) -> Result<(), String> {
+        use tauri_plugin_store::StoreExt;
+
+        let store = self
+            .app_handle
+            .store("settings")
+            .map_err(|e| e.to_string())?;
+        store.delete(PILL_POSITION_KEY);
+        store.save().map_err(|e| e.to_string())?;
+        self.reposition_floating_windows();
+        Ok(())
+    }
+
+    /// Reposition pill and toast windows after a monitor configuration change
+    /// (display connect/disconnect, resolution change). A dragged pill goes back
+    /// to its saved spot, clamped onto a remaining display; otherwise the preset.
+    pub fn reposition_floating_windows(&self) {
+        use tauri::LogicalPosition;
+
+        let (pill_x, pill_y) = match self.get_pill_window() {
+            Some(pill) => self.place_pill(&pill),
+            None => self.calculate_center_position(),
+        };
 
         // Reposition toast window (above pill)
         if let Some(toast) = self.app_handle.get_webview_window("toast") {
@@ -711,6 +995,7 @@ impl WindowManager {
 
         // Reposition pill window
         if let Some(pill) = self.get_pill_window() {
+            self.mark_pill_placed();
             if let Err(e) = pill.set_position(LogicalPosition::new(pill_x, pill_y)) {
                 log::warn!("Failed to reposition pill window: {}", e);
             } else {
@@ -748,7 +1033,21 @@ impl WindowManager {
 
 #[cfg(test)]
 mod tests {
-    use super::calculate_pill_position;
+    use super::{
+        calculate_pill_position, restore_saved_position, saved_position_for, DisplayArea,
+        SavedPillPosition,
+    };
+
+    fn display(name: &str, x: i32, width: u32, scale: f64) -> DisplayArea {
+        DisplayArea {
+            name: name.to_string(),
+            x,
+            y: 0,
+            width,
+            height: (width as f64 * 9.0 / 16.0) as u32,
+            scale,
+        }
+    }
 
     // Screen: 1920x1080, pill: 80x40, edge_offset: 10
     // x_left = 10, x_center = 920, x_right = 1830
@@ -810,4 +1109,41 @@ mod tests {
         assert_eq!(x, 50.0);
         assert_eq!(y, 990.0); // 1080 - 40 - 50
     }
+
+    #[test]
+    fn saved_position_round_trips_on_secondary_display() {
+        // Primary 1920x1080 @1x, secondary 2560x1440 @2x to its right.
+        let displays = [
+            display("primary", 0, 1920, 1.0),
+            display("secondary", 1920, 2560, 2.0),
+        ];
+
+        let saved = saved_position_for(1920 + 400, 600, &displays).unwrap();
+        assert_eq!(
+            saved,
+            SavedPillPosition {
+                display: "secondary".to_string(),
+                x: 200.0,
+                y: 300.0,
+            }
+        );
+        assert_eq!(
+            restore_saved_position(&saved, &displays),
+            Some((1920 + 400, 600))
+        );
+    }
+
+    #[test]
+    fn restore_clamps_onto_primary_when_display_is_gone() {
+        let saved = SavedPillPosition {
+            display: "secondary".to_string(),
+            x: 1200.0,
+            y: 700.0,
+        };
+        let displays = [display("primary", 0, 1280, 1.0)];
+
+        // 1280x720 primary: the 80x40 pill must fit fully inside.
+        assert_eq!(restore_saved_position(&saved, &displays), Some((1200, 680)));
+        assert_eq!(restore_saved_position(&saved, &[]), None);
+    }
 }
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 
 use active_win_pos_rs::get_active_window;
@@ -11,19 +12,35 @@ use tauri_plugin_store::StoreExt;
 use crate::ai::error::{user_facing_message, AiProviderError};
 use crate::ai::prompts::EnhancementPreset;
 use crate::commands::settings::{
-    normalize_final_text_language, normalize_transcription_task,
+    normalize_final_text_language, normalize_transcription_task, normalize_translate_to_language,
     FINAL_TEXT_LANGUAGE_SAME_AS_TRANSCRIPT,
 };
 use crate::parakeet::messages::ParakeetVocabularyTerm;
+use crate::text::replacements::{
+    apply_replacements, has_word_boundaries, literal_matches, replacement_regex, ReplacementRule,
+};
+use crate::text::voice_commands::{
+    default_voice_commands, voice_command_output, VoiceCommandOutput,
+};
 use crate::transcription::TranscriptionResult;
 use crate::whisper::languages::validate_language;
 
 const WRITING_SETTINGS_KEY: &str = "writing_settings";
 
+/// Warning code for a translation step that failed and left the text as is.
+pub const TRANSLATION_FAILED_WARNING: &str = "translation_failed";
+
+/// Code mode switched on for the current app session (not persisted).
+static CODE_MODE_SESSION: AtomicBool = AtomicBool::new(false);
+
 fn default_enabled() -> bool {
     true
 }
 
+fn default_whole_word() -> bool {
+    true
+}
+
 fn default_preserve_literal() -> bool {
     true
 }
@@ -117,6 +134,10 @@ pub struct TextReplacementRule {
     pub language: Option<String>,
     #[serde(default = "default_enabled")]
     pub enabled: bool,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    #[serde(default = "default_whole_word")]
+    pub whole_word: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -160,6 +181,49 @@ pub struct VoiceCommandRule {
     pub enabled: bool,
 }
 
+/// How a code-mode symbol joins the words around it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolSpacing {
+    /// No space on either side (`a.b`, `my_var`, `f(x`).
+    Tight,
+    /// One space on each side (`a = b`).
+    Spaced,
+    /// Attaches to the previous word, space after (`a, b`, `f(x) + 1`).
+    AttachLeft,
+    /// Attaches to the next word (`[1`, `"text`).
+    AttachRight,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpokenSymbolRule {
+    pub phrase: String,
+    pub symbol: String,
+    pub spacing: SymbolSpacing,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+/// Deterministic code dictation: spoken symbols become characters, prose
+/// capitalization and number words are undone, and AI formatting is skipped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeModeSettings {
+    /// Apps (substring match, like app formatting rules) that always use code mode.
+    #[serde(default)]
+    pub apps: Vec<String>,
+    #[serde(default = "default_code_symbols")]
+    pub symbols: Vec<SpokenSymbolRule>,
+}
+
+impl Default for CodeModeSettings {
+    fn default() -> Self {
+        Self {
+            apps: Vec::new(),
+            symbols: default_code_symbols(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WritingSettings {
     #[serde(default)]
@@ -172,6 +236,11 @@ pub struct WritingSettings {
     pub app_formatting_rules: Vec<AppFormattingRule>,
     #[serde(default = "default_voice_commands")]
     pub voice_commands: Vec<VoiceCommandRule>,
+    /// Off leaves every spoken command ("new line", "insert comma", ...) as words.
+    #[serde(default = "default_enabled")]
+    pub voice_commands_enabled: bool,
+    #[serde(default)]
+    pub code_mode: CodeModeSettings,
 }
 
 impl Default for WritingSettings {
@@ -182,26 +251,61 @@ impl Default for WritingSettings {
             snippets: Vec::new(),
             app_formatting_rules: Vec::new(),
             voice_commands: default_voice_commands(),
+            voice_commands_enabled: true,
+            code_mode: CodeModeSettings::default(),
         }
     }
 }
 
-fn default_voice_commands() -> Vec<VoiceCommandRule> {
+fn default_code_symbols() -> Vec<SpokenSymbolRule> {
+    use SymbolSpacing::*;
     [
-        ("new paragraph", "paragraph"),
-        ("new line", "new_line"),
-        ("question mark", "question_mark"),
-        ("exclamation point", "exclamation_mark"),
-        ("exclamation mark", "exclamation_mark"),
-        ("full stop", "period"),
-        ("insert comma", "comma"),
-        ("insert period", "period"),
+        ("open paren", "(", Tight),
+        ("close paren", ")", AttachLeft),
+        ("open bracket", "[", AttachRight),
+        ("close bracket", "]", AttachLeft),
+        ("open brace", "{", Spaced),
+        ("close brace", "}", Spaced),
+        ("less than", "<", Spaced),
+        ("greater than", ">", Spaced),
+        ("equals equals", "==", Spaced),
+        ("not equals", "!=", Spaced),
+        ("equals", "=", Spaced),
+        ("plus", "+", Spaced),
+        ("minus", "-", Spaced),
+        ("times", "*", Spaced),
+        ("asterisk", "*", Tight),
+        ("slash", "/", Tight),
+        ("backslash", "\\", Tight),
+        ("arrow", "->", Spaced),
+        ("fat arrow", "=>", Spaced),
+        ("and and", "&&", Spaced),
+        ("or or", "||", Spaced),
+        ("ampersand", "&", Tight),
+        ("pipe", "|", Spaced),
+        ("bang", "!", AttachRight),
+        ("semicolon", ";", AttachLeft),
+        ("colon", ":", AttachLeft),
+        ("double colon", "::", Tight),
+        ("comma", ",", AttachLeft),
+        ("dot", ".", Tight),
+        ("underscore", "_", Tight),
+        ("dash", "-", Tight),
+        ("hash", "#", AttachRight),
+        ("at sign", "@", AttachRight),
+        ("dollar sign", "$", AttachRight),
+        ("percent", "%", Spaced),
+        ("caret", "^", Tight),
+        ("tilde", "~", AttachRight),
+        ("backtick", "`", Tight),
+        ("double quote", "\"", Tight),
+        ("single quote", "'", Tight),
     ]
     .into_iter()
-    .map(|(phrase, output)| VoiceCommandRule {
+    .map(|(phrase, symbol, spacing)| SpokenSymbolRule {
         phrase: phrase.to_string(),
-        output: output.to_string(),
-        language: Some("en".to_string()),
+        symbol: symbol.to_string(),
+        spacing,
         enabled: true,
     })
     .collect()
@@ -222,6 +326,7 @@ pub enum WritingOperationKind {
     Translation,
     AiCleanup,
     VoiceCommand,
+    CodeFormatting,
     FinalGuard,
 }
 
@@ -284,6 +389,8 @@ pub fn sanitize_writing_settings(settings: WritingSettings) -> WritingSettings {
                     to: to.to_string(),
                     language: normalize_language_scope(rule.language.as_deref()),
                     enabled: rule.enabled,
+                    case_sensitive: rule.case_sensitive,
+                    whole_word: rule.whole_word,
                 })
             })
             .collect(),
@@ -342,6 +449,7 @@ pub fn sanitize_writing_settings(settings: WritingSettings) -> WritingSettings {
                 })
             })
             .collect(),
+        voice_commands_enabled: settings.voice_commands_enabled,
         app_formatting_rules: settings
             .app_formatting_rules
             .into_iter()
@@ -357,6 +465,33 @@ pub fn sanitize_writing_settings(settings: WritingSettings) -> WritingSettings {
                 })
             })
             .collect(),
+        code_mode: CodeModeSettings {
+            apps: settings
+                .code_mode
+                .apps
+                .into_iter()
+                .map(|app_name| app_name.trim().to_string())
+                .filter(|app_name| !app_name.is_empty())
+                .collect(),
+            symbols: settings
+                .code_mode
+                .symbols
+                .into_iter()
+                .filter_map(|rule| {
+                    let phrase = rule.phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+                    let symbol = rule.symbol.trim();
+                    if phrase.is_empty() || symbol.is_empty() {
+                        return None;
+                    }
+                    Some(SpokenSymbolRule {
+                        phrase,
+                        symbol: symbol.to_string(),
+                        spacing: rule.spacing,
+                        enabled: rule.enabled,
+                    })
+                })
+                .collect(),
+        },
     }
 }
 
@@ -392,7 +527,37 @@ fn enabled_app_rules(settings: &WritingSettings) -> impl Iterator<Item = &AppFor
 }
 
 fn app_rules_need_active_app(settings: &WritingSettings) -> bool {
-    enabled_app_rules(settings).next().is_some()
+    enabled_app_rules(settings).next().is_some() || !settings.code_mode.apps.is_empty()
+}
+
+/// Turn code mode on or off for the rest of this app session.
+pub fn set_code_mode_session(enabled: bool) {
+    CODE_MODE_SESSION.store(enabled, Ordering::SeqCst);
+}
+
+pub fn code_mode_session_enabled() -> bool {
+    CODE_MODE_SESSION.load(Ordering::SeqCst)
+}
+
+fn resolve_code_mode(
+    settings: &WritingSettings,
+    session_enabled: bool,
+    active_app: Option<&ContextHint>,
+) -> bool {
+    if session_enabled {
+        return true;
+    }
+    let Some(app_name) = active_app.and_then(|hint| hint.app_name.as_deref()) else {
+        return false;
+    };
+    let normalized_app_name = app_name.trim().to_ascii_lowercase();
+    !normalized_app_name.is_empty()
+        && settings
+            .code_mode
+            .apps
+            .iter()
+            .map(|rule_app| rule_app.trim().to_ascii_lowercase())
+            .any(|rule_app| !rule_app.is_empty() && normalized_app_name.contains(&rule_app))
 }
 
 fn resolve_app_formatting_preset(
@@ -532,24 +697,6 @@ fn language_scope_matches(scope: Option<&str>, transcript_language: Option<&str>
     }
 }
 
-fn is_boundary_word_char(ch: char) -> bool {
-    ch.is_alphanumeric() || ch == '_'
-}
-
-fn candidate_has_boundaries(text: &str, start: usize, end: usize) -> bool {
-    let left_ok = text[..start]
-        .chars()
-        .last()
-        .map(|ch| !is_boundary_word_char(ch))
-        .unwrap_or(true);
-    let right_ok = text[end..]
-        .chars()
-        .next()
-        .map(|ch| !is_boundary_word_char(ch))
-        .unwrap_or(true);
-    left_ok && right_ok
-}
-
 fn span_in_protected_token(text: &str, start: usize, end: usize) -> bool {
     let token_start = text[..start]
         .char_indices()
@@ -595,6 +742,7 @@ struct CompiledReplacementRule {
     source_form: String,
     source_kind: LibraryRuleSourceKind,
     language_scope: Option<String>,
+    whole_word: bool,
 }
 
 #[derive(Clone)]
@@ -634,7 +782,8 @@ fn replacement_rules_fingerprint(
 ) -> String {
     let mut fingerprint = String::new();
     for rule in replacements.iter().filter(|rule| rule.enabled) {
-        fingerprint.push_str("R;i;");
+        fingerprint.push_str(if rule.case_sensitive { "R;c;" } else { "R;i;" });
+        fingerprint.push_str(if rule.whole_word { "w;" } else { "s;" });
         push_fingerprint_field(&mut fingerprint, &rule.from);
         push_fingerprint_field(&mut fingerprint, &rule.to);
         push_fingerprint_option(&mut fingerprint, rule.language.as_deref());
@@ -667,9 +816,7 @@ fn compiled_replacement_rules(
 
     let mut compiled = Vec::new();
     for rule in replacements.iter().filter(|rule| rule.enabled) {
-        let Ok(regex) = RegexBuilder::new(&regex::escape(&rule.from))
-            .case_insensitive(true)
-            .build()
+        let Some(regex) = replacement_regex(&rule.from, rule.case_sensitive, rule.whole_word)
         else {
             continue;
         };
@@ -681,6 +828,7 @@ fn compiled_replacement_rules(
             source_form: rule.from.clone(),
             source_kind: LibraryRuleSourceKind::ExplicitReplacement,
             language_scope: normalize_language_scope(rule.language.as_deref()),
+            whole_word: rule.whole_word,
         });
     }
     for word in custom_words.iter().filter(|word| word.enabled) {
@@ -701,6 +849,7 @@ fn compiled_replacement_rules(
             source_form: spoken_form.to_string(),
             source_kind: LibraryRuleSourceKind::CustomWordSpokenForm,
             language_scope: normalize_language_scope(word.language.as_deref()),
+            whole_word: true,
         });
     }
 
@@ -765,57 +914,21 @@ fn compiled_voice_command_rules(rules: &[VoiceCommandRule]) -> Arc<Vec<CompiledV
     compiled
 }
 
-#[derive(Clone)]
-struct ReplacementCandidate {
-    start: usize,
-    end: usize,
-    replacement: String,
-    detail: String,
-    priority: u8,
-    source_form: String,
-    source_kind: LibraryRuleSourceKind,
-    language_scope: Option<String>,
-}
-
-fn collect_replacement_candidates(
-    text: &str,
-    replacements: &[TextReplacementRule],
-    custom_words: &[CustomWord],
-    transcript_language: Option<&str>,
-) -> Vec<ReplacementCandidate> {
-    let compiled = compiled_replacement_rules(replacements, custom_words);
-    let mut candidates = Vec::new();
+impl ReplacementRule for CompiledReplacementRule {
+    fn find(&self, text: &str) -> Vec<(usize, usize)> {
+        literal_matches(&self.regex, self.whole_word, text)
+            .into_iter()
+            .filter(|&(start, end)| !span_in_protected_token(text, start, end))
+            .collect()
+    }
 
-    for rule in compiled
-        .iter()
-        .filter(|rule| language_scope_matches(rule.language_scope.as_deref(), transcript_language))
-    {
-        for mat in rule.regex.find_iter(text) {
-            if !candidate_has_boundaries(text, mat.start(), mat.end())
-                || span_in_protected_token(text, mat.start(), mat.end())
-            {
-                continue;
-            }
-            candidates.push(ReplacementCandidate {
-                start: mat.start(),
-                end: mat.end(),
-                replacement: rule.replacement.clone(),
-                detail: rule.detail.clone(),
-                priority: rule.priority,
-                source_form: rule.source_form.clone(),
-                source_kind: rule.source_kind,
-                language_scope: rule.language_scope.clone(),
-            });
-        }
+    fn replacement(&self) -> &str {
+        &self.replacement
     }
 
-    candidates.sort_by(|left, right| {
-        left.start
-            .cmp(&right.start)
-            .then(right.priority.cmp(&left.priority))
-            .then((right.end - right.start).cmp(&(left.end - left.start)))
-    });
-    candidates
+    fn priority(&self) -> u8 {
+        self.priority
+    }
 }
 
 struct TextReplacementResult {
@@ -830,64 +943,35 @@ fn apply_text_replacements_with_provenance(
     custom_words: &[CustomWord],
     transcript_language: Option<&str>,
 ) -> TextReplacementResult {
-    let candidates =
-        collect_replacement_candidates(text, replacements, custom_words, transcript_language);
-    if candidates.is_empty() {
-        return TextReplacementResult {
-            text: text.to_string(),
-            operations: Vec::new(),
-            provenance: Vec::new(),
-        };
-    }
-
-    let mut selected = Vec::new();
-    let mut cursor = 0usize;
-    for candidate in candidates {
-        if candidate.start < cursor {
-            continue;
-        }
-        cursor = candidate.end;
-        selected.push(candidate);
-    }
-
-    if selected.is_empty() {
-        return TextReplacementResult {
-            text: text.to_string(),
-            operations: Vec::new(),
-            provenance: Vec::new(),
-        };
-    }
-
-    let mut output = String::with_capacity(text.len());
-    let mut operations = Vec::with_capacity(selected.len());
-    let mut provenance = Vec::with_capacity(selected.len());
-    let mut last = 0usize;
+    let compiled = compiled_replacement_rules(replacements, custom_words);
+    let rules: Vec<&CompiledReplacementRule> = compiled
+        .iter()
+        .filter(|rule| language_scope_matches(rule.language_scope.as_deref(), transcript_language))
+        .collect();
+    let replaced = apply_replacements(text, &rules);
 
-    for candidate in selected {
-        output.push_str(&text[last..candidate.start]);
-        let target_start = output.len();
-        output.push_str(&candidate.replacement);
-        let target_end = output.len();
+    let mut operations = Vec::with_capacity(replaced.applied.len());
+    let mut provenance = Vec::with_capacity(replaced.applied.len());
+    for applied in replaced.applied {
+        let rule = rules[applied.rule];
         operations.push(AppliedWritingOperation {
             kind: WritingOperationKind::Replacement,
-            detail: candidate.detail,
+            detail: rule.detail.clone(),
         });
         provenance.push(LibraryRuleApplication {
-            source_form: candidate.source_form,
-            target_text: candidate.replacement,
-            source_kind: candidate.source_kind,
-            language_scope: candidate.language_scope,
-            start: candidate.start,
-            end: candidate.end,
-            target_start,
-            target_end,
+            source_form: rule.source_form.clone(),
+            target_text: rule.replacement.clone(),
+            source_kind: rule.source_kind,
+            language_scope: rule.language_scope.clone(),
+            start: applied.start,
+            end: applied.end,
+            target_start: applied.target_start,
+            target_end: applied.target_end,
         });
-        last = candidate.end;
     }
-    output.push_str(&text[last..]);
 
     TextReplacementResult {
-        text: output,
+        text: replaced.text,
         operations,
         provenance,
     }
@@ -925,12 +1009,6 @@ fn match_snippet<'a>(
         .max_by_key(|snippet| snippet.trigger.len())
 }
 
-#[derive(Clone, Copy)]
-enum VoiceCommandOutput {
-    Punctuation(&'static str),
-    Break(&'static str),
-}
-
 #[derive(Clone)]
 struct VoiceCommandCandidate {
     start: usize,
@@ -939,21 +1017,6 @@ struct VoiceCommandCandidate {
     output: VoiceCommandOutput,
 }
 
-fn voice_command_output(token: &str) -> Option<VoiceCommandOutput> {
-    match token {
-        "comma" => Some(VoiceCommandOutput::Punctuation(",")),
-        "period" => Some(VoiceCommandOutput::Punctuation(".")),
-        "question_mark" => Some(VoiceCommandOutput::Punctuation("?")),
-        "exclamation_mark" => Some(VoiceCommandOutput::Punctuation("!")),
-        "colon" => Some(VoiceCommandOutput::Punctuation(":")),
-        "semicolon" => Some(VoiceCommandOutput::Punctuation(";")),
-        "dash" => Some(VoiceCommandOutput::Punctuation("\u{2014}")),
-        "new_line" => Some(VoiceCommandOutput::Break("\n")),
-        "paragraph" => Some(VoiceCommandOutput::Break("\n\n")),
-        _ => None,
-    }
-}
-
 fn spans_overlap(left_start: usize, left_end: usize, right_start: usize, right_end: usize) -> bool {
     left_start < right_end && right_start < left_end
 }
@@ -979,7 +1042,7 @@ fn collect_voice_command_candidates(
         .filter(|rule| language_scope_matches(rule.language_scope.as_deref(), transcript_language))
     {
         for mat in rule.regex.find_iter(text) {
-            if candidate_has_boundaries(text, mat.start(), mat.end())
+            if has_word_boundaries(text, mat.start(), mat.end())
                 && !span_in_protected_token(text, mat.start(), mat.end())
                 && !protected_span_contains(protected_spans, mat.start(), mat.end())
             {
@@ -1196,7 +1259,7 @@ fn apply_voice_command_stage(
     transcript_language: Option<&str>,
     applied_operations: &mut Vec<AppliedWritingOperation>,
 ) {
-    if library_result.literal_locked {
+    if library_result.literal_locked || !settings.voice_commands_enabled {
         return;
     }
 
@@ -1216,6 +1279,167 @@ fn apply_voice_command_stage(
     applied_operations.extend(result.operations);
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CodePiece {
+    Word(String),
+    Symbol(String, SymbolSpacing),
+}
+
+const CODE_NUMBER_WORDS: [&str; 11] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+];
+
+fn is_titlecase_word(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next().is_some_and(|first| first.is_uppercase())
+        && chars.clone().next().is_some()
+        && chars.all(|ch| ch.is_lowercase())
+}
+
+/// Undo the prose shaping speech engines apply: sentence punctuation glued to
+/// words is dropped (symbols come from the spoken vocabulary instead), words
+/// capitalized only for starting a sentence are lowercased, and small number
+/// words become digits.
+fn strip_prose_shaping(text: &str) -> String {
+    let mut words = Vec::new();
+    let mut sentence_start = true;
+    for raw in text.split_whitespace() {
+        let word = raw.trim_end_matches(['.', ',', '!', '?', ';', ':']);
+        let ended_sentence = raw[word.len()..].contains(['.', '!', '?']);
+        if !word.is_empty() {
+            let lower = word.to_lowercase();
+            if let Some(digit) = CODE_NUMBER_WORDS.iter().position(|w| *w == lower) {
+                words.push(digit.to_string());
+            } else if sentence_start && is_titlecase_word(word) {
+                words.push(lower);
+            } else {
+                words.push(word.to_string());
+            }
+            sentence_start = false;
+        }
+        if ended_sentence {
+            sentence_start = true;
+        }
+    }
+    words.join(" ")
+}
+
+fn code_symbol_regex(rules: &[&SpokenSymbolRule]) -> Option<Regex> {
+    let mut phrases: Vec<String> = rules
+        .iter()
+        .map(|rule| {
+            rule.phrase
+                .split_whitespace()
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join(r"\s+")
+        })
+        .filter(|pattern| !pattern.is_empty())
+        .collect();
+    if phrases.is_empty() {
+        return None;
+    }
+    // Longest first so "equals equals" wins over "equals".
+    phrases.sort_by_key(|pattern| std::cmp::Reverse(pattern.len()));
+    RegexBuilder::new(&format!(r"\b(?:{})\b", phrases.join("|")))
+        .case_insensitive(true)
+        .build()
+        .ok()
+}
+
+fn space_before_code_piece(previous: Option<&CodePiece>, current: &CodePiece) -> bool {
+    use SymbolSpacing::*;
+    let Some(previous) = previous else {
+        return false;
+    };
+    if matches!(previous, CodePiece::Symbol(_, Tight | AttachRight)) {
+        return false;
+    }
+    match current {
+        CodePiece::Symbol(_, Tight | AttachLeft) => false,
+        CodePiece::Symbol(_, AttachRight) => {
+            matches!(previous, CodePiece::Symbol(_, Spaced | AttachLeft))
+        }
+        _ => true,
+    }
+}
+
+fn push_code_words(chunk: &str, pieces: &mut Vec<CodePiece>) {
+    pieces.extend(
+        chunk
+            .split_whitespace()
+            .map(|word| CodePiece::Word(word.to_string())),
+    );
+}
+
+/// Format dictated code: map the spoken-symbol vocabulary to characters and
+/// join the result using each symbol's spacing.
+pub(crate) fn format_code_dictation(text: &str, symbols: &[SpokenSymbolRule]) -> String {
+    let text = strip_prose_shaping(text);
+    let enabled: Vec<&SpokenSymbolRule> = symbols.iter().filter(|rule| rule.enabled).collect();
+    let mut pieces = Vec::new();
+
+    match code_symbol_regex(&enabled) {
+        Some(regex) => {
+            let mut last = 0usize;
+            for found in regex.find_iter(&text) {
+                push_code_words(&text[last..found.start()], &mut pieces);
+                let spoken = found
+                    .as_str()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .to_lowercase();
+                match enabled
+                    .iter()
+                    .find(|rule| rule.phrase.to_lowercase() == spoken)
+                {
+                    Some(rule) => pieces.push(CodePiece::Symbol(rule.symbol.clone(), rule.spacing)),
+                    None => push_code_words(found.as_str(), &mut pieces),
+                }
+                last = found.end();
+            }
+            push_code_words(&text[last..], &mut pieces);
+        }
+        None => push_code_words(&text, &mut pieces),
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut previous: Option<&CodePiece> = None;
+    for piece in &pieces {
+        if space_before_code_piece(previous, piece) {
+            output.push(' ');
+        }
+        match piece {
+            CodePiece::Word(word) => output.push_str(word),
+            CodePiece::Symbol(symbol, _) => output.push_str(symbol),
+        }
+        previous = Some(piece);
+    }
+    output
+}
+
+fn apply_code_mode_stage(
+    library_result: &mut LibraryRulesResult,
+    settings: &WritingSettings,
+    applied_operations: &mut Vec<AppliedWritingOperation>,
+) {
+    if library_result.literal_locked {
+        return;
+    }
+
+    let formatted = format_code_dictation(&library_result.text, &settings.code_mode.symbols);
+    if formatted != library_result.text {
+        library_result.text = formatted;
+        // Spans recorded by earlier stages no longer line up with the rewritten text.
+        library_result.provenance.clear();
+        applied_operations.push(AppliedWritingOperation {
+            kind: WritingOperationKind::CodeFormatting,
+            detail: "Applied code mode symbol formatting".to_string(),
+        });
+    }
+}
+
 fn capture_active_app_context(should_capture: bool) -> Option<ContextHint> {
     if !should_capture {
         return None;
@@ -1382,6 +1606,26 @@ pub fn compile_context_for_target(
     (!context.is_empty()).then_some(context)
 }
 
+/// Whisper's initial prompt: the user's priming text first, so it survives
+/// truncation, then the dictionary context, within the same byte budget as
+/// the dictionary context alone.
+pub fn combine_whisper_initial_prompt(
+    user_prompt: &str,
+    dictionary_context: Option<String>,
+) -> Option<String> {
+    let user_prompt = user_prompt.trim();
+    let combined = match dictionary_context {
+        Some(context) if !user_prompt.is_empty() => format!("{} {}", user_prompt, context),
+        Some(context) => context,
+        None => user_prompt.to_string(),
+    };
+    let max_bytes = ProviderContextTarget::WhisperInitialPrompt
+        .capabilities()
+        .max_bytes;
+    let combined = truncate_at_char_boundary(combined, max_bytes);
+    (!combined.is_empty()).then_some(combined)
+}
+
 fn strip_nul_bytes(value: &str) -> String {
     value.chars().filter(|ch| *ch != '\0').collect()
 }
@@ -1725,7 +1969,7 @@ fn collect_final_guard_candidates(
         let mut exact: Option<(usize, usize)> = None;
         let mut shifted: Option<(usize, usize)> = None;
         for mat in regex.find_iter(text) {
-            if !candidate_has_boundaries(text, mat.start(), mat.end())
+            if !has_word_boundaries(text, mat.start(), mat.end())
                 || span_in_protected_token(text, mat.start(), mat.end())
             {
                 continue;
@@ -1840,6 +2084,82 @@ fn smart_formatting_ai_context(
     )
 }
 
+/// Only transcriptions from the last few minutes count as the current dictation session.
+const RECENT_CONTEXT_MAX_AGE_MINUTES: i64 = 30;
+
+/// Pick the texts of the newest `count` completed history rows recorded within the
+/// session window, returned oldest first so the prompt reads chronologically.
+fn select_recent_context_texts(
+    entries: &[(String, serde_json::Value)],
+    count: usize,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Vec<String> {
+    let mut recent: Vec<(chrono::DateTime<chrono::Utc>, String)> = entries
+        .iter()
+        .filter_map(|(key, entry)| {
+            let timestamp = chrono::DateTime::parse_from_rfc3339(key)
+                .ok()?
+                .with_timezone(&chrono::Utc);
+            let age = now.signed_duration_since(timestamp);
+            if age < chrono::Duration::zero()
+                || age > chrono::Duration::minutes(RECENT_CONTEXT_MAX_AGE_MINUTES)
+            {
+                return None;
+            }
+            let status = entry
+                .get("status")
+                .and_then(|value| value.as_str())
+                .unwrap_or("completed");
+            if matches!(status, "in_progress" | "failed") {
+                return None;
+            }
+            let text = entry.get("text").and_then(|value| value.as_str())?.trim();
+            (!text.is_empty()).then(|| (timestamp, text.to_string()))
+        })
+        .collect();
+
+    recent.sort_by(|left, right| right.0.cmp(&left.0));
+    recent.truncate(count);
+    recent.reverse();
+    recent.into_iter().map(|(_, text)| text).collect()
+}
+
+/// Recent-dictation context for AI formatting, when `use_recent_context` is enabled.
+fn load_recent_ai_context(app: &AppHandle) -> Option<String> {
+    use crate::commands::settings::{
+        DEFAULT_RECENT_CONTEXT_COUNT, MAX_RECENT_CONTEXT_COUNT, MIN_RECENT_CONTEXT_COUNT,
+    };
+
+    let settings_store = app.store("settings").ok()?;
+    let enabled = settings_store
+        .get("use_recent_context")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if !enabled {
+        return None;
+    }
+    let count = settings_store
+        .get("recent_context_count")
+        .and_then(|v| v.as_u64())
+        .map(|v| {
+            v.clamp(
+                MIN_RECENT_CONTEXT_COUNT as u64,
+                MAX_RECENT_CONTEXT_COUNT as u64,
+            )
+        })
+        .unwrap_or(DEFAULT_RECENT_CONTEXT_COUNT as u64) as usize;
+
+    let history = app.store("transcriptions").ok()?;
+    let keys = crate::commands::audio::page_history_keys(history.keys(), count * 2);
+    let entries: Vec<(String, serde_json::Value)> = keys
+        .into_iter()
+        .filter_map(|key| history.get(&key).map(|value| (key, value)))
+        .collect();
+
+    let texts = select_recent_context_texts(&entries, count, chrono::Utc::now());
+    crate::ai::prompts::build_recent_context(&texts, crate::ai::prompts::RECENT_CONTEXT_MAX_BYTES)
+}
+
 struct SmartFormattingRequest<'a> {
     app: AppHandle,
     text: &'a str,
@@ -1855,17 +2175,24 @@ struct SmartFormattingRequest<'a> {
 async fn run_smart_formatting(
     request: SmartFormattingRequest<'_>,
 ) -> Result<String, AiProviderError> {
-    let options = crate::ai::EnhancementOptions {
-        preset: request.profile.mode.into(),
-    };
+    let stored_options =
+        crate::commands::ai::get_enhancement_options_for_ai_enabled(request.app.clone(), true)
+            .await
+            .unwrap_or_default();
+    let options = crate::ai::prompts::effective_enhancement_options(
+        &stored_options,
+        Some(request.profile.mode.into()),
+    );
     let ai_context =
         smart_formatting_ai_context(request.settings, request.transcript_language.as_deref());
+    let recent_context = load_recent_ai_context(&request.app);
     match crate::commands::ai::polish_text_typed(
         &request.app,
         request.text,
         &options,
         Some(request.output_language.as_str()),
         ai_context.as_deref(),
+        recent_context.as_deref(),
     )
     .await
     {
@@ -1940,6 +2267,63 @@ fn resolve_smart_formatting_outcome(
     }
 }
 
+fn load_translate_to_language(app: &AppHandle) -> String {
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get("translate_to_language"))
+        .and_then(|value| value.as_str().map(normalize_translate_to_language))
+        .unwrap_or_default()
+}
+
+/// Optional translation after AI formatting. Skipped when no target is set or
+/// the text is already in it; on failure the untranslated text is kept and a
+/// `TRANSLATION_FAILED_WARNING` is recorded for the pill.
+async fn run_translation_stage<F, Fut>(
+    text: String,
+    source_language: Option<&str>,
+    target_language: &str,
+    translate: F,
+    output_language: &mut String,
+    applied_operations: &mut Vec<AppliedWritingOperation>,
+    warnings: &mut Vec<WritingWarning>,
+) -> String
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<String, AiProviderError>>,
+{
+    if target_language.is_empty() || source_language == Some(target_language) {
+        return text;
+    }
+
+    match translate().await {
+        Ok(translated) if !translated.trim().is_empty() => {
+            applied_operations.push(AppliedWritingOperation {
+                kind: WritingOperationKind::Translation,
+                detail: format!("Translated transcript to {}", target_language),
+            });
+            *output_language = target_language.to_string();
+            translated
+        }
+        result => {
+            let error = result.err().unwrap_or(AiProviderError::BadResponse);
+            log::warn!(
+                "Translation to {} failed: category={}",
+                target_language,
+                user_facing_message(&error)
+            );
+            warnings.push(WritingWarning {
+                code: TRANSLATION_FAILED_WARNING.to_string(),
+                message: format!(
+                    "Translation to {} failed ({}); inserted the untranslated text",
+                    target_language,
+                    user_facing_message(&error)
+                ),
+            });
+            text
+        }
+    }
+}
+
 pub async fn process_transcription(
     app: AppHandle,
     transcription: TranscriptionResult,
@@ -1957,6 +2341,7 @@ pub async fn process_transcription(
             .fallback_transcript_language(transcription.spoken_language.as_deref())
     });
     let mut output_language = resolve_output_language(&profile, &transcription);
+    let translate_to_language = load_translate_to_language(&app);
     let mut applied_operations = Vec::new();
     let mut warnings = Vec::new();
     let cleaned_text = sanitize_transcript(&transcription.raw_text);
@@ -1978,6 +2363,10 @@ pub async fn process_transcription(
         transcript_language.as_deref(),
         &mut applied_operations,
     );
+    let code_mode = resolve_code_mode(&settings, code_mode_session_enabled(), active_app.as_ref());
+    if code_mode {
+        apply_code_mode_stage(&mut library_result, &settings, &mut applied_operations);
+    }
 
     // When the transcript language is known, a transform is needed iff it differs
     // from the configured output language. When it is unknown (engine omitted it,
@@ -1992,19 +2381,21 @@ pub async fn process_transcription(
         None => profile.final_text_language != FINAL_TEXT_LANGUAGE_SAME_AS_TRANSCRIPT,
     };
 
+    // Code mode output is final: neither AI rewriting nor translation may touch it.
+    let literal_output = library_result.literal_locked || code_mode;
     let can_run_ai_formatting = ai_enabled && profile.mode != WritingMode::PersonalDictation;
 
-    if needs_output_language_transform && !can_run_ai_formatting && !library_result.literal_locked {
+    if needs_output_language_transform && !can_run_ai_formatting && !literal_output {
         return Err(WritingError::OutputLanguageRequiresAi);
     }
 
-    if profile.mode.requires_ai_formatting() && !ai_enabled && !library_result.literal_locked {
+    if profile.mode.requires_ai_formatting() && !ai_enabled && !literal_output {
         return Err(WritingError::Config(
             "This writing mode requires AI formatting. Enable AI formatting in settings or switch to Personal Dictation.".into(),
         ));
     }
 
-    let should_run_ai = can_run_ai_formatting && !library_result.literal_locked;
+    let should_run_ai = can_run_ai_formatting && !literal_output;
 
     let mut ai_error = None;
     let mut final_text = if library_result.literal_locked {
@@ -2018,10 +2409,21 @@ pub async fn process_transcription(
             );
         }
         library_result.text.clone()
+    } else if code_mode {
+        if needs_output_language_transform {
+            record_output_language_transform_fallback(
+                &mut warnings,
+                &mut output_language,
+                transcript_language.as_deref(),
+                "code_mode_literal_preserved",
+                "Code mode output preserved; output language was not transformed".to_string(),
+            );
+        }
+        library_result.text.clone()
     } else if should_run_ai {
         let (text, error) = resolve_smart_formatting_outcome(
             run_smart_formatting(SmartFormattingRequest {
-                app,
+                app: app.clone(),
                 text: &library_result.text,
                 transcript_language: transcript_language.clone(),
                 output_language: &mut output_language,
@@ -2052,10 +2454,38 @@ pub async fn process_transcription(
     );
     final_text = guarded_text;
     applied_operations.extend(guard_operations);
+    let ai_applied = should_run_ai && ai_error.is_none() && final_text != library_result.text;
+
+    // Snippets and code mode stay literal, as they do for AI formatting
+    if !literal_output {
+        let source_language = if output_language == FINAL_TEXT_LANGUAGE_SAME_AS_TRANSCRIPT {
+            transcript_language.clone()
+        } else {
+            Some(output_language.clone())
+        };
+        let input = final_text.clone();
+        final_text = run_translation_stage(
+            final_text,
+            source_language.as_deref(),
+            &translate_to_language,
+            || {
+                crate::commands::ai::translate_text_typed(
+                    &app,
+                    &input,
+                    source_language.as_deref(),
+                    &translate_to_language,
+                )
+            },
+            &mut output_language,
+            &mut applied_operations,
+            &mut warnings,
+        )
+        .await;
+    }
 
     Ok(WritingResult {
         raw_text: transcription.raw_text.clone(),
-        ai_applied: should_run_ai && ai_error.is_none() && final_text != library_result.text,
+        ai_applied,
         final_text,
         output_language,
         mode: profile.mode,
@@ -2209,6 +2639,8 @@ mod tests {
                 to: "Voicetypr".to_string(),
                 language: Some("en".to_string()),
                 enabled: true,
+                case_sensitive: false,
+                whole_word: true,
             }],
             snippets: vec![Snippet {
                 trigger: "sig".to_string(),
@@ -2293,6 +2725,67 @@ mod tests {
         assert!(warnings[0].message.contains("timed out"));
     }
 
+    #[tokio::test]
+    async fn test_translation_stage_uses_provider_output_and_falls_back_on_failure() {
+        let mut output_language = "es".to_string();
+        let mut operations = Vec::new();
+        let mut warnings = Vec::new();
+        let translated = run_translation_stage(
+            "Hola a todos".to_string(),
+            Some("es"),
+            "fr",
+            || async { Ok("Bonjour à tous".to_string()) },
+            &mut output_language,
+            &mut operations,
+            &mut warnings,
+        )
+        .await;
+        assert_eq!(translated, "Bonjour à tous");
+        assert_eq!(output_language, "fr");
+        assert_eq!(operations[0].kind, WritingOperationKind::Translation);
+        assert!(warnings.is_empty());
+
+        let mut output_language = "es".to_string();
+        let mut operations = Vec::new();
+        let untranslated = run_translation_stage(
+            "Hola a todos".to_string(),
+            Some("es"),
+            "fr",
+            || async { Err(AiProviderError::Network) },
+            &mut output_language,
+            &mut operations,
+            &mut warnings,
+        )
+        .await;
+        assert_eq!(untranslated, "Hola a todos");
+        assert_eq!(output_language, "es");
+        assert!(operations.is_empty());
+        assert_eq!(warnings[0].code, TRANSLATION_FAILED_WARNING);
+    }
+
+    #[tokio::test]
+    async fn test_translation_stage_skips_without_target_or_matching_source() {
+        let mut output_language = "fr".to_string();
+        let mut operations = Vec::new();
+        let mut warnings = Vec::new();
+        for (source, target) in [(Some("fr"), "fr"), (Some("es"), "")] {
+            let text = run_translation_stage(
+                "Bonjour".to_string(),
+                source,
+                target,
+                // Would record a warning if the provider were called
+                || async { Err(AiProviderError::Internal) },
+                &mut output_language,
+                &mut operations,
+                &mut warnings,
+            )
+            .await;
+            assert_eq!(text, "Bonjour");
+        }
+        assert!(operations.is_empty());
+        assert!(warnings.is_empty());
+    }
+
     #[test]
     fn test_writing_mode_maps_from_presets() {
         assert_eq!(
@@ -2446,6 +2939,8 @@ mod tests {
                     to: " Voicetypr ".to_string(),
                     language: Some(" en ".to_string()),
                     enabled: true,
+                    case_sensitive: false,
+                    whole_word: true,
                 },
                 TextReplacementRule::default(),
             ],
@@ -2492,6 +2987,8 @@ mod tests {
             to: "Voicetypr".to_string(),
             language: Some("en".to_string()),
             enabled: true,
+            case_sensitive: false,
+            whole_word: true,
         }];
         let custom_words = vec![CustomWord {
             phrase: "Voicetypr".to_string(),
@@ -2755,6 +3252,30 @@ mod tests {
         assert!(context.contains("Voicetypr"));
     }
 
+    #[test]
+    fn test_whisper_initial_prompt_puts_user_text_before_dictionary_terms() {
+        assert_eq!(
+            combine_whisper_initial_prompt(
+                " Kubernetes runbook notes. ",
+                Some("Preferred spellings: kubectl.".to_string()),
+            )
+            .as_deref(),
+            Some("Kubernetes runbook notes. Preferred spellings: kubectl.")
+        );
+        assert_eq!(
+            combine_whisper_initial_prompt("", Some("Preferred spellings: kubectl.".to_string()))
+                .as_deref(),
+            Some("Preferred spellings: kubectl.")
+        );
+        assert_eq!(combine_whisper_initial_prompt("  ", None), None);
+
+        let long_dictionary = format!("Preferred spellings: {}.", "term, ".repeat(400));
+        let combined =
+            combine_whisper_initial_prompt("Use British spelling.", Some(long_dictionary)).unwrap();
+        assert!(combined.starts_with("Use British spelling. Preferred spellings:"));
+        assert!(combined.len() <= 900);
+    }
+
     #[test]
     fn test_phrase_only_custom_word_does_not_replace_text() {
         let (text, ops) = apply_text_replacements(
@@ -2780,6 +3301,8 @@ mod tests {
             to: "React".to_string(),
             language: Some("en".to_string()),
             enabled: true,
+            case_sensitive: false,
+            whole_word: true,
         }];
 
         let (identifier_text, identifier_ops) =
@@ -2793,6 +3316,32 @@ mod tests {
         assert!(language_ops.is_empty());
     }
 
+    fn replacement_rule(from: &str, to: &str) -> TextReplacementRule {
+        TextReplacementRule {
+            from: from.to_string(),
+            to: to.to_string(),
+            language: None,
+            enabled: true,
+            case_sensitive: false,
+            whole_word: true,
+        }
+    }
+
+    #[test]
+    fn test_compiled_replacement_rules_skip_disabled_and_empty_rules() {
+        let replacements = [
+            TextReplacementRule {
+                enabled: false,
+                ..replacement_rule("hello", "bye")
+            },
+            replacement_rule("", "x"),
+            replacement_rule("hi", "Hi"),
+        ];
+        let compiled = compiled_replacement_rules(&replacements, &[]);
+        assert_eq!(compiled.len(), 1);
+        assert_eq!(compiled[0].source_form, "hi");
+    }
+
     #[test]
     fn test_replacements_protect_word_tokens_urls_and_emails() {
         let replacements = vec![TextReplacementRule {
@@ -2800,6 +3349,8 @@ mod tests {
             to: "bar".to_string(),
             language: Some("en".to_string()),
             enabled: true,
+            case_sensitive: false,
+            whole_word: true,
         }];
 
         let (protected_text, protected_ops) = apply_text_replacements(
@@ -2860,6 +3411,8 @@ mod tests {
                 to: "Voicetypr".to_string(),
                 language: Some("en".to_string()),
                 enabled: true,
+                case_sensitive: false,
+                whole_word: true,
             }],
             custom_words: vec![
                 CustomWord {
@@ -2935,6 +3488,8 @@ mod tests {
                 to: "Voicetypr".to_string(),
                 language: Some("en".to_string()),
                 enabled: true,
+                case_sensitive: false,
+                whole_word: true,
             }],
             &[CustomWord {
                 phrase: "React".to_string(),
@@ -2981,6 +3536,8 @@ mod tests {
                 to: "Voicetypr".to_string(),
                 language: Some("en".to_string()),
                 enabled: true,
+                case_sensitive: false,
+                whole_word: true,
             }],
             &[
                 CustomWord {
@@ -3022,6 +3579,8 @@ mod tests {
                 to: "Voicetypr".to_string(),
                 language: Some("en".to_string()),
                 enabled: true,
+                case_sensitive: false,
+                whole_word: true,
             }],
             &[],
             Some("en"),
@@ -3425,6 +3984,28 @@ mod tests {
         assert!(ops.is_empty());
     }
 
+    #[test]
+    fn test_voice_command_stage_respects_disabled_setting() {
+        let mut result = LibraryRulesResult {
+            text: "first line new line second insert comma".to_string(),
+            literal_locked: false,
+            provenance: Vec::new(),
+        };
+        let mut ops = Vec::new();
+        let settings = WritingSettings {
+            voice_commands_enabled: false,
+            ..WritingSettings::default()
+        };
+
+        apply_voice_command_stage(&mut result, &settings, Some("en"), &mut ops);
+
+        assert_eq!(result.text, "first line new line second insert comma");
+        assert!(ops.is_empty());
+
+        let legacy: WritingSettings = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert!(legacy.voice_commands_enabled);
+    }
+
     #[test]
     fn test_voice_command_stage_protects_library_outputs() {
         let mut result = LibraryRulesResult {
@@ -3533,6 +4114,8 @@ mod tests {
                 to: "React".to_string(),
                 language: Some("en".to_string()),
                 enabled: true,
+                case_sensitive: false,
+                whole_word: true,
             }],
             ..WritingSettings::default()
         };
@@ -3562,6 +4145,8 @@ mod tests {
                 to: "Voicetypr".to_string(),
                 language: Some("en".to_string()),
                 enabled: true,
+                case_sensitive: false,
+                whole_word: true,
             }],
             ..WritingSettings::default()
         };
@@ -3615,6 +4200,8 @@ mod tests {
                 to: "Voice\0typr".to_string(),
                 language: Some("en".to_string()),
                 enabled: true,
+                case_sensitive: false,
+                whole_word: true,
             }],
             ..WritingSettings::default()
         };
@@ -3663,6 +4250,8 @@ mod tests {
                 to: "Voicetypr".to_string(),
                 language: Some("en".to_string()),
                 enabled: true,
+                case_sensitive: false,
+                whole_word: true,
             }],
             ..WritingSettings::default()
         };
@@ -3884,4 +4473,151 @@ mod tests {
             EnhancementPreset::PersonalDictation
         );
     }
+
+    #[test]
+    fn test_select_recent_context_texts_keeps_newest_completed_rows_in_order() {
+        let now = chrono::DateTime::parse_from_rfc3339("2026-05-23T10:30:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let entries = vec![
+            (
+                "2026-05-23T09:00:00Z".to_string(),
+                serde_json::json!({ "text": "Too old for this session" }),
+            ),
+            (
+                "2026-05-23T10:10:00Z".to_string(),
+                serde_json::json!({ "text": "First Kubernetes note" }),
+            ),
+            (
+                "2026-05-23T10:20:00Z".to_string(),
+                serde_json::json!({ "text": "Failed row", "status": "failed" }),
+            ),
+            (
+                "2026-05-23T10:25:00Z".to_string(),
+                serde_json::json!({ "text": "Second kubectl note", "status": "completed" }),
+            ),
+            (
+                "2026-05-23T10:28:00Z".to_string(),
+                serde_json::json!({ "text": "   " }),
+            ),
+        ];
+
+        assert_eq!(
+            select_recent_context_texts(&entries, 2, now),
+            vec![
+                "First Kubernetes note".to_string(),
+                "Second kubectl note".to_string()
+            ]
+        );
+        assert_eq!(
+            select_recent_context_texts(&entries, 1, now),
+            vec!["Second kubectl note".to_string()]
+        );
+    }
+
+    fn code(text: &str) -> String {
+        format_code_dictation(text, &default_code_symbols())
+    }
+
+    #[test]
+    fn code_mode_maps_common_programming_symbols() {
+        assert_eq!(
+            code("print open paren x comma y close paren semicolon"),
+            "print(x, y);"
+        );
+        assert_eq!(
+            code("items equals open bracket one comma two close bracket"),
+            "items = [1, 2]"
+        );
+        assert_eq!(code("self dot user underscore name"), "self.user_name");
+        assert_eq!(code("if x equals equals three colon"), "if x == 3:");
+        assert_eq!(code("a not equals b and and c or or d"), "a != b && c || d");
+        assert_eq!(
+            code("fn main open paren close paren arrow i32 open brace"),
+            "fn main() -> i32 {"
+        );
+        assert_eq!(code("std double colon fs"), "std::fs");
+        assert_eq!(code("total plus one times rate"), "total + 1 * rate");
+        assert_eq!(code("f open paren x close paren dot len"), "f(x).len");
+    }
+
+    #[test]
+    fn code_mode_strips_prose_capitalization_and_punctuation() {
+        assert_eq!(code("Count equals zero."), "count = 0");
+        assert_eq!(
+            code("Value equals one. Next equals two"),
+            "value = 1 next = 2"
+        );
+        // Identifiers with inner capitals are not prose capitalization.
+        assert_eq!(code("MyClass dot New"), "MyClass.New");
+        // Decimal points inside tokens are kept.
+        assert_eq!(code("pi equals 3.14"), "pi = 3.14");
+    }
+
+    #[test]
+    fn code_mode_matches_phrases_case_insensitively_and_skips_disabled_rules() {
+        let mut symbols = default_code_symbols();
+        assert_eq!(format_code_dictation("x Semicolon", &symbols), "x;");
+        for rule in symbols.iter_mut().filter(|rule| rule.phrase == "semicolon") {
+            rule.enabled = false;
+        }
+        assert_eq!(
+            format_code_dictation("x semicolon", &symbols),
+            "x semicolon"
+        );
+        assert_eq!(format_code_dictation("x semicolon", &[]), "x semicolon");
+    }
+
+    #[test]
+    fn code_mode_resolves_from_session_or_app_rules() {
+        let settings = WritingSettings {
+            code_mode: CodeModeSettings {
+                apps: vec!["Code".to_string()],
+                ..CodeModeSettings::default()
+            },
+            ..WritingSettings::default()
+        };
+        let vscode = ContextHint {
+            app_name: Some("Visual Studio Code".to_string()),
+        };
+        let slack = ContextHint {
+            app_name: Some("Slack".to_string()),
+        };
+
+        assert!(resolve_code_mode(&settings, false, Some(&vscode)));
+        assert!(!resolve_code_mode(&settings, false, Some(&slack)));
+        assert!(!resolve_code_mode(&settings, false, None));
+        assert!(resolve_code_mode(&settings, true, Some(&slack)));
+        assert!(app_rules_need_active_app(&settings));
+    }
+
+    #[test]
+    fn code_mode_settings_default_when_absent_and_sanitize_drops_blank_rules() {
+        let parsed: WritingSettings = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert_eq!(parsed.code_mode, CodeModeSettings::default());
+
+        let settings = sanitize_writing_settings(WritingSettings {
+            code_mode: CodeModeSettings {
+                apps: vec!["  ".to_string(), " Xcode ".to_string()],
+                symbols: vec![
+                    SpokenSymbolRule {
+                        phrase: "  open   paren ".to_string(),
+                        symbol: "(".to_string(),
+                        spacing: SymbolSpacing::Tight,
+                        enabled: true,
+                    },
+                    SpokenSymbolRule {
+                        phrase: "empty".to_string(),
+                        symbol: " ".to_string(),
+                        spacing: SymbolSpacing::Tight,
+                        enabled: true,
+                    },
+                ],
+            },
+            ..WritingSettings::default()
+        });
+        assert_eq!(settings.code_mode.apps, vec!["Xcode".to_string()]);
+        assert_eq!(settings.code_mode.symbols.len(), 1);
+        assert_eq!(settings.code_mode.symbols[0].phrase, "open paren");
+    }
 }
@@ -65,13 +65,6 @@ export function AppContainer() {
 
     const init = async () => {
       try {
-        // Run cleanup if enabled
-        if (settings?.transcription_cleanup_days) {
-          await invoke("cleanup_old_transcriptions", {
-            days: settings.transcription_cleanup_days
-          });
-        }
-
         // Initialize update service for automatic update checks
         if (settings) {
           await updateService.initialize(settings);
@@ -225,6 +218,33 @@ export function AppContainer() {
             duration: 8000
           });
         });
+
+        await register<{
+          provider: string;
+          reason: "key_invalid" | "network_down";
+          fallback: { engine: string; model: string } | null;
+          title?: string;
+          message?: string;
+        }>("cloud-key-check", (data) => {
+          log.warn("Cloud key pre-flight did not pass:", data);
+          if (data.reason === "network_down") {
+            toast.warning("Soniox Unreachable", {
+              description: "Couldn't confirm your Soniox key. Check your connection.",
+              duration: 5000
+            });
+          } else if (data.fallback) {
+            toast.warning("Soniox Key Invalid", {
+              description: `Recording with local model ${data.fallback.model}. Update your key in Models.`,
+              duration: 6000
+            });
+          } else {
+            setActiveSection("models");
+            toast.error(data.title || "Soniox Key Invalid", {
+              description: data.message || "Update your Soniox key in Models before recording.",
+              duration: 8000
+            });
+          }
+        });
       } catch (error) {
         log.error("Failed to register app event listeners:", error);
       }
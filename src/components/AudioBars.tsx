@@ -1,6 +1,6 @@
 import type { CSSProperties } from "react";
 
-type BarState = "idle" | "listening" | "transcribing" | "formatting";
+type BarState = "idle" | "listening" | "paused" | "transcribing" | "formatting";
 
 interface AudioBarsProps {
   state: BarState;
@@ -67,7 +67,8 @@ function getWaveStyle(state: BarState, envelope: number, level: number, index: n
 }
 
 export function AudioBars({ state, audioLevel = 0 }: AudioBarsProps) {
-  const isIdle = state === "idle";
+  // Paused recordings keep the still silhouette, dimmed further.
+  const isIdle = state === "idle" || state === "paused";
   const isFormatting = state === "formatting";
   const level = Math.max(0, Math.min(1, audioLevel));
   const color = isFormatting ? SAGE_BRIGHT : SAGE;
@@ -93,7 +94,7 @@ export function AudioBars({ state, audioLevel = 0 }: AudioBarsProps) {
             <span
               key={index}
               className="rounded-full"
-              style={{ ...base, transform: `scaleY(${(REST_SCALE + envelope * 0.12).toFixed(3)})`, opacity: 0.55 }}
+              style={{ ...base, transform: `scaleY(${(REST_SCALE + envelope * 0.12).toFixed(3)})`, opacity: state === "paused" ? 0.3 : 0.55 }}
             />
           );
         }
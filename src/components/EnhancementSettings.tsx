@@ -269,7 +269,14 @@ function ReplacementEditor({
           onClick={() =>
             onChange([
               ...replacements,
-              { from: "", to: "", language: null, enabled: true },
+              {
+                from: "",
+                to: "",
+                language: null,
+                enabled: true,
+                case_sensitive: false,
+                whole_word: true,
+              },
             ])
           }
         >
@@ -378,6 +385,32 @@ function ReplacementEditor({
                     />
                   </InputGroup>
                 </Field>
+                <div className="flex flex-wrap items-center gap-4">
+                  <div className="flex items-center gap-2">
+                    <Switch
+                      checked={rule.case_sensitive ?? false}
+                      disabled={disabled}
+                      onCheckedChange={(checked) =>
+                        onChange(
+                          updateItem(replacements, index, { ...rule, case_sensitive: checked }),
+                        )
+                      }
+                    />
+                    <span className="text-xs text-muted-foreground">Match case</span>
+                  </div>
+                  <div className="flex items-center gap-2">
+                    <Switch
+                      checked={rule.whole_word ?? true}
+                      disabled={disabled}
+                      onCheckedChange={(checked) =>
+                        onChange(
+                          updateItem(replacements, index, { ...rule, whole_word: checked }),
+                        )
+                      }
+                    />
+                    <span className="text-xs text-muted-foreground">Whole word</span>
+                  </div>
+                </div>
               </FieldGroup>
             </FieldSet>
           ))}
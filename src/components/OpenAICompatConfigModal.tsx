@@ -15,6 +15,8 @@ import { invoke } from "@tauri-apps/api/core";
 
 interface OpenAICompatConfigModalProps {
   isOpen: boolean;
+  // "ollama" configures a local Ollama server, which needs no API key
+  variant?: "custom" | "ollama";
   defaultBaseUrl?: string;
   defaultModel?: string;
   onClose: () => void;
@@ -23,6 +25,7 @@ interface OpenAICompatConfigModalProps {
 
 export function OpenAICompatConfigModal({
   isOpen,
+  variant = "custom",
   defaultBaseUrl = "https://api.openai.com/v1",
   defaultModel = "",
   onClose,
@@ -35,6 +38,7 @@ export function OpenAICompatConfigModal({
   const [testing, setTesting] = useState(false);
   const [testResult, setTestResult] = useState<null | { ok: boolean; message: string }>(null);
   const [testedPayload, setTestedPayload] = useState<null | { baseUrl: string; model: string; apiKey: string }>(null);
+  const isOllama = variant === "ollama";
   const testOk = useMemo(() => testResult?.ok === true, [testResult]);
   const inputsMatchTest = useMemo(() => {
     if (!testedPayload) return false;
@@ -107,9 +111,11 @@ export function OpenAICompatConfigModal({
       <DialogContent className="sm:max-w-[520px]">
         <form onSubmit={handleSubmit}>
           <DialogHeader>
-            <DialogTitle>Configure OpenAI-Compatible Provider</DialogTitle>
+            <DialogTitle>{isOllama ? "Configure Ollama" : "Configure OpenAI-Compatible Provider"}</DialogTitle>
             <DialogDescription>
-              Set the API base URL, model ID, and optional API key for any OpenAI-compatible endpoint.
+              {isOllama
+                ? "Point VoiceTypr at your local Ollama server and enter the name of a model you have pulled."
+                : "Set the API base URL, model ID, and optional API key for any OpenAI-compatible endpoint."}
             </DialogDescription>
           </DialogHeader>
 
@@ -131,22 +137,24 @@ export function OpenAICompatConfigModal({
               <Label htmlFor="model">Model ID</Label>
               <Input
                 id="model"
-                placeholder="e.g. gpt-5-nano, gpt-5-mini"
+                placeholder={isOllama ? "e.g. llama3.1, qwen2.5" : "e.g. gpt-5-nano, gpt-5-mini"}
                 value={model}
                 onChange={(e) => setModel(e.target.value)}
               />
             </div>
 
-            <div className="grid gap-2">
-              <Label htmlFor="apiKey">API Key</Label>
-              <Input
-                id="apiKey"
-                type="password"
-                placeholder="Leave empty for no authentication"
-                value={apiKey}
-                onChange={(e) => setApiKey(e.target.value)}
-              />
-            </div>
+            {!isOllama && (
+              <div className="grid gap-2">
+                <Label htmlFor="apiKey">API Key</Label>
+                <Input
+                  id="apiKey"
+                  type="password"
+                  placeholder="Leave empty for no authentication"
+                  value={apiKey}
+                  onChange={(e) => setApiKey(e.target.value)}
+                />
+              </div>
+            )}
           </div>
 
           <DialogFooter>
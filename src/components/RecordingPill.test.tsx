@@ -5,7 +5,7 @@ import { RecordingPill } from './RecordingPill';
 
 const { audioBarsMock, mockRecording, mockSettings } = vi.hoisted(() => ({
   audioBarsMock: vi.fn(),
-  mockRecording: { state: 'idle' },
+  mockRecording: { state: 'idle', isPaused: false },
   mockSettings: {
     pill_indicator_mode: 'when_recording',
     pill_indicator_offset: 10,
@@ -37,6 +37,7 @@ describe('RecordingPill', () => {
   beforeEach(() => {
     audioBarsMock.mockClear();
     mockRecording.state = 'idle';
+    mockRecording.isPaused = false;
     mockSettings.pill_indicator_mode = 'when_recording';
   });
 
@@ -98,6 +99,15 @@ describe('RecordingPill', () => {
     expect(screen.getByTestId('audio-bars')).toHaveAttribute('data-state', 'transcribing');
   });
 
+  it('shows paused bars while a recording is paused', () => {
+    mockRecording.state = 'recording';
+    mockRecording.isPaused = true;
+    render(<RecordingPill />);
+
+    expect(screen.getByTestId('audio-bars')).toHaveAttribute('data-state', 'paused');
+    expect(screen.getByTestId('audio-bars')).toHaveAttribute('data-audio-level', '0');
+  });
+
   it('passes through audio levels while listening', () => {
     mockRecording.state = 'recording';
     render(<RecordingPill />);
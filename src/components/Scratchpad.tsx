@@ -0,0 +1,112 @@
+import { Button } from "@/components/ui/button";
+import { createLogger } from "@/lib/logger";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { save } from "@tauri-apps/plugin-dialog";
+import { Copy, Download, Trash2, X } from "lucide-react";
+import { useCallback, useEffect, useRef, useState } from "react";
+
+const log = createLogger("scratchpad");
+
+interface ScratchpadUpdatedPayload {
+  text: string;
+}
+
+export function Scratchpad() {
+  const [text, setText] = useState("");
+  const [status, setStatus] = useState<string | null>(null);
+  const bottomRef = useRef<HTMLDivElement>(null);
+
+  useEffect(() => {
+    invoke<string>("get_scratchpad")
+      .then(setText)
+      .catch((error) => log.error("Failed to load scratchpad:", error));
+
+    const unlisten = listen<ScratchpadUpdatedPayload>("scratchpad-updated", (event) => {
+      setText(event.payload.text);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  useEffect(() => {
+    bottomRef.current?.scrollIntoView({ block: "end" });
+  }, [text]);
+
+  useEffect(() => {
+    if (!status) return;
+    const timer = window.setTimeout(() => setStatus(null), 1500);
+    return () => window.clearTimeout(timer);
+  }, [status]);
+
+  const handleCopy = useCallback(async () => {
+    try {
+      await invoke("copy_scratchpad");
+      setStatus("Copied");
+    } catch (error) {
+      log.error("Failed to copy scratchpad:", error);
+      setStatus("Copy failed");
+    }
+  }, []);
+
+  const handleClear = useCallback(async () => {
+    try {
+      await invoke("clear_scratchpad");
+    } catch (error) {
+      log.error("Failed to clear scratchpad:", error);
+      setStatus("Clear failed");
+    }
+  }, []);
+
+  const handleExport = useCallback(async () => {
+    try {
+      const path = await save({
+        defaultPath: "voicetypr-scratchpad.txt",
+        filters: [{ name: "Text", extensions: ["txt", "md"] }],
+      });
+      if (!path) return;
+      await invoke("export_scratchpad", { path });
+      setStatus("Saved");
+    } catch (error) {
+      log.error("Failed to export scratchpad:", error);
+      setStatus("Export failed");
+    }
+  }, []);
+
+  const handleHide = useCallback(() => {
+    invoke("hide_scratchpad").catch((error) => log.error("Failed to hide scratchpad:", error));
+  }, []);
+
+  const isEmpty = text.trim().length === 0;
+
+  return (
+    <div className="flex h-full flex-col">
+      <div className="flex items-center gap-1 border-b border-border px-2 py-1.5" data-tauri-drag-region>
+        <span className="flex-1 text-xs font-medium text-muted-foreground" data-tauri-drag-region>
+          {status ?? "Scratchpad"}
+        </span>
+        <Button variant="ghost" size="icon-xs" onClick={handleCopy} disabled={isEmpty} title="Copy all">
+          <Copy />
+        </Button>
+        <Button variant="ghost" size="icon-xs" onClick={handleExport} disabled={isEmpty} title="Export">
+          <Download />
+        </Button>
+        <Button variant="ghost" size="icon-xs" onClick={handleClear} disabled={isEmpty} title="Clear">
+          <Trash2 />
+        </Button>
+        <Button variant="ghost" size="icon-xs" onClick={handleHide} title="Hide">
+          <X />
+        </Button>
+      </div>
+      <div className="flex-1 overflow-y-auto px-3 py-2">
+        {isEmpty ? (
+          <p className="text-xs text-muted-foreground">Dictations will collect here.</p>
+        ) : (
+          <p className="whitespace-pre-wrap text-sm select-text">{text}</p>
+        )}
+        <div ref={bottomRef} />
+      </div>
+    </div>
+  );
+}
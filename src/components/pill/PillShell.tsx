@@ -14,8 +14,10 @@ interface PillStatusProps {
 export function PillShell({ children, isActive }: PillShellProps) {
   return (
     <div className="pointer-events-none fixed inset-0 z-50 flex items-center justify-center">
+      {/* Drag anywhere on the pill to move it; children ignore the pointer so the drag region gets the mousedown */}
       <div
-        className={`flex select-none items-center justify-center rounded-full border border-white/10 bg-[#14171c] text-neutral-100 transition-[padding] duration-150 ease-out ${isActive ? "px-2.5 py-1" : "px-2 py-1"}`}
+        data-tauri-drag-region
+        className={`pointer-events-auto flex cursor-grab select-none items-center justify-center rounded-full border border-white/10 bg-[#14171c] text-neutral-100 transition-[padding] duration-150 ease-out [&>*]:pointer-events-none ${isActive ? "px-2.5 py-1" : "px-2 py-1"}`}
       >
         {children}
       </div>
@@ -4,7 +4,7 @@ import type { PillIndicatorMode } from "@/types";
 import { listen } from "@tauri-apps/api/event";
 import { useEffect, useMemo, useState } from "react";
 
-export type PillState = "idle" | "listening" | "transcribing" | "formatting";
+export type PillState = "idle" | "listening" | "paused" | "transcribing" | "formatting";
 
 const FORMATTING_EVENTS = [
   ["enhancing-started", true],
@@ -28,12 +28,14 @@ export function usePillController(): PillControllerState {
 
   const pillState = useMemo<PillState>(() => {
     if (isFormatting) return "formatting";
-    if (recording.state === "recording") return "listening";
+    if (recording.state === "recording") {
+      return recording.isPaused ? "paused" : "listening";
+    }
     if (recording.state === "transcribing" || recording.state === "stopping") {
       return "transcribing";
     }
     return "idle";
-  }, [isFormatting, recording.state]);
+  }, [isFormatting, recording.isPaused, recording.state]);
 
   const isListening = pillState === "listening";
 
@@ -15,7 +15,7 @@ import {
   SettingsPage,
   SettingRow,
 } from "@/components/settings/settings-ui";
-import { useLicense } from "@/contexts/LicenseContext";
+import { offlineGraceMessage, useLicense } from "@/contexts/LicenseContext";
 import { open } from '@tauri-apps/plugin-shell';
 import { ask } from '@tauri-apps/plugin-dialog';
 import {
@@ -23,7 +23,8 @@ import {
   Clock,
   Crown,
   HelpCircle,
-  Shield
+  Shield,
+  WifiOff
 } from "lucide-react";
 import { useState } from 'react';
 import { toast } from 'sonner';
@@ -32,7 +33,7 @@ import { createLogger } from "@/lib/logger";
 const log = createLogger("account");
 
 export function AccountSection() {
-  const { status, isLoading, checkStatus, activateLicense, deactivateLicense, openPurchasePage } = useLicense();
+  const { status, offlineGraceDaysLeft, isLoading, checkStatus, activateLicense, deactivateLicense, openPurchasePage } = useLicense();
   const [licenseKey, setLicenseKey] = useState('');
   const [isActivating, setIsActivating] = useState(false);
 
@@ -224,6 +225,28 @@ export function AccountSection() {
           </div>
         )}
 
+        {/* Offline grace notice while the license can't be re-validated */}
+        {status && offlineGraceDaysLeft !== null && (
+          <div
+            className={`mt-4 rounded-lg border p-4 ${
+              offlineGraceDaysLeft <= 0
+                ? 'border-destructive/20 bg-destructive/10'
+                : 'border-amber-500/20 bg-amber-500/10'
+            }`}
+          >
+            <div className="flex items-start gap-3">
+              <WifiOff
+                className={`mt-0.5 h-4 w-4 ${
+                  offlineGraceDaysLeft <= 0 ? 'text-destructive' : 'text-amber-500'
+                }`}
+              />
+              <p className="text-xs text-muted-foreground">
+                {offlineGraceMessage(offlineGraceDaysLeft)}
+              </p>
+            </div>
+          </div>
+        )}
+
         {/* Trial/Expired notice for unlicensed users */}
         {status && (status.status === 'trial' || status.status === 'expired') && (
           <div className="mt-4 rounded-lg border border-amber-500/20 bg-amber-500/10 p-4">
@@ -52,6 +52,7 @@ export function AudioUploadSection() {
     speakerSegments,
     diarizationError,
     diarized,
+    chunkProgress,
     select,
     clearSelection,
     start,
@@ -409,7 +410,9 @@ export function AudioUploadSection() {
                           {isProcessing ? (
                             <>
                               <Loader2 className="h-4 w-4 mr-2 animate-spin" />
-                              Processing...
+                              {chunkProgress && chunkProgress.total > 1
+                                ? `Transcribing chunk ${Math.min(chunkProgress.done + 1, chunkProgress.total)} of ${chunkProgress.total}...`
+                                : 'Processing...'}
                             </>
                           ) : (
                             <>
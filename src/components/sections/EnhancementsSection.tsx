@@ -115,6 +115,10 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
   const [settingsLoaded, setSettingsLoaded] = useState(false);
   const writingSaveGeneration = useRef(0);
   const enhancementSaveGeneration = useRef(0);
+  // Sampling values have no control here; keep the stored ones on preset saves.
+  const samplingOptionsRef = useRef<
+    Pick<EnhancementOptions, "temperature" | "max_output_tokens">
+  >({});
   const writingSettingsRef = useRef(writingSettings);
   const writingSaveQueueRef = useRef(Promise.resolve());
 
@@ -125,6 +129,10 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
   const loadEnhancementOptions = async (aiEnabled: boolean) => {
     try {
       const options = await invoke<EnhancementOptions>("get_enhancement_options");
+      samplingOptionsRef.current = {
+        temperature: options.temperature,
+        max_output_tokens: options.max_output_tokens,
+      };
       let nextOptions = fromBackendOptions(options, aiEnabled);
       if (!aiEnabled && presetRequiresAiFormatting(nextOptions.preset)) {
         nextOptions = { preset: "PersonalDictation" };
@@ -160,7 +168,10 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
           const keyId = providerId;
           let isConfigured = await hasApiKey(keyId);
 
-          if ((providerId === "custom" || providerId === "openai") && !isConfigured) {
+          if (
+            (providerId === "custom" || providerId === "openai" || providerId === "ollama") &&
+            !isConfigured
+          ) {
             try {
               const providerSettings = normalizeAISettings(
                 await invoke<AISettingsResponse>("get_ai_settings_for_provider", {
@@ -278,7 +289,11 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
       async (event) => {
         let providerStillConfigured = false;
 
-        if (event.payload.provider === "custom" || event.payload.provider === "openai") {
+        if (
+          event.payload.provider === "custom" ||
+          event.payload.provider === "openai" ||
+          event.payload.provider === "ollama"
+        ) {
           try {
             const providerSettings = normalizeAISettings(
               await invoke<AISettingsResponse>("get_ai_settings_for_provider", {
@@ -365,7 +380,7 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
     setEnhancementOptions(nextOptions);
     try {
       await invoke("update_enhancement_options", {
-        options: toBackendOptions(nextOptions),
+        options: { ...toBackendOptions(nextOptions), ...samplingOptionsRef.current },
       });
     } catch (error) {
       if (enhancementSaveGeneration.current === generationAtEnqueue) {
@@ -485,17 +500,17 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
   const handleSetupApiKey = async (providerId: string) => {
     setSelectedProvider(providerId);
 
-    if (providerId === "custom") {
+    if (providerId === "custom" || providerId === "ollama") {
       try {
         const [savedConfig, providerSettingsResponse] = await Promise.all([
-          invoke<{ baseUrl: string }>("get_openai_config"),
+          invoke<{ baseUrl: string }>("get_openai_config", { provider: providerId }),
           invoke<AISettingsResponse>("get_ai_settings_for_provider", {
             provider: providerId,
           }),
         ]);
         const providerSettings = normalizeAISettings(providerSettingsResponse);
         setOpenAIDefaultBaseUrl(savedConfig.baseUrl || "https://api.openai.com/v1");
-        if (providerSettings.model) {
+        if (providerId === "custom" && providerSettings.model) {
           setCustomModelName(providerSettings.model);
         }
       } catch (error) {
@@ -580,7 +595,7 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
   };
 
   const hasAnyValidConfig = Object.values(providerApiKeys).some(Boolean);
-  const isUsingCustomProvider = aiSettings.provider === "custom";
+  const isUsingCustomProvider = aiSettings.provider === "custom" || aiSettings.provider === "ollama";
   const hasSelectedModel = Boolean(
     aiSettings.provider &&
       aiSettings.model &&
@@ -596,10 +611,13 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
       ? "same_as_transcript"
       : storedFinalTextLanguage;
 
-  const activeModelName = isUsingCustomProvider
-    ? customModelName
-    : getModels(aiSettings.provider).find((model) => model.id === aiSettings.model)?.name ||
-      humanizeModelId(aiSettings.model);
+  const activeModelName =
+    aiSettings.provider === "custom"
+      ? customModelName
+      : isUsingCustomProvider
+        ? aiSettings.model
+        : getModels(aiSettings.provider).find((model) => model.id === aiSettings.model)?.name ||
+          humanizeModelId(aiSettings.model);
 
   const visibleProviders = useMemo(
     () => providers.filter((provider) => showAdvancedProviders || provider.status !== "hidden"),
@@ -762,15 +780,17 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
                 const hasKey = providerApiKeys[provider.id] || false;
                 const isCustomActive = Boolean(
                   provider.isCustom &&
-                    aiSettings.provider === "custom" &&
-                    providerApiKeys.custom &&
+                    aiSettings.provider === provider.id &&
+                    providerApiKeys[provider.id] &&
                     aiSettings.enabled,
                 );
                 const isActive = provider.isCustom
                   ? isCustomActive
                   : Boolean(aiSettings.provider === provider.id && aiSettings.enabled);
                 const selectedModel = provider.isCustom
-                  ? aiSettings.modelsByProvider.custom || customModelName || null
+                  ? aiSettings.modelsByProvider[provider.id] ||
+                    (provider.id === "custom" ? customModelName : null) ||
+                    null
                   : aiSettings.modelsByProvider[provider.id] ||
                     (aiSettings.provider === provider.id ? aiSettings.model : null);
                 const models = getModels(provider.id);
@@ -819,9 +839,9 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
                           )}
                         </div>
 
-                        {provider.isCustom && hasKey && customModelName && (
+                        {provider.isCustom && hasKey && selectedModel && (
                           <p className="text-sm text-muted-foreground">
-                            Model: <span className="text-foreground">{customModelName}</span>
+                            Model: <span className="text-foreground">{selectedModel}</span>
                           </p>
                         )}
                         {!hasKey && (
@@ -1017,8 +1037,13 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
 
       <OpenAICompatConfigModal
         isOpen={showOpenAIConfig}
+        variant={selectedProvider === "ollama" ? "ollama" : "custom"}
         defaultBaseUrl={openAIDefaultBaseUrl}
-        defaultModel={customModelName || ""}
+        defaultModel={
+          selectedProvider === "ollama"
+            ? aiSettings.modelsByProvider.ollama || ""
+            : customModelName || ""
+        }
         onClose={() => setShowOpenAIConfig(false)}
         onSubmit={async ({ baseUrl, model, apiKey }) => {
           try {
@@ -1027,6 +1052,38 @@ export function EnhancementsSection({ view = "all" }: { view?: EnhancementsView
             const trimmedModel = model.trim();
             const trimmedKey = apiKey?.trim() || "";
 
+            if (selectedProvider === "ollama") {
+              // Ollama runs locally without a key; only the endpoint and model are stored.
+              await invoke("validate_ai_api_key", {
+                args: { provider: "ollama", baseUrl: trimmedBase, model: trimmedModel, noAuth: true },
+              });
+              await invoke("set_openai_config", {
+                args: { baseUrl: trimmedBase, noAuth: true, provider: "ollama" },
+              });
+              await invoke("update_ai_settings", {
+                enabled: aiSettings.enabled,
+                provider: "ollama",
+                model: trimmedModel,
+              });
+
+              setOpenAIDefaultBaseUrl(trimmedBase);
+              setAISettings((prev) => ({
+                ...prev,
+                provider: "ollama",
+                model: trimmedModel,
+                hasApiKey: true,
+                modelsByProvider: {
+                  ...prev.modelsByProvider,
+                  ollama: trimmedModel,
+                },
+              }));
+              setProviderApiKeys((prev) => ({ ...prev, ollama: true }));
+
+              toast.success("Ollama configured");
+              setShowOpenAIConfig(false);
+              return;
+            }
+
             const existingKey = trimmedKey ? "" : await getApiKey("custom");
             const validationKey = trimmedKey || existingKey || "";
             const noAuth = !validationKey;
@@ -27,7 +27,7 @@ import { useSettings } from "@/contexts/SettingsContext";
 import { updateService } from "@/services/updateService";
 import { isMacOS, isWindows } from "@/lib/platform";
 import { findActivePrimaryBinding, formatPrimaryHotkeyLabel } from "@/lib/shortcut-display";
-import { PillIndicatorMode, PillIndicatorPosition, TranscriptionAcceleration } from "@/types";
+import { InsertionMethod, MediaPauseTestResult, PillIndicatorMode, PillIndicatorPosition, TranscriptionAcceleration } from "@/types";
 import { invoke } from "@tauri-apps/api/core";
 import type { ShortcutBinding, ShortcutSettings } from "@/types/shortcuts";
 import type { AccelerationStatus } from "@/types/acceleration";
@@ -134,6 +134,7 @@ export function GeneralSettings() {
   const [accelerationStatus, setAccelerationStatus] =
     useState<AccelerationStatus | null>(null);
   const [testingAcceleration, setTestingAcceleration] = useState(false);
+  const [testingMediaPause, setTestingMediaPause] = useState(false);
 
   useEffect(() => {
     const checkAutostart = async () => {
@@ -366,6 +367,25 @@ export function GeneralSettings() {
     }
   };
 
+  const handleTestMediaPause = async () => {
+    setTestingMediaPause(true);
+    try {
+      const result = await invoke<MediaPauseTestResult>("test_media_pause");
+      if (result.outcome === "paused" && result.resumed) {
+        toast.success(result.message);
+      } else if (result.outcome === "nothing_playing") {
+        toast.info(result.message);
+      } else {
+        toast.warning(result.message);
+      }
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      toast.error("Media pause test failed", { description: message });
+    } finally {
+      setTestingMediaPause(false);
+    }
+  };
+
   return (
     <div className="h-full min-h-0 overflow-auto">
       <div className="mx-auto flex w-full max-w-3xl flex-col gap-3.5 px-6 py-7 md:px-8">
@@ -620,6 +640,36 @@ export function GeneralSettings() {
                       />
                     </div>
                   </Field>
+
+                  <Field orientation="responsive" className="items-center gap-3">
+                    <FieldContent>
+                      <FieldTitle>Microphone gain</FieldTitle>
+                      <FieldDescription>
+                        Boost a quiet mic without changing OS settings. The recording level meter reflects it.
+                      </FieldDescription>
+                    </FieldContent>
+                    <div className="w-full min-w-0 md:flex-1">
+                      <div className="flex items-center gap-3">
+                        <Slider
+                          aria-label="Microphone gain"
+                          min={-12}
+                          max={24}
+                          step={1}
+                          value={[settings.mic_gain_db ?? 0]}
+                          onValueChange={async ([gain]) =>
+                            await updateSettings({
+                              mic_gain_db: gain,
+                            })
+                          }
+                          className="w-full"
+                        />
+                        <div className="min-w-14 rounded-md border bg-muted/60 px-2 py-1 text-center text-[11px] font-medium text-foreground tabular-nums">
+                          {(settings.mic_gain_db ?? 0) > 0 ? "+" : ""}
+                          {settings.mic_gain_db ?? 0} dB
+                        </div>
+                      </div>
+                    </div>
+                  </Field>
                 </FieldSet>
 
                 <FieldSet className="gap-4 border-t border-border/60 pt-5">
@@ -664,20 +714,90 @@ export function GeneralSettings() {
 
                   <Field orientation="responsive" className="items-center gap-3">
                     <FieldContent>
-                      <FieldTitle>Pause media during recording</FieldTitle>
+                      <FieldTitle>Insertion method</FieldTitle>
                       <FieldDescription>
-                        Automatically pause playing music or videos while recording.
+                        Type the text for terminals and secure fields that ignore paste. Auto pastes first and types if pasting fails.
                       </FieldDescription>
                     </FieldContent>
-                    <Switch
-                      id="pause-media"
-                      checked={settings.pause_media_during_recording ?? false}
-                      onCheckedChange={async (checked) =>
+                    <Select
+                      value={settings.insertion_method ?? 'paste'}
+                      onValueChange={async (value: InsertionMethod) =>
                         await updateSettings({
-                          pause_media_during_recording: checked,
+                          insertion_method: value,
                         })
                       }
-                    />
+                    >
+                      <SelectTrigger className="w-full md:w-[190px]">
+                        <SelectValue />
+                      </SelectTrigger>
+                      <SelectContent>
+                        <SelectItem value="paste">Paste</SelectItem>
+                        <SelectItem value="type">Type</SelectItem>
+                        <SelectItem value="auto">Auto</SelectItem>
+                      </SelectContent>
+                    </Select>
+                  </Field>
+
+                  {(settings.insertion_method ?? 'paste') !== 'paste' && (
+                    <Field orientation="responsive" className="items-center gap-3">
+                      <FieldContent>
+                        <FieldTitle>Typing delay</FieldTitle>
+                        <FieldDescription>
+                          Slow typing down for apps that drop fast input.
+                        </FieldDescription>
+                      </FieldContent>
+                      <div className="w-full min-w-0 md:flex-1">
+                        <div className="flex items-center gap-3">
+                          <Slider
+                            aria-label="Typing delay"
+                            min={0}
+                            max={200}
+                            step={5}
+                            value={[settings.typing_delay_ms ?? 0]}
+                            onValueChange={async ([delay]) =>
+                              await updateSettings({
+                                typing_delay_ms: delay,
+                              })
+                            }
+                            className="w-full"
+                          />
+                          <div className="min-w-14 rounded-md border bg-muted/60 px-2 py-1 text-center text-[11px] font-medium text-foreground tabular-nums">
+                            {settings.typing_delay_ms ?? 0} ms
+                          </div>
+                        </div>
+                      </div>
+                    </Field>
+                  )}
+
+                  <Field orientation="responsive" className="items-center gap-3">
+                    <FieldContent>
+                      <FieldTitle>Pause media during recording</FieldTitle>
+                      <FieldDescription>
+                        Automatically pause playing music or videos while recording. Apps without system media controls can't be paused.
+                      </FieldDescription>
+                    </FieldContent>
+                    <div className="flex items-center gap-3">
+                      {settings.pause_media_during_recording && (
+                        <Button
+                          type="button"
+                          variant="outline"
+                          size="sm"
+                          onClick={handleTestMediaPause}
+                          disabled={testingMediaPause}
+                        >
+                          {testingMediaPause ? "Testing..." : "Test"}
+                        </Button>
+                      )}
+                      <Switch
+                        id="pause-media"
+                        checked={settings.pause_media_during_recording ?? true}
+                        onCheckedChange={async (checked) =>
+                          await updateSettings({
+                            pause_media_during_recording: checked,
+                          })
+                        }
+                      />
+                    </div>
                   </Field>
 
                   <Field orientation="responsive" className="items-center gap-3">
@@ -870,6 +990,30 @@ export function GeneralSettings() {
                           </div>
                         </div>
                       </Field>
+
+                      <Field orientation="responsive" className="items-center gap-3">
+                        <FieldContent>
+                          <FieldTitle>Dragged position</FieldTitle>
+                          <FieldDescription>
+                            The indicator reopens where you last dragged it. Reset to use the position above.
+                          </FieldDescription>
+                        </FieldContent>
+                        <Button
+                          variant="outline"
+                          size="sm"
+                          onClick={async () => {
+                            try {
+                              await invoke("reset_pill_position");
+                              toast.success("Indicator position reset");
+                            } catch (error) {
+                              log.error("Failed to reset pill position:", error);
+                              toast.error("Failed to reset indicator position");
+                            }
+                          }}
+                        >
+                          Reset pill position
+                        </Button>
+                      </Field>
                     </>
                   )}
                 </FieldSet>
@@ -30,11 +30,13 @@ import {
   Wrench,
   LifeBuoy,
   Stethoscope,
+  Archive,
 } from "lucide-react";
 import XIcon from "@/components/icons/XIcon";
 import { useState, useEffect } from 'react';
 import { toast } from 'sonner';
 import { invoke } from '@tauri-apps/api/core';
+import { save } from '@tauri-apps/plugin-dialog';
 import { getVersion } from '@tauri-apps/api/app';
 import { platform, version as osVersion } from '@tauri-apps/plugin-os';
 import { open } from '@tauri-apps/plugin-shell';
@@ -230,6 +232,24 @@ Actual behavior:
     }
   };
 
+  const handleExportDiagnostics = async () => {
+    try {
+      const stamp = new Date().toISOString().slice(0, 10);
+      const destination = await save({
+        defaultPath: `voicetypr-diagnostics-${stamp}.zip`,
+        filters: [{ name: 'Zip archive', extensions: ['zip'] }],
+      });
+      if (!destination) return;
+
+      const written = await invoke<string>('export_diagnostics', { path: destination });
+      toast.success('Diagnostics exported. API keys and passwords are redacted.');
+      await invoke('show_in_folder', { path: written });
+    } catch (error) {
+      log.error('Failed to export diagnostics:', error);
+      toast.error('Failed to export diagnostics');
+    }
+  };
+
   return (
     <SettingsPage>
       <SettingsHeader
@@ -373,6 +393,24 @@ Actual behavior:
             <ChevronDown className="h-4 w-4 text-muted-foreground -rotate-90" />
           </button>
 
+          <button
+            onClick={handleExportDiagnostics}
+            className="w-full rounded-lg border border-border/50 bg-card hover:bg-accent/50 transition-colors p-4 flex items-center justify-between group"
+          >
+            <div className="flex items-center gap-3">
+              <div className="p-2 rounded-lg bg-sage-bg group-hover:bg-sage-bg/80 transition-colors">
+                <Archive className="h-4 w-4 text-sage" />
+              </div>
+              <div className="text-left">
+                <p className="text-sm font-medium">Export Diagnostics</p>
+                <p className="text-xs text-muted-foreground">
+                  Save logs, settings and system details as a zip for support
+                </p>
+              </div>
+            </div>
+            <ChevronDown className="h-4 w-4 text-muted-foreground -rotate-90" />
+          </button>
+
           {/* System Info Footer */}
           <div className="flex items-center justify-between pt-1 text-xs text-muted-foreground">
             <span>Voicetypr v{appVersion}</span>
@@ -30,6 +30,7 @@ import {
   SettingsPage,
 } from "@/components/settings/settings-ui";
 import { Spinner } from "@/components/ui/spinner";
+import { Switch } from "@/components/ui/switch";
 import { ToggleGroup, ToggleGroupItem } from "@/components/ui/toggle-group";
 import { useSettings } from "@/contexts/SettingsContext";
 import { getErrorMessage } from "@/utils/error";
@@ -188,6 +189,18 @@ export function ModelsSection({
     [updateSettings],
   );
 
+  const handleAutoDetectChange = useCallback(
+    async (checked: boolean) => {
+      try {
+        await updateSettings({ auto_detect_language: checked });
+      } catch (error) {
+        log.error("Failed to update language detection:", error);
+        toast.error("Failed to update language detection");
+      }
+    },
+    [updateSettings],
+  );
+
   // Remote servers management
   // Quick list fetch (no status checks) - for immediate display
   const fetchRemoteServers = useCallback(async () => {
@@ -709,6 +722,22 @@ export function ModelsSection({
             </div>
           }
         />
+        {currentEngine === "whisper" && !isEnglishOnlyModel && (
+          <SettingRow
+            title="Detect language automatically"
+            description="Whisper picks the language from the first seconds of each recording. Falls back to the spoken language above when unsure."
+            control={
+              <Switch
+                id="auto-detect-language"
+                aria-label="Detect language automatically"
+                checked={settings?.auto_detect_language ?? false}
+                onCheckedChange={(checked) => {
+                  void handleAutoDetectChange(checked);
+                }}
+              />
+            }
+          />
+        )}
       </SettingsCard>
 
       <div className="flex flex-wrap items-center justify-between gap-3">
@@ -27,7 +27,7 @@ import { useCanAutoInsert, useReadiness } from "@/contexts/ReadinessContext";
 import { useSettings } from "@/contexts/SettingsContext";
 import { invoke } from "@tauri-apps/api/core";
 import { ask, save } from "@tauri-apps/plugin-dialog";
-import { AlertCircle, AlertTriangle, Mic, Trash2, Search, Copy, Monitor, Globe, FileAudio, Terminal, Download, RotateCcw, Loader2, FolderOpen, HelpCircle, ShieldCheck } from "lucide-react";
+import { AlertCircle, AlertTriangle, Mic, Trash2, Search, Copy, Monitor, Globe, FileAudio, Terminal, Download, RotateCcw, Loader2, FolderOpen, HelpCircle, ShieldCheck, Star } from "lucide-react";
 import { useState, useMemo, useCallback, useEffect } from "react";
 import { toast } from "sonner";
 import { cn } from "@/lib/utils";
@@ -497,6 +497,21 @@ export function RecentRecordings({ history, hotkey = "Cmd+Shift+Space", onHistor
     }
   };
 
+  const handleToggleFavorite = async (e: React.MouseEvent, item: TranscriptionHistory) => {
+    e.stopPropagation();
+
+    try {
+      await invoke("set_transcription_favorite", { timestamp: item.id, favorite: !item.favorite });
+
+      if (onHistoryUpdate) {
+        onHistoryUpdate();
+      }
+    } catch (error) {
+      log.error("Failed to update favorite:", error);
+      toast.error("Failed to update favorite");
+    }
+  };
+
   const handleClearAll = async () => {
     if (history.length === 0) return;
 
@@ -543,13 +558,13 @@ export function RecentRecordings({ history, hotkey = "Cmd+Shift+Space", onHistor
     }
   };
 
-  const handleExport = async () => {
+  const handleExport = async (format: "json" | "srt" | "vtt" | "csv") => {
     if (history.length === 0) return;
 
     try {
       // Show confirmation dialog with location info
       const confirmed = await ask(
-        `Export ${history.length} transcription${history.length !== 1 ? 's' : ''} to JSON?\n\nThe file will be saved to your Downloads folder.`, 
+        `Export ${history.length} transcription${history.length !== 1 ? 's' : ''} to ${format.toUpperCase()}?\n\nThe file will be saved to your Downloads folder.`, 
         {
           title: "Export Transcriptions",
           kind: "info"
@@ -559,10 +574,18 @@ export function RecentRecordings({ history, hotkey = "Cmd+Shift+Space", onHistor
       if (!confirmed) return;
 
       // Call the backend export command
-      await invoke<string>("export_transcriptions");
+      const path = await invoke<string>("export_transcriptions", { format });
       
       toast.success(`Exported ${history.length} transcriptions`, {
-        description: `Saved to Downloads folder`
+        description: `Saved to Downloads folder`,
+        action: {
+          label: "Show in folder",
+          onClick: () => {
+            invoke("show_in_folder", { path }).catch((error) =>
+              log.error("Failed to open export location:", error)
+            );
+          }
+        }
       });
     } catch (error) {
       log.error("Failed to export transcriptions:", error);
@@ -594,7 +617,7 @@ export function RecentRecordings({ history, hotkey = "Cmd+Shift+Space", onHistor
                   <div className="space-y-3 text-sm leading-6 text-muted-foreground">
                     <p><strong className="text-foreground">Search</strong> filters saved transcripts by text and source metadata.</p>
                     <p><strong className="text-foreground">Re-transcribe</strong> reruns a saved audio take with your current transcription source. It only appears when the original audio file was saved.</p>
-                    <p><strong className="text-foreground">Export</strong> saves transcript history as JSON for backup or review.</p>
+                    <p><strong className="text-foreground">Export</strong> saves transcript history as JSON, text, Markdown, CSV, or SRT/VTT subtitles with timestamps.</p>
                   </div>
                 </DialogContent>
               </Dialog>
@@ -615,9 +638,12 @@ export function RecentRecordings({ history, hotkey = "Cmd+Shift+Space", onHistor
                   </Button>
                 </DropdownMenuTrigger>
                 <DropdownMenuContent align="end">
-                  <DropdownMenuItem onClick={handleExport}>JSON (.json)</DropdownMenuItem>
+                  <DropdownMenuItem onClick={() => handleExport("json")}>JSON (.json)</DropdownMenuItem>
                   <DropdownMenuItem onClick={() => handleExportText("txt")}>Plain text (.txt)</DropdownMenuItem>
                   <DropdownMenuItem onClick={() => handleExportText("md")}>Markdown (.md)</DropdownMenuItem>
+                  <DropdownMenuItem onClick={() => handleExport("csv")}>CSV (.csv)</DropdownMenuItem>
+                  <DropdownMenuItem onClick={() => handleExport("srt")}>Subtitles (.srt)</DropdownMenuItem>
+                  <DropdownMenuItem onClick={() => handleExport("vtt")}>WebVTT (.vtt)</DropdownMenuItem>
                 </DropdownMenuContent>
               </DropdownMenu>
             )}
@@ -804,6 +830,12 @@ export function RecentRecordings({ history, hotkey = "Cmd+Shift+Space", onHistor
 
                           <div className="mt-1.5 flex flex-wrap items-center gap-x-2 gap-y-1 text-xs text-muted-foreground">
                             <span className="font-medium text-foreground/80">{sourceLabel(item.writing?.source)}</span>
+                            {item.engine && item.engine !== item.model && (
+                              <>
+                                <span className="text-muted-foreground/40">·</span>
+                                <span className="capitalize">{item.engine}</span>
+                              </>
+                            )}
                             {item.model && (
                               <>
                                 <span className="text-muted-foreground/40">·</span>
@@ -885,6 +917,16 @@ export function RecentRecordings({ history, hotkey = "Cmd+Shift+Space", onHistor
                               {isInProgress ? <Loader2 className="h-3.5 w-3.5 animate-spin" /> : <RotateCcw className="h-3.5 w-3.5" />}
                             </button>
                           )}
+                          <button
+                            onClick={(e) => handleToggleFavorite(e, item)}
+                            className={cn(
+                              "grid size-7 place-items-center rounded-md border border-border bg-card transition-colors hover:text-foreground",
+                              item.favorite ? "text-amber-500" : "text-muted-foreground",
+                            )}
+                            title={item.favorite ? "Remove from favorites" : "Add to favorites (kept when history is trimmed)"}
+                          >
+                            <Star className={cn("h-3.5 w-3.5", item.favorite && "fill-current")} />
+                          </button>
                           <button
                             onClick={(e) => handleDelete(e, item.id)}
                             className="grid size-7 place-items-center rounded-md border border-border bg-card text-muted-foreground transition-colors hover:border-destructive/40 hover:text-destructive"
@@ -7,10 +7,11 @@ vi.mock('@tauri-apps/api/core', () => ({
 }));
 
 vi.mock('sonner', () => ({
-  toast: { error: vi.fn(), success: vi.fn(), info: vi.fn() },
+  toast: { error: vi.fn(), success: vi.fn(), info: vi.fn(), warning: vi.fn() },
 }));
 
 import { invoke } from '@tauri-apps/api/core';
+import { toast } from 'sonner';
 import { LicenseProvider, useLicense } from './LicenseContext';
 
 const SECRET_KEY = 'SUPER-SECRET-LICENSE-KEY-XYZ';
@@ -28,6 +29,11 @@ function Probe() {
   return null;
 }
 
+function GraceProbe() {
+  const { offlineGraceDaysLeft } = useLicense();
+  return <span data-testid="grace">{String(offlineGraceDaysLeft)}</span>;
+}
+
 describe('LicenseContext', () => {
   beforeEach(() => {
     vi.clearAllMocks();
@@ -71,4 +77,18 @@ describe('LicenseContext', () => {
     expect(serialized).toContain('"status":"licensed"');
     expect(serialized).toContain('"license_type":"lifetime"');
   });
+
+  it('exposes the offline grace days and warns about them', async () => {
+    vi.mocked(invoke).mockResolvedValue({ ...licensedStatus, offline_grace_days_left: 3 });
+
+    const { findByText } = render(
+      <LicenseProvider>
+        <GraceProbe />
+      </LicenseProvider>,
+    );
+
+    expect(await findByText('3')).toBeDefined();
+    expect(toast.warning).toHaveBeenCalledTimes(1);
+    expect(vi.mocked(toast.warning).mock.calls[0][0]).toContain('3 more days');
+  });
 });
@@ -9,6 +9,8 @@ const log = createLogger("license");
 
 interface LicenseContextValue {
   status: LicenseStatus | null;
+  // Days of offline use left while the license can't be re-validated; null when validated online
+  offlineGraceDaysLeft: number | null;
   isLoading: boolean;
   checkStatus: () => Promise<void>;
   restoreLicense: () => Promise<void>;
@@ -19,10 +21,18 @@ interface LicenseContextValue {
 
 const LicenseContext = createContext<LicenseContextValue | undefined>(undefined);
 
+export function offlineGraceMessage(daysLeft: number): string {
+  if (daysLeft <= 0) {
+    return "Couldn't re-validate your license. Connect to the internet to keep using Voicetypr.";
+  }
+  return `Couldn't reach the license server. Voicetypr keeps working offline for ${daysLeft} more day${daysLeft !== 1 ? 's' : ''}.`;
+}
+
 export function LicenseProvider({ children }: { children: ReactNode }) {
   const [status, setStatus] = useState<LicenseStatus | null>(null);
   const [isLoading, setIsLoading] = useState(true);
   const latestCheckStatusId = useRef(0);
+  const warnedOfflineGrace = useRef(false);
 
   const withTimeout = async <T,>(promise: Promise<T>, timeoutMs: number): Promise<T> => {
     let timeoutHandle: ReturnType<typeof setTimeout> | undefined;
@@ -86,8 +96,22 @@ export function LicenseProvider({ children }: { children: ReactNode }) {
         trial_days_left: licenseStatus.trial_days_left,
         license_type: licenseStatus.license_type,
         expires_at: licenseStatus.expires_at,
+        offline_grace_days_left: licenseStatus.offline_grace_days_left,
       });
       setStatus(licenseStatus);
+
+      // Warn once per offline stretch, not on every re-check
+      const graceDaysLeft = licenseStatus.offline_grace_days_left ?? null;
+      if (graceDaysLeft === null) {
+        warnedOfflineGrace.current = false;
+      } else if (!warnedOfflineGrace.current) {
+        warnedOfflineGrace.current = true;
+        if (graceDaysLeft <= 0) {
+          toast.error(offlineGraceMessage(graceDaysLeft));
+        } else {
+          toast.warning(offlineGraceMessage(graceDaysLeft));
+        }
+      }
     } catch (error) {
       if (checkId !== latestCheckStatusId.current) return;
 
@@ -161,6 +185,7 @@ export function LicenseProvider({ children }: { children: ReactNode }) {
 
   const value: LicenseContextValue = {
     status,
+    offlineGraceDaysLeft: status?.offline_grace_days_left ?? null,
     isLoading,
     checkStatus,
     restoreLicense,
@@ -393,8 +393,11 @@ export function useModelManagement(options: UseModelManagementOptions = {}) {
         progress: number;
         requestId?: string;
         phase?: string | null;
+        resumedFrom?: number;
+        bytesPerSec?: number | null; // moving average; null until a rate is known
+        etaSeconds?: number | null;
       }>("download-progress", (payload) => {
-        const { model, progress, engine, requestId, phase } = payload;
+        const { model, progress, engine, requestId, phase, resumedFrom } = payload;
         if (requestId && cancelledDownloadRequests.current.has(requestId)) {
           return;
         }
@@ -404,7 +407,7 @@ export function useModelManagement(options: UseModelManagementOptions = {}) {
           return;
         }
 
-        log.debug(`[useModelManagement] Download progress for ${model} (${engine ?? "whisper"}): ${progress.toFixed(1)}%`);
+        log.debug(`[useModelManagement] Download progress for ${model} (${engine ?? "whisper"}): ${progress.toFixed(1)}%${resumedFrom ? ` (resumed from ${resumedFrom} bytes)` : ""}`);
 
         // Keep updating progress until we receive the verifying event
         setDownloadProgress((prev) => ({
@@ -42,7 +42,7 @@ describe('useProviderModels', () => {
       await result.current.fetchModels();
     });
     
-    expect(invoke).toHaveBeenCalledWith('list_provider_models', { provider: 'openai' });
+    expect(invoke).toHaveBeenCalledWith('list_ai_models', { provider: 'openai' });
     expect(result.current.models).toEqual(mockModels);
     expect(result.current.loading).toBe(false);
     expect(result.current.error).toBeNull();
@@ -38,7 +38,7 @@ export function useProviderModels(providerId: string): UseProviderModelsReturn {
     setError(null);
 
     try {
-      const fetchedModels = await invoke<AIProviderModel[]>("list_provider_models", {
+      const fetchedModels = await invoke<AIProviderModel[]>("list_ai_models", {
         provider: providerId,
       });
       setModels(fetchedModels);
@@ -89,7 +89,7 @@ export function useAllProviderModels() {
     setErrorMap(prev => ({ ...prev, [providerId]: null }));
 
     try {
-      const fetchedModels = await invoke<AIProviderModel[]>("list_provider_models", {
+      const fetchedModels = await invoke<AIProviderModel[]>("list_ai_models", {
         provider: providerId,
       });
       setModelsMap(prev => ({ ...prev, [providerId]: fetchedModels }));
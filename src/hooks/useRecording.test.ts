@@ -75,6 +75,32 @@ describe('useRecording', () => {
     expect(result.current.isActive).toBe(false);
   });
 
+  it('should toggle paused from pause and resume events', async () => {
+    const { result } = renderHook(() => useRecording());
+
+    await waitFor(() => {
+      expect(mockListen).toHaveBeenCalled();
+    });
+
+    act(() => {
+      emitMockEvent('recording-state-changed', { state: 'recording', error: null });
+      emitMockEvent('recording-paused', undefined);
+    });
+    expect(result.current.isPaused).toBe(true);
+
+    act(() => {
+      emitMockEvent('recording-resumed', undefined);
+    });
+    expect(result.current.isPaused).toBe(false);
+
+    // Leaving the recording state drops a stale pause.
+    act(() => {
+      emitMockEvent('recording-paused', undefined);
+      emitMockEvent('recording-state-changed', { state: 'transcribing', error: null });
+    });
+    expect(result.current.isPaused).toBe(false);
+  });
+
   it('should invoke start_recording command', async () => {
     const { result } = renderHook(() => useRecording());
 
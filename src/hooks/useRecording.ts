@@ -13,7 +13,10 @@ interface UseRecordingReturn {
   error: string | null;
   startRecording: () => Promise<void>;
   stopRecording: () => Promise<void>;
+  pauseRecording: () => Promise<void>;
+  resumeRecording: () => Promise<void>;
   isActive: boolean;
+  isPaused: boolean; // recording is open but not capturing (see pause_recording)
 }
 
 const getCommandErrorMessage = (err: unknown, fallback: string) => {
@@ -29,6 +32,7 @@ const getCommandErrorMessage = (err: unknown, fallback: string) => {
 export function useRecording(): UseRecordingReturn {
   const [state, setState] = useState<RecordingState>('idle');
   const [error, setError] = useState<string | null>(null);
+  const [isPaused, setIsPaused] = useState(false);
 
   // Check initial state on mount by requesting current state
   useEffect(() => {
@@ -58,6 +62,19 @@ export function useRecording(): UseRecordingReturn {
         log.debug('[Recording Hook] State changed:', event.payload);
         setState(event.payload.state);
         setError(event.payload.error || null);
+        if (event.payload.state !== 'recording') {
+          setIsPaused(false);
+        }
+      }));
+
+      unsubscribers.push(await listen('recording-paused', () => {
+        log.debug('[Recording Hook] Recording paused');
+        setIsPaused(true);
+      }));
+
+      unsubscribers.push(await listen('recording-resumed', () => {
+        log.debug('[Recording Hook] Recording resumed');
+        setIsPaused(false);
       }));
 
       // Legacy events for compatibility
@@ -130,11 +147,30 @@ export function useRecording(): UseRecordingReturn {
     }
   }, []);
 
+  const pauseRecording = useCallback(async () => {
+    try {
+      await invoke('pause_recording');
+    } catch (err) {
+      log.error('[Recording Hook] Failed to pause recording:', err);
+    }
+  }, []);
+
+  const resumeRecording = useCallback(async () => {
+    try {
+      await invoke('resume_recording');
+    } catch (err) {
+      log.error('[Recording Hook] Failed to resume recording:', err);
+    }
+  }, []);
+
   return {
     state,
     error,
     startRecording,
     stopRecording,
-    isActive: state !== 'idle' && state !== 'error'
+    pauseRecording,
+    resumeRecording,
+    isActive: state !== 'idle' && state !== 'error',
+    isPaused: isPaused && state === 'recording'
   };
 }
\ No newline at end of file
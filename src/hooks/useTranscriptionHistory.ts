@@ -10,16 +10,19 @@ interface RawTranscriptionHistoryItem {
   timestamp?: string;
   text: string;
   model: string;
+  engine?: string;
   recording_file?: string;
   source_recording_id?: string;
   status?: TranscriptionHistory["status"];
   writing?: TranscriptionHistory["writing"];
+  favorite?: boolean;
 }
 
 interface TranscriptionAddedEvent {
   timestamp: string;
   text: string;
   model: string;
+  engine?: string;
   recording_file?: string;
   source_recording_id?: string;
   status?: TranscriptionHistory["status"];
@@ -45,10 +48,12 @@ function toHistoryItem(item: RawTranscriptionHistoryItem): TranscriptionHistory
     text: item.text,
     timestamp: new Date(timestamp),
     model: item.model,
+    engine: item.engine,
     recording_file: item.recording_file,
     source_recording_id: item.source_recording_id,
     status: item.status,
     writing: item.writing,
+    favorite: item.favorite,
   };
 }
 
@@ -58,6 +63,7 @@ function fromAddedEvent(item: TranscriptionAddedEvent): TranscriptionHistory {
     text: item.text,
     timestamp: new Date(item.timestamp),
     model: item.model,
+    engine: item.engine,
     recording_file: item.recording_file,
     source_recording_id: item.source_recording_id,
     status: item.status,
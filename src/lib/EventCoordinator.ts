@@ -140,6 +140,7 @@ export class EventCoordinator {
       // Recording/transcription errors now use pill_toast() → FeedbackToast directly,
       // not as routed events. Only domain-specific main window errors are listed here.
       "parakeet-unavailable": "main",
+      "parakeet-ready": "main",
       
       // Debug events
       "test-event": "pill",
@@ -55,6 +55,13 @@ const cloudProviderDefinitions = [
     description: 'Cloud transcription via Cohere Transcribe',
     docsUrl: 'https://docs.cohere.com/docs/transcribe',
   },
+  {
+    id: 'assemblyai',
+    displayName: 'AssemblyAI',
+    providerName: 'AssemblyAI',
+    description: 'Cloud transcription with speaker labels via AssemblyAI Universal',
+    docsUrl: 'https://www.assemblyai.com/docs/getting-started/transcribe-an-audio-file',
+  },
 ] as const satisfies ReadonlyArray<
   Pick<
     CloudProviderDefinition,
@@ -13,6 +13,7 @@ const KNOWN_MODEL_DISPLAY_NAMES: Record<string, string> = {
   groq: 'Groq (Cloud)',
   deepgram: 'Deepgram (Cloud)',
   cohere: 'Cohere (Cloud)',
+  assemblyai: 'AssemblyAI (Cloud)',
 };
 
 function titleCaseToken(token: string) {
@@ -0,0 +1,12 @@
+import React from "react";
+import ReactDOM from "react-dom/client";
+import { Scratchpad } from "./components/Scratchpad";
+import "./globals.css";
+
+ReactDOM.createRoot(document.getElementById("root") as HTMLElement).render(
+  <React.StrictMode>
+    <div className="w-screen h-screen overflow-hidden bg-background text-foreground">
+      <Scratchpad />
+    </div>
+  </React.StrictMode>,
+);
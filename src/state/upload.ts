@@ -1,5 +1,6 @@
 import { create } from 'zustand'
 import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
 
 export type UploadStatus = 'idle' | 'processing' | 'done' | 'error'
 export type UploadResult =
@@ -17,6 +18,10 @@ export type TranscriptionWord = {
   confidence?: number
 }
 
+export type ChunkProgress = { done: number; total: number }
+
+type TranscriptionProgressPayload = { done_chunks: number; total_chunks: number }
+
 type UploadTranscriptionResult = {
   text: string
   words: TranscriptionWord[] | null
@@ -31,6 +36,7 @@ type UploadState = {
   speakerSegments: SpeakerSegment[]
   diarizationError: string | null
   diarized: boolean
+  chunkProgress: ChunkProgress | null // set while a long file is transcribed in chunks
   select: (path: string) => void
   clearSelection: () => void
   start: (modelName: string, modelEngine: string | null, historyModelName?: string) => Promise<UploadResult | null>
@@ -45,6 +51,7 @@ export const useUploadStore = create<UploadState>((set, get) => ({
   speakerSegments: [],
   diarizationError: null,
   diarized: false,
+  chunkProgress: null,
 
   select: (path: string) => {
     const name = path.split(/[\\/]/).pop() || 'audio file'
@@ -57,7 +64,10 @@ export const useUploadStore = create<UploadState>((set, get) => ({
     const { selectedFile, status } = get()
     if (!selectedFile) return null
     if (status === 'processing') return null
-    set({ status: 'processing', error: null, resultText: null, speakerSegments: [], diarizationError: null, diarized: false })
+    set({ status: 'processing', error: null, resultText: null, speakerSegments: [], diarizationError: null, diarized: false, chunkProgress: null })
+    const unlisten = await listen<TranscriptionProgressPayload>('transcription-progress', (event) => {
+      set({ chunkProgress: { done: event.payload.done_chunks, total: event.payload.total_chunks } })
+    })
     try {
       const res = await invoke<UploadTranscriptionResult>('transcribe_audio_file', {
         filePath: selectedFile.path,
@@ -92,8 +102,11 @@ export const useUploadStore = create<UploadState>((set, get) => ({
       const message = String(e?.message || e)
       set({ status: 'error', error: message })
       return { outcome: 'error', message }
+    } finally {
+      unlisten()
+      set({ chunkProgress: null })
     }
   },
 
-  reset: () => set({ selectedFile: null, status: 'idle', resultText: null, error: null, speakerSegments: [], diarizationError: null, diarized: false })
+  reset: () => set({ selectedFile: null, status: 'idle', resultText: null, error: null, speakerSegments: [], diarizationError: null, diarized: false, chunkProgress: null })
 }))
@@ -1,4 +1,4 @@
-export type SpeechModelEngine = 'whisper' | 'parakeet' | 'soniox' | 'openai' | 'groq' | 'deepgram' | 'cohere';
+export type SpeechModelEngine = 'whisper' | 'parakeet' | 'soniox' | 'openai' | 'groq' | 'deepgram' | 'cohere' | 'assemblyai';
 export type ModelKind = 'local' | 'cloud';
 
 /** A downloaded shareable model exposed by a remote Voicetypr host. */
@@ -49,6 +49,86 @@ export interface CloudModelInfo extends BaseModelInfo {
 
 export type ModelInfo = LocalModelInfo | CloudModelInfo;
 
+/** Effective API endpoint for a cloud STT provider (see `get_stt_endpoint`). */
+export interface SttEndpoint {
+  provider: string;
+  baseUrl: string;
+  defaultBaseUrl: string;
+  isCustom: boolean;
+}
+
+/** Input device with the capture formats it advertises (see `get_audio_devices_detailed`). */
+export interface AudioDeviceInfo {
+  name: string;
+  isDefault: boolean;
+  sampleRates: number[];
+  channelCounts: number[];
+  supportsTargetRate: boolean; // False when capture must be resampled to 16 kHz
+}
+
+/** Payload of the `recording-config` event, sent once capture starts. */
+export interface RecordingCaptureConfig {
+  deviceSampleRate: number; // the mic's native rate
+  captureSampleRate: number;
+  outputSampleRate: number; // rate of the saved WAV; resampled when it differs from captureSampleRate
+  channels: number;
+}
+
+/** Mic check result from `sample_input_level`; linear levels are 0-1 full scale. */
+export interface InputLevelSample {
+  peak: number;
+  rms: number;
+  peak_dbfs: number;
+  rms_dbfs: number;
+  clipping: boolean;
+  suggested_mic_gain_db: number; // -12 to +24, whole dB
+}
+
+/** Audio cleanup applied before re-transcribing a recording (see `retranscribe_with_options`). */
+export interface PreprocessOptions {
+  denoise?: boolean;
+  agc?: boolean; // automatic gain control
+  loudnorm?: boolean;
+  trim?: boolean; // strip leading/trailing silence
+}
+
+/** Result of the backend `check_for_updates` command. */
+export interface UpdateCheckResult {
+  available: boolean;
+  currentVersion: string;
+  version: string | null;
+  notes: string | null;
+}
+
+/** Result of `test_media_pause`: pause whatever is playing, then resume it. */
+export interface MediaPauseTestResult {
+  outcome: 'paused' | 'nothing_playing' | 'failed' | 'unsupported';
+  resumed: boolean;
+  message: string;
+}
+
+/** Per-model latency summary from `get_transcription_metrics` (last 200 transcriptions). */
+export interface TranscriptionModelMetrics {
+  engine: string;
+  model: string;
+  count: number;
+  p50_ms: number;
+  p95_ms: number;
+  real_time_factor: number | null; // transcription time / audio time; below 1 is faster than real time
+  avg_char_count: number;
+}
+
+/** Saved structured-dictation template with `{{placeholder}}` slots. */
+export interface TranscriptionTemplate {
+  id: string;
+  name: string;
+  body: string;
+  fillPlaceholder: string; // Slot filled with the latest transcription by `insert_template`
+}
+
+/** Extra request parameters merged into a cloud STT provider's requests (see `set_stt_extra_params`). */
+export type SttExtraParams = Record<string, string | number | boolean | unknown[] | Record<string, unknown>>;
+
 export const isCloudModel = (model: ModelInfo): model is CloudModelInfo =>
   model.kind === 'cloud';
 
@@ -59,18 +139,33 @@ export type RecordingMode = 'toggle' | 'push_to_talk';
 export type PillIndicatorMode = 'never' | 'always' | 'when_recording';
 export type PillIndicatorPosition = 'top-left' | 'top-center' | 'top-right' | 'bottom-left' | 'bottom-center' | 'bottom-right';
 export type TranscriptionAcceleration = 'auto' | 'gpu' | 'cpu';
+export type InsertionMethod = 'paste' | 'type' | 'auto';
+
+/** Overrides for recordings started while a matching app is frontmost. Empty fields keep the global setting. */
+export interface AppProfile {
+  app_match: string; // bundle id (macOS) or executable name (Windows)
+  model?: string;
+  engine?: string; // 'whisper' | 'parakeet' | cloud provider id
+  language?: string;
+  ai_enabled?: boolean | null;
+  clipboard_only?: boolean | null; // true = copy without pasting in this app; null keeps auto_paste_transcription
+  whisper_initial_prompt?: string; // empty keeps the global Whisper initial prompt
+}
 
 export interface AppSettings {
   hotkey: string;
   current_model: string;
   speech_language: string;
+  last_multilingual_language?: string | null; // restored when leaving an English-only model (backend-managed)
   transcription_task?: 'transcribe' | 'translate_to_english';
   final_text_language?: string;
+  translate_to_language?: string; // ISO code to translate dictations into with the AI provider ("" = off)
   theme: string;
   transcription_cleanup_days?: number | null;
   launch_at_startup?: boolean;
   onboarding_completed?: boolean;
   check_updates_automatically?: boolean;
+  update_check_interval_hours?: number; // 0 = off, else backend checks every N hours (max 168) and notifies
   selected_microphone?: string | null;
   // Push-to-talk support
   recording_mode?: RecordingMode;
@@ -96,8 +191,71 @@ export interface AppSettings {
   // Recording persistence settings
   save_recordings?: boolean;
   recording_retention_days?: number | null; // null = keep forever
+  recording_format?: 'wav' | 'flac' | 'opus'; // saved recordings are encoded with ffmpeg; default wav
+  recordings_dir?: string; // absolute folder; recordings go in its `voicetypr` subfolder ("" = app data)
+  keep_raw_recordings?: boolean; // keep the raw capture as raw_<timestamp>.wav for debugging (default false)
   // Transcription acceleration (Windows only; stored-but-ignored on other platforms)
   transcription_acceleration?: TranscriptionAcceleration;
+  // Feed recent transcriptions to AI formatting as consistency context
+  use_recent_context?: boolean;
+  recent_context_count?: number; // 1-10
+  recording_start_debounce_ms?: number; // 0-2000, ignore repeat starts from any trigger
+  min_recording_duration_ms?: number; // 0-5000, discard shorter recordings before transcription
+  min_recording_toggle_s?: number | null; // 0-5 s minimum for toggle recordings, 0 = off; null uses min_recording_duration_ms
+  min_recording_ptt_s?: number | null; // 0-5 s minimum for push-to-talk recordings, 0 = off; null uses min_recording_duration_ms
+  ptt_min_hold_ms?: number; // 0-1000, push-to-talk taps released sooner never start a recording
+  keep_engine_warm?: boolean; // Re-warm the cloud engine connection while idle
+  parakeet_idle_timeout_minutes?: number; // 0 = keep the Parakeet sidecar alive, else shut it down after N idle minutes (default 15)
+  pill_show_delay_ms?: number; // 0-2000, hide the pill for recordings shorter than this
+  pill_hide_delay_ms?: number; // 0-5000, keep the pill up this long after text is delivered (0 = hide before inserting)
+  pill_hide_delay_error_ms?: number; // 0-5000 (default 2500), keep the pill up after a failed transcription
+  pill_hide_delay_empty_ms?: number; // 0-5000 (default 2500), keep the pill up when no speech was captured
+  max_queued_transcriptions?: number; // 1-10 (default 3), transcriptions running or waiting before new ones are rejected
+  auto_engine_by_network?: boolean; // use a local model while the cloud provider is unreachable
+  offline_fallback_model?: string; // empty = best downloaded Whisper model
+  offline_fallback_engine?: 'whisper' | 'parakeet';
+  insert_streaming?: boolean; // type Soniox realtime interim text, correcting as finals arrive
+  upload_segment_seconds?: number | null; // 0 = off, else 30-3600; split long uploads into chunks. null = 300 for Whisper/Parakeet, off for cloud
+  strip_ai_preamble?: boolean; // Remove "Here is the corrected text:" wrappers from AI output (default true)
+  recording_indicator?: 'pill' | 'notification' | 'both' | 'none'; // Start/stop feedback when the pill is hidden by full-screen apps
+  scratchpad_mode?: boolean; // route dictations to the scratchpad window instead of inserting them
+  transcription_sinks?: string[]; // 'cursor' (default), 'clipboard', 'file:<path>' with {date}/{year}/{month}/{day} placeholders
+  insertion_retry_count?: number; // 0-5 extra paste attempts before falling back to the clipboard
+  insertion_retry_delay_ms?: number; // 0-1000, pause between paste attempts
+  insertion_method?: InsertionMethod; // paste (default), simulated typing, or paste with typing fallback
+  typing_delay_ms?: number; // 0-200, pause after each typed character for apps that drop fast input
+  max_history_entries?: number; // 0 = no cap; evicts the oldest non-favorite rows beyond this
+  duplicate_window_seconds?: number; // 0-60 (default 2); skip saving a repeat of the previous transcription (ignoring case/spacing) within this window, 0 = off
+  soniox_realtime?: boolean; // stream audio to Soniox while recording; falls back to file upload
+  soniox_fallback_local?: boolean; // record with a downloaded local model when the Soniox key is rejected
+  local_control_socket?: boolean; // accept {"cmd":"start"|"stop"|"status"} JSON lines from scripts on a local socket
+  assemblyai_speaker_labels?: boolean; // prefix AssemblyAI transcripts with "Speaker A:" lines
+  loudness_normalize?: boolean; // two-pass loudnorm to -16 LUFS before transcription (default true); silent recordings are left as is
+  auto_trim_silence?: boolean; // trim leading/trailing silence and long pauses before local transcription
+  trim_silence_threshold_db?: number; // -60 to -20 dBFS (default -45), audio at or below counts as silence when trimming
+  trim_max_gap_ms?: number; // 300-10000 (default 1500), internal pauses longer than this are shortened when trimming
+  noise_suppression?: boolean; // spectral noise suppression on recordings before transcription
+  auto_detect_language?: boolean; // Whisper detects the spoken language per recording; speech_language is the low-confidence fallback
+  silence_auto_stop_enabled?: boolean; // false = silence never ends a recording (default true)
+  silence_threshold_db?: number; // -60 to -20 dBFS, audio at or below counts as silence
+  silence_duration_ms?: number | null; // 500-10000, pause after speech that ends a recording; null = 5-minute safety stop
+  mic_gain_db?: number; // -12 to +24 dB applied to captured samples, soft-clipped near full scale
+  clipping_warning_enabled?: boolean; // false = never warn on the pill when the input clips (default true)
+  allow_recording_when_muted?: boolean; // true = record even when the OS reports the mic as muted (default false)
+  preferred_sample_rate?: number; // 8000-96000 Hz (default 16000); mics that can't capture it are resampled
+  channel_select?: 'mix' | 'left' | 'right'; // channel kept from a stereo mic (default 'mix' averages them)
+  max_recording_duration_s?: number; // 0-14400 (default 600); recording stops itself after this long, 0 = unlimited
+  low_confidence_threshold?: number; // 0-1 (default 0.35), warn when Whisper's average token confidence is lower; 0 = off
+  whisper_initial_prompt?: string; // up to 500 bytes; primes Whisper engines with domain terms/spelling ahead of dictionary terms
+  whisper_beam_size?: number | null; // 1-8 beams, 1 = greedy; null keeps greedy on CPU and 5 beams on GPU. More beams are slower
+  whisper_temperature_fallback?: boolean; // false = never re-decode low-confidence segments at higher temperatures (default true)
+  openai_stt_model?: 'gpt-4o-transcribe' | 'whisper-1'; // OpenAI cloud STT model; whisper-1 also serves translate-to-English
+  // Final formatting of inserted text, applied after AI formatting and replacements
+  trim_whitespace?: boolean;
+  collapse_spaces?: boolean; // runs of spaces/tabs become one space; line breaks kept
+  auto_capitalize_first?: boolean;
+  ensure_trailing_space?: boolean; // end with exactly one space unless the text ends in a line break
+  profiles?: AppProfile[]; // per-app overrides, resolved from the frontmost app when recording starts
 }
 
 /** Writing-step outcome attached to a history row (mirrors the backend `writing` metadata blob). */
@@ -131,10 +289,72 @@ export interface TranscriptionHistory {
   text: string;
   timestamp: Date;
   model: string;
+  engine?: string; // Engine that produced the row, e.g. 'whisper', 'parakeet', 'soniox'
   recording_file?: string; // Filename of the saved recording (not full path)
+  recording_format?: 'wav' | 'flac' | 'opus';
   source_recording_id?: string; // For re-transcriptions, references original transcription
   status?: 'completed' | 'in_progress' | 'failed';
+  language?: string | null; // Transcript language, when the engine reported one
+  duration_ms?: number | null; // Length of the recorded audio
+  avg_confidence?: number | null; // 0-1, Whisper only
+  char_count?: number;
   writing?: TranscriptionWritingMeta;
+  favorite?: boolean; // Exempt from the max_history_entries cap
+}
+
+// Row returned by `search_transcriptions`: the stored history row plus the
+// matched spans of its text as [start, end) string offsets.
+export interface TranscriptionSearchHit {
+  timestamp: string;
+  text: string;
+  model: string;
+  engine?: string;
+  recording_file?: string;
+  status?: TranscriptionHistory['status'];
+  writing?: TranscriptionWritingMeta;
+  matches: [number, number][];
+}
+
+/** Payload of the versioned `recording-event`, emitted at every recording lifecycle transition. */
+export interface RecordingEvent {
+  version: number; // schema version, currently 1
+  phase:
+    | 'idle'
+    | 'starting'
+    | 'recording'
+    | 'stopping'
+    | 'transcribing'
+    | 'error'
+    | 'enhancing_started'
+    | 'enhancing_completed'
+    | 'enhancing_failed';
+  model: string | null;
+  engine: string | null;
+  timestamp: number; // ms since the Unix epoch
+  detail: string | null; // error message or other context for the phase
+}
+
+/** Payload of the pill's `theme-changed` event; the initial value is `window.__SYSTEM_THEME__`. */
+export type SystemTheme = 'dark' | 'light';
+
+/** One entry of `run_health_check`. */
+export interface HealthCheck {
+  ok: boolean;
+  detail: string;
+}
+
+/** Result of `run_health_check`, shown in the Diagnostics panel. */
+export interface HealthReport {
+  microphone_permission: HealthCheck;
+  accessibility_permission: HealthCheck;
+  model_available: HealthCheck;
+  ffmpeg_sidecar: HealthCheck;
+  selected_model_loadable: HealthCheck;
+  ai_api_key: HealthCheck; // passes while AI enhancement is off
+  license: HealthCheck;
+  disk_space: HealthCheck;
+  healthy: boolean;
+  failing: string[]; // names of the failing checks
 }
 
 export interface LicenseStatus {
@@ -143,4 +363,5 @@ export interface LicenseStatus {
   license_type?: string;
   license_key?: string;
   expires_at?: string;
+  offline_grace_days_left?: number | null; // days of offline use left while the license can't be re-validated; 0 = blocked
 }
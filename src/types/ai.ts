@@ -71,6 +71,8 @@ export const migratePreset = (raw: string, aiEnabled = false): EnhancementPreset
 
 export interface EnhancementOptions {
   preset: EnhancementPreset;
+  temperature?: number; // 0.0-1.0, defaults to 0.2
+  max_output_tokens?: number | null; // 256-8192, null keeps the provider default
 }
 
 export interface AISettings {
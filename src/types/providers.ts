@@ -55,6 +55,10 @@ const PROVIDER_UI_METADATA: Record<string, { color: string; apiKeyUrl: string }>
     color: "text-purple-600",
     apiKeyUrl: "",
   },
+  ollama: {
+    color: "text-slate-600",
+    apiKeyUrl: "",
+  },
 };
 
 export function toProviderConfig(provider: AiProvider): AIProviderConfig {
@@ -36,6 +36,11 @@ export interface ShortcutBinding {
   trigger_kind?: TriggerKind;
   /** Modifier target for "modifier_hold" / "isolated_tap" kinds. */
   modifier?: ModifierSpec | null;
+  /** Recording actions only: speech language to use instead of the global one. */
+  language?: string;
+  /** Recording actions only: model (and its engine) to use instead of the global one. */
+  model?: string;
+  engine?: string;
 }
 
 export interface ShortcutSettings {
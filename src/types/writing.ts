@@ -11,6 +11,8 @@ export interface TextReplacementRule {
   to: string
   language?: string | null
   enabled: boolean
+  case_sensitive?: boolean // default false
+  whole_word?: boolean // default true; false also matches inside longer words
 }
 
 export interface VoiceCommandRule {
@@ -35,16 +37,34 @@ export interface Snippet {
   preserve_literal: boolean
 }
 
+export type SymbolSpacing = 'tight' | 'spaced' | 'attach_left' | 'attach_right'
+
+export interface SpokenSymbolRule {
+  phrase: string
+  symbol: string
+  spacing: SymbolSpacing
+  enabled: boolean
+}
+
+// Deterministic code dictation. Left undefined, the backend applies its
+// built-in symbol vocabulary (`default_code_symbols()` in writing.rs).
+export interface CodeModeSettings {
+  apps: string[]
+  symbols?: SpokenSymbolRule[]
+}
+
 export interface WritingSettings {
   replacements: TextReplacementRule[]
   custom_words: CustomWord[]
   snippets: Snippet[]
   voice_commands: VoiceCommandRule[]
+  voice_commands_enabled?: boolean // default true; false keeps spoken commands as words
   app_formatting_rules: AppFormattingRule[]
+  code_mode?: CodeModeSettings
 }
 
 // Built-in voice commands. MUST mirror the Rust `default_voice_commands()` in
-// `src-tauri/src/writing.rs` so the TypeScript default agrees with the serde
+// `src-tauri/src/text/voice_commands.rs` so the TypeScript default agrees with the serde
 // default Rust applies when `voice_commands` is absent. Rust treats an explicit
 // `[]` as "built-ins disabled", so the TS default must never be `[]` for an
 // unconfigured user — that would round-trip through `update_writing_settings`
@@ -78,6 +98,8 @@ export const mergeWritingSettings = (
   custom_words: partial.custom_words ?? defaultWritingSettings.custom_words,
   snippets: partial.snippets ?? defaultWritingSettings.snippets,
   voice_commands: partial.voice_commands ?? defaultWritingSettings.voice_commands,
+  voice_commands_enabled: partial.voice_commands_enabled,
   app_formatting_rules:
     partial.app_formatting_rules ?? defaultWritingSettings.app_formatting_rules,
+  code_mode: partial.code_mode,
 })
@@ -41,6 +41,21 @@ export const keyringHas = async (key: string): Promise<boolean> => {
   return await invoke<boolean>('keyring_has', { key });
 };
 
+/**
+ * List the identifiers of all credentials in the secure store (values are never returned)
+ */
+export const listStoredKeys = async (): Promise<string[]> => {
+  return await invoke<string[]>('list_stored_keys');
+};
+
+/**
+ * Delete every stored API key and connection password (the license is kept)
+ * @returns The identifiers that were removed
+ */
+export const clearAllKeys = async (): Promise<string[]> => {
+  return await invoke<string[]>('clear_all_keys');
+};
+
 interface SaveApiKeyOptions {
   baseUrl?: string;
   model?: string;
@@ -117,6 +132,11 @@ export const saveSttApiKey = async (provider: string, apiKey: string): Promise<v
   // Validate first; only persist on success
   await invoke('validate_stt_key', { provider, apiKey });
   await keyringSet(`stt_api_key_${provider}`, apiKey);
+  try {
+    await invoke('clear_stt_key_cache', { provider });
+  } catch (_) {
+    // best-effort
+  }
   await emit('stt-key-saved', { provider });
 };
 
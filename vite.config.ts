@@ -21,6 +21,7 @@ export default defineConfig(async () => ({
         main: path.resolve(__dirname, 'index.html'),
         pill: path.resolve(__dirname, 'pill.html'),
         toast: path.resolve(__dirname, 'toast.html'),
+        scratchpad: path.resolve(__dirname, 'scratchpad.html'),
       },
     },
   },